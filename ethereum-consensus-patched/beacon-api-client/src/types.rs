@@ -2,6 +2,7 @@ use crate::ApiError;
 use ethereum_consensus::{
     altair::networking::MetaData,
     capella::Withdrawal,
+    fork_choice::{ChainReorgEvent, FinalizedCheckpointEvent, HeadEvent},
     networking::{Enr, Multiaddr, PeerId},
     phase0::{Checkpoint, SignedBeaconBlockHeader, Validator},
     primitives::{
@@ -294,6 +295,30 @@ impl Topic for PayloadAttributesTopic {
     type Data = VersionedValue<PayloadAttributesEvent>;
 }
 
+pub struct HeadTopic;
+
+impl Topic for HeadTopic {
+    const NAME: &'static str = "head";
+
+    type Data = HeadEvent;
+}
+
+pub struct ChainReorgTopic;
+
+impl Topic for ChainReorgTopic {
+    const NAME: &'static str = "chain_reorg";
+
+    type Data = ChainReorgEvent;
+}
+
+pub struct FinalizedCheckpointTopic;
+
+impl Topic for FinalizedCheckpointTopic {
+    const NAME: &'static str = "finalized_checkpoint";
+
+    type Data = FinalizedCheckpointEvent;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PayloadAttributesEvent {
     #[serde(with = "crate::serde::as_str")]
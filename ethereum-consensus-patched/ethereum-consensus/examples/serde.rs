@@ -12,18 +12,18 @@ use ethereum_consensus::{
 
 fn main() {
     let mut block = SignedBeaconBlock::default();
-    block.message.body.proposer_slashings.push(ProposerSlashing::default());
-    block.message.body.attester_slashings.push(AttesterSlashing::default());
-    block.message.body.attestations.push(Attestation::default());
-    block.message.body.deposits.push(Deposit::default());
-    block.message.body.voluntary_exits.push(SignedVoluntaryExit::default());
+    block.message.body.proposer_slashings.push(ProposerSlashing::default()).unwrap();
+    block.message.body.attester_slashings.push(AttesterSlashing::default()).unwrap();
+    block.message.body.attestations.push(Attestation::default()).unwrap();
+    block.message.body.deposits.push(Deposit::default()).unwrap();
+    block.message.body.voluntary_exits.push(SignedVoluntaryExit::default()).unwrap();
 
     let block_json = serde_json::to_string(&block).unwrap();
     println!("{block_json}");
     let _: SignedBeaconBlock = serde_json::from_str(&block_json).unwrap();
 
     let mut state = BeaconState::default();
-    state.current_epoch_attestations.push(PendingAttestation::default());
+    state.current_epoch_attestations.push(PendingAttestation::default()).unwrap();
 
     let state_json = serde_json::to_string(&state).unwrap();
     println!("{state_json}");
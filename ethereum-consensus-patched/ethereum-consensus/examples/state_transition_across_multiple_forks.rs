@@ -28,7 +28,7 @@ fn main() -> std::result::Result<(), Box<dyn Error>> {
     block.message.slot = executor.context.bellatrix_fork_epoch * executor.context.slots_per_epoch;
     executor.apply_block(&mut block.into())?;
 
-    let mut state = executor.state.bellatrix().unwrap();
+    let state = executor.state.bellatrix().unwrap();
     let state_root = state.hash_tree_root()?;
     dbg!(state_root);
     Ok(())
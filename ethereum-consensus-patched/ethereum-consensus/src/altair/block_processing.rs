@@ -221,11 +221,11 @@ pub fn process_deposit<
             // NOTE: explicitly return with no error and also no further mutations to `state`
             return Ok(())
         }
-        state.validators.push(get_validator_from_deposit(deposit, context));
-        state.balances.push(amount);
-        state.previous_epoch_participation.push(ParticipationFlags::default());
-        state.current_epoch_participation.push(ParticipationFlags::default());
-        state.inactivity_scores.push(0)
+        state.validators.push(get_validator_from_deposit(deposit, context))?;
+        state.balances.push(amount)?;
+        state.previous_epoch_participation.push(ParticipationFlags::default())?;
+        state.current_epoch_participation.push(ParticipationFlags::default())?;
+        state.inactivity_scores.push(0)?
     } else {
         let index = state.validators.iter().position(|v| &v.public_key == public_key).unwrap();
 
@@ -363,7 +363,7 @@ pub fn process_block<
 ) -> Result<()> {
     process_block_header(state, block, context)?;
     process_randao(state, &block.body, context)?;
-    process_eth1_data(state, &block.body, context);
+    process_eth1_data(state, &block.body, context)?;
     process_operations(state, &mut block.body, context)?;
     process_sync_aggregate(state, &block.body.sync_aggregate, context)?;
     Ok(())
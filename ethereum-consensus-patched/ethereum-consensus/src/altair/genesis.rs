@@ -39,6 +39,55 @@ pub fn initialize_beacon_state_from_eth1<
         MAX_VALIDATORS_PER_COMMITTEE,
         SYNC_COMMITTEE_SIZE,
     >,
+> {
+    initialize_beacon_state_from_eth1_with_progress::<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        MAX_PROPOSER_SLASHINGS,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+    >(eth1_block_hash, eth1_timestamp, deposits, context, |_| {})
+}
+
+pub fn initialize_beacon_state_from_eth1_with_progress<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+>(
+    eth1_block_hash: Hash32,
+    eth1_timestamp: u64,
+    deposits: &mut [Deposit],
+    context: &Context,
+    observer: impl FnMut(&Progress),
+) -> Result<
+    BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
 > {
     let fork = Fork {
         previous_version: context.altair_fork_version,
@@ -50,7 +99,7 @@ pub fn initialize_beacon_state_from_eth1<
         deposit_count: deposits.len() as u64,
         ..Default::default()
     };
-    let mut latest_block_body = BeaconBlockBody::<
+    let latest_block_body = BeaconBlockBody::<
         MAX_PROPOSER_SLASHINGS,
         MAX_VALIDATORS_PER_COMMITTEE,
         MAX_ATTESTER_SLASHINGS,
@@ -76,11 +125,13 @@ pub fn initialize_beacon_state_from_eth1<
         ..Default::default()
     };
 
+    let mut tracker = ProgressTracker::new(deposits.len() as u64, observer);
     let mut leaves = List::<DepositData, DEPOSIT_DATA_LIST_BOUND>::default();
     for deposit in deposits.iter_mut() {
-        leaves.push(deposit.data.clone());
+        leaves.push(deposit.data.clone())?;
         state.eth1_data.deposit_root = leaves.hash_tree_root()?;
         process_deposit(&mut state, deposit, context)?;
+        tracker.advance(1);
     }
 
     for i in 0..state.validators.len() {
@@ -103,5 +154,7 @@ pub fn initialize_beacon_state_from_eth1<
     state.current_sync_committee = sync_committee.clone();
     state.next_sync_committee = sync_committee;
 
+    tracker.finish();
+
     Ok(state)
 }
@@ -1,15 +1,19 @@
+#[cfg(feature = "transition")]
+use crate::altair::{
+    get_base_reward, get_total_balance, is_in_inactivity_leak, PARTICIPATION_FLAG_WEIGHTS,
+};
 use crate::{
     altair::{
         beacon_state::BeaconState,
         compute_shuffled_index,
         constants::{
-            PARTICIPATION_FLAG_WEIGHTS, PROPOSER_WEIGHT, TIMELY_HEAD_FLAG_INDEX,
-            TIMELY_SOURCE_FLAG_INDEX, TIMELY_TARGET_FLAG_INDEX, WEIGHT_DENOMINATOR,
+            PROPOSER_WEIGHT, TIMELY_HEAD_FLAG_INDEX, TIMELY_SOURCE_FLAG_INDEX,
+            TIMELY_TARGET_FLAG_INDEX, WEIGHT_DENOMINATOR,
         },
-        decrease_balance, get_active_validator_indices, get_base_reward, get_beacon_proposer_index,
-        get_block_root, get_block_root_at_slot, get_current_epoch, get_eligible_validator_indices,
-        get_previous_epoch, get_seed, get_total_active_balance, get_total_balance,
-        increase_balance, initiate_validator_exit, is_in_inactivity_leak,
+        decrease_balance, get_active_validator_indices, get_beacon_proposer_index, get_block_root,
+        get_block_root_at_slot, get_current_epoch, get_eligible_validator_indices,
+        get_previous_epoch, get_seed, get_total_active_balance, increase_balance,
+        initiate_validator_exit,
         sync::SyncCommittee,
         AttestationData,
     },
@@ -17,7 +21,7 @@ use crate::{
     domains::DomainType,
     error::{invalid_operation_error, Error, InvalidAttestation, InvalidOperation},
     primitives::{BlsPublicKey, Epoch, Gwei, ParticipationFlags, ValidatorIndex},
-    ssz::prelude::Vector,
+    ssz::prelude::{Proof, Prove, Vector},
     state_transition::{Context, Result},
 };
 use integer_sqrt::IntegerSquareRoot;
@@ -59,6 +63,35 @@ pub fn get_next_sync_committee_indices<
     context: &Context,
 ) -> Result<Vec<ValidatorIndex>> {
     let epoch = get_current_epoch(state, context) + 1;
+    compute_sync_committee_indices(state, epoch, context)
+}
+
+// Return the sync committee indices, with possible duplicates, for the sync committee selected
+// at `epoch` from the validators active then. At the start of each sync committee period, the
+// committee for the following period is selected.
+pub fn compute_sync_committee_indices<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    epoch: Epoch,
+    context: &Context,
+) -> Result<Vec<ValidatorIndex>> {
     let max_random_byte = u8::MAX as u64;
     let active_validator_indices = get_active_validator_indices(state, epoch);
     let active_validator_count = active_validator_indices.len();
@@ -112,7 +145,35 @@ pub fn get_next_sync_committee<
     >,
     context: &Context,
 ) -> Result<SyncCommittee<SYNC_COMMITTEE_SIZE>> {
-    let indices = get_next_sync_committee_indices(state, context)?;
+    let epoch = get_current_epoch(state, context) + 1;
+    compute_sync_committee(state, epoch, context)
+}
+
+// Return the sync committee selected at `epoch`, with possible pubkey duplicates.
+pub fn compute_sync_committee<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    epoch: Epoch,
+    context: &Context,
+) -> Result<SyncCommittee<SYNC_COMMITTEE_SIZE>> {
+    let indices = compute_sync_committee_indices(state, epoch, context)?;
     let public_keys =
         indices.into_iter().map(|i| state.validators[i].public_key.clone()).collect::<Vec<_>>();
     let public_keys = Vector::<BlsPublicKey, SYNC_COMMITTEE_SIZE>::try_from(public_keys)
@@ -122,6 +183,80 @@ pub fn get_next_sync_committee<
     Ok(SyncCommittee::<SYNC_COMMITTEE_SIZE> { public_keys, aggregate_public_key })
 }
 
+// Return the sync committee period of `epoch`.
+pub fn compute_sync_committee_period(epoch: Epoch, context: &Context) -> u64 {
+    epoch / context.epochs_per_sync_committee_period
+}
+
+// Return the sync committee for `period`, which is either the current or the next period of
+// `state`: committees of earlier periods are no longer in the state and those of later periods
+// depend on validator balances and randomness which are not yet known.
+pub fn get_sync_committee_for_period<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    period: u64,
+    context: &Context,
+) -> Result<SyncCommittee<SYNC_COMMITTEE_SIZE>> {
+    let current_period = compute_sync_committee_period(get_current_epoch(state, context), context);
+    if period == current_period {
+        Ok(state.current_sync_committee.clone())
+    } else if period == current_period + 1 {
+        Ok(state.next_sync_committee.clone())
+    } else {
+        Err(Error::SyncCommitteePeriodUnavailable { requested: period, current: current_period })
+    }
+}
+
+// Return the sync committee for `period`, as `get_sync_committee_for_period` does, along with
+// a proof of it against the hash tree root of `state`.
+pub fn prove_sync_committee_for_period<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    period: u64,
+    context: &Context,
+) -> Result<(SyncCommittee<SYNC_COMMITTEE_SIZE>, Proof)> {
+    let sync_committee = get_sync_committee_for_period(state, period, context)?;
+    let current_period = compute_sync_committee_period(get_current_epoch(state, context), context);
+    let field =
+        if period == current_period { "current_sync_committee" } else { "next_sync_committee" };
+    let proof = state.prove(&[field.into()])?;
+    Ok((sync_committee, proof))
+}
+
 pub fn get_base_reward_per_increment<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -262,6 +397,7 @@ pub fn get_attestation_participation_flag_indices<
 }
 
 // Return the deltas for a given ``flag_index`` by scanning through the participation flags.
+#[cfg(feature = "transition")]
 pub fn get_flag_index_deltas<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -409,3 +545,31 @@ pub fn slash_validator<
     increase_balance(state, whistleblower_index, whistleblower_reward - proposer_reward);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "light-client")]
+    use crate::altair::light_client::NEXT_SYNC_COMMITTEE_INDEX;
+    use crate::{altair::minimal::BeaconState, mock::mock_with_context, ssz::prelude::*};
+
+    #[test]
+    fn test_sync_committee_for_period() {
+        let context = Context::for_minimal();
+        let mut state = mock_with_context::<BeaconState>(7, &context);
+        state.slot = 3 * context.slots_per_epoch * context.epochs_per_sync_committee_period + 5;
+        let period = 3;
+
+        let current = get_sync_committee_for_period(&state, period, &context).unwrap();
+        assert_eq!(current, state.current_sync_committee);
+        assert!(get_sync_committee_for_period(&state, period + 2, &context).is_err());
+        assert!(get_sync_committee_for_period(&state, period - 1, &context).is_err());
+
+        let (next, proof) = prove_sync_committee_for_period(&state, period + 1, &context).unwrap();
+        assert_eq!(next, state.next_sync_committee);
+        #[cfg(feature = "light-client")]
+        assert_eq!(proof.generalized_index().unwrap(), NEXT_SYNC_COMMITTEE_INDEX);
+        assert_eq!(proof.leaf, next.hash_tree_root().unwrap());
+        assert!(proof.verify(state.hash_tree_root().unwrap()).is_ok());
+    }
+}
@@ -1,10 +1,14 @@
 use crate::{
     altair::{
+        beacon_state::BeaconState,
+        compute_epoch_at_slot, compute_sync_committee_period,
         sync::{SyncAggregate, SyncCommittee},
         BeaconBlockHeader,
     },
-    primitives::{Bytes32, Slot},
+    error::{Error, InvalidLightClientUpdate},
+    primitives::{Bytes32, Root, Slot, GENESIS_SLOT},
     ssz::prelude::*,
+    state_transition::Context,
 };
 
 pub const FINALIZED_ROOT_INDEX: usize = 105;
@@ -55,6 +59,32 @@ pub struct LightClientOptimisticUpdate<const SYNC_COMMITTEE_SIZE: usize> {
     pub signature_slot: Slot,
 }
 
+impl<const SYNC_COMMITTEE_SIZE: usize> From<&LightClientUpdate<SYNC_COMMITTEE_SIZE>>
+    for LightClientFinalityUpdate<SYNC_COMMITTEE_SIZE>
+{
+    fn from(update: &LightClientUpdate<SYNC_COMMITTEE_SIZE>) -> Self {
+        Self {
+            attested_header: update.attested_header.clone(),
+            finalized_header: update.finalized_header.clone(),
+            finality_branch: update.finality_branch.clone(),
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+        }
+    }
+}
+
+impl<const SYNC_COMMITTEE_SIZE: usize> From<&LightClientUpdate<SYNC_COMMITTEE_SIZE>>
+    for LightClientOptimisticUpdate<SYNC_COMMITTEE_SIZE>
+{
+    fn from(update: &LightClientUpdate<SYNC_COMMITTEE_SIZE>) -> Self {
+        Self {
+            attested_header: update.attested_header.clone(),
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct LightClientStore<const SYNC_COMMITTEE_SIZE: usize> {
     pub finalized_header: LightClientHeader,
@@ -65,3 +95,288 @@ pub struct LightClientStore<const SYNC_COMMITTEE_SIZE: usize> {
     pub previous_max_active_participants: u64,
     pub current_max_active_participants: u64,
 }
+
+// Return the bootstrap for the block with `header`, where `state` is the post-state of that block.
+pub fn create_light_client_bootstrap<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    header: &BeaconBlockHeader,
+) -> crate::state_transition::Result<LightClientBootstrap<SYNC_COMMITTEE_SIZE>> {
+    let state_root = state.hash_tree_root()?;
+    crate::altair::light_client::verify_post_state(header, state.slot, state_root)?;
+    let proof = state.prove(&["current_sync_committee".into()])?;
+    Ok(LightClientBootstrap {
+        header: LightClientHeader { beacon: header.clone() },
+        current_sync_committee: state.current_sync_committee.clone(),
+        current_sync_committee_branch: crate::altair::light_client::as_branch(
+            proof,
+            CURRENT_SYNC_COMMITTEE_INDEX,
+        )?,
+    })
+}
+
+// Return the update for `sync_aggregate`, signed at `signature_slot` over the block with
+// `attested_header`. The `attested_state` is the post-state of that block and
+// `finalized_header` the block of its finalized checkpoint, if known.
+pub fn create_light_client_update<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    attested_state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    attested_header: &BeaconBlockHeader,
+    finalized_header: Option<&BeaconBlockHeader>,
+    sync_aggregate: &SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    signature_slot: Slot,
+    context: &Context,
+) -> crate::state_transition::Result<LightClientUpdate<SYNC_COMMITTEE_SIZE>> {
+    let participants = sync_aggregate.sync_committee_bits.count_ones();
+    if participants < context.min_sync_committee_participants {
+        return Err(InvalidLightClientUpdate::InsufficientParticipation {
+            participants,
+            minimum: context.min_sync_committee_participants,
+        }
+        .into())
+    }
+    if signature_slot <= attested_header.slot {
+        return Err(InvalidLightClientUpdate::SignatureSlotNotAfterAttested {
+            signature_slot,
+            attested_slot: attested_header.slot,
+        }
+        .into())
+    }
+    let state_root = attested_state.hash_tree_root()?;
+    crate::altair::light_client::verify_post_state(
+        attested_header,
+        attested_state.slot,
+        state_root,
+    )?;
+
+    let mut update = LightClientUpdate {
+        attested_header: LightClientHeader { beacon: attested_header.clone() },
+        sync_aggregate: sync_aggregate.clone(),
+        signature_slot,
+        ..Default::default()
+    };
+
+    // the next sync committee is only useful when signed by the current sync committee
+    let attested_epoch = compute_epoch_at_slot(attested_header.slot, context);
+    let signature_epoch = compute_epoch_at_slot(signature_slot, context);
+    let attested_period = compute_sync_committee_period(attested_epoch, context);
+    let signature_period = compute_sync_committee_period(signature_epoch, context);
+    if attested_period == signature_period {
+        let proof = attested_state.prove(&["next_sync_committee".into()])?;
+        update.next_sync_committee = attested_state.next_sync_committee.clone();
+        update.next_sync_committee_branch =
+            crate::altair::light_client::as_branch(proof, NEXT_SYNC_COMMITTEE_INDEX)?;
+    }
+
+    if let Some(finalized_header) = finalized_header {
+        let expected = attested_state.finalized_checkpoint.root;
+        // the genesis block is finalized with an empty root and so has an empty header
+        if finalized_header.slot != GENESIS_SLOT {
+            let provided = finalized_header.hash_tree_root()?;
+            if provided != expected {
+                return Err(
+                    InvalidLightClientUpdate::FinalizedRootMismatch { provided, expected }.into()
+                )
+            }
+            update.finalized_header = LightClientHeader { beacon: finalized_header.clone() };
+        } else if expected != Root::default() {
+            return Err(InvalidLightClientUpdate::FinalizedRootMismatch {
+                provided: Root::default(),
+                expected,
+            }
+            .into())
+        }
+        let proof = attested_state.prove(&["finalized_checkpoint".into(), "root".into()])?;
+        update.finality_branch =
+            crate::altair::light_client::as_branch(proof, FINALIZED_ROOT_INDEX)?;
+    }
+
+    Ok(update)
+}
+
+// The `header` must be the latest block header of the state at `state_slot` with root
+// `state_root`, i.e. with its state root filled in.
+pub(crate) fn verify_post_state(
+    header: &BeaconBlockHeader,
+    state_slot: Slot,
+    state_root: Root,
+) -> Result<(), Error> {
+    if state_slot != header.slot || state_root != header.state_root {
+        return Err(InvalidLightClientUpdate::StateHeaderMismatch {
+            state_root,
+            state_slot,
+            header_state_root: header.state_root,
+            header_slot: header.slot,
+        }
+        .into())
+    }
+    Ok(())
+}
+
+// Convert `proof` into the fixed-depth branch carried by light client messages, checking
+// that it locates the node light clients expect.
+pub(crate) fn as_branch<const DEPTH: usize>(
+    proof: Proof,
+    generalized_index: usize,
+) -> Result<Vector<Bytes32, DEPTH>, Error> {
+    let provided = proof.generalized_index()?;
+    if provided != generalized_index {
+        return Err(InvalidLightClientUpdate::UnexpectedGeneralizedIndex {
+            provided,
+            expected: generalized_index,
+        }
+        .into())
+    }
+    let branch = proof
+        .branch
+        .iter()
+        .map(|node| Bytes32::try_from(node.as_ref()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(SimpleSerializeError::from)?;
+    let branch = Vector::try_from(branch).map_err(|(_, err)| err)?;
+    Ok(branch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        altair::minimal::{BeaconState, SyncAggregate},
+        mock::mock_with_context,
+        state_transition::Context,
+    };
+
+    fn verify_branch(
+        leaf: Node,
+        branch: &[Bytes32],
+        depth: usize,
+        generalized_index: usize,
+        root: Root,
+    ) -> bool {
+        let index = generalized_index % 2usize.pow(depth as u32);
+        is_valid_merkle_branch(leaf, branch, depth, index, root).is_ok()
+    }
+
+    #[test]
+    fn test_create_light_client_update() {
+        let context = Context::for_minimal();
+        let mut state = mock_with_context::<BeaconState>(7, &context);
+        let slots_per_period = context.slots_per_epoch * context.epochs_per_sync_committee_period;
+        state.slot = 3 * slots_per_period + 5;
+        let finalized_header = BeaconBlockHeader { slot: 8, ..Default::default() };
+        state.finalized_checkpoint.root = finalized_header.hash_tree_root().unwrap();
+        let state_root = state.hash_tree_root().unwrap();
+        let header = BeaconBlockHeader { slot: state.slot, state_root, ..Default::default() };
+
+        let bootstrap = create_light_client_bootstrap(&state, &header).unwrap();
+        assert!(verify_branch(
+            bootstrap.current_sync_committee.hash_tree_root().unwrap(),
+            &bootstrap.current_sync_committee_branch,
+            CURRENT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2,
+            CURRENT_SYNC_COMMITTEE_INDEX,
+            state_root,
+        ));
+
+        let mut sync_aggregate = SyncAggregate::default();
+        sync_aggregate.sync_committee_bits.set(0, true);
+        let update = create_light_client_update(
+            &state,
+            &header,
+            Some(&finalized_header),
+            &sync_aggregate,
+            header.slot + 1,
+            &context,
+        )
+        .unwrap();
+        assert_eq!(update.next_sync_committee, state.next_sync_committee);
+        assert!(verify_branch(
+            update.next_sync_committee.hash_tree_root().unwrap(),
+            &update.next_sync_committee_branch,
+            NEXT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2,
+            NEXT_SYNC_COMMITTEE_INDEX,
+            state_root,
+        ));
+        assert_eq!(update.finalized_header.beacon, finalized_header);
+        assert!(verify_branch(
+            state.finalized_checkpoint.root,
+            &update.finality_branch,
+            FINALIZED_ROOT_INDEX_FLOOR_LOG_2,
+            FINALIZED_ROOT_INDEX,
+            state_root,
+        ));
+        let finality_update = LightClientFinalityUpdate::from(&update);
+        assert_eq!(finality_update.finality_branch, update.finality_branch);
+
+        // signed by the next sync committee, which cannot vouch for its successor
+        let update = create_light_client_update(
+            &state,
+            &header,
+            None,
+            &sync_aggregate,
+            4 * slots_per_period,
+            &context,
+        )
+        .unwrap();
+        assert_eq!(update.next_sync_committee_branch, Default::default());
+        assert_eq!(update.finality_branch, Default::default());
+
+        let result =
+            create_light_client_update(&state, &header, None, &sync_aggregate, 4, &context);
+        assert!(result.is_err());
+        let result = create_light_client_update(
+            &state,
+            &header,
+            None,
+            &SyncAggregate::default(),
+            header.slot + 1,
+            &context,
+        );
+        assert!(result.is_err());
+        let stale_header = BeaconBlockHeader { state_root: Root::default(), ..header.clone() };
+        assert!(create_light_client_bootstrap(&state, &stale_header).is_err());
+        let other_header = BeaconBlockHeader { slot: 9, ..Default::default() };
+        let result = create_light_client_update(
+            &state,
+            &header,
+            Some(&other_header),
+            &sync_aggregate,
+            header.slot + 1,
+            &context,
+        );
+        assert!(result.is_err());
+    }
+}
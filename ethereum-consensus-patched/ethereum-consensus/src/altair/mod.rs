@@ -3,19 +3,25 @@
 //! the "presets" like `mainnet` or `minimal`.
 pub mod beacon_block;
 pub mod beacon_state;
+#[cfg(feature = "transition")]
 pub mod block_processing;
 pub mod constants;
+#[cfg(feature = "transition")]
 pub mod epoch_processing;
+#[cfg(feature = "transition")]
 pub mod fork;
+#[cfg(feature = "transition")]
 pub mod genesis;
 pub mod helpers;
+#[cfg(feature = "light-client")]
 pub mod light_client;
 pub mod networking;
 pub mod presets;
 pub mod spec;
 pub mod sync;
+#[cfg(feature = "validator")]
 pub mod validator;
 
 pub use spec::*;
 
-pub use presets::{mainnet, minimal, Preset};
+pub use presets::{gnosis, mainnet, minimal, Preset};
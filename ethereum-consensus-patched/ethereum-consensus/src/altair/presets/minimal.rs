@@ -1,15 +1,16 @@
-use crate::altair::spec;
+#[cfg(feature = "validator")]
+pub use crate::phase0::presets::minimal::{AggregateAndProof, SignedAggregateAndProof};
 pub use crate::{
     altair::presets::Preset,
     phase0::presets::minimal::{
-        AggregateAndProof, Attestation, AttesterSlashing, HistoricalBatch, IndexedAttestation,
-        PendingAttestation, SignedAggregateAndProof, EPOCHS_PER_HISTORICAL_VECTOR,
-        EPOCHS_PER_SLASHINGS_VECTOR, ETH1_DATA_VOTES_BOUND, HISTORICAL_ROOTS_LIMIT,
-        MAX_ATTESTATIONS, MAX_ATTESTER_SLASHINGS, MAX_DEPOSITS, MAX_PROPOSER_SLASHINGS,
-        MAX_VALIDATORS_PER_COMMITTEE, MAX_VOLUNTARY_EXITS, SLOTS_PER_HISTORICAL_ROOT,
-        VALIDATOR_REGISTRY_LIMIT,
+        Attestation, AttesterSlashing, HistoricalBatch, IndexedAttestation, PendingAttestation,
+        EPOCHS_PER_HISTORICAL_VECTOR, EPOCHS_PER_SLASHINGS_VECTOR, ETH1_DATA_VOTES_BOUND,
+        HISTORICAL_ROOTS_LIMIT, MAX_ATTESTATIONS, MAX_ATTESTER_SLASHINGS, MAX_DEPOSITS,
+        MAX_PROPOSER_SLASHINGS, MAX_VALIDATORS_PER_COMMITTEE, MAX_VOLUNTARY_EXITS,
+        SLOTS_PER_HISTORICAL_ROOT, VALIDATOR_REGISTRY_LIMIT,
     },
 };
+use crate::{altair::spec, parameters::SpecParameters};
 
 pub use spec::*;
 
@@ -34,14 +35,28 @@ pub const PRESET: Preset = Preset {
     update_timeout: UPDATE_TIMEOUT,
 };
 
+pub const SPEC_PARAMETERS: SpecParameters = SpecParameters {
+    fork: crate::Fork::Altair,
+    phase0: crate::phase0::presets::minimal::PRESET,
+    altair: Some(crate::altair::presets::minimal::PRESET),
+    bellatrix: None,
+    capella: None,
+    deneb: None,
+    electra: None,
+};
+
 pub type SyncAggregate = spec::SyncAggregate<SYNC_COMMITTEE_SIZE>;
 pub type SyncCommittee = spec::SyncCommittee<SYNC_COMMITTEE_SIZE>;
 
+#[cfg(feature = "light-client")]
 pub type LightClientUpdate = crate::altair::light_client::LightClientUpdate<SYNC_COMMITTEE_SIZE>;
+#[cfg(feature = "light-client")]
 pub type LightClientBootstrap =
     crate::altair::light_client::LightClientBootstrap<SYNC_COMMITTEE_SIZE>;
+#[cfg(feature = "light-client")]
 pub type LightClientFinalityUpdate =
     crate::altair::light_client::LightClientFinalityUpdate<SYNC_COMMITTEE_SIZE>;
+#[cfg(feature = "light-client")]
 pub type LightClientOptimisticUpdate =
     crate::altair::light_client::LightClientOptimisticUpdate<SYNC_COMMITTEE_SIZE>;
 
@@ -86,6 +101,9 @@ pub type SignedBeaconBlock = spec::SignedBeaconBlock<
     SYNC_COMMITTEE_SIZE,
 >;
 
+#[cfg(feature = "validator")]
 pub type SyncCommitteeContribution = spec::SyncCommitteeContribution<SYNC_SUBCOMMITTEE_SIZE>;
+#[cfg(feature = "validator")]
 pub type ContributionAndProof = spec::ContributionAndProof<SYNC_SUBCOMMITTEE_SIZE>;
+#[cfg(feature = "validator")]
 pub type SignedContributionAndProof = spec::SignedContributionAndProof<SYNC_SUBCOMMITTEE_SIZE>;
@@ -1,8 +1,11 @@
+pub mod gnosis;
 pub mod mainnet;
 pub mod minimal;
 
 use crate::primitives::Epoch;
 
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
 pub struct Preset {
     pub inactivity_penalty_quotient_altair: u64,
     pub min_slashing_penalty_quotient_altair: u64,
@@ -1,42 +1,99 @@
 //! WARNING: This file was derived by the `spec-gen` utility. DO NOT EDIT MANUALLY.
+#[cfg(feature = "transition")]
+pub use crate::altair::block_processing::process_attestation;
+#[cfg(feature = "transition")]
+pub use crate::altair::block_processing::process_block;
+#[cfg(feature = "transition")]
+pub use crate::altair::block_processing::process_deposit;
+#[cfg(feature = "transition")]
+pub use crate::altair::block_processing::process_sync_aggregate;
+#[cfg(feature = "transition")]
+pub use crate::altair::epoch_processing::get_base_reward;
+#[cfg(feature = "transition")]
+pub use crate::altair::epoch_processing::process_epoch;
+#[cfg(feature = "transition")]
+pub use crate::altair::epoch_processing::process_inactivity_updates;
+#[cfg(feature = "transition")]
+pub use crate::altair::epoch_processing::process_justification_and_finalization;
+#[cfg(feature = "transition")]
+pub use crate::altair::epoch_processing::process_participation_flag_updates;
+#[cfg(feature = "transition")]
+pub use crate::altair::epoch_processing::process_rewards_and_penalties;
+#[cfg(feature = "transition")]
+pub use crate::altair::epoch_processing::process_slashings;
+#[cfg(feature = "transition")]
+pub use crate::altair::epoch_processing::process_sync_committee_updates;
+#[cfg(feature = "transition")]
+pub use crate::altair::fork::upgrade_to_altair;
+#[cfg(feature = "transition")]
+pub use crate::altair::genesis::initialize_beacon_state_from_eth1;
+#[cfg(feature = "transition")]
+pub use crate::altair::genesis::initialize_beacon_state_from_eth1_with_progress;
+#[cfg(feature = "transition")]
+pub use crate::altair::helpers::get_flag_index_deltas;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::create_light_client_bootstrap;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::create_light_client_update;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::LightClientBootstrap;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::LightClientFinalityUpdate;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::LightClientHeader;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::LightClientOptimisticUpdate;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::LightClientStore;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::LightClientUpdate;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::CURRENT_SYNC_COMMITTEE_INDEX;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::CURRENT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::FINALIZED_ROOT_INDEX;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::FINALIZED_ROOT_INDEX_FLOOR_LOG_2;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::NEXT_SYNC_COMMITTEE_INDEX;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::NEXT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2;
+#[cfg(feature = "validator")]
+pub use crate::altair::validator::ContributionAndProof;
+#[cfg(feature = "validator")]
+pub use crate::altair::validator::SignedContributionAndProof;
+#[cfg(feature = "validator")]
+pub use crate::altair::validator::SyncAggregatorSelectionData;
+#[cfg(feature = "validator")]
+pub use crate::altair::validator::SyncCommitteeContribution;
+#[cfg(feature = "validator")]
+pub use crate::altair::validator::SyncCommitteeMessage;
+#[cfg(feature = "validator")]
+pub use crate::phase0::validator::AggregateAndProof;
+#[cfg(feature = "validator")]
+pub use crate::phase0::validator::Eth1Block;
+#[cfg(feature = "validator")]
+pub use crate::phase0::validator::SignedAggregateAndProof;
 pub use crate::{
     altair::{
         beacon_block::{BeaconBlock, BeaconBlockBody, SignedBeaconBlock},
         beacon_state::BeaconState,
-        block_processing::{
-            process_attestation, process_block, process_deposit, process_sync_aggregate,
-        },
         constants::{
             PARTICIPATION_FLAG_WEIGHTS, PROPOSER_WEIGHT, SYNC_COMMITTEE_SUBNET_COUNT,
             SYNC_REWARD_WEIGHT, TIMELY_HEAD_FLAG_INDEX, TIMELY_HEAD_WEIGHT,
             TIMELY_SOURCE_FLAG_INDEX, TIMELY_SOURCE_WEIGHT, TIMELY_TARGET_FLAG_INDEX,
             TIMELY_TARGET_WEIGHT, WEIGHT_DENOMINATOR,
         },
-        epoch_processing::{
-            get_base_reward, process_epoch, process_inactivity_updates,
-            process_justification_and_finalization, process_participation_flag_updates,
-            process_rewards_and_penalties, process_slashings, process_sync_committee_updates,
-        },
-        fork::upgrade_to_altair,
-        genesis::initialize_beacon_state_from_eth1,
         helpers::{
-            add_flag, get_attestation_participation_flag_indices, get_base_reward_per_increment,
-            get_flag_index_deltas, get_inactivity_penalty_deltas, get_next_sync_committee,
-            get_next_sync_committee_indices, get_unslashed_participating_indices, has_flag,
+            add_flag, compute_sync_committee, compute_sync_committee_indices,
+            compute_sync_committee_period, get_attestation_participation_flag_indices,
+            get_base_reward_per_increment, get_inactivity_penalty_deltas, get_next_sync_committee,
+            get_next_sync_committee_indices, get_sync_committee_for_period,
+            get_unslashed_participating_indices, has_flag, prove_sync_committee_for_period,
             slash_validator,
         },
-        light_client::{
-            LightClientBootstrap, LightClientFinalityUpdate, LightClientHeader,
-            LightClientOptimisticUpdate, LightClientStore, LightClientUpdate,
-            CURRENT_SYNC_COMMITTEE_INDEX, CURRENT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2,
-            FINALIZED_ROOT_INDEX, FINALIZED_ROOT_INDEX_FLOOR_LOG_2, NEXT_SYNC_COMMITTEE_INDEX,
-            NEXT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2,
-        },
         sync::{SyncAggregate, SyncCommittee},
-        validator::{
-            ContributionAndProof, SignedContributionAndProof, SyncAggregatorSelectionData,
-            SyncCommitteeContribution, SyncCommitteeMessage,
-        },
     },
     error::*,
     phase0::{
@@ -51,7 +108,7 @@ pub use crate::{
             DepositMessage, Eth1Data, IndexedAttestation, PendingAttestation, ProposerSlashing,
             SignedVoluntaryExit, VoluntaryExit,
         },
-        validator::{AggregateAndProof, Eth1Block, SignedAggregateAndProof, Validator},
+        validator::Validator,
     },
     primitives::*,
     signing::*,
@@ -62,6 +119,7 @@ use crate::{
     ssz::prelude::*,
 };
 use std::{cmp, collections::HashSet};
+#[cfg(feature = "transition")]
 pub fn process_proposer_slashing<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -131,6 +189,7 @@ pub fn process_proposer_slashing<
     }
     slash_validator(state, proposer_index, None, context)
 }
+#[cfg(feature = "transition")]
 pub fn process_attester_slashing<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -187,6 +246,7 @@ pub fn process_attester_slashing<
         Ok(())
     }
 }
+#[cfg(feature = "transition")]
 pub fn get_validator_from_deposit(deposit: &Deposit, context: &Context) -> Validator {
     let amount = deposit.data.amount;
     let effective_balance = Gwei::min(
@@ -204,6 +264,7 @@ pub fn get_validator_from_deposit(deposit: &Deposit, context: &Context) -> Valid
         ..Default::default()
     }
 }
+#[cfg(feature = "transition")]
 pub fn process_voluntary_exit<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -273,6 +334,7 @@ pub fn process_voluntary_exit<
     initiate_validator_exit(state, voluntary_exit.validator_index, context);
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_block_header<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -348,10 +410,12 @@ pub fn process_block_header<
     }
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn xor(a: &Bytes32, b: &Bytes32) -> Bytes32 {
     let inner = a.iter().zip(b.iter()).map(|(a, b)| a ^ b).collect::<Vec<_>>();
     ByteVector::<32>::try_from(inner.as_ref()).unwrap()
 }
+#[cfg(feature = "transition")]
 pub fn process_randao<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -401,6 +465,7 @@ pub fn process_randao<
     state.randao_mixes[mix_index as usize] = mix;
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_eth1_data<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -436,14 +501,16 @@ pub fn process_eth1_data<
         SYNC_COMMITTEE_SIZE,
     >,
     context: &Context,
-) {
-    state.eth1_data_votes.push(body.eth1_data.clone());
+) -> Result<()> {
+    state.eth1_data_votes.push(body.eth1_data.clone())?;
     let votes_count =
         state.eth1_data_votes.iter().filter(|&vote| *vote == body.eth1_data).count() as u64;
     if votes_count * 2 > context.epochs_per_eth1_voting_period * context.slots_per_epoch {
         state.eth1_data = body.eth1_data.clone();
     }
+    Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_operations<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -505,6 +572,7 @@ pub fn process_operations<
         .try_for_each(|op| process_voluntary_exit(state, op, context))?;
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_registry_updates<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -563,6 +631,7 @@ pub fn process_registry_updates<
         validator.activation_epoch = activation_exit_epoch;
     }
 }
+#[cfg(feature = "transition")]
 pub fn process_eth1_data_reset<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -590,6 +659,7 @@ pub fn process_eth1_data_reset<
         state.eth1_data_votes.clear();
     }
 }
+#[cfg(feature = "transition")]
 pub fn process_effective_balance_updates<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -628,6 +698,7 @@ pub fn process_effective_balance_updates<
         }
     }
 }
+#[cfg(feature = "transition")]
 pub fn process_slashings_reset<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -654,6 +725,7 @@ pub fn process_slashings_reset<
     let slashings_index = next_epoch % context.epochs_per_slashings_vector;
     state.slashings[slashings_index as usize] = 0;
 }
+#[cfg(feature = "transition")]
 pub fn process_randao_mixes_reset<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -681,6 +753,7 @@ pub fn process_randao_mixes_reset<
     let mix_index = next_epoch % context.epochs_per_historical_vector;
     state.randao_mixes[mix_index as usize] = get_randao_mix(state, current_epoch).clone();
 }
+#[cfg(feature = "transition")]
 pub fn process_historical_roots_update<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -706,14 +779,15 @@ pub fn process_historical_roots_update<
     let next_epoch = get_current_epoch(state, context) + 1;
     let epochs_per_historical_root = context.slots_per_historical_root / context.slots_per_epoch;
     if next_epoch % epochs_per_historical_root == 0 {
-        let mut historical_batch = HistoricalSummary {
+        let historical_batch = HistoricalSummary {
             block_summary_root: state.block_roots.hash_tree_root()?,
             state_summary_root: state.state_roots.hash_tree_root()?,
         };
-        state.historical_roots.push(historical_batch.hash_tree_root()?)
+        state.historical_roots.push(historical_batch.hash_tree_root()?)?
     }
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn weigh_justification_and_finalization<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -775,6 +849,7 @@ pub fn weigh_justification_and_finalization<
     }
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn get_proposer_reward<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -800,6 +875,7 @@ pub fn get_proposer_reward<
 ) -> Result<Gwei> {
     Ok(get_base_reward(state, attesting_index, context)? / context.proposer_reward_quotient)
 }
+#[cfg(feature = "transition")]
 pub fn get_finality_delay<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -824,6 +900,7 @@ pub fn get_finality_delay<
 ) -> Epoch {
     get_previous_epoch(state, context) - state.finalized_checkpoint.epoch
 }
+#[cfg(feature = "transition")]
 pub fn is_in_inactivity_leak<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -848,6 +925,7 @@ pub fn is_in_inactivity_leak<
 ) -> bool {
     get_finality_delay(state, context) > context.min_epochs_to_inactivity_penalty
 }
+#[cfg(feature = "transition")]
 pub fn is_valid_genesis_state<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -876,6 +954,7 @@ pub fn is_valid_genesis_state<
     get_active_validator_indices(state, GENESIS_EPOCH).len() >=
         context.min_genesis_active_validator_count
 }
+#[cfg(feature = "transition")]
 pub fn get_genesis_block<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -952,10 +1031,7 @@ pub fn is_slashable_validator(validator: &Validator, epoch: Epoch) -> bool {
         epoch < validator.withdrawable_epoch
 }
 pub fn is_slashable_attestation_data(data_1: &AttestationData, data_2: &AttestationData) -> bool {
-    let double_vote = data_1 != data_2 && data_1.target.epoch == data_2.target.epoch;
-    let surround_vote =
-        data_1.source.epoch < data_2.source.epoch && data_2.target.epoch < data_1.target.epoch;
-    double_vote || surround_vote
+    data_1.is_slashable(data_2)
 }
 pub fn is_valid_indexed_attestation<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
@@ -1241,10 +1317,7 @@ pub fn compute_domain(
     let fork_version = fork_version.unwrap_or(context.genesis_fork_version);
     let genesis_validators_root = genesis_validators_root.unwrap_or_default();
     let fork_data_root = compute_fork_data_root(fork_version, genesis_validators_root)?;
-    let mut domain = Domain::default();
-    domain[..4].copy_from_slice(&domain_type.as_bytes());
-    domain[4..].copy_from_slice(&fork_data_root[..28]);
-    Ok(domain)
+    Ok(Domain::new(domain_type, &fork_data_root))
 }
 pub fn compute_fork_data_root(
     current_version: Version,
@@ -1810,6 +1883,7 @@ pub fn get_eligible_validator_indices<
         }
     })
 }
+#[cfg(feature = "transition")]
 pub fn process_slots<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1845,6 +1919,7 @@ pub fn process_slots<
     }
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_slot<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1878,6 +1953,7 @@ pub fn process_slot<
     state.block_roots[root_index as usize] = previous_block_root;
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn state_transition_block_in_slot<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1930,6 +2006,7 @@ pub fn state_transition_block_in_slot<
         Ok(())
     }
 }
+#[cfg(feature = "transition")]
 pub fn state_transition<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -4,7 +4,17 @@ use crate::{
 };
 
 #[derive(
-    Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    Clone,
+    SimpleSerialize,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct SyncAggregate<const SYNC_COMMITTEE_SIZE: usize> {
     pub sync_committee_bits: Bitvector<SYNC_COMMITTEE_SIZE>,
@@ -12,7 +22,17 @@ pub struct SyncAggregate<const SYNC_COMMITTEE_SIZE: usize> {
 }
 
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct SyncCommittee<const SYNC_COMMITTEE_SIZE: usize> {
     #[serde(rename = "pubkeys")]
@@ -8,7 +8,17 @@ use crate::{
 };
 
 #[derive(
-    Default, Debug, Clone, PartialEq, Eq, SimpleSerialize, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    SimpleSerialize,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct BlindedBeaconBlockBody<
     const MAX_PROPOSER_SLASHINGS: usize,
@@ -36,7 +46,17 @@ pub struct BlindedBeaconBlockBody<
 }
 
 #[derive(
-    Default, Debug, Clone, PartialEq, Eq, SimpleSerialize, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    SimpleSerialize,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct BlindedBeaconBlock<
     const MAX_PROPOSER_SLASHINGS: usize,
@@ -69,7 +89,17 @@ pub struct BlindedBeaconBlock<
 }
 
 #[derive(
-    Default, Debug, Clone, PartialEq, Eq, SimpleSerialize, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    SimpleSerialize,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct SignedBlindedBeaconBlock<
     const MAX_PROPOSER_SLASHINGS: usize,
@@ -7,7 +7,7 @@ use crate::{
     },
     error::{invalid_operation_error, InvalidExecutionPayload},
     ssz::prelude::*,
-    state_transition::{Context, Result},
+    state_transition::{Context, PayloadStatus, Result},
 };
 
 pub fn process_execution_payload<
@@ -50,7 +50,7 @@ pub fn process_execution_payload<
     >,
     execution_engine: &E,
     context: &Context,
-) -> Result<()> {
+) -> Result<PayloadStatus> {
     let parent_hash_invalid =
         payload.parent_hash != state.latest_execution_payload_header.block_hash;
     if is_merge_transition_complete(state) && parent_hash_invalid {
@@ -87,7 +87,8 @@ pub fn process_execution_payload<
     }
 
     let new_payload_request = NewPayloadRequest(payload);
-    execution_engine.verify_and_notify_new_payload(&new_payload_request)?;
+    let status = execution_engine.verify_and_notify_new_payload(&new_payload_request)?;
+    status.ensure_not_invalid()?;
 
     state.latest_execution_payload_header = ExecutionPayloadHeader {
         parent_hash: payload.parent_hash.clone(),
@@ -106,7 +107,7 @@ pub fn process_execution_payload<
         transactions_root: payload.transactions.hash_tree_root()?,
     };
 
-    Ok(())
+    Ok(status)
 }
 
 pub fn process_block<
@@ -161,10 +162,12 @@ pub fn process_block<
     >,
     execution_engine: &E,
     context: &Context,
-) -> Result<()> {
+) -> Result<PayloadStatus> {
     process_block_header(state, block, context)?;
+    // a block from before the merge has no payload to validate, so it is never optimistic
+    let mut status = PayloadStatus::Valid;
     if is_execution_enabled(state, &block.body) {
-        process_execution_payload(
+        status = process_execution_payload(
             state,
             &mut block.body.execution_payload,
             execution_engine,
@@ -172,8 +175,8 @@ pub fn process_block<
         )?;
     }
     process_randao(state, &block.body, context)?;
-    process_eth1_data(state, &block.body, context);
+    process_eth1_data(state, &block.body, context)?;
     process_operations(state, &mut block.body, context)?;
     process_sync_aggregate(state, &block.body.sync_aggregate, context)?;
-    Ok(())
+    Ok(status)
 }
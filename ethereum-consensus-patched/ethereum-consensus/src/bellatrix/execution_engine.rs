@@ -1,7 +1,6 @@
 use crate::{
     bellatrix::execution_payload::ExecutionPayload,
-    error::ExecutionEngineError,
-    state_transition::{self, Result},
+    state_transition::{self, PayloadStatus, Result},
 };
 
 pub struct NewPayloadRequest<
@@ -34,25 +33,31 @@ pub trait ExecutionEngine<
             MAX_BYTES_PER_TRANSACTION,
             MAX_TRANSACTIONS_PER_PAYLOAD,
         >,
-    ) -> Result<()>;
+    ) -> Result<PayloadStatus>;
 }
 
-// The `DefaultExecutionEngine` performs no operations and validation
-// is determined by `execution_is_valid`.
+// The `DefaultExecutionEngine` performs no operations and reports the
+// `payload_status` it was configured with for every payload.
 #[derive(Debug)]
 pub struct DefaultExecutionEngine {
-    execution_is_valid: bool,
+    payload_status: PayloadStatus,
 }
 
 impl Default for DefaultExecutionEngine {
     fn default() -> Self {
-        Self { execution_is_valid: true }
+        Self { payload_status: PayloadStatus::Valid }
     }
 }
 
 impl DefaultExecutionEngine {
     pub fn new(execution_is_valid: bool) -> Self {
-        Self { execution_is_valid }
+        let payload_status =
+            if execution_is_valid { PayloadStatus::Valid } else { PayloadStatus::InvalidBlockHash };
+        Self { payload_status }
+    }
+
+    pub fn with_payload_status(payload_status: PayloadStatus) -> Self {
+        Self { payload_status }
     }
 
     fn is_valid_block_hash<
@@ -68,12 +73,8 @@ impl DefaultExecutionEngine {
             MAX_BYTES_PER_TRANSACTION,
             MAX_TRANSACTIONS_PER_PAYLOAD,
         >,
-    ) -> Result<()> {
-        if !self.execution_is_valid {
-            Err(ExecutionEngineError::InvalidBlockHash.into())
-        } else {
-            Ok(())
-        }
+    ) -> bool {
+        self.payload_status != PayloadStatus::InvalidBlockHash
     }
 
     fn notify_new_payload<
@@ -89,12 +90,8 @@ impl DefaultExecutionEngine {
             MAX_BYTES_PER_TRANSACTION,
             MAX_TRANSACTIONS_PER_PAYLOAD,
         >,
-    ) -> Result<()> {
-        if !self.execution_is_valid {
-            Err(ExecutionEngineError::InvalidPayload.into())
-        } else {
-            Ok(())
-        }
+    ) -> PayloadStatus {
+        self.payload_status.clone()
     }
 }
 
@@ -119,9 +116,11 @@ impl<
             MAX_BYTES_PER_TRANSACTION,
             MAX_TRANSACTIONS_PER_PAYLOAD,
         >,
-    ) -> Result<()> {
-        self.is_valid_block_hash(new_payload_request.0)?;
-        self.notify_new_payload(new_payload_request.0)
+    ) -> Result<PayloadStatus> {
+        if !self.is_valid_block_hash(new_payload_request.0) {
+            return Ok(PayloadStatus::InvalidBlockHash)
+        }
+        Ok(self.notify_new_payload(new_payload_request.0))
     }
 }
 
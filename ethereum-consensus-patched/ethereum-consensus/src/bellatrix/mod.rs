@@ -4,19 +4,24 @@
 pub mod beacon_block;
 pub mod beacon_state;
 pub mod blinded_beacon_block;
+#[cfg(feature = "transition")]
 pub mod block_processing;
+#[cfg(feature = "transition")]
 pub mod epoch_processing;
 pub mod execution_engine;
 pub mod execution_payload;
+#[cfg(feature = "transition")]
 pub mod fork;
 pub mod fork_choice;
+#[cfg(feature = "transition")]
 pub mod genesis;
 pub mod helpers;
 pub mod networking;
 pub mod presets;
 pub mod spec;
+#[cfg(feature = "transition")]
 pub mod state_transition;
 
 pub use spec::*;
 
-pub use presets::{mainnet, minimal, Preset};
+pub use presets::{gnosis, mainnet, minimal, Preset};
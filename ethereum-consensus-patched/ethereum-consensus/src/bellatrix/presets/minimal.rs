@@ -1,16 +1,21 @@
-use crate::bellatrix::spec;
+#[cfg(feature = "light-client")]
+pub use crate::altair::presets::minimal::LightClientUpdate;
+#[cfg(feature = "validator")]
+pub use crate::altair::presets::minimal::{
+    AggregateAndProof, ContributionAndProof, SignedAggregateAndProof, SignedContributionAndProof,
+    SyncCommitteeContribution,
+};
 pub use crate::{
     altair::presets::minimal::{
-        AggregateAndProof, Attestation, AttesterSlashing, ContributionAndProof, HistoricalBatch,
-        IndexedAttestation, LightClientUpdate, PendingAttestation, SignedAggregateAndProof,
-        SignedContributionAndProof, SyncAggregate, SyncCommittee, SyncCommitteeContribution,
-        EPOCHS_PER_HISTORICAL_VECTOR, EPOCHS_PER_SLASHINGS_VECTOR, ETH1_DATA_VOTES_BOUND,
-        HISTORICAL_ROOTS_LIMIT, MAX_ATTESTATIONS, MAX_ATTESTER_SLASHINGS, MAX_DEPOSITS,
-        MAX_PROPOSER_SLASHINGS, MAX_VALIDATORS_PER_COMMITTEE, MAX_VOLUNTARY_EXITS,
+        Attestation, AttesterSlashing, HistoricalBatch, IndexedAttestation, PendingAttestation,
+        SyncAggregate, SyncCommittee, EPOCHS_PER_HISTORICAL_VECTOR, EPOCHS_PER_SLASHINGS_VECTOR,
+        ETH1_DATA_VOTES_BOUND, HISTORICAL_ROOTS_LIMIT, MAX_ATTESTATIONS, MAX_ATTESTER_SLASHINGS,
+        MAX_DEPOSITS, MAX_PROPOSER_SLASHINGS, MAX_VALIDATORS_PER_COMMITTEE, MAX_VOLUNTARY_EXITS,
         SLOTS_PER_HISTORICAL_ROOT, SYNC_COMMITTEE_SIZE, VALIDATOR_REGISTRY_LIMIT,
     },
     bellatrix::presets::Preset,
 };
+use crate::{bellatrix::spec, parameters::SpecParameters};
 
 pub use spec::*;
 
@@ -32,6 +37,16 @@ pub const PRESET: Preset = Preset {
     max_extra_data_bytes: MAX_EXTRA_DATA_BYTES,
 };
 
+pub const SPEC_PARAMETERS: SpecParameters = SpecParameters {
+    fork: crate::Fork::Bellatrix,
+    phase0: crate::phase0::presets::minimal::PRESET,
+    altair: Some(crate::altair::presets::minimal::PRESET),
+    bellatrix: Some(crate::bellatrix::presets::minimal::PRESET),
+    capella: None,
+    deneb: None,
+    electra: None,
+};
+
 pub type Transaction = spec::Transaction<MAX_BYTES_PER_TRANSACTION>;
 
 pub type ExecutionPayload = spec::ExecutionPayload<
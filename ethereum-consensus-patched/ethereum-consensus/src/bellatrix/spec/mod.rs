@@ -1,4 +1,64 @@
 //! WARNING: This file was derived by the `spec-gen` utility. DO NOT EDIT MANUALLY.
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::LightClientBootstrap;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::LightClientFinalityUpdate;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::LightClientHeader;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::LightClientOptimisticUpdate;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::LightClientStore;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::LightClientUpdate;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::CURRENT_SYNC_COMMITTEE_INDEX;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::CURRENT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::FINALIZED_ROOT_INDEX;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::FINALIZED_ROOT_INDEX_FLOOR_LOG_2;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::NEXT_SYNC_COMMITTEE_INDEX;
+#[cfg(feature = "light-client")]
+pub use crate::altair::light_client::NEXT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2;
+#[cfg(feature = "validator")]
+pub use crate::altair::validator::ContributionAndProof;
+#[cfg(feature = "validator")]
+pub use crate::altair::validator::SignedContributionAndProof;
+#[cfg(feature = "validator")]
+pub use crate::altair::validator::SyncAggregatorSelectionData;
+#[cfg(feature = "validator")]
+pub use crate::altair::validator::SyncCommitteeContribution;
+#[cfg(feature = "validator")]
+pub use crate::altair::validator::SyncCommitteeMessage;
+#[cfg(feature = "transition")]
+pub use crate::bellatrix::block_processing::process_block;
+#[cfg(feature = "transition")]
+pub use crate::bellatrix::block_processing::process_execution_payload;
+#[cfg(feature = "transition")]
+pub use crate::bellatrix::epoch_processing::process_epoch;
+#[cfg(feature = "transition")]
+pub use crate::bellatrix::epoch_processing::process_slashings;
+#[cfg(feature = "transition")]
+pub use crate::bellatrix::fork::upgrade_to_bellatrix;
+#[cfg(feature = "transition")]
+pub use crate::bellatrix::genesis::initialize_beacon_state_from_eth1;
+#[cfg(feature = "transition")]
+pub use crate::bellatrix::genesis::initialize_beacon_state_from_eth1_with_progress;
+#[cfg(feature = "transition")]
+pub use crate::bellatrix::state_transition::state_transition;
+#[cfg(feature = "transition")]
+pub use crate::bellatrix::state_transition::state_transition_block_in_slot;
+#[cfg(feature = "transition")]
+use crate::crypto::eth_fast_aggregate_verify;
+#[cfg(feature = "validator")]
+pub use crate::phase0::validator::AggregateAndProof;
+#[cfg(feature = "validator")]
+pub use crate::phase0::validator::Eth1Block;
+#[cfg(feature = "validator")]
+pub use crate::phase0::validator::SignedAggregateAndProof;
 pub use crate::{
     altair::{
         constants::{
@@ -7,18 +67,7 @@ pub use crate::{
             TIMELY_SOURCE_FLAG_INDEX, TIMELY_SOURCE_WEIGHT, TIMELY_TARGET_FLAG_INDEX,
             TIMELY_TARGET_WEIGHT, WEIGHT_DENOMINATOR,
         },
-        light_client::{
-            LightClientBootstrap, LightClientFinalityUpdate, LightClientHeader,
-            LightClientOptimisticUpdate, LightClientStore, LightClientUpdate,
-            CURRENT_SYNC_COMMITTEE_INDEX, CURRENT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2,
-            FINALIZED_ROOT_INDEX, FINALIZED_ROOT_INDEX_FLOOR_LOG_2, NEXT_SYNC_COMMITTEE_INDEX,
-            NEXT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2,
-        },
         sync::{SyncAggregate, SyncCommittee},
-        validator::{
-            ContributionAndProof, SignedContributionAndProof, SyncAggregatorSelectionData,
-            SyncCommitteeContribution, SyncCommitteeMessage,
-        },
     },
     bellatrix::{
         beacon_block::{BeaconBlock, BeaconBlockBody, SignedBeaconBlock},
@@ -26,18 +75,13 @@ pub use crate::{
         blinded_beacon_block::{
             BlindedBeaconBlock, BlindedBeaconBlockBody, SignedBlindedBeaconBlock,
         },
-        block_processing::{process_block, process_execution_payload},
-        epoch_processing::{process_epoch, process_slashings},
         execution_engine::{DefaultExecutionEngine, NewPayloadRequest},
         execution_payload::{ExecutionPayload, ExecutionPayloadHeader, Transaction},
-        fork::upgrade_to_bellatrix,
         fork_choice::PowBlock,
-        genesis::initialize_beacon_state_from_eth1,
         helpers::{
             compute_timestamp_at_slot, get_inactivity_penalty_deltas, is_execution_enabled,
             is_merge_transition_block, is_merge_transition_complete, slash_validator,
         },
-        state_transition::{state_transition, state_transition_block_in_slot},
     },
     error::*,
     phase0::{
@@ -52,23 +96,21 @@ pub use crate::{
             DepositMessage, Eth1Data, IndexedAttestation, PendingAttestation, ProposerSlashing,
             SignedVoluntaryExit, VoluntaryExit,
         },
-        validator::{AggregateAndProof, Eth1Block, SignedAggregateAndProof, Validator},
+        validator::Validator,
     },
     primitives::*,
     signing::*,
-    state_transition::{Context, Result, Validation},
+    state_transition::{Context, PayloadStatus, Result, Validation},
 };
 use crate::{
-    crypto::{eth_aggregate_public_keys, eth_fast_aggregate_verify, fast_aggregate_verify, hash},
+    crypto::{eth_aggregate_public_keys, fast_aggregate_verify, hash},
     ssz::prelude::*,
 };
 use integer_sqrt::IntegerSquareRoot;
-use std::{
-    cmp,
-    collections::{HashMap, HashSet},
-    iter::zip,
-    mem,
-};
+use std::{cmp, collections::HashSet};
+#[cfg(feature = "transition")]
+use std::{collections::HashMap, iter::zip, mem};
+#[cfg(feature = "transition")]
 pub fn process_attestation<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -183,6 +225,7 @@ pub fn process_attestation<
     increase_balance(state, get_beacon_proposer_index(state, context)?, proposer_reward);
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_deposit<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -245,17 +288,18 @@ pub fn process_deposit<
         if verify_signature(public_key, signing_root.as_ref(), &deposit.data.signature).is_err() {
             return Ok(())
         }
-        state.validators.push(get_validator_from_deposit(deposit, context));
-        state.balances.push(amount);
-        state.previous_epoch_participation.push(ParticipationFlags::default());
-        state.current_epoch_participation.push(ParticipationFlags::default());
-        state.inactivity_scores.push(0)
+        state.validators.push(get_validator_from_deposit(deposit, context))?;
+        state.balances.push(amount)?;
+        state.previous_epoch_participation.push(ParticipationFlags::default())?;
+        state.current_epoch_participation.push(ParticipationFlags::default())?;
+        state.inactivity_scores.push(0)?
     } else {
         let index = state.validators.iter().position(|v| &v.public_key == public_key).unwrap();
         increase_balance(state, index, amount);
     }
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_sync_aggregate<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -343,6 +387,7 @@ pub fn process_sync_aggregate<
     }
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_proposer_slashing<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -416,6 +461,7 @@ pub fn process_proposer_slashing<
     }
     slash_validator(state, proposer_index, None, context)
 }
+#[cfg(feature = "transition")]
 pub fn process_attester_slashing<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -476,6 +522,7 @@ pub fn process_attester_slashing<
         Ok(())
     }
 }
+#[cfg(feature = "transition")]
 pub fn get_validator_from_deposit(deposit: &Deposit, context: &Context) -> Validator {
     let amount = deposit.data.amount;
     let effective_balance = Gwei::min(
@@ -493,6 +540,7 @@ pub fn get_validator_from_deposit(deposit: &Deposit, context: &Context) -> Valid
         ..Default::default()
     }
 }
+#[cfg(feature = "transition")]
 pub fn process_voluntary_exit<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -566,6 +614,7 @@ pub fn process_voluntary_exit<
     initiate_validator_exit(state, voluntary_exit.validator_index, context);
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_block_header<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -651,10 +700,12 @@ pub fn process_block_header<
     }
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn xor(a: &Bytes32, b: &Bytes32) -> Bytes32 {
     let inner = a.iter().zip(b.iter()).map(|(a, b)| a ^ b).collect::<Vec<_>>();
     ByteVector::<32>::try_from(inner.as_ref()).unwrap()
 }
+#[cfg(feature = "transition")]
 pub fn process_randao<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -714,6 +765,7 @@ pub fn process_randao<
     state.randao_mixes[mix_index as usize] = mix;
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_eth1_data<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -759,14 +811,16 @@ pub fn process_eth1_data<
         MAX_TRANSACTIONS_PER_PAYLOAD,
     >,
     context: &Context,
-) {
-    state.eth1_data_votes.push(body.eth1_data.clone());
+) -> Result<()> {
+    state.eth1_data_votes.push(body.eth1_data.clone())?;
     let votes_count =
         state.eth1_data_votes.iter().filter(|&vote| *vote == body.eth1_data).count() as u64;
     if votes_count * 2 > context.epochs_per_eth1_voting_period * context.slots_per_epoch {
         state.eth1_data = body.eth1_data.clone();
     }
+    Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_operations<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -838,6 +892,7 @@ pub fn process_operations<
         .try_for_each(|op| process_voluntary_exit(state, op, context))?;
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn get_base_reward<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -869,6 +924,7 @@ pub fn get_base_reward<
         state.validators[index].effective_balance / context.effective_balance_increment;
     Ok(increments * get_base_reward_per_increment(state, context)?)
 }
+#[cfg(feature = "transition")]
 pub fn process_justification_and_finalization<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -922,6 +978,7 @@ pub fn process_justification_and_finalization<
         context,
     )
 }
+#[cfg(feature = "transition")]
 pub fn process_inactivity_updates<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -974,6 +1031,7 @@ pub fn process_inactivity_updates<
     }
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_rewards_and_penalties<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1018,6 +1076,7 @@ pub fn process_rewards_and_penalties<
     }
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_participation_flag_updates<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1050,6 +1109,7 @@ pub fn process_participation_flag_updates<
         rotate_participation.try_into().expect("should convert from Vec to List");
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_sync_committee_updates<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1085,6 +1145,7 @@ pub fn process_sync_committee_updates<
     }
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_registry_updates<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1147,6 +1208,7 @@ pub fn process_registry_updates<
         validator.activation_epoch = activation_exit_epoch;
     }
 }
+#[cfg(feature = "transition")]
 pub fn process_eth1_data_reset<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1178,6 +1240,7 @@ pub fn process_eth1_data_reset<
         state.eth1_data_votes.clear();
     }
 }
+#[cfg(feature = "transition")]
 pub fn process_effective_balance_updates<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1220,6 +1283,7 @@ pub fn process_effective_balance_updates<
         }
     }
 }
+#[cfg(feature = "transition")]
 pub fn process_slashings_reset<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1250,6 +1314,7 @@ pub fn process_slashings_reset<
     let slashings_index = next_epoch % context.epochs_per_slashings_vector;
     state.slashings[slashings_index as usize] = 0;
 }
+#[cfg(feature = "transition")]
 pub fn process_randao_mixes_reset<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1281,6 +1346,7 @@ pub fn process_randao_mixes_reset<
     let mix_index = next_epoch % context.epochs_per_historical_vector;
     state.randao_mixes[mix_index as usize] = get_randao_mix(state, current_epoch).clone();
 }
+#[cfg(feature = "transition")]
 pub fn process_historical_roots_update<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1310,14 +1376,15 @@ pub fn process_historical_roots_update<
     let next_epoch = get_current_epoch(state, context) + 1;
     let epochs_per_historical_root = context.slots_per_historical_root / context.slots_per_epoch;
     if next_epoch % epochs_per_historical_root == 0 {
-        let mut historical_batch = HistoricalSummary {
+        let historical_batch = HistoricalSummary {
             block_summary_root: state.block_roots.hash_tree_root()?,
             state_summary_root: state.state_roots.hash_tree_root()?,
         };
-        state.historical_roots.push(historical_batch.hash_tree_root()?)
+        state.historical_roots.push(historical_batch.hash_tree_root()?)?
     }
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn weigh_justification_and_finalization<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1383,6 +1450,7 @@ pub fn weigh_justification_and_finalization<
     }
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn get_proposer_reward<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1412,6 +1480,7 @@ pub fn get_proposer_reward<
 ) -> Result<Gwei> {
     Ok(get_base_reward(state, attesting_index, context)? / context.proposer_reward_quotient)
 }
+#[cfg(feature = "transition")]
 pub fn get_finality_delay<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1440,6 +1509,7 @@ pub fn get_finality_delay<
 ) -> Epoch {
     get_previous_epoch(state, context) - state.finalized_checkpoint.epoch
 }
+#[cfg(feature = "transition")]
 pub fn is_in_inactivity_leak<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1468,6 +1538,7 @@ pub fn is_in_inactivity_leak<
 ) -> bool {
     get_finality_delay(state, context) > context.min_epochs_to_inactivity_penalty
 }
+#[cfg(feature = "transition")]
 pub fn is_valid_genesis_state<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1500,6 +1571,7 @@ pub fn is_valid_genesis_state<
     get_active_validator_indices(state, GENESIS_EPOCH).len() >=
         context.min_genesis_active_validator_count
 }
+#[cfg(feature = "transition")]
 pub fn get_genesis_block<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1583,6 +1655,35 @@ pub fn get_next_sync_committee_indices<
     context: &Context,
 ) -> Result<Vec<ValidatorIndex>> {
     let epoch = get_current_epoch(state, context) + 1;
+    compute_sync_committee_indices(state, epoch, context)
+}
+pub fn compute_sync_committee_indices<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    epoch: Epoch,
+    context: &Context,
+) -> Result<Vec<ValidatorIndex>> {
     let max_random_byte = u8::MAX as u64;
     let active_validator_indices = get_active_validator_indices(state, epoch);
     let active_validator_count = active_validator_indices.len();
@@ -1636,7 +1737,37 @@ pub fn get_next_sync_committee<
     >,
     context: &Context,
 ) -> Result<SyncCommittee<SYNC_COMMITTEE_SIZE>> {
-    let indices = get_next_sync_committee_indices(state, context)?;
+    let epoch = get_current_epoch(state, context) + 1;
+    compute_sync_committee(state, epoch, context)
+}
+pub fn compute_sync_committee<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    epoch: Epoch,
+    context: &Context,
+) -> Result<SyncCommittee<SYNC_COMMITTEE_SIZE>> {
+    let indices = compute_sync_committee_indices(state, epoch, context)?;
     let public_keys =
         indices.into_iter().map(|i| state.validators[i].public_key.clone()).collect::<Vec<_>>();
     let public_keys = Vector::<BlsPublicKey, SYNC_COMMITTEE_SIZE>::try_from(public_keys)
@@ -1644,6 +1775,79 @@ pub fn get_next_sync_committee<
     let aggregate_public_key = eth_aggregate_public_keys(&public_keys)?;
     Ok(SyncCommittee::<SYNC_COMMITTEE_SIZE> { public_keys, aggregate_public_key })
 }
+pub fn compute_sync_committee_period(epoch: Epoch, context: &Context) -> u64 {
+    epoch / context.epochs_per_sync_committee_period
+}
+pub fn get_sync_committee_for_period<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    period: u64,
+    context: &Context,
+) -> Result<SyncCommittee<SYNC_COMMITTEE_SIZE>> {
+    let current_period = compute_sync_committee_period(get_current_epoch(state, context), context);
+    if period == current_period {
+        Ok(state.current_sync_committee.clone())
+    } else if period == current_period + 1 {
+        Ok(state.next_sync_committee.clone())
+    } else {
+        Err(Error::SyncCommitteePeriodUnavailable { requested: period, current: current_period })
+    }
+}
+pub fn prove_sync_committee_for_period<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    period: u64,
+    context: &Context,
+) -> Result<(SyncCommittee<SYNC_COMMITTEE_SIZE>, Proof)> {
+    let sync_committee = get_sync_committee_for_period(state, period, context)?;
+    let current_period = compute_sync_committee_period(get_current_epoch(state, context), context);
+    let field =
+        if period == current_period { "current_sync_committee" } else { "next_sync_committee" };
+    let proof = state.prove(&[field.into()])?;
+    Ok((sync_committee, proof))
+}
 pub fn get_base_reward_per_increment<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1784,6 +1988,7 @@ pub fn get_attestation_participation_flag_indices<
     }
     Ok(participation_flag_indices)
 }
+#[cfg(feature = "transition")]
 pub fn get_flag_index_deltas<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1880,10 +2085,7 @@ pub fn is_slashable_validator(validator: &Validator, epoch: Epoch) -> bool {
         epoch < validator.withdrawable_epoch
 }
 pub fn is_slashable_attestation_data(data_1: &AttestationData, data_2: &AttestationData) -> bool {
-    let double_vote = data_1 != data_2 && data_1.target.epoch == data_2.target.epoch;
-    let surround_vote =
-        data_1.source.epoch < data_2.source.epoch && data_2.target.epoch < data_1.target.epoch;
-    double_vote || surround_vote
+    data_1.is_slashable(data_2)
 }
 pub fn is_valid_indexed_attestation<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
@@ -2195,10 +2397,7 @@ pub fn compute_domain(
     let fork_version = fork_version.unwrap_or(context.genesis_fork_version);
     let genesis_validators_root = genesis_validators_root.unwrap_or_default();
     let fork_data_root = compute_fork_data_root(fork_version, genesis_validators_root)?;
-    let mut domain = Domain::default();
-    domain[..4].copy_from_slice(&domain_type.as_bytes());
-    domain[4..].copy_from_slice(&fork_data_root[..28]);
-    Ok(domain)
+    Ok(Domain::new(domain_type, &fork_data_root))
 }
 pub fn compute_fork_data_root(
     current_version: Version,
@@ -2836,6 +3035,137 @@ pub fn get_eligible_validator_indices<
         }
     })
 }
+#[cfg(feature = "light-client")]
+pub fn create_light_client_bootstrap<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    header: &BeaconBlockHeader,
+) -> crate::state_transition::Result<LightClientBootstrap<SYNC_COMMITTEE_SIZE>> {
+    let state_root = state.hash_tree_root()?;
+    crate::altair::light_client::verify_post_state(header, state.slot, state_root)?;
+    let proof = state.prove(&["current_sync_committee".into()])?;
+    Ok(LightClientBootstrap {
+        header: LightClientHeader { beacon: header.clone() },
+        current_sync_committee: state.current_sync_committee.clone(),
+        current_sync_committee_branch: crate::altair::light_client::as_branch(
+            proof,
+            CURRENT_SYNC_COMMITTEE_INDEX,
+        )?,
+    })
+}
+#[cfg(feature = "light-client")]
+pub fn create_light_client_update<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    attested_state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    attested_header: &BeaconBlockHeader,
+    finalized_header: Option<&BeaconBlockHeader>,
+    sync_aggregate: &SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    signature_slot: Slot,
+    context: &Context,
+) -> crate::state_transition::Result<LightClientUpdate<SYNC_COMMITTEE_SIZE>> {
+    let participants = sync_aggregate.sync_committee_bits.count_ones();
+    if participants < context.min_sync_committee_participants {
+        return Err(InvalidLightClientUpdate::InsufficientParticipation {
+            participants,
+            minimum: context.min_sync_committee_participants,
+        }
+        .into());
+    }
+    if signature_slot <= attested_header.slot {
+        return Err(InvalidLightClientUpdate::SignatureSlotNotAfterAttested {
+            signature_slot,
+            attested_slot: attested_header.slot,
+        }
+        .into());
+    }
+    let state_root = attested_state.hash_tree_root()?;
+    crate::altair::light_client::verify_post_state(
+        attested_header,
+        attested_state.slot,
+        state_root,
+    )?;
+    let mut update = LightClientUpdate {
+        attested_header: LightClientHeader { beacon: attested_header.clone() },
+        sync_aggregate: sync_aggregate.clone(),
+        signature_slot,
+        ..Default::default()
+    };
+    let attested_epoch = compute_epoch_at_slot(attested_header.slot, context);
+    let signature_epoch = compute_epoch_at_slot(signature_slot, context);
+    let attested_period = compute_sync_committee_period(attested_epoch, context);
+    let signature_period = compute_sync_committee_period(signature_epoch, context);
+    if attested_period == signature_period {
+        let proof = attested_state.prove(&["next_sync_committee".into()])?;
+        update.next_sync_committee = attested_state.next_sync_committee.clone();
+        update.next_sync_committee_branch =
+            crate::altair::light_client::as_branch(proof, NEXT_SYNC_COMMITTEE_INDEX)?;
+    }
+    if let Some(finalized_header) = finalized_header {
+        let expected = attested_state.finalized_checkpoint.root;
+        if finalized_header.slot != GENESIS_SLOT {
+            let provided = finalized_header.hash_tree_root()?;
+            if provided != expected {
+                return Err(
+                    InvalidLightClientUpdate::FinalizedRootMismatch { provided, expected }.into()
+                )
+            }
+            update.finalized_header = LightClientHeader { beacon: finalized_header.clone() };
+        } else if expected != Root::default() {
+            return Err(InvalidLightClientUpdate::FinalizedRootMismatch {
+                provided: Root::default(),
+                expected,
+            }
+            .into())
+        }
+        let proof = attested_state.prove(&["finalized_checkpoint".into(), "root".into()])?;
+        update.finality_branch =
+            crate::altair::light_client::as_branch(proof, FINALIZED_ROOT_INDEX)?;
+    }
+    Ok(update)
+}
+#[cfg(feature = "transition")]
 pub fn process_slots<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -2875,6 +3205,7 @@ pub fn process_slots<
     }
     Ok(())
 }
+#[cfg(feature = "transition")]
 pub fn process_slot<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
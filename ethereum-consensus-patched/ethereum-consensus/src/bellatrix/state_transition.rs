@@ -4,7 +4,7 @@ use crate::{
         SignedBeaconBlock,
     },
     ssz::prelude::Merkleized,
-    state_transition::{Context, Result, Validation},
+    state_transition::{Context, PayloadStatus, Result, Validation},
     Error,
 };
 
@@ -64,7 +64,7 @@ pub fn state_transition_block_in_slot<
     execution_engine: &E,
     validation: Validation,
     context: &Context,
-) -> Result<()> {
+) -> Result<PayloadStatus> {
     let validate_result = match validation {
         Validation::Enabled => true,
         Validation::Disabled => false,
@@ -73,14 +73,16 @@ pub fn state_transition_block_in_slot<
         verify_block_signature(state, signed_block, context)?;
     }
     let block = &mut signed_block.message;
-    process_block(state, block, execution_engine, context)?;
+    let status = process_block(state, block, execution_engine, context)?;
     if validate_result && block.state_root != state.hash_tree_root()? {
         Err(Error::InvalidStateRoot)
     } else {
-        Ok(())
+        Ok(status)
     }
 }
 
+// Return the status of the execution payload of `signed_block` as reported by the execution
+// engine, e.g. to drive the optimistic sync rules of fork choice with.
 pub fn state_transition<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -134,7 +136,7 @@ pub fn state_transition<
     execution_engine: &E,
     validation: Validation,
     context: &Context,
-) -> Result<()> {
+) -> Result<PayloadStatus> {
     process_slots(state, signed_block.message.slot, context)?;
 
     state_transition_block_in_slot(state, signed_block, execution_engine, validation, context)
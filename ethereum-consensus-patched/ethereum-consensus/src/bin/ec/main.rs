@@ -1,4 +1,5 @@
 mod bls;
+mod test_vectors;
 mod validator;
 
 use clap::{Parser, Subcommand};
@@ -7,6 +8,7 @@ use clap::{Parser, Subcommand};
 pub enum Commands {
     Validator(validator::Command),
     Bls(bls::Command),
+    TestVectors(test_vectors::Command),
 }
 
 #[derive(Debug, Parser)]
@@ -22,5 +24,6 @@ fn main() -> eyre::Result<()> {
     match cli.command {
         Commands::Validator(cmd) => cmd.execute(),
         Commands::Bls(cmd) => cmd.execute(),
+        Commands::TestVectors(cmd) => cmd.execute(),
     }
 }
@@ -0,0 +1,131 @@
+use clap::{Args, ValueEnum};
+use ethereum_consensus::{
+    altair,
+    mock::{mock_with_context, Mock},
+    phase0,
+    primitives::Root,
+    ssz::prelude::*,
+    state_transition::Context,
+};
+use serde::Serialize;
+use std::{fs, path::PathBuf};
+
+type Emitter = fn(u64, &Context) -> eyre::Result<(Vec<u8>, Root)>;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Preset {
+    Mainnet,
+    Minimal,
+}
+
+#[derive(Debug, Args)]
+#[clap(
+    about = "Emits SSZ test vectors (serialized values and their hash tree roots) of consensus types, for checking other implementations against this one."
+)]
+pub struct Command {
+    #[clap(
+        long,
+        value_enum,
+        default_value = "minimal",
+        help = "preset used to specialize each type"
+    )]
+    preset: Preset,
+    #[clap(long, default_value_t = 0, help = "first seed used to generate values (inclusive)")]
+    start_seed: u64,
+    #[clap(long, default_value_t = 8, help = "number of values to generate for each type")]
+    count: u64,
+    #[clap(
+        long = "type",
+        help = "only emit vectors for the given type, e.g. `phase0/BeaconBlock`"
+    )]
+    types: Vec<String>,
+    #[clap(help = "directory to write the manifest and SSZ files into")]
+    output_dir: PathBuf,
+}
+
+#[derive(Debug, Serialize)]
+struct TestVector {
+    #[serde(rename = "type")]
+    type_name: &'static str,
+    seed: u64,
+    // relative to the manifest
+    path: String,
+    serialized_length: usize,
+    root: Root,
+}
+
+#[derive(Debug, Serialize)]
+struct Manifest {
+    preset: String,
+    vectors: Vec<TestVector>,
+}
+
+fn emit<T: Mock + SimpleSerialize>(seed: u64, context: &Context) -> eyre::Result<(Vec<u8>, Root)> {
+    let value = mock_with_context::<T>(seed, context);
+    let encoding = ssz_rs::serialize(&value)?;
+    let root = value.hash_tree_root()?;
+    Ok((encoding, root))
+}
+
+macro_rules! emitters_for_preset {
+    ($preset:ident) => {
+        vec![
+            ("phase0/Checkpoint", emit::<phase0::Checkpoint> as Emitter),
+            ("phase0/AttestationData", emit::<phase0::AttestationData>),
+            ("phase0/Attestation", emit::<phase0::$preset::Attestation>),
+            ("phase0/IndexedAttestation", emit::<phase0::$preset::IndexedAttestation>),
+            ("phase0/Validator", emit::<phase0::Validator>),
+            ("phase0/Eth1Data", emit::<phase0::Eth1Data>),
+            ("phase0/BeaconBlockHeader", emit::<phase0::BeaconBlockHeader>),
+            ("phase0/SignedBeaconBlockHeader", emit::<phase0::SignedBeaconBlockHeader>),
+            ("phase0/BeaconBlock", emit::<phase0::$preset::BeaconBlock>),
+            ("phase0/SignedBeaconBlock", emit::<phase0::$preset::SignedBeaconBlock>),
+            ("phase0/BeaconState", emit::<phase0::$preset::BeaconState>),
+            ("altair/SyncAggregate", emit::<altair::$preset::SyncAggregate>),
+            ("altair/BeaconBlock", emit::<altair::$preset::BeaconBlock>),
+            ("altair/SignedBeaconBlock", emit::<altair::$preset::SignedBeaconBlock>),
+            ("altair/BeaconState", emit::<altair::$preset::BeaconState>),
+        ]
+    };
+}
+
+impl Command {
+    pub fn execute(self) -> eyre::Result<()> {
+        let (preset, context, emitters) = match self.preset {
+            Preset::Mainnet => ("mainnet", Context::for_mainnet(), emitters_for_preset!(mainnet)),
+            Preset::Minimal => ("minimal", Context::for_minimal(), emitters_for_preset!(minimal)),
+        };
+
+        for type_name in &self.types {
+            if !emitters.iter().any(|(name, _)| name == type_name) {
+                eyre::bail!("no test vectors available for type `{type_name}`");
+            }
+        }
+
+        let mut vectors = vec![];
+        for (type_name, emitter) in emitters {
+            if !self.types.is_empty() && !self.types.iter().any(|name| name == type_name) {
+                continue
+            }
+            fs::create_dir_all(self.output_dir.join(type_name))?;
+            for seed in self.start_seed..self.start_seed + self.count {
+                let (encoding, root) = emitter(seed, &context)?;
+                let path = format!("{type_name}/{seed}.ssz");
+                fs::write(self.output_dir.join(&path), &encoding)?;
+                vectors.push(TestVector {
+                    type_name,
+                    seed,
+                    path,
+                    serialized_length: encoding.len(),
+                    root,
+                });
+            }
+        }
+
+        let manifest = Manifest { preset: preset.to_string(), vectors };
+        let manifest_path = self.output_dir.join("manifest.json");
+        fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)?;
+        println!("wrote {} test vectors to {}", manifest.vectors.len(), manifest_path.display());
+        Ok(())
+    }
+}
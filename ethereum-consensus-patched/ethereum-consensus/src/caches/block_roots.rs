@@ -0,0 +1,230 @@
+use crate::{
+    capella::HistoricalSummary,
+    crypto::hash,
+    primitives::{Bytes32, Root, Slot},
+    ssz::prelude::*,
+    state_transition::Context,
+    types::BeaconState,
+};
+use std::collections::BTreeMap;
+
+/// A proof that `block_root` is the block root at `slot`, against the `block_summary_root`
+/// of the historical summary at `historical_summary_index` in a post-`capella` state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockRootProof {
+    pub slot: Slot,
+    pub block_root: Root,
+    pub historical_summary_index: usize,
+    pub branch: Vec<Bytes32>,
+}
+
+impl BlockRootProof {
+    /// Verify the proof against `historical_summary`, which must be the historical summary at
+    /// `self.historical_summary_index`.
+    pub fn verify(&self, historical_summary: &HistoricalSummary, context: &Context) -> bool {
+        let index = (self.slot % context.slots_per_historical_root) as usize;
+        is_valid_merkle_branch(
+            self.block_root,
+            &self.branch,
+            self.branch.len(),
+            index,
+            historical_summary.block_summary_root,
+        )
+        .is_ok()
+    }
+}
+
+/// Block roots indexed by slot, retained beyond the `SLOTS_PER_HISTORICAL_ROOT` slots
+/// kept in the state so that queries for older slots can be answered.
+///
+/// Like `state.block_roots`, the entry for a slot without a block is the root of the most
+/// recent block before it.
+#[derive(Debug, Clone, Default)]
+pub struct BlockRootsCache {
+    roots: BTreeMap<Slot, Root>,
+}
+
+impl BlockRootsCache {
+    pub fn len(&self) -> usize {
+        self.roots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    pub fn insert(&mut self, slot: Slot, root: Root) {
+        self.roots.insert(slot, root);
+    }
+
+    /// Record the block roots of all slots in the history of `state` that it still contains.
+    pub fn update_from_state<
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const PENDING_ATTESTATIONS_BOUND: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    >(
+        &mut self,
+        state: &BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            PENDING_ATTESTATIONS_BOUND,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
+        >,
+    ) {
+        let state_slot = state.slot();
+        let block_roots = state.block_roots();
+        let start = state_slot.saturating_sub(SLOTS_PER_HISTORICAL_ROOT as Slot);
+        for slot in start..state_slot {
+            let root = block_roots[slot as usize % SLOTS_PER_HISTORICAL_ROOT];
+            self.roots.insert(slot, root);
+        }
+    }
+
+    /// Return the block root at `slot`, if known.
+    pub fn get_block_root_at_slot(&self, slot: Slot) -> Option<Root> {
+        self.roots.get(&slot).copied()
+    }
+
+    /// Drop all entries for slots before `slot`.
+    pub fn prune_before(&mut self, slot: Slot) {
+        self.roots = self.roots.split_off(&slot);
+    }
+
+    /// Return the block roots of every slot in the `SLOTS_PER_HISTORICAL_ROOT`-aligned
+    /// period containing `slot`, if all of them are known.
+    ///
+    /// The result is ordered as `state.block_roots` was at the end of that period.
+    pub fn period_roots(&self, slot: Slot, context: &Context) -> Option<Vec<Root>> {
+        let period_start = slot - slot % context.slots_per_historical_root;
+        let period_end = period_start + context.slots_per_historical_root;
+        let roots = self.roots.range(period_start..period_end).map(|(_, root)| *root);
+        let roots = roots.collect::<Vec<_>>();
+        if roots.len() as Slot == context.slots_per_historical_root {
+            Some(roots)
+        } else {
+            None
+        }
+    }
+
+    /// Build a proof of the block root at `slot` against the corresponding historical summary.
+    ///
+    /// Returns `None` if `slot` precedes the `capella` fork, when historical summaries were
+    /// introduced, or if the roots for the whole period containing `slot` are not known.
+    pub fn prove_block_root(&self, slot: Slot, context: &Context) -> Option<BlockRootProof> {
+        let capella_fork_slot = context.capella_fork_epoch.checked_mul(context.slots_per_epoch)?;
+        if slot < capella_fork_slot {
+            return None
+        }
+        let leaves = self.period_roots(slot, context)?;
+        let period = slot / context.slots_per_historical_root;
+        let historical_summary_index =
+            (period - capella_fork_slot / context.slots_per_historical_root) as usize;
+        let index = (slot % context.slots_per_historical_root) as usize;
+        Some(BlockRootProof {
+            slot,
+            block_root: leaves[index],
+            historical_summary_index,
+            branch: compute_merkle_branch(leaves, index),
+        })
+    }
+}
+
+// Compute the branch for the leaf at `index` in the tree over `leaves`, whose length must be
+// a power of two.
+fn compute_merkle_branch(mut layer: Vec<Root>, mut index: usize) -> Vec<Bytes32> {
+    debug_assert!(layer.len().is_power_of_two());
+    let mut branch = vec![];
+    let mut input = [0u8; 64];
+    while layer.len() > 1 {
+        branch.push(Bytes32::try_from(layer[index ^ 1].as_ref()).expect("is valid length"));
+        layer = layer
+            .chunks(2)
+            .map(|pair| {
+                input[..32].copy_from_slice(pair[0].as_ref());
+                input[32..].copy_from_slice(pair[1].as_ref());
+                Root::try_from(hash(input).as_ref()).expect("is valid root")
+            })
+            .collect();
+        index /= 2;
+    }
+    branch
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::mainnet;
+
+    fn root(i: u64) -> Root {
+        let mut root = Root::default();
+        root.as_mut()[..8].copy_from_slice(&i.to_le_bytes());
+        root
+    }
+
+    #[test]
+    fn test_block_roots_cache_beyond_state() {
+        let context = Context::for_mainnet();
+        let mut inner = crate::capella::mainnet::BeaconState::default();
+        let period = context.slots_per_historical_root;
+        let mut cache = BlockRootsCache::default();
+
+        for slot in 0..2 * period {
+            inner.block_roots[(slot % period) as usize] = root(slot);
+            inner.slot = slot + 1;
+            if (slot + 1) % period == 0 {
+                cache.update_from_state(&mainnet::BeaconState::Capella(inner.clone()));
+            }
+        }
+        assert_eq!(cache.len() as u64, 2 * period);
+        assert_eq!(cache.get_block_root_at_slot(3), Some(root(3)));
+
+        cache.prune_before(period);
+        assert_eq!(cache.get_block_root_at_slot(3), None);
+        assert_eq!(cache.get_block_root_at_slot(period + 3), Some(root(period + 3)));
+    }
+
+    #[test]
+    fn test_block_root_proof() {
+        let mut context = Context::for_mainnet();
+        context.capella_fork_epoch = 0;
+        let period = context.slots_per_historical_root;
+        let mut state = crate::capella::mainnet::BeaconState::default();
+        let mut cache = BlockRootsCache::default();
+        for slot in period..2 * period {
+            state.block_roots[(slot % period) as usize] = root(slot);
+            cache.insert(slot, root(slot));
+        }
+        let historical_summary = HistoricalSummary {
+            block_summary_root: state.block_roots.hash_tree_root().unwrap(),
+            state_summary_root: Root::default(),
+        };
+
+        let proof = cache.prove_block_root(period + 5, &context).unwrap();
+        assert_eq!(proof.historical_summary_index, 1);
+        assert_eq!(proof.block_root, root(period + 5));
+        assert!(proof.verify(&historical_summary, &context));
+
+        assert!(cache.prove_block_root(5, &context).is_none());
+    }
+}
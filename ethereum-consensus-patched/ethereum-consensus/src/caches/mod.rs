@@ -0,0 +1,10 @@
+//! This module contains caches over data derived from the beacon state that are
+//! expensive to recompute on demand, and a record of objects already seen on the network.
+
+mod block_roots;
+mod seen;
+mod state;
+
+pub use block_roots::*;
+pub use seen::*;
+pub use state::*;
@@ -0,0 +1,136 @@
+use crate::{
+    phase0::compute_start_slot_at_epoch,
+    primitives::{Epoch, Root, Slot},
+    state_transition::Context,
+};
+use std::collections::{BTreeMap, HashMap};
+
+/// What was recorded about a root the first time it was seen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeenEntry<M> {
+    pub slot: Slot,
+    pub metadata: M,
+}
+
+/// A bounded record of the roots of objects already seen, e.g. over gossip or by an
+/// operation pool, so that later copies can be recognized as duplicates.
+///
+/// Each root is tagged with the slot the object belongs to. Entries are evicted in slot order,
+/// either explicitly as the clock or finality advances or, if `capacity` is exceeded, oldest
+/// first.
+#[derive(Debug, Clone)]
+pub struct SeenCache<M = ()> {
+    capacity: usize,
+    entries: HashMap<Root, SeenEntry<M>>,
+    roots_by_slot: BTreeMap<Slot, Vec<Root>>,
+}
+
+impl<M> SeenCache<M> {
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, entries: HashMap::new(), roots_by_slot: BTreeMap::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn contains(&self, root: &Root) -> bool {
+        self.entries.contains_key(root)
+    }
+
+    pub fn get(&self, root: &Root) -> Option<&SeenEntry<M>> {
+        self.entries.get(root)
+    }
+
+    /// Record that the object with `root` at `slot` was seen.
+    ///
+    /// If `root` was already seen, the cache is unchanged and the entry from the first
+    /// sighting is returned, so the caller can treat the object as a duplicate.
+    pub fn observe(&mut self, root: Root, slot: Slot, metadata: M) -> Option<&SeenEntry<M>> {
+        if self.entries.contains_key(&root) {
+            return self.entries.get(&root)
+        }
+        self.entries.insert(root, SeenEntry { slot, metadata });
+        self.roots_by_slot.entry(slot).or_default().push(root);
+        while self.entries.len() > self.capacity {
+            self.evict_oldest();
+        }
+        None
+    }
+
+    fn evict_oldest(&mut self) {
+        let Some(mut entry) = self.roots_by_slot.first_entry() else { return };
+        let roots = entry.get_mut();
+        if let Some(root) = roots.pop() {
+            self.entries.remove(&root);
+        }
+        if roots.is_empty() {
+            entry.remove();
+        }
+    }
+
+    /// Drop all entries for slots before `slot`, e.g. once they fall outside the window in
+    /// which the corresponding objects are still accepted.
+    pub fn prune_before(&mut self, slot: Slot) {
+        let retained = self.roots_by_slot.split_off(&slot);
+        for root in self.roots_by_slot.values().flatten() {
+            self.entries.remove(root);
+        }
+        self.roots_by_slot = retained;
+    }
+
+    /// Drop all entries for slots before the start of `finalized_epoch`, which can no longer
+    /// be relevant to fork choice.
+    pub fn prune_finalized(&mut self, finalized_epoch: Epoch, context: &Context) {
+        self.prune_before(compute_start_slot_at_epoch(finalized_epoch, context));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root(i: u8) -> Root {
+        Root::try_from([i; 32].as_ref()).unwrap()
+    }
+
+    #[test]
+    fn test_seen_cache_suppresses_duplicates() {
+        let mut cache = SeenCache::new(3);
+        assert!(cache.observe(root(1), 10, "first").is_none());
+        let entry = cache.observe(root(1), 12, "second").unwrap();
+        assert_eq!(entry, &SeenEntry { slot: 10, metadata: "first" });
+
+        cache.observe(root(2), 11, "");
+        cache.observe(root(3), 9, "");
+        cache.observe(root(4), 12, "");
+        assert_eq!(cache.len(), 3);
+        assert!(!cache.contains(&root(3)));
+        assert!(cache.contains(&root(1)));
+    }
+
+    #[test]
+    fn test_seen_cache_pruning() {
+        let context = Context::for_mainnet();
+        let mut cache = SeenCache::<()>::new(64);
+        for i in 0..64 {
+            cache.observe(root(i), i as Slot, ());
+        }
+        cache.prune_before(8);
+        assert_eq!(cache.len(), 56);
+        assert!(cache.get(&root(7)).is_none());
+
+        cache.prune_finalized(1, &context);
+        assert_eq!(cache.len(), 32);
+        assert!(cache.contains(&root(32)));
+        assert!(!cache.contains(&root(31)));
+    }
+}
@@ -0,0 +1,456 @@
+use crate::{
+    altair::{has_flag, PARTICIPATION_FLAG_WEIGHTS},
+    crypto::hash,
+    error::{invalid_operation_error, InvalidAttestation, InvalidOperation},
+    phase0::{
+        compute_committee, compute_epoch_at_slot, is_active_validator, Attestation,
+        IndexedAttestation, Validator,
+    },
+    primitives::{
+        BlsPublicKey, Bytes32, CommitteeIndex, DomainType, Epoch, Gwei, ParticipationFlags, Slot,
+        ValidatorIndex, GENESIS_EPOCH,
+    },
+    ssz::prelude::List,
+    state_transition::{Context, Result},
+    types::BeaconState,
+    Error,
+};
+use std::{collections::HashMap, thread};
+
+const PARTICIPATION_FLAG_COUNT: usize = PARTICIPATION_FLAG_WEIGHTS.len();
+
+/// Data derived from the validator registry that stays fixed for the duration of an epoch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EpochCache {
+    pub epoch: Epoch,
+    pub active_validator_indices: Vec<ValidatorIndex>,
+    pub total_active_balance: Gwei,
+    pub committees_per_slot: usize,
+    pub attester_seed: Bytes32,
+}
+
+/// Running totals of the unslashed participating balance for each participation flag,
+/// indexed by `TIMELY_*_FLAG_INDEX`. Only defined for forks from `altair` onwards.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProgressiveBalances {
+    pub previous_epoch_flag_balances: [Gwei; PARTICIPATION_FLAG_COUNT],
+    pub current_epoch_flag_balances: [Gwei; PARTICIPATION_FLAG_COUNT],
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StateCaches {
+    pub epoch_cache: EpochCache,
+    pub pubkey_index: HashMap<BlsPublicKey, ValidatorIndex>,
+    /// Beacon committees for the current epoch, keyed by `(slot, committee_index)`.
+    pub committees: HashMap<(Slot, CommitteeIndex), Vec<ValidatorIndex>>,
+    pub progressive_balances: Option<ProgressiveBalances>,
+}
+
+impl StateCaches {
+    pub fn validator_index(&self, public_key: &BlsPublicKey) -> Option<ValidatorIndex> {
+        self.pubkey_index.get(public_key).copied()
+    }
+
+    pub fn beacon_committee(&self, slot: Slot, index: CommitteeIndex) -> Option<&[ValidatorIndex]> {
+        self.committees.get(&(slot, index)).map(|committee| committee.as_slice())
+    }
+
+    /// Return the index of the sole validator attesting in an unaggregated `attestation`, as
+    /// received on an attestation subnet, by looking up its position in the cached committee.
+    ///
+    /// Unlike `get_attesting_indices`, no set of attesting indices is built; the attestation is
+    /// rejected unless exactly one bit of its correctly-sized `aggregation_bits` is set.
+    /// `Error::CommitteeNotCached` is returned for attestations outside of the cached epoch.
+    pub fn unaggregated_attesting_index<const MAX_VALIDATORS_PER_COMMITTEE: usize>(
+        &self,
+        attestation: &Attestation<MAX_VALIDATORS_PER_COMMITTEE>,
+    ) -> Result<ValidatorIndex> {
+        let data = &attestation.data;
+        let committees_per_slot = self.epoch_cache.committees_per_slot;
+        if data.index >= committees_per_slot {
+            return Err(invalid_operation_error(InvalidOperation::Attestation(
+                InvalidAttestation::InvalidIndex {
+                    index: data.index,
+                    upper_bound: committees_per_slot,
+                },
+            )))
+        }
+        let committee = self
+            .beacon_committee(data.slot, data.index)
+            .ok_or(Error::CommitteeNotCached { slot: data.slot, index: data.index })?;
+
+        let bits = &attestation.aggregation_bits;
+        if bits.len() != committee.len() {
+            return Err(invalid_operation_error(InvalidOperation::Attestation(
+                InvalidAttestation::Bitfield {
+                    expected_length: committee.len(),
+                    length: bits.len(),
+                },
+            )))
+        }
+        let attesting_count = bits.count_ones();
+        if attesting_count != 1 {
+            return Err(invalid_operation_error(InvalidOperation::Attestation(
+                InvalidAttestation::NotUnaggregated { attesting_count },
+            )))
+        }
+        let position = bits.first_one().expect("exactly one bit is set");
+        Ok(committee[position])
+    }
+
+    /// Convert an unaggregated `attestation` to the equivalent `IndexedAttestation`, as
+    /// `get_indexed_attestation` would, via [`Self::unaggregated_attesting_index`].
+    pub fn unaggregated_indexed_attestation<const MAX_VALIDATORS_PER_COMMITTEE: usize>(
+        &self,
+        attestation: &Attestation<MAX_VALIDATORS_PER_COMMITTEE>,
+    ) -> Result<IndexedAttestation<MAX_VALIDATORS_PER_COMMITTEE>> {
+        let index = self.unaggregated_attesting_index(attestation)?;
+        let attesting_indices = List::try_from(vec![index]).map_err(|(_, err)| err)?;
+        Ok(IndexedAttestation {
+            attesting_indices,
+            data: attestation.data.clone(),
+            signature: attestation.signature.clone(),
+        })
+    }
+}
+
+/// Precompute the caches required for block processing in a single parallel pass over `state`.
+///
+/// This is intended to be called once a state is obtained outside of the regular state
+/// transition (e.g. after checkpoint sync) so that the first block processed on top of it
+/// does not pay for building each cache serially.
+pub fn warm_caches<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    context: &Context,
+) -> Result<StateCaches> {
+    let epoch = compute_epoch_at_slot(state.slot(), context);
+    let validators = state.validators();
+
+    thread::scope(|scope| {
+        let pubkey_index = scope.spawn(|| {
+            validators
+                .iter()
+                .enumerate()
+                .map(|(index, validator)| (validator.public_key.clone(), index))
+                .collect::<HashMap<_, _>>()
+        });
+
+        let progressive_balances = scope.spawn(|| {
+            let previous_participation = state.previous_epoch_participation()?;
+            let current_participation = state.current_epoch_participation()?;
+            let previous_epoch = if epoch == GENESIS_EPOCH { GENESIS_EPOCH } else { epoch - 1 };
+            Some(ProgressiveBalances {
+                previous_epoch_flag_balances: compute_flag_balances(
+                    validators,
+                    previous_participation,
+                    previous_epoch,
+                    context,
+                ),
+                current_epoch_flag_balances: compute_flag_balances(
+                    validators,
+                    current_participation,
+                    epoch,
+                    context,
+                ),
+            })
+        });
+
+        let epoch_cache = compute_epoch_cache(state, epoch, context);
+        let committees = compute_committees(&epoch_cache, context)?;
+
+        Ok(StateCaches {
+            epoch_cache,
+            pubkey_index: pubkey_index.join().expect("can build pubkey index"),
+            committees,
+            progressive_balances: progressive_balances
+                .join()
+                .expect("can compute progressive balances"),
+        })
+    })
+}
+
+fn compute_epoch_cache<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    epoch: Epoch,
+    context: &Context,
+) -> EpochCache {
+    let mut active_validator_indices = vec![];
+    let mut total_active_balance = Gwei::default();
+    for (index, validator) in state.validators().iter().enumerate() {
+        if is_active_validator(validator, epoch) {
+            active_validator_indices.push(index);
+            total_active_balance += validator.effective_balance;
+        }
+    }
+    let total_active_balance = u64::max(total_active_balance, context.effective_balance_increment);
+
+    let committees_per_slot = u64::max(
+        1,
+        u64::min(
+            context.max_committees_per_slot,
+            active_validator_indices.len() as u64 /
+                context.slots_per_epoch /
+                context.target_committee_size,
+        ),
+    ) as usize;
+
+    // NOTE: mirrors `get_seed` which is only defined over the fork-specific state types
+    let mix_epoch = epoch + (context.epochs_per_historical_vector - context.min_seed_lookahead) - 1;
+    let mix = &state.randao_mixes()[mix_epoch as usize % EPOCHS_PER_HISTORICAL_VECTOR];
+    let mut input = [0u8; 44];
+    input[..4].copy_from_slice(&DomainType::BeaconAttester.as_bytes());
+    input[4..12].copy_from_slice(&epoch.to_le_bytes());
+    input[12..].copy_from_slice(mix.as_ref());
+    let attester_seed = hash(input);
+
+    EpochCache {
+        epoch,
+        active_validator_indices,
+        total_active_balance,
+        committees_per_slot,
+        attester_seed,
+    }
+}
+
+fn compute_committees(
+    epoch_cache: &EpochCache,
+    context: &Context,
+) -> Result<HashMap<(Slot, CommitteeIndex), Vec<ValidatorIndex>>> {
+    let start_slot = epoch_cache.epoch * context.slots_per_epoch;
+    let slots = (start_slot..start_slot + context.slots_per_epoch).collect::<Vec<_>>();
+    let workers = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    let slots_per_worker = slots.len() / workers + 1;
+    let count = epoch_cache.committees_per_slot * context.slots_per_epoch as usize;
+
+    thread::scope(|scope| {
+        let handles = slots
+            .chunks(slots_per_worker)
+            .map(|slots| {
+                scope.spawn(move || {
+                    let mut committees = Vec::with_capacity(slots.len());
+                    for &slot in slots {
+                        let slot_offset = (slot % context.slots_per_epoch) as usize;
+                        for index in 0..epoch_cache.committees_per_slot {
+                            let committee = compute_committee(
+                                &epoch_cache.active_validator_indices,
+                                &epoch_cache.attester_seed,
+                                slot_offset * epoch_cache.committees_per_slot + index,
+                                count,
+                                context,
+                            )?;
+                            committees.push(((slot, index), committee));
+                        }
+                    }
+                    Ok(committees)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut committees = HashMap::with_capacity(count);
+        for handle in handles {
+            let result: Result<Vec<_>> = handle.join().expect("can compute committees");
+            committees.extend(result?);
+        }
+        Ok(committees)
+    })
+}
+
+fn compute_flag_balances(
+    validators: &[Validator],
+    participation: &[ParticipationFlags],
+    epoch: Epoch,
+    context: &Context,
+) -> [Gwei; PARTICIPATION_FLAG_COUNT] {
+    let mut balances = [Gwei::default(); PARTICIPATION_FLAG_COUNT];
+    for (validator, &flags) in validators.iter().zip(participation.iter()) {
+        if validator.slashed || !is_active_validator(validator, epoch) {
+            continue
+        }
+        for (flag_index, balance) in balances.iter_mut().enumerate() {
+            if has_flag(flags, flag_index) {
+                *balance += validator.effective_balance;
+            }
+        }
+    }
+    balances.map(|balance| u64::max(balance, context.effective_balance_increment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        altair::{add_flag, TIMELY_HEAD_FLAG_INDEX, TIMELY_TARGET_FLAG_INDEX},
+        primitives::FAR_FUTURE_EPOCH,
+        ssz::prelude::Bitlist,
+        types::minimal,
+    };
+
+    fn validator(seed: u8, context: &Context) -> Validator {
+        let mut public_key = BlsPublicKey::default();
+        public_key[0] = seed;
+        Validator {
+            public_key,
+            effective_balance: context.max_effective_balance,
+            exit_epoch: FAR_FUTURE_EPOCH,
+            withdrawable_epoch: FAR_FUTURE_EPOCH,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_warm_caches() {
+        let context = Context::for_minimal();
+        let validator_count = 64;
+        let mut inner = crate::altair::minimal::BeaconState::default();
+        for i in 0..validator_count {
+            inner.validators.push(validator(i as u8, &context)).unwrap();
+            inner.balances.push(context.max_effective_balance).unwrap();
+            inner.previous_epoch_participation.push(0).unwrap();
+            let flags = add_flag(0, TIMELY_TARGET_FLAG_INDEX);
+            inner.current_epoch_participation.push(if i % 2 == 0 { flags } else { 0 }).unwrap();
+        }
+        inner.slot = 3 * context.slots_per_epoch + 1;
+        let state = minimal::BeaconState::Altair(inner);
+
+        let caches = warm_caches(&state, &context).unwrap();
+
+        assert_eq!(caches.epoch_cache.epoch, 3);
+        assert_eq!(caches.epoch_cache.active_validator_indices.len(), validator_count);
+        assert_eq!(
+            caches.epoch_cache.total_active_balance,
+            validator_count as u64 * context.max_effective_balance
+        );
+        for i in 0..validator_count {
+            let public_key = &state.validators()[i].public_key;
+            assert_eq!(caches.validator_index(public_key), Some(i));
+        }
+
+        let mut members = caches.committees.values().flatten().copied().collect::<Vec<_>>();
+        members.sort_unstable();
+        assert_eq!(members, (0..validator_count).collect::<Vec<_>>());
+        let slot = 3 * context.slots_per_epoch;
+        assert!(caches.beacon_committee(slot, 0).is_some());
+
+        let balances = caches.progressive_balances.unwrap();
+        assert_eq!(
+            balances.current_epoch_flag_balances[TIMELY_TARGET_FLAG_INDEX],
+            (validator_count as u64 / 2) * context.max_effective_balance
+        );
+        assert_eq!(
+            balances.current_epoch_flag_balances[TIMELY_HEAD_FLAG_INDEX],
+            context.effective_balance_increment
+        );
+    }
+
+    #[test]
+    fn test_unaggregated_attesting_index() {
+        let context = Context::for_minimal();
+        let mut inner = crate::phase0::minimal::BeaconState::default();
+        for i in 0..64 {
+            inner.validators.push(validator(i as u8, &context)).unwrap();
+            inner.balances.push(context.max_effective_balance).unwrap();
+        }
+        inner.slot = 2 * context.slots_per_epoch;
+        let state = minimal::BeaconState::Phase0(inner);
+        let caches = warm_caches(&state, &context).unwrap();
+
+        let slot = 2 * context.slots_per_epoch + 3;
+        let committee = caches.beacon_committee(slot, 0).unwrap().to_vec();
+        let mut attestation = crate::phase0::minimal::Attestation::default();
+        attestation.data.slot = slot;
+        let mut bits = vec![false; committee.len()];
+        bits[committee.len() - 1] = true;
+        attestation.aggregation_bits = Bitlist::try_from(bits.as_slice()).unwrap();
+
+        let index = caches.unaggregated_attesting_index(&attestation).unwrap();
+        assert_eq!(index, committee[committee.len() - 1]);
+        let indexed_attestation = caches.unaggregated_indexed_attestation(&attestation).unwrap();
+        assert_eq!(indexed_attestation.attesting_indices.as_ref(), &[index]);
+        assert_eq!(indexed_attestation.data, attestation.data);
+
+        attestation.aggregation_bits.set(0, true);
+        assert!(caches.unaggregated_attesting_index(&attestation).is_err());
+        attestation.aggregation_bits.pop();
+        assert!(caches.unaggregated_attesting_index(&attestation).is_err());
+
+        attestation.data.slot = 3 * context.slots_per_epoch;
+        assert!(matches!(
+            caches.unaggregated_attesting_index(&attestation),
+            Err(Error::CommitteeNotCached { .. })
+        ));
+    }
+
+    #[test]
+    fn test_warm_caches_phase0() {
+        let context = Context::for_minimal();
+        let mut inner = crate::phase0::minimal::BeaconState::default();
+        inner.validators.push(validator(1, &context)).unwrap();
+        let state = minimal::BeaconState::Phase0(inner);
+
+        let caches = warm_caches(&state, &context).unwrap();
+        assert!(caches.progressive_balances.is_none());
+        assert_eq!(caches.committees.len(), context.slots_per_epoch as usize);
+        let members = caches.committees.values().flatten().copied().collect::<Vec<_>>();
+        assert_eq!(members, vec![0]);
+    }
+}
@@ -16,7 +16,7 @@ use crate::{
     },
     primitives::{BLS_WITHDRAWAL_PREFIX, ETH1_ADDRESS_WITHDRAWAL_PREFIX},
     ssz::prelude::*,
-    state_transition::{Context, Result},
+    state_transition::{Context, PayloadStatus, Result},
 };
 
 pub fn process_bls_to_execution_change<
@@ -210,7 +210,7 @@ pub fn process_execution_payload<
     >,
     execution_engine: &E,
     context: &Context,
-) -> Result<()> {
+) -> Result<PayloadStatus> {
     let parent_hash_invalid =
         payload.parent_hash != state.latest_execution_payload_header.block_hash;
     if parent_hash_invalid {
@@ -247,7 +247,8 @@ pub fn process_execution_payload<
     }
 
     let new_payload_request = NewPayloadRequest(payload);
-    execution_engine.verify_and_notify_new_payload(&new_payload_request)?;
+    let status = execution_engine.verify_and_notify_new_payload(&new_payload_request)?;
+    status.ensure_not_invalid()?;
 
     state.latest_execution_payload_header = ExecutionPayloadHeader {
         parent_hash: payload.parent_hash.clone(),
@@ -267,7 +268,7 @@ pub fn process_execution_payload<
         withdrawals_root: payload.withdrawals.hash_tree_root()?,
     };
 
-    Ok(())
+    Ok(status)
 }
 
 pub fn process_withdrawals<
@@ -406,6 +407,71 @@ pub fn get_expected_withdrawals<
     withdrawals
 }
 
+/// Check that the withdrawals in `execution_payload` are those expected from `state` and that
+/// their root is the `withdrawals_root` committed to in `execution_payload_header`, without
+/// applying them to `state`.
+///
+/// This is the check a relay makes of a payload submitted by a builder before offering its
+/// header to a proposer.
+pub fn verify_withdrawals<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    execution_payload: &ExecutionPayload<
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+    >,
+    execution_payload_header: &ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    context: &Context,
+) -> Result<()> {
+    let expected_withdrawals = get_expected_withdrawals(state, context);
+    if execution_payload.withdrawals.as_ref() != expected_withdrawals {
+        return Err(invalid_operation_error(InvalidOperation::Withdrawal(
+            InvalidWithdrawals::IncorrectWithdrawals {
+                provided: execution_payload.withdrawals.to_vec(),
+                expected: expected_withdrawals,
+            },
+        )))
+    }
+
+    let withdrawals_root = execution_payload.withdrawals.hash_tree_root()?;
+    if withdrawals_root != execution_payload_header.withdrawals_root {
+        return Err(invalid_operation_error(InvalidOperation::Withdrawal(
+            InvalidWithdrawals::WithdrawalsRootMismatch {
+                provided: execution_payload_header.withdrawals_root,
+                expected: withdrawals_root,
+            },
+        )))
+    }
+    Ok(())
+}
+
 pub fn process_block<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -463,13 +529,92 @@ pub fn process_block<
     >,
     execution_engine: &E,
     context: &Context,
-) -> Result<()> {
+) -> Result<PayloadStatus> {
     process_block_header(state, block, context)?;
     process_withdrawals(state, &block.body.execution_payload, context)?;
-    process_execution_payload(state, &mut block.body.execution_payload, execution_engine, context)?;
+    let status = process_execution_payload(
+        state,
+        &mut block.body.execution_payload,
+        execution_engine,
+        context,
+    )?;
     process_randao(state, &block.body, context)?;
-    process_eth1_data(state, &block.body, context);
+    process_eth1_data(state, &block.body, context)?;
     process_operations(state, &mut block.body, context)?;
     process_sync_aggregate(state, &block.body.sync_aggregate, context)?;
-    Ok(())
+    Ok(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        capella::{
+            minimal::{BeaconState, ExecutionPayload, ExecutionPayloadHeader},
+            DefaultExecutionEngine,
+        },
+        error::{Error, InvalidBlock},
+        primitives::Root,
+    };
+
+    #[test]
+    fn test_verify_withdrawals() {
+        let context = Context::for_minimal();
+        let state = BeaconState::default();
+        let mut payload = ExecutionPayload::default();
+        let mut header = ExecutionPayloadHeader {
+            withdrawals_root: payload.withdrawals.hash_tree_root().unwrap(),
+            ..Default::default()
+        };
+        assert!(verify_withdrawals(&state, &payload, &header, &context).is_ok());
+
+        header.withdrawals_root = Root::default();
+        let result = verify_withdrawals(&state, &payload, &header, &context);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidBlock(err)) if matches!(
+                *err,
+                InvalidBlock::InvalidOperation(InvalidOperation::Withdrawal(
+                    InvalidWithdrawals::WithdrawalsRootMismatch { .. }
+                ))
+            )
+        ));
+
+        payload.withdrawals.push(Withdrawal::default()).unwrap();
+        header.withdrawals_root = payload.withdrawals.hash_tree_root().unwrap();
+        let result = verify_withdrawals(&state, &payload, &header, &context);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidBlock(err)) if matches!(
+                *err,
+                InvalidBlock::InvalidOperation(InvalidOperation::Withdrawal(
+                    InvalidWithdrawals::IncorrectWithdrawals { .. }
+                ))
+            )
+        ));
+    }
+
+    #[test]
+    fn test_process_execution_payload_returns_status() {
+        let context = Context::for_minimal();
+        let mut state = BeaconState::default();
+        let mut payload = ExecutionPayload { block_number: 1, ..Default::default() };
+
+        let execution_engine = DefaultExecutionEngine::with_payload_status(PayloadStatus::Syncing);
+        let status =
+            process_execution_payload(&mut state, &mut payload, &execution_engine, &context)
+                .unwrap();
+        assert_eq!(status, PayloadStatus::Syncing);
+        assert_eq!(state.latest_execution_payload_header.block_number, 1);
+
+        let mut state = BeaconState::default();
+        let execution_engine =
+            DefaultExecutionEngine::with_payload_status(PayloadStatus::Invalid {
+                latest_valid_hash: None,
+            });
+        let result =
+            process_execution_payload(&mut state, &mut payload, &execution_engine, &context);
+        assert!(result.is_err());
+        assert_eq!(state.latest_execution_payload_header.block_number, 0);
+    }
 }
@@ -42,7 +42,7 @@ pub fn process_historical_summaries_update<
             block_summary_root: state.block_roots.hash_tree_root()?,
             state_summary_root: state.state_roots.hash_tree_root()?,
         };
-        state.historical_summaries.push(historical_summary);
+        state.historical_summaries.push(historical_summary)?;
     }
     Ok(())
 }
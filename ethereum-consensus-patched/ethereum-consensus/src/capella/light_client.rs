@@ -0,0 +1,483 @@
+use crate::{
+    altair::{
+        light_client::{
+            CURRENT_SYNC_COMMITTEE_INDEX, CURRENT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2,
+            FINALIZED_ROOT_INDEX, FINALIZED_ROOT_INDEX_FLOOR_LOG_2, NEXT_SYNC_COMMITTEE_INDEX,
+            NEXT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2,
+        },
+        sync::{SyncAggregate, SyncCommittee},
+    },
+    capella::{
+        beacon_block::BeaconBlock, beacon_state::BeaconState, compute_epoch_at_slot,
+        compute_sync_committee_period, execution_payload::ExecutionPayloadHeader,
+    },
+    error::InvalidLightClientUpdate,
+    phase0::beacon_block::BeaconBlockHeader,
+    primitives::{Bytes32, Root, Slot, GENESIS_SLOT},
+    ssz::prelude::*,
+    state_transition::Context,
+};
+
+// From capella, light client headers also carry the execution payload header, proven
+// against the `body_root` of the beacon block header.
+pub const EXECUTION_PAYLOAD_INDEX: usize = 25;
+pub const EXECUTION_PAYLOAD_INDEX_FLOOR_LOG_2: usize = 4;
+
+#[derive(Default, Debug, Clone, SimpleSerialize, serde::Serialize, serde::Deserialize)]
+pub struct LightClientHeader<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_DATA_BYTES: usize> {
+    pub beacon: BeaconBlockHeader,
+    pub execution: ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub execution_branch: Vector<Bytes32, EXECUTION_PAYLOAD_INDEX_FLOOR_LOG_2>,
+}
+
+#[derive(Default, Debug, Clone, SimpleSerialize, serde::Serialize, serde::Deserialize)]
+pub struct LightClientBootstrap<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub current_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    pub current_sync_committee_branch: Vector<Bytes32, CURRENT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2>,
+}
+
+#[derive(Default, Debug, Clone, SimpleSerialize, serde::Serialize, serde::Deserialize)]
+pub struct LightClientUpdate<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub attested_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub next_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    pub next_sync_committee_branch: Vector<Bytes32, NEXT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2>,
+    pub finalized_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub finality_branch: Vector<Bytes32, FINALIZED_ROOT_INDEX_FLOOR_LOG_2>,
+    pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    pub signature_slot: Slot,
+}
+
+#[derive(Default, Debug, Clone, SimpleSerialize, serde::Serialize, serde::Deserialize)]
+pub struct LightClientFinalityUpdate<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub attested_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub finalized_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub finality_branch: Vector<Bytes32, FINALIZED_ROOT_INDEX_FLOOR_LOG_2>,
+    pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    pub signature_slot: Slot,
+}
+
+#[derive(Default, Debug, Clone, SimpleSerialize, serde::Serialize, serde::Deserialize)]
+pub struct LightClientOptimisticUpdate<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub attested_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    pub signature_slot: Slot,
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > From<&LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+    for LightClientFinalityUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    fn from(
+        update: &LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ) -> Self {
+        Self {
+            attested_header: update.attested_header.clone(),
+            finalized_header: update.finalized_header.clone(),
+            finality_branch: update.finality_branch.clone(),
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+        }
+    }
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > From<&LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+    for LightClientOptimisticUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    fn from(
+        update: &LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ) -> Self {
+        Self {
+            attested_header: update.attested_header.clone(),
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct LightClientStore<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub finalized_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub current_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    pub next_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    pub best_valid_update:
+        Option<LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
+    pub optimistic_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub previous_max_active_participants: u64,
+    pub current_max_active_participants: u64,
+}
+
+// Return the light client header for `block`, proving its execution payload header against the
+// body root.
+pub fn block_to_light_client_header<
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+>(
+    block: &BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+    >,
+) -> crate::state_transition::Result<LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+{
+    let beacon = BeaconBlockHeader {
+        slot: block.slot,
+        proposer_index: block.proposer_index,
+        parent_root: block.parent_root,
+        state_root: block.state_root,
+        body_root: block.body.hash_tree_root()?,
+    };
+    let mut execution_payload = block.body.execution_payload.clone();
+    let execution = ExecutionPayloadHeader::try_from(&mut execution_payload)?;
+    let proof = block.body.prove(&["execution_payload".into()])?;
+    Ok(LightClientHeader {
+        beacon,
+        execution,
+        execution_branch: crate::altair::light_client::as_branch(proof, EXECUTION_PAYLOAD_INDEX)?,
+    })
+}
+
+// Return the bootstrap for `block`, where `state` is the post-state of that block.
+pub fn create_light_client_bootstrap<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    block: &BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+    >,
+) -> crate::state_transition::Result<
+    LightClientBootstrap<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+> {
+    let header = block_to_light_client_header(block)?;
+    let state_root = state.hash_tree_root()?;
+    crate::altair::light_client::verify_post_state(&header.beacon, state.slot, state_root)?;
+    let proof = state.prove(&["current_sync_committee".into()])?;
+    Ok(LightClientBootstrap {
+        header,
+        current_sync_committee: state.current_sync_committee.clone(),
+        current_sync_committee_branch: crate::altair::light_client::as_branch(
+            proof,
+            CURRENT_SYNC_COMMITTEE_INDEX,
+        )?,
+    })
+}
+
+// Return the update for `sync_aggregate`, signed at `signature_slot` over `attested_block`.
+// The `attested_state` is the post-state of that block and `finalized_block` the block of its
+// finalized checkpoint, if known.
+pub fn create_light_client_update<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+>(
+    attested_state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    attested_block: &BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+    >,
+    finalized_block: Option<
+        &BeaconBlock<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            MAX_ATTESTER_SLASHINGS,
+            MAX_ATTESTATIONS,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+        >,
+    >,
+    sync_aggregate: &SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    signature_slot: Slot,
+    context: &Context,
+) -> crate::state_transition::Result<
+    LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+> {
+    let participants = sync_aggregate.sync_committee_bits.count_ones();
+    if participants < context.min_sync_committee_participants {
+        return Err(InvalidLightClientUpdate::InsufficientParticipation {
+            participants,
+            minimum: context.min_sync_committee_participants,
+        }
+        .into())
+    }
+    if signature_slot <= attested_block.slot {
+        return Err(InvalidLightClientUpdate::SignatureSlotNotAfterAttested {
+            signature_slot,
+            attested_slot: attested_block.slot,
+        }
+        .into())
+    }
+    let attested_header = block_to_light_client_header(attested_block)?;
+    let state_root = attested_state.hash_tree_root()?;
+    crate::altair::light_client::verify_post_state(
+        &attested_header.beacon,
+        attested_state.slot,
+        state_root,
+    )?;
+
+    let mut update = LightClientUpdate {
+        attested_header,
+        sync_aggregate: sync_aggregate.clone(),
+        signature_slot,
+        ..Default::default()
+    };
+
+    // the next sync committee is only useful when signed by the current sync committee
+    let attested_epoch = compute_epoch_at_slot(attested_block.slot, context);
+    let signature_epoch = compute_epoch_at_slot(signature_slot, context);
+    let attested_period = compute_sync_committee_period(attested_epoch, context);
+    let signature_period = compute_sync_committee_period(signature_epoch, context);
+    if attested_period == signature_period {
+        let proof = attested_state.prove(&["next_sync_committee".into()])?;
+        update.next_sync_committee = attested_state.next_sync_committee.clone();
+        update.next_sync_committee_branch =
+            crate::altair::light_client::as_branch(proof, NEXT_SYNC_COMMITTEE_INDEX)?;
+    }
+
+    if let Some(finalized_block) = finalized_block {
+        let expected = attested_state.finalized_checkpoint.root;
+        // the genesis block is finalized with an empty root and so has an empty header
+        if finalized_block.slot != GENESIS_SLOT {
+            let finalized_header = block_to_light_client_header(finalized_block)?;
+            let provided = finalized_header.beacon.hash_tree_root()?;
+            if provided != expected {
+                return Err(
+                    InvalidLightClientUpdate::FinalizedRootMismatch { provided, expected }.into()
+                )
+            }
+            update.finalized_header = finalized_header;
+        } else if expected != Root::default() {
+            return Err(InvalidLightClientUpdate::FinalizedRootMismatch {
+                provided: Root::default(),
+                expected,
+            }
+            .into())
+        }
+        let proof = attested_state.prove(&["finalized_checkpoint".into(), "root".into()])?;
+        update.finality_branch =
+            crate::altair::light_client::as_branch(proof, FINALIZED_ROOT_INDEX)?;
+    }
+
+    Ok(update)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capella::minimal::{BeaconBlock, BeaconState, SyncAggregate};
+
+    fn verify_branch(
+        leaf: Node,
+        branch: &[Bytes32],
+        depth: usize,
+        generalized_index: usize,
+        root: Root,
+    ) -> bool {
+        let index = generalized_index % 2usize.pow(depth as u32);
+        is_valid_merkle_branch(leaf, branch, depth, index, root).is_ok()
+    }
+
+    fn verify_execution_branch<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >(
+        header: &LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ) -> bool {
+        verify_branch(
+            header.execution.hash_tree_root().unwrap(),
+            &header.execution_branch,
+            EXECUTION_PAYLOAD_INDEX_FLOOR_LOG_2,
+            EXECUTION_PAYLOAD_INDEX,
+            header.beacon.body_root,
+        )
+    }
+
+    #[test]
+    fn test_light_client_header_carries_execution() {
+        let context = Context::for_minimal();
+        let mut finalized_block = BeaconBlock { slot: 8, ..Default::default() };
+        finalized_block.body.execution_payload.block_number = 3;
+        let mut state = BeaconState { slot: 21, ..Default::default() };
+        state.finalized_checkpoint.root = finalized_block.hash_tree_root().unwrap();
+        let state_root = state.hash_tree_root().unwrap();
+        let mut block = BeaconBlock { slot: state.slot, state_root, ..Default::default() };
+        block.body.execution_payload.block_number = 11;
+        block.body.execution_payload.gas_used = 21_000;
+
+        let bootstrap = create_light_client_bootstrap(&state, &block).unwrap();
+        assert_eq!(
+            bootstrap.header.beacon.hash_tree_root().unwrap(),
+            block.hash_tree_root().unwrap()
+        );
+        assert_eq!(bootstrap.header.execution.block_number, 11);
+        assert_eq!(bootstrap.header.execution.gas_used, 21_000);
+        assert!(verify_execution_branch(&bootstrap.header));
+        assert!(verify_branch(
+            bootstrap.current_sync_committee.hash_tree_root().unwrap(),
+            &bootstrap.current_sync_committee_branch,
+            CURRENT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2,
+            CURRENT_SYNC_COMMITTEE_INDEX,
+            state_root,
+        ));
+
+        let mut sync_aggregate = SyncAggregate::default();
+        sync_aggregate.sync_committee_bits.set(0, true);
+        let update = create_light_client_update(
+            &state,
+            &block,
+            Some(&finalized_block),
+            &sync_aggregate,
+            block.slot + 1,
+            &context,
+        )
+        .unwrap();
+        assert!(verify_execution_branch(&update.attested_header));
+        assert_eq!(update.finalized_header.execution.block_number, 3);
+        assert!(verify_execution_branch(&update.finalized_header));
+        assert!(verify_branch(
+            state.finalized_checkpoint.root,
+            &update.finality_branch,
+            FINALIZED_ROOT_INDEX_FLOOR_LOG_2,
+            FINALIZED_ROOT_INDEX,
+            state_root,
+        ));
+
+        // tampering with the execution header breaks its branch
+        let mut header = update.attested_header.clone();
+        header.execution.block_number += 1;
+        assert!(!verify_execution_branch(&header));
+    }
+}
@@ -4,18 +4,24 @@
 pub mod beacon_block;
 pub mod beacon_state;
 pub mod blinded_beacon_block;
+#[cfg(feature = "transition")]
 pub mod block_processing;
 pub mod bls_to_execution_change;
+#[cfg(feature = "transition")]
 pub mod epoch_processing;
 pub mod execution_engine;
 pub mod execution_payload;
+#[cfg(feature = "transition")]
 pub mod fork;
+#[cfg(feature = "transition")]
 pub mod genesis;
 pub mod helpers;
+#[cfg(feature = "light-client")]
+pub mod light_client;
 pub mod presets;
 pub mod spec;
 pub mod withdrawal;
 
 pub use spec::*;
 
-pub use presets::{mainnet, minimal, Preset};
+pub use presets::{gnosis, mainnet, minimal, Preset};
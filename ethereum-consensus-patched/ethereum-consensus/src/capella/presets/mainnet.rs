@@ -1,17 +1,21 @@
-use crate::capella::spec;
+#[cfg(feature = "validator")]
+pub use crate::bellatrix::presets::mainnet::{
+    AggregateAndProof, ContributionAndProof, SignedAggregateAndProof, SignedContributionAndProof,
+    SyncCommitteeContribution,
+};
 pub use crate::{
     bellatrix::presets::mainnet::{
-        AggregateAndProof, Attestation, AttesterSlashing, ContributionAndProof, HistoricalBatch,
-        IndexedAttestation, LightClientUpdate, PendingAttestation, SignedAggregateAndProof,
-        SignedContributionAndProof, SyncAggregate, SyncCommittee, SyncCommitteeContribution,
-        BYTES_PER_LOGS_BLOOM, EPOCHS_PER_HISTORICAL_VECTOR, EPOCHS_PER_SLASHINGS_VECTOR,
-        ETH1_DATA_VOTES_BOUND, HISTORICAL_ROOTS_LIMIT, MAX_ATTESTATIONS, MAX_ATTESTER_SLASHINGS,
-        MAX_BYTES_PER_TRANSACTION, MAX_DEPOSITS, MAX_EXTRA_DATA_BYTES, MAX_PROPOSER_SLASHINGS,
-        MAX_TRANSACTIONS_PER_PAYLOAD, MAX_VALIDATORS_PER_COMMITTEE, MAX_VOLUNTARY_EXITS,
-        SLOTS_PER_HISTORICAL_ROOT, SYNC_COMMITTEE_SIZE, VALIDATOR_REGISTRY_LIMIT,
+        Attestation, AttesterSlashing, HistoricalBatch, IndexedAttestation, PendingAttestation,
+        SyncAggregate, SyncCommittee, BYTES_PER_LOGS_BLOOM, EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR, ETH1_DATA_VOTES_BOUND, HISTORICAL_ROOTS_LIMIT,
+        MAX_ATTESTATIONS, MAX_ATTESTER_SLASHINGS, MAX_BYTES_PER_TRANSACTION, MAX_DEPOSITS,
+        MAX_EXTRA_DATA_BYTES, MAX_PROPOSER_SLASHINGS, MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_VALIDATORS_PER_COMMITTEE, MAX_VOLUNTARY_EXITS, SLOTS_PER_HISTORICAL_ROOT,
+        SYNC_COMMITTEE_SIZE, VALIDATOR_REGISTRY_LIMIT,
     },
     capella::presets::Preset,
 };
+use crate::{capella::spec, parameters::SpecParameters};
 
 pub use spec::*;
 
@@ -25,6 +29,16 @@ pub const PRESET: Preset = Preset {
     max_validators_per_withdrawals_sweep: MAX_VALIDATORS_PER_WITHDRAWALS_SWEEP,
 };
 
+pub const SPEC_PARAMETERS: SpecParameters = SpecParameters {
+    fork: crate::Fork::Capella,
+    phase0: crate::phase0::presets::mainnet::PRESET,
+    altair: Some(crate::altair::presets::mainnet::PRESET),
+    bellatrix: Some(crate::bellatrix::presets::mainnet::PRESET),
+    capella: Some(crate::capella::presets::mainnet::PRESET),
+    deneb: None,
+    electra: None,
+};
+
 pub type ExecutionPayload = spec::ExecutionPayload<
     BYTES_PER_LOGS_BLOOM,
     MAX_EXTRA_DATA_BYTES,
@@ -36,6 +50,27 @@ pub type ExecutionPayload = spec::ExecutionPayload<
 pub type ExecutionPayloadHeader =
     spec::ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
 
+#[cfg(feature = "light-client")]
+pub type LightClientHeader = spec::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+#[cfg(feature = "light-client")]
+pub type LightClientUpdate =
+    spec::LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+#[cfg(feature = "light-client")]
+pub type LightClientBootstrap =
+    spec::LightClientBootstrap<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+#[cfg(feature = "light-client")]
+pub type LightClientFinalityUpdate = spec::LightClientFinalityUpdate<
+    SYNC_COMMITTEE_SIZE,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+>;
+#[cfg(feature = "light-client")]
+pub type LightClientOptimisticUpdate = spec::LightClientOptimisticUpdate<
+    SYNC_COMMITTEE_SIZE,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+>;
+
 pub type BlindedBeaconBlockBody = spec::BlindedBeaconBlockBody<
     MAX_PROPOSER_SLASHINGS,
     MAX_VALIDATORS_PER_COMMITTEE,
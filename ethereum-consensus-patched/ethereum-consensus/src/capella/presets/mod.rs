@@ -1,6 +1,9 @@
+pub mod gnosis;
 pub mod mainnet;
 pub mod minimal;
 
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
 pub struct Preset {
     pub max_bls_to_execution_changes: usize,
     pub max_withdrawals_per_payload: usize,
@@ -0,0 +1,261 @@
+//! A bundle of the chain metadata a downstream verifier needs to check signatures and
+//! state against a given network, serializable to a single artifact.
+use crate::{
+    phase0::compute_epoch_at_slot,
+    primitives::{Epoch, ExecutionAddress, Root, Version, GENESIS_EPOCH},
+    ssz::prelude::*,
+    state_transition::Context,
+    types::BeaconState,
+    Error,
+};
+use thiserror::Error;
+
+/// Upper bound on the number of forks in a `ChainInfo` fork schedule.
+pub const MAX_FORK_SCHEDULE_LENGTH: usize = 16;
+
+/// The fork `version` which activates at `epoch`.
+#[derive(
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct ScheduledFork {
+    #[serde(with = "crate::serde::as_hex")]
+    pub version: Version,
+    #[serde(with = "crate::serde::as_str")]
+    pub epoch: Epoch,
+}
+
+#[derive(
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct ChainInfo {
+    pub genesis_validators_root: Root,
+    #[serde(with = "crate::serde::as_str")]
+    pub genesis_time: u64,
+    /// Every fork of the chain in activation order; forks not yet scheduled have an
+    /// activation epoch of `FAR_FUTURE_EPOCH`.
+    pub fork_schedule: List<ScheduledFork, MAX_FORK_SCHEDULE_LENGTH>,
+    #[serde(with = "crate::serde::as_str")]
+    pub deposit_chain_id: u64,
+    pub deposit_contract_address: ExecutionAddress,
+}
+
+#[derive(Debug, Error)]
+pub enum ChainInfoMismatch {
+    #[error("expected genesis validators root {expected:?} but state has {provided:?}")]
+    GenesisValidatorsRoot { expected: Root, provided: Root },
+    #[error("expected genesis time {expected} but state has {provided}")]
+    GenesisTime { expected: u64, provided: u64 },
+    #[error("expected fork {expected:?} at epoch {epoch} but state has {provided:?}")]
+    Fork { epoch: Epoch, expected: crate::phase0::Fork, provided: crate::phase0::Fork },
+}
+
+impl ChainInfo {
+    pub fn new(genesis_validators_root: Root, genesis_time: u64, context: &Context) -> Self {
+        let fork_schedule = [
+            (context.genesis_fork_version, GENESIS_EPOCH),
+            (context.altair_fork_version, context.altair_fork_epoch),
+            (context.bellatrix_fork_version, context.bellatrix_fork_epoch),
+            (context.capella_fork_version, context.capella_fork_epoch),
+            (context.deneb_fork_version, context.deneb_fork_epoch),
+        ]
+        .into_iter()
+        .map(|(version, epoch)| ScheduledFork { version, epoch })
+        .collect::<Vec<_>>();
+        Self {
+            genesis_validators_root,
+            genesis_time,
+            fork_schedule: fork_schedule.try_into().expect("is within bounds"),
+            deposit_chain_id: context.deposit_chain_id as u64,
+            deposit_contract_address: context.deposit_contract_address.clone(),
+        }
+    }
+
+    /// Collect the chain metadata of the network `state` belongs to.
+    pub fn from_state<
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const PENDING_ATTESTATIONS_BOUND: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    >(
+        state: &BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            PENDING_ATTESTATIONS_BOUND,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
+        >,
+        context: &Context,
+    ) -> Self {
+        Self::new(state.genesis_validators_root(), state.genesis_time(), context)
+    }
+
+    /// Return the fork in effect at `epoch` according to the fork schedule, as it would
+    /// appear in a state at that epoch.
+    pub fn fork_at(&self, epoch: Epoch) -> Option<crate::phase0::Fork> {
+        let index = self.fork_schedule.iter().rposition(|fork| fork.epoch <= epoch)?;
+        let current = &self.fork_schedule[index];
+        let previous = index.checked_sub(1).map(|i| &self.fork_schedule[i]).unwrap_or(current);
+        Some(crate::phase0::Fork {
+            previous_version: previous.version,
+            current_version: current.version,
+            epoch: current.epoch,
+        })
+    }
+
+    /// Check that `state` belongs to the chain described by `self`.
+    pub fn verify_state<
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const PENDING_ATTESTATIONS_BOUND: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    >(
+        &self,
+        state: &BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            PENDING_ATTESTATIONS_BOUND,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
+        >,
+        context: &Context,
+    ) -> Result<(), Error> {
+        let genesis_validators_root = state.genesis_validators_root();
+        if genesis_validators_root != self.genesis_validators_root {
+            return Err(ChainInfoMismatch::GenesisValidatorsRoot {
+                expected: self.genesis_validators_root,
+                provided: genesis_validators_root,
+            }
+            .into())
+        }
+        if state.genesis_time() != self.genesis_time {
+            return Err(ChainInfoMismatch::GenesisTime {
+                expected: self.genesis_time,
+                provided: state.genesis_time(),
+            }
+            .into())
+        }
+        let epoch = compute_epoch_at_slot(state.slot(), context);
+        let expected = self.fork_at(epoch).unwrap_or_default();
+        if *state.fork() != expected {
+            return Err(
+                ChainInfoMismatch::Fork { epoch, expected, provided: state.fork().clone() }.into()
+            )
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{primitives::FAR_FUTURE_EPOCH, types::mainnet};
+
+    #[test]
+    fn test_chain_info_verification() {
+        let context = Context::for_mainnet();
+        let mut inner = crate::capella::mainnet::BeaconState {
+            genesis_time: 1606824023,
+            genesis_validators_root: Root::try_from([7u8; 32].as_ref()).unwrap(),
+            slot: context.capella_fork_epoch * context.slots_per_epoch + 1,
+            fork: crate::phase0::Fork {
+                previous_version: context.bellatrix_fork_version,
+                current_version: context.capella_fork_version,
+                epoch: context.capella_fork_epoch,
+            },
+            ..Default::default()
+        };
+        let state = mainnet::BeaconState::Capella(inner.clone());
+        let chain_info = ChainInfo::from_state(&state, &context);
+        assert_eq!(chain_info.fork_schedule.len(), 5);
+        assert_eq!(chain_info.deposit_chain_id, 1);
+        chain_info.verify_state(&state, &context).unwrap();
+
+        inner.genesis_time += 1;
+        let state = mainnet::BeaconState::Capella(inner.clone());
+        assert!(chain_info.verify_state(&state, &context).is_err());
+
+        inner.genesis_time -= 1;
+        inner.fork.previous_version = context.altair_fork_version;
+        let state = mainnet::BeaconState::Capella(inner);
+        assert!(chain_info.verify_state(&state, &context).is_err());
+    }
+
+    #[test]
+    fn test_chain_info_serialization() {
+        let mut context = Context::for_minimal();
+        context.altair_fork_epoch = 0;
+        context.bellatrix_fork_epoch = 0;
+        context.capella_fork_epoch = 10;
+        context.deneb_fork_epoch = FAR_FUTURE_EPOCH;
+        let chain_info = ChainInfo::new(Root::default(), 42, &context);
+        let fork = chain_info.fork_at(0).unwrap();
+        assert_eq!(fork.previous_version, context.altair_fork_version);
+        assert_eq!(fork.current_version, context.bellatrix_fork_version);
+        let fork = chain_info.fork_at(20).unwrap();
+        assert_eq!(fork.current_version, context.capella_fork_version);
+        assert_eq!(fork.epoch, 10);
+
+        let encoding = serialize(&chain_info).unwrap();
+        assert_eq!(ChainInfo::deserialize(&encoding).unwrap(), chain_info);
+        let json = serde_json::to_string(&chain_info).unwrap();
+        assert_eq!(serde_json::from_str::<ChainInfo>(&json).unwrap(), chain_info);
+    }
+}
@@ -13,6 +13,7 @@ pub const MAINNET_GENESIS_TIME: u64 = 1606824023;
 pub const SEPOLIA_GENESIS_TIME: u64 = 1655733600;
 pub const GOERLI_GENESIS_TIME: u64 = 1616508000;
 pub const HOLESKY_GENESIS_TIME: u64 = 1694786400;
+pub const GNOSIS_GENESIS_TIME: u64 = 1638993340;
 
 fn slot_to_nanos(slot: Slot, seconds_per_slot: u128, genesis_time: u128) -> u128 {
     u128::from(slot) * seconds_per_slot + genesis_time
@@ -118,6 +119,13 @@ pub fn for_holesky() -> Clock<SystemTimeProvider> {
     from_system_time(genesis_time, seconds_per_slot, slots_per_epoch)
 }
 
+pub fn for_gnosis() -> Clock<SystemTimeProvider> {
+    let genesis_time = GNOSIS_GENESIS_TIME;
+    let seconds_per_slot = configs::gnosis::SECONDS_PER_SLOT;
+    let slots_per_epoch = presets::gnosis::SLOTS_PER_EPOCH;
+    from_system_time(genesis_time, seconds_per_slot, slots_per_epoch)
+}
+
 impl<T: TimeProvider + Send + Sync> Clock<T> {
     pub fn new(
         genesis_time: u64,
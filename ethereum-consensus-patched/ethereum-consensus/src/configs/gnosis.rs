@@ -0,0 +1,87 @@
+//! WARNING: Derived by `spec-gen` from `spec-gen/presets/gnosis.yaml`. DO NOT EDIT MANUALLY.
+use crate::{
+    configs::Config,
+    networks::Network,
+    primitives::{Epoch, ExecutionAddress, Gwei, Version, FAR_FUTURE_EPOCH, U256},
+};
+pub const MIN_GENESIS_ACTIVE_VALIDATOR_COUNT: usize = 4096;
+pub const MIN_GENESIS_TIME: u64 = 1638968400;
+pub const GENESIS_FORK_VERSION: Version = [0, 0, 0, 100];
+pub const GENESIS_DELAY: u64 = 6000;
+pub const SECONDS_PER_SLOT: u64 = 5;
+pub const SECONDS_PER_ETH1_BLOCK: u64 = 6;
+pub const MIN_VALIDATOR_WITHDRAWABILITY_DELAY: Epoch = 256;
+pub const SHARD_COMMITTEE_PERIOD: Epoch = 256;
+pub const ETH1_FOLLOW_DISTANCE: u64 = 1024;
+pub const EJECTION_BALANCE: Gwei = 16 * 10u64.pow(9);
+pub const MIN_PER_EPOCH_CHURN_LIMIT: u64 = 4;
+pub const MAX_PER_EPOCH_ACTIVATION_CHURN_LIMIT: u64 = 2;
+pub const CHURN_LIMIT_QUOTIENT: u64 = 4096;
+pub const MIN_PER_EPOCH_CHURN_LIMIT_ELECTRA: Gwei = 64 * 10u64.pow(9);
+pub const MAX_PER_EPOCH_ACTIVATION_EXIT_CHURN_LIMIT: Gwei = 64 * 10u64.pow(9);
+pub const TERMINAL_BLOCK_HASH_ACTIVATION_EPOCH: Epoch = FAR_FUTURE_EPOCH;
+pub const ALTAIR_FORK_VERSION: Version = [1, 0, 0, 100];
+pub const ALTAIR_FORK_EPOCH: Epoch = 512;
+pub const BELLATRIX_FORK_VERSION: Version = [2, 0, 0, 100];
+pub const BELLATRIX_FORK_EPOCH: Epoch = 385536;
+pub const CAPELLA_FORK_VERSION: Version = [3, 0, 0, 100];
+pub const CAPELLA_FORK_EPOCH: Epoch = 648704;
+pub const DENEB_FORK_VERSION: Version = [4, 0, 0, 100];
+pub const DENEB_FORK_EPOCH: Epoch = 889856;
+pub const ELECTRA_FORK_VERSION: Version = [5, 0, 0, 100];
+pub const ELECTRA_FORK_EPOCH: Epoch = FAR_FUTURE_EPOCH;
+pub const INACTIVITY_SCORE_BIAS: u64 = 4;
+pub const INACTIVITY_SCORE_RECOVERY_RATE: u64 = 16;
+pub const PROPOSER_SCORE_BOOST: u64 = 40;
+pub const MAX_BLOBS_PER_BLOCK_ELECTRA: usize = 2;
+pub const DEPOSIT_CHAIN_ID: usize = 100;
+pub const DEPOSIT_NETWORK_ID: usize = 100;
+pub fn config() -> Config {
+    let terminal_total_difficulty =
+        U256::from_str_radix("8626000000000000000000058750000000000000000000", 10).unwrap();
+    let terminal_block_hash = Default::default();
+    let deposit_contract_address = ExecutionAddress::try_from(
+        [11, 152, 5, 126, 163, 16, 244, 211, 31, 42, 69, 43, 65, 70, 71, 0, 125, 22, 69, 217]
+            .as_ref(),
+    )
+    .unwrap();
+    Config {
+        preset_base: "gnosis".to_string(),
+        name: Network::Gnosis,
+        terminal_total_difficulty,
+        terminal_block_hash,
+        terminal_block_hash_activation_epoch: TERMINAL_BLOCK_HASH_ACTIVATION_EPOCH,
+        min_genesis_active_validator_count: MIN_GENESIS_ACTIVE_VALIDATOR_COUNT,
+        min_genesis_time: MIN_GENESIS_TIME,
+        genesis_fork_version: GENESIS_FORK_VERSION,
+        genesis_delay: GENESIS_DELAY,
+        altair_fork_version: ALTAIR_FORK_VERSION,
+        altair_fork_epoch: ALTAIR_FORK_EPOCH,
+        bellatrix_fork_version: BELLATRIX_FORK_VERSION,
+        bellatrix_fork_epoch: BELLATRIX_FORK_EPOCH,
+        capella_fork_version: CAPELLA_FORK_VERSION,
+        capella_fork_epoch: CAPELLA_FORK_EPOCH,
+        deneb_fork_version: DENEB_FORK_VERSION,
+        deneb_fork_epoch: DENEB_FORK_EPOCH,
+        electra_fork_version: ELECTRA_FORK_VERSION,
+        electra_fork_epoch: ELECTRA_FORK_EPOCH,
+        seconds_per_slot: SECONDS_PER_SLOT,
+        seconds_per_eth1_block: SECONDS_PER_ETH1_BLOCK,
+        min_validator_withdrawability_delay: MIN_VALIDATOR_WITHDRAWABILITY_DELAY,
+        shard_committee_period: SHARD_COMMITTEE_PERIOD,
+        eth1_follow_distance: ETH1_FOLLOW_DISTANCE,
+        inactivity_score_bias: INACTIVITY_SCORE_BIAS,
+        inactivity_score_recovery_rate: INACTIVITY_SCORE_RECOVERY_RATE,
+        ejection_balance: EJECTION_BALANCE,
+        min_per_epoch_churn_limit: MIN_PER_EPOCH_CHURN_LIMIT,
+        max_per_epoch_activation_churn_limit: MAX_PER_EPOCH_ACTIVATION_CHURN_LIMIT,
+        churn_limit_quotient: CHURN_LIMIT_QUOTIENT,
+        min_per_epoch_churn_limit_electra: MIN_PER_EPOCH_CHURN_LIMIT_ELECTRA,
+        max_per_epoch_activation_exit_churn_limit: MAX_PER_EPOCH_ACTIVATION_EXIT_CHURN_LIMIT,
+        proposer_score_boost: PROPOSER_SCORE_BOOST,
+        max_blobs_per_block_electra: MAX_BLOBS_PER_BLOCK_ELECTRA,
+        deposit_chain_id: DEPOSIT_CHAIN_ID,
+        deposit_network_id: DEPOSIT_NETWORK_ID,
+        deposit_contract_address,
+    }
+}
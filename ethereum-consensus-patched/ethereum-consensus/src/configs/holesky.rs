@@ -18,6 +18,8 @@ pub const EJECTION_BALANCE: Gwei = 28 * 10u64.pow(9);
 pub const MIN_PER_EPOCH_CHURN_LIMIT: u64 = 4;
 pub const MAX_PER_EPOCH_ACTIVATION_CHURN_LIMIT: u64 = 8;
 pub const CHURN_LIMIT_QUOTIENT: u64 = 65536;
+pub const MIN_PER_EPOCH_CHURN_LIMIT_ELECTRA: Gwei = 128 * 10u64.pow(9);
+pub const MAX_PER_EPOCH_ACTIVATION_EXIT_CHURN_LIMIT: Gwei = 256 * 10u64.pow(9);
 pub const TERMINAL_BLOCK_HASH_ACTIVATION_EPOCH: Epoch = FAR_FUTURE_EPOCH;
 pub const ALTAIR_FORK_VERSION: Version = [2, 1, 112, 0];
 pub const ALTAIR_FORK_EPOCH: Epoch = 0;
@@ -27,9 +29,12 @@ pub const CAPELLA_FORK_VERSION: Version = [4, 1, 112, 0];
 pub const CAPELLA_FORK_EPOCH: Epoch = 256;
 pub const DENEB_FORK_VERSION: Version = [64, 1, 112, 0];
 pub const DENEB_FORK_EPOCH: Epoch = FAR_FUTURE_EPOCH;
+pub const ELECTRA_FORK_VERSION: Version = [6, 1, 112, 0];
+pub const ELECTRA_FORK_EPOCH: Epoch = FAR_FUTURE_EPOCH;
 pub const INACTIVITY_SCORE_BIAS: u64 = 4;
 pub const INACTIVITY_SCORE_RECOVERY_RATE: u64 = 16;
 pub const PROPOSER_SCORE_BOOST: u64 = 40;
+pub const MAX_BLOBS_PER_BLOCK_ELECTRA: usize = 9;
 pub const DEPOSIT_CHAIN_ID: usize = 17000;
 pub const DEPOSIT_NETWORK_ID: usize = 17000;
 
@@ -63,6 +68,8 @@ pub fn config() -> Config {
         capella_fork_epoch: CAPELLA_FORK_EPOCH,
         deneb_fork_version: DENEB_FORK_VERSION,
         deneb_fork_epoch: DENEB_FORK_EPOCH,
+        electra_fork_version: ELECTRA_FORK_VERSION,
+        electra_fork_epoch: ELECTRA_FORK_EPOCH,
         seconds_per_slot: SECONDS_PER_SLOT,
         seconds_per_eth1_block: SECONDS_PER_ETH1_BLOCK,
         min_validator_withdrawability_delay: MIN_VALIDATOR_WITHDRAWABILITY_DELAY,
@@ -74,7 +81,10 @@ pub fn config() -> Config {
         min_per_epoch_churn_limit: MIN_PER_EPOCH_CHURN_LIMIT,
         max_per_epoch_activation_churn_limit: MAX_PER_EPOCH_ACTIVATION_CHURN_LIMIT,
         churn_limit_quotient: CHURN_LIMIT_QUOTIENT,
+        min_per_epoch_churn_limit_electra: MIN_PER_EPOCH_CHURN_LIMIT_ELECTRA,
+        max_per_epoch_activation_exit_churn_limit: MAX_PER_EPOCH_ACTIVATION_EXIT_CHURN_LIMIT,
         proposer_score_boost: PROPOSER_SCORE_BOOST,
+        max_blobs_per_block_electra: MAX_BLOBS_PER_BLOCK_ELECTRA,
         deposit_chain_id: DEPOSIT_CHAIN_ID,
         deposit_network_id: DEPOSIT_NETWORK_ID,
         deposit_contract_address,
@@ -1,3 +1,4 @@
+pub mod gnosis;
 pub mod goerli;
 pub mod holesky;
 pub mod mainnet;
@@ -9,7 +10,7 @@ use crate::{
     primitives::{Epoch, ExecutionAddress, Gwei, Hash32, Version, U256},
 };
 
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub struct Config {
     pub preset_base: String,
@@ -38,6 +39,11 @@ pub struct Config {
     #[serde(with = "crate::serde::as_hex")]
     pub deneb_fork_version: Version,
     pub deneb_fork_epoch: Epoch,
+    // configs for networks that never scheduled electra omit its values
+    #[serde(with = "crate::serde::as_hex", default = "defaults::electra_fork_version")]
+    pub electra_fork_version: Version,
+    #[serde(default = "defaults::electra_fork_epoch")]
+    pub electra_fork_epoch: Epoch,
 
     pub seconds_per_slot: u64,
     pub seconds_per_eth1_block: u64,
@@ -51,10 +57,42 @@ pub struct Config {
     pub min_per_epoch_churn_limit: u64,
     pub max_per_epoch_activation_churn_limit: u64,
     pub churn_limit_quotient: u64,
+    #[serde(default = "defaults::min_per_epoch_churn_limit_electra")]
+    pub min_per_epoch_churn_limit_electra: Gwei,
+    #[serde(default = "defaults::max_per_epoch_activation_exit_churn_limit")]
+    pub max_per_epoch_activation_exit_churn_limit: Gwei,
 
     pub proposer_score_boost: u64,
 
+    #[serde(default = "defaults::max_blobs_per_block_electra")]
+    pub max_blobs_per_block_electra: usize,
+
     pub deposit_chain_id: usize,
     pub deposit_network_id: usize,
     pub deposit_contract_address: ExecutionAddress,
 }
+
+mod defaults {
+    use super::mainnet;
+    use crate::primitives::{Epoch, Gwei, Version};
+
+    pub(super) fn electra_fork_version() -> Version {
+        mainnet::ELECTRA_FORK_VERSION
+    }
+
+    pub(super) fn electra_fork_epoch() -> Epoch {
+        mainnet::ELECTRA_FORK_EPOCH
+    }
+
+    pub(super) fn min_per_epoch_churn_limit_electra() -> Gwei {
+        mainnet::MIN_PER_EPOCH_CHURN_LIMIT_ELECTRA
+    }
+
+    pub(super) fn max_per_epoch_activation_exit_churn_limit() -> Gwei {
+        mainnet::MAX_PER_EPOCH_ACTIVATION_EXIT_CHURN_LIMIT
+    }
+
+    pub(super) fn max_blobs_per_block_electra() -> usize {
+        mainnet::MAX_BLOBS_PER_BLOCK_ELECTRA
+    }
+}
@@ -225,7 +225,16 @@ impl SecretKey {
 }
 
 #[derive(
-    Clone, Default, Hash, PartialEq, Eq, SimpleSerialize, serde::Serialize, serde::Deserialize,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    SimpleSerialize,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct PublicKey(ByteVector<BLS_PUBLIC_KEY_BYTES_LEN>);
 
@@ -276,7 +285,16 @@ impl TryFrom<&PublicKey> for bls_impl::PublicKey {
 }
 
 #[derive(
-    Clone, Default, Hash, PartialEq, Eq, SimpleSerialize, serde::Serialize, serde::Deserialize,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    SimpleSerialize,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct Signature(ByteVector<BLS_SIGNATURE_BYTES_LEN>);
 
@@ -334,6 +352,12 @@ const INFINITY_COMPRESSED_SIGNATURE: [u8; BLS_SIGNATURE_BYTES_LEN] = [
 ];
 
 impl Signature {
+    /// The signature at the point at infinity, used as a placeholder where no signature is
+    /// available, e.g. for deposits queued when upgrading a state.
+    pub fn infinity() -> Self {
+        Self(ByteVector::try_from(INFINITY_COMPRESSED_SIGNATURE.as_ref()).expect("correct length"))
+    }
+
     pub fn is_infinity(&self) -> bool {
         self.as_ref() == INFINITY_COMPRESSED_SIGNATURE
     }
@@ -8,7 +8,17 @@ use crate::{
 };
 
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct BeaconState<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
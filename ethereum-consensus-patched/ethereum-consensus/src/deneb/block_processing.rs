@@ -19,7 +19,7 @@ use crate::{
     primitives::FAR_FUTURE_EPOCH,
     signing::verify_signed_data,
     ssz::prelude::*,
-    state_transition::{Context, Result},
+    state_transition::{Context, PayloadStatus, Result},
 };
 
 pub fn process_attestation<
@@ -193,7 +193,7 @@ pub fn process_execution_payload<
     >,
     execution_engine: &E,
     context: &Context,
-) -> Result<()> {
+) -> Result<PayloadStatus> {
     let payload = &mut body.execution_payload;
 
     let parent_hash_invalid =
@@ -249,7 +249,8 @@ pub fn process_execution_payload<
         versioned_hashes: &versioned_hashes,
         parent_beacon_block_root: state.latest_block_header.parent_root,
     };
-    execution_engine.verify_and_notify_new_payload(&new_payload_request)?;
+    let status = execution_engine.verify_and_notify_new_payload(&new_payload_request)?;
+    status.ensure_not_invalid()?;
 
     state.latest_execution_payload_header = ExecutionPayloadHeader {
         parent_hash: payload.parent_hash.clone(),
@@ -271,7 +272,7 @@ pub fn process_execution_payload<
         excess_blob_gas: payload.excess_blob_gas,
     };
 
-    Ok(())
+    Ok(status)
 }
 
 pub fn process_voluntary_exit<
@@ -412,13 +413,13 @@ pub fn process_block<
     >,
     execution_engine: &E,
     context: &Context,
-) -> Result<()> {
+) -> Result<PayloadStatus> {
     process_block_header(state, block, context)?;
     process_withdrawals(state, &block.body.execution_payload, context)?;
-    process_execution_payload(state, &mut block.body, execution_engine, context)?;
+    let status = process_execution_payload(state, &mut block.body, execution_engine, context)?;
     process_randao(state, &block.body, context)?;
-    process_eth1_data(state, &block.body, context);
+    process_eth1_data(state, &block.body, context)?;
     process_operations(state, &mut block.body, context)?;
     process_sync_aggregate(state, &block.body.sync_aggregate, context)?;
-    Ok(())
+    Ok(status)
 }
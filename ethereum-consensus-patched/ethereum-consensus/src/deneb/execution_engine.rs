@@ -1,8 +1,7 @@
 use crate::{
     deneb::{execution_payload::ExecutionPayload, polynomial_commitments::VersionedHash},
-    error::ExecutionEngineError,
     primitives::Root,
-    state_transition::Result,
+    state_transition::{PayloadStatus, Result},
 };
 
 pub struct NewPayloadRequest<
@@ -41,25 +40,31 @@ pub trait ExecutionEngine<
             MAX_TRANSACTIONS_PER_PAYLOAD,
             MAX_WITHDRAWALS_PER_PAYLOAD,
         >,
-    ) -> Result<()>;
+    ) -> Result<PayloadStatus>;
 }
 
-// The `DefaultExecutionEngine` performs no operations and validation
-// is determined by `execution_is_valid`.
+// The `DefaultExecutionEngine` performs no operations and reports the
+// `payload_status` it was configured with for every payload.
 #[derive(Debug)]
 pub struct DefaultExecutionEngine {
-    execution_is_valid: bool,
+    payload_status: PayloadStatus,
 }
 
 impl Default for DefaultExecutionEngine {
     fn default() -> Self {
-        Self { execution_is_valid: true }
+        Self { payload_status: PayloadStatus::Valid }
     }
 }
 
 impl DefaultExecutionEngine {
     pub fn new(execution_is_valid: bool) -> Self {
-        Self { execution_is_valid }
+        let payload_status =
+            if execution_is_valid { PayloadStatus::Valid } else { PayloadStatus::InvalidBlockHash };
+        Self { payload_status }
+    }
+
+    pub fn with_payload_status(payload_status: PayloadStatus) -> Self {
+        Self { payload_status }
     }
 
     fn is_valid_block_hash<
@@ -78,12 +83,8 @@ impl DefaultExecutionEngine {
             MAX_WITHDRAWALS_PER_PAYLOAD,
         >,
         _parent_beacon_block_root: &Root,
-    ) -> Result<()> {
-        if !self.execution_is_valid {
-            Err(ExecutionEngineError::InvalidBlockHash.into())
-        } else {
-            Ok(())
-        }
+    ) -> bool {
+        self.payload_status != PayloadStatus::InvalidBlockHash
     }
 
     fn is_valid_versioned_hashes<
@@ -101,12 +102,8 @@ impl DefaultExecutionEngine {
             MAX_TRANSACTIONS_PER_PAYLOAD,
             MAX_WITHDRAWALS_PER_PAYLOAD,
         >,
-    ) -> Result<()> {
-        if !self.execution_is_valid {
-            Err(ExecutionEngineError::InvalidVersionedHashes.into())
-        } else {
-            Ok(())
-        }
+    ) -> bool {
+        !self.payload_status.is_invalid()
     }
 
     fn notify_new_payload<
@@ -125,12 +122,8 @@ impl DefaultExecutionEngine {
             MAX_WITHDRAWALS_PER_PAYLOAD,
         >,
         _parent_beacon_block_root: &Root,
-    ) -> Result<()> {
-        if !self.execution_is_valid {
-            Err(ExecutionEngineError::InvalidPayload.into())
-        } else {
-            Ok(())
-        }
+    ) -> PayloadStatus {
+        self.payload_status.clone()
     }
 }
 
@@ -158,17 +151,21 @@ impl<
             MAX_TRANSACTIONS_PER_PAYLOAD,
             MAX_WITHDRAWALS_PER_PAYLOAD,
         >,
-    ) -> Result<()> {
-        self.is_valid_block_hash(
+    ) -> Result<PayloadStatus> {
+        if !self.is_valid_block_hash(
             new_payload_request.execution_payload,
             &new_payload_request.parent_beacon_block_root,
-        )?;
+        ) {
+            return Ok(PayloadStatus::InvalidBlockHash)
+        }
 
-        self.is_valid_versioned_hashes(new_payload_request)?;
+        if !self.is_valid_versioned_hashes(new_payload_request) {
+            return Ok(PayloadStatus::Invalid { latest_valid_hash: None })
+        }
 
-        self.notify_new_payload(
+        Ok(self.notify_new_payload(
             new_payload_request.execution_payload,
             &new_payload_request.parent_beacon_block_root,
-        )
+        ))
     }
 }
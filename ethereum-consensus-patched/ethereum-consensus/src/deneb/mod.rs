@@ -6,13 +6,19 @@ pub mod beacon_state;
 pub mod blinded_beacon_block;
 pub mod blinded_blob_sidecar;
 pub mod blob_sidecar;
+#[cfg(feature = "transition")]
 pub mod block_processing;
+#[cfg(feature = "transition")]
 pub mod epoch_processing;
 pub mod execution_engine;
 pub mod execution_payload;
+#[cfg(feature = "transition")]
 pub mod fork;
+#[cfg(feature = "transition")]
 pub mod genesis;
 pub mod helpers;
+#[cfg(feature = "light-client")]
+pub mod light_client;
 pub mod networking;
 pub mod polynomial_commitments;
 pub mod presets;
@@ -20,4 +26,4 @@ pub mod spec;
 
 pub use spec::*;
 
-pub use presets::{mainnet, minimal, Preset};
+pub use presets::{gnosis, mainnet, minimal, Preset};
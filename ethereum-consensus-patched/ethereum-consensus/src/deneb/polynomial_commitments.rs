@@ -1,4 +1,7 @@
-use crate::{deneb::blob_sidecar::Blob, primitives::Bytes32, ssz::prelude::*};
+#[cfg(feature = "kzg")]
+use crate::deneb::blob_sidecar::Blob;
+use crate::{primitives::Bytes32, ssz::prelude::*};
+#[cfg(feature = "kzg")]
 pub use c_kzg::KzgSettings;
 use thiserror::Error;
 
@@ -13,17 +16,20 @@ pub type KzgProof = ByteVector<BYTES_PER_PROOF>;
 
 #[derive(Debug, Error)]
 pub enum Error {
+    #[cfg(feature = "kzg")]
     #[error(transparent)]
     CKzg(#[from] c_kzg::Error),
     #[error("proof verification failed")]
     InvalidProof,
 }
 
+#[cfg(feature = "kzg")]
 pub struct ProofAndEvaluation {
     pub proof: KzgProof,
     pub evaluation: FieldElement,
 }
 
+#[cfg(feature = "kzg")]
 pub fn blob_to_kzg_commitment<const BYTES_PER_BLOB: usize>(
     blob: &Blob<BYTES_PER_BLOB>,
     kzg_settings: &KzgSettings,
@@ -35,6 +41,7 @@ pub fn blob_to_kzg_commitment<const BYTES_PER_BLOB: usize>(
     Ok(inner)
 }
 
+#[cfg(feature = "kzg")]
 pub fn compute_kzg_proof<const BYTES_PER_BLOB: usize>(
     blob: &Blob<BYTES_PER_BLOB>,
     evaluation_point: &FieldElement,
@@ -52,6 +59,7 @@ pub fn compute_kzg_proof<const BYTES_PER_BLOB: usize>(
     Ok(result)
 }
 
+#[cfg(feature = "kzg")]
 pub fn compute_blob_kzg_proof<const BYTES_PER_BLOB: usize>(
     blob: &Blob<BYTES_PER_BLOB>,
     commitment: &KzgCommitment,
@@ -65,6 +73,7 @@ pub fn compute_blob_kzg_proof<const BYTES_PER_BLOB: usize>(
     Ok(KzgProof::try_from(proof.to_bytes().as_ref() as &[u8]).expect("input is correct size"))
 }
 
+#[cfg(feature = "kzg")]
 pub fn verify_kzg_proof(
     commitment: &KzgCommitment,
     evaluation_point: &FieldElement,
@@ -88,6 +97,7 @@ pub fn verify_kzg_proof(
     res.then_some(()).ok_or(Error::InvalidProof)
 }
 
+#[cfg(feature = "kzg")]
 pub fn verify_blob_kzg_proof<const BYTES_PER_BLOB: usize>(
     blob: &Blob<BYTES_PER_BLOB>,
     commitment: &KzgCommitment,
@@ -103,6 +113,7 @@ pub fn verify_blob_kzg_proof<const BYTES_PER_BLOB: usize>(
     res.then_some(()).ok_or(Error::InvalidProof)
 }
 
+#[cfg(feature = "kzg")]
 pub fn verify_blob_kzg_proof_batch<const BYTES_PER_BLOB: usize>(
     blobs: &[Blob<BYTES_PER_BLOB>],
     commitments: &[KzgCommitment],
@@ -1,4 +1,40 @@
-#[derive(Clone, Copy)]
+use crate::{
+    phase0::compute_fork_data_root,
+    primitives::{Root, Version},
+    ssz::prelude::*,
+    Error,
+};
+use ssz_rs::utils::{decode_hex, write_bytes_to_lower_hex};
+use std::{fmt, str::FromStr};
+use thiserror::Error as ThisError;
+
+pub const DOMAIN_BEACON_PROPOSER: [u8; 4] = [0x00, 0x00, 0x00, 0x00];
+pub const DOMAIN_BEACON_ATTESTER: [u8; 4] = [0x01, 0x00, 0x00, 0x00];
+pub const DOMAIN_RANDAO: [u8; 4] = [0x02, 0x00, 0x00, 0x00];
+pub const DOMAIN_DEPOSIT: [u8; 4] = [0x03, 0x00, 0x00, 0x00];
+pub const DOMAIN_VOLUNTARY_EXIT: [u8; 4] = [0x04, 0x00, 0x00, 0x00];
+pub const DOMAIN_SELECTION_PROOF: [u8; 4] = [0x05, 0x00, 0x00, 0x00];
+pub const DOMAIN_AGGREGATE_AND_PROOF: [u8; 4] = [0x06, 0x00, 0x00, 0x00];
+pub const DOMAIN_SYNC_COMMITTEE: [u8; 4] = [0x07, 0x00, 0x00, 0x00];
+pub const DOMAIN_SYNC_COMMITTEE_SELECTION_PROOF: [u8; 4] = [0x08, 0x00, 0x00, 0x00];
+pub const DOMAIN_CONTRIBUTION_AND_PROOF: [u8; 4] = [0x09, 0x00, 0x00, 0x00];
+pub const DOMAIN_BLS_TO_EXECUTION_CHANGE: [u8; 4] = [0x0A, 0x00, 0x00, 0x00];
+pub const DOMAIN_BLOB_SIDECAR: [u8; 4] = [0x0B, 0x00, 0x00, 0x00];
+pub const DOMAIN_APPLICATION_MASK: [u8; 4] = [0x00, 0x00, 0x00, 0x01];
+// NOTE: the builder specs define the builder domain as the application mask itself
+pub const DOMAIN_APPLICATION_BUILDER: [u8; 4] = DOMAIN_APPLICATION_MASK;
+
+#[derive(ThisError, Debug, PartialEq, Eq)]
+pub enum DomainError {
+    #[error("unknown domain type `{0}`")]
+    UnknownDomainType(String),
+    #[error("expected {expected} bytes for domain but got {provided}")]
+    InvalidLength { expected: usize, provided: usize },
+    #[error("error decoding hex-encoded domain: {0}")]
+    Hex(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum DomainType {
     BeaconProposer,              // 0
     BeaconAttester,              // 1
@@ -17,14 +53,244 @@ pub enum DomainType {
 }
 
 impl DomainType {
+    pub const ALL: [DomainType; 14] = [
+        Self::BeaconProposer,
+        Self::BeaconAttester,
+        Self::Randao,
+        Self::Deposit,
+        Self::VoluntaryExit,
+        Self::SelectionProof,
+        Self::AggregateAndProof,
+        Self::SyncCommittee,
+        Self::SyncCommitteeSelectionProof,
+        Self::ContributionAndProof,
+        Self::BlsToExecutionChange,
+        Self::BlobSidecar,
+        Self::ApplicationMask,
+        Self::ApplicationBuilder,
+    ];
+
     pub fn as_bytes(&self) -> [u8; 4] {
         match self {
-            Self::ApplicationMask => [0, 0, 0, 1],
-            Self::ApplicationBuilder => [0, 0, 0, 1],
-            _ => {
-                let data = *self as u32;
-                data.to_le_bytes()
-            }
+            Self::BeaconProposer => DOMAIN_BEACON_PROPOSER,
+            Self::BeaconAttester => DOMAIN_BEACON_ATTESTER,
+            Self::Randao => DOMAIN_RANDAO,
+            Self::Deposit => DOMAIN_DEPOSIT,
+            Self::VoluntaryExit => DOMAIN_VOLUNTARY_EXIT,
+            Self::SelectionProof => DOMAIN_SELECTION_PROOF,
+            Self::AggregateAndProof => DOMAIN_AGGREGATE_AND_PROOF,
+            Self::SyncCommittee => DOMAIN_SYNC_COMMITTEE,
+            Self::SyncCommitteeSelectionProof => DOMAIN_SYNC_COMMITTEE_SELECTION_PROOF,
+            Self::ContributionAndProof => DOMAIN_CONTRIBUTION_AND_PROOF,
+            Self::BlsToExecutionChange => DOMAIN_BLS_TO_EXECUTION_CHANGE,
+            Self::BlobSidecar => DOMAIN_BLOB_SIDECAR,
+            Self::ApplicationMask => DOMAIN_APPLICATION_MASK,
+            Self::ApplicationBuilder => DOMAIN_APPLICATION_BUILDER,
+        }
+    }
+
+    // Name of the domain type as given in the consensus specs, e.g. `DOMAIN_BEACON_PROPOSER`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::BeaconProposer => "DOMAIN_BEACON_PROPOSER",
+            Self::BeaconAttester => "DOMAIN_BEACON_ATTESTER",
+            Self::Randao => "DOMAIN_RANDAO",
+            Self::Deposit => "DOMAIN_DEPOSIT",
+            Self::VoluntaryExit => "DOMAIN_VOLUNTARY_EXIT",
+            Self::SelectionProof => "DOMAIN_SELECTION_PROOF",
+            Self::AggregateAndProof => "DOMAIN_AGGREGATE_AND_PROOF",
+            Self::SyncCommittee => "DOMAIN_SYNC_COMMITTEE",
+            Self::SyncCommitteeSelectionProof => "DOMAIN_SYNC_COMMITTEE_SELECTION_PROOF",
+            Self::ContributionAndProof => "DOMAIN_CONTRIBUTION_AND_PROOF",
+            Self::BlsToExecutionChange => "DOMAIN_BLS_TO_EXECUTION_CHANGE",
+            Self::BlobSidecar => "DOMAIN_BLOB_SIDECAR",
+            Self::ApplicationMask => "DOMAIN_APPLICATION_MASK",
+            Self::ApplicationBuilder => "DOMAIN_APPLICATION_BUILDER",
+        }
+    }
+}
+
+// NOTE: `ApplicationMask` and `ApplicationBuilder` share an encoding; the builder domain
+// is returned as it is the only application domain currently in use.
+impl TryFrom<[u8; 4]> for DomainType {
+    type Error = DomainError;
+
+    fn try_from(bytes: [u8; 4]) -> Result<Self, Self::Error> {
+        if bytes == DOMAIN_APPLICATION_BUILDER {
+            return Ok(Self::ApplicationBuilder)
+        }
+        Self::ALL
+            .into_iter()
+            .find(|domain_type| domain_type.as_bytes() == bytes)
+            .ok_or_else(|| DomainError::UnknownDomainType(format!("{bytes:02x?}")))
+    }
+}
+
+impl fmt::Display for DomainType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for DomainType {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|domain_type| domain_type.name() == s)
+            .ok_or_else(|| DomainError::UnknownDomainType(s.to_string()))
+    }
+}
+
+// A `Domain` is the concatenation of a 4-byte `DomainType` and the first 28 bytes of the
+// `fork_data_root` computed from a fork version and the genesis validators root.
+#[derive(
+    Default, Clone, Copy, PartialEq, Eq, Hash, SimpleSerialize, serde::Serialize, serde::Deserialize,
+)]
+pub struct Domain(#[serde(with = "crate::serde::as_hex")] [u8; 32]);
+
+impl Domain {
+    pub const fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn new(domain_type: DomainType, fork_data_root: &Root) -> Self {
+        let mut domain = [0u8; 32];
+        domain[..4].copy_from_slice(&domain_type.as_bytes());
+        domain[4..].copy_from_slice(&fork_data_root[..28]);
+        Self(domain)
+    }
+
+    pub fn domain_type_bytes(&self) -> [u8; 4] {
+        self.0[..4].try_into().expect("domain has enough bytes")
+    }
+
+    pub fn domain_type(&self) -> Option<DomainType> {
+        DomainType::try_from(self.domain_type_bytes()).ok()
+    }
+
+    // Returns the truncated `fork_data_root` committing to the fork version and
+    // genesis validators root this domain was computed for.
+    pub fn fork_data_root_prefix(&self) -> &[u8] {
+        &self.0[4..]
+    }
+
+    // Indicates if this domain was computed for the given `fork_version` and
+    // `genesis_validators_root`.
+    pub fn is_for_fork(
+        &self,
+        fork_version: Version,
+        genesis_validators_root: Root,
+    ) -> Result<bool, Error> {
+        let fork_data_root = compute_fork_data_root(fork_version, genesis_validators_root)?;
+        Ok(self.fork_data_root_prefix() == &fork_data_root[..28])
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Domain {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 32]> for Domain {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Domain> for [u8; 32] {
+    fn from(domain: Domain) -> Self {
+        domain.0
+    }
+}
+
+impl TryFrom<&[u8]> for Domain {
+    type Error = DomainError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let inner = bytes
+            .try_into()
+            .map_err(|_| DomainError::InvalidLength { expected: 32, provided: bytes.len() })?;
+        Ok(Self(inner))
+    }
+}
+
+impl fmt::Debug for Domain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.domain_type() {
+            Some(domain_type) => write!(f, "{domain_type}:")?,
+            None => write!(f, "{:02x?}:", self.domain_type_bytes())?,
         }
+        write_bytes_to_lower_hex(f, self.fork_data_root_prefix())
+    }
+}
+
+impl fmt::Display for Domain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_bytes_to_lower_hex(f, self.0)
+    }
+}
+
+impl FromStr for Domain {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = decode_hex(s).map_err(|err| DomainError::Hex(err.to_string()))?;
+        Self::try_from(bytes.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{phase0::compute_domain, state_transition::Context};
+
+    #[test]
+    fn test_domain_type_roundtrip() {
+        for domain_type in DomainType::ALL {
+            let recovered = DomainType::from_str(&domain_type.to_string()).unwrap();
+            assert_eq!(recovered, domain_type);
+        }
+        let recovered = DomainType::try_from(DOMAIN_APPLICATION_MASK).unwrap();
+        assert_eq!(recovered, DomainType::ApplicationBuilder);
+        assert!(DomainType::try_from([0xFF, 0, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_domain_accessors() {
+        let context = Context::for_mainnet();
+        let genesis_validators_root = Root::try_from([1u8; 32].as_ref()).unwrap();
+        let fork_version = context.altair_fork_version;
+        let domain = compute_domain(
+            DomainType::SyncCommittee,
+            Some(fork_version),
+            Some(genesis_validators_root),
+            &context,
+        )
+        .unwrap();
+        assert_eq!(domain.domain_type(), Some(DomainType::SyncCommittee));
+        assert!(domain.is_for_fork(fork_version, genesis_validators_root).unwrap());
+        assert!(!domain
+            .is_for_fork(context.genesis_fork_version, genesis_validators_root)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_domain_display_roundtrip() {
+        let context = Context::for_mainnet();
+        let domain = compute_domain(DomainType::ApplicationBuilder, None, None, &context).unwrap();
+        let repr = domain.to_string();
+        assert_eq!(repr, "0x00000001f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a9");
+        let recovered = Domain::from_str(&repr).unwrap();
+        assert_eq!(recovered, domain);
+        assert!(Domain::from_str("0x0000").is_err());
+        assert!(Domain::from_str(&repr.replace('f', "g")).is_err());
+        assert!(format!("{domain:?}").starts_with("DOMAIN_APPLICATION_BUILDER:0x"));
     }
 }
@@ -0,0 +1,1307 @@
+use crate::{
+    crypto::verify_signature,
+    domains::DomainType,
+    electra::{
+        add_flag, compute_consolidation_epoch_and_update_churn, compute_domain,
+        compute_epoch_at_slot, compute_exit_epoch_and_update_churn, compute_signing_root,
+        compute_timestamp_at_slot, constants::UNSET_DEPOSIT_REQUESTS_START_INDEX, decrease_balance,
+        get_attestation_participation_flag_indices, get_base_reward, get_beacon_committee,
+        get_beacon_proposer_index, get_committee_count_per_slot, get_committee_indices,
+        get_consolidation_churn_limit, get_current_epoch, get_indexed_attestation,
+        get_max_effective_balance, get_pending_balance_to_withdraw, get_previous_epoch,
+        get_randao_mix, has_compounding_withdrawal_credential, has_eth1_withdrawal_credential,
+        has_execution_withdrawal_credential, has_flag, increase_balance, initiate_validator_exit,
+        is_active_validator, is_fully_withdrawable_validator, is_partially_withdrawable_validator,
+        is_valid_indexed_attestation, kzg_commitment_to_versioned_hash, process_attester_slashing,
+        process_bls_to_execution_change, process_proposer_slashing,
+        switch_to_compounding_validator, Attestation, BeaconBlockBody, BeaconState,
+        ConsolidationRequest, Deposit, DepositMessage, DepositRequest, ExecutionEngine,
+        ExecutionPayload, ExecutionPayloadHeader, NewPayloadRequest, PendingConsolidation,
+        PendingDeposit, PendingPartialWithdrawal, SignedVoluntaryExit, Validator, Withdrawal,
+        WithdrawalRequest, FULL_EXIT_REQUEST_AMOUNT, PARTICIPATION_FLAG_WEIGHTS, PROPOSER_WEIGHT,
+        WEIGHT_DENOMINATOR,
+    },
+    error::{
+        invalid_operation_error, InvalidAttestation, InvalidDeposit, InvalidExecutionPayload,
+        InvalidOperation, InvalidVoluntaryExit, InvalidWithdrawals,
+    },
+    primitives::{
+        BlsPublicKey, BlsSignature, Bytes32, ExecutionAddress, Gwei, ParticipationFlags,
+        FAR_FUTURE_EPOCH, GENESIS_SLOT,
+    },
+    signing::verify_signed_data,
+    ssz::prelude::*,
+    state_transition::{Context, PayloadStatus, Result},
+};
+
+pub fn get_expected_withdrawals<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    context: &Context,
+) -> (Vec<Withdrawal>, usize) {
+    let epoch = get_current_epoch(state, context);
+    let mut withdrawal_index = state.next_withdrawal_index;
+    let mut validator_index = state.next_withdrawal_validator_index;
+    let mut withdrawals: Vec<Withdrawal> = vec![];
+    let mut processed_partial_withdrawals_count = 0;
+
+    for withdrawal in state.pending_partial_withdrawals.iter() {
+        if withdrawal.withdrawable_epoch > epoch ||
+            withdrawals.len() == context.max_pending_partials_per_withdrawals_sweep
+        {
+            break
+        }
+        let validator = &state.validators[withdrawal.validator_index];
+        let has_sufficient_effective_balance =
+            validator.effective_balance >= context.min_activation_balance;
+        let total_withdrawn = withdrawals
+            .iter()
+            .filter(|w| w.validator_index == withdrawal.validator_index)
+            .map(|w| w.amount)
+            .sum::<Gwei>();
+        let balance = state.balances[withdrawal.validator_index] - total_withdrawn;
+        let has_excess_balance = balance > context.min_activation_balance;
+        if validator.exit_epoch == FAR_FUTURE_EPOCH &&
+            has_sufficient_effective_balance &&
+            has_excess_balance
+        {
+            let withdrawable_balance =
+                Gwei::min(balance - context.min_activation_balance, withdrawal.amount);
+            let address =
+                ExecutionAddress::try_from(&validator.withdrawal_credentials.as_slice()[12..])
+                    .expect("providing the correct amount of input to type");
+            withdrawals.push(Withdrawal {
+                index: withdrawal_index,
+                validator_index: withdrawal.validator_index,
+                address,
+                amount: withdrawable_balance,
+            });
+            withdrawal_index += 1;
+        }
+        processed_partial_withdrawals_count += 1;
+    }
+
+    let bound = state.validators.len().min(context.max_validators_per_withdrawals_sweep);
+    for _ in 0..bound {
+        let validator = &state.validators[validator_index];
+        let partially_withdrawn_balance = withdrawals
+            .iter()
+            .filter(|w| w.validator_index == validator_index)
+            .map(|w| w.amount)
+            .sum::<Gwei>();
+        let balance = state.balances[validator_index] - partially_withdrawn_balance;
+        if is_fully_withdrawable_validator(validator, balance, epoch) {
+            let address =
+                ExecutionAddress::try_from(&validator.withdrawal_credentials.as_slice()[12..])
+                    .expect("providing the correct amount of input to type");
+            withdrawals.push(Withdrawal {
+                index: withdrawal_index,
+                validator_index,
+                address,
+                amount: balance,
+            });
+            withdrawal_index += 1;
+        } else if is_partially_withdrawable_validator(validator, balance, context) {
+            let address =
+                ExecutionAddress::try_from(&validator.withdrawal_credentials.as_slice()[12..])
+                    .expect("providing the correct amount of input to type");
+            withdrawals.push(Withdrawal {
+                index: withdrawal_index,
+                validator_index,
+                address,
+                amount: balance - get_max_effective_balance(validator, context),
+            });
+            withdrawal_index += 1;
+        }
+        if withdrawals.len() == context.max_withdrawals_per_payload {
+            break
+        }
+        validator_index = (validator_index + 1) % state.validators.len();
+    }
+    (withdrawals, processed_partial_withdrawals_count)
+}
+
+pub fn process_withdrawals<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    execution_payload: &ExecutionPayload<
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+    >,
+    context: &Context,
+) -> Result<()> {
+    let (expected_withdrawals, processed_partial_withdrawals_count) =
+        get_expected_withdrawals(state, context);
+    if execution_payload.withdrawals.as_ref() != expected_withdrawals {
+        return Err(invalid_operation_error(InvalidOperation::Withdrawal(
+            InvalidWithdrawals::IncorrectWithdrawals {
+                provided: execution_payload.withdrawals.to_vec(),
+                expected: expected_withdrawals,
+            },
+        )))
+    }
+    for withdrawal in &expected_withdrawals {
+        decrease_balance(state, withdrawal.validator_index, withdrawal.amount);
+    }
+    state.pending_partial_withdrawals = List::try_from_iter(
+        state.pending_partial_withdrawals.iter().skip(processed_partial_withdrawals_count).cloned(),
+    )?;
+    if let Some(latest_withdrawal) = expected_withdrawals.last() {
+        state.next_withdrawal_index = latest_withdrawal.index + 1;
+    }
+    if expected_withdrawals.len() == context.max_withdrawals_per_payload {
+        let latest_withdrawal = expected_withdrawals.last().expect("empty withdrawals");
+        let next_validator_index = (latest_withdrawal.validator_index + 1) % state.validators.len();
+        state.next_withdrawal_validator_index = next_validator_index;
+    } else {
+        let next_index =
+            state.next_withdrawal_validator_index + context.max_validators_per_withdrawals_sweep;
+        state.next_withdrawal_validator_index = next_index % state.validators.len();
+    }
+    Ok(())
+}
+
+/// Check that the withdrawals in `execution_payload` are those expected from `state` and that
+/// their root is the `withdrawals_root` committed to in `execution_payload_header`, without
+/// applying them to `state`.
+pub fn verify_withdrawals<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    execution_payload: &ExecutionPayload<
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+    >,
+    execution_payload_header: &ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    context: &Context,
+) -> Result<()> {
+    let (expected_withdrawals, _) = get_expected_withdrawals(state, context);
+    if execution_payload.withdrawals.as_ref() != expected_withdrawals {
+        return Err(invalid_operation_error(InvalidOperation::Withdrawal(
+            InvalidWithdrawals::IncorrectWithdrawals {
+                provided: execution_payload.withdrawals.to_vec(),
+                expected: expected_withdrawals,
+            },
+        )))
+    }
+    let withdrawals_root = execution_payload.withdrawals.hash_tree_root()?;
+    if withdrawals_root != execution_payload_header.withdrawals_root {
+        return Err(invalid_operation_error(InvalidOperation::Withdrawal(
+            InvalidWithdrawals::WithdrawalsRootMismatch {
+                provided: execution_payload_header.withdrawals_root,
+                expected: withdrawals_root,
+            },
+        )))
+    }
+    Ok(())
+}
+
+pub fn process_execution_payload<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+    const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+    const MAX_ATTESTATIONS_ELECTRA: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+    const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+    const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+    E: ExecutionEngine<
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    body: &mut BeaconBlockBody<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >,
+    execution_engine: &E,
+    context: &Context,
+) -> Result<PayloadStatus> {
+    let payload = &mut body.execution_payload;
+
+    let parent_hash_invalid =
+        payload.parent_hash != state.latest_execution_payload_header.block_hash;
+    if parent_hash_invalid {
+        return Err(invalid_operation_error(
+            InvalidExecutionPayload::InvalidParentHash {
+                provided: payload.parent_hash.clone(),
+                expected: state.latest_execution_payload_header.block_hash.clone(),
+            }
+            .into(),
+        ))
+    }
+
+    let current_epoch = get_current_epoch(state, context);
+    let randao_mix = get_randao_mix(state, current_epoch);
+    if &payload.prev_randao != randao_mix {
+        return Err(invalid_operation_error(
+            InvalidExecutionPayload::InvalidPrevRandao {
+                provided: payload.prev_randao.clone(),
+                expected: randao_mix.clone(),
+            }
+            .into(),
+        ))
+    }
+
+    let timestamp = compute_timestamp_at_slot(state, state.slot, context)?;
+    if payload.timestamp != timestamp {
+        return Err(invalid_operation_error(
+            InvalidExecutionPayload::InvalidTimestamp {
+                provided: payload.timestamp,
+                expected: timestamp,
+            }
+            .into(),
+        ))
+    }
+
+    if body.blob_kzg_commitments.len() > context.max_blobs_per_block_electra {
+        return Err(invalid_operation_error(
+            InvalidExecutionPayload::InvalidBlobCommitments {
+                provided: body.blob_kzg_commitments.len(),
+                limit: context.max_blobs_per_block_electra,
+            }
+            .into(),
+        ))
+    }
+
+    let versioned_hashes =
+        body.blob_kzg_commitments.iter().map(kzg_commitment_to_versioned_hash).collect::<Vec<_>>();
+
+    let new_payload_request = NewPayloadRequest {
+        execution_payload: &*payload,
+        versioned_hashes: &versioned_hashes,
+        parent_beacon_block_root: state.latest_block_header.parent_root,
+        execution_requests: &body.execution_requests,
+    };
+    let status = execution_engine.verify_and_notify_new_payload(&new_payload_request)?;
+    status.ensure_not_invalid()?;
+
+    state.latest_execution_payload_header = ExecutionPayloadHeader {
+        parent_hash: payload.parent_hash.clone(),
+        fee_recipient: payload.fee_recipient.clone(),
+        state_root: payload.state_root.clone(),
+        receipts_root: payload.receipts_root.clone(),
+        logs_bloom: payload.logs_bloom.clone(),
+        prev_randao: payload.prev_randao.clone(),
+        block_number: payload.block_number,
+        gas_limit: payload.gas_limit,
+        gas_used: payload.gas_used,
+        timestamp: payload.timestamp,
+        extra_data: payload.extra_data.clone(),
+        base_fee_per_gas: payload.base_fee_per_gas,
+        block_hash: payload.block_hash.clone(),
+        transactions_root: payload.transactions.hash_tree_root()?,
+        withdrawals_root: payload.withdrawals.hash_tree_root()?,
+        blob_gas_used: payload.blob_gas_used,
+        excess_blob_gas: payload.excess_blob_gas,
+    };
+
+    Ok(status)
+}
+
+pub fn process_operations<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+    const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+    const MAX_ATTESTATIONS_ELECTRA: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+    const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+    const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    body: &mut BeaconBlockBody<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >,
+    context: &Context,
+) -> Result<()> {
+    let eth1_deposit_index_limit =
+        u64::min(state.eth1_data.deposit_count, state.deposit_requests_start_index);
+    let expected_deposit_count = if state.eth1_deposit_index < eth1_deposit_index_limit {
+        usize::min(
+            context.max_deposits,
+            (eth1_deposit_index_limit - state.eth1_deposit_index) as usize,
+        )
+    } else {
+        0
+    };
+    if body.deposits.len() != expected_deposit_count {
+        return Err(invalid_operation_error(InvalidOperation::Deposit(
+            InvalidDeposit::IncorrectCount {
+                expected: expected_deposit_count,
+                count: body.deposits.len(),
+            },
+        )))
+    }
+    body.proposer_slashings
+        .iter_mut()
+        .try_for_each(|op| process_proposer_slashing(state, op, context))?;
+    body.attester_slashings
+        .iter_mut()
+        .try_for_each(|op| process_attester_slashing(state, op, context))?;
+    body.attestations.iter().try_for_each(|op| process_attestation(state, op, context))?;
+    body.deposits.iter_mut().try_for_each(|op| process_deposit(state, op, context))?;
+    body.voluntary_exits
+        .iter_mut()
+        .try_for_each(|op| process_voluntary_exit(state, op, context))?;
+    body.bls_to_execution_changes
+        .iter_mut()
+        .try_for_each(|op| process_bls_to_execution_change(state, op, context))?;
+    let execution_requests = &body.execution_requests;
+    execution_requests
+        .deposits
+        .iter()
+        .try_for_each(|op| process_deposit_request(state, op, context))?;
+    execution_requests
+        .withdrawals
+        .iter()
+        .try_for_each(|op| process_withdrawal_request(state, op, context))?;
+    execution_requests
+        .consolidations
+        .iter()
+        .try_for_each(|op| process_consolidation_request(state, op, context))?;
+    Ok(())
+}
+
+pub fn process_attestation<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    attestation: &Attestation<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+    context: &Context,
+) -> Result<()> {
+    let data = &attestation.data;
+    let is_previous = data.target.epoch == get_previous_epoch(state, context);
+    let current_epoch = get_current_epoch(state, context);
+    let is_current = data.target.epoch == current_epoch;
+    let valid_target_epoch = is_previous || is_current;
+    if !valid_target_epoch {
+        return Err(invalid_operation_error(InvalidOperation::Attestation(
+            InvalidAttestation::InvalidTargetEpoch {
+                target: data.target.epoch,
+                current: current_epoch,
+            },
+        )))
+    }
+    let attestation_epoch = compute_epoch_at_slot(data.slot, context);
+    if data.target.epoch != attestation_epoch {
+        return Err(invalid_operation_error(InvalidOperation::Attestation(
+            InvalidAttestation::InvalidSlot {
+                slot: data.slot,
+                epoch: attestation_epoch,
+                target: data.target.epoch,
+            },
+        )))
+    }
+    let attestation_is_timely = data.slot + context.min_attestation_inclusion_delay <= state.slot;
+    if !attestation_is_timely {
+        return Err(invalid_operation_error(InvalidOperation::Attestation(
+            InvalidAttestation::NoDelay {
+                attestation_slot: data.slot,
+                state_slot: state.slot,
+                required_delay: context.min_attestation_inclusion_delay,
+            },
+        )))
+    }
+    if data.index != 0 {
+        return Err(invalid_operation_error(InvalidOperation::Attestation(
+            InvalidAttestation::NonZeroCommitteeIndex(data.index),
+        )))
+    }
+    let bits = &attestation.aggregation_bits;
+    let committee_count = get_committee_count_per_slot(state, data.target.epoch, context);
+    let mut committee_offset = 0;
+    for committee_index in get_committee_indices(&attestation.committee_bits) {
+        if committee_index >= committee_count {
+            return Err(invalid_operation_error(InvalidOperation::Attestation(
+                InvalidAttestation::InvalidIndex {
+                    index: committee_index,
+                    upper_bound: committee_count,
+                },
+            )))
+        }
+        let committee = get_beacon_committee(state, data.slot, committee_index, context)?;
+        if committee_offset + committee.len() > bits.len() {
+            return Err(invalid_operation_error(InvalidOperation::Attestation(
+                InvalidAttestation::Bitfield {
+                    expected_length: committee_offset + committee.len(),
+                    length: bits.len(),
+                },
+            )))
+        }
+        let has_attesters = (0..committee.len()).any(|i| bits[committee_offset + i]);
+        if !has_attesters {
+            return Err(invalid_operation_error(InvalidOperation::Attestation(
+                InvalidAttestation::EmptyCommittee(committee_index),
+            )))
+        }
+        committee_offset += committee.len();
+    }
+    if bits.len() != committee_offset {
+        return Err(invalid_operation_error(InvalidOperation::Attestation(
+            InvalidAttestation::Bitfield { expected_length: committee_offset, length: bits.len() },
+        )))
+    }
+    let inclusion_delay = state.slot - data.slot;
+    let participation_flag_indices =
+        get_attestation_participation_flag_indices(state, data, inclusion_delay, context)?;
+    let mut indexed_attestation = get_indexed_attestation(state, attestation, context)?;
+    is_valid_indexed_attestation(state, &mut indexed_attestation, context)?;
+    let mut proposer_reward_numerator = 0;
+    for &index in indexed_attestation.attesting_indices.iter() {
+        for (flag_index, weight) in PARTICIPATION_FLAG_WEIGHTS.iter().enumerate() {
+            if is_current {
+                if participation_flag_indices.contains(&flag_index) &&
+                    !has_flag(state.current_epoch_participation[index], flag_index)
+                {
+                    state.current_epoch_participation[index] =
+                        add_flag(state.current_epoch_participation[index], flag_index);
+                    proposer_reward_numerator += get_base_reward(state, index, context)? * weight;
+                }
+            } else if participation_flag_indices.contains(&flag_index) &&
+                !has_flag(state.previous_epoch_participation[index], flag_index)
+            {
+                state.previous_epoch_participation[index] =
+                    add_flag(state.previous_epoch_participation[index], flag_index);
+                proposer_reward_numerator += get_base_reward(state, index, context)? * weight;
+            }
+        }
+    }
+    let proposer_reward_denominator =
+        (WEIGHT_DENOMINATOR - PROPOSER_WEIGHT) * WEIGHT_DENOMINATOR / PROPOSER_WEIGHT;
+    let proposer_reward = proposer_reward_numerator / proposer_reward_denominator;
+    increase_balance(state, get_beacon_proposer_index(state, context)?, proposer_reward);
+    Ok(())
+}
+
+pub fn get_validator_from_deposit(
+    public_key: &BlsPublicKey,
+    withdrawal_credentials: &Bytes32,
+    amount: Gwei,
+    context: &Context,
+) -> Validator {
+    let mut validator = Validator {
+        public_key: public_key.clone(),
+        withdrawal_credentials: withdrawal_credentials.clone(),
+        effective_balance: 0,
+        activation_eligibility_epoch: FAR_FUTURE_EPOCH,
+        activation_epoch: FAR_FUTURE_EPOCH,
+        exit_epoch: FAR_FUTURE_EPOCH,
+        withdrawable_epoch: FAR_FUTURE_EPOCH,
+        ..Default::default()
+    };
+    let max_effective_balance = get_max_effective_balance(&validator, context);
+    validator.effective_balance =
+        Gwei::min(amount - amount % context.effective_balance_increment, max_effective_balance);
+    validator
+}
+
+pub fn add_validator_to_registry<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    public_key: &BlsPublicKey,
+    withdrawal_credentials: &Bytes32,
+    amount: Gwei,
+    context: &Context,
+) -> Result<()> {
+    let validator = get_validator_from_deposit(public_key, withdrawal_credentials, amount, context);
+    state.validators.push(validator)?;
+    state.balances.push(amount)?;
+    state.previous_epoch_participation.push(ParticipationFlags::default())?;
+    state.current_epoch_participation.push(ParticipationFlags::default())?;
+    state.inactivity_scores.push(0)?;
+    Ok(())
+}
+
+pub fn is_valid_deposit_signature(
+    public_key: &BlsPublicKey,
+    withdrawal_credentials: &Bytes32,
+    amount: Gwei,
+    signature: &BlsSignature,
+    context: &Context,
+) -> Result<bool> {
+    let mut deposit_message = DepositMessage {
+        public_key: public_key.clone(),
+        withdrawal_credentials: withdrawal_credentials.clone(),
+        amount,
+    };
+    let domain = compute_domain(DomainType::Deposit, None, None, context)?;
+    let signing_root = compute_signing_root(&mut deposit_message, domain)?;
+    Ok(verify_signature(public_key, signing_root.as_ref(), signature).is_ok())
+}
+
+pub fn apply_deposit<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    public_key: &BlsPublicKey,
+    withdrawal_credentials: &Bytes32,
+    amount: Gwei,
+    signature: &BlsSignature,
+    context: &Context,
+) -> Result<()> {
+    if !state.validators.iter().any(|v| &v.public_key == public_key) {
+        if !is_valid_deposit_signature(
+            public_key,
+            withdrawal_credentials,
+            amount,
+            signature,
+            context,
+        )? {
+            return Ok(())
+        }
+        add_validator_to_registry(state, public_key, withdrawal_credentials, 0, context)?;
+    }
+    state.pending_deposits.push(PendingDeposit {
+        public_key: public_key.clone(),
+        withdrawal_credentials: withdrawal_credentials.clone(),
+        amount,
+        signature: signature.clone(),
+        slot: GENESIS_SLOT,
+    })?;
+    Ok(())
+}
+
+pub fn process_deposit<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    deposit: &mut Deposit,
+    context: &Context,
+) -> Result<()> {
+    let leaf = deposit.data.hash_tree_root()?;
+    let branch = &deposit.proof;
+    let depth = crate::phase0::block_processing::DEPOSIT_MERKLE_DEPTH;
+    let index = state.eth1_deposit_index as usize;
+    let root = state.eth1_data.deposit_root;
+    if is_valid_merkle_branch(leaf, branch, depth, index, root).is_err() {
+        return Err(invalid_operation_error(InvalidOperation::Deposit(
+            InvalidDeposit::InvalidProof {
+                leaf,
+                branch: branch
+                    .iter()
+                    .map(|node| Node::try_from(node.as_ref()).expect("correct size"))
+                    .collect(),
+                depth,
+                index,
+                root,
+            },
+        )))
+    }
+    state.eth1_deposit_index += 1;
+    apply_deposit(
+        state,
+        &deposit.data.public_key,
+        &deposit.data.withdrawal_credentials,
+        deposit.data.amount,
+        &deposit.data.signature,
+        context,
+    )
+}
+
+pub fn process_voluntary_exit<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    signed_voluntary_exit: &mut SignedVoluntaryExit,
+    context: &Context,
+) -> Result<()> {
+    let voluntary_exit = &mut signed_voluntary_exit.message;
+    let validator = state.validators.get(voluntary_exit.validator_index).ok_or_else(|| {
+        invalid_operation_error(InvalidOperation::VoluntaryExit(
+            InvalidVoluntaryExit::InvalidIndex(voluntary_exit.validator_index),
+        ))
+    })?;
+    let current_epoch = get_current_epoch(state, context);
+    if !is_active_validator(validator, current_epoch) {
+        return Err(invalid_operation_error(InvalidOperation::VoluntaryExit(
+            InvalidVoluntaryExit::InactiveValidator(current_epoch),
+        )))
+    }
+    if validator.exit_epoch != FAR_FUTURE_EPOCH {
+        return Err(invalid_operation_error(InvalidOperation::VoluntaryExit(
+            InvalidVoluntaryExit::ValidatorAlreadyExited {
+                index: voluntary_exit.validator_index,
+                epoch: validator.exit_epoch,
+            },
+        )))
+    }
+    if current_epoch < voluntary_exit.epoch {
+        return Err(invalid_operation_error(InvalidOperation::VoluntaryExit(
+            InvalidVoluntaryExit::EarlyExit { current_epoch, exit_epoch: voluntary_exit.epoch },
+        )))
+    }
+    let minimum_time_active = validator.activation_epoch + context.shard_committee_period;
+    if current_epoch < minimum_time_active {
+        return Err(invalid_operation_error(InvalidOperation::VoluntaryExit(
+            InvalidVoluntaryExit::ValidatorIsNotActiveForLongEnough {
+                current_epoch,
+                minimum_time_active,
+            },
+        )))
+    }
+    let pending_balance_to_withdraw =
+        get_pending_balance_to_withdraw(state, voluntary_exit.validator_index);
+    if pending_balance_to_withdraw != 0 {
+        return Err(invalid_operation_error(InvalidOperation::VoluntaryExit(
+            InvalidVoluntaryExit::PendingWithdrawals {
+                index: voluntary_exit.validator_index,
+                amount: pending_balance_to_withdraw,
+            },
+        )))
+    }
+    let domain = compute_domain(
+        DomainType::VoluntaryExit,
+        Some(context.capella_fork_version),
+        Some(state.genesis_validators_root),
+        context,
+    )?;
+    let public_key = &validator.public_key;
+    verify_signed_data(voluntary_exit, &signed_voluntary_exit.signature, public_key, domain)
+        .map_err(|_| {
+            invalid_operation_error(InvalidOperation::VoluntaryExit(
+                InvalidVoluntaryExit::InvalidSignature(signed_voluntary_exit.signature.clone()),
+            ))
+        })?;
+    initiate_validator_exit(state, voluntary_exit.validator_index, context)
+}
+
+fn is_source_address(validator: &Validator, source_address: &ExecutionAddress) -> bool {
+    validator.withdrawal_credentials[12..] == source_address[..]
+}
+
+pub fn process_withdrawal_request<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    withdrawal_request: &WithdrawalRequest,
+    context: &Context,
+) -> Result<()> {
+    let amount = withdrawal_request.amount;
+    let is_full_exit_request = amount == FULL_EXIT_REQUEST_AMOUNT;
+    // if the partial withdrawal queue is full, only full exits are processed
+    if state.pending_partial_withdrawals.len() == PENDING_PARTIAL_WITHDRAWALS_LIMIT &&
+        !is_full_exit_request
+    {
+        return Ok(())
+    }
+    let Some(index) = state
+        .validators
+        .iter()
+        .position(|v| v.public_key == withdrawal_request.validator_public_key)
+    else {
+        return Ok(())
+    };
+    let validator = &state.validators[index];
+    if !(has_execution_withdrawal_credential(validator) &&
+        is_source_address(validator, &withdrawal_request.source_address))
+    {
+        return Ok(())
+    }
+    let current_epoch = get_current_epoch(state, context);
+    if !is_active_validator(validator, current_epoch) {
+        return Ok(())
+    }
+    if validator.exit_epoch != FAR_FUTURE_EPOCH {
+        return Ok(())
+    }
+    if current_epoch < validator.activation_epoch + context.shard_committee_period {
+        return Ok(())
+    }
+
+    let pending_balance_to_withdraw = get_pending_balance_to_withdraw(state, index);
+    if is_full_exit_request {
+        // only exit the validator if it has no pending withdrawals in the queue
+        if pending_balance_to_withdraw == 0 {
+            initiate_validator_exit(state, index, context)?;
+        }
+        return Ok(())
+    }
+
+    let balance = state.balances[index];
+    let has_sufficient_effective_balance =
+        validator.effective_balance >= context.min_activation_balance;
+    let has_excess_balance = balance > context.min_activation_balance + pending_balance_to_withdraw;
+    // only allow partial withdrawals with compounding withdrawal credentials
+    if has_compounding_withdrawal_credential(validator) &&
+        has_sufficient_effective_balance &&
+        has_excess_balance
+    {
+        let to_withdraw = Gwei::min(
+            balance - context.min_activation_balance - pending_balance_to_withdraw,
+            amount,
+        );
+        let exit_queue_epoch = compute_exit_epoch_and_update_churn(state, to_withdraw, context)?;
+        let withdrawable_epoch = exit_queue_epoch + context.min_validator_withdrawability_delay;
+        state.pending_partial_withdrawals.push(PendingPartialWithdrawal {
+            validator_index: index,
+            amount: to_withdraw,
+            withdrawable_epoch,
+        })?;
+    }
+    Ok(())
+}
+
+pub fn process_deposit_request<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    deposit_request: &DepositRequest,
+    _context: &Context,
+) -> Result<()> {
+    if state.deposit_requests_start_index == UNSET_DEPOSIT_REQUESTS_START_INDEX {
+        state.deposit_requests_start_index = deposit_request.index;
+    }
+    state.pending_deposits.push(PendingDeposit {
+        public_key: deposit_request.public_key.clone(),
+        withdrawal_credentials: deposit_request.withdrawal_credentials.clone(),
+        amount: deposit_request.amount,
+        signature: deposit_request.signature.clone(),
+        slot: state.slot,
+    })?;
+    Ok(())
+}
+
+pub fn is_valid_switch_to_compounding_request<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    consolidation_request: &ConsolidationRequest,
+    context: &Context,
+) -> bool {
+    // switching to compounding requires the source and target to be equal
+    if consolidation_request.source_public_key != consolidation_request.target_public_key {
+        return false
+    }
+    let Some(source_validator) =
+        state.validators.iter().find(|v| v.public_key == consolidation_request.source_public_key)
+    else {
+        return false
+    };
+    if !is_source_address(source_validator, &consolidation_request.source_address) {
+        return false
+    }
+    if !has_eth1_withdrawal_credential(source_validator) {
+        return false
+    }
+    let current_epoch = get_current_epoch(state, context);
+    if !is_active_validator(source_validator, current_epoch) {
+        return false
+    }
+    source_validator.exit_epoch == FAR_FUTURE_EPOCH
+}
+
+pub fn process_consolidation_request<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    consolidation_request: &ConsolidationRequest,
+    context: &Context,
+) -> Result<()> {
+    if is_valid_switch_to_compounding_request(state, consolidation_request, context) {
+        let source_index = state
+            .validators
+            .iter()
+            .position(|v| v.public_key == consolidation_request.source_public_key)
+            .expect("validated by `is_valid_switch_to_compounding_request`");
+        switch_to_compounding_validator(state, source_index, context)?;
+        return Ok(())
+    }
+
+    // a consolidation with the source equal to the target cannot be used as an exit
+    if consolidation_request.source_public_key == consolidation_request.target_public_key {
+        return Ok(())
+    }
+    if state.pending_consolidations.len() == PENDING_CONSOLIDATIONS_LIMIT {
+        return Ok(())
+    }
+    if get_consolidation_churn_limit(state, context)? <= context.min_activation_balance {
+        return Ok(())
+    }
+
+    let find_index = |public_key: &BlsPublicKey| {
+        state.validators.iter().position(|v| &v.public_key == public_key)
+    };
+    let Some(source_index) = find_index(&consolidation_request.source_public_key) else {
+        return Ok(())
+    };
+    let Some(target_index) = find_index(&consolidation_request.target_public_key) else {
+        return Ok(())
+    };
+    let source_validator = &state.validators[source_index];
+    let target_validator = &state.validators[target_index];
+
+    if !(has_execution_withdrawal_credential(source_validator) &&
+        is_source_address(source_validator, &consolidation_request.source_address))
+    {
+        return Ok(())
+    }
+    if !has_compounding_withdrawal_credential(target_validator) {
+        return Ok(())
+    }
+    let current_epoch = get_current_epoch(state, context);
+    if !is_active_validator(source_validator, current_epoch) ||
+        !is_active_validator(target_validator, current_epoch)
+    {
+        return Ok(())
+    }
+    if source_validator.exit_epoch != FAR_FUTURE_EPOCH ||
+        target_validator.exit_epoch != FAR_FUTURE_EPOCH
+    {
+        return Ok(())
+    }
+    if current_epoch < source_validator.activation_epoch + context.shard_committee_period {
+        return Ok(())
+    }
+    if get_pending_balance_to_withdraw(state, source_index) > 0 {
+        return Ok(())
+    }
+
+    let exit_epoch = compute_consolidation_epoch_and_update_churn(
+        state,
+        state.validators[source_index].effective_balance,
+        context,
+    )?;
+    let source_validator = &mut state.validators[source_index];
+    source_validator.exit_epoch = exit_epoch;
+    source_validator.withdrawable_epoch = exit_epoch + context.min_validator_withdrawability_delay;
+    state.pending_consolidations.push(PendingConsolidation { source_index, target_index })?;
+    Ok(())
+}
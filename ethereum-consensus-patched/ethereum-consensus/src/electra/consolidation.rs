@@ -0,0 +1,305 @@
+//! Planning of consolidations, e.g. for an operator merging many validators into fewer
+//! validators with compounding withdrawal credentials.
+//!
+//! Unlike most operations, a `ConsolidationRequest` carries no signature: it is authorized by
+//! being sent to the consolidation request contract from the source validator's withdrawal
+//! address, which must be the `source_address` of the request. The helpers here only build
+//! the requests; submitting them in execution layer transactions is left to the caller.
+use crate::{
+    electra::{
+        beacon_state::BeaconState, compute_activation_exit_epoch, get_consolidation_churn_limit,
+        get_current_epoch, get_pending_balance_to_withdraw, has_compounding_withdrawal_credential,
+        has_execution_withdrawal_credential, is_active_validator, operations::ConsolidationRequest,
+        Validator,
+    },
+    error::InvalidConsolidation,
+    primitives::{Epoch, ExecutionAddress, Gwei, ValidatorIndex, FAR_FUTURE_EPOCH},
+    state_transition::{Context, Result},
+};
+use std::collections::HashSet;
+
+/// A consolidation of the validator at `source_index` into the validator at `target_index`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedConsolidation {
+    pub source_index: ValidatorIndex,
+    pub target_index: ValidatorIndex,
+    pub request: ConsolidationRequest,
+    /// The epoch the source exits in if the planned consolidations are processed in order
+    /// and before any other consolidation.
+    pub exit_epoch: Epoch,
+}
+
+fn validator_at(validators: &[Validator], index: ValidatorIndex) -> Result<&Validator> {
+    validators.get(index).ok_or_else(|| InvalidConsolidation::InvalidIndex(index).into())
+}
+
+fn check_active(validator: &Validator, index: ValidatorIndex, epoch: Epoch) -> Result<()> {
+    if !is_active_validator(validator, epoch) {
+        return Err(InvalidConsolidation::InactiveValidator { index, epoch }.into())
+    }
+    if validator.exit_epoch != FAR_FUTURE_EPOCH {
+        return Err(InvalidConsolidation::ValidatorAlreadyExited {
+            index,
+            epoch: validator.exit_epoch,
+        }
+        .into())
+    }
+    Ok(())
+}
+
+// Check the conditions on the source and target of a consolidation under which
+// `process_consolidation_request` would accept it, other than those on the churn and the
+// pending consolidations queue that depend on the other requests processed with it.
+pub fn check_consolidation<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    source_index: ValidatorIndex,
+    target_index: ValidatorIndex,
+    context: &Context,
+) -> Result<()> {
+    if source_index == target_index {
+        return Err(InvalidConsolidation::SameValidator(source_index).into())
+    }
+    let source = validator_at(&state.validators, source_index)?;
+    let target = validator_at(&state.validators, target_index)?;
+    if !has_execution_withdrawal_credential(source) {
+        return Err(InvalidConsolidation::SourceWithoutExecutionCredentials(source_index).into())
+    }
+    if !has_compounding_withdrawal_credential(target) {
+        return Err(InvalidConsolidation::TargetWithoutCompoundingCredentials(target_index).into())
+    }
+
+    let current_epoch = get_current_epoch(state, context);
+    check_active(source, source_index, current_epoch)?;
+    check_active(target, target_index, current_epoch)?;
+    let minimum_time_active = source.activation_epoch + context.shard_committee_period;
+    if current_epoch < minimum_time_active {
+        return Err(InvalidConsolidation::ValidatorIsNotActiveForLongEnough {
+            index: source_index,
+            current_epoch,
+            minimum_time_active,
+        }
+        .into())
+    }
+    let amount = get_pending_balance_to_withdraw(state, source_index);
+    if amount > 0 {
+        return Err(InvalidConsolidation::PendingWithdrawals { index: source_index, amount }.into())
+    }
+    Ok(())
+}
+
+// Plan the consolidations of each `(source_index, target_index)` in `consolidations`,
+// checking each is accepted when processed in order from `state` and building its request.
+pub fn plan_consolidations<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    consolidations: &[(ValidatorIndex, ValidatorIndex)],
+    context: &Context,
+) -> Result<Vec<PlannedConsolidation>> {
+    let available = PENDING_CONSOLIDATIONS_LIMIT - state.pending_consolidations.len();
+    if consolidations.len() > available {
+        return Err(
+            InvalidConsolidation::QueueFull { requested: consolidations.len(), available }.into()
+        )
+    }
+    let churn_limit = get_consolidation_churn_limit(state, context)?;
+    if churn_limit <= context.min_activation_balance {
+        return Err(InvalidConsolidation::InsufficientChurn {
+            churn_limit,
+            minimum: context.min_activation_balance,
+        }
+        .into())
+    }
+
+    // a source exits, so it can neither consolidate twice nor receive another consolidation
+    let mut sources = HashSet::new();
+    let mut targets = HashSet::new();
+    for &(source_index, target_index) in consolidations {
+        if targets.contains(&source_index) || !sources.insert(source_index) {
+            return Err(InvalidConsolidation::AlreadyPlanned(source_index).into())
+        }
+        if sources.contains(&target_index) {
+            return Err(InvalidConsolidation::AlreadyPlanned(target_index).into())
+        }
+        targets.insert(target_index);
+    }
+
+    // mirrors `compute_consolidation_epoch_and_update_churn` without mutating the state
+    let current_epoch = get_current_epoch(state, context);
+    let mut earliest_consolidation_epoch = u64::max(
+        state.earliest_consolidation_epoch,
+        compute_activation_exit_epoch(current_epoch, context),
+    );
+    let mut balance_to_consume: Gwei =
+        if state.earliest_consolidation_epoch < earliest_consolidation_epoch {
+            churn_limit
+        } else {
+            state.consolidation_balance_to_consume
+        };
+
+    let mut plan = Vec::with_capacity(consolidations.len());
+    for &(source_index, target_index) in consolidations {
+        check_consolidation(state, source_index, target_index, context)?;
+        let source = &state.validators[source_index];
+        let target = &state.validators[target_index];
+
+        let balance = source.effective_balance;
+        if balance > balance_to_consume {
+            let additional_epochs = (balance - balance_to_consume - 1) / churn_limit + 1;
+            earliest_consolidation_epoch += additional_epochs;
+            balance_to_consume += additional_epochs * churn_limit;
+        }
+        balance_to_consume -= balance;
+
+        let source_address = ExecutionAddress::try_from(&source.withdrawal_credentials[12..])
+            .expect("withdrawal credentials end in an execution address");
+        let request = ConsolidationRequest {
+            source_address,
+            source_public_key: source.public_key.clone(),
+            target_public_key: target.public_key.clone(),
+        };
+        plan.push(PlannedConsolidation {
+            source_index,
+            target_index,
+            request,
+            exit_epoch: earliest_consolidation_epoch,
+        });
+    }
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electra::{compute_consolidation_epoch_and_update_churn, minimal::BeaconState},
+        primitives::{COMPOUNDING_WITHDRAWAL_PREFIX, ETH1_ADDRESS_WITHDRAWAL_PREFIX},
+        Error,
+    };
+
+    fn validator(prefix: u8, effective_balance: Gwei) -> Validator {
+        let mut validator = Validator {
+            effective_balance,
+            exit_epoch: FAR_FUTURE_EPOCH,
+            withdrawable_epoch: FAR_FUTURE_EPOCH,
+            ..Default::default()
+        };
+        validator.withdrawal_credentials[0] = prefix;
+        validator.withdrawal_credentials[12..].fill(prefix + 1);
+        validator
+    }
+
+    fn is_invalid(result: Result<Vec<PlannedConsolidation>>) -> bool {
+        matches!(result, Err(Error::InvalidConsolidation(..)))
+    }
+
+    #[test]
+    fn test_plan_consolidations() {
+        let context = Context::for_minimal();
+        let slot = context.shard_committee_period * context.slots_per_epoch;
+        let mut state = BeaconState { slot, ..Default::default() };
+        // enough stake for a consolidation churn above the minimum activation balance
+        for _ in 0..4 {
+            let validator =
+                validator(COMPOUNDING_WITHDRAWAL_PREFIX, context.max_effective_balance_electra);
+            state.validators.push(validator).unwrap();
+        }
+        for _ in 0..3 {
+            let validator =
+                validator(ETH1_ADDRESS_WITHDRAWAL_PREFIX, context.min_activation_balance);
+            state.validators.push(validator).unwrap();
+        }
+        let balances = state.validators.iter().map(|v| v.effective_balance).collect::<Vec<_>>();
+        for balance in balances {
+            state.balances.push(balance).unwrap();
+        }
+
+        let consolidations = [(4, 0), (5, 0), (6, 1)];
+        let plan = plan_consolidations(&state, &consolidations, &context).unwrap();
+        assert_eq!(plan.len(), 3);
+        for (planned, (source_index, target_index)) in plan.iter().zip(consolidations) {
+            assert_eq!(planned.source_index, source_index);
+            assert_eq!(planned.target_index, target_index);
+            assert_eq!(
+                planned.request.source_public_key,
+                state.validators[source_index].public_key
+            );
+            assert_eq!(
+                planned.request.source_address[..],
+                [ETH1_ADDRESS_WITHDRAWAL_PREFIX + 1; 20]
+            );
+            let exit_epoch = compute_consolidation_epoch_and_update_churn(
+                &mut state,
+                context.min_activation_balance,
+                &context,
+            )
+            .unwrap();
+            assert_eq!(planned.exit_epoch, exit_epoch);
+        }
+
+        // a source cannot be used twice nor be the target of another consolidation
+        assert!(is_invalid(plan_consolidations(&state, &[(4, 0), (4, 1)], &context)));
+        assert!(is_invalid(plan_consolidations(&state, &[(4, 0), (5, 4)], &context)));
+        // the target must have compounding credentials and the source execution credentials
+        assert!(is_invalid(plan_consolidations(&state, &[(4, 5)], &context)));
+        assert!(is_invalid(plan_consolidations(&state, &[(7, 0)], &context)));
+        state.validators[6].exit_epoch = 100;
+        assert!(is_invalid(plan_consolidations(&state, &[(6, 0)], &context)));
+        state.slot = 0;
+        assert!(is_invalid(plan_consolidations(&state, &[(5, 0)], &context)));
+    }
+}
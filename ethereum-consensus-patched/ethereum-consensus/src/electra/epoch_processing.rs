@@ -0,0 +1,422 @@
+use crate::{
+    electra::{
+        add_validator_to_registry, compute_activation_exit_epoch, compute_start_slot_at_epoch,
+        decrease_balance, get_activation_exit_churn_limit, get_current_epoch,
+        get_max_effective_balance, get_total_active_balance, increase_balance,
+        initiate_validator_exit, is_active_validator, is_eligible_for_activation,
+        is_eligible_for_activation_queue, is_valid_deposit_signature, process_eth1_data_reset,
+        process_historical_summaries_update, process_inactivity_updates,
+        process_justification_and_finalization, process_participation_flag_updates,
+        process_randao_mixes_reset, process_rewards_and_penalties, process_slashings_reset,
+        process_sync_committee_updates, BeaconState, PendingDeposit,
+    },
+    primitives::{Gwei, FAR_FUTURE_EPOCH, GENESIS_SLOT},
+    ssz::prelude::*,
+    state_transition::{Context, Result},
+};
+
+pub fn process_epoch<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    context: &Context,
+) -> Result<()> {
+    process_justification_and_finalization(state, context)?;
+    process_inactivity_updates(state, context)?;
+    process_rewards_and_penalties(state, context)?;
+    process_registry_updates(state, context)?;
+    process_slashings(state, context)?;
+    process_eth1_data_reset(state, context);
+    process_pending_deposits(state, context)?;
+    process_pending_consolidations(state, context)?;
+    process_effective_balance_updates(state, context);
+    process_slashings_reset(state, context);
+    process_randao_mixes_reset(state, context);
+    process_historical_summaries_update(state, context)?;
+    process_participation_flag_updates(state)?;
+    process_sync_committee_updates(state, context)?;
+    Ok(())
+}
+
+pub fn process_registry_updates<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    context: &Context,
+) -> Result<()> {
+    let current_epoch = get_current_epoch(state, context);
+    let activation_epoch = compute_activation_exit_epoch(current_epoch, context);
+    for i in 0..state.validators.len() {
+        let validator = &state.validators[i];
+        if is_eligible_for_activation_queue(validator, context) {
+            state.validators[i].activation_eligibility_epoch = current_epoch + 1;
+        } else if is_active_validator(validator, current_epoch) &&
+            validator.effective_balance <= context.ejection_balance
+        {
+            initiate_validator_exit(state, i, context)?;
+        } else if is_eligible_for_activation(state, validator) {
+            state.validators[i].activation_epoch = activation_epoch;
+        }
+    }
+    Ok(())
+}
+
+pub fn process_slashings<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    context: &Context,
+) -> Result<()> {
+    let epoch = get_current_epoch(state, context);
+    let total_balance = get_total_active_balance(state, context)?;
+    let adjusted_total_slashing_balance = Gwei::min(
+        state.slashings.iter().sum::<Gwei>() * context.proportional_slashing_multiplier_bellatrix,
+        total_balance,
+    );
+    let increment = context.effective_balance_increment;
+    let penalty_per_effective_balance_increment =
+        adjusted_total_slashing_balance / (total_balance / increment);
+    for i in 0..state.validators.len() {
+        let validator = &state.validators[i];
+        if validator.slashed &&
+            (epoch + context.epochs_per_slashings_vector / 2) == validator.withdrawable_epoch
+        {
+            let effective_balance_increments = validator.effective_balance / increment;
+            let penalty = penalty_per_effective_balance_increment * effective_balance_increments;
+            decrease_balance(state, i, penalty);
+        }
+    }
+    Ok(())
+}
+
+pub fn apply_pending_deposit<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    deposit: &PendingDeposit,
+    context: &Context,
+) -> Result<()> {
+    match state.validators.iter().position(|v| v.public_key == deposit.public_key) {
+        Some(index) => increase_balance(state, index, deposit.amount),
+        None => {
+            // the deposit contract does not check the signature (proof of possession)
+            if is_valid_deposit_signature(
+                &deposit.public_key,
+                &deposit.withdrawal_credentials,
+                deposit.amount,
+                &deposit.signature,
+                context,
+            )? {
+                add_validator_to_registry(
+                    state,
+                    &deposit.public_key,
+                    &deposit.withdrawal_credentials,
+                    deposit.amount,
+                    context,
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn process_pending_deposits<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    context: &Context,
+) -> Result<()> {
+    let next_epoch = get_current_epoch(state, context) + 1;
+    let available_for_processing =
+        state.deposit_balance_to_consume + get_activation_exit_churn_limit(state, context)?;
+    let mut processed_amount = 0;
+    let mut next_deposit_index = 0;
+    let mut deposits_to_postpone = vec![];
+    let mut is_churn_limit_reached = false;
+    let finalized_slot = compute_start_slot_at_epoch(state.finalized_checkpoint.epoch, context);
+
+    let pending_deposits = state.pending_deposits.to_vec();
+    for deposit in pending_deposits.iter() {
+        // deposit requests wait until all deposits from the eth1 bridge have been applied
+        if deposit.slot > GENESIS_SLOT &&
+            state.eth1_deposit_index < state.deposit_requests_start_index
+        {
+            break
+        }
+        if deposit.slot > finalized_slot {
+            break
+        }
+        if next_deposit_index >= context.max_pending_deposits_per_epoch {
+            break
+        }
+
+        let (is_validator_exited, is_validator_withdrawn) = state
+            .validators
+            .iter()
+            .find(|v| v.public_key == deposit.public_key)
+            .map(|validator| {
+                (validator.exit_epoch < FAR_FUTURE_EPOCH, validator.withdrawable_epoch < next_epoch)
+            })
+            .unwrap_or_default();
+
+        if is_validator_withdrawn {
+            // the deposited balance will never become active so no churn is consumed
+            apply_pending_deposit(state, deposit, context)?;
+        } else if is_validator_exited {
+            deposits_to_postpone.push(deposit.clone());
+        } else {
+            is_churn_limit_reached = processed_amount + deposit.amount > available_for_processing;
+            if is_churn_limit_reached {
+                break
+            }
+            processed_amount += deposit.amount;
+            apply_pending_deposit(state, deposit, context)?;
+        }
+        next_deposit_index += 1;
+    }
+
+    state.pending_deposits = List::try_from_iter(
+        pending_deposits.into_iter().skip(next_deposit_index).chain(deposits_to_postpone),
+    )?;
+    state.deposit_balance_to_consume =
+        if is_churn_limit_reached { available_for_processing - processed_amount } else { 0 };
+    Ok(())
+}
+
+pub fn process_pending_consolidations<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    context: &Context,
+) -> Result<()> {
+    let next_epoch = get_current_epoch(state, context) + 1;
+    let mut next_pending_consolidation = 0;
+    for i in 0..state.pending_consolidations.len() {
+        let source_index = state.pending_consolidations[i].source_index;
+        let target_index = state.pending_consolidations[i].target_index;
+        let source_validator = &state.validators[source_index];
+        if source_validator.slashed {
+            next_pending_consolidation += 1;
+            continue
+        }
+        if source_validator.withdrawable_epoch > next_epoch {
+            break
+        }
+        // only the active balance moves to the target, any excess stays withdrawable
+        let source_effective_balance =
+            Gwei::min(state.balances[source_index], source_validator.effective_balance);
+        decrease_balance(state, source_index, source_effective_balance);
+        increase_balance(state, target_index, source_effective_balance);
+        next_pending_consolidation += 1;
+    }
+    state.pending_consolidations = List::try_from_iter(
+        state.pending_consolidations.iter().skip(next_pending_consolidation).cloned(),
+    )?;
+    Ok(())
+}
+
+pub fn process_effective_balance_updates<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    context: &Context,
+) {
+    let hysteresis_increment = context.effective_balance_increment / context.hysteresis_quotient;
+    let downward_threshold = hysteresis_increment * context.hysteresis_downward_multiplier;
+    let upward_threshold = hysteresis_increment * context.hysteresis_upward_multiplier;
+    for i in 0..state.validators.len() {
+        let validator = &mut state.validators[i];
+        let balance = state.balances[i];
+        let max_effective_balance = get_max_effective_balance(validator, context);
+        if balance + downward_threshold < validator.effective_balance ||
+            validator.effective_balance + upward_threshold < balance
+        {
+            validator.effective_balance = Gwei::min(
+                balance - balance % context.effective_balance_increment,
+                max_effective_balance,
+            );
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{
+    deneb::{execution_payload::ExecutionPayload, polynomial_commitments::VersionedHash},
+    electra::{
+        constants::{CONSOLIDATION_REQUEST_TYPE, DEPOSIT_REQUEST_TYPE, WITHDRAWAL_REQUEST_TYPE},
+        operations::ExecutionRequests,
+    },
+    primitives::Root,
+    ssz::prelude::*,
+    state_transition::{PayloadStatus, Result},
+};
+
+pub struct NewPayloadRequest<
+    'a,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+> {
+    pub execution_payload: &'a ExecutionPayload<
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+    >,
+    pub versioned_hashes: &'a [VersionedHash],
+    pub parent_beacon_block_root: Root,
+    pub execution_requests: &'a ExecutionRequests<
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >,
+}
+
+pub trait ExecutionEngine<
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+>
+{
+    fn verify_and_notify_new_payload(
+        &self,
+        new_payload_request: &NewPayloadRequest<
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    ) -> Result<PayloadStatus>;
+}
+
+// Encodes each non-empty list of requests as its type byte followed by the SSZ serialization
+// of the list, the form in which requests are handed to the execution layer.
+pub fn get_execution_requests_list<
+    const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+>(
+    execution_requests: &ExecutionRequests<
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >,
+) -> Result<Vec<Vec<u8>>> {
+    let mut requests_list = vec![];
+    for (request_type, request_data) in [
+        (
+            DEPOSIT_REQUEST_TYPE,
+            serialize(&execution_requests.deposits)
+                .map_err(|err| -> SimpleSerializeError { err.into() })?,
+        ),
+        (
+            WITHDRAWAL_REQUEST_TYPE,
+            serialize(&execution_requests.withdrawals)
+                .map_err(|err| -> SimpleSerializeError { err.into() })?,
+        ),
+        (
+            CONSOLIDATION_REQUEST_TYPE,
+            serialize(&execution_requests.consolidations)
+                .map_err(|err| -> SimpleSerializeError { err.into() })?,
+        ),
+    ] {
+        // a list of requests encodes to no bytes iff it is empty
+        if !request_data.is_empty() {
+            let mut request = Vec::with_capacity(1 + request_data.len());
+            request.push(request_type);
+            request.extend(request_data);
+            requests_list.push(request);
+        }
+    }
+    Ok(requests_list)
+}
+
+// The `DefaultExecutionEngine` performs no operations and reports the
+// `payload_status` it was configured with for every payload.
+#[derive(Debug)]
+pub struct DefaultExecutionEngine {
+    payload_status: PayloadStatus,
+}
+
+impl Default for DefaultExecutionEngine {
+    fn default() -> Self {
+        Self { payload_status: PayloadStatus::Valid }
+    }
+}
+
+impl DefaultExecutionEngine {
+    pub fn new(execution_is_valid: bool) -> Self {
+        let payload_status =
+            if execution_is_valid { PayloadStatus::Valid } else { PayloadStatus::InvalidBlockHash };
+        Self { payload_status }
+    }
+
+    pub fn with_payload_status(payload_status: PayloadStatus) -> Self {
+        Self { payload_status }
+    }
+
+    fn is_valid_block_hash<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    >(
+        &self,
+        _payload: &ExecutionPayload<
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+        >,
+        _parent_beacon_block_root: &Root,
+        _execution_requests_list: &[Vec<u8>],
+    ) -> bool {
+        self.payload_status != PayloadStatus::InvalidBlockHash
+    }
+
+    fn is_valid_versioned_hashes<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+    >(
+        &self,
+        _new_payload_request: &NewPayloadRequest<
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    ) -> bool {
+        !self.payload_status.is_invalid()
+    }
+
+    fn notify_new_payload<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    >(
+        &self,
+        _payload: &ExecutionPayload<
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+        >,
+        _parent_beacon_block_root: &Root,
+        _execution_requests_list: &[Vec<u8>],
+    ) -> PayloadStatus {
+        self.payload_status.clone()
+    }
+}
+
+impl<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+    >
+    ExecutionEngine<
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    > for DefaultExecutionEngine
+{
+    fn verify_and_notify_new_payload(
+        &self,
+        new_payload_request: &NewPayloadRequest<
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    ) -> Result<PayloadStatus> {
+        let execution_requests_list =
+            get_execution_requests_list(new_payload_request.execution_requests)?;
+
+        if !self.is_valid_block_hash(
+            new_payload_request.execution_payload,
+            &new_payload_request.parent_beacon_block_root,
+            &execution_requests_list,
+        ) {
+            return Ok(PayloadStatus::InvalidBlockHash)
+        }
+
+        if !self.is_valid_versioned_hashes(new_payload_request) {
+            return Ok(PayloadStatus::Invalid { latest_valid_hash: None })
+        }
+
+        Ok(self.notify_new_payload(
+            new_payload_request.execution_payload,
+            &new_payload_request.parent_beacon_block_root,
+            &execution_requests_list,
+        ))
+    }
+}
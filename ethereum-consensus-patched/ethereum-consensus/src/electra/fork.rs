@@ -0,0 +1,186 @@
+use crate::{
+    deneb,
+    electra::{
+        compute_activation_exit_epoch, constants::UNSET_DEPOSIT_REQUESTS_START_INDEX,
+        get_activation_exit_churn_limit, get_consolidation_churn_limit,
+        has_compounding_withdrawal_credential, queue_excess_active_balance, BeaconState, Fork,
+        PendingDeposit,
+    },
+    primitives::{BlsSignature, FAR_FUTURE_EPOCH, GENESIS_SLOT},
+    state_transition::{Context, Result},
+};
+
+pub fn upgrade_to_electra<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &deneb::BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    context: &Context,
+) -> Result<
+    BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+> {
+    let epoch = deneb::get_current_epoch(state, context);
+    let earliest_exit_epoch = state
+        .validators
+        .iter()
+        .map(|validator| validator.exit_epoch)
+        .filter(|&exit_epoch| exit_epoch != FAR_FUTURE_EPOCH)
+        .max()
+        .unwrap_or(epoch) +
+        1;
+    let mut post = BeaconState {
+        genesis_time: state.genesis_time,
+        genesis_validators_root: state.genesis_validators_root,
+        slot: state.slot,
+        fork: Fork {
+            previous_version: state.fork.current_version,
+            current_version: context.electra_fork_version,
+            epoch,
+        },
+        latest_block_header: state.latest_block_header.clone(),
+        block_roots: state.block_roots.clone(),
+        state_roots: state.state_roots.clone(),
+        historical_roots: state.historical_roots.clone(),
+        eth1_data: state.eth1_data.clone(),
+        eth1_data_votes: state.eth1_data_votes.clone(),
+        eth1_deposit_index: state.eth1_deposit_index,
+        validators: state.validators.clone(),
+        balances: state.balances.clone(),
+        randao_mixes: state.randao_mixes.clone(),
+        slashings: state.slashings.clone(),
+        previous_epoch_participation: state.previous_epoch_participation.clone(),
+        current_epoch_participation: state.current_epoch_participation.clone(),
+        justification_bits: state.justification_bits.clone(),
+        previous_justified_checkpoint: state.previous_justified_checkpoint.clone(),
+        current_justified_checkpoint: state.current_justified_checkpoint.clone(),
+        finalized_checkpoint: state.finalized_checkpoint.clone(),
+        inactivity_scores: state.inactivity_scores.clone(),
+        current_sync_committee: state.current_sync_committee.clone(),
+        next_sync_committee: state.next_sync_committee.clone(),
+        latest_execution_payload_header: state.latest_execution_payload_header.clone(),
+        next_withdrawal_index: state.next_withdrawal_index,
+        next_withdrawal_validator_index: state.next_withdrawal_validator_index,
+        historical_summaries: state.historical_summaries.clone(),
+        deposit_requests_start_index: UNSET_DEPOSIT_REQUESTS_START_INDEX,
+        deposit_balance_to_consume: 0,
+        exit_balance_to_consume: 0,
+        earliest_exit_epoch,
+        consolidation_balance_to_consume: 0,
+        earliest_consolidation_epoch: compute_activation_exit_epoch(epoch, context),
+        pending_deposits: Default::default(),
+        pending_partial_withdrawals: Default::default(),
+        pending_consolidations: Default::default(),
+    };
+    post.exit_balance_to_consume = get_activation_exit_churn_limit(&post, context)?;
+    post.consolidation_balance_to_consume = get_consolidation_churn_limit(&post, context)?;
+
+    // validators that are not yet active go through the new deposit queue
+    let mut pre_activation = post
+        .validators
+        .iter()
+        .enumerate()
+        .filter(|(_, validator)| validator.activation_epoch == FAR_FUTURE_EPOCH)
+        .map(|(index, validator)| (validator.activation_eligibility_epoch, index))
+        .collect::<Vec<_>>();
+    pre_activation.sort_unstable();
+    for (_, index) in pre_activation {
+        let balance = post.balances[index];
+        post.balances[index] = 0;
+        let validator = &mut post.validators[index];
+        validator.effective_balance = 0;
+        validator.activation_eligibility_epoch = FAR_FUTURE_EPOCH;
+        // the infinity signature is a placeholder and `GENESIS_SLOT` distinguishes these
+        // deposits from those made by deposit requests
+        let pending_deposit = PendingDeposit {
+            public_key: validator.public_key.clone(),
+            withdrawal_credentials: validator.withdrawal_credentials.clone(),
+            amount: balance,
+            signature: BlsSignature::infinity(),
+            slot: GENESIS_SLOT,
+        };
+        post.pending_deposits.push(pending_deposit)?;
+    }
+
+    // early adopters of compounding credentials still go through the activation churn
+    for index in 0..post.validators.len() {
+        if has_compounding_withdrawal_credential(&post.validators[index]) {
+            queue_excess_active_balance(&mut post, index, context)?;
+        }
+    }
+
+    Ok(post)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{deneb::minimal as deneb_minimal, electra::minimal, phase0::Validator};
+
+    #[test]
+    fn test_upgrade_queues_pending_validators() {
+        let context = Context::for_minimal();
+        let mut state = deneb_minimal::BeaconState::default();
+        let balance = context.max_effective_balance;
+        let active = Validator {
+            effective_balance: balance,
+            exit_epoch: FAR_FUTURE_EPOCH,
+            withdrawable_epoch: FAR_FUTURE_EPOCH,
+            ..Default::default()
+        };
+        let pending = Validator {
+            activation_eligibility_epoch: 0,
+            activation_epoch: FAR_FUTURE_EPOCH,
+            ..active.clone()
+        };
+        for validator in [active, pending] {
+            state.validators.push(validator).unwrap();
+            state.balances.push(balance).unwrap();
+        }
+
+        let post: minimal::BeaconState = upgrade_to_electra(&state, &context).unwrap();
+        assert_eq!(post.fork.current_version, context.electra_fork_version);
+        assert_eq!(post.deposit_requests_start_index, UNSET_DEPOSIT_REQUESTS_START_INDEX);
+        assert_eq!(post.balances[0], balance);
+        assert_eq!(post.balances[1], 0);
+        assert_eq!(post.validators[1].effective_balance, 0);
+        assert_eq!(post.pending_deposits.len(), 1);
+        assert_eq!(post.pending_deposits[0].amount, balance);
+        assert_eq!(post.pending_deposits[0].slot, GENESIS_SLOT);
+    }
+}
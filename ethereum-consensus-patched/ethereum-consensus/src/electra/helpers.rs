@@ -0,0 +1,758 @@
+use crate::{
+    crypto::hash,
+    domains::DomainType,
+    electra::{
+        beacon_state::BeaconState,
+        compute_activation_exit_epoch, compute_shuffled_index, decrease_balance,
+        get_active_validator_indices, get_beacon_committee, get_beacon_proposer_index,
+        get_current_epoch, get_seed, get_total_active_balance, has_eth1_withdrawal_credential,
+        increase_balance,
+        operations::{Attestation, IndexedAttestation, PendingDeposit},
+        Validator, PROPOSER_WEIGHT, WEIGHT_DENOMINATOR,
+    },
+    error::{invalid_operation_error, InvalidAttestation, InvalidOperation},
+    primitives::{
+        BlsSignature, Bytes32, CommitteeIndex, Epoch, Gwei, ValidatorIndex,
+        COMPOUNDING_WITHDRAWAL_PREFIX, FAR_FUTURE_EPOCH, GENESIS_SLOT,
+    },
+    ssz::prelude::*,
+    state_transition::{Context, Result},
+    Error,
+};
+use std::collections::HashSet;
+
+pub fn is_eligible_for_activation_queue(validator: &Validator, context: &Context) -> bool {
+    validator.activation_eligibility_epoch == FAR_FUTURE_EPOCH &&
+        validator.effective_balance >= context.min_activation_balance
+}
+
+pub fn is_compounding_withdrawal_credential(withdrawal_credentials: &Bytes32) -> bool {
+    withdrawal_credentials[0] == COMPOUNDING_WITHDRAWAL_PREFIX
+}
+
+pub fn has_compounding_withdrawal_credential(validator: &Validator) -> bool {
+    is_compounding_withdrawal_credential(&validator.withdrawal_credentials)
+}
+
+pub fn has_execution_withdrawal_credential(validator: &Validator) -> bool {
+    has_compounding_withdrawal_credential(validator) || has_eth1_withdrawal_credential(validator)
+}
+
+pub fn is_fully_withdrawable_validator(validator: &Validator, balance: Gwei, epoch: Epoch) -> bool {
+    has_execution_withdrawal_credential(validator) &&
+        validator.withdrawable_epoch <= epoch &&
+        balance > 0
+}
+
+pub fn is_partially_withdrawable_validator(
+    validator: &Validator,
+    balance: Gwei,
+    context: &Context,
+) -> bool {
+    let max_effective_balance = get_max_effective_balance(validator, context);
+    let has_max_effective_balance = validator.effective_balance == max_effective_balance;
+    let has_excess_balance = balance > max_effective_balance;
+    has_execution_withdrawal_credential(validator) &&
+        has_max_effective_balance &&
+        has_excess_balance
+}
+
+pub fn get_committee_indices<const MAX_COMMITTEES_PER_SLOT: usize>(
+    committee_bits: &Bitvector<MAX_COMMITTEES_PER_SLOT>,
+) -> Vec<CommitteeIndex> {
+    committee_bits.iter().enumerate().filter_map(|(index, bit)| bit.then_some(index)).collect()
+}
+
+pub fn get_max_effective_balance(validator: &Validator, context: &Context) -> Gwei {
+    if has_compounding_withdrawal_credential(validator) {
+        context.max_effective_balance_electra
+    } else {
+        context.min_activation_balance
+    }
+}
+
+pub fn get_balance_churn_limit<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    context: &Context,
+) -> Result<Gwei> {
+    let churn = u64::max(
+        context.min_per_epoch_churn_limit_electra,
+        get_total_active_balance(state, context)? / context.churn_limit_quotient,
+    );
+    Ok(churn - churn % context.effective_balance_increment)
+}
+
+pub fn get_activation_exit_churn_limit<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    context: &Context,
+) -> Result<Gwei> {
+    Ok(u64::min(
+        context.max_per_epoch_activation_exit_churn_limit,
+        get_balance_churn_limit(state, context)?,
+    ))
+}
+
+pub fn get_consolidation_churn_limit<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    context: &Context,
+) -> Result<Gwei> {
+    Ok(get_balance_churn_limit(state, context)? - get_activation_exit_churn_limit(state, context)?)
+}
+
+pub fn get_pending_balance_to_withdraw<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    validator_index: ValidatorIndex,
+) -> Gwei {
+    state
+        .pending_partial_withdrawals
+        .iter()
+        .filter(|withdrawal| withdrawal.validator_index == validator_index)
+        .map(|withdrawal| withdrawal.amount)
+        .sum()
+}
+
+pub fn get_attesting_indices<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    attestation: &Attestation<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+    context: &Context,
+) -> Result<HashSet<ValidatorIndex>> {
+    let bits = &attestation.aggregation_bits;
+    let mut indices = HashSet::with_capacity(bits.len());
+    let mut committee_offset = 0;
+    for committee_index in get_committee_indices(&attestation.committee_bits) {
+        let committee =
+            get_beacon_committee(state, attestation.data.slot, committee_index, context)?;
+        if committee_offset + committee.len() > bits.len() {
+            return Err(invalid_operation_error(InvalidOperation::Attestation(
+                InvalidAttestation::Bitfield {
+                    expected_length: committee_offset + committee.len(),
+                    length: bits.len(),
+                },
+            )))
+        }
+        for (i, validator_index) in committee.iter().enumerate() {
+            if bits[committee_offset + i] {
+                indices.insert(*validator_index);
+            }
+        }
+        committee_offset += committee.len();
+    }
+    Ok(indices)
+}
+
+pub fn get_indexed_attestation<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    attestation: &Attestation<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+    context: &Context,
+) -> Result<IndexedAttestation<MAX_VALIDATORS_PER_SLOT>> {
+    let mut attesting_indices =
+        get_attesting_indices(state, attestation, context)?.into_iter().collect::<Vec<_>>();
+    attesting_indices.sort_unstable();
+    let attesting_indices = attesting_indices.try_into().map_err(|(_, err)| err)?;
+    Ok(IndexedAttestation {
+        attesting_indices,
+        data: attestation.data.clone(),
+        signature: attestation.signature.clone(),
+    })
+}
+
+pub fn compute_proposer_index<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    indices: &[ValidatorIndex],
+    seed: &Bytes32,
+    context: &Context,
+) -> Result<ValidatorIndex> {
+    if indices.is_empty() {
+        return Err(Error::CollectionCannotBeEmpty)
+    }
+    let max_random_value = u16::MAX as u64;
+    let mut i = 0;
+    let total = indices.len();
+    let mut hash_input = [0u8; 40];
+    hash_input[..32].copy_from_slice(seed.as_ref());
+    loop {
+        let shuffled_index = compute_shuffled_index(i % total, total, seed, context)?;
+        let candidate_index = indices[shuffled_index];
+        let i_bytes: [u8; 8] = (i / 16).to_le_bytes();
+        hash_input[32..].copy_from_slice(&i_bytes);
+        let random_bytes = hash(hash_input);
+        let offset = i % 16 * 2;
+        let random_value =
+            u16::from_le_bytes([random_bytes[offset], random_bytes[offset + 1]]) as u64;
+        let effective_balance = state.validators[candidate_index].effective_balance;
+        if effective_balance * max_random_value >=
+            context.max_effective_balance_electra * random_value
+        {
+            return Ok(candidate_index)
+        }
+        i += 1;
+    }
+}
+
+pub fn compute_sync_committee_indices<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    epoch: Epoch,
+    context: &Context,
+) -> Result<Vec<ValidatorIndex>> {
+    let max_random_value = u16::MAX as u64;
+    let active_validator_indices = get_active_validator_indices(state, epoch);
+    let active_validator_count = active_validator_indices.len();
+    let seed = get_seed(state, epoch, DomainType::SyncCommittee, context);
+    let mut i: usize = 0;
+    let mut sync_committee_indices = vec![];
+    let mut hash_input = [0u8; 40];
+    hash_input[..32].copy_from_slice(seed.as_ref());
+    while sync_committee_indices.len() < context.sync_committee_size {
+        let shuffled_index = compute_shuffled_index(
+            i % active_validator_count,
+            active_validator_count,
+            &seed,
+            context,
+        )?;
+        let candidate_index = active_validator_indices[shuffled_index];
+        let i_bytes: [u8; 8] = ((i / 16) as u64).to_le_bytes();
+        hash_input[32..].copy_from_slice(&i_bytes);
+        let random_bytes = hash(hash_input);
+        let offset = i % 16 * 2;
+        let random_value =
+            u16::from_le_bytes([random_bytes[offset], random_bytes[offset + 1]]) as u64;
+        let effective_balance = state.validators[candidate_index].effective_balance;
+        if effective_balance * max_random_value >=
+            context.max_effective_balance_electra * random_value
+        {
+            sync_committee_indices.push(candidate_index);
+        }
+        i += 1;
+    }
+    Ok(sync_committee_indices)
+}
+
+pub fn initiate_validator_exit<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    index: ValidatorIndex,
+    context: &Context,
+) -> Result<()> {
+    if state.validators[index].exit_epoch != FAR_FUTURE_EPOCH {
+        return Ok(())
+    }
+    let exit_queue_epoch = compute_exit_epoch_and_update_churn(
+        state,
+        state.validators[index].effective_balance,
+        context,
+    )?;
+    let validator = &mut state.validators[index];
+    validator.exit_epoch = exit_queue_epoch;
+    validator.withdrawable_epoch = exit_queue_epoch + context.min_validator_withdrawability_delay;
+    Ok(())
+}
+
+pub fn switch_to_compounding_validator<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    index: ValidatorIndex,
+    context: &Context,
+) -> Result<()> {
+    state.validators[index].withdrawal_credentials[0] = COMPOUNDING_WITHDRAWAL_PREFIX;
+    queue_excess_active_balance(state, index, context)
+}
+
+pub fn queue_excess_active_balance<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    index: ValidatorIndex,
+    context: &Context,
+) -> Result<()> {
+    let balance = state.balances[index];
+    if balance > context.min_activation_balance {
+        let excess_balance = balance - context.min_activation_balance;
+        state.balances[index] = context.min_activation_balance;
+        let validator = &state.validators[index];
+        // NOTE: the infinity signature marks this deposit as having been checked already
+        let pending_deposit = PendingDeposit {
+            public_key: validator.public_key.clone(),
+            withdrawal_credentials: validator.withdrawal_credentials.clone(),
+            amount: excess_balance,
+            signature: BlsSignature::infinity(),
+            slot: GENESIS_SLOT,
+        };
+        state.pending_deposits.push(pending_deposit)?;
+    }
+    Ok(())
+}
+
+pub fn compute_exit_epoch_and_update_churn<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    exit_balance: Gwei,
+    context: &Context,
+) -> Result<Epoch> {
+    let mut earliest_exit_epoch = u64::max(
+        state.earliest_exit_epoch,
+        compute_activation_exit_epoch(get_current_epoch(state, context), context),
+    );
+    let per_epoch_churn = get_activation_exit_churn_limit(state, context)?;
+    let mut exit_balance_to_consume = if state.earliest_exit_epoch < earliest_exit_epoch {
+        per_epoch_churn
+    } else {
+        state.exit_balance_to_consume
+    };
+    if exit_balance > exit_balance_to_consume {
+        let balance_to_process = exit_balance - exit_balance_to_consume;
+        let additional_epochs = (balance_to_process - 1) / per_epoch_churn + 1;
+        earliest_exit_epoch += additional_epochs;
+        exit_balance_to_consume += additional_epochs * per_epoch_churn;
+    }
+    state.exit_balance_to_consume = exit_balance_to_consume - exit_balance;
+    state.earliest_exit_epoch = earliest_exit_epoch;
+    Ok(state.earliest_exit_epoch)
+}
+
+pub fn compute_consolidation_epoch_and_update_churn<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    consolidation_balance: Gwei,
+    context: &Context,
+) -> Result<Epoch> {
+    let mut earliest_consolidation_epoch = u64::max(
+        state.earliest_consolidation_epoch,
+        compute_activation_exit_epoch(get_current_epoch(state, context), context),
+    );
+    let per_epoch_consolidation_churn = get_consolidation_churn_limit(state, context)?;
+    let mut consolidation_balance_to_consume =
+        if state.earliest_consolidation_epoch < earliest_consolidation_epoch {
+            per_epoch_consolidation_churn
+        } else {
+            state.consolidation_balance_to_consume
+        };
+    if consolidation_balance > consolidation_balance_to_consume {
+        let balance_to_process = consolidation_balance - consolidation_balance_to_consume;
+        let additional_epochs = (balance_to_process - 1) / per_epoch_consolidation_churn + 1;
+        earliest_consolidation_epoch += additional_epochs;
+        consolidation_balance_to_consume += additional_epochs * per_epoch_consolidation_churn;
+    }
+    state.consolidation_balance_to_consume =
+        consolidation_balance_to_consume - consolidation_balance;
+    state.earliest_consolidation_epoch = earliest_consolidation_epoch;
+    Ok(state.earliest_consolidation_epoch)
+}
+
+pub fn slash_validator<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    slashed_index: ValidatorIndex,
+    whistleblower_index: Option<ValidatorIndex>,
+    context: &Context,
+) -> Result<()> {
+    let epoch = get_current_epoch(state, context);
+    initiate_validator_exit(state, slashed_index, context)?;
+    let validator = &mut state.validators[slashed_index];
+    validator.slashed = true;
+    validator.withdrawable_epoch =
+        u64::max(validator.withdrawable_epoch, epoch + context.epochs_per_slashings_vector);
+    let effective_balance = validator.effective_balance;
+    let slashings_index = epoch as usize % EPOCHS_PER_SLASHINGS_VECTOR;
+    state.slashings[slashings_index] += effective_balance;
+    decrease_balance(
+        state,
+        slashed_index,
+        effective_balance / context.min_slashing_penalty_quotient_electra,
+    );
+    let proposer_index = get_beacon_proposer_index(state, context)?;
+    let whistleblower_index = whistleblower_index.unwrap_or(proposer_index);
+    let whistleblower_reward = effective_balance / context.whistleblower_reward_quotient_electra;
+    let proposer_reward = whistleblower_reward * PROPOSER_WEIGHT / WEIGHT_DENOMINATOR;
+    increase_balance(state, proposer_index, proposer_reward);
+    increase_balance(state, whistleblower_index, whistleblower_reward - proposer_reward);
+    Ok(())
+}
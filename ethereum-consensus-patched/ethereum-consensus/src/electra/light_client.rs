@@ -0,0 +1,218 @@
+use crate::{
+    altair::sync::{SyncAggregate, SyncCommittee},
+    deneb::light_client::LightClientHeader,
+    primitives::{Bytes32, Slot},
+    ssz::prelude::*,
+};
+
+// The `BeaconState` grew past 32 fields in electra, so its fields are one level deeper.
+pub const FINALIZED_ROOT_INDEX: usize = 169;
+pub const FINALIZED_ROOT_INDEX_FLOOR_LOG_2: usize = 7;
+
+pub const CURRENT_SYNC_COMMITTEE_INDEX: usize = 86;
+pub const CURRENT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2: usize = 6;
+
+pub const NEXT_SYNC_COMMITTEE_INDEX: usize = 87;
+pub const NEXT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2: usize = 6;
+
+#[derive(Default, Debug, Clone, SimpleSerialize, serde::Serialize, serde::Deserialize)]
+pub struct LightClientBootstrap<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub current_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    pub current_sync_committee_branch: Vector<Bytes32, CURRENT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2>,
+}
+
+#[derive(Default, Debug, Clone, SimpleSerialize, serde::Serialize, serde::Deserialize)]
+pub struct LightClientUpdate<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub attested_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub next_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    pub next_sync_committee_branch: Vector<Bytes32, NEXT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2>,
+    pub finalized_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub finality_branch: Vector<Bytes32, FINALIZED_ROOT_INDEX_FLOOR_LOG_2>,
+    pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    pub signature_slot: Slot,
+}
+
+#[derive(Default, Debug, Clone, SimpleSerialize, serde::Serialize, serde::Deserialize)]
+pub struct LightClientFinalityUpdate<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub attested_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub finalized_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub finality_branch: Vector<Bytes32, FINALIZED_ROOT_INDEX_FLOOR_LOG_2>,
+    pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    pub signature_slot: Slot,
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > From<&LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+    for LightClientFinalityUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    fn from(
+        update: &LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ) -> Self {
+        Self {
+            attested_header: update.attested_header.clone(),
+            finalized_header: update.finalized_header.clone(),
+            finality_branch: update.finality_branch.clone(),
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+        }
+    }
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > From<&LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+    for crate::deneb::light_client::LightClientOptimisticUpdate<
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    fn from(
+        update: &LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ) -> Self {
+        Self {
+            attested_header: update.attested_header.clone(),
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct LightClientStore<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    pub finalized_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub current_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    pub next_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    pub best_valid_update:
+        Option<LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
+    pub optimistic_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub previous_max_active_participants: u64,
+    pub current_max_active_participants: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        capella::light_client::{EXECUTION_PAYLOAD_INDEX, EXECUTION_PAYLOAD_INDEX_FLOOR_LOG_2},
+        electra::{
+            create_light_client_bootstrap, create_light_client_update,
+            minimal::{BeaconBlock, BeaconState},
+        },
+        mock::mock_with_context,
+        primitives::{BlsPublicKey, Root},
+        state_transition::Context,
+    };
+
+    fn verify_branch(
+        leaf: Node,
+        branch: &[Bytes32],
+        depth: usize,
+        generalized_index: usize,
+        root: Root,
+    ) -> bool {
+        let index = generalized_index % 2usize.pow(depth as u32);
+        is_valid_merkle_branch(leaf, branch, depth, index, root).is_ok()
+    }
+
+    #[test]
+    fn test_create_light_client_update() {
+        let context = Context::for_minimal();
+        let slots_per_period = context.slots_per_epoch * context.epochs_per_sync_committee_period;
+        let mut state = BeaconState { slot: 3 * slots_per_period + 5, ..Default::default() };
+        state.next_sync_committee.aggregate_public_key =
+            mock_with_context::<BlsPublicKey>(7, &context);
+        let finalized_block = BeaconBlock { slot: 8, ..Default::default() };
+        state.finalized_checkpoint.root = finalized_block.hash_tree_root().unwrap();
+        let state_root = state.hash_tree_root().unwrap();
+        let mut block = BeaconBlock { slot: state.slot, state_root, ..Default::default() };
+        block.body.execution_payload.block_number = 11;
+
+        let bootstrap = create_light_client_bootstrap(&state, &block).unwrap();
+        let header = &bootstrap.header;
+        assert_eq!(header.beacon.hash_tree_root().unwrap(), block.hash_tree_root().unwrap());
+        assert_eq!(header.execution.block_number, 11);
+        assert!(verify_branch(
+            header.execution.hash_tree_root().unwrap(),
+            &header.execution_branch,
+            EXECUTION_PAYLOAD_INDEX_FLOOR_LOG_2,
+            EXECUTION_PAYLOAD_INDEX,
+            header.beacon.body_root,
+        ));
+        assert_eq!(bootstrap.current_sync_committee_branch.len(), 6);
+        assert!(verify_branch(
+            bootstrap.current_sync_committee.hash_tree_root().unwrap(),
+            &bootstrap.current_sync_committee_branch,
+            CURRENT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2,
+            CURRENT_SYNC_COMMITTEE_INDEX,
+            state_root,
+        ));
+
+        let mut sync_aggregate = crate::electra::minimal::SyncAggregate::default();
+        sync_aggregate.sync_committee_bits.set(0, true);
+        let update = create_light_client_update(
+            &state,
+            &block,
+            Some(&finalized_block),
+            &sync_aggregate,
+            block.slot + 1,
+            &context,
+        )
+        .unwrap();
+        assert_eq!(update.next_sync_committee, state.next_sync_committee);
+        assert!(verify_branch(
+            update.next_sync_committee.hash_tree_root().unwrap(),
+            &update.next_sync_committee_branch,
+            NEXT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2,
+            NEXT_SYNC_COMMITTEE_INDEX,
+            state_root,
+        ));
+        // the branch of the current committee does not prove the next one
+        assert!(!verify_branch(
+            update.next_sync_committee.hash_tree_root().unwrap(),
+            &bootstrap.current_sync_committee_branch,
+            NEXT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2,
+            NEXT_SYNC_COMMITTEE_INDEX,
+            state_root,
+        ));
+        assert_eq!(
+            update.finalized_header.beacon.hash_tree_root().unwrap(),
+            state.finalized_checkpoint.root
+        );
+        assert_eq!(update.finality_branch.len(), 7);
+        assert!(verify_branch(
+            state.finalized_checkpoint.root,
+            &update.finality_branch,
+            FINALIZED_ROOT_INDEX_FLOOR_LOG_2,
+            FINALIZED_ROOT_INDEX,
+            state_root,
+        ));
+        let finality_update = LightClientFinalityUpdate::from(&update);
+        assert_eq!(finality_update.finality_branch, update.finality_branch);
+
+        let stale_block = BeaconBlock { state_root: Root::default(), ..block.clone() };
+        assert!(create_light_client_bootstrap(&state, &stale_block).is_err());
+    }
+}
@@ -0,0 +1,30 @@
+//! This module provides an implementation of the `electra` fork
+//! of the consensus spec. The primary entrypoints should be one of
+//! the "presets" like `mainnet` or `minimal`.
+pub mod beacon_block;
+pub mod beacon_state;
+pub mod blinded_beacon_block;
+#[cfg(feature = "transition")]
+pub mod block_processing;
+#[cfg(feature = "validator")]
+pub mod consolidation;
+pub mod constants;
+#[cfg(feature = "transition")]
+pub mod epoch_processing;
+pub mod execution_engine;
+#[cfg(feature = "transition")]
+pub mod fork;
+#[cfg(feature = "transition")]
+pub mod genesis;
+pub mod helpers;
+#[cfg(feature = "light-client")]
+pub mod light_client;
+pub mod operations;
+pub mod presets;
+pub mod spec;
+#[cfg(feature = "validator")]
+pub mod validator;
+
+pub use spec::*;
+
+pub use presets::{gnosis, mainnet, minimal, Preset};
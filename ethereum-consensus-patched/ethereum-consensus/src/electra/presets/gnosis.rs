@@ -0,0 +1,223 @@
+//! WARNING: Derived by `spec-gen` from `spec-gen/presets/gnosis.yaml`. DO NOT EDIT MANUALLY.
+pub use crate::deneb::presets::gnosis::{
+    Blob, BlobSidecar, ExecutionPayload, ExecutionPayloadHeader, HistoricalBatch,
+    PendingAttestation, SyncAggregate, SyncCommittee, BYTES_PER_BLOB, BYTES_PER_LOGS_BLOOM,
+    EPOCHS_PER_HISTORICAL_VECTOR, EPOCHS_PER_SLASHINGS_VECTOR, ETH1_DATA_VOTES_BOUND,
+    FIELD_ELEMENTS_PER_BLOB, HISTORICAL_ROOTS_LIMIT, KZG_COMMITMENT_INCLUSION_PROOF_DEPTH,
+    MAX_BLOBS_PER_BLOCK, MAX_BLOB_COMMITMENTS_PER_BLOCK, MAX_BLS_TO_EXECUTION_CHANGES,
+    MAX_BYTES_PER_TRANSACTION, MAX_DEPOSITS, MAX_EXTRA_DATA_BYTES, MAX_PROPOSER_SLASHINGS,
+    MAX_REQUEST_BLOB_SIDECARS, MAX_TRANSACTIONS_PER_PAYLOAD, MAX_VALIDATORS_PER_COMMITTEE,
+    MAX_VOLUNTARY_EXITS, MAX_WITHDRAWALS_PER_PAYLOAD, SLOTS_PER_HISTORICAL_ROOT,
+    SYNC_COMMITTEE_SIZE, VALIDATOR_REGISTRY_LIMIT,
+};
+#[cfg(feature = "validator")]
+pub use crate::deneb::presets::gnosis::{
+    ContributionAndProof, SignedContributionAndProof, SyncCommitteeContribution,
+};
+use crate::{
+    electra::{presets::Preset, spec},
+    parameters::SpecParameters,
+};
+pub use spec::*;
+pub const MIN_ACTIVATION_BALANCE: Gwei = 32_000_000_000;
+pub const MAX_EFFECTIVE_BALANCE_ELECTRA: Gwei = 2_048_000_000_000;
+pub const MIN_SLASHING_PENALTY_QUOTIENT_ELECTRA: u64 = 4096;
+pub const WHISTLEBLOWER_REWARD_QUOTIENT_ELECTRA: u64 = 4096;
+pub const PENDING_DEPOSITS_LIMIT: usize = 134_217_728;
+pub const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize = 134_217_728;
+pub const PENDING_CONSOLIDATIONS_LIMIT: usize = 262_144;
+pub const MAX_ATTESTER_SLASHINGS_ELECTRA: usize = 1;
+pub const MAX_ATTESTATIONS_ELECTRA: usize = 8;
+pub const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize = 8192;
+pub const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize = 16;
+pub const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize = 2;
+pub const MAX_PENDING_PARTIALS_PER_WITHDRAWALS_SWEEP: usize = 8;
+pub const MAX_PENDING_DEPOSITS_PER_EPOCH: usize = 16;
+pub const MAX_COMMITTEES_PER_SLOT: usize =
+    crate::phase0::presets::gnosis::MAX_COMMITTEES_PER_SLOT as usize;
+pub const MAX_VALIDATORS_PER_SLOT: usize = MAX_VALIDATORS_PER_COMMITTEE * MAX_COMMITTEES_PER_SLOT;
+pub const PRESET: Preset = Preset {
+    min_activation_balance: MIN_ACTIVATION_BALANCE,
+    max_effective_balance_electra: MAX_EFFECTIVE_BALANCE_ELECTRA,
+    min_slashing_penalty_quotient_electra: MIN_SLASHING_PENALTY_QUOTIENT_ELECTRA,
+    whistleblower_reward_quotient_electra: WHISTLEBLOWER_REWARD_QUOTIENT_ELECTRA,
+    max_pending_partials_per_withdrawals_sweep: MAX_PENDING_PARTIALS_PER_WITHDRAWALS_SWEEP,
+    pending_deposits_limit: PENDING_DEPOSITS_LIMIT,
+    pending_partial_withdrawals_limit: PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+    pending_consolidations_limit: PENDING_CONSOLIDATIONS_LIMIT,
+    max_attester_slashings_electra: MAX_ATTESTER_SLASHINGS_ELECTRA,
+    max_attestations_electra: MAX_ATTESTATIONS_ELECTRA,
+    max_deposit_requests_per_payload: MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+    max_withdrawal_requests_per_payload: MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    max_consolidation_requests_per_payload: MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    max_pending_deposits_per_epoch: MAX_PENDING_DEPOSITS_PER_EPOCH,
+};
+pub const SPEC_PARAMETERS: SpecParameters = SpecParameters {
+    fork: crate::Fork::Electra,
+    phase0: crate::phase0::presets::gnosis::PRESET,
+    altair: Some(crate::altair::presets::gnosis::PRESET),
+    bellatrix: Some(crate::bellatrix::presets::gnosis::PRESET),
+    capella: Some(crate::capella::presets::gnosis::PRESET),
+    deneb: Some(crate::deneb::presets::gnosis::PRESET),
+    electra: Some(crate::electra::presets::gnosis::PRESET),
+};
+pub type Attestation = spec::Attestation<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>;
+pub type IndexedAttestation = spec::IndexedAttestation<MAX_VALIDATORS_PER_SLOT>;
+pub type AttesterSlashing = spec::AttesterSlashing<MAX_VALIDATORS_PER_SLOT>;
+#[cfg(feature = "validator")]
+pub type AggregateAndProof =
+    spec::AggregateAndProof<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>;
+#[cfg(feature = "validator")]
+pub type SignedAggregateAndProof =
+    spec::SignedAggregateAndProof<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>;
+pub type ExecutionRequests = spec::ExecutionRequests<
+    MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+    MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+>;
+pub type BlindedBeaconBlockBody = spec::BlindedBeaconBlockBody<
+    MAX_PROPOSER_SLASHINGS,
+    MAX_VALIDATORS_PER_SLOT,
+    MAX_COMMITTEES_PER_SLOT,
+    MAX_ATTESTER_SLASHINGS_ELECTRA,
+    MAX_ATTESTATIONS_ELECTRA,
+    MAX_DEPOSITS,
+    MAX_VOLUNTARY_EXITS,
+    SYNC_COMMITTEE_SIZE,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+    MAX_BLS_TO_EXECUTION_CHANGES,
+    MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+    MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+>;
+pub type BlindedBeaconBlock = spec::BlindedBeaconBlock<
+    MAX_PROPOSER_SLASHINGS,
+    MAX_VALIDATORS_PER_SLOT,
+    MAX_COMMITTEES_PER_SLOT,
+    MAX_ATTESTER_SLASHINGS_ELECTRA,
+    MAX_ATTESTATIONS_ELECTRA,
+    MAX_DEPOSITS,
+    MAX_VOLUNTARY_EXITS,
+    SYNC_COMMITTEE_SIZE,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+    MAX_BLS_TO_EXECUTION_CHANGES,
+    MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+    MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+>;
+pub type SignedBlindedBeaconBlock = spec::SignedBlindedBeaconBlock<
+    MAX_PROPOSER_SLASHINGS,
+    MAX_VALIDATORS_PER_SLOT,
+    MAX_COMMITTEES_PER_SLOT,
+    MAX_ATTESTER_SLASHINGS_ELECTRA,
+    MAX_ATTESTATIONS_ELECTRA,
+    MAX_DEPOSITS,
+    MAX_VOLUNTARY_EXITS,
+    SYNC_COMMITTEE_SIZE,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+    MAX_BLS_TO_EXECUTION_CHANGES,
+    MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+    MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+>;
+pub type BeaconState = spec::BeaconState<
+    SLOTS_PER_HISTORICAL_ROOT,
+    HISTORICAL_ROOTS_LIMIT,
+    ETH1_DATA_VOTES_BOUND,
+    VALIDATOR_REGISTRY_LIMIT,
+    EPOCHS_PER_HISTORICAL_VECTOR,
+    EPOCHS_PER_SLASHINGS_VECTOR,
+    MAX_VALIDATORS_PER_COMMITTEE,
+    SYNC_COMMITTEE_SIZE,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+    PENDING_DEPOSITS_LIMIT,
+    PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+    PENDING_CONSOLIDATIONS_LIMIT,
+>;
+pub type BeaconBlockBody = spec::BeaconBlockBody<
+    MAX_PROPOSER_SLASHINGS,
+    MAX_VALIDATORS_PER_SLOT,
+    MAX_COMMITTEES_PER_SLOT,
+    MAX_ATTESTER_SLASHINGS_ELECTRA,
+    MAX_ATTESTATIONS_ELECTRA,
+    MAX_DEPOSITS,
+    MAX_VOLUNTARY_EXITS,
+    SYNC_COMMITTEE_SIZE,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+    MAX_BYTES_PER_TRANSACTION,
+    MAX_TRANSACTIONS_PER_PAYLOAD,
+    MAX_WITHDRAWALS_PER_PAYLOAD,
+    MAX_BLS_TO_EXECUTION_CHANGES,
+    MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+    MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+>;
+pub type BeaconBlock = spec::BeaconBlock<
+    MAX_PROPOSER_SLASHINGS,
+    MAX_VALIDATORS_PER_SLOT,
+    MAX_COMMITTEES_PER_SLOT,
+    MAX_ATTESTER_SLASHINGS_ELECTRA,
+    MAX_ATTESTATIONS_ELECTRA,
+    MAX_DEPOSITS,
+    MAX_VOLUNTARY_EXITS,
+    SYNC_COMMITTEE_SIZE,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+    MAX_BYTES_PER_TRANSACTION,
+    MAX_TRANSACTIONS_PER_PAYLOAD,
+    MAX_WITHDRAWALS_PER_PAYLOAD,
+    MAX_BLS_TO_EXECUTION_CHANGES,
+    MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+    MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+>;
+pub type SignedBeaconBlock = spec::SignedBeaconBlock<
+    MAX_PROPOSER_SLASHINGS,
+    MAX_VALIDATORS_PER_SLOT,
+    MAX_COMMITTEES_PER_SLOT,
+    MAX_ATTESTER_SLASHINGS_ELECTRA,
+    MAX_ATTESTATIONS_ELECTRA,
+    MAX_DEPOSITS,
+    MAX_VOLUNTARY_EXITS,
+    SYNC_COMMITTEE_SIZE,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+    MAX_BYTES_PER_TRANSACTION,
+    MAX_TRANSACTIONS_PER_PAYLOAD,
+    MAX_WITHDRAWALS_PER_PAYLOAD,
+    MAX_BLS_TO_EXECUTION_CHANGES,
+    MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+    MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+>;
+#[cfg(feature = "light-client")]
+pub type LightClientHeader = spec::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+#[cfg(feature = "light-client")]
+pub type LightClientUpdate =
+    spec::LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+#[cfg(feature = "light-client")]
+pub type LightClientBootstrap =
+    spec::LightClientBootstrap<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>;
+#[cfg(feature = "light-client")]
+pub type LightClientFinalityUpdate = spec::LightClientFinalityUpdate<
+    SYNC_COMMITTEE_SIZE,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+>;
+#[cfg(feature = "light-client")]
+pub type LightClientOptimisticUpdate = spec::LightClientOptimisticUpdate<
+    SYNC_COMMITTEE_SIZE,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+>;
@@ -1,9 +1,13 @@
 use crate::{
     capella::Withdrawal,
+    chain_info::ChainInfoMismatch,
     crypto::Error as CryptoError,
     deneb::polynomial_commitments::Error as PolynomialCommitmentError,
     phase0::{AttestationData, BeaconBlockHeader, Checkpoint},
-    primitives::{BlsPublicKey, BlsSignature, Bytes32, Epoch, Hash32, Root, Slot, ValidatorIndex},
+    primitives::{
+        BlsPublicKey, BlsSignature, Bytes32, CommitteeIndex, Epoch, Gwei, Hash32, Root, Slot,
+        ValidatorIndex,
+    },
     ssz::prelude::*,
     Fork,
 };
@@ -49,8 +53,24 @@ pub enum Error {
         "transition requested from a later fork {destination_fork:?} to an earlier fork {source_fork:?}"
     )]
     IncompatibleFork { source_fork: Fork, destination_fork: Fork },
+    #[error("state does not match chain info: {0}")]
+    ChainInfoMismatch(#[from] ChainInfoMismatch),
     #[error("genesis time unknown for network {0}")]
     UnknownGenesisTime(String),
+    #[error("block with root {0:?} is unknown")]
+    UnknownBlock(Root),
+    #[error("finalized block with root {0:?} cannot be invalidated")]
+    InvalidatedFinalizedBlock(Root),
+    #[error(
+        "sync committee for period {requested} is not available from a state in period {current}"
+    )]
+    SyncCommitteePeriodUnavailable { requested: u64, current: u64 },
+    #[error("invalid light client update: {0}")]
+    InvalidLightClientUpdate(#[from] InvalidLightClientUpdate),
+    #[error("invalid consolidation: {0}")]
+    InvalidConsolidation(#[from] InvalidConsolidation),
+    #[error("no committee is cached for slot {slot} and index {index}")]
+    CommitteeNotCached { slot: Slot, index: CommitteeIndex },
     #[cfg(feature = "serde")]
     #[error("an unknown preset {0} was supplied when constructing context")]
     UnknownPreset(String),
@@ -124,6 +144,12 @@ pub enum InvalidAttestation {
     InvalidSource { expected: Checkpoint, source_checkpoint: Checkpoint, current: Epoch },
     #[error("attestation in slot {attestation_slot} does not have the minimum delay {required_delay} against state {state_slot}")]
     NoDelay { attestation_slot: Slot, state_slot: Slot, required_delay: Slot },
+    #[error("expected exactly one attesting validator in an unaggregated attestation but found {attesting_count}")]
+    NotUnaggregated { attesting_count: usize },
+    #[error("attestation data has non-zero committee index {0}, committees are given by the committee bits")]
+    NonZeroCommitteeIndex(CommitteeIndex),
+    #[error("no validator in committee {0} participated in the attestation")]
+    EmptyCommittee(CommitteeIndex),
 }
 
 #[derive(Debug, Error)]
@@ -186,12 +212,18 @@ pub enum InvalidVoluntaryExit {
     ValidatorIsNotActiveForLongEnough { current_epoch: Epoch, minimum_time_active: Epoch },
     #[error("voluntary exit has invalid signature: {0:?}")]
     InvalidSignature(BlsSignature),
+    #[error("validator {index} has {amount} Gwei of partial withdrawals pending")]
+    PendingWithdrawals { index: ValidatorIndex, amount: Gwei },
 }
 
 #[derive(Debug, Error)]
 pub enum InvalidWithdrawals {
     #[error("expected withdrawals {expected:#?} do not match provided withdrawals {provided:#?}")]
     IncorrectWithdrawals { provided: Vec<Withdrawal>, expected: Vec<Withdrawal> },
+    #[error(
+        "withdrawals root {provided:?} does not match the root of the withdrawals {expected:?}"
+    )]
+    WithdrawalsRootMismatch { provided: Root, expected: Root },
 }
 
 #[derive(Debug, Error)]
@@ -222,6 +254,7 @@ pub enum InvalidExecutionPayload {
     InvalidBlobCommitments { provided: usize, limit: usize },
 }
 
+#[cfg(feature = "transition")]
 pub(crate) fn invalid_header_error(error: InvalidBeaconBlockHeader) -> Error {
     Error::InvalidBlock(Box::new(InvalidBlock::Header(error)))
 }
@@ -239,3 +272,52 @@ pub enum ExecutionEngineError {
     #[error("invalid versioned hashes in payload")]
     InvalidVersionedHashes,
 }
+
+#[derive(Debug, Error)]
+pub enum InvalidLightClientUpdate {
+    #[error("sync aggregate has {participants} participants but at least {minimum} are required")]
+    InsufficientParticipation { participants: usize, minimum: usize },
+    #[error("signature slot {signature_slot} is not after the attested slot {attested_slot}")]
+    SignatureSlotNotAfterAttested { signature_slot: Slot, attested_slot: Slot },
+    #[error("state with root {state_root:?} at slot {state_slot} is not the post-state of the header with state root {header_state_root:?} at slot {header_slot}")]
+    StateHeaderMismatch {
+        state_root: Root,
+        state_slot: Slot,
+        header_state_root: Root,
+        header_slot: Slot,
+    },
+    #[error("expected finalized root {expected:?} but header has root {provided:?}")]
+    FinalizedRootMismatch { provided: Root, expected: Root },
+    #[error("expected a proof for generalized index {expected} but proof is for {provided}")]
+    UnexpectedGeneralizedIndex { provided: usize, expected: usize },
+}
+
+#[derive(Debug, Error)]
+pub enum InvalidConsolidation {
+    #[error("validator with index {0} is not in state")]
+    InvalidIndex(ValidatorIndex),
+    #[error("validator {0} cannot consolidate into itself")]
+    SameValidator(ValidatorIndex),
+    #[error("validator {0} is already part of another planned consolidation")]
+    AlreadyPlanned(ValidatorIndex),
+    #[error("source validator {0} does not have execution withdrawal credentials")]
+    SourceWithoutExecutionCredentials(ValidatorIndex),
+    #[error("target validator {0} does not have compounding withdrawal credentials")]
+    TargetWithoutCompoundingCredentials(ValidatorIndex),
+    #[error("validator {index} is not active in the current epoch {epoch}")]
+    InactiveValidator { index: ValidatorIndex, epoch: Epoch },
+    #[error("validator {index} already exited in {epoch}")]
+    ValidatorAlreadyExited { index: ValidatorIndex, epoch: Epoch },
+    #[error("validator {index} needs to be active until epoch {minimum_time_active}, currently in {current_epoch}")]
+    ValidatorIsNotActiveForLongEnough {
+        index: ValidatorIndex,
+        current_epoch: Epoch,
+        minimum_time_active: Epoch,
+    },
+    #[error("validator {index} has {amount} Gwei of partial withdrawals pending")]
+    PendingWithdrawals { index: ValidatorIndex, amount: Gwei },
+    #[error("{requested} consolidations do not fit in the {available} free slots of the pending consolidations queue")]
+    QueueFull { requested: usize, available: usize },
+    #[error("consolidation churn limit {churn_limit} does not exceed the minimum activation balance {minimum}")]
+    InsufficientChurn { churn_limit: Gwei, minimum: Gwei },
+}
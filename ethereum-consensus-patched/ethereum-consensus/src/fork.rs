@@ -1,7 +1,9 @@
 use std::fmt;
 
 // Identifies the fork of the protocol the associated object belongs to.
-#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum Fork {
     Phase0,
@@ -9,6 +11,35 @@ pub enum Fork {
     Bellatrix,
     Capella,
     Deneb,
+    Electra,
+}
+
+// Capability queries so callers can branch on the presence of a given block body field
+// rather than matching on every fork explicitly.
+impl Fork {
+    pub fn supports_sync_aggregate(&self) -> bool {
+        *self >= Self::Altair
+    }
+
+    pub fn supports_execution_payload(&self) -> bool {
+        *self >= Self::Bellatrix
+    }
+
+    pub fn supports_withdrawals(&self) -> bool {
+        *self >= Self::Capella
+    }
+
+    pub fn supports_bls_to_execution_changes(&self) -> bool {
+        *self >= Self::Capella
+    }
+
+    pub fn supports_blob_commitments(&self) -> bool {
+        *self >= Self::Deneb
+    }
+
+    pub fn supports_execution_requests(&self) -> bool {
+        *self >= Self::Electra
+    }
 }
 
 impl fmt::Display for Fork {
@@ -19,6 +50,7 @@ impl fmt::Display for Fork {
             Self::Bellatrix => write!(f, "bellatrix"),
             Self::Capella => write!(f, "capella"),
             Self::Deneb => write!(f, "deneb"),
+            Self::Electra => write!(f, "electra"),
         }
     }
 }
@@ -0,0 +1,424 @@
+//! Tracking of the canonical head chosen by fork choice, producing the events the beacon API
+//! streams to subscribers of the `head`, `chain_reorg` and `finalized_checkpoint` topics.
+//!
+//! Fork choice itself is left to the caller: the `HeadTracker` keeps just enough of the block
+//! tree (each block's slot, parent and state root) to relate successive heads to each other,
+//! along with which blocks were imported optimistically, before the execution engine validated
+//! their payloads.
+use crate::{
+    phase0::Checkpoint,
+    primitives::{Epoch, Root, Slot},
+    state_transition::{Context, PayloadStatus},
+    Error,
+};
+use std::collections::HashMap;
+
+/// A new head, as in the beacon API `head` event.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct HeadEvent {
+    #[serde(with = "crate::serde::as_str")]
+    pub slot: Slot,
+    pub block: Root,
+    pub state: Root,
+    pub epoch_transition: bool,
+    pub previous_duty_dependent_root: Root,
+    pub current_duty_dependent_root: Root,
+    pub execution_optimistic: bool,
+}
+
+/// A head change to a block that does not descend from the previous head, as in the beacon
+/// API `chain_reorg` event.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ChainReorgEvent {
+    #[serde(with = "crate::serde::as_str")]
+    pub slot: Slot,
+    /// Number of slots from the common ancestor of the old and new heads to the old head.
+    #[serde(with = "crate::serde::as_str")]
+    pub depth: u64,
+    pub old_head_block: Root,
+    pub new_head_block: Root,
+    pub old_head_state: Root,
+    pub new_head_state: Root,
+    #[serde(with = "crate::serde::as_str")]
+    pub epoch: Epoch,
+    pub execution_optimistic: bool,
+}
+
+/// An advance of the finalized checkpoint, as in the beacon API `finalized_checkpoint` event.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FinalizedCheckpointEvent {
+    pub block: Root,
+    pub state: Root,
+    #[serde(with = "crate::serde::as_str")]
+    pub epoch: Epoch,
+    pub execution_optimistic: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ForkChoiceEvent {
+    Head(HeadEvent),
+    ChainReorg(ChainReorgEvent),
+    FinalizedCheckpoint(FinalizedCheckpointEvent),
+}
+
+impl ForkChoiceEvent {
+    /// The name of the beacon API event topic this event is published under.
+    pub fn topic(&self) -> &'static str {
+        match self {
+            Self::Head(..) => "head",
+            Self::ChainReorg(..) => "chain_reorg",
+            Self::FinalizedCheckpoint(..) => "finalized_checkpoint",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BlockSummary {
+    slot: Slot,
+    parent_root: Root,
+    state_root: Root,
+    optimistic: bool,
+}
+
+/// Follows the head chosen by a fork choice implementation and turns each change into the
+/// events a beacon node would publish.
+///
+/// Blocks must be reported with `on_block` before they can become the head. Blocks which do
+/// not descend from the latest finalized block are pruned as finality advances.
+#[derive(Debug, Clone)]
+pub struct HeadTracker {
+    slots_per_epoch: Slot,
+    blocks: HashMap<Root, BlockSummary>,
+    head: Root,
+    finalized: Checkpoint,
+}
+
+impl HeadTracker {
+    /// Start tracking from an `anchor` block, e.g. the genesis block or the block of the
+    /// finalized checkpoint a node started from, which is the initial head.
+    pub fn new(
+        anchor_root: Root,
+        anchor_slot: Slot,
+        anchor_state_root: Root,
+        context: &Context,
+    ) -> Self {
+        let anchor = BlockSummary {
+            slot: anchor_slot,
+            parent_root: Root::default(),
+            state_root: anchor_state_root,
+            optimistic: false,
+        };
+        let epoch = anchor_slot / context.slots_per_epoch;
+        Self {
+            slots_per_epoch: context.slots_per_epoch,
+            blocks: HashMap::from([(anchor_root, anchor)]),
+            head: anchor_root,
+            finalized: Checkpoint { epoch, root: anchor_root },
+        }
+    }
+
+    pub fn head(&self) -> Root {
+        self.head
+    }
+
+    pub fn finalized_checkpoint(&self) -> &Checkpoint {
+        &self.finalized
+    }
+
+    pub fn contains_block(&self, root: &Root) -> bool {
+        self.blocks.contains_key(root)
+    }
+
+    /// Record an imported block, so that it can become the head.
+    pub fn on_block(
+        &mut self,
+        root: Root,
+        slot: Slot,
+        parent_root: Root,
+        state_root: Root,
+    ) -> Result<(), Error> {
+        if !self.blocks.contains_key(&parent_root) {
+            return Err(Error::UnknownBlock(parent_root))
+        }
+        self.blocks.insert(root, BlockSummary { slot, parent_root, state_root, optimistic: false });
+        Ok(())
+    }
+
+    /// Whether the block was imported optimistically and its payload is not yet known to be
+    /// valid.
+    pub fn is_optimistic(&self, root: &Root) -> bool {
+        self.blocks.get(root).map(|block| block.optimistic).unwrap_or_default()
+    }
+
+    /// Record the status of the payload of an imported block, as reported by the execution
+    /// engine when the block was processed or later, e.g. once it finished syncing.
+    ///
+    /// Following the optimistic sync rules, a block whose payload was not validated yet is
+    /// optimistic until a `VALID` status for it or a descendant. An invalid payload removes the
+    /// block and its descendants; if that includes the head, the parent of the block becomes
+    /// the head and the events describing the change are returned. Callers given a
+    /// `latest_valid_hash` should report the blocks between it and the payload as invalid too.
+    pub fn on_payload_status(
+        &mut self,
+        root: Root,
+        status: &PayloadStatus,
+    ) -> Result<Vec<ForkChoiceEvent>, Error> {
+        let block = *self.block(&root)?;
+        if status.is_not_validated() {
+            self.blocks.get_mut(&root).expect("block is present").optimistic = true;
+            return Ok(vec![])
+        }
+        if status.is_valid() {
+            let mut root = root;
+            while let Some(block) = self.blocks.get_mut(&root) {
+                if !block.optimistic {
+                    break
+                }
+                block.optimistic = false;
+                root = block.parent_root;
+            }
+            return Ok(vec![])
+        }
+
+        if root == self.finalized.root {
+            return Err(Error::InvalidatedFinalizedBlock(root))
+        }
+        let mut events = vec![];
+        if self.ancestor_at(self.head, block.slot) == root {
+            let execution_optimistic = self.is_optimistic(&block.parent_root);
+            events = self.update_head(block.parent_root, execution_optimistic)?;
+        }
+        let invalidated = self
+            .blocks
+            .keys()
+            .filter(|&&descendant| self.ancestor_at(descendant, block.slot) == root)
+            .copied()
+            .collect::<Vec<_>>();
+        for root in invalidated {
+            self.blocks.remove(&root);
+        }
+        Ok(events)
+    }
+
+    fn block(&self, root: &Root) -> Result<&BlockSummary, Error> {
+        self.blocks.get(root).ok_or(Error::UnknownBlock(*root))
+    }
+
+    fn epoch_at(&self, slot: Slot) -> Epoch {
+        slot / self.slots_per_epoch
+    }
+
+    // Return the root of the latest block at or before `slot` in the chain of `root`, or the
+    // oldest retained block of that chain if it does not reach back to `slot`.
+    fn ancestor_at(&self, mut root: Root, slot: Slot) -> Root {
+        while let Some(block) = self.blocks.get(&root) {
+            if block.slot <= slot || !self.blocks.contains_key(&block.parent_root) {
+                break
+            }
+            root = block.parent_root;
+        }
+        root
+    }
+
+    // Return the root of the duty dependent block for `epoch` in the chain of `root`, i.e. the
+    // block at the last slot of the epoch before it.
+    fn dependent_root(&self, root: Root, epoch: Epoch) -> Root {
+        let slot = (epoch * self.slots_per_epoch).saturating_sub(1);
+        self.ancestor_at(root, slot)
+    }
+
+    // Return the latest block in the chains of both `a` and `b`.
+    fn common_ancestor(&self, mut a: Root, mut b: Root) -> Result<Root, Error> {
+        while a != b {
+            let (block_a, block_b) = (self.block(&a)?, self.block(&b)?);
+            if block_a.slot >= block_b.slot {
+                a = block_a.parent_root;
+            }
+            if block_b.slot >= block_a.slot {
+                b = block_b.parent_root;
+            }
+        }
+        Ok(a)
+    }
+
+    /// Make `new_head` the head, returning the events describing the change: a `ChainReorg`
+    /// event if the new head does not descend from the old one, followed by a `Head` event.
+    ///
+    /// No events are returned if the head is unchanged.
+    pub fn update_head(
+        &mut self,
+        new_head: Root,
+        execution_optimistic: bool,
+    ) -> Result<Vec<ForkChoiceEvent>, Error> {
+        if new_head == self.head {
+            return Ok(vec![])
+        }
+        let old = *self.block(&self.head)?;
+        let new = *self.block(&new_head)?;
+        let ancestor = self.common_ancestor(self.head, new_head)?;
+
+        let mut events = Vec::with_capacity(2);
+        if ancestor != self.head {
+            let ancestor_slot = self.block(&ancestor)?.slot;
+            events.push(ForkChoiceEvent::ChainReorg(ChainReorgEvent {
+                slot: new.slot,
+                depth: old.slot - ancestor_slot,
+                old_head_block: self.head,
+                new_head_block: new_head,
+                old_head_state: old.state_root,
+                new_head_state: new.state_root,
+                epoch: self.epoch_at(new.slot),
+                execution_optimistic,
+            }));
+        }
+        let epoch = self.epoch_at(new.slot);
+        events.push(ForkChoiceEvent::Head(HeadEvent {
+            slot: new.slot,
+            block: new_head,
+            state: new.state_root,
+            epoch_transition: epoch > self.epoch_at(old.slot),
+            previous_duty_dependent_root: self.dependent_root(new_head, epoch.saturating_sub(1)),
+            current_duty_dependent_root: self.dependent_root(new_head, epoch),
+            execution_optimistic,
+        }));
+        self.head = new_head;
+        Ok(events)
+    }
+
+    /// Advance the finalized checkpoint, returning the `FinalizedCheckpoint` event if it moved
+    /// forward and pruning blocks that conflict with it.
+    ///
+    /// The head should already descend from the new checkpoint, as fork choice ensures.
+    pub fn update_finalized_checkpoint(
+        &mut self,
+        checkpoint: Checkpoint,
+        execution_optimistic: bool,
+    ) -> Result<Option<ForkChoiceEvent>, Error> {
+        if checkpoint.epoch <= self.finalized.epoch {
+            return Ok(None)
+        }
+        let finalized = *self.block(&checkpoint.root)?;
+        let retained = self
+            .blocks
+            .keys()
+            .filter(|&&root| self.ancestor_at(root, finalized.slot) == checkpoint.root)
+            .copied()
+            .collect::<Vec<_>>();
+        self.blocks = retained.into_iter().map(|root| (root, self.blocks[&root])).collect();
+
+        let event = FinalizedCheckpointEvent {
+            block: checkpoint.root,
+            state: finalized.state_root,
+            epoch: checkpoint.epoch,
+            execution_optimistic,
+        };
+        self.finalized = checkpoint;
+        Ok(Some(ForkChoiceEvent::FinalizedCheckpoint(event)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root(byte: u8) -> Root {
+        Root::try_from([byte; 32].as_ref()).unwrap()
+    }
+
+    #[test]
+    fn test_head_changes_and_reorgs() {
+        let context = Context::for_minimal();
+        let mut tracker = HeadTracker::new(root(0), 0, root(100), &context);
+        // 0 <- 1 <- 2 <- 3
+        //        \- 4 (slot 9)
+        tracker.on_block(root(1), 1, root(0), root(101)).unwrap();
+        tracker.on_block(root(2), 2, root(1), root(102)).unwrap();
+        tracker.on_block(root(3), 3, root(2), root(103)).unwrap();
+        tracker.on_block(root(4), 9, root(1), root(104)).unwrap();
+        assert!(tracker.on_block(root(5), 10, root(42), root(105)).is_err());
+
+        let events = tracker.update_head(root(3), false).unwrap();
+        assert_eq!(events.len(), 1);
+        let ForkChoiceEvent::Head(head) = &events[0] else { panic!("is a head event") };
+        assert_eq!((head.slot, head.block, head.state), (3, root(3), root(103)));
+        assert!(!head.epoch_transition);
+        assert_eq!(head.current_duty_dependent_root, root(0));
+        assert!(tracker.update_head(root(3), false).unwrap().is_empty());
+
+        let events = tracker.update_head(root(4), true).unwrap();
+        assert_eq!(
+            events.iter().map(ForkChoiceEvent::topic).collect::<Vec<_>>(),
+            ["chain_reorg", "head"]
+        );
+        let ForkChoiceEvent::ChainReorg(reorg) = &events[0] else { panic!("is a reorg event") };
+        assert_eq!(reorg.depth, 2);
+        assert_eq!((reorg.old_head_block, reorg.new_head_block), (root(3), root(4)));
+        assert_eq!((reorg.old_head_state, reorg.new_head_state), (root(103), root(104)));
+        assert_eq!(reorg.epoch, 1);
+        let ForkChoiceEvent::Head(head) = &events[1] else { panic!("is a head event") };
+        assert!(head.epoch_transition && head.execution_optimistic);
+        assert_eq!(head.current_duty_dependent_root, root(1));
+        assert_eq!(head.previous_duty_dependent_root, root(0));
+
+        let value = serde_json::to_value(reorg).unwrap();
+        assert_eq!(value["depth"], "2");
+        assert_eq!(value["slot"], "9");
+    }
+
+    #[test]
+    fn test_optimistic_blocks() {
+        let context = Context::for_minimal();
+        let mut tracker = HeadTracker::new(root(0), 0, root(100), &context);
+        // 0 <- 1 <- 2 <- 3
+        //        \- 4
+        tracker.on_block(root(1), 1, root(0), root(101)).unwrap();
+        tracker.on_block(root(2), 2, root(1), root(102)).unwrap();
+        tracker.on_block(root(3), 3, root(2), root(103)).unwrap();
+        tracker.on_block(root(4), 4, root(1), root(104)).unwrap();
+        for i in 1..=4 {
+            tracker.on_payload_status(root(i), &PayloadStatus::Syncing).unwrap();
+        }
+        assert!(tracker.is_optimistic(&root(3)));
+        assert!(!tracker.is_optimistic(&root(0)));
+
+        tracker.on_payload_status(root(2), &PayloadStatus::Valid).unwrap();
+        assert!(!tracker.is_optimistic(&root(1)) && !tracker.is_optimistic(&root(2)));
+        assert!(tracker.is_optimistic(&root(3)) && tracker.is_optimistic(&root(4)));
+
+        tracker.update_head(root(3), true).unwrap();
+        let status = PayloadStatus::Invalid { latest_valid_hash: None };
+        assert!(tracker.on_payload_status(root(4), &status).unwrap().is_empty());
+        assert!(!tracker.contains_block(&root(4)));
+
+        let events = tracker.on_payload_status(root(3), &status).unwrap();
+        assert_eq!(tracker.head(), root(2));
+        assert!(!tracker.contains_block(&root(3)));
+        let ForkChoiceEvent::Head(head) = &events[1] else { panic!("is a head event") };
+        assert_eq!(head.block, root(2));
+        assert!(!head.execution_optimistic);
+
+        assert!(tracker.on_payload_status(root(0), &PayloadStatus::InvalidBlockHash).is_err());
+        assert!(tracker.on_payload_status(root(3), &PayloadStatus::Valid).is_err());
+    }
+
+    #[test]
+    fn test_finality_prunes_conflicting_blocks() {
+        let context = Context::for_minimal();
+        let mut tracker = HeadTracker::new(root(0), 0, root(100), &context);
+        tracker.on_block(root(1), 8, root(0), root(101)).unwrap();
+        tracker.on_block(root(2), 9, root(1), root(102)).unwrap();
+        tracker.on_block(root(3), 9, root(0), root(103)).unwrap();
+        tracker.update_head(root(2), false).unwrap();
+
+        let checkpoint = Checkpoint { epoch: 1, root: root(1) };
+        let event = tracker.update_finalized_checkpoint(checkpoint.clone(), false).unwrap();
+        let Some(ForkChoiceEvent::FinalizedCheckpoint(event)) = event else {
+            panic!("is a finalized checkpoint event")
+        };
+        assert_eq!((event.block, event.state, event.epoch), (root(1), root(101), 1));
+        assert!(tracker.update_finalized_checkpoint(checkpoint, false).unwrap().is_none());
+        assert!(tracker.contains_block(&root(2)));
+        assert!(!tracker.contains_block(&root(3)));
+        assert!(!tracker.contains_block(&root(0)));
+    }
+}
@@ -1,18 +1,62 @@
+//! An implementation of the [ethereum consensus specs](https://github.com/ethereum/consensus-specs).
+//!
+//! Most users will want the [`prelude`] along with the module for a given fork and preset,
+//! e.g. `ethereum_consensus::deneb::mainnet`.
+//!
+//! The crate is organized as:
+//! - one module per fork (e.g. [`phase0`], [`deneb`]) holding its types, presets and state
+//!   transition logic
+//! - [`types`] with enums wrapping the types of each fork, e.g. a `BeaconBlock` of any fork
+//! - [`state_transition`] with the [`state_transition::Context`] and, with `transition`, the
+//!   `Executor` to drive the state transition across forks
+//! - supporting modules like [`primitives`], [`crypto`], [`configs`] and [`clock`]
+//!
+//! Some subsystems pull in heavy dependencies or are not needed by every user and are gated
+//! behind features, all enabled by default:
+//! - `serde`: (de)serialization of consensus types, e.g. to and from the beacon APIs' JSON and
+//!   config files; this is currently required to build the crate
+//! - `async`: a stream of slots from a [`clock::Clock`], via `tokio`
+//! - `networking`: the p2p types in the `networking` module, e.g. `PeerId` and `Enr`
+//! - `kzg`: KZG commitments and proofs over blobs in [`deneb::polynomial_commitments`], via `c-kzg`
+//! - `apis`: the types of the builder APIs in the `builder` module
+//! - `transition`: block, epoch and slot processing, genesis and the fork upgrades of each fork,
+//!   e.g. `deneb::state_transition`, and the `Executor`
+//! - `validator`: the validator duty types of each fork, e.g. `altair::SyncCommitteeMessage`, and
+//!   the consolidation planning in `electra::consolidation`
+//! - `light-client`: the light client types of each fork and the `create_light_client_*` functions
+//!   building bootstraps and updates
+//!
+//! BLS via `blst` is always compiled, as it backs the key and signature types.
+//!
+//! To depend on only the consensus types, e.g. in a verifier, use:
+//!
+//! ```toml
+//! ethereum-consensus = { version = "0.1", default-features = false, features = ["serde"] }
+//! ```
 pub mod altair;
 pub mod bellatrix;
+#[cfg(feature = "apis")]
 pub mod builder;
+pub mod caches;
 pub mod capella;
+pub mod chain_info;
 pub mod clock;
 pub mod configs;
 pub mod crypto;
 pub mod deneb;
 pub mod domains;
+pub mod electra;
 pub mod error;
 mod fork;
+pub mod fork_choice;
+pub mod mock;
+#[cfg(feature = "networking")]
 pub mod networking;
 pub mod networks;
+pub mod parameters;
 pub mod phase0;
 pub mod primitives;
+pub mod registry;
 #[cfg(feature = "serde")]
 pub mod serde;
 pub mod signing;
@@ -22,3 +66,14 @@ pub mod types;
 
 pub use error::Error;
 pub use fork::Fork;
+
+/// The `prelude` contains the primitive types, SSZ traits and state transition context
+/// commonly needed alongside the types of a particular fork.
+pub mod prelude {
+    pub use crate::{
+        primitives::*,
+        ssz::prelude::*,
+        state_transition::{Context, Validation},
+        Error, Fork,
+    };
+}
@@ -0,0 +1,710 @@
+//! Deterministic generators of structurally valid instances of consensus types.
+//!
+//! Unlike purely random data, values produced here respect the invariants the state
+//! transition relies on (e.g. attestation bitfields match the committee they refer to,
+//! registry-sized lists have matching lengths, checkpoints are ordered) so they can be
+//! fed to operation pools, APIs and fork choice in integration tests.
+//! Signatures and public keys are random bytes and will not verify.
+use crate::{
+    altair::{self, SyncAggregate, SyncCommittee},
+    phase0::{
+        self, get_beacon_committee, Attestation, AttestationData, BeaconBlockHeader, Checkpoint,
+        Eth1Data, Fork, IndexedAttestation, SignedBeaconBlockHeader, Validator,
+    },
+    primitives::{
+        BlsPublicKey, BlsSignature, Bytes32, CommitteeIndex, Epoch, Root, Slot, FAR_FUTURE_EPOCH,
+    },
+    ssz::prelude::*,
+    state_transition::{Context, Result},
+};
+
+// Bounds used so that generated values stay small enough to be practical in tests.
+const MAX_MOCK_COMMITTEE_SIZE: usize = 128;
+const MAX_MOCK_VALIDATORS: usize = 256;
+const MAX_MOCK_OPERATIONS: usize = 4;
+const MAX_MOCK_EPOCH: Epoch = 1 << 20;
+
+/// A small deterministic PRNG (SplitMix64) so that a given seed always yields the same value,
+/// independent of the version of any external randomness crate.
+#[derive(Debug, Clone)]
+pub struct MockRng(u64);
+
+impl MockRng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    // Returns a value in `[low, high)`; `high` must be greater than `low`.
+    pub fn gen_range(&mut self, low: u64, high: u64) -> u64 {
+        low + self.next_u64() % (high - low)
+    }
+
+    pub fn gen_bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+pub trait Mock: Sized {
+    fn mock(rng: &mut MockRng, context: &Context) -> Self;
+}
+
+/// Generate a value of `T` from `seed`, using the mainnet configuration.
+pub fn mock<T: Mock>(seed: u64) -> T {
+    mock_with_context(seed, &Context::for_mainnet())
+}
+
+pub fn mock_with_context<T: Mock>(seed: u64, context: &Context) -> T {
+    T::mock(&mut MockRng::new(seed), context)
+}
+
+/// Generate an attestation for the committee at (`slot`, `index`) in `state` with a random,
+/// non-empty subset of the committee participating.
+pub fn mock_attestation_for_committee<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+>(
+    rng: &mut MockRng,
+    state: &phase0::BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+    slot: Slot,
+    index: CommitteeIndex,
+    context: &Context,
+) -> Result<Attestation<MAX_VALIDATORS_PER_COMMITTEE>> {
+    let committee = get_beacon_committee(state, slot, index, context)?;
+    let epoch = slot / context.slots_per_epoch;
+    let mut data = AttestationData {
+        slot,
+        index,
+        beacon_block_root: Root::mock(rng, context),
+        source: state.current_justified_checkpoint.clone(),
+        target: Checkpoint { epoch, root: Root::mock(rng, context) },
+    };
+    if data.source.epoch > epoch {
+        data.source.epoch = epoch;
+    }
+    Ok(Attestation {
+        aggregation_bits: mock_bitlist(rng, committee.len()),
+        data,
+        signature: BlsSignature::mock(rng, context),
+    })
+}
+
+// Generates a `Bitlist` of `len` bits with at least one bit set, if `len` is non-zero.
+fn mock_bitlist<const N: usize>(rng: &mut MockRng, len: usize) -> Bitlist<N> {
+    let mut bits = (0..len).map(|_| rng.gen_bool()).collect::<Vec<_>>();
+    if len > 0 {
+        let index = rng.gen_range(0, len as u64) as usize;
+        bits[index] = true;
+    }
+    Bitlist::try_from(bits.as_slice()).expect("length is within bounds")
+}
+
+fn mock_list<T: Mock + Serializable, const N: usize>(
+    rng: &mut MockRng,
+    max_len: usize,
+    context: &Context,
+) -> List<T, N> {
+    let len = rng.gen_range(0, usize::min(max_len, N) as u64 + 1) as usize;
+    let elements = (0..len).map(|_| T::mock(rng, context)).collect::<Vec<_>>();
+    List::try_from(elements).map_err(|(_, err)| err).expect("length is within bounds")
+}
+
+fn mock_bytes<const N: usize>(rng: &mut MockRng) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    rng.fill_bytes(&mut bytes);
+    bytes
+}
+
+impl Mock for u64 {
+    fn mock(rng: &mut MockRng, _: &Context) -> Self {
+        rng.next_u64()
+    }
+}
+
+impl Mock for bool {
+    fn mock(rng: &mut MockRng, _: &Context) -> Self {
+        rng.gen_bool()
+    }
+}
+
+impl Mock for Root {
+    fn mock(rng: &mut MockRng, _: &Context) -> Self {
+        Root::try_from(mock_bytes::<32>(rng).as_ref()).expect("is valid root")
+    }
+}
+
+impl<const N: usize> Mock for ByteVector<N> {
+    fn mock(rng: &mut MockRng, _: &Context) -> Self {
+        let mut bytes = vec![0u8; N];
+        rng.fill_bytes(&mut bytes);
+        ByteVector::try_from(bytes.as_ref()).expect("is valid length")
+    }
+}
+
+impl Mock for BlsPublicKey {
+    fn mock(rng: &mut MockRng, _: &Context) -> Self {
+        BlsPublicKey::try_from(mock_bytes::<48>(rng).as_ref()).expect("is valid length")
+    }
+}
+
+impl Mock for BlsSignature {
+    fn mock(rng: &mut MockRng, _: &Context) -> Self {
+        BlsSignature::try_from(mock_bytes::<96>(rng).as_ref()).expect("is valid length")
+    }
+}
+
+impl Mock for Checkpoint {
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        Self { epoch: rng.gen_range(0, MAX_MOCK_EPOCH), root: Root::mock(rng, context) }
+    }
+}
+
+impl Mock for AttestationData {
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        let target = Checkpoint::mock(rng, context);
+        let source = Checkpoint {
+            epoch: rng.gen_range(0, target.epoch + 1),
+            root: Root::mock(rng, context),
+        };
+        let slot =
+            target.epoch * context.slots_per_epoch + rng.gen_range(0, context.slots_per_epoch);
+        Self {
+            slot,
+            index: rng.gen_range(0, context.max_committees_per_slot) as CommitteeIndex,
+            beacon_block_root: Root::mock(rng, context),
+            source,
+            target,
+        }
+    }
+}
+
+impl<const MAX_VALIDATORS_PER_COMMITTEE: usize> Mock for Attestation<MAX_VALIDATORS_PER_COMMITTEE> {
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        let max_len = usize::min(MAX_VALIDATORS_PER_COMMITTEE, MAX_MOCK_COMMITTEE_SIZE);
+        let len = rng.gen_range(1, max_len as u64 + 1) as usize;
+        Self {
+            aggregation_bits: mock_bitlist(rng, len),
+            data: AttestationData::mock(rng, context),
+            signature: BlsSignature::mock(rng, context),
+        }
+    }
+}
+
+impl<const MAX_VALIDATORS_PER_COMMITTEE: usize> Mock
+    for IndexedAttestation<MAX_VALIDATORS_PER_COMMITTEE>
+{
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        let max_len = usize::min(MAX_VALIDATORS_PER_COMMITTEE, MAX_MOCK_COMMITTEE_SIZE);
+        let len = rng.gen_range(1, max_len as u64 + 1) as usize;
+        // indices must be sorted and unique
+        let mut index = 0;
+        let attesting_indices = (0..len)
+            .map(|_| {
+                index += rng.gen_range(1, 8) as usize;
+                index
+            })
+            .collect::<Vec<_>>();
+        Self {
+            attesting_indices: attesting_indices
+                .try_into()
+                .map_err(|(_, err)| err)
+                .expect("length is within bounds"),
+            data: AttestationData::mock(rng, context),
+            signature: BlsSignature::mock(rng, context),
+        }
+    }
+}
+
+impl Mock for Validator {
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        let increments = context.max_effective_balance / context.effective_balance_increment;
+        let activation_epoch = rng.gen_range(0, MAX_MOCK_EPOCH);
+        let exited = rng.gen_range(0, 8) == 0;
+        let (exit_epoch, withdrawable_epoch) = if exited {
+            let exit_epoch = rng.gen_range(activation_epoch + 1, MAX_MOCK_EPOCH + 1);
+            (exit_epoch, exit_epoch + context.min_validator_withdrawability_delay)
+        } else {
+            (FAR_FUTURE_EPOCH, FAR_FUTURE_EPOCH)
+        };
+        Self {
+            public_key: BlsPublicKey::mock(rng, context),
+            withdrawal_credentials: Bytes32::mock(rng, context),
+            effective_balance: rng.gen_range(0, increments + 1) *
+                context.effective_balance_increment,
+            slashed: exited && rng.gen_bool(),
+            activation_eligibility_epoch: activation_epoch.saturating_sub(1),
+            activation_epoch,
+            exit_epoch,
+            withdrawable_epoch,
+        }
+    }
+}
+
+impl Mock for Eth1Data {
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        Self {
+            deposit_root: Root::mock(rng, context),
+            deposit_count: rng.gen_range(0, 1 << 32),
+            block_hash: Bytes32::mock(rng, context),
+        }
+    }
+}
+
+impl Mock for BeaconBlockHeader {
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        Self {
+            slot: rng.gen_range(1, MAX_MOCK_EPOCH * context.slots_per_epoch),
+            proposer_index: rng.gen_range(0, MAX_MOCK_VALIDATORS as u64) as usize,
+            parent_root: Root::mock(rng, context),
+            state_root: Root::mock(rng, context),
+            body_root: Root::mock(rng, context),
+        }
+    }
+}
+
+impl Mock for SignedBeaconBlockHeader {
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        Self {
+            message: BeaconBlockHeader::mock(rng, context),
+            signature: BlsSignature::mock(rng, context),
+        }
+    }
+}
+
+impl<const SYNC_COMMITTEE_SIZE: usize> Mock for SyncAggregate<SYNC_COMMITTEE_SIZE> {
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        let bits = (0..SYNC_COMMITTEE_SIZE).map(|_| rng.gen_bool()).collect::<Vec<_>>();
+        Self {
+            sync_committee_bits: Bitvector::try_from(bits.as_slice()).expect("is valid length"),
+            sync_committee_signature: BlsSignature::mock(rng, context),
+        }
+    }
+}
+
+// Attestations in a block may only refer to slots in the last epoch before the block's slot.
+fn mock_block_attestations<
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const MAX_ATTESTATIONS: usize,
+>(
+    rng: &mut MockRng,
+    slot: Slot,
+    context: &Context,
+) -> List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS> {
+    let mut attestations: List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS> =
+        mock_list(rng, MAX_MOCK_OPERATIONS, context);
+    for attestation in attestations.iter_mut() {
+        let data = &mut attestation.data;
+        let lower_bound = slot.saturating_sub(context.slots_per_epoch);
+        data.slot = rng.gen_range(lower_bound, slot);
+        data.target.epoch = data.slot / context.slots_per_epoch;
+        data.source.epoch = rng.gen_range(0, data.target.epoch + 1);
+    }
+    attestations
+}
+
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+    > Mock
+    for phase0::BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+    >
+{
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        let header = BeaconBlockHeader::mock(rng, context);
+        let body = phase0::BeaconBlockBody {
+            randao_reveal: BlsSignature::mock(rng, context),
+            eth1_data: Eth1Data::mock(rng, context),
+            graffiti: Bytes32::mock(rng, context),
+            attestations: mock_block_attestations(rng, header.slot, context),
+            ..Default::default()
+        };
+        Self {
+            slot: header.slot,
+            proposer_index: header.proposer_index,
+            parent_root: header.parent_root,
+            state_root: header.state_root,
+            body,
+        }
+    }
+}
+
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+    > Mock
+    for phase0::SignedBeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+    >
+{
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        Self {
+            message: phase0::BeaconBlock::mock(rng, context),
+            signature: BlsSignature::mock(rng, context),
+        }
+    }
+}
+
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    > Mock
+    for altair::BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+    >
+{
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        let header = BeaconBlockHeader::mock(rng, context);
+        let body = altair::BeaconBlockBody {
+            randao_reveal: BlsSignature::mock(rng, context),
+            eth1_data: Eth1Data::mock(rng, context),
+            graffiti: Bytes32::mock(rng, context),
+            attestations: mock_block_attestations(rng, header.slot, context),
+            sync_aggregate: SyncAggregate::mock(rng, context),
+            ..Default::default()
+        };
+        Self {
+            slot: header.slot,
+            proposer_index: header.proposer_index,
+            parent_root: header.parent_root,
+            state_root: header.state_root,
+            body,
+        }
+    }
+}
+
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    > Mock
+    for altair::SignedBeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+    >
+{
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        Self {
+            message: altair::BeaconBlock::mock(rng, context),
+            signature: BlsSignature::mock(rng, context),
+        }
+    }
+}
+
+// Fields shared by the state of every fork, generated so that the checkpoints are ordered
+// and the registry lists have matching lengths.
+struct MockStateCore<const VALIDATOR_REGISTRY_LIMIT: usize> {
+    slot: Slot,
+    fork: Fork,
+    latest_block_header: BeaconBlockHeader,
+    validators: List<Validator, VALIDATOR_REGISTRY_LIMIT>,
+    balances: List<u64, VALIDATOR_REGISTRY_LIMIT>,
+    previous_justified_checkpoint: Checkpoint,
+    current_justified_checkpoint: Checkpoint,
+    finalized_checkpoint: Checkpoint,
+}
+
+impl<const VALIDATOR_REGISTRY_LIMIT: usize> MockStateCore<VALIDATOR_REGISTRY_LIMIT> {
+    fn new(rng: &mut MockRng, fork_version: [u8; 4], context: &Context) -> Self {
+        let epoch = rng.gen_range(4, MAX_MOCK_EPOCH);
+        let slot = epoch * context.slots_per_epoch + rng.gen_range(0, context.slots_per_epoch);
+
+        let validator_count =
+            rng.gen_range(1, usize::min(MAX_MOCK_VALIDATORS, VALIDATOR_REGISTRY_LIMIT) as u64 + 1);
+        let mut validators = Vec::with_capacity(validator_count as usize);
+        let mut balances = Vec::with_capacity(validator_count as usize);
+        for _ in 0..validator_count {
+            let mut validator = Validator::mock(rng, context);
+            // ensure the validator is active at the mocked epoch
+            validator.activation_eligibility_epoch = 0;
+            validator.activation_epoch = rng.gen_range(0, epoch);
+            validator.exit_epoch = FAR_FUTURE_EPOCH;
+            validator.withdrawable_epoch = FAR_FUTURE_EPOCH;
+            validator.slashed = false;
+            balances.push(validator.effective_balance);
+            validators.push(validator);
+        }
+
+        let finalized_epoch = rng.gen_range(0, epoch - 1);
+        let current_justified_epoch = rng.gen_range(finalized_epoch, epoch);
+        let previous_justified_epoch = rng.gen_range(finalized_epoch, current_justified_epoch + 1);
+        let mut latest_block_header = BeaconBlockHeader::mock(rng, context);
+        latest_block_header.slot = slot;
+        latest_block_header.state_root = Root::default();
+
+        Self {
+            slot,
+            fork: Fork {
+                previous_version: fork_version,
+                current_version: fork_version,
+                epoch: rng.gen_range(0, epoch + 1),
+            },
+            latest_block_header,
+            validators: validators.try_into().map_err(|(_, err)| err).expect("within bounds"),
+            balances: balances.try_into().map_err(|(_, err)| err).expect("within bounds"),
+            previous_justified_checkpoint: Checkpoint {
+                epoch: previous_justified_epoch,
+                root: Root::mock(rng, context),
+            },
+            current_justified_checkpoint: Checkpoint {
+                epoch: current_justified_epoch,
+                root: Root::mock(rng, context),
+            },
+            finalized_checkpoint: Checkpoint {
+                epoch: finalized_epoch,
+                root: Root::mock(rng, context),
+            },
+        }
+    }
+}
+
+fn mock_vector<T: Mock + Serializable + Default + Clone, const N: usize>(
+    rng: &mut MockRng,
+    context: &Context,
+) -> Vector<T, N> {
+    let elements = (0..N).map(|_| T::mock(rng, context)).collect::<Vec<_>>();
+    Vector::try_from(elements).map_err(|(_, err)| err).expect("is exact length")
+}
+
+impl<
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const PENDING_ATTESTATIONS_BOUND: usize,
+    > Mock
+    for phase0::BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >
+{
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        let core = MockStateCore::new(rng, context.genesis_fork_version, context);
+        Self {
+            genesis_time: context.min_genesis_time,
+            genesis_validators_root: Root::mock(rng, context),
+            slot: core.slot,
+            fork: core.fork,
+            latest_block_header: core.latest_block_header,
+            block_roots: mock_vector(rng, context),
+            state_roots: mock_vector(rng, context),
+            eth1_data: Eth1Data::mock(rng, context),
+            validators: core.validators,
+            balances: core.balances,
+            randao_mixes: mock_vector(rng, context),
+            previous_justified_checkpoint: core.previous_justified_checkpoint,
+            current_justified_checkpoint: core.current_justified_checkpoint,
+            finalized_checkpoint: core.finalized_checkpoint,
+            ..Default::default()
+        }
+    }
+}
+
+impl<
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    > Mock
+    for altair::BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >
+{
+    fn mock(rng: &mut MockRng, context: &Context) -> Self {
+        let core = MockStateCore::<VALIDATOR_REGISTRY_LIMIT>::new(
+            rng,
+            context.altair_fork_version,
+            context,
+        );
+        let validator_count = core.validators.len();
+        let participation = (0..validator_count)
+            .map(|_| rng.gen_range(0, 1 << PARTICIPATION_FLAGS) as u8)
+            .collect::<Vec<_>>();
+        // sync committee members are sampled from the registry
+        let public_keys = (0..SYNC_COMMITTEE_SIZE)
+            .map(|_| {
+                let index = rng.gen_range(0, validator_count as u64) as usize;
+                core.validators[index].public_key.clone()
+            })
+            .collect::<Vec<_>>();
+        let sync_committee = SyncCommittee {
+            public_keys: Vector::try_from(public_keys).map_err(|(_, err)| err).expect("exact"),
+            aggregate_public_key: BlsPublicKey::mock(rng, context),
+        };
+        Self {
+            genesis_time: context.min_genesis_time,
+            genesis_validators_root: Root::mock(rng, context),
+            slot: core.slot,
+            fork: core.fork,
+            latest_block_header: core.latest_block_header,
+            block_roots: mock_vector(rng, context),
+            state_roots: mock_vector(rng, context),
+            eth1_data: Eth1Data::mock(rng, context),
+            validators: core.validators,
+            balances: core.balances,
+            randao_mixes: mock_vector(rng, context),
+            previous_epoch_participation: participation.clone().try_into().unwrap_or_default(),
+            current_epoch_participation: participation.try_into().unwrap_or_default(),
+            previous_justified_checkpoint: core.previous_justified_checkpoint,
+            current_justified_checkpoint: core.current_justified_checkpoint,
+            finalized_checkpoint: core.finalized_checkpoint,
+            inactivity_scores: vec![0; validator_count].try_into().unwrap_or_default(),
+            current_sync_committee: sync_committee.clone(),
+            next_sync_committee: sync_committee,
+            ..Default::default()
+        }
+    }
+}
+
+const PARTICIPATION_FLAGS: u64 = altair::PARTICIPATION_FLAG_WEIGHTS.len() as u64;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        phase0::{compute_epoch_at_slot, is_active_validator},
+        ssz::prelude::Merkleized,
+    };
+
+    type BeaconState = phase0::minimal::BeaconState;
+
+    #[test]
+    fn test_mock_is_deterministic() {
+        let context = Context::for_minimal();
+        let a = mock_with_context::<phase0::minimal::SignedBeaconBlock>(7, &context);
+        let b = mock_with_context::<phase0::minimal::SignedBeaconBlock>(7, &context);
+        assert_eq!(a.hash_tree_root().unwrap(), b.hash_tree_root().unwrap());
+        let c = mock_with_context::<phase0::minimal::SignedBeaconBlock>(8, &context);
+        assert_ne!(a.hash_tree_root().unwrap(), c.hash_tree_root().unwrap());
+    }
+
+    #[test]
+    fn test_mock_state_invariants() {
+        let context = Context::for_minimal();
+        for seed in 0..8 {
+            let state = mock_with_context::<altair::minimal::BeaconState>(seed, &context);
+            let epoch = compute_epoch_at_slot(state.slot, &context);
+            assert_eq!(state.validators.len(), state.balances.len());
+            assert_eq!(state.validators.len(), state.current_epoch_participation.len());
+            assert_eq!(state.validators.len(), state.inactivity_scores.len());
+            assert!(state.validators.iter().all(|v| is_active_validator(v, epoch)));
+            assert!(state.finalized_checkpoint.epoch <= state.current_justified_checkpoint.epoch);
+            assert!(state.current_justified_checkpoint.epoch < epoch);
+        }
+    }
+
+    #[test]
+    fn test_mock_block_attestations() {
+        let context = Context::for_minimal();
+        for seed in 0..8 {
+            let block = mock_with_context::<phase0::minimal::BeaconBlock>(seed, &context);
+            for attestation in block.body.attestations.iter() {
+                let data = &attestation.data;
+                assert!(data.slot < block.slot);
+                assert!(data.slot + context.slots_per_epoch >= block.slot);
+                assert_eq!(data.target.epoch, compute_epoch_at_slot(data.slot, &context));
+                assert!(data.source.epoch <= data.target.epoch);
+                assert!(attestation.aggregation_bits.iter().any(|bit| *bit));
+            }
+        }
+    }
+
+    #[test]
+    fn test_mock_attestation_for_committee() {
+        let context = Context::for_minimal();
+        let mut rng = MockRng::new(3);
+        let state = BeaconState::mock(&mut rng, &context);
+        let slot = state.slot;
+        let attestation = mock_attestation_for_committee(&mut rng, &state, slot, 0, &context)
+            .expect("can build attestation");
+        let committee = get_beacon_committee(&state, slot, 0, &context).unwrap();
+        assert_eq!(attestation.aggregation_bits.len(), committee.len());
+    }
+}
@@ -1,9 +1,18 @@
+use crate::{
+    altair::{
+        constants::SYNC_COMMITTEE_SUBNET_COUNT, mainnet::TARGET_AGGREGATORS_PER_SYNC_SUBCOMMITTEE,
+    },
+    deneb::networking::BLOB_SIDECAR_SUBNET_COUNT,
+    phase0::{mainnet::TARGET_AGGREGATORS_PER_COMMITTEE, networking::ATTESTATION_SUBNET_COUNT},
+    state_transition::Context,
+    Fork,
+};
 use enr;
 pub use multiaddr::Multiaddr;
 use multihash::{Code, Error, Multihash};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::{convert::TryFrom, fmt, str::FromStr};
+use std::{convert::TryFrom, fmt, str::FromStr, time::Duration};
 use thiserror::Error;
 
 pub const MAX_INLINE_KEY_LENGTH: usize = 42;
@@ -125,6 +134,168 @@ pub enum MessageDomain {
     ValidSnappy,
 }
 
+/// The global gossip topics, with subnet topics (e.g. `beacon_attestation_{subnet_id}`)
+/// represented once for all of their subnets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GossipTopicKind {
+    BeaconBlock,
+    BeaconAggregateAndProof,
+    BeaconAttestation,
+    VoluntaryExit,
+    ProposerSlashing,
+    AttesterSlashing,
+    SyncCommitteeContributionAndProof,
+    SyncCommittee,
+    BlsToExecutionChange,
+    BlobSidecar,
+}
+
+impl GossipTopicKind {
+    /// The topic name, without the `_{subnet_id}` suffix for subnet topics.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::BeaconBlock => "beacon_block",
+            Self::BeaconAggregateAndProof => "beacon_aggregate_and_proof",
+            Self::BeaconAttestation => "beacon_attestation",
+            Self::VoluntaryExit => "voluntary_exit",
+            Self::ProposerSlashing => "proposer_slashing",
+            Self::AttesterSlashing => "attester_slashing",
+            Self::SyncCommitteeContributionAndProof => "sync_committee_contribution_and_proof",
+            Self::SyncCommittee => "sync_committee",
+            Self::BlsToExecutionChange => "bls_to_execution_change",
+            Self::BlobSidecar => "blob_sidecar",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TopicRate {
+    pub topic: GossipTopicKind,
+    /// Number of subnets the topic is split over, or 1 for global topics.
+    pub subnet_count: usize,
+    /// Expected number of messages per slot on each subnet of the topic.
+    ///
+    /// For operations which are only produced occasionally (exits, slashings, BLS to
+    /// execution changes) this is the most that can be included in a block, i.e. an upper
+    /// bound on the sustained rate.
+    pub messages_per_slot: f64,
+}
+
+/// The parameters gossipsub peer scoring is configured from, derived from the `Context`
+/// and the size of the active validator set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GossipScoringInputs {
+    pub slot_duration: Duration,
+    pub slots_per_epoch: u64,
+    pub active_validator_count: usize,
+    pub committees_per_slot: u64,
+    pub topic_rates: Vec<TopicRate>,
+}
+
+impl GossipScoringInputs {
+    /// Compute the expected message rates of each topic active at `fork`.
+    pub fn new(active_validator_count: usize, fork: Fork, context: &Context) -> Self {
+        let slots_per_epoch = context.slots_per_epoch;
+        // NOTE: mirrors `get_committee_count_per_slot`
+        let committees_per_slot = u64::max(
+            1,
+            u64::min(
+                context.max_committees_per_slot,
+                active_validator_count as u64 / slots_per_epoch / context.target_committee_size,
+            ),
+        );
+        let committee_size =
+            active_validator_count as f64 / (slots_per_epoch * committees_per_slot) as f64;
+        let attesters_per_slot = active_validator_count as f64 / slots_per_epoch as f64;
+
+        let mut topic_rates = vec![
+            TopicRate {
+                topic: GossipTopicKind::BeaconBlock,
+                subnet_count: 1,
+                messages_per_slot: 1.0,
+            },
+            TopicRate {
+                topic: GossipTopicKind::BeaconAggregateAndProof,
+                subnet_count: 1,
+                messages_per_slot: committees_per_slot as f64 *
+                    committee_size.min(TARGET_AGGREGATORS_PER_COMMITTEE as f64),
+            },
+            TopicRate {
+                topic: GossipTopicKind::BeaconAttestation,
+                subnet_count: ATTESTATION_SUBNET_COUNT,
+                messages_per_slot: attesters_per_slot / ATTESTATION_SUBNET_COUNT as f64,
+            },
+            TopicRate {
+                topic: GossipTopicKind::VoluntaryExit,
+                subnet_count: 1,
+                messages_per_slot: context.max_voluntary_exits as f64,
+            },
+            TopicRate {
+                topic: GossipTopicKind::ProposerSlashing,
+                subnet_count: 1,
+                messages_per_slot: context.max_proposer_slashings as f64,
+            },
+            TopicRate {
+                topic: GossipTopicKind::AttesterSlashing,
+                subnet_count: 1,
+                messages_per_slot: context.max_attester_slashings as f64,
+            },
+        ];
+        if fork.supports_sync_aggregate() {
+            let subcommittee_size = context.sync_committee_size / SYNC_COMMITTEE_SUBNET_COUNT;
+            let aggregators_per_subcommittee =
+                usize::min(subcommittee_size, TARGET_AGGREGATORS_PER_SYNC_SUBCOMMITTEE);
+            topic_rates.push(TopicRate {
+                topic: GossipTopicKind::SyncCommitteeContributionAndProof,
+                subnet_count: 1,
+                messages_per_slot: (SYNC_COMMITTEE_SUBNET_COUNT * aggregators_per_subcommittee)
+                    as f64,
+            });
+            topic_rates.push(TopicRate {
+                topic: GossipTopicKind::SyncCommittee,
+                subnet_count: SYNC_COMMITTEE_SUBNET_COUNT,
+                messages_per_slot: subcommittee_size as f64,
+            });
+        }
+        if fork.supports_bls_to_execution_changes() {
+            topic_rates.push(TopicRate {
+                topic: GossipTopicKind::BlsToExecutionChange,
+                subnet_count: 1,
+                messages_per_slot: context.max_bls_to_execution_changes as f64,
+            });
+        }
+        if fork.supports_blob_commitments() {
+            topic_rates.push(TopicRate {
+                topic: GossipTopicKind::BlobSidecar,
+                subnet_count: BLOB_SIDECAR_SUBNET_COUNT,
+                messages_per_slot: context.max_blobs_per_block as f64 /
+                    BLOB_SIDECAR_SUBNET_COUNT as f64,
+            });
+        }
+
+        Self {
+            slot_duration: Duration::from_secs(context.seconds_per_slot),
+            slots_per_epoch,
+            active_validator_count,
+            committees_per_slot,
+            topic_rates,
+        }
+    }
+
+    pub fn epoch_duration(&self) -> Duration {
+        self.slot_duration * self.slots_per_epoch as u32
+    }
+
+    pub fn rate(&self, topic: GossipTopicKind) -> Option<&TopicRate> {
+        self.topic_rates.iter().find(|rate| rate.topic == topic)
+    }
+
+    /// Expected number of messages per epoch on each subnet of `topic`, if it is active.
+    pub fn messages_per_epoch(&self, topic: GossipTopicKind) -> Option<f64> {
+        self.rate(topic).map(|rate| rate.messages_per_slot * self.slots_per_epoch as f64)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,4 +314,29 @@ mod tests {
         let id: PeerId = PeerId::from_str(id_repr).unwrap();
         assert_eq!(format!("{id}"), "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N")
     }
+
+    #[test]
+    fn test_gossip_scoring_inputs() {
+        let context = Context::for_mainnet();
+        let inputs = GossipScoringInputs::new(500_000, Fork::Deneb, &context);
+        assert_eq!(inputs.committees_per_slot, 64);
+        assert_eq!(inputs.epoch_duration(), Duration::from_secs(384));
+        assert_eq!(inputs.messages_per_epoch(GossipTopicKind::BeaconBlock), Some(32.0));
+
+        let aggregates = inputs.rate(GossipTopicKind::BeaconAggregateAndProof).unwrap();
+        assert_eq!(aggregates.messages_per_slot, 64.0 * 16.0);
+        let attestations = inputs.rate(GossipTopicKind::BeaconAttestation).unwrap();
+        assert_eq!(attestations.subnet_count, 64);
+        assert_eq!(attestations.messages_per_slot, 500_000.0 / 32.0 / 64.0);
+        assert!(inputs.rate(GossipTopicKind::BlobSidecar).is_some());
+
+        let inputs = GossipScoringInputs::new(64, Fork::Phase0, &context);
+        assert_eq!(inputs.committees_per_slot, 1);
+        assert_eq!(
+            inputs.rate(GossipTopicKind::BeaconAggregateAndProof).unwrap().messages_per_slot,
+            2.0
+        );
+        assert!(inputs.rate(GossipTopicKind::SyncCommittee).is_none());
+        assert!(inputs.rate(GossipTopicKind::BlsToExecutionChange).is_none());
+    }
 }
@@ -15,6 +15,7 @@ pub enum Network {
     Sepolia,
     Goerli,
     Holesky,
+    Gnosis,
     Custom(String),
 }
 
@@ -25,6 +26,7 @@ impl std::fmt::Display for Network {
             Self::Sepolia => write!(f, "sepolia"),
             Self::Goerli => write!(f, "goerli"),
             Self::Holesky => write!(f, "holesky"),
+            Self::Gnosis => write!(f, "gnosis"),
             Self::Custom(config_dir) => write!(f, "{config_dir}"),
         }
     }
@@ -43,6 +45,7 @@ impl From<String> for Network {
             "sepolia" => Self::Sepolia,
             "goerli" => Self::Goerli,
             "holesky" => Self::Holesky,
+            "gnosis" => Self::Gnosis,
             _ => Self::Custom(value),
         }
     }
@@ -57,6 +60,7 @@ impl TryFrom<Network> for Context {
             Network::Sepolia => Ok(Context::for_sepolia()),
             Network::Goerli => Ok(Context::for_goerli()),
             Network::Holesky => Ok(Context::for_holesky()),
+            Network::Gnosis => Ok(Context::for_gnosis()),
             Network::Custom(config) => {
                 let config_file = PathBuf::from(config).join("config.yaml");
                 Context::try_from_file(config_file)
@@ -87,4 +91,14 @@ mod tests {
         let recovered_file: File = toml::from_str(&str).unwrap();
         assert_eq!(file, recovered_file);
     }
+
+    #[test]
+    fn test_gnosis() {
+        let network = Network::from("gnosis".to_string());
+        assert_eq!(network, Network::Gnosis);
+        let context = Context::try_from(network).unwrap();
+        assert_eq!(context.network(), &Network::Gnosis);
+        assert_eq!(context.deposit_chain_id, 100);
+        assert!(context.clock().is_some());
+    }
 }
@@ -0,0 +1,159 @@
+//! A single view over the preset values of every fork.
+//!
+//! Each fork module defines its own `Preset` with the values it introduces, so code that is
+//! generic over forks would otherwise have to know which module a given limit lives in.
+//! `SpecParameters` collects the presets of every fork up to some target fork. It is available
+//! as a constant in each fork's `mainnet` and `minimal` modules, and from a (possibly runtime
+//! loaded) `Context` via `Context::spec_parameters`.
+use crate::{altair, bellatrix, capella, deneb, electra, phase0, Fork};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecParameters {
+    pub fork: Fork,
+    pub phase0: phase0::Preset,
+    pub altair: Option<altair::Preset>,
+    pub bellatrix: Option<bellatrix::Preset>,
+    pub capella: Option<capella::Preset>,
+    pub deneb: Option<deneb::Preset>,
+    pub electra: Option<electra::Preset>,
+}
+
+impl SpecParameters {
+    /// Return the parameters in effect at `fork`, dropping the presets of any later fork.
+    ///
+    /// Returns `None` if `fork` is after `self.fork`, as those presets are not known.
+    pub fn at_fork(&self, fork: Fork) -> Option<Self> {
+        if fork > self.fork {
+            return None
+        }
+        let mut parameters = self.clone();
+        parameters.fork = fork;
+        if fork < Fork::Altair {
+            parameters.altair = None;
+        }
+        if fork < Fork::Bellatrix {
+            parameters.bellatrix = None;
+        }
+        if fork < Fork::Capella {
+            parameters.capella = None;
+        }
+        if fork < Fork::Deneb {
+            parameters.deneb = None;
+        }
+        if fork < Fork::Electra {
+            parameters.electra = None;
+        }
+        Some(parameters)
+    }
+
+    pub fn slots_per_epoch(&self) -> u64 {
+        self.phase0.slots_per_epoch
+    }
+
+    pub fn max_validators_per_committee(&self) -> usize {
+        self.phase0.max_validators_per_committee
+    }
+
+    pub fn max_attestations(&self) -> usize {
+        self.phase0.max_attestations
+    }
+
+    pub fn sync_committee_size(&self) -> Option<usize> {
+        self.altair.as_ref().map(|preset| preset.sync_committee_size)
+    }
+
+    pub fn max_transactions_per_payload(&self) -> Option<usize> {
+        self.bellatrix.as_ref().map(|preset| preset.max_transactions_per_payload)
+    }
+
+    pub fn max_withdrawals_per_payload(&self) -> Option<usize> {
+        self.capella.as_ref().map(|preset| preset.max_withdrawals_per_payload)
+    }
+
+    pub fn max_blob_commitments_per_block(&self) -> Option<usize> {
+        self.deneb.as_ref().map(|preset| preset.max_blob_commitments_per_block)
+    }
+
+    pub fn max_attestations_electra(&self) -> Option<usize> {
+        self.electra.as_ref().map(|preset| preset.max_attestations_electra)
+    }
+
+    /// The (inactivity penalty quotient, min slashing penalty quotient, proportional slashing
+    /// multiplier) used by the state transition at `self.fork`.
+    pub fn penalty_parameters(&self) -> (u64, u64, u64) {
+        if let Some(preset) = &self.bellatrix {
+            (
+                preset.inactivity_penalty_quotient_bellatrix,
+                preset.min_slashing_penalty_quotient_bellatrix,
+                preset.proportional_slashing_multiplier_bellatrix,
+            )
+        } else if let Some(preset) = &self.altair {
+            (
+                preset.inactivity_penalty_quotient_altair,
+                preset.min_slashing_penalty_quotient_altair,
+                preset.proportional_slashing_multiplier_altair,
+            )
+        } else {
+            (
+                self.phase0.inactivity_penalty_quotient,
+                self.phase0.min_slashing_penalty_quotient,
+                self.phase0.proportional_slashing_multiplier,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state_transition::Context;
+
+    #[test]
+    fn test_spec_parameters_match_context() {
+        let context = Context::for_mainnet();
+        assert_eq!(context.spec_parameters(Fork::Deneb), deneb::mainnet::SPEC_PARAMETERS);
+        assert_eq!(context.spec_parameters(Fork::Altair), altair::mainnet::SPEC_PARAMETERS);
+        assert_eq!(context.spec_parameters(Fork::Electra), electra::mainnet::SPEC_PARAMETERS);
+
+        let context = Context::for_gnosis();
+        assert_eq!(context.spec_parameters(Fork::Deneb), deneb::gnosis::SPEC_PARAMETERS);
+        assert_eq!(context.slots_per_epoch, phase0::gnosis::SLOTS_PER_EPOCH);
+        assert_eq!(
+            context.max_withdrawals_per_payload,
+            capella::gnosis::MAX_WITHDRAWALS_PER_PAYLOAD
+        );
+
+        let context = Context::for_minimal();
+        assert_eq!(context.spec_parameters(Fork::Capella), capella::minimal::SPEC_PARAMETERS);
+        assert_eq!(
+            deneb::minimal::SPEC_PARAMETERS.at_fork(Fork::Bellatrix),
+            Some(bellatrix::minimal::SPEC_PARAMETERS)
+        );
+        assert!(phase0::minimal::SPEC_PARAMETERS.at_fork(Fork::Altair).is_none());
+        assert_eq!(
+            electra::minimal::SPEC_PARAMETERS.at_fork(Fork::Deneb),
+            Some(deneb::minimal::SPEC_PARAMETERS)
+        );
+    }
+
+    #[test]
+    fn test_spec_parameters_accessors() {
+        let parameters = bellatrix::minimal::SPEC_PARAMETERS;
+        assert_eq!(parameters.sync_committee_size(), Some(altair::minimal::SYNC_COMMITTEE_SIZE));
+        assert_eq!(parameters.max_attestations(), phase0::minimal::MAX_ATTESTATIONS);
+        assert!(parameters.max_withdrawals_per_payload().is_none());
+        assert!(parameters.max_attestations_electra().is_none());
+        assert_eq!(
+            electra::minimal::SPEC_PARAMETERS.max_attestations_electra(),
+            Some(electra::minimal::MAX_ATTESTATIONS_ELECTRA)
+        );
+        assert_eq!(
+            parameters.penalty_parameters().0,
+            bellatrix::minimal::INACTIVITY_PENALTY_QUOTIENT_BELLATRIX
+        );
+        assert_eq!(
+            phase0::minimal::SPEC_PARAMETERS.penalty_parameters().0,
+            phase0::minimal::INACTIVITY_PENALTY_QUOTIENT
+        );
+    }
+}
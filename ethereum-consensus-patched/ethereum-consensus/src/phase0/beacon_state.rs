@@ -10,7 +10,17 @@ use crate::{
 };
 
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct Fork {
     #[serde(with = "crate::serde::as_hex")]
@@ -37,7 +47,17 @@ pub struct HistoricalBatch<const SLOTS_PER_HISTORICAL_ROOT: usize> {
 // Note: `HistoricalSummary` is defined in the `capella` specs; however, this // repo used the same
 // strategy to compute the `HistoricalBatch` roots so // the type already existed.
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct HistoricalSummary {
     pub block_summary_root: Root,
@@ -45,7 +65,17 @@ pub struct HistoricalSummary {
 }
 
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct BeaconState<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
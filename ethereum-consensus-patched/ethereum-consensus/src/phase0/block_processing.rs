@@ -273,7 +273,7 @@ pub fn process_attestation<
                 },
             )))
         }
-        state.current_epoch_attestations.push(pending_attestation);
+        state.current_epoch_attestations.push(pending_attestation)?;
     } else {
         if data.source != state.previous_justified_checkpoint {
             return Err(invalid_operation_error(InvalidOperation::Attestation(
@@ -284,7 +284,7 @@ pub fn process_attestation<
                 },
             )))
         }
-        state.previous_epoch_attestations.push(pending_attestation);
+        state.previous_epoch_attestations.push(pending_attestation)?;
     }
 
     Ok(())
@@ -373,8 +373,8 @@ pub fn process_deposit<
             return Ok(())
         }
 
-        state.validators.push(get_validator_from_deposit(deposit, context));
-        state.balances.push(amount);
+        state.validators.push(get_validator_from_deposit(deposit, context))?;
+        state.balances.push(amount)?;
     } else {
         let index = state.validators.iter().position(|v| &v.public_key == public_key).unwrap();
 
@@ -632,8 +632,8 @@ pub fn process_eth1_data<
         MAX_VOLUNTARY_EXITS,
     >,
     context: &Context,
-) {
-    state.eth1_data_votes.push(body.eth1_data.clone());
+) -> Result<()> {
+    state.eth1_data_votes.push(body.eth1_data.clone())?;
 
     let votes_count =
         state.eth1_data_votes.iter().filter(|&vote| *vote == body.eth1_data).count() as u64;
@@ -641,6 +641,7 @@ pub fn process_eth1_data<
     if votes_count * 2 > context.epochs_per_eth1_voting_period * context.slots_per_epoch {
         state.eth1_data = body.eth1_data.clone();
     }
+    Ok(())
 }
 
 pub fn process_operations<
@@ -743,7 +744,7 @@ pub fn process_block<
 ) -> Result<()> {
     process_block_header(state, block, context)?;
     process_randao(state, &block.body, context)?;
-    process_eth1_data(state, &block.body, context);
+    process_eth1_data(state, &block.body, context)?;
     process_operations(state, &mut block.body, context)?;
     Ok(())
 }
@@ -62,10 +62,7 @@ pub fn is_slashable_validator(validator: &Validator, epoch: Epoch) -> bool {
 }
 
 pub fn is_slashable_attestation_data(data_1: &AttestationData, data_2: &AttestationData) -> bool {
-    let double_vote = data_1 != data_2 && data_1.target.epoch == data_2.target.epoch;
-    let surround_vote =
-        data_1.source.epoch < data_2.source.epoch && data_2.target.epoch < data_1.target.epoch;
-    double_vote || surround_vote
+    data_1.is_slashable(data_2)
 }
 
 pub fn is_valid_indexed_attestation<
@@ -380,10 +377,7 @@ pub fn compute_domain(
     let genesis_validators_root = genesis_validators_root.unwrap_or_default();
     let fork_data_root = compute_fork_data_root(fork_version, genesis_validators_root)?;
 
-    let mut domain = Domain::default();
-    domain[..4].copy_from_slice(&domain_type.as_bytes());
-    domain[4..].copy_from_slice(&fork_data_root[..28]);
-    Ok(domain)
+    Ok(Domain::new(domain_type, &fork_data_root))
 }
 
 pub fn compute_fork_data_root(
@@ -3,19 +3,24 @@
 //! the "presets" like `mainnet` or `minimal`.
 pub mod beacon_block;
 pub mod beacon_state;
+#[cfg(feature = "transition")]
 pub mod block_processing;
 pub mod constants;
+#[cfg(feature = "transition")]
 pub mod epoch_processing;
+#[cfg(feature = "transition")]
 pub mod genesis;
 pub mod helpers;
 pub mod networking;
 pub mod operations;
 pub mod presets;
+#[cfg(feature = "transition")]
 pub mod slot_processing;
 pub mod spec;
+#[cfg(feature = "transition")]
 pub mod state_transition;
 pub mod validator;
 
 pub use spec::*;
 
-pub use presets::{mainnet, minimal, Preset};
+pub use presets::{gnosis, mainnet, minimal, Preset};
@@ -7,8 +7,19 @@ use crate::{
     ssz::prelude::*,
 };
 
+/// Checkpoints are ordered by epoch, and then by root to give a total order.
 #[derive(
-    Default, Clone, Debug, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Clone,
+    Debug,
+    SimpleSerialize,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct Checkpoint {
     #[serde(with = "crate::serde::as_str")]
@@ -17,7 +28,17 @@ pub struct Checkpoint {
 }
 
 #[derive(
-    Default, Clone, Debug, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Clone,
+    Debug,
+    SimpleSerialize,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct AttestationData {
     #[serde(with = "crate::serde::as_str")]
@@ -29,8 +50,47 @@ pub struct AttestationData {
     pub target: Checkpoint,
 }
 
+impl AttestationData {
+    pub fn is_same_target(&self, other: &Self) -> bool {
+        self.target == other.target
+    }
+
+    pub fn is_same_source(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+
+    /// Returns `true` if `self` and `other` are distinct votes for the same target epoch.
+    pub fn is_double_vote(&self, other: &Self) -> bool {
+        self != other && self.target.epoch == other.target.epoch
+    }
+
+    /// Returns `true` if the source and target of `self` strictly surround those of `other`.
+    pub fn surrounds(&self, other: &Self) -> bool {
+        self.source.epoch < other.source.epoch && other.target.epoch < self.target.epoch
+    }
+
+    /// Returns `true` if attesting to both `self` and `other` is slashable, i.e. they form a
+    /// double vote or `self` surrounds `other`.
+    ///
+    /// Note that like `is_slashable_attestation_data` this is not symmetric; to detect a
+    /// surround vote in either direction, check both `a.is_slashable(b)` and `b.is_slashable(a)`.
+    pub fn is_slashable(&self, other: &Self) -> bool {
+        self.is_double_vote(other) || self.surrounds(other)
+    }
+}
+
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct IndexedAttestation<const MAX_VALIDATORS_PER_COMMITTEE: usize> {
     #[serde(with = "crate::serde::seq_of_str")]
@@ -40,7 +100,17 @@ pub struct IndexedAttestation<const MAX_VALIDATORS_PER_COMMITTEE: usize> {
 }
 
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct PendingAttestation<const MAX_VALIDATORS_PER_COMMITTEE: usize> {
     pub aggregation_bits: Bitlist<MAX_VALIDATORS_PER_COMMITTEE>,
@@ -52,7 +122,17 @@ pub struct PendingAttestation<const MAX_VALIDATORS_PER_COMMITTEE: usize> {
 }
 
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct Attestation<const MAX_VALIDATORS_PER_COMMITTEE: usize> {
     pub aggregation_bits: Bitlist<MAX_VALIDATORS_PER_COMMITTEE>,
@@ -61,7 +141,17 @@ pub struct Attestation<const MAX_VALIDATORS_PER_COMMITTEE: usize> {
 }
 
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct Eth1Data {
     pub deposit_root: Root,
@@ -80,7 +170,17 @@ pub struct DepositMessage {
 }
 
 #[derive(
-    Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    Clone,
+    SimpleSerialize,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct DepositData {
     #[serde(rename = "pubkey")]
@@ -92,7 +192,17 @@ pub struct DepositData {
 }
 
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct ProposerSlashing {
     pub signed_header_1: SignedBeaconBlockHeader,
@@ -100,7 +210,17 @@ pub struct ProposerSlashing {
 }
 
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct AttesterSlashing<const MAX_VALIDATORS_PER_COMMITTEE: usize> {
     pub attestation_1: IndexedAttestation<MAX_VALIDATORS_PER_COMMITTEE>,
@@ -114,7 +234,17 @@ const fn get_deposit_proof_length() -> usize {
 const DEPOSIT_PROOF_LENGTH: usize = get_deposit_proof_length();
 
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct Deposit {
     pub proof: Vector<Bytes32, DEPOSIT_PROOF_LENGTH>,
@@ -122,7 +252,17 @@ pub struct Deposit {
 }
 
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct VoluntaryExit {
     #[serde(with = "crate::serde::as_str")]
@@ -132,9 +272,59 @@ pub struct VoluntaryExit {
 }
 
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct SignedVoluntaryExit {
     pub message: VoluntaryExit,
     pub signature: BlsSignature,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(source_epoch: Epoch, target_epoch: Epoch) -> AttestationData {
+        AttestationData {
+            source: Checkpoint { epoch: source_epoch, ..Default::default() },
+            target: Checkpoint { epoch: target_epoch, ..Default::default() },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_attestation_data_slashability() {
+        let outer = data(1, 5);
+        let inner = data(2, 4);
+        assert!(outer.surrounds(&inner));
+        assert!(outer.is_slashable(&inner));
+        assert!(!inner.is_slashable(&outer));
+
+        let mut other = data(1, 5);
+        assert!(!outer.is_slashable(&other));
+        other.beacon_block_root = Root::try_from([1u8; 32].as_ref()).unwrap();
+        assert!(outer.is_same_target(&data(0, 5)));
+        assert!(outer.is_same_source(&other));
+        assert!(outer.is_double_vote(&other));
+        assert!(outer.is_slashable(&other));
+    }
+
+    #[test]
+    fn test_checkpoint_ordering() {
+        let root = Root::try_from([1u8; 32].as_ref()).unwrap();
+        let a = Checkpoint { epoch: 1, root };
+        let b = Checkpoint { epoch: 2, root: Root::default() };
+        assert!(a < b);
+        assert!(Checkpoint { epoch: 1, root: Root::default() } < a);
+        assert_eq!([b.clone(), a.clone()].iter().max(), Some(&b));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::phase0::{presets::Preset, spec};
+use crate::{
+    parameters::SpecParameters,
+    phase0::{presets::Preset, spec},
+};
 
 pub use spec::*;
 
@@ -74,6 +77,16 @@ pub const PRESET: Preset = Preset {
     max_voluntary_exits: MAX_VOLUNTARY_EXITS,
 };
 
+pub const SPEC_PARAMETERS: SpecParameters = SpecParameters {
+    fork: crate::Fork::Phase0,
+    phase0: crate::phase0::presets::mainnet::PRESET,
+    altair: None,
+    bellatrix: None,
+    capella: None,
+    deneb: None,
+    electra: None,
+};
+
 pub type IndexedAttestation = spec::IndexedAttestation<MAX_VALIDATORS_PER_COMMITTEE>;
 pub type PendingAttestation = spec::PendingAttestation<MAX_VALIDATORS_PER_COMMITTEE>;
 pub type AttesterSlashing = spec::AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>;
@@ -123,5 +136,7 @@ pub type SignedBeaconBlock = spec::SignedBeaconBlock<
     MAX_VOLUNTARY_EXITS,
 >;
 
+#[cfg(feature = "validator")]
 pub type AggregateAndProof = spec::AggregateAndProof<MAX_VALIDATORS_PER_COMMITTEE>;
+#[cfg(feature = "validator")]
 pub type SignedAggregateAndProof = spec::SignedAggregateAndProof<MAX_VALIDATORS_PER_COMMITTEE>;
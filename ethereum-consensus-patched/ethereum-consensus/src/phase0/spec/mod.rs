@@ -1,4 +1,106 @@
 //! WARNING: This file was derived by the `spec-gen` utility. DO NOT EDIT MANUALLY.
+#[cfg(feature = "transition")]
+pub use crate::phase0::block_processing::get_validator_from_deposit;
+#[cfg(feature = "transition")]
+pub use crate::phase0::block_processing::process_attestation;
+#[cfg(feature = "transition")]
+pub use crate::phase0::block_processing::process_attester_slashing;
+#[cfg(feature = "transition")]
+pub use crate::phase0::block_processing::process_block;
+#[cfg(feature = "transition")]
+pub use crate::phase0::block_processing::process_block_header;
+#[cfg(feature = "transition")]
+pub use crate::phase0::block_processing::process_deposit;
+#[cfg(feature = "transition")]
+pub use crate::phase0::block_processing::process_eth1_data;
+#[cfg(feature = "transition")]
+pub use crate::phase0::block_processing::process_operations;
+#[cfg(feature = "transition")]
+pub use crate::phase0::block_processing::process_proposer_slashing;
+#[cfg(feature = "transition")]
+pub use crate::phase0::block_processing::process_randao;
+#[cfg(feature = "transition")]
+pub use crate::phase0::block_processing::process_voluntary_exit;
+#[cfg(feature = "transition")]
+pub use crate::phase0::block_processing::xor;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_attestation_component_deltas;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_attestation_deltas;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_attesting_balance;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_base_reward;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_finality_delay;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_head_deltas;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_inactivity_penalty_deltas;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_inclusion_delay_deltas;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_matching_head_attestations;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_matching_source_attestations;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_matching_target_attestations;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_proposer_reward;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_source_deltas;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_target_deltas;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::get_unslashed_attesting_indices;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::is_in_inactivity_leak;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::process_effective_balance_updates;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::process_epoch;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::process_eth1_data_reset;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::process_historical_roots_update;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::process_justification_and_finalization;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::process_participation_record_updates;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::process_randao_mixes_reset;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::process_registry_updates;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::process_rewards_and_penalties;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::process_slashings;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::process_slashings_reset;
+#[cfg(feature = "transition")]
+pub use crate::phase0::epoch_processing::weigh_justification_and_finalization;
+#[cfg(feature = "transition")]
+pub use crate::phase0::genesis::get_genesis_block;
+#[cfg(feature = "transition")]
+pub use crate::phase0::genesis::initialize_beacon_state_from_eth1;
+#[cfg(feature = "transition")]
+pub use crate::phase0::genesis::initialize_beacon_state_from_eth1_with_progress;
+#[cfg(feature = "transition")]
+pub use crate::phase0::genesis::is_valid_genesis_state;
+#[cfg(feature = "transition")]
+pub use crate::phase0::slot_processing::process_slot;
+#[cfg(feature = "transition")]
+pub use crate::phase0::slot_processing::process_slots;
+#[cfg(feature = "transition")]
+pub use crate::phase0::state_transition::state_transition;
+#[cfg(feature = "transition")]
+pub use crate::phase0::state_transition::state_transition_block_in_slot;
+#[cfg(feature = "validator")]
+pub use crate::phase0::validator::AggregateAndProof;
+#[cfg(feature = "validator")]
+pub use crate::phase0::validator::Eth1Block;
+#[cfg(feature = "validator")]
+pub use crate::phase0::validator::SignedAggregateAndProof;
 pub use crate::{
     error::*,
     phase0::{
@@ -7,30 +109,10 @@ pub use crate::{
             SignedBeaconBlockHeader,
         },
         beacon_state::{BeaconState, Fork, ForkData, HistoricalBatch, HistoricalSummary},
-        block_processing::{
-            get_validator_from_deposit, process_attestation, process_attester_slashing,
-            process_block, process_block_header, process_deposit, process_eth1_data,
-            process_operations, process_proposer_slashing, process_randao, process_voluntary_exit,
-            xor,
-        },
         constants::{
             BASE_REWARDS_PER_EPOCH, DEPOSIT_CONTRACT_TREE_DEPTH, DEPOSIT_DATA_LIST_BOUND,
             JUSTIFICATION_BITS_LENGTH,
         },
-        epoch_processing::{
-            get_attestation_component_deltas, get_attestation_deltas, get_attesting_balance,
-            get_base_reward, get_finality_delay, get_head_deltas, get_inactivity_penalty_deltas,
-            get_inclusion_delay_deltas, get_matching_head_attestations,
-            get_matching_source_attestations, get_matching_target_attestations,
-            get_proposer_reward, get_source_deltas, get_target_deltas,
-            get_unslashed_attesting_indices, is_in_inactivity_leak,
-            process_effective_balance_updates, process_epoch, process_eth1_data_reset,
-            process_historical_roots_update, process_justification_and_finalization,
-            process_participation_record_updates, process_randao_mixes_reset,
-            process_registry_updates, process_rewards_and_penalties, process_slashings,
-            process_slashings_reset, weigh_justification_and_finalization,
-        },
-        genesis::{get_genesis_block, initialize_beacon_state_from_eth1, is_valid_genesis_state},
         helpers::{
             compute_activation_exit_epoch, compute_committee, compute_domain,
             compute_epoch_at_slot, compute_fork_data_root, compute_fork_digest,
@@ -50,9 +132,7 @@ pub use crate::{
             DepositMessage, Eth1Data, IndexedAttestation, PendingAttestation, ProposerSlashing,
             SignedVoluntaryExit, VoluntaryExit,
         },
-        slot_processing::{process_slot, process_slots},
-        state_transition::{state_transition, state_transition_block_in_slot},
-        validator::{AggregateAndProof, Eth1Block, SignedAggregateAndProof, Validator},
+        validator::Validator,
     },
     primitives::*,
     signing::*,
@@ -1,11 +1,25 @@
+#[cfg(feature = "validator")]
 use crate::{
     phase0::operations::Attestation,
-    primitives::{BlsPublicKey, BlsSignature, Bytes32, Epoch, Gwei, Root, ValidatorIndex},
+    primitives::{BlsSignature, Root, ValidatorIndex},
+};
+use crate::{
+    primitives::{BlsPublicKey, Bytes32, Epoch, Gwei},
     ssz::prelude::*,
 };
 
 #[derive(
-    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Default,
+    Debug,
+    SimpleSerialize,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct Validator {
     #[serde(rename = "pubkey")]
@@ -25,6 +39,7 @@ pub struct Validator {
     pub withdrawable_epoch: Epoch,
 }
 
+#[cfg(feature = "validator")]
 #[derive(Default, Debug, SimpleSerialize, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Eth1Block {
     pub timestamp: u64,
@@ -32,6 +47,7 @@ pub struct Eth1Block {
     pub deposit_count: u64,
 }
 
+#[cfg(feature = "validator")]
 #[derive(Default, Debug, SimpleSerialize, Clone, serde::Serialize, serde::Deserialize)]
 pub struct AggregateAndProof<const MAX_VALIDATORS_PER_COMMITTEE: usize> {
     #[serde(with = "crate::serde::as_str")]
@@ -40,6 +56,7 @@ pub struct AggregateAndProof<const MAX_VALIDATORS_PER_COMMITTEE: usize> {
     pub selection_proof: BlsSignature,
 }
 
+#[cfg(feature = "validator")]
 #[derive(Default, Debug, SimpleSerialize, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SignedAggregateAndProof<const MAX_VALIDATORS_PER_COMMITTEE: usize> {
     pub message: AggregateAndProof<MAX_VALIDATORS_PER_COMMITTEE>,
@@ -1,7 +1,7 @@
 use crate::ssz::prelude::*;
 pub use crate::{
     crypto::{PublicKey as BlsPublicKey, Signature as BlsSignature},
-    domains::DomainType,
+    domains::{Domain, DomainType},
     ssz::prelude::U256,
 };
 
@@ -18,7 +18,6 @@ pub type Hash32 = Bytes32;
 
 pub type Version = [u8; 4];
 pub type ForkDigest = [u8; 4];
-pub type Domain = [u8; 32];
 
 pub type ExecutionAddress = ByteVector<20>;
 
@@ -44,6 +43,7 @@ pub const FAR_FUTURE_EPOCH: Epoch = Epoch::MAX;
 
 pub const BLS_WITHDRAWAL_PREFIX: u8 = 0x00;
 pub const ETH1_ADDRESS_WITHDRAWAL_PREFIX: u8 = 0x01;
+pub const COMPOUNDING_WITHDRAWAL_PREFIX: u8 = 0x02;
 
 #[cfg(test)]
 #[cfg(feature = "serde")]
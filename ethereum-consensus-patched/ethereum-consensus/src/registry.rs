@@ -0,0 +1,224 @@
+//! Analysis of the composition of the validator registry, and helpers for
+//! maintaining compact mirrors of the registry keyed by active index.
+//!
+//! The registry is append-only: validators are never removed after exiting, so over time
+//! a growing share of it consists of validators that no longer take part in consensus.
+//! Protocols that mirror the registry (e.g. in a light client or a bridge contract) can
+//! use the helpers here to key their mirror by the index of each validator within the
+//! active set instead, and to remap that mirror after a large number of exits.
+use crate::{
+    phase0::{is_active_validator, Validator},
+    primitives::{Epoch, Gwei, ValidatorIndex},
+    state_transition::Context,
+};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegistryReport {
+    pub epoch: Epoch,
+    pub total: usize,
+    pub active: usize,
+    /// Validators which have not been activated yet.
+    pub pending: usize,
+    /// Validators which have exited but are retained in the registry.
+    pub exited: usize,
+    pub slashed: usize,
+    /// Count of validators for each effective balance, in multiples of
+    /// `EFFECTIVE_BALANCE_INCREMENT`.
+    pub effective_balance_distribution: BTreeMap<Gwei, usize>,
+    /// Validators which are withdrawable and have no remaining balance, so they can never
+    /// become active again and may be dropped from any mirror of the registry.
+    pub compaction_candidates: Vec<ValidatorIndex>,
+}
+
+impl RegistryReport {
+    /// Returns the share of the registry that could be dropped by compaction.
+    pub fn compaction_ratio(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0
+        }
+        self.compaction_candidates.len() as f64 / self.total as f64
+    }
+}
+
+/// Report the composition of the registry at `epoch`.
+///
+/// `balances` must be the balances list matching `validators`; validators without a
+/// corresponding balance are treated as having none.
+pub fn analyze_registry(
+    validators: &[Validator],
+    balances: &[Gwei],
+    epoch: Epoch,
+    context: &Context,
+) -> RegistryReport {
+    let mut report = RegistryReport { epoch, total: validators.len(), ..Default::default() };
+    for (index, validator) in validators.iter().enumerate() {
+        if is_active_validator(validator, epoch) {
+            report.active += 1;
+        } else if epoch < validator.activation_epoch {
+            report.pending += 1;
+        } else {
+            report.exited += 1;
+        }
+        if validator.slashed {
+            report.slashed += 1;
+        }
+
+        let increments = validator.effective_balance / context.effective_balance_increment;
+        *report.effective_balance_distribution.entry(increments).or_default() += 1;
+
+        let balance = balances.get(index).copied().unwrap_or_default();
+        if validator.withdrawable_epoch <= epoch && balance == 0 {
+            report.compaction_candidates.push(index);
+        }
+    }
+    report
+}
+
+/// A bidirectional mapping between the index of each validator in the registry and its
+/// index within the set of active validators at a given epoch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActiveIndexMap {
+    pub epoch: Epoch,
+    registry_indices: Vec<ValidatorIndex>,
+    compact_indices: HashMap<ValidatorIndex, usize>,
+}
+
+impl ActiveIndexMap {
+    pub fn new(validators: &[Validator], epoch: Epoch) -> Self {
+        let registry_indices = validators
+            .iter()
+            .enumerate()
+            .filter_map(|(index, validator)| is_active_validator(validator, epoch).then_some(index))
+            .collect::<Vec<_>>();
+        let compact_indices = registry_indices
+            .iter()
+            .enumerate()
+            .map(|(compact_index, &index)| (index, compact_index))
+            .collect();
+        Self { epoch, registry_indices, compact_indices }
+    }
+
+    pub fn len(&self) -> usize {
+        self.registry_indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.registry_indices.is_empty()
+    }
+
+    pub fn to_compact(&self, index: ValidatorIndex) -> Option<usize> {
+        self.compact_indices.get(&index).copied()
+    }
+
+    pub fn to_registry(&self, compact_index: usize) -> Option<ValidatorIndex> {
+        self.registry_indices.get(compact_index).copied()
+    }
+
+    /// The registry indices of the active validators, in compact order.
+    pub fn registry_indices(&self) -> &[ValidatorIndex] {
+        &self.registry_indices
+    }
+
+    /// Compute how a mirror keyed by the compact indices of `previous` must be rewritten to
+    /// be keyed by the compact indices of `self`.
+    pub fn remap_from(&self, previous: &Self) -> Remapping {
+        let mut remapping = Remapping::default();
+        for (old_index, registry_index) in previous.registry_indices.iter().enumerate() {
+            match self.to_compact(*registry_index) {
+                Some(new_index) if new_index != old_index => {
+                    remapping.moved.push((old_index, new_index))
+                }
+                Some(_) => {}
+                None => remapping.removed.push(old_index),
+            }
+        }
+        for (new_index, registry_index) in self.registry_indices.iter().enumerate() {
+            if previous.to_compact(*registry_index).is_none() {
+                remapping.added.push((new_index, *registry_index));
+            }
+        }
+        remapping
+    }
+}
+
+/// The changes needed to move a compact mirror of the registry from one active set to another.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Remapping {
+    /// Entries that remain, as `(old_compact_index, new_compact_index)`.
+    pub moved: Vec<(usize, usize)>,
+    /// Compact indices in the old mirror that are no longer active.
+    pub removed: Vec<usize>,
+    /// New entries, as `(new_compact_index, registry_index)`.
+    pub added: Vec<(usize, ValidatorIndex)>,
+}
+
+impl Remapping {
+    pub fn is_empty(&self) -> bool {
+        self.moved.is_empty() && self.removed.is_empty() && self.added.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::FAR_FUTURE_EPOCH;
+
+    fn validator(activation_epoch: Epoch, exit_epoch: Epoch, context: &Context) -> Validator {
+        let withdrawable_epoch = if exit_epoch == FAR_FUTURE_EPOCH {
+            FAR_FUTURE_EPOCH
+        } else {
+            exit_epoch + context.min_validator_withdrawability_delay
+        };
+        Validator {
+            effective_balance: context.max_effective_balance,
+            activation_epoch,
+            exit_epoch,
+            withdrawable_epoch,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_analyze_registry() {
+        let context = Context::for_minimal();
+        let validators = vec![
+            validator(0, FAR_FUTURE_EPOCH, &context),
+            validator(0, 2, &context),
+            validator(0, 2, &context),
+            validator(FAR_FUTURE_EPOCH, FAR_FUTURE_EPOCH, &context),
+        ];
+        let balances = vec![context.max_effective_balance, 0, 1, context.max_effective_balance];
+        let epoch = 2 + context.min_validator_withdrawability_delay;
+        let report = analyze_registry(&validators, &balances, epoch, &context);
+        assert_eq!(report.total, 4);
+        assert_eq!(report.active, 1);
+        assert_eq!(report.exited, 2);
+        assert_eq!(report.pending, 1);
+        assert_eq!(report.compaction_candidates, vec![1]);
+        assert_eq!(report.effective_balance_distribution.values().sum::<usize>(), 4);
+    }
+
+    #[test]
+    fn test_active_index_remapping() {
+        let context = Context::for_minimal();
+        let validators = vec![
+            validator(0, FAR_FUTURE_EPOCH, &context),
+            validator(0, 5, &context),
+            validator(0, FAR_FUTURE_EPOCH, &context),
+            validator(5, FAR_FUTURE_EPOCH, &context),
+        ];
+        let before = ActiveIndexMap::new(&validators, 1);
+        let after = ActiveIndexMap::new(&validators, 5);
+        assert_eq!(before.registry_indices(), &[0, 1, 2]);
+        assert_eq!(after.registry_indices(), &[0, 2, 3]);
+        assert_eq!(after.to_compact(2), Some(1));
+        assert_eq!(after.to_registry(2), Some(3));
+
+        let remapping = after.remap_from(&before);
+        assert_eq!(remapping.moved, vec![(2, 1)]);
+        assert_eq!(remapping.removed, vec![1]);
+        assert_eq!(remapping.added, vec![(2, 3)]);
+        assert!(after.remap_from(&after).is_empty());
+    }
+}
@@ -1,65 +1,71 @@
-pub use ssz_rs::serde::{as_hex, as_str, try_bytes_from_hex_str};
+pub use ssz_rs::serde::{as_hex, as_str, seq_of_str, try_bytes_from_hex_str};
 
-pub mod seq_of_str {
-    use serde::{
-        de::{Deserializer, Error},
-        ser::SerializeSeq,
-    };
-    use std::{fmt, marker::PhantomData, str::FromStr};
+/// Conversion to and from the typed YAML representation of values used by the consensus
+/// spec tests (i.e. `value.yaml`), where integers are YAML numbers rather than the decimal
+/// strings used by the beacon APIs.
+pub mod spec_yaml {
+    use crate::Error;
+    use serde::{de::DeserializeOwned, Serialize};
+    use serde_yaml::Value;
 
-    pub fn serialize<S, T, U>(data: T, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-        T: AsRef<[U]>,
-        U: fmt::Display,
-    {
-        let mut seq = serializer.serialize_seq(None)?;
-        for elem in data.as_ref().iter() {
-            let rendered_elem = format!("{elem}");
-            seq.serialize_element(&rendered_elem)?;
-        }
-        seq.end()
+    pub fn from_str<T: DeserializeOwned>(s: &str) -> Result<T, Error> {
+        from_value(serde_yaml::from_str(s)?)
     }
 
-    struct Visitor<T>(PhantomData<Vec<T>>);
-
-    impl<'de, T: FromStr> serde::de::Visitor<'de> for Visitor<T> {
-        type Value = Vec<T>;
+    pub fn from_value<T: DeserializeOwned>(value: Value) -> Result<T, Error> {
+        Ok(serde_yaml::from_value(numbers_to_strings(value))?)
+    }
 
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("sequence of string")
-        }
+    pub fn to_value<T: Serialize>(value: &T) -> Result<Value, Error> {
+        Ok(strings_to_numbers(serde_yaml::to_value(value)?))
+    }
 
-        fn visit_seq<S>(self, mut access: S) -> Result<Self::Value, S::Error>
-        where
-            S: serde::de::SeqAccess<'de>,
-        {
-            let mut coll = Vec::with_capacity(access.size_hint().unwrap_or(0));
+    pub fn to_string<T: Serialize>(value: &T) -> Result<String, Error> {
+        Ok(serde_yaml::to_string(&to_value(value)?)?)
+    }
 
-            while let Some(elem) = access.next_element()? {
-                let recovered_elem = T::from_str(elem).map_err(|_| {
-                    Error::custom("failure to parse element of sequence from string")
-                })?;
-                coll.push(recovered_elem);
+    fn numbers_to_strings(value: Value) -> Value {
+        match value {
+            Value::Number(n) => Value::String(n.to_string()),
+            Value::Sequence(elements) => {
+                Value::Sequence(elements.into_iter().map(numbers_to_strings).collect())
             }
-            Ok(coll)
+            Value::Mapping(entries) => Value::Mapping(
+                entries.into_iter().map(|(k, v)| (k, numbers_to_strings(v))).collect(),
+            ),
+            value => value,
         }
     }
 
-    pub fn deserialize<'de, D, T, U>(deserializer: D) -> Result<T, D::Error>
-    where
-        D: Deserializer<'de>,
-        T: TryFrom<Vec<U>>,
-        U: FromStr,
-    {
-        let data = deserializer.deserialize_seq(Visitor(PhantomData))?;
-        T::try_from(data).map_err(|_| serde::de::Error::custom("failure to parse collection"))
+    // NOTE: hex-encoded data is always `0x`-prefixed so any string made only of digits
+    // must have been an integer.
+    fn strings_to_numbers(value: Value) -> Value {
+        match value {
+            Value::String(s) if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) => {
+                match s.parse::<u64>() {
+                    Ok(n) => Value::Number(n.into()),
+                    Err(_) => Value::String(s),
+                }
+            }
+            Value::Sequence(elements) => {
+                Value::Sequence(elements.into_iter().map(strings_to_numbers).collect())
+            }
+            Value::Mapping(entries) => Value::Mapping(
+                entries.into_iter().map(|(k, v)| (k, strings_to_numbers(v))).collect(),
+            ),
+            value => value,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::types::mainnet::SignedBeaconBlock;
+    use super::spec_yaml;
+    use crate::{
+        phase0::{mainnet::IndexedAttestation, AttestationData},
+        ssz::prelude::*,
+        types::mainnet::SignedBeaconBlock,
+    };
 
     const EXPECTED_SIGNED_BLOCK_STR: &str = r#"
     { "message": {
@@ -120,4 +126,35 @@ mod tests {
         let recovered_signed_block: SignedBeaconBlock = serde_json::from_str(&str).unwrap();
         assert_eq!(signed_block, recovered_signed_block);
     }
+
+    const ATTESTATION_VALUE_YAML: &str = r#"
+attesting_indices: [3, 1024, 7]
+data:
+  slot: 12
+  index: 2
+  beacon_block_root: '0x0101010101010101010101010101010101010101010101010101010101010101'
+  source: {epoch: 1, root: '0x0000000000000000000000000000000000000000000000000000000000000000'}
+  target: {epoch: 18446744073709551615, root: '0x0202020202020202020202020202020202020202020202020202020202020202'}
+signature: '0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000'
+"#;
+
+    #[test]
+    fn test_spec_yaml_round_trip() {
+        let attestation: IndexedAttestation = spec_yaml::from_str(ATTESTATION_VALUE_YAML).unwrap();
+        assert_eq!(attestation.attesting_indices.as_ref(), &[3, 1024, 7]);
+        assert_eq!(attestation.data.slot, 12);
+        assert_eq!(attestation.data.target.epoch, u64::MAX);
+
+        let value = spec_yaml::to_value(&attestation).unwrap();
+        let expected: serde_yaml::Value = serde_yaml::from_str(ATTESTATION_VALUE_YAML).unwrap();
+        assert_eq!(value, expected);
+
+        let encoding = serialize(&attestation).unwrap();
+        let recovered = IndexedAttestation::deserialize(&encoding).unwrap();
+        assert_eq!(recovered.hash_tree_root().unwrap(), attestation.hash_tree_root().unwrap());
+
+        let data: AttestationData =
+            spec_yaml::from_str(&spec_yaml::to_string(&attestation.data).unwrap()).unwrap();
+        assert_eq!(data, attestation.data);
+    }
 }
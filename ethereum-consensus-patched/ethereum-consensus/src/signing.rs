@@ -17,7 +17,7 @@ pub fn compute_signing_root<T: Merkleized>(
 ) -> Result<Root, Error> {
     let object_root = ssz_object.hash_tree_root()?;
 
-    let mut s = SigningData { object_root, domain };
+    let s = SigningData { object_root, domain };
     s.hash_tree_root().map_err(Error::Merkleization)
 }
 
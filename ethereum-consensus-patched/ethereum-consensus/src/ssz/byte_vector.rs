@@ -2,11 +2,21 @@ use crate::ssz::prelude::*;
 use ssz_rs::utils::{write_bytes_to_lower_hex, write_bytes_to_lower_hex_display};
 use std::{
     fmt,
-    hash::{Hash, Hasher},
     ops::{Deref, DerefMut},
 };
 
-#[derive(Default, Clone, PartialEq, Eq, SimpleSerialize, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    SimpleSerialize,
+    serde::Serialize,
+    serde::Deserialize,
+)]
 pub struct ByteVector<const N: usize>(#[serde(with = "crate::serde::as_hex")] Vector<u8, N>);
 
 impl<const N: usize> TryFrom<&[u8]> for ByteVector<N> {
@@ -17,12 +27,6 @@ impl<const N: usize> TryFrom<&[u8]> for ByteVector<N> {
     }
 }
 
-impl<const N: usize> Hash for ByteVector<N> {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.as_ref().hash(state);
-    }
-}
-
 impl<const N: usize> fmt::Debug for ByteVector<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write_bytes_to_lower_hex(f, &self.0)
@@ -2,8 +2,9 @@ use crate::{
     altair, bellatrix, capella,
     clock::{self, Clock, SystemTimeProvider},
     configs::{self, Config},
-    deneb,
+    deneb, electra,
     networks::Network,
+    parameters::SpecParameters,
     phase0,
     primitives::{Epoch, ExecutionAddress, Gwei, Hash32, Slot, Version, U256},
     Error, Fork,
@@ -73,6 +74,22 @@ pub struct Context {
     pub max_blob_commitments_per_block: usize,
     pub max_blobs_per_block: usize,
 
+    // electra preset
+    pub min_activation_balance: Gwei,
+    pub max_effective_balance_electra: Gwei,
+    pub min_slashing_penalty_quotient_electra: u64,
+    pub whistleblower_reward_quotient_electra: u64,
+    pub max_pending_partials_per_withdrawals_sweep: usize,
+    pub pending_deposits_limit: usize,
+    pub pending_partial_withdrawals_limit: usize,
+    pub pending_consolidations_limit: usize,
+    pub max_attester_slashings_electra: usize,
+    pub max_attestations_electra: usize,
+    pub max_deposit_requests_per_payload: usize,
+    pub max_withdrawal_requests_per_payload: usize,
+    pub max_consolidation_requests_per_payload: usize,
+    pub max_pending_deposits_per_epoch: usize,
+
     // config
     pub name: Network,
 
@@ -93,6 +110,8 @@ pub struct Context {
     pub capella_fork_epoch: Epoch,
     pub deneb_fork_version: Version,
     pub deneb_fork_epoch: Epoch,
+    pub electra_fork_version: Version,
+    pub electra_fork_epoch: Epoch,
 
     pub seconds_per_slot: u64,
     pub seconds_per_eth1_block: u64,
@@ -106,19 +125,24 @@ pub struct Context {
     pub min_per_epoch_churn_limit: u64,
     pub max_per_epoch_activation_churn_limit: u64,
     pub churn_limit_quotient: u64,
+    pub min_per_epoch_churn_limit_electra: Gwei,
+    pub max_per_epoch_activation_exit_churn_limit: Gwei,
 
     pub proposer_score_boost: u64,
 
+    pub max_blobs_per_block_electra: usize,
+
     pub deposit_chain_id: usize,
     pub deposit_network_id: usize,
     pub deposit_contract_address: ExecutionAddress,
 }
 
 impl Context {
+    /// Builds a `Context` from a `config.yaml` whose `PRESET_BASE` is one of the built-in
+    /// presets. Use `Context::try_from_files` for any other preset.
     #[cfg(feature = "serde")]
     pub fn try_from_file<P: AsRef<std::path::Path>>(config_file: P) -> Result<Self, Error> {
-        let mut file = std::fs::File::open(config_file)?;
-        let config: Config = serde_yaml::from_reader(&mut file)?;
+        let config: Config = read_yaml(config_file)?;
         let context = match config.preset_base.as_ref() {
             "mainnet" => {
                 let phase0_preset = &phase0::mainnet::PRESET;
@@ -126,12 +150,31 @@ impl Context {
                 let bellatrix_preset = &bellatrix::mainnet::PRESET;
                 let capella_preset = &capella::mainnet::PRESET;
                 let deneb_preset = &deneb::mainnet::PRESET;
+                let electra_preset = &electra::mainnet::PRESET;
+                Self::from(
+                    phase0_preset,
+                    altair_preset,
+                    bellatrix_preset,
+                    capella_preset,
+                    deneb_preset,
+                    electra_preset,
+                    &config,
+                )
+            }
+            "gnosis" => {
+                let phase0_preset = &phase0::gnosis::PRESET;
+                let altair_preset = &altair::gnosis::PRESET;
+                let bellatrix_preset = &bellatrix::gnosis::PRESET;
+                let capella_preset = &capella::gnosis::PRESET;
+                let deneb_preset = &deneb::gnosis::PRESET;
+                let electra_preset = &electra::gnosis::PRESET;
                 Self::from(
                     phase0_preset,
                     altair_preset,
                     bellatrix_preset,
                     capella_preset,
                     deneb_preset,
+                    electra_preset,
                     &config,
                 )
             }
@@ -141,12 +184,14 @@ impl Context {
                 let bellatrix_preset = &bellatrix::minimal::PRESET;
                 let capella_preset = &capella::minimal::PRESET;
                 let deneb_preset = &deneb::minimal::PRESET;
+                let electra_preset = &electra::minimal::PRESET;
                 Self::from(
                     phase0_preset,
                     altair_preset,
                     bellatrix_preset,
                     capella_preset,
                     deneb_preset,
+                    electra_preset,
                     &config,
                 )
             }
@@ -155,12 +200,39 @@ impl Context {
         Ok(context)
     }
 
+    /// Builds a `Context` from a `config.yaml` and a directory with one preset file per fork
+    /// (`phase0.yaml`, `altair.yaml`, ...), following the layout of `consensus-specs`.
+    #[cfg(feature = "serde")]
+    pub fn try_from_files<P: AsRef<std::path::Path>, Q: AsRef<std::path::Path>>(
+        config_file: P,
+        preset_dir: Q,
+    ) -> Result<Self, Error> {
+        let config: Config = read_yaml(config_file)?;
+        let preset_dir = preset_dir.as_ref();
+        let phase0_preset: phase0::Preset = read_yaml(preset_dir.join("phase0.yaml"))?;
+        let altair_preset: altair::Preset = read_yaml(preset_dir.join("altair.yaml"))?;
+        let bellatrix_preset: bellatrix::Preset = read_yaml(preset_dir.join("bellatrix.yaml"))?;
+        let capella_preset: capella::Preset = read_yaml(preset_dir.join("capella.yaml"))?;
+        let deneb_preset: deneb::Preset = read_yaml(preset_dir.join("deneb.yaml"))?;
+        let electra_preset: electra::Preset = read_yaml(preset_dir.join("electra.yaml"))?;
+        Ok(Self::from(
+            &phase0_preset,
+            &altair_preset,
+            &bellatrix_preset,
+            &capella_preset,
+            &deneb_preset,
+            &electra_preset,
+            &config,
+        ))
+    }
+
     pub fn from(
         phase0_preset: &phase0::Preset,
         altair_preset: &altair::Preset,
         bellatrix_preset: &bellatrix::Preset,
         capella_preset: &capella::Preset,
         deneb_preset: &deneb::Preset,
+        electra_preset: &electra::Preset,
         config: &Config,
     ) -> Self {
         Self {
@@ -227,6 +299,25 @@ impl Context {
             field_elements_per_blob: deneb_preset.field_elements_per_blob,
             max_blob_commitments_per_block: deneb_preset.max_blob_commitments_per_block,
             max_blobs_per_block: deneb_preset.max_blobs_per_block,
+            // electra
+            min_activation_balance: electra_preset.min_activation_balance,
+            max_effective_balance_electra: electra_preset.max_effective_balance_electra,
+            min_slashing_penalty_quotient_electra: electra_preset
+                .min_slashing_penalty_quotient_electra,
+            whistleblower_reward_quotient_electra: electra_preset
+                .whistleblower_reward_quotient_electra,
+            max_pending_partials_per_withdrawals_sweep: electra_preset
+                .max_pending_partials_per_withdrawals_sweep,
+            pending_deposits_limit: electra_preset.pending_deposits_limit,
+            pending_partial_withdrawals_limit: electra_preset.pending_partial_withdrawals_limit,
+            pending_consolidations_limit: electra_preset.pending_consolidations_limit,
+            max_attester_slashings_electra: electra_preset.max_attester_slashings_electra,
+            max_attestations_electra: electra_preset.max_attestations_electra,
+            max_deposit_requests_per_payload: electra_preset.max_deposit_requests_per_payload,
+            max_withdrawal_requests_per_payload: electra_preset.max_withdrawal_requests_per_payload,
+            max_consolidation_requests_per_payload: electra_preset
+                .max_consolidation_requests_per_payload,
+            max_pending_deposits_per_epoch: electra_preset.max_pending_deposits_per_epoch,
             // config
             name: config.name.clone(),
             terminal_total_difficulty: config.terminal_total_difficulty,
@@ -244,6 +335,8 @@ impl Context {
             capella_fork_epoch: config.capella_fork_epoch,
             deneb_fork_version: config.deneb_fork_version,
             deneb_fork_epoch: config.deneb_fork_epoch,
+            electra_fork_version: config.electra_fork_version,
+            electra_fork_epoch: config.electra_fork_epoch,
             seconds_per_slot: config.seconds_per_slot,
             seconds_per_eth1_block: config.seconds_per_eth1_block,
             min_validator_withdrawability_delay: config.min_validator_withdrawability_delay,
@@ -255,7 +348,11 @@ impl Context {
             min_per_epoch_churn_limit: config.min_per_epoch_churn_limit,
             max_per_epoch_activation_churn_limit: config.max_per_epoch_activation_churn_limit,
             churn_limit_quotient: config.churn_limit_quotient,
+            min_per_epoch_churn_limit_electra: config.min_per_epoch_churn_limit_electra,
+            max_per_epoch_activation_exit_churn_limit: config
+                .max_per_epoch_activation_exit_churn_limit,
             proposer_score_boost: config.proposer_score_boost,
+            max_blobs_per_block_electra: config.max_blobs_per_block_electra,
             deposit_chain_id: config.deposit_chain_id,
             deposit_network_id: config.deposit_network_id,
             deposit_contract_address: config.deposit_contract_address.clone(),
@@ -269,12 +366,33 @@ impl Context {
         let bellatrix_preset = &bellatrix::mainnet::PRESET;
         let capella_preset = &capella::mainnet::PRESET;
         let deneb_preset = &deneb::mainnet::PRESET;
+        let electra_preset = &electra::mainnet::PRESET;
+        Self::from(
+            phase0_preset,
+            altair_preset,
+            bellatrix_preset,
+            capella_preset,
+            deneb_preset,
+            electra_preset,
+            config,
+        )
+    }
+
+    pub fn for_gnosis() -> Self {
+        let config = &configs::gnosis::config();
+        let phase0_preset = &phase0::gnosis::PRESET;
+        let altair_preset = &altair::gnosis::PRESET;
+        let bellatrix_preset = &bellatrix::gnosis::PRESET;
+        let capella_preset = &capella::gnosis::PRESET;
+        let deneb_preset = &deneb::gnosis::PRESET;
+        let electra_preset = &electra::gnosis::PRESET;
         Self::from(
             phase0_preset,
             altair_preset,
             bellatrix_preset,
             capella_preset,
             deneb_preset,
+            electra_preset,
             config,
         )
     }
@@ -286,12 +404,14 @@ impl Context {
         let bellatrix_preset = &bellatrix::minimal::PRESET;
         let capella_preset = &capella::minimal::PRESET;
         let deneb_preset = &deneb::minimal::PRESET;
+        let electra_preset = &electra::minimal::PRESET;
         Self::from(
             phase0_preset,
             altair_preset,
             bellatrix_preset,
             capella_preset,
             deneb_preset,
+            electra_preset,
             config,
         )
     }
@@ -303,12 +423,14 @@ impl Context {
         let bellatrix_preset = &bellatrix::mainnet::PRESET;
         let capella_preset = &capella::mainnet::PRESET;
         let deneb_preset = &deneb::mainnet::PRESET;
+        let electra_preset = &electra::mainnet::PRESET;
         Self::from(
             phase0_preset,
             altair_preset,
             bellatrix_preset,
             capella_preset,
             deneb_preset,
+            electra_preset,
             config,
         )
     }
@@ -320,12 +442,14 @@ impl Context {
         let bellatrix_preset = &bellatrix::mainnet::PRESET;
         let capella_preset = &capella::mainnet::PRESET;
         let deneb_preset = &deneb::mainnet::PRESET;
+        let electra_preset = &electra::mainnet::PRESET;
         Self::from(
             phase0_preset,
             altair_preset,
             bellatrix_preset,
             capella_preset,
             deneb_preset,
+            electra_preset,
             config,
         )
     }
@@ -337,19 +461,23 @@ impl Context {
         let bellatrix_preset = &bellatrix::mainnet::PRESET;
         let capella_preset = &capella::mainnet::PRESET;
         let deneb_preset = &deneb::mainnet::PRESET;
+        let electra_preset = &electra::mainnet::PRESET;
         Self::from(
             phase0_preset,
             altair_preset,
             bellatrix_preset,
             capella_preset,
             deneb_preset,
+            electra_preset,
             config,
         )
     }
 
     pub fn fork_for(&self, slot: Slot) -> Fork {
         let epoch = slot / self.slots_per_epoch;
-        if epoch >= self.deneb_fork_epoch {
+        if epoch >= self.electra_fork_epoch {
+            Fork::Electra
+        } else if epoch >= self.deneb_fork_epoch {
             Fork::Deneb
         } else if epoch >= self.capella_fork_epoch {
             Fork::Capella
@@ -369,9 +497,100 @@ impl Context {
             Fork::Bellatrix => self.bellatrix_fork_version,
             Fork::Capella => self.capella_fork_version,
             Fork::Deneb => self.deneb_fork_version,
+            Fork::Electra => self.electra_fork_version,
         }
     }
 
+    /// Collect the preset values of this context in effect at `fork`.
+    pub fn spec_parameters(&self, fork: Fork) -> SpecParameters {
+        let parameters = SpecParameters {
+            fork: Fork::Electra,
+            phase0: phase0::Preset {
+                max_committees_per_slot: self.max_committees_per_slot,
+                target_committee_size: self.target_committee_size,
+                max_validators_per_committee: self.max_validators_per_committee,
+                shuffle_round_count: self.shuffle_round_count,
+                hysteresis_quotient: self.hysteresis_quotient,
+                hysteresis_downward_multiplier: self.hysteresis_downward_multiplier,
+                hysteresis_upward_multiplier: self.hysteresis_upward_multiplier,
+                min_deposit_amount: self.min_deposit_amount,
+                max_effective_balance: self.max_effective_balance,
+                effective_balance_increment: self.effective_balance_increment,
+                min_attestation_inclusion_delay: self.min_attestation_inclusion_delay,
+                slots_per_epoch: self.slots_per_epoch,
+                min_seed_lookahead: self.min_seed_lookahead,
+                max_seed_lookahead: self.max_seed_lookahead,
+                min_epochs_to_inactivity_penalty: self.min_epochs_to_inactivity_penalty,
+                epochs_per_eth1_voting_period: self.epochs_per_eth1_voting_period,
+                slots_per_historical_root: self.slots_per_historical_root,
+                epochs_per_historical_vector: self.epochs_per_historical_vector,
+                epochs_per_slashings_vector: self.epochs_per_slashings_vector,
+                historical_roots_limit: self.historical_roots_limit,
+                validator_registry_limit: self.validator_registry_limit,
+                base_reward_factor: self.base_reward_factor,
+                whistleblower_reward_quotient: self.whistleblower_reward_quotient,
+                proposer_reward_quotient: self.proposer_reward_quotient,
+                inactivity_penalty_quotient: self.inactivity_penalty_quotient,
+                min_slashing_penalty_quotient: self.min_slashing_penalty_quotient,
+                proportional_slashing_multiplier: self.proportional_slashing_multiplier,
+                max_proposer_slashings: self.max_proposer_slashings,
+                max_attester_slashings: self.max_attester_slashings,
+                max_attestations: self.max_attestations,
+                max_deposits: self.max_deposits,
+                max_voluntary_exits: self.max_voluntary_exits,
+            },
+            altair: Some(altair::Preset {
+                inactivity_penalty_quotient_altair: self.inactivity_penalty_quotient_altair,
+                min_slashing_penalty_quotient_altair: self.min_slashing_penalty_quotient_altair,
+                proportional_slashing_multiplier_altair: self
+                    .proportional_slashing_multiplier_altair,
+                sync_committee_size: self.sync_committee_size,
+                epochs_per_sync_committee_period: self.epochs_per_sync_committee_period,
+                min_sync_committee_participants: self.min_sync_committee_participants,
+                update_timeout: self.update_timeout,
+            }),
+            bellatrix: Some(bellatrix::Preset {
+                inactivity_penalty_quotient_bellatrix: self.inactivity_penalty_quotient_bellatrix,
+                min_slashing_penalty_quotient_bellatrix: self
+                    .min_slashing_penalty_quotient_bellatrix,
+                proportional_slashing_multiplier_bellatrix: self
+                    .proportional_slashing_multiplier_bellatrix,
+                max_bytes_per_transaction: self.max_bytes_per_transaction,
+                max_transactions_per_payload: self.max_transactions_per_payload,
+                bytes_per_logs_bloom: self.bytes_per_logs_bloom,
+                max_extra_data_bytes: self.max_extra_data_bytes,
+            }),
+            capella: Some(capella::Preset {
+                max_bls_to_execution_changes: self.max_bls_to_execution_changes,
+                max_withdrawals_per_payload: self.max_withdrawals_per_payload,
+                max_validators_per_withdrawals_sweep: self.max_validators_per_withdrawals_sweep,
+            }),
+            deneb: Some(deneb::Preset {
+                field_elements_per_blob: self.field_elements_per_blob,
+                max_blob_commitments_per_block: self.max_blob_commitments_per_block,
+                max_blobs_per_block: self.max_blobs_per_block,
+            }),
+            electra: Some(electra::Preset {
+                min_activation_balance: self.min_activation_balance,
+                max_effective_balance_electra: self.max_effective_balance_electra,
+                min_slashing_penalty_quotient_electra: self.min_slashing_penalty_quotient_electra,
+                whistleblower_reward_quotient_electra: self.whistleblower_reward_quotient_electra,
+                max_pending_partials_per_withdrawals_sweep: self
+                    .max_pending_partials_per_withdrawals_sweep,
+                pending_deposits_limit: self.pending_deposits_limit,
+                pending_partial_withdrawals_limit: self.pending_partial_withdrawals_limit,
+                pending_consolidations_limit: self.pending_consolidations_limit,
+                max_attester_slashings_electra: self.max_attester_slashings_electra,
+                max_attestations_electra: self.max_attestations_electra,
+                max_deposit_requests_per_payload: self.max_deposit_requests_per_payload,
+                max_withdrawal_requests_per_payload: self.max_withdrawal_requests_per_payload,
+                max_consolidation_requests_per_payload: self.max_consolidation_requests_per_payload,
+                max_pending_deposits_per_epoch: self.max_pending_deposits_per_epoch,
+            }),
+        };
+        parameters.at_fork(fork).expect("context has the presets of every fork")
+    }
+
     pub fn genesis_time(&self) -> Result<u64, Error> {
         match &self.name {
             Network::Mainnet => Ok(crate::clock::MAINNET_GENESIS_TIME),
@@ -388,6 +607,7 @@ impl Context {
             Network::Sepolia => Some(clock::for_sepolia()),
             Network::Goerli => Some(clock::for_goerli()),
             Network::Holesky => Some(clock::for_holesky()),
+            Network::Gnosis => Some(clock::for_gnosis()),
             _ => None,
         }
     }
@@ -400,3 +620,115 @@ impl Context {
         &self.name
     }
 }
+
+#[cfg(feature = "serde")]
+fn read_yaml<T: serde::de::DeserializeOwned, P: AsRef<std::path::Path>>(
+    path: P,
+) -> Result<T, Error> {
+    let file = std::fs::File::open(path)?;
+    Ok(serde_yaml::from_reader(file)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_try_from_files() {
+        let dir = std::env::temp_dir().join(format!("context-{}", std::process::id()));
+        let preset_dir = dir.join("presets");
+        std::fs::create_dir_all(&preset_dir).unwrap();
+        let write = |path: std::path::PathBuf, contents: String| std::fs::write(path, contents);
+
+        let mut config = configs::minimal::config();
+        config.preset_base = "custom".to_string();
+        config.seconds_per_slot = 2;
+        write(dir.join("config.yaml"), serde_yaml::to_string(&config).unwrap()).unwrap();
+        let mut phase0_preset = phase0::minimal::PRESET;
+        phase0_preset.slots_per_epoch = 4;
+        write(preset_dir.join("phase0.yaml"), serde_yaml::to_string(&phase0_preset).unwrap())
+            .unwrap();
+        let presets = [
+            ("altair.yaml", serde_yaml::to_string(&altair::minimal::PRESET)),
+            ("bellatrix.yaml", serde_yaml::to_string(&bellatrix::minimal::PRESET)),
+            ("capella.yaml", serde_yaml::to_string(&capella::minimal::PRESET)),
+            ("deneb.yaml", serde_yaml::to_string(&deneb::minimal::PRESET)),
+        ];
+        for (name, contents) in presets {
+            write(preset_dir.join(name), contents.unwrap()).unwrap();
+        }
+
+        // every preset file is required
+        assert!(Context::try_from_files(dir.join("config.yaml"), &preset_dir).is_err());
+
+        let electra_preset = serde_yaml::to_string(&electra::minimal::PRESET).unwrap();
+        write(preset_dir.join("electra.yaml"), electra_preset).unwrap();
+        let context = Context::try_from_files(dir.join("config.yaml"), &preset_dir).unwrap();
+        assert_eq!(context.seconds_per_slot, 2);
+        assert_eq!(context.slots_per_epoch, 4);
+        assert_eq!(context.sync_committee_size, altair::minimal::SYNC_COMMITTEE_SIZE);
+        assert_eq!(context.max_attestations_electra, electra::minimal::MAX_ATTESTATIONS_ELECTRA);
+        assert!(matches!(
+            Context::try_from_file(dir.join("config.yaml")),
+            Err(Error::UnknownPreset(preset)) if preset == "custom"
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_gnosis_matches_gnosischain_configs() {
+        // `mainnet/presets/gnosis/altair.yaml` of `gnosischain/configs`
+        let altair_preset = "
+            INACTIVITY_PENALTY_QUOTIENT_ALTAIR: 50331648
+            MIN_SLASHING_PENALTY_QUOTIENT_ALTAIR: 64
+            PROPORTIONAL_SLASHING_MULTIPLIER_ALTAIR: 2
+            SYNC_COMMITTEE_SIZE: 512
+            EPOCHS_PER_SYNC_COMMITTEE_PERIOD: 512
+            MIN_SYNC_COMMITTEE_PARTICIPANTS: 1
+            UPDATE_TIMEOUT: 8192
+        ";
+        let altair_preset: altair::Preset = serde_yaml::from_str(altair_preset).unwrap();
+        assert_eq!(altair_preset, altair::gnosis::PRESET);
+        // `mainnet/presets/gnosis/capella.yaml`
+        let capella_preset = "
+            MAX_BLS_TO_EXECUTION_CHANGES: 16
+            MAX_WITHDRAWALS_PER_PAYLOAD: 8
+            MAX_VALIDATORS_PER_WITHDRAWALS_SWEEP: 8192
+        ";
+        let capella_preset: capella::Preset = serde_yaml::from_str(capella_preset).unwrap();
+        assert_eq!(capella_preset, capella::gnosis::PRESET);
+
+        let context = Context::for_gnosis();
+        assert_eq!(context.slots_per_epoch, 16);
+        assert_eq!(context.base_reward_factor, 25);
+        // a light client update times out after a sync committee period
+        assert_eq!(
+            context.update_timeout as u64,
+            context.slots_per_epoch * context.epochs_per_sync_committee_period
+        );
+        assert_eq!(context.max_blobs_per_block, 2);
+        assert_eq!(context.seconds_per_slot, 5);
+        assert_eq!(context.altair_fork_epoch, 512);
+        assert_eq!(context.bellatrix_fork_epoch, 385536);
+        assert_eq!(context.capella_fork_epoch, 648704);
+        assert_eq!(context.deneb_fork_epoch, 889856);
+        assert_eq!(context.deposit_chain_id, 100);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_config_without_electra() {
+        let config = serde_yaml::to_string(&configs::goerli::config()).unwrap();
+        let config =
+            config.lines().filter(|line| !line.contains("ELECTRA")).collect::<Vec<_>>().join("\n");
+        let config: Config = serde_yaml::from_str(&config).unwrap();
+        assert_eq!(config.electra_fork_epoch, crate::primitives::FAR_FUTURE_EPOCH);
+        assert_eq!(
+            config.max_blobs_per_block_electra,
+            configs::mainnet::MAX_BLOBS_PER_BLOCK_ELECTRA
+        );
+    }
+}
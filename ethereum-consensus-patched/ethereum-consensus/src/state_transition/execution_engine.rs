@@ -1,4 +1,49 @@
-use crate::bellatrix;
+use crate::{bellatrix, error::ExecutionEngineError, primitives::Hash32, state_transition::Result};
+
+/// The status of an execution payload as reported by the execution engine, following
+/// `PayloadStatusV1` of the Engine API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayloadStatus {
+    /// The payload and all of its ancestors are valid.
+    Valid,
+    /// The payload is invalid. `latest_valid_hash` is the block hash of its most recent valid
+    /// ancestor, if the engine knows it, so that any invalid ancestors can be discarded too.
+    Invalid { latest_valid_hash: Option<Hash32> },
+    /// The engine is syncing and could not validate the payload yet.
+    Syncing,
+    /// The payload is well-formed but does not extend the canonical chain of the engine, so it
+    /// was not validated yet.
+    Accepted,
+    /// The block hash of the payload does not match its contents.
+    InvalidBlockHash,
+}
+
+impl PayloadStatus {
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid)
+    }
+
+    /// Whether the payload is known to be invalid, in which case its block must be rejected.
+    pub fn is_invalid(&self) -> bool {
+        matches!(self, Self::Invalid { .. } | Self::InvalidBlockHash)
+    }
+
+    /// Whether the payload was not validated yet (`NOT_VALIDATED` in the optimistic sync
+    /// specs), in which case its block may be imported optimistically.
+    pub fn is_not_validated(&self) -> bool {
+        matches!(self, Self::Syncing | Self::Accepted)
+    }
+
+    /// Fail if the payload is invalid. Payloads the engine could not validate yet are
+    /// accepted, so that their blocks can be imported optimistically.
+    pub fn ensure_not_invalid(&self) -> Result<()> {
+        match self {
+            Self::Invalid { .. } => Err(ExecutionEngineError::InvalidPayload.into()),
+            Self::InvalidBlockHash => Err(ExecutionEngineError::InvalidBlockHash.into()),
+            _ => Ok(()),
+        }
+    }
+}
 
 #[derive(Debug)]
 pub enum ExecutionEngine<
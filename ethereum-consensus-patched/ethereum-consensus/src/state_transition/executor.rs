@@ -1,8 +1,8 @@
 use crate::{
     altair, bellatrix, phase0,
     state_transition::{
-        execution_engine::ExecutionEngine, BeaconState, Context, Result, SignedBeaconBlock,
-        Validation,
+        execution_engine::ExecutionEngine, BeaconState, Context, PayloadStatus, Result,
+        SignedBeaconBlock, Validation,
     },
     Error, Fork,
 };
@@ -131,6 +131,8 @@ impl<
         Self { state, execution_engine, context }
     }
 
+    /// Apply `signed_block` to the state, returning the status of its execution payload if it
+    /// has one, e.g. to pass to `HeadTracker::on_payload_status`.
     pub fn apply_block(
         &mut self,
         signed_block: &mut SignedBeaconBlock<
@@ -146,7 +148,7 @@ impl<
             MAX_BYTES_PER_TRANSACTION,
             MAX_TRANSACTIONS_PER_PAYLOAD,
         >,
-    ) -> Result<()> {
+    ) -> Result<Option<PayloadStatus>> {
         self.apply_block_with_validation(signed_block, Validation::Enabled)
     }
 
@@ -166,16 +168,16 @@ impl<
             MAX_TRANSACTIONS_PER_PAYLOAD,
         >,
         validation: Validation,
-    ) -> Result<()> {
+    ) -> Result<Option<PayloadStatus>> {
         match signed_block {
             SignedBeaconBlock::Phase0(signed_block) => {
-                self.apply_phase0_block_with_validation(signed_block, validation)
+                self.apply_phase0_block_with_validation(signed_block, validation).map(|_| None)
             }
             SignedBeaconBlock::Altair(signed_block) => {
-                self.apply_altair_block_with_validation(signed_block, validation)
+                self.apply_altair_block_with_validation(signed_block, validation).map(|_| None)
             }
             SignedBeaconBlock::Bellatrix(signed_block) => {
-                self.apply_bellatrix_block_with_validation(signed_block, validation)
+                self.apply_bellatrix_block_with_validation(signed_block, validation).map(Some)
             }
         }
     }
@@ -264,7 +266,7 @@ impl<
             MAX_TRANSACTIONS_PER_PAYLOAD,
         >,
         validation: Validation,
-    ) -> Result<()> {
+    ) -> Result<PayloadStatus> {
         match &mut self.state {
             BeaconState::Phase0(state) => {
                 let fork_slot = self.context.altair_fork_epoch * self.context.slots_per_epoch;
@@ -274,14 +276,14 @@ impl<
                 let fork_slot = self.context.bellatrix_fork_epoch * self.context.slots_per_epoch;
                 altair::process_slots(&mut state, fork_slot, &self.context)?;
                 let mut state = bellatrix::upgrade_to_bellatrix(&state, &self.context);
-                if signed_block.message.slot == state.slot {
+                let status = if signed_block.message.slot == state.slot {
                     bellatrix::state_transition_block_in_slot(
                         &mut state,
                         signed_block,
                         self.execution_engine.bellatrix(),
                         validation,
                         &self.context,
-                    )?;
+                    )?
                 } else {
                     bellatrix::state_transition(
                         &mut state,
@@ -289,23 +291,23 @@ impl<
                         self.execution_engine.bellatrix(),
                         validation,
                         &self.context,
-                    )?;
-                }
+                    )?
+                };
                 self.state = state.into();
-                Ok(())
+                Ok(status)
             }
             BeaconState::Altair(state) => {
                 let fork_slot = self.context.bellatrix_fork_epoch * self.context.slots_per_epoch;
                 altair::process_slots(state, fork_slot, &self.context)?;
                 let mut state = bellatrix::upgrade_to_bellatrix(state, &self.context);
-                if signed_block.message.slot == state.slot {
+                let status = if signed_block.message.slot == state.slot {
                     bellatrix::state_transition_block_in_slot(
                         &mut state,
                         signed_block,
                         self.execution_engine.bellatrix(),
                         validation,
                         &self.context,
-                    )?;
+                    )?
                 } else {
                     bellatrix::state_transition(
                         &mut state,
@@ -313,10 +315,10 @@ impl<
                         self.execution_engine.bellatrix(),
                         validation,
                         &self.context,
-                    )?;
-                }
+                    )?
+                };
                 self.state = state.into();
-                Ok(())
+                Ok(status)
             }
             BeaconState::Bellatrix(state) => bellatrix::state_transition(
                 state,
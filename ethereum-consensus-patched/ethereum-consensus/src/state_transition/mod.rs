@@ -1,14 +1,21 @@
+#[cfg(feature = "transition")]
 mod beacon_block;
+#[cfg(feature = "transition")]
 mod beacon_state;
 mod context;
 mod execution_engine;
+#[cfg(feature = "transition")]
 mod executor;
+#[cfg(feature = "transition")]
 mod presets;
 
+#[cfg(feature = "transition")]
 pub use beacon_block::*;
+#[cfg(feature = "transition")]
 pub use beacon_state::*;
 pub use context::*;
 pub use execution_engine::*;
+#[cfg(feature = "transition")]
 pub use executor::*;
 
 pub type Result<T> = std::result::Result<T, crate::Error>;
@@ -19,10 +26,17 @@ pub enum Validation {
     Disabled,
 }
 
+#[cfg(feature = "transition")]
 pub mod mainnet {
     pub use super::presets::mainnet::*;
 }
 
+#[cfg(feature = "transition")]
+pub mod gnosis {
+    pub use super::presets::gnosis::*;
+}
+
+#[cfg(feature = "transition")]
 pub mod minimal {
     pub use super::presets::minimal::*;
 }
@@ -1,2 +1,3 @@
+pub mod gnosis;
 pub mod mainnet;
 pub mod minimal;
@@ -4,6 +4,10 @@ use crate::{
     bellatrix::beacon_block as bellatrix,
     capella::{beacon_block as capella, SignedBlsToExecutionChange},
     deneb::{beacon_block as deneb, polynomial_commitments::KzgCommitment},
+    electra::{
+        beacon_block as electra, Attestation as AttestationElectra,
+        AttesterSlashing as AttesterSlashingElectra, ExecutionRequests,
+    },
     phase0::{
         beacon_block as phase0, Attestation, AttesterSlashing, Deposit, Eth1Data, ProposerSlashing,
         SignedVoluntaryExit,
@@ -13,7 +17,7 @@ use crate::{
     types::execution_payload::{ExecutionPayloadRef, ExecutionPayloadRefMut},
     Fork as Version,
 };
-#[derive(Debug, Clone, PartialEq, Eq, Merkleized, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Merkleized, serde::Serialize)]
 #[ssz(transparent)]
 #[serde(untagged)]
 pub enum BeaconBlockBody<
@@ -31,6 +35,13 @@ pub enum BeaconBlockBody<
     const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
     const MAX_BLS_TO_EXECUTION_CHANGES: usize,
     const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+    const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+    const MAX_ATTESTATIONS_ELECTRA: usize,
+    const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
 > {
     Phase0(
         phase0::BeaconBlockBody<
@@ -103,6 +114,28 @@ pub enum BeaconBlockBody<
             MAX_BLOB_COMMITMENTS_PER_BLOCK,
         >,
     ),
+    Electra(
+        electra::BeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    ),
 }
 impl<
         const MAX_PROPOSER_SLASHINGS: usize,
@@ -119,6 +152,13 @@ impl<
         const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     BeaconBlockBody<
         MAX_PROPOSER_SLASHINGS,
@@ -135,6 +175,13 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     pub fn phase0(
@@ -349,6 +396,64 @@ impl<
             _ => None,
         }
     }
+    pub fn electra(
+        &self,
+    ) -> Option<
+        &electra::BeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
+    pub fn electra_mut(
+        &mut self,
+    ) -> Option<
+        &mut electra::BeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
     pub fn version(&self) -> Version {
         match self {
             Self::Phase0(_) => Version::Phase0,
@@ -356,6 +461,7 @@ impl<
             Self::Bellatrix(_) => Version::Bellatrix,
             Self::Capella(_) => Version::Capella,
             Self::Deneb(_) => Version::Deneb,
+            Self::Electra(_) => Version::Electra,
         }
     }
     pub fn randao_reveal(&self) -> &BlsSignature {
@@ -365,6 +471,7 @@ impl<
             Self::Bellatrix(inner) => &inner.randao_reveal,
             Self::Capella(inner) => &inner.randao_reveal,
             Self::Deneb(inner) => &inner.randao_reveal,
+            Self::Electra(inner) => &inner.randao_reveal,
         }
     }
     pub fn randao_reveal_mut(&mut self) -> &mut BlsSignature {
@@ -374,6 +481,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.randao_reveal,
             Self::Capella(inner) => &mut inner.randao_reveal,
             Self::Deneb(inner) => &mut inner.randao_reveal,
+            Self::Electra(inner) => &mut inner.randao_reveal,
         }
     }
     pub fn eth1_data(&self) -> &Eth1Data {
@@ -383,6 +491,7 @@ impl<
             Self::Bellatrix(inner) => &inner.eth1_data,
             Self::Capella(inner) => &inner.eth1_data,
             Self::Deneb(inner) => &inner.eth1_data,
+            Self::Electra(inner) => &inner.eth1_data,
         }
     }
     pub fn eth1_data_mut(&mut self) -> &mut Eth1Data {
@@ -392,6 +501,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.eth1_data,
             Self::Capella(inner) => &mut inner.eth1_data,
             Self::Deneb(inner) => &mut inner.eth1_data,
+            Self::Electra(inner) => &mut inner.eth1_data,
         }
     }
     pub fn graffiti(&self) -> &Bytes32 {
@@ -401,6 +511,7 @@ impl<
             Self::Bellatrix(inner) => &inner.graffiti,
             Self::Capella(inner) => &inner.graffiti,
             Self::Deneb(inner) => &inner.graffiti,
+            Self::Electra(inner) => &inner.graffiti,
         }
     }
     pub fn graffiti_mut(&mut self) -> &mut Bytes32 {
@@ -410,6 +521,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.graffiti,
             Self::Capella(inner) => &mut inner.graffiti,
             Self::Deneb(inner) => &mut inner.graffiti,
+            Self::Electra(inner) => &mut inner.graffiti,
         }
     }
     pub fn proposer_slashings(&self) -> &List<ProposerSlashing, MAX_PROPOSER_SLASHINGS> {
@@ -419,6 +531,7 @@ impl<
             Self::Bellatrix(inner) => &inner.proposer_slashings,
             Self::Capella(inner) => &inner.proposer_slashings,
             Self::Deneb(inner) => &inner.proposer_slashings,
+            Self::Electra(inner) => &inner.proposer_slashings,
         }
     }
     pub fn proposer_slashings_mut(
@@ -430,50 +543,56 @@ impl<
             Self::Bellatrix(inner) => &mut inner.proposer_slashings,
             Self::Capella(inner) => &mut inner.proposer_slashings,
             Self::Deneb(inner) => &mut inner.proposer_slashings,
+            Self::Electra(inner) => &mut inner.proposer_slashings,
         }
     }
     pub fn attester_slashings(
         &self,
-    ) -> &List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS> {
+    ) -> Option<&List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS>> {
         match self {
-            Self::Phase0(inner) => &inner.attester_slashings,
-            Self::Altair(inner) => &inner.attester_slashings,
-            Self::Bellatrix(inner) => &inner.attester_slashings,
-            Self::Capella(inner) => &inner.attester_slashings,
-            Self::Deneb(inner) => &inner.attester_slashings,
+            Self::Phase0(inner) => Some(&inner.attester_slashings),
+            Self::Altair(inner) => Some(&inner.attester_slashings),
+            Self::Bellatrix(inner) => Some(&inner.attester_slashings),
+            Self::Capella(inner) => Some(&inner.attester_slashings),
+            Self::Deneb(inner) => Some(&inner.attester_slashings),
+            Self::Electra(_) => None,
         }
     }
     pub fn attester_slashings_mut(
         &mut self,
-    ) -> &mut List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS> {
+    ) -> Option<&mut List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS>>
+    {
         match self {
-            Self::Phase0(inner) => &mut inner.attester_slashings,
-            Self::Altair(inner) => &mut inner.attester_slashings,
-            Self::Bellatrix(inner) => &mut inner.attester_slashings,
-            Self::Capella(inner) => &mut inner.attester_slashings,
-            Self::Deneb(inner) => &mut inner.attester_slashings,
+            Self::Phase0(inner) => Some(&mut inner.attester_slashings),
+            Self::Altair(inner) => Some(&mut inner.attester_slashings),
+            Self::Bellatrix(inner) => Some(&mut inner.attester_slashings),
+            Self::Capella(inner) => Some(&mut inner.attester_slashings),
+            Self::Deneb(inner) => Some(&mut inner.attester_slashings),
+            Self::Electra(_) => None,
         }
     }
     pub fn attestations(
         &self,
-    ) -> &List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS> {
+    ) -> Option<&List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS>> {
         match self {
-            Self::Phase0(inner) => &inner.attestations,
-            Self::Altair(inner) => &inner.attestations,
-            Self::Bellatrix(inner) => &inner.attestations,
-            Self::Capella(inner) => &inner.attestations,
-            Self::Deneb(inner) => &inner.attestations,
+            Self::Phase0(inner) => Some(&inner.attestations),
+            Self::Altair(inner) => Some(&inner.attestations),
+            Self::Bellatrix(inner) => Some(&inner.attestations),
+            Self::Capella(inner) => Some(&inner.attestations),
+            Self::Deneb(inner) => Some(&inner.attestations),
+            Self::Electra(_) => None,
         }
     }
     pub fn attestations_mut(
         &mut self,
-    ) -> &mut List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS> {
+    ) -> Option<&mut List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS>> {
         match self {
-            Self::Phase0(inner) => &mut inner.attestations,
-            Self::Altair(inner) => &mut inner.attestations,
-            Self::Bellatrix(inner) => &mut inner.attestations,
-            Self::Capella(inner) => &mut inner.attestations,
-            Self::Deneb(inner) => &mut inner.attestations,
+            Self::Phase0(inner) => Some(&mut inner.attestations),
+            Self::Altair(inner) => Some(&mut inner.attestations),
+            Self::Bellatrix(inner) => Some(&mut inner.attestations),
+            Self::Capella(inner) => Some(&mut inner.attestations),
+            Self::Deneb(inner) => Some(&mut inner.attestations),
+            Self::Electra(_) => None,
         }
     }
     pub fn deposits(&self) -> &List<Deposit, MAX_DEPOSITS> {
@@ -483,6 +602,7 @@ impl<
             Self::Bellatrix(inner) => &inner.deposits,
             Self::Capella(inner) => &inner.deposits,
             Self::Deneb(inner) => &inner.deposits,
+            Self::Electra(inner) => &inner.deposits,
         }
     }
     pub fn deposits_mut(&mut self) -> &mut List<Deposit, MAX_DEPOSITS> {
@@ -492,6 +612,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.deposits,
             Self::Capella(inner) => &mut inner.deposits,
             Self::Deneb(inner) => &mut inner.deposits,
+            Self::Electra(inner) => &mut inner.deposits,
         }
     }
     pub fn voluntary_exits(&self) -> &List<SignedVoluntaryExit, MAX_VOLUNTARY_EXITS> {
@@ -501,6 +622,7 @@ impl<
             Self::Bellatrix(inner) => &inner.voluntary_exits,
             Self::Capella(inner) => &inner.voluntary_exits,
             Self::Deneb(inner) => &inner.voluntary_exits,
+            Self::Electra(inner) => &inner.voluntary_exits,
         }
     }
     pub fn voluntary_exits_mut(&mut self) -> &mut List<SignedVoluntaryExit, MAX_VOLUNTARY_EXITS> {
@@ -510,6 +632,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.voluntary_exits,
             Self::Capella(inner) => &mut inner.voluntary_exits,
             Self::Deneb(inner) => &mut inner.voluntary_exits,
+            Self::Electra(inner) => &mut inner.voluntary_exits,
         }
     }
     pub fn sync_aggregate(&self) -> Option<&SyncAggregate<SYNC_COMMITTEE_SIZE>> {
@@ -519,6 +642,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.sync_aggregate),
             Self::Capella(inner) => Some(&inner.sync_aggregate),
             Self::Deneb(inner) => Some(&inner.sync_aggregate),
+            Self::Electra(inner) => Some(&inner.sync_aggregate),
         }
     }
     pub fn sync_aggregate_mut(&mut self) -> Option<&mut SyncAggregate<SYNC_COMMITTEE_SIZE>> {
@@ -528,6 +652,7 @@ impl<
             Self::Bellatrix(inner) => Some(&mut inner.sync_aggregate),
             Self::Capella(inner) => Some(&mut inner.sync_aggregate),
             Self::Deneb(inner) => Some(&mut inner.sync_aggregate),
+            Self::Electra(inner) => Some(&mut inner.sync_aggregate),
         }
     }
     pub fn execution_payload(
@@ -547,6 +672,7 @@ impl<
             Self::Bellatrix(inner) => Some(From::from(&inner.execution_payload)),
             Self::Capella(inner) => Some(From::from(&inner.execution_payload)),
             Self::Deneb(inner) => Some(From::from(&inner.execution_payload)),
+            Self::Electra(inner) => Some(From::from(&inner.execution_payload)),
         }
     }
     pub fn execution_payload_mut(
@@ -566,6 +692,7 @@ impl<
             Self::Bellatrix(inner) => Some(From::from(&mut inner.execution_payload)),
             Self::Capella(inner) => Some(From::from(&mut inner.execution_payload)),
             Self::Deneb(inner) => Some(From::from(&mut inner.execution_payload)),
+            Self::Electra(inner) => Some(From::from(&mut inner.execution_payload)),
         }
     }
     pub fn bls_to_execution_changes(
@@ -577,6 +704,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&inner.bls_to_execution_changes),
             Self::Deneb(inner) => Some(&inner.bls_to_execution_changes),
+            Self::Electra(inner) => Some(&inner.bls_to_execution_changes),
         }
     }
     pub fn bls_to_execution_changes_mut(
@@ -588,6 +716,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&mut inner.bls_to_execution_changes),
             Self::Deneb(inner) => Some(&mut inner.bls_to_execution_changes),
+            Self::Electra(inner) => Some(&mut inner.bls_to_execution_changes),
         }
     }
     pub fn blob_kzg_commitments(
@@ -599,6 +728,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(inner) => Some(&inner.blob_kzg_commitments),
+            Self::Electra(inner) => Some(&inner.blob_kzg_commitments),
         }
     }
     pub fn blob_kzg_commitments_mut(
@@ -610,6 +740,105 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(inner) => Some(&mut inner.blob_kzg_commitments),
+            Self::Electra(inner) => Some(&mut inner.blob_kzg_commitments),
+        }
+    }
+    pub fn attester_slashings_electra(
+        &self,
+    ) -> Option<
+        &List<AttesterSlashingElectra<MAX_VALIDATORS_PER_SLOT>, MAX_ATTESTER_SLASHINGS_ELECTRA>,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.attester_slashings),
+        }
+    }
+    pub fn attester_slashings_electra_mut(
+        &mut self,
+    ) -> Option<
+        &mut List<AttesterSlashingElectra<MAX_VALIDATORS_PER_SLOT>, MAX_ATTESTER_SLASHINGS_ELECTRA>,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.attester_slashings),
+        }
+    }
+    pub fn attestations_electra(
+        &self,
+    ) -> Option<
+        &List<
+            AttestationElectra<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+            MAX_ATTESTATIONS_ELECTRA,
+        >,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.attestations),
+        }
+    }
+    pub fn attestations_electra_mut(
+        &mut self,
+    ) -> Option<
+        &mut List<
+            AttestationElectra<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+            MAX_ATTESTATIONS_ELECTRA,
+        >,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.attestations),
+        }
+    }
+    pub fn execution_requests(
+        &self,
+    ) -> Option<
+        &ExecutionRequests<
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.execution_requests),
+        }
+    }
+    pub fn execution_requests_mut(
+        &mut self,
+    ) -> Option<
+        &mut ExecutionRequests<
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.execution_requests),
         }
     }
 }
@@ -629,6 +858,13 @@ impl<
         const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     > serde::Deserialize<'de>
     for BeaconBlockBody<
         MAX_PROPOSER_SLASHINGS,
@@ -645,6 +881,13 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -652,6 +895,9 @@ impl<
         D: serde::Deserializer<'de>,
     {
         let value = serde_json::Value::deserialize(deserializer)?;
+        if let Ok(inner) = <_ as serde::Deserialize>::deserialize(&value) {
+            return Ok(Self::Electra(inner))
+        }
         if let Ok(inner) = <_ as serde::Deserialize>::deserialize(&value) {
             return Ok(Self::Deneb(inner))
         }
@@ -670,7 +916,7 @@ impl<
         Err(serde::de::Error::custom("no variant could be deserialized from input"))
     }
 }
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum BeaconBlockBodyRef<
     'a,
     const MAX_PROPOSER_SLASHINGS: usize,
@@ -687,6 +933,13 @@ pub enum BeaconBlockBodyRef<
     const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
     const MAX_BLS_TO_EXECUTION_CHANGES: usize,
     const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+    const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+    const MAX_ATTESTATIONS_ELECTRA: usize,
+    const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
 > {
     Phase0(
         &'a phase0::BeaconBlockBody<
@@ -759,6 +1012,28 @@ pub enum BeaconBlockBodyRef<
             MAX_BLOB_COMMITMENTS_PER_BLOCK,
         >,
     ),
+    Electra(
+        &'a electra::BeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    ),
 }
 impl<
         'a,
@@ -776,6 +1051,13 @@ impl<
         const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     BeaconBlockBodyRef<
         'a,
@@ -793,6 +1075,13 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     pub fn phase0(
@@ -901,6 +1190,35 @@ impl<
             _ => None,
         }
     }
+    pub fn electra(
+        &self,
+    ) -> Option<
+        &electra::BeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
     pub fn version(&self) -> Version {
         match self {
             Self::Phase0(_) => Version::Phase0,
@@ -908,6 +1226,7 @@ impl<
             Self::Bellatrix(_) => Version::Bellatrix,
             Self::Capella(_) => Version::Capella,
             Self::Deneb(_) => Version::Deneb,
+            Self::Electra(_) => Version::Electra,
         }
     }
     pub fn randao_reveal(&self) -> &BlsSignature {
@@ -917,6 +1236,7 @@ impl<
             Self::Bellatrix(inner) => &inner.randao_reveal,
             Self::Capella(inner) => &inner.randao_reveal,
             Self::Deneb(inner) => &inner.randao_reveal,
+            Self::Electra(inner) => &inner.randao_reveal,
         }
     }
     pub fn eth1_data(&self) -> &Eth1Data {
@@ -926,6 +1246,7 @@ impl<
             Self::Bellatrix(inner) => &inner.eth1_data,
             Self::Capella(inner) => &inner.eth1_data,
             Self::Deneb(inner) => &inner.eth1_data,
+            Self::Electra(inner) => &inner.eth1_data,
         }
     }
     pub fn graffiti(&self) -> &Bytes32 {
@@ -935,6 +1256,7 @@ impl<
             Self::Bellatrix(inner) => &inner.graffiti,
             Self::Capella(inner) => &inner.graffiti,
             Self::Deneb(inner) => &inner.graffiti,
+            Self::Electra(inner) => &inner.graffiti,
         }
     }
     pub fn proposer_slashings(&self) -> &List<ProposerSlashing, MAX_PROPOSER_SLASHINGS> {
@@ -944,28 +1266,31 @@ impl<
             Self::Bellatrix(inner) => &inner.proposer_slashings,
             Self::Capella(inner) => &inner.proposer_slashings,
             Self::Deneb(inner) => &inner.proposer_slashings,
+            Self::Electra(inner) => &inner.proposer_slashings,
         }
     }
     pub fn attester_slashings(
         &self,
-    ) -> &List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS> {
+    ) -> Option<&List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS>> {
         match self {
-            Self::Phase0(inner) => &inner.attester_slashings,
-            Self::Altair(inner) => &inner.attester_slashings,
-            Self::Bellatrix(inner) => &inner.attester_slashings,
-            Self::Capella(inner) => &inner.attester_slashings,
-            Self::Deneb(inner) => &inner.attester_slashings,
+            Self::Phase0(inner) => Some(&inner.attester_slashings),
+            Self::Altair(inner) => Some(&inner.attester_slashings),
+            Self::Bellatrix(inner) => Some(&inner.attester_slashings),
+            Self::Capella(inner) => Some(&inner.attester_slashings),
+            Self::Deneb(inner) => Some(&inner.attester_slashings),
+            Self::Electra(_) => None,
         }
     }
     pub fn attestations(
         &self,
-    ) -> &List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS> {
+    ) -> Option<&List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS>> {
         match self {
-            Self::Phase0(inner) => &inner.attestations,
-            Self::Altair(inner) => &inner.attestations,
-            Self::Bellatrix(inner) => &inner.attestations,
-            Self::Capella(inner) => &inner.attestations,
-            Self::Deneb(inner) => &inner.attestations,
+            Self::Phase0(inner) => Some(&inner.attestations),
+            Self::Altair(inner) => Some(&inner.attestations),
+            Self::Bellatrix(inner) => Some(&inner.attestations),
+            Self::Capella(inner) => Some(&inner.attestations),
+            Self::Deneb(inner) => Some(&inner.attestations),
+            Self::Electra(_) => None,
         }
     }
     pub fn deposits(&self) -> &List<Deposit, MAX_DEPOSITS> {
@@ -975,6 +1300,7 @@ impl<
             Self::Bellatrix(inner) => &inner.deposits,
             Self::Capella(inner) => &inner.deposits,
             Self::Deneb(inner) => &inner.deposits,
+            Self::Electra(inner) => &inner.deposits,
         }
     }
     pub fn voluntary_exits(&self) -> &List<SignedVoluntaryExit, MAX_VOLUNTARY_EXITS> {
@@ -984,6 +1310,7 @@ impl<
             Self::Bellatrix(inner) => &inner.voluntary_exits,
             Self::Capella(inner) => &inner.voluntary_exits,
             Self::Deneb(inner) => &inner.voluntary_exits,
+            Self::Electra(inner) => &inner.voluntary_exits,
         }
     }
     pub fn sync_aggregate(&self) -> Option<&SyncAggregate<SYNC_COMMITTEE_SIZE>> {
@@ -993,6 +1320,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.sync_aggregate),
             Self::Capella(inner) => Some(&inner.sync_aggregate),
             Self::Deneb(inner) => Some(&inner.sync_aggregate),
+            Self::Electra(inner) => Some(&inner.sync_aggregate),
         }
     }
     pub fn execution_payload(
@@ -1012,6 +1340,7 @@ impl<
             Self::Bellatrix(inner) => Some(From::from(&inner.execution_payload)),
             Self::Capella(inner) => Some(From::from(&inner.execution_payload)),
             Self::Deneb(inner) => Some(From::from(&inner.execution_payload)),
+            Self::Electra(inner) => Some(From::from(&inner.execution_payload)),
         }
     }
     pub fn bls_to_execution_changes(
@@ -1023,6 +1352,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&inner.bls_to_execution_changes),
             Self::Deneb(inner) => Some(&inner.bls_to_execution_changes),
+            Self::Electra(inner) => Some(&inner.bls_to_execution_changes),
         }
     }
     pub fn blob_kzg_commitments(
@@ -1034,6 +1364,56 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(inner) => Some(&inner.blob_kzg_commitments),
+            Self::Electra(inner) => Some(&inner.blob_kzg_commitments),
+        }
+    }
+    pub fn attester_slashings_electra(
+        &self,
+    ) -> Option<
+        &List<AttesterSlashingElectra<MAX_VALIDATORS_PER_SLOT>, MAX_ATTESTER_SLASHINGS_ELECTRA>,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.attester_slashings),
+        }
+    }
+    pub fn attestations_electra(
+        &self,
+    ) -> Option<
+        &List<
+            AttestationElectra<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+            MAX_ATTESTATIONS_ELECTRA,
+        >,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.attestations),
+        }
+    }
+    pub fn execution_requests(
+        &self,
+    ) -> Option<
+        &ExecutionRequests<
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.execution_requests),
         }
     }
 }
@@ -1053,6 +1433,13 @@ impl<
         const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a phase0::BeaconBlockBody<
@@ -1080,6 +1467,13 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -1111,6 +1505,13 @@ impl<
         const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a altair::BeaconBlockBody<
@@ -1139,6 +1540,13 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -1171,6 +1579,13 @@ impl<
         const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a bellatrix::BeaconBlockBody<
@@ -1203,6 +1618,13 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -1239,6 +1661,13 @@ impl<
         const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a capella::BeaconBlockBody<
@@ -1273,6 +1702,13 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -1311,6 +1747,13 @@ impl<
         const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a deneb::BeaconBlockBody<
@@ -1346,6 +1789,13 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -1369,7 +1819,103 @@ impl<
         Self::Deneb(value)
     }
 }
-#[derive(Debug, PartialEq, Eq, Merkleized)]
+impl<
+        'a,
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+        const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+    >
+    From<
+        &'a electra::BeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    >
+    for BeaconBlockBodyRef<
+        'a,
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >
+{
+    fn from(
+        value: &'a electra::BeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    ) -> Self {
+        Self::Electra(value)
+    }
+}
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Merkleized)]
 #[ssz(transparent)]
 pub enum BeaconBlockBodyRefMut<
     'a,
@@ -1387,6 +1933,13 @@ pub enum BeaconBlockBodyRefMut<
     const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
     const MAX_BLS_TO_EXECUTION_CHANGES: usize,
     const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+    const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+    const MAX_ATTESTATIONS_ELECTRA: usize,
+    const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
 > {
     Phase0(
         &'a mut phase0::BeaconBlockBody<
@@ -1459,6 +2012,28 @@ pub enum BeaconBlockBodyRefMut<
             MAX_BLOB_COMMITMENTS_PER_BLOCK,
         >,
     ),
+    Electra(
+        &'a mut electra::BeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    ),
 }
 impl<
         'a,
@@ -1476,6 +2051,13 @@ impl<
         const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     BeaconBlockBodyRefMut<
         'a,
@@ -1493,6 +2075,13 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     pub fn phase0(
@@ -1707,6 +2296,64 @@ impl<
             _ => None,
         }
     }
+    pub fn electra(
+        &self,
+    ) -> Option<
+        &electra::BeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
+    pub fn electra_mut(
+        &mut self,
+    ) -> Option<
+        &mut electra::BeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
     pub fn version(&self) -> Version {
         match self {
             Self::Phase0(_) => Version::Phase0,
@@ -1714,6 +2361,7 @@ impl<
             Self::Bellatrix(_) => Version::Bellatrix,
             Self::Capella(_) => Version::Capella,
             Self::Deneb(_) => Version::Deneb,
+            Self::Electra(_) => Version::Electra,
         }
     }
     pub fn randao_reveal(&self) -> &BlsSignature {
@@ -1723,6 +2371,7 @@ impl<
             Self::Bellatrix(inner) => &inner.randao_reveal,
             Self::Capella(inner) => &inner.randao_reveal,
             Self::Deneb(inner) => &inner.randao_reveal,
+            Self::Electra(inner) => &inner.randao_reveal,
         }
     }
     pub fn randao_reveal_mut(&mut self) -> &mut BlsSignature {
@@ -1732,6 +2381,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.randao_reveal,
             Self::Capella(inner) => &mut inner.randao_reveal,
             Self::Deneb(inner) => &mut inner.randao_reveal,
+            Self::Electra(inner) => &mut inner.randao_reveal,
         }
     }
     pub fn eth1_data(&self) -> &Eth1Data {
@@ -1741,6 +2391,7 @@ impl<
             Self::Bellatrix(inner) => &inner.eth1_data,
             Self::Capella(inner) => &inner.eth1_data,
             Self::Deneb(inner) => &inner.eth1_data,
+            Self::Electra(inner) => &inner.eth1_data,
         }
     }
     pub fn eth1_data_mut(&mut self) -> &mut Eth1Data {
@@ -1750,6 +2401,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.eth1_data,
             Self::Capella(inner) => &mut inner.eth1_data,
             Self::Deneb(inner) => &mut inner.eth1_data,
+            Self::Electra(inner) => &mut inner.eth1_data,
         }
     }
     pub fn graffiti(&self) -> &Bytes32 {
@@ -1759,6 +2411,7 @@ impl<
             Self::Bellatrix(inner) => &inner.graffiti,
             Self::Capella(inner) => &inner.graffiti,
             Self::Deneb(inner) => &inner.graffiti,
+            Self::Electra(inner) => &inner.graffiti,
         }
     }
     pub fn graffiti_mut(&mut self) -> &mut Bytes32 {
@@ -1768,6 +2421,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.graffiti,
             Self::Capella(inner) => &mut inner.graffiti,
             Self::Deneb(inner) => &mut inner.graffiti,
+            Self::Electra(inner) => &mut inner.graffiti,
         }
     }
     pub fn proposer_slashings(&self) -> &List<ProposerSlashing, MAX_PROPOSER_SLASHINGS> {
@@ -1777,6 +2431,7 @@ impl<
             Self::Bellatrix(inner) => &inner.proposer_slashings,
             Self::Capella(inner) => &inner.proposer_slashings,
             Self::Deneb(inner) => &inner.proposer_slashings,
+            Self::Electra(inner) => &inner.proposer_slashings,
         }
     }
     pub fn proposer_slashings_mut(
@@ -1788,50 +2443,56 @@ impl<
             Self::Bellatrix(inner) => &mut inner.proposer_slashings,
             Self::Capella(inner) => &mut inner.proposer_slashings,
             Self::Deneb(inner) => &mut inner.proposer_slashings,
+            Self::Electra(inner) => &mut inner.proposer_slashings,
         }
     }
     pub fn attester_slashings(
         &self,
-    ) -> &List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS> {
+    ) -> Option<&List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS>> {
         match self {
-            Self::Phase0(inner) => &inner.attester_slashings,
-            Self::Altair(inner) => &inner.attester_slashings,
-            Self::Bellatrix(inner) => &inner.attester_slashings,
-            Self::Capella(inner) => &inner.attester_slashings,
-            Self::Deneb(inner) => &inner.attester_slashings,
+            Self::Phase0(inner) => Some(&inner.attester_slashings),
+            Self::Altair(inner) => Some(&inner.attester_slashings),
+            Self::Bellatrix(inner) => Some(&inner.attester_slashings),
+            Self::Capella(inner) => Some(&inner.attester_slashings),
+            Self::Deneb(inner) => Some(&inner.attester_slashings),
+            Self::Electra(_) => None,
         }
     }
     pub fn attester_slashings_mut(
         &mut self,
-    ) -> &mut List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS> {
+    ) -> Option<&mut List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS>>
+    {
         match self {
-            Self::Phase0(inner) => &mut inner.attester_slashings,
-            Self::Altair(inner) => &mut inner.attester_slashings,
-            Self::Bellatrix(inner) => &mut inner.attester_slashings,
-            Self::Capella(inner) => &mut inner.attester_slashings,
-            Self::Deneb(inner) => &mut inner.attester_slashings,
+            Self::Phase0(inner) => Some(&mut inner.attester_slashings),
+            Self::Altair(inner) => Some(&mut inner.attester_slashings),
+            Self::Bellatrix(inner) => Some(&mut inner.attester_slashings),
+            Self::Capella(inner) => Some(&mut inner.attester_slashings),
+            Self::Deneb(inner) => Some(&mut inner.attester_slashings),
+            Self::Electra(_) => None,
         }
     }
     pub fn attestations(
         &self,
-    ) -> &List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS> {
+    ) -> Option<&List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS>> {
         match self {
-            Self::Phase0(inner) => &inner.attestations,
-            Self::Altair(inner) => &inner.attestations,
-            Self::Bellatrix(inner) => &inner.attestations,
-            Self::Capella(inner) => &inner.attestations,
-            Self::Deneb(inner) => &inner.attestations,
+            Self::Phase0(inner) => Some(&inner.attestations),
+            Self::Altair(inner) => Some(&inner.attestations),
+            Self::Bellatrix(inner) => Some(&inner.attestations),
+            Self::Capella(inner) => Some(&inner.attestations),
+            Self::Deneb(inner) => Some(&inner.attestations),
+            Self::Electra(_) => None,
         }
     }
     pub fn attestations_mut(
         &mut self,
-    ) -> &mut List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS> {
+    ) -> Option<&mut List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS>> {
         match self {
-            Self::Phase0(inner) => &mut inner.attestations,
-            Self::Altair(inner) => &mut inner.attestations,
-            Self::Bellatrix(inner) => &mut inner.attestations,
-            Self::Capella(inner) => &mut inner.attestations,
-            Self::Deneb(inner) => &mut inner.attestations,
+            Self::Phase0(inner) => Some(&mut inner.attestations),
+            Self::Altair(inner) => Some(&mut inner.attestations),
+            Self::Bellatrix(inner) => Some(&mut inner.attestations),
+            Self::Capella(inner) => Some(&mut inner.attestations),
+            Self::Deneb(inner) => Some(&mut inner.attestations),
+            Self::Electra(_) => None,
         }
     }
     pub fn deposits(&self) -> &List<Deposit, MAX_DEPOSITS> {
@@ -1841,6 +2502,7 @@ impl<
             Self::Bellatrix(inner) => &inner.deposits,
             Self::Capella(inner) => &inner.deposits,
             Self::Deneb(inner) => &inner.deposits,
+            Self::Electra(inner) => &inner.deposits,
         }
     }
     pub fn deposits_mut(&mut self) -> &mut List<Deposit, MAX_DEPOSITS> {
@@ -1850,6 +2512,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.deposits,
             Self::Capella(inner) => &mut inner.deposits,
             Self::Deneb(inner) => &mut inner.deposits,
+            Self::Electra(inner) => &mut inner.deposits,
         }
     }
     pub fn voluntary_exits(&self) -> &List<SignedVoluntaryExit, MAX_VOLUNTARY_EXITS> {
@@ -1859,6 +2522,7 @@ impl<
             Self::Bellatrix(inner) => &inner.voluntary_exits,
             Self::Capella(inner) => &inner.voluntary_exits,
             Self::Deneb(inner) => &inner.voluntary_exits,
+            Self::Electra(inner) => &inner.voluntary_exits,
         }
     }
     pub fn voluntary_exits_mut(&mut self) -> &mut List<SignedVoluntaryExit, MAX_VOLUNTARY_EXITS> {
@@ -1868,6 +2532,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.voluntary_exits,
             Self::Capella(inner) => &mut inner.voluntary_exits,
             Self::Deneb(inner) => &mut inner.voluntary_exits,
+            Self::Electra(inner) => &mut inner.voluntary_exits,
         }
     }
     pub fn sync_aggregate(&self) -> Option<&SyncAggregate<SYNC_COMMITTEE_SIZE>> {
@@ -1877,6 +2542,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.sync_aggregate),
             Self::Capella(inner) => Some(&inner.sync_aggregate),
             Self::Deneb(inner) => Some(&inner.sync_aggregate),
+            Self::Electra(inner) => Some(&inner.sync_aggregate),
         }
     }
     pub fn sync_aggregate_mut(&mut self) -> Option<&mut SyncAggregate<SYNC_COMMITTEE_SIZE>> {
@@ -1886,6 +2552,7 @@ impl<
             Self::Bellatrix(inner) => Some(&mut inner.sync_aggregate),
             Self::Capella(inner) => Some(&mut inner.sync_aggregate),
             Self::Deneb(inner) => Some(&mut inner.sync_aggregate),
+            Self::Electra(inner) => Some(&mut inner.sync_aggregate),
         }
     }
     pub fn execution_payload(
@@ -1905,6 +2572,7 @@ impl<
             Self::Bellatrix(inner) => Some(From::from(&inner.execution_payload)),
             Self::Capella(inner) => Some(From::from(&inner.execution_payload)),
             Self::Deneb(inner) => Some(From::from(&inner.execution_payload)),
+            Self::Electra(inner) => Some(From::from(&inner.execution_payload)),
         }
     }
     pub fn execution_payload_mut(
@@ -1924,6 +2592,7 @@ impl<
             Self::Bellatrix(inner) => Some(From::from(&mut inner.execution_payload)),
             Self::Capella(inner) => Some(From::from(&mut inner.execution_payload)),
             Self::Deneb(inner) => Some(From::from(&mut inner.execution_payload)),
+            Self::Electra(inner) => Some(From::from(&mut inner.execution_payload)),
         }
     }
     pub fn bls_to_execution_changes(
@@ -1935,6 +2604,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&inner.bls_to_execution_changes),
             Self::Deneb(inner) => Some(&inner.bls_to_execution_changes),
+            Self::Electra(inner) => Some(&inner.bls_to_execution_changes),
         }
     }
     pub fn bls_to_execution_changes_mut(
@@ -1946,6 +2616,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&mut inner.bls_to_execution_changes),
             Self::Deneb(inner) => Some(&mut inner.bls_to_execution_changes),
+            Self::Electra(inner) => Some(&mut inner.bls_to_execution_changes),
         }
     }
     pub fn blob_kzg_commitments(
@@ -1957,6 +2628,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(inner) => Some(&inner.blob_kzg_commitments),
+            Self::Electra(inner) => Some(&inner.blob_kzg_commitments),
         }
     }
     pub fn blob_kzg_commitments_mut(
@@ -1968,6 +2640,105 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(inner) => Some(&mut inner.blob_kzg_commitments),
+            Self::Electra(inner) => Some(&mut inner.blob_kzg_commitments),
+        }
+    }
+    pub fn attester_slashings_electra(
+        &self,
+    ) -> Option<
+        &List<AttesterSlashingElectra<MAX_VALIDATORS_PER_SLOT>, MAX_ATTESTER_SLASHINGS_ELECTRA>,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.attester_slashings),
+        }
+    }
+    pub fn attester_slashings_electra_mut(
+        &mut self,
+    ) -> Option<
+        &mut List<AttesterSlashingElectra<MAX_VALIDATORS_PER_SLOT>, MAX_ATTESTER_SLASHINGS_ELECTRA>,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.attester_slashings),
+        }
+    }
+    pub fn attestations_electra(
+        &self,
+    ) -> Option<
+        &List<
+            AttestationElectra<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+            MAX_ATTESTATIONS_ELECTRA,
+        >,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.attestations),
+        }
+    }
+    pub fn attestations_electra_mut(
+        &mut self,
+    ) -> Option<
+        &mut List<
+            AttestationElectra<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+            MAX_ATTESTATIONS_ELECTRA,
+        >,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.attestations),
+        }
+    }
+    pub fn execution_requests(
+        &self,
+    ) -> Option<
+        &ExecutionRequests<
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.execution_requests),
+        }
+    }
+    pub fn execution_requests_mut(
+        &mut self,
+    ) -> Option<
+        &mut ExecutionRequests<
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.execution_requests),
         }
     }
 }
@@ -1987,6 +2758,13 @@ impl<
         const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a mut phase0::BeaconBlockBody<
@@ -2014,6 +2792,13 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -2045,6 +2830,13 @@ impl<
         const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a mut altair::BeaconBlockBody<
@@ -2073,6 +2865,13 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -2105,6 +2904,13 @@ impl<
         const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a mut bellatrix::BeaconBlockBody<
@@ -2137,6 +2943,13 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -2173,6 +2986,13 @@ impl<
         const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a mut capella::BeaconBlockBody<
@@ -2207,6 +3027,13 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -2245,6 +3072,13 @@ impl<
         const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a mut deneb::BeaconBlockBody<
@@ -2280,6 +3114,13 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -2303,3 +3144,99 @@ impl<
         Self::Deneb(value)
     }
 }
+impl<
+        'a,
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+        const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+    >
+    From<
+        &'a mut electra::BeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    >
+    for BeaconBlockBodyRefMut<
+        'a,
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >
+{
+    fn from(
+        value: &'a mut electra::BeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    ) -> Self {
+        Self::Electra(value)
+    }
+}
@@ -4,6 +4,9 @@ use crate::{
     bellatrix::beacon_state as bellatrix,
     capella::beacon_state::{self as capella, HistoricalSummary},
     deneb::beacon_state as deneb,
+    electra::{
+        beacon_state as electra, PendingConsolidation, PendingDeposit, PendingPartialWithdrawal,
+    },
     phase0::{
         beacon_block::BeaconBlockHeader,
         beacon_state::{self as phase0, Fork},
@@ -11,12 +14,14 @@ use crate::{
         validator::Validator,
         JUSTIFICATION_BITS_LENGTH,
     },
-    primitives::{Bytes32, Gwei, ParticipationFlags, Root, Slot, ValidatorIndex, WithdrawalIndex},
+    primitives::{
+        Bytes32, Epoch, Gwei, ParticipationFlags, Root, Slot, ValidatorIndex, WithdrawalIndex,
+    },
     ssz::prelude::*,
     types::execution_payload_header::{ExecutionPayloadHeaderRef, ExecutionPayloadHeaderRefMut},
     Fork as Version,
 };
-#[derive(Debug, Clone, PartialEq, Eq, Merkleized, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Merkleized, serde::Serialize)]
 #[ssz(transparent)]
 #[serde(untagged)]
 pub enum BeaconState<
@@ -31,6 +36,9 @@ pub enum BeaconState<
     const SYNC_COMMITTEE_SIZE: usize,
     const BYTES_PER_LOGS_BLOOM: usize,
     const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
 > {
     Phase0(
         phase0::BeaconState<
@@ -98,6 +106,23 @@ pub enum BeaconState<
             MAX_EXTRA_DATA_BYTES,
         >,
     ),
+    Electra(
+        electra::BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
+        >,
+    ),
 }
 impl<
         const SLOTS_PER_HISTORICAL_ROOT: usize,
@@ -111,6 +136,9 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
     >
     BeaconState<
         SLOTS_PER_HISTORICAL_ROOT,
@@ -124,6 +152,9 @@ impl<
         SYNC_COMMITTEE_SIZE,
         BYTES_PER_LOGS_BLOOM,
         MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
     >
 {
     pub fn phase0(
@@ -328,6 +359,54 @@ impl<
             _ => None,
         }
     }
+    pub fn electra(
+        &self,
+    ) -> Option<
+        &electra::BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
+    pub fn electra_mut(
+        &mut self,
+    ) -> Option<
+        &mut electra::BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
     pub fn version(&self) -> Version {
         match self {
             Self::Phase0(_) => Version::Phase0,
@@ -335,6 +414,7 @@ impl<
             Self::Bellatrix(_) => Version::Bellatrix,
             Self::Capella(_) => Version::Capella,
             Self::Deneb(_) => Version::Deneb,
+            Self::Electra(_) => Version::Electra,
         }
     }
     pub fn genesis_time(&self) -> u64 {
@@ -344,6 +424,7 @@ impl<
             Self::Bellatrix(inner) => inner.genesis_time,
             Self::Capella(inner) => inner.genesis_time,
             Self::Deneb(inner) => inner.genesis_time,
+            Self::Electra(inner) => inner.genesis_time,
         }
     }
     pub fn genesis_validators_root(&self) -> Root {
@@ -353,6 +434,7 @@ impl<
             Self::Bellatrix(inner) => inner.genesis_validators_root,
             Self::Capella(inner) => inner.genesis_validators_root,
             Self::Deneb(inner) => inner.genesis_validators_root,
+            Self::Electra(inner) => inner.genesis_validators_root,
         }
     }
     pub fn slot(&self) -> Slot {
@@ -362,6 +444,7 @@ impl<
             Self::Bellatrix(inner) => inner.slot,
             Self::Capella(inner) => inner.slot,
             Self::Deneb(inner) => inner.slot,
+            Self::Electra(inner) => inner.slot,
         }
     }
     pub fn fork(&self) -> &Fork {
@@ -371,6 +454,7 @@ impl<
             Self::Bellatrix(inner) => &inner.fork,
             Self::Capella(inner) => &inner.fork,
             Self::Deneb(inner) => &inner.fork,
+            Self::Electra(inner) => &inner.fork,
         }
     }
     pub fn fork_mut(&mut self) -> &mut Fork {
@@ -380,6 +464,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.fork,
             Self::Capella(inner) => &mut inner.fork,
             Self::Deneb(inner) => &mut inner.fork,
+            Self::Electra(inner) => &mut inner.fork,
         }
     }
     pub fn latest_block_header(&self) -> &BeaconBlockHeader {
@@ -389,6 +474,7 @@ impl<
             Self::Bellatrix(inner) => &inner.latest_block_header,
             Self::Capella(inner) => &inner.latest_block_header,
             Self::Deneb(inner) => &inner.latest_block_header,
+            Self::Electra(inner) => &inner.latest_block_header,
         }
     }
     pub fn latest_block_header_mut(&mut self) -> &mut BeaconBlockHeader {
@@ -398,6 +484,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.latest_block_header,
             Self::Capella(inner) => &mut inner.latest_block_header,
             Self::Deneb(inner) => &mut inner.latest_block_header,
+            Self::Electra(inner) => &mut inner.latest_block_header,
         }
     }
     pub fn block_roots(&self) -> &Vector<Root, SLOTS_PER_HISTORICAL_ROOT> {
@@ -407,6 +494,7 @@ impl<
             Self::Bellatrix(inner) => &inner.block_roots,
             Self::Capella(inner) => &inner.block_roots,
             Self::Deneb(inner) => &inner.block_roots,
+            Self::Electra(inner) => &inner.block_roots,
         }
     }
     pub fn block_roots_mut(&mut self) -> &mut Vector<Root, SLOTS_PER_HISTORICAL_ROOT> {
@@ -416,6 +504,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.block_roots,
             Self::Capella(inner) => &mut inner.block_roots,
             Self::Deneb(inner) => &mut inner.block_roots,
+            Self::Electra(inner) => &mut inner.block_roots,
         }
     }
     pub fn state_roots(&self) -> &Vector<Root, SLOTS_PER_HISTORICAL_ROOT> {
@@ -425,6 +514,7 @@ impl<
             Self::Bellatrix(inner) => &inner.state_roots,
             Self::Capella(inner) => &inner.state_roots,
             Self::Deneb(inner) => &inner.state_roots,
+            Self::Electra(inner) => &inner.state_roots,
         }
     }
     pub fn state_roots_mut(&mut self) -> &mut Vector<Root, SLOTS_PER_HISTORICAL_ROOT> {
@@ -434,6 +524,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.state_roots,
             Self::Capella(inner) => &mut inner.state_roots,
             Self::Deneb(inner) => &mut inner.state_roots,
+            Self::Electra(inner) => &mut inner.state_roots,
         }
     }
     pub fn historical_roots(&self) -> &List<Root, HISTORICAL_ROOTS_LIMIT> {
@@ -443,6 +534,7 @@ impl<
             Self::Bellatrix(inner) => &inner.historical_roots,
             Self::Capella(inner) => &inner.historical_roots,
             Self::Deneb(inner) => &inner.historical_roots,
+            Self::Electra(inner) => &inner.historical_roots,
         }
     }
     pub fn historical_roots_mut(&mut self) -> &mut List<Root, HISTORICAL_ROOTS_LIMIT> {
@@ -452,6 +544,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.historical_roots,
             Self::Capella(inner) => &mut inner.historical_roots,
             Self::Deneb(inner) => &mut inner.historical_roots,
+            Self::Electra(inner) => &mut inner.historical_roots,
         }
     }
     pub fn eth1_data(&self) -> &Eth1Data {
@@ -461,6 +554,7 @@ impl<
             Self::Bellatrix(inner) => &inner.eth1_data,
             Self::Capella(inner) => &inner.eth1_data,
             Self::Deneb(inner) => &inner.eth1_data,
+            Self::Electra(inner) => &inner.eth1_data,
         }
     }
     pub fn eth1_data_mut(&mut self) -> &mut Eth1Data {
@@ -470,6 +564,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.eth1_data,
             Self::Capella(inner) => &mut inner.eth1_data,
             Self::Deneb(inner) => &mut inner.eth1_data,
+            Self::Electra(inner) => &mut inner.eth1_data,
         }
     }
     pub fn eth1_data_votes(&self) -> &List<Eth1Data, ETH1_DATA_VOTES_BOUND> {
@@ -479,6 +574,7 @@ impl<
             Self::Bellatrix(inner) => &inner.eth1_data_votes,
             Self::Capella(inner) => &inner.eth1_data_votes,
             Self::Deneb(inner) => &inner.eth1_data_votes,
+            Self::Electra(inner) => &inner.eth1_data_votes,
         }
     }
     pub fn eth1_data_votes_mut(&mut self) -> &mut List<Eth1Data, ETH1_DATA_VOTES_BOUND> {
@@ -488,6 +584,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.eth1_data_votes,
             Self::Capella(inner) => &mut inner.eth1_data_votes,
             Self::Deneb(inner) => &mut inner.eth1_data_votes,
+            Self::Electra(inner) => &mut inner.eth1_data_votes,
         }
     }
     pub fn eth1_deposit_index(&self) -> u64 {
@@ -497,6 +594,7 @@ impl<
             Self::Bellatrix(inner) => inner.eth1_deposit_index,
             Self::Capella(inner) => inner.eth1_deposit_index,
             Self::Deneb(inner) => inner.eth1_deposit_index,
+            Self::Electra(inner) => inner.eth1_deposit_index,
         }
     }
     pub fn validators(&self) -> &List<Validator, VALIDATOR_REGISTRY_LIMIT> {
@@ -506,6 +604,7 @@ impl<
             Self::Bellatrix(inner) => &inner.validators,
             Self::Capella(inner) => &inner.validators,
             Self::Deneb(inner) => &inner.validators,
+            Self::Electra(inner) => &inner.validators,
         }
     }
     pub fn validators_mut(&mut self) -> &mut List<Validator, VALIDATOR_REGISTRY_LIMIT> {
@@ -515,6 +614,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.validators,
             Self::Capella(inner) => &mut inner.validators,
             Self::Deneb(inner) => &mut inner.validators,
+            Self::Electra(inner) => &mut inner.validators,
         }
     }
     pub fn balances(&self) -> &List<Gwei, VALIDATOR_REGISTRY_LIMIT> {
@@ -524,6 +624,7 @@ impl<
             Self::Bellatrix(inner) => &inner.balances,
             Self::Capella(inner) => &inner.balances,
             Self::Deneb(inner) => &inner.balances,
+            Self::Electra(inner) => &inner.balances,
         }
     }
     pub fn balances_mut(&mut self) -> &mut List<Gwei, VALIDATOR_REGISTRY_LIMIT> {
@@ -533,6 +634,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.balances,
             Self::Capella(inner) => &mut inner.balances,
             Self::Deneb(inner) => &mut inner.balances,
+            Self::Electra(inner) => &mut inner.balances,
         }
     }
     pub fn randao_mixes(&self) -> &Vector<Bytes32, EPOCHS_PER_HISTORICAL_VECTOR> {
@@ -542,6 +644,7 @@ impl<
             Self::Bellatrix(inner) => &inner.randao_mixes,
             Self::Capella(inner) => &inner.randao_mixes,
             Self::Deneb(inner) => &inner.randao_mixes,
+            Self::Electra(inner) => &inner.randao_mixes,
         }
     }
     pub fn randao_mixes_mut(&mut self) -> &mut Vector<Bytes32, EPOCHS_PER_HISTORICAL_VECTOR> {
@@ -551,6 +654,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.randao_mixes,
             Self::Capella(inner) => &mut inner.randao_mixes,
             Self::Deneb(inner) => &mut inner.randao_mixes,
+            Self::Electra(inner) => &mut inner.randao_mixes,
         }
     }
     pub fn slashings(&self) -> &Vector<Gwei, EPOCHS_PER_SLASHINGS_VECTOR> {
@@ -560,6 +664,7 @@ impl<
             Self::Bellatrix(inner) => &inner.slashings,
             Self::Capella(inner) => &inner.slashings,
             Self::Deneb(inner) => &inner.slashings,
+            Self::Electra(inner) => &inner.slashings,
         }
     }
     pub fn slashings_mut(&mut self) -> &mut Vector<Gwei, EPOCHS_PER_SLASHINGS_VECTOR> {
@@ -569,6 +674,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.slashings,
             Self::Capella(inner) => &mut inner.slashings,
             Self::Deneb(inner) => &mut inner.slashings,
+            Self::Electra(inner) => &mut inner.slashings,
         }
     }
     pub fn previous_epoch_attestations(
@@ -581,6 +687,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(_) => None,
+            Self::Electra(_) => None,
         }
     }
     pub fn previous_epoch_attestations_mut(
@@ -594,6 +701,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(_) => None,
+            Self::Electra(_) => None,
         }
     }
     pub fn current_epoch_attestations(
@@ -606,6 +714,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(_) => None,
+            Self::Electra(_) => None,
         }
     }
     pub fn current_epoch_attestations_mut(
@@ -619,6 +728,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(_) => None,
+            Self::Electra(_) => None,
         }
     }
     pub fn justification_bits(&self) -> &Bitvector<JUSTIFICATION_BITS_LENGTH> {
@@ -628,6 +738,7 @@ impl<
             Self::Bellatrix(inner) => &inner.justification_bits,
             Self::Capella(inner) => &inner.justification_bits,
             Self::Deneb(inner) => &inner.justification_bits,
+            Self::Electra(inner) => &inner.justification_bits,
         }
     }
     pub fn justification_bits_mut(&mut self) -> &mut Bitvector<JUSTIFICATION_BITS_LENGTH> {
@@ -637,6 +748,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.justification_bits,
             Self::Capella(inner) => &mut inner.justification_bits,
             Self::Deneb(inner) => &mut inner.justification_bits,
+            Self::Electra(inner) => &mut inner.justification_bits,
         }
     }
     pub fn previous_justified_checkpoint(&self) -> &Checkpoint {
@@ -646,6 +758,7 @@ impl<
             Self::Bellatrix(inner) => &inner.previous_justified_checkpoint,
             Self::Capella(inner) => &inner.previous_justified_checkpoint,
             Self::Deneb(inner) => &inner.previous_justified_checkpoint,
+            Self::Electra(inner) => &inner.previous_justified_checkpoint,
         }
     }
     pub fn previous_justified_checkpoint_mut(&mut self) -> &mut Checkpoint {
@@ -655,6 +768,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.previous_justified_checkpoint,
             Self::Capella(inner) => &mut inner.previous_justified_checkpoint,
             Self::Deneb(inner) => &mut inner.previous_justified_checkpoint,
+            Self::Electra(inner) => &mut inner.previous_justified_checkpoint,
         }
     }
     pub fn current_justified_checkpoint(&self) -> &Checkpoint {
@@ -664,6 +778,7 @@ impl<
             Self::Bellatrix(inner) => &inner.current_justified_checkpoint,
             Self::Capella(inner) => &inner.current_justified_checkpoint,
             Self::Deneb(inner) => &inner.current_justified_checkpoint,
+            Self::Electra(inner) => &inner.current_justified_checkpoint,
         }
     }
     pub fn current_justified_checkpoint_mut(&mut self) -> &mut Checkpoint {
@@ -673,6 +788,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.current_justified_checkpoint,
             Self::Capella(inner) => &mut inner.current_justified_checkpoint,
             Self::Deneb(inner) => &mut inner.current_justified_checkpoint,
+            Self::Electra(inner) => &mut inner.current_justified_checkpoint,
         }
     }
     pub fn finalized_checkpoint(&self) -> &Checkpoint {
@@ -682,6 +798,7 @@ impl<
             Self::Bellatrix(inner) => &inner.finalized_checkpoint,
             Self::Capella(inner) => &inner.finalized_checkpoint,
             Self::Deneb(inner) => &inner.finalized_checkpoint,
+            Self::Electra(inner) => &inner.finalized_checkpoint,
         }
     }
     pub fn finalized_checkpoint_mut(&mut self) -> &mut Checkpoint {
@@ -691,6 +808,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.finalized_checkpoint,
             Self::Capella(inner) => &mut inner.finalized_checkpoint,
             Self::Deneb(inner) => &mut inner.finalized_checkpoint,
+            Self::Electra(inner) => &mut inner.finalized_checkpoint,
         }
     }
     pub fn previous_epoch_participation(
@@ -702,6 +820,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.previous_epoch_participation),
             Self::Capella(inner) => Some(&inner.previous_epoch_participation),
             Self::Deneb(inner) => Some(&inner.previous_epoch_participation),
+            Self::Electra(inner) => Some(&inner.previous_epoch_participation),
         }
     }
     pub fn previous_epoch_participation_mut(
@@ -713,6 +832,7 @@ impl<
             Self::Bellatrix(inner) => Some(&mut inner.previous_epoch_participation),
             Self::Capella(inner) => Some(&mut inner.previous_epoch_participation),
             Self::Deneb(inner) => Some(&mut inner.previous_epoch_participation),
+            Self::Electra(inner) => Some(&mut inner.previous_epoch_participation),
         }
     }
     pub fn current_epoch_participation(
@@ -724,6 +844,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.current_epoch_participation),
             Self::Capella(inner) => Some(&inner.current_epoch_participation),
             Self::Deneb(inner) => Some(&inner.current_epoch_participation),
+            Self::Electra(inner) => Some(&inner.current_epoch_participation),
         }
     }
     pub fn current_epoch_participation_mut(
@@ -735,6 +856,7 @@ impl<
             Self::Bellatrix(inner) => Some(&mut inner.current_epoch_participation),
             Self::Capella(inner) => Some(&mut inner.current_epoch_participation),
             Self::Deneb(inner) => Some(&mut inner.current_epoch_participation),
+            Self::Electra(inner) => Some(&mut inner.current_epoch_participation),
         }
     }
     pub fn inactivity_scores(&self) -> Option<&List<u64, VALIDATOR_REGISTRY_LIMIT>> {
@@ -744,6 +866,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.inactivity_scores),
             Self::Capella(inner) => Some(&inner.inactivity_scores),
             Self::Deneb(inner) => Some(&inner.inactivity_scores),
+            Self::Electra(inner) => Some(&inner.inactivity_scores),
         }
     }
     pub fn inactivity_scores_mut(&mut self) -> Option<&mut List<u64, VALIDATOR_REGISTRY_LIMIT>> {
@@ -753,6 +876,7 @@ impl<
             Self::Bellatrix(inner) => Some(&mut inner.inactivity_scores),
             Self::Capella(inner) => Some(&mut inner.inactivity_scores),
             Self::Deneb(inner) => Some(&mut inner.inactivity_scores),
+            Self::Electra(inner) => Some(&mut inner.inactivity_scores),
         }
     }
     pub fn current_sync_committee(&self) -> Option<&SyncCommittee<SYNC_COMMITTEE_SIZE>> {
@@ -762,6 +886,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.current_sync_committee),
             Self::Capella(inner) => Some(&inner.current_sync_committee),
             Self::Deneb(inner) => Some(&inner.current_sync_committee),
+            Self::Electra(inner) => Some(&inner.current_sync_committee),
         }
     }
     pub fn current_sync_committee_mut(
@@ -773,6 +898,7 @@ impl<
             Self::Bellatrix(inner) => Some(&mut inner.current_sync_committee),
             Self::Capella(inner) => Some(&mut inner.current_sync_committee),
             Self::Deneb(inner) => Some(&mut inner.current_sync_committee),
+            Self::Electra(inner) => Some(&mut inner.current_sync_committee),
         }
     }
     pub fn next_sync_committee(&self) -> Option<&SyncCommittee<SYNC_COMMITTEE_SIZE>> {
@@ -782,6 +908,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.next_sync_committee),
             Self::Capella(inner) => Some(&inner.next_sync_committee),
             Self::Deneb(inner) => Some(&inner.next_sync_committee),
+            Self::Electra(inner) => Some(&inner.next_sync_committee),
         }
     }
     pub fn next_sync_committee_mut(&mut self) -> Option<&mut SyncCommittee<SYNC_COMMITTEE_SIZE>> {
@@ -791,6 +918,7 @@ impl<
             Self::Bellatrix(inner) => Some(&mut inner.next_sync_committee),
             Self::Capella(inner) => Some(&mut inner.next_sync_committee),
             Self::Deneb(inner) => Some(&mut inner.next_sync_committee),
+            Self::Electra(inner) => Some(&mut inner.next_sync_committee),
         }
     }
     pub fn latest_execution_payload_header(
@@ -802,6 +930,7 @@ impl<
             Self::Bellatrix(inner) => Some(From::from(&inner.latest_execution_payload_header)),
             Self::Capella(inner) => Some(From::from(&inner.latest_execution_payload_header)),
             Self::Deneb(inner) => Some(From::from(&inner.latest_execution_payload_header)),
+            Self::Electra(inner) => Some(From::from(&inner.latest_execution_payload_header)),
         }
     }
     pub fn latest_execution_payload_header_mut(
@@ -813,6 +942,7 @@ impl<
             Self::Bellatrix(inner) => Some(From::from(&mut inner.latest_execution_payload_header)),
             Self::Capella(inner) => Some(From::from(&mut inner.latest_execution_payload_header)),
             Self::Deneb(inner) => Some(From::from(&mut inner.latest_execution_payload_header)),
+            Self::Electra(inner) => Some(From::from(&mut inner.latest_execution_payload_header)),
         }
     }
     pub fn next_withdrawal_index(&self) -> Option<WithdrawalIndex> {
@@ -822,6 +952,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(inner.next_withdrawal_index),
             Self::Deneb(inner) => Some(inner.next_withdrawal_index),
+            Self::Electra(inner) => Some(inner.next_withdrawal_index),
         }
     }
     pub fn next_withdrawal_validator_index(&self) -> Option<ValidatorIndex> {
@@ -831,6 +962,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(inner.next_withdrawal_validator_index),
             Self::Deneb(inner) => Some(inner.next_withdrawal_validator_index),
+            Self::Electra(inner) => Some(inner.next_withdrawal_validator_index),
         }
     }
     pub fn historical_summaries(&self) -> Option<&List<HistoricalSummary, HISTORICAL_ROOTS_LIMIT>> {
@@ -840,6 +972,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&inner.historical_summaries),
             Self::Deneb(inner) => Some(&inner.historical_summaries),
+            Self::Electra(inner) => Some(&inner.historical_summaries),
         }
     }
     pub fn historical_summaries_mut(
@@ -851,6 +984,137 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&mut inner.historical_summaries),
             Self::Deneb(inner) => Some(&mut inner.historical_summaries),
+            Self::Electra(inner) => Some(&mut inner.historical_summaries),
+        }
+    }
+    pub fn deposit_requests_start_index(&self) -> Option<u64> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.deposit_requests_start_index),
+        }
+    }
+    pub fn deposit_balance_to_consume(&self) -> Option<Gwei> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.deposit_balance_to_consume),
+        }
+    }
+    pub fn exit_balance_to_consume(&self) -> Option<Gwei> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.exit_balance_to_consume),
+        }
+    }
+    pub fn earliest_exit_epoch(&self) -> Option<Epoch> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.earliest_exit_epoch),
+        }
+    }
+    pub fn consolidation_balance_to_consume(&self) -> Option<Gwei> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.consolidation_balance_to_consume),
+        }
+    }
+    pub fn earliest_consolidation_epoch(&self) -> Option<Epoch> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.earliest_consolidation_epoch),
+        }
+    }
+    pub fn pending_deposits(&self) -> Option<&List<PendingDeposit, PENDING_DEPOSITS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.pending_deposits),
+        }
+    }
+    pub fn pending_deposits_mut(
+        &mut self,
+    ) -> Option<&mut List<PendingDeposit, PENDING_DEPOSITS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.pending_deposits),
+        }
+    }
+    pub fn pending_partial_withdrawals(
+        &self,
+    ) -> Option<&List<PendingPartialWithdrawal, PENDING_PARTIAL_WITHDRAWALS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.pending_partial_withdrawals),
+        }
+    }
+    pub fn pending_partial_withdrawals_mut(
+        &mut self,
+    ) -> Option<&mut List<PendingPartialWithdrawal, PENDING_PARTIAL_WITHDRAWALS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.pending_partial_withdrawals),
+        }
+    }
+    pub fn pending_consolidations(
+        &self,
+    ) -> Option<&List<PendingConsolidation, PENDING_CONSOLIDATIONS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.pending_consolidations),
+        }
+    }
+    pub fn pending_consolidations_mut(
+        &mut self,
+    ) -> Option<&mut List<PendingConsolidation, PENDING_CONSOLIDATIONS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.pending_consolidations),
         }
     }
 }
@@ -867,6 +1131,9 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
     > serde::Deserialize<'de>
     for BeaconState<
         SLOTS_PER_HISTORICAL_ROOT,
@@ -880,6 +1147,9 @@ impl<
         SYNC_COMMITTEE_SIZE,
         BYTES_PER_LOGS_BLOOM,
         MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
     >
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -887,6 +1157,9 @@ impl<
         D: serde::Deserializer<'de>,
     {
         let value = serde_json::Value::deserialize(deserializer)?;
+        if let Ok(inner) = <_ as serde::Deserialize>::deserialize(&value) {
+            return Ok(Self::Electra(inner))
+        }
         if let Ok(inner) = <_ as serde::Deserialize>::deserialize(&value) {
             return Ok(Self::Deneb(inner))
         }
@@ -905,7 +1178,7 @@ impl<
         Err(serde::de::Error::custom("no variant could be deserialized from input"))
     }
 }
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum BeaconStateRef<
     'a,
     const SLOTS_PER_HISTORICAL_ROOT: usize,
@@ -919,6 +1192,9 @@ pub enum BeaconStateRef<
     const SYNC_COMMITTEE_SIZE: usize,
     const BYTES_PER_LOGS_BLOOM: usize,
     const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
 > {
     Phase0(
         &'a phase0::BeaconState<
@@ -986,6 +1262,23 @@ pub enum BeaconStateRef<
             MAX_EXTRA_DATA_BYTES,
         >,
     ),
+    Electra(
+        &'a electra::BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
+        >,
+    ),
 }
 impl<
         'a,
@@ -1000,6 +1293,9 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
     >
     BeaconStateRef<
         'a,
@@ -1014,6 +1310,9 @@ impl<
         SYNC_COMMITTEE_SIZE,
         BYTES_PER_LOGS_BLOOM,
         MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
     >
 {
     pub fn phase0(
@@ -1117,6 +1416,30 @@ impl<
             _ => None,
         }
     }
+    pub fn electra(
+        &self,
+    ) -> Option<
+        &electra::BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
     pub fn version(&self) -> Version {
         match self {
             Self::Phase0(_) => Version::Phase0,
@@ -1124,6 +1447,7 @@ impl<
             Self::Bellatrix(_) => Version::Bellatrix,
             Self::Capella(_) => Version::Capella,
             Self::Deneb(_) => Version::Deneb,
+            Self::Electra(_) => Version::Electra,
         }
     }
     pub fn genesis_time(&self) -> u64 {
@@ -1133,6 +1457,7 @@ impl<
             Self::Bellatrix(inner) => inner.genesis_time,
             Self::Capella(inner) => inner.genesis_time,
             Self::Deneb(inner) => inner.genesis_time,
+            Self::Electra(inner) => inner.genesis_time,
         }
     }
     pub fn genesis_validators_root(&self) -> Root {
@@ -1142,6 +1467,7 @@ impl<
             Self::Bellatrix(inner) => inner.genesis_validators_root,
             Self::Capella(inner) => inner.genesis_validators_root,
             Self::Deneb(inner) => inner.genesis_validators_root,
+            Self::Electra(inner) => inner.genesis_validators_root,
         }
     }
     pub fn slot(&self) -> Slot {
@@ -1151,6 +1477,7 @@ impl<
             Self::Bellatrix(inner) => inner.slot,
             Self::Capella(inner) => inner.slot,
             Self::Deneb(inner) => inner.slot,
+            Self::Electra(inner) => inner.slot,
         }
     }
     pub fn fork(&self) -> &Fork {
@@ -1160,6 +1487,7 @@ impl<
             Self::Bellatrix(inner) => &inner.fork,
             Self::Capella(inner) => &inner.fork,
             Self::Deneb(inner) => &inner.fork,
+            Self::Electra(inner) => &inner.fork,
         }
     }
     pub fn latest_block_header(&self) -> &BeaconBlockHeader {
@@ -1169,6 +1497,7 @@ impl<
             Self::Bellatrix(inner) => &inner.latest_block_header,
             Self::Capella(inner) => &inner.latest_block_header,
             Self::Deneb(inner) => &inner.latest_block_header,
+            Self::Electra(inner) => &inner.latest_block_header,
         }
     }
     pub fn block_roots(&self) -> &Vector<Root, SLOTS_PER_HISTORICAL_ROOT> {
@@ -1178,6 +1507,7 @@ impl<
             Self::Bellatrix(inner) => &inner.block_roots,
             Self::Capella(inner) => &inner.block_roots,
             Self::Deneb(inner) => &inner.block_roots,
+            Self::Electra(inner) => &inner.block_roots,
         }
     }
     pub fn state_roots(&self) -> &Vector<Root, SLOTS_PER_HISTORICAL_ROOT> {
@@ -1187,6 +1517,7 @@ impl<
             Self::Bellatrix(inner) => &inner.state_roots,
             Self::Capella(inner) => &inner.state_roots,
             Self::Deneb(inner) => &inner.state_roots,
+            Self::Electra(inner) => &inner.state_roots,
         }
     }
     pub fn historical_roots(&self) -> &List<Root, HISTORICAL_ROOTS_LIMIT> {
@@ -1196,6 +1527,7 @@ impl<
             Self::Bellatrix(inner) => &inner.historical_roots,
             Self::Capella(inner) => &inner.historical_roots,
             Self::Deneb(inner) => &inner.historical_roots,
+            Self::Electra(inner) => &inner.historical_roots,
         }
     }
     pub fn eth1_data(&self) -> &Eth1Data {
@@ -1205,6 +1537,7 @@ impl<
             Self::Bellatrix(inner) => &inner.eth1_data,
             Self::Capella(inner) => &inner.eth1_data,
             Self::Deneb(inner) => &inner.eth1_data,
+            Self::Electra(inner) => &inner.eth1_data,
         }
     }
     pub fn eth1_data_votes(&self) -> &List<Eth1Data, ETH1_DATA_VOTES_BOUND> {
@@ -1214,6 +1547,7 @@ impl<
             Self::Bellatrix(inner) => &inner.eth1_data_votes,
             Self::Capella(inner) => &inner.eth1_data_votes,
             Self::Deneb(inner) => &inner.eth1_data_votes,
+            Self::Electra(inner) => &inner.eth1_data_votes,
         }
     }
     pub fn eth1_deposit_index(&self) -> u64 {
@@ -1223,6 +1557,7 @@ impl<
             Self::Bellatrix(inner) => inner.eth1_deposit_index,
             Self::Capella(inner) => inner.eth1_deposit_index,
             Self::Deneb(inner) => inner.eth1_deposit_index,
+            Self::Electra(inner) => inner.eth1_deposit_index,
         }
     }
     pub fn validators(&self) -> &List<Validator, VALIDATOR_REGISTRY_LIMIT> {
@@ -1232,6 +1567,7 @@ impl<
             Self::Bellatrix(inner) => &inner.validators,
             Self::Capella(inner) => &inner.validators,
             Self::Deneb(inner) => &inner.validators,
+            Self::Electra(inner) => &inner.validators,
         }
     }
     pub fn balances(&self) -> &List<Gwei, VALIDATOR_REGISTRY_LIMIT> {
@@ -1241,6 +1577,7 @@ impl<
             Self::Bellatrix(inner) => &inner.balances,
             Self::Capella(inner) => &inner.balances,
             Self::Deneb(inner) => &inner.balances,
+            Self::Electra(inner) => &inner.balances,
         }
     }
     pub fn randao_mixes(&self) -> &Vector<Bytes32, EPOCHS_PER_HISTORICAL_VECTOR> {
@@ -1250,6 +1587,7 @@ impl<
             Self::Bellatrix(inner) => &inner.randao_mixes,
             Self::Capella(inner) => &inner.randao_mixes,
             Self::Deneb(inner) => &inner.randao_mixes,
+            Self::Electra(inner) => &inner.randao_mixes,
         }
     }
     pub fn slashings(&self) -> &Vector<Gwei, EPOCHS_PER_SLASHINGS_VECTOR> {
@@ -1259,6 +1597,7 @@ impl<
             Self::Bellatrix(inner) => &inner.slashings,
             Self::Capella(inner) => &inner.slashings,
             Self::Deneb(inner) => &inner.slashings,
+            Self::Electra(inner) => &inner.slashings,
         }
     }
     pub fn previous_epoch_attestations(
@@ -1271,6 +1610,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(_) => None,
+            Self::Electra(_) => None,
         }
     }
     pub fn current_epoch_attestations(
@@ -1283,6 +1623,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(_) => None,
+            Self::Electra(_) => None,
         }
     }
     pub fn justification_bits(&self) -> &Bitvector<JUSTIFICATION_BITS_LENGTH> {
@@ -1292,6 +1633,7 @@ impl<
             Self::Bellatrix(inner) => &inner.justification_bits,
             Self::Capella(inner) => &inner.justification_bits,
             Self::Deneb(inner) => &inner.justification_bits,
+            Self::Electra(inner) => &inner.justification_bits,
         }
     }
     pub fn previous_justified_checkpoint(&self) -> &Checkpoint {
@@ -1301,6 +1643,7 @@ impl<
             Self::Bellatrix(inner) => &inner.previous_justified_checkpoint,
             Self::Capella(inner) => &inner.previous_justified_checkpoint,
             Self::Deneb(inner) => &inner.previous_justified_checkpoint,
+            Self::Electra(inner) => &inner.previous_justified_checkpoint,
         }
     }
     pub fn current_justified_checkpoint(&self) -> &Checkpoint {
@@ -1310,6 +1653,7 @@ impl<
             Self::Bellatrix(inner) => &inner.current_justified_checkpoint,
             Self::Capella(inner) => &inner.current_justified_checkpoint,
             Self::Deneb(inner) => &inner.current_justified_checkpoint,
+            Self::Electra(inner) => &inner.current_justified_checkpoint,
         }
     }
     pub fn finalized_checkpoint(&self) -> &Checkpoint {
@@ -1319,6 +1663,7 @@ impl<
             Self::Bellatrix(inner) => &inner.finalized_checkpoint,
             Self::Capella(inner) => &inner.finalized_checkpoint,
             Self::Deneb(inner) => &inner.finalized_checkpoint,
+            Self::Electra(inner) => &inner.finalized_checkpoint,
         }
     }
     pub fn previous_epoch_participation(
@@ -1330,6 +1675,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.previous_epoch_participation),
             Self::Capella(inner) => Some(&inner.previous_epoch_participation),
             Self::Deneb(inner) => Some(&inner.previous_epoch_participation),
+            Self::Electra(inner) => Some(&inner.previous_epoch_participation),
         }
     }
     pub fn current_epoch_participation(
@@ -1341,6 +1687,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.current_epoch_participation),
             Self::Capella(inner) => Some(&inner.current_epoch_participation),
             Self::Deneb(inner) => Some(&inner.current_epoch_participation),
+            Self::Electra(inner) => Some(&inner.current_epoch_participation),
         }
     }
     pub fn inactivity_scores(&self) -> Option<&List<u64, VALIDATOR_REGISTRY_LIMIT>> {
@@ -1350,6 +1697,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.inactivity_scores),
             Self::Capella(inner) => Some(&inner.inactivity_scores),
             Self::Deneb(inner) => Some(&inner.inactivity_scores),
+            Self::Electra(inner) => Some(&inner.inactivity_scores),
         }
     }
     pub fn current_sync_committee(&self) -> Option<&SyncCommittee<SYNC_COMMITTEE_SIZE>> {
@@ -1359,6 +1707,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.current_sync_committee),
             Self::Capella(inner) => Some(&inner.current_sync_committee),
             Self::Deneb(inner) => Some(&inner.current_sync_committee),
+            Self::Electra(inner) => Some(&inner.current_sync_committee),
         }
     }
     pub fn next_sync_committee(&self) -> Option<&SyncCommittee<SYNC_COMMITTEE_SIZE>> {
@@ -1368,6 +1717,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.next_sync_committee),
             Self::Capella(inner) => Some(&inner.next_sync_committee),
             Self::Deneb(inner) => Some(&inner.next_sync_committee),
+            Self::Electra(inner) => Some(&inner.next_sync_committee),
         }
     }
     pub fn latest_execution_payload_header(
@@ -1379,6 +1729,7 @@ impl<
             Self::Bellatrix(inner) => Some(From::from(&inner.latest_execution_payload_header)),
             Self::Capella(inner) => Some(From::from(&inner.latest_execution_payload_header)),
             Self::Deneb(inner) => Some(From::from(&inner.latest_execution_payload_header)),
+            Self::Electra(inner) => Some(From::from(&inner.latest_execution_payload_header)),
         }
     }
     pub fn next_withdrawal_index(&self) -> Option<WithdrawalIndex> {
@@ -1388,6 +1739,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(inner.next_withdrawal_index),
             Self::Deneb(inner) => Some(inner.next_withdrawal_index),
+            Self::Electra(inner) => Some(inner.next_withdrawal_index),
         }
     }
     pub fn next_withdrawal_validator_index(&self) -> Option<ValidatorIndex> {
@@ -1397,6 +1749,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(inner.next_withdrawal_validator_index),
             Self::Deneb(inner) => Some(inner.next_withdrawal_validator_index),
+            Self::Electra(inner) => Some(inner.next_withdrawal_validator_index),
         }
     }
     pub fn historical_summaries(&self) -> Option<&List<HistoricalSummary, HISTORICAL_ROOTS_LIMIT>> {
@@ -1406,8 +1759,165 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&inner.historical_summaries),
             Self::Deneb(inner) => Some(&inner.historical_summaries),
+            Self::Electra(inner) => Some(&inner.historical_summaries),
         }
     }
+    pub fn deposit_requests_start_index(&self) -> Option<u64> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.deposit_requests_start_index),
+        }
+    }
+    pub fn deposit_balance_to_consume(&self) -> Option<Gwei> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.deposit_balance_to_consume),
+        }
+    }
+    pub fn exit_balance_to_consume(&self) -> Option<Gwei> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.exit_balance_to_consume),
+        }
+    }
+    pub fn earliest_exit_epoch(&self) -> Option<Epoch> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.earliest_exit_epoch),
+        }
+    }
+    pub fn consolidation_balance_to_consume(&self) -> Option<Gwei> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.consolidation_balance_to_consume),
+        }
+    }
+    pub fn earliest_consolidation_epoch(&self) -> Option<Epoch> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.earliest_consolidation_epoch),
+        }
+    }
+    pub fn pending_deposits(&self) -> Option<&List<PendingDeposit, PENDING_DEPOSITS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.pending_deposits),
+        }
+    }
+    pub fn pending_partial_withdrawals(
+        &self,
+    ) -> Option<&List<PendingPartialWithdrawal, PENDING_PARTIAL_WITHDRAWALS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.pending_partial_withdrawals),
+        }
+    }
+    pub fn pending_consolidations(
+        &self,
+    ) -> Option<&List<PendingConsolidation, PENDING_CONSOLIDATIONS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.pending_consolidations),
+        }
+    }
+}
+impl<
+        'a,
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const PENDING_ATTESTATIONS_BOUND: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    >
+    From<
+        &'a phase0::BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            PENDING_ATTESTATIONS_BOUND,
+        >,
+    >
+    for BeaconStateRef<
+        'a,
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >
+{
+    fn from(
+        value: &'a phase0::BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            PENDING_ATTESTATIONS_BOUND,
+        >,
+    ) -> Self {
+        Self::Phase0(value)
+    }
 }
 impl<
         'a,
@@ -1422,9 +1932,12 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
     >
     From<
-        &'a phase0::BeaconState<
+        &'a altair::BeaconState<
             SLOTS_PER_HISTORICAL_ROOT,
             HISTORICAL_ROOTS_LIMIT,
             ETH1_DATA_VOTES_BOUND,
@@ -1432,7 +1945,7 @@ impl<
             EPOCHS_PER_HISTORICAL_VECTOR,
             EPOCHS_PER_SLASHINGS_VECTOR,
             MAX_VALIDATORS_PER_COMMITTEE,
-            PENDING_ATTESTATIONS_BOUND,
+            SYNC_COMMITTEE_SIZE,
         >,
     >
     for BeaconStateRef<
@@ -1448,10 +1961,13 @@ impl<
         SYNC_COMMITTEE_SIZE,
         BYTES_PER_LOGS_BLOOM,
         MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
     >
 {
     fn from(
-        value: &'a phase0::BeaconState<
+        value: &'a altair::BeaconState<
             SLOTS_PER_HISTORICAL_ROOT,
             HISTORICAL_ROOTS_LIMIT,
             ETH1_DATA_VOTES_BOUND,
@@ -1459,10 +1975,10 @@ impl<
             EPOCHS_PER_HISTORICAL_VECTOR,
             EPOCHS_PER_SLASHINGS_VECTOR,
             MAX_VALIDATORS_PER_COMMITTEE,
-            PENDING_ATTESTATIONS_BOUND,
+            SYNC_COMMITTEE_SIZE,
         >,
     ) -> Self {
-        Self::Phase0(value)
+        Self::Altair(value)
     }
 }
 impl<
@@ -1478,9 +1994,12 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
     >
     From<
-        &'a altair::BeaconState<
+        &'a bellatrix::BeaconState<
             SLOTS_PER_HISTORICAL_ROOT,
             HISTORICAL_ROOTS_LIMIT,
             ETH1_DATA_VOTES_BOUND,
@@ -1489,6 +2008,8 @@ impl<
             EPOCHS_PER_SLASHINGS_VECTOR,
             MAX_VALIDATORS_PER_COMMITTEE,
             SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
         >,
     >
     for BeaconStateRef<
@@ -1504,10 +2025,13 @@ impl<
         SYNC_COMMITTEE_SIZE,
         BYTES_PER_LOGS_BLOOM,
         MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
     >
 {
     fn from(
-        value: &'a altair::BeaconState<
+        value: &'a bellatrix::BeaconState<
             SLOTS_PER_HISTORICAL_ROOT,
             HISTORICAL_ROOTS_LIMIT,
             ETH1_DATA_VOTES_BOUND,
@@ -1516,9 +2040,11 @@ impl<
             EPOCHS_PER_SLASHINGS_VECTOR,
             MAX_VALIDATORS_PER_COMMITTEE,
             SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
         >,
     ) -> Self {
-        Self::Altair(value)
+        Self::Bellatrix(value)
     }
 }
 impl<
@@ -1534,9 +2060,12 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
     >
     From<
-        &'a bellatrix::BeaconState<
+        &'a capella::BeaconState<
             SLOTS_PER_HISTORICAL_ROOT,
             HISTORICAL_ROOTS_LIMIT,
             ETH1_DATA_VOTES_BOUND,
@@ -1562,10 +2091,13 @@ impl<
         SYNC_COMMITTEE_SIZE,
         BYTES_PER_LOGS_BLOOM,
         MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
     >
 {
     fn from(
-        value: &'a bellatrix::BeaconState<
+        value: &'a capella::BeaconState<
             SLOTS_PER_HISTORICAL_ROOT,
             HISTORICAL_ROOTS_LIMIT,
             ETH1_DATA_VOTES_BOUND,
@@ -1578,7 +2110,7 @@ impl<
             MAX_EXTRA_DATA_BYTES,
         >,
     ) -> Self {
-        Self::Bellatrix(value)
+        Self::Capella(value)
     }
 }
 impl<
@@ -1594,9 +2126,12 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
     >
     From<
-        &'a capella::BeaconState<
+        &'a deneb::BeaconState<
             SLOTS_PER_HISTORICAL_ROOT,
             HISTORICAL_ROOTS_LIMIT,
             ETH1_DATA_VOTES_BOUND,
@@ -1622,10 +2157,13 @@ impl<
         SYNC_COMMITTEE_SIZE,
         BYTES_PER_LOGS_BLOOM,
         MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
     >
 {
     fn from(
-        value: &'a capella::BeaconState<
+        value: &'a deneb::BeaconState<
             SLOTS_PER_HISTORICAL_ROOT,
             HISTORICAL_ROOTS_LIMIT,
             ETH1_DATA_VOTES_BOUND,
@@ -1638,7 +2176,7 @@ impl<
             MAX_EXTRA_DATA_BYTES,
         >,
     ) -> Self {
-        Self::Capella(value)
+        Self::Deneb(value)
     }
 }
 impl<
@@ -1654,9 +2192,12 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
     >
     From<
-        &'a deneb::BeaconState<
+        &'a electra::BeaconState<
             SLOTS_PER_HISTORICAL_ROOT,
             HISTORICAL_ROOTS_LIMIT,
             ETH1_DATA_VOTES_BOUND,
@@ -1667,6 +2208,9 @@ impl<
             SYNC_COMMITTEE_SIZE,
             BYTES_PER_LOGS_BLOOM,
             MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
         >,
     >
     for BeaconStateRef<
@@ -1682,10 +2226,13 @@ impl<
         SYNC_COMMITTEE_SIZE,
         BYTES_PER_LOGS_BLOOM,
         MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
     >
 {
     fn from(
-        value: &'a deneb::BeaconState<
+        value: &'a electra::BeaconState<
             SLOTS_PER_HISTORICAL_ROOT,
             HISTORICAL_ROOTS_LIMIT,
             ETH1_DATA_VOTES_BOUND,
@@ -1696,12 +2243,15 @@ impl<
             SYNC_COMMITTEE_SIZE,
             BYTES_PER_LOGS_BLOOM,
             MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
         >,
     ) -> Self {
-        Self::Deneb(value)
+        Self::Electra(value)
     }
 }
-#[derive(Debug, PartialEq, Eq, Merkleized)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Merkleized)]
 #[ssz(transparent)]
 pub enum BeaconStateRefMut<
     'a,
@@ -1716,6 +2266,9 @@ pub enum BeaconStateRefMut<
     const SYNC_COMMITTEE_SIZE: usize,
     const BYTES_PER_LOGS_BLOOM: usize,
     const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
 > {
     Phase0(
         &'a mut phase0::BeaconState<
@@ -1783,6 +2336,23 @@ pub enum BeaconStateRefMut<
             MAX_EXTRA_DATA_BYTES,
         >,
     ),
+    Electra(
+        &'a mut electra::BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
+        >,
+    ),
 }
 impl<
         'a,
@@ -1797,6 +2367,9 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
     >
     BeaconStateRefMut<
         'a,
@@ -1811,6 +2384,9 @@ impl<
         SYNC_COMMITTEE_SIZE,
         BYTES_PER_LOGS_BLOOM,
         MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
     >
 {
     pub fn phase0(
@@ -2015,6 +2591,54 @@ impl<
             _ => None,
         }
     }
+    pub fn electra(
+        &self,
+    ) -> Option<
+        &electra::BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
+    pub fn electra_mut(
+        &mut self,
+    ) -> Option<
+        &mut electra::BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
     pub fn version(&self) -> Version {
         match self {
             Self::Phase0(_) => Version::Phase0,
@@ -2022,6 +2646,7 @@ impl<
             Self::Bellatrix(_) => Version::Bellatrix,
             Self::Capella(_) => Version::Capella,
             Self::Deneb(_) => Version::Deneb,
+            Self::Electra(_) => Version::Electra,
         }
     }
     pub fn genesis_time(&self) -> u64 {
@@ -2031,6 +2656,7 @@ impl<
             Self::Bellatrix(inner) => inner.genesis_time,
             Self::Capella(inner) => inner.genesis_time,
             Self::Deneb(inner) => inner.genesis_time,
+            Self::Electra(inner) => inner.genesis_time,
         }
     }
     pub fn genesis_validators_root(&self) -> Root {
@@ -2040,6 +2666,7 @@ impl<
             Self::Bellatrix(inner) => inner.genesis_validators_root,
             Self::Capella(inner) => inner.genesis_validators_root,
             Self::Deneb(inner) => inner.genesis_validators_root,
+            Self::Electra(inner) => inner.genesis_validators_root,
         }
     }
     pub fn slot(&self) -> Slot {
@@ -2049,6 +2676,7 @@ impl<
             Self::Bellatrix(inner) => inner.slot,
             Self::Capella(inner) => inner.slot,
             Self::Deneb(inner) => inner.slot,
+            Self::Electra(inner) => inner.slot,
         }
     }
     pub fn fork(&self) -> &Fork {
@@ -2058,6 +2686,7 @@ impl<
             Self::Bellatrix(inner) => &inner.fork,
             Self::Capella(inner) => &inner.fork,
             Self::Deneb(inner) => &inner.fork,
+            Self::Electra(inner) => &inner.fork,
         }
     }
     pub fn fork_mut(&mut self) -> &mut Fork {
@@ -2067,6 +2696,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.fork,
             Self::Capella(inner) => &mut inner.fork,
             Self::Deneb(inner) => &mut inner.fork,
+            Self::Electra(inner) => &mut inner.fork,
         }
     }
     pub fn latest_block_header(&self) -> &BeaconBlockHeader {
@@ -2076,6 +2706,7 @@ impl<
             Self::Bellatrix(inner) => &inner.latest_block_header,
             Self::Capella(inner) => &inner.latest_block_header,
             Self::Deneb(inner) => &inner.latest_block_header,
+            Self::Electra(inner) => &inner.latest_block_header,
         }
     }
     pub fn latest_block_header_mut(&mut self) -> &mut BeaconBlockHeader {
@@ -2085,6 +2716,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.latest_block_header,
             Self::Capella(inner) => &mut inner.latest_block_header,
             Self::Deneb(inner) => &mut inner.latest_block_header,
+            Self::Electra(inner) => &mut inner.latest_block_header,
         }
     }
     pub fn block_roots(&self) -> &Vector<Root, SLOTS_PER_HISTORICAL_ROOT> {
@@ -2094,6 +2726,7 @@ impl<
             Self::Bellatrix(inner) => &inner.block_roots,
             Self::Capella(inner) => &inner.block_roots,
             Self::Deneb(inner) => &inner.block_roots,
+            Self::Electra(inner) => &inner.block_roots,
         }
     }
     pub fn block_roots_mut(&mut self) -> &mut Vector<Root, SLOTS_PER_HISTORICAL_ROOT> {
@@ -2103,6 +2736,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.block_roots,
             Self::Capella(inner) => &mut inner.block_roots,
             Self::Deneb(inner) => &mut inner.block_roots,
+            Self::Electra(inner) => &mut inner.block_roots,
         }
     }
     pub fn state_roots(&self) -> &Vector<Root, SLOTS_PER_HISTORICAL_ROOT> {
@@ -2112,6 +2746,7 @@ impl<
             Self::Bellatrix(inner) => &inner.state_roots,
             Self::Capella(inner) => &inner.state_roots,
             Self::Deneb(inner) => &inner.state_roots,
+            Self::Electra(inner) => &inner.state_roots,
         }
     }
     pub fn state_roots_mut(&mut self) -> &mut Vector<Root, SLOTS_PER_HISTORICAL_ROOT> {
@@ -2121,6 +2756,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.state_roots,
             Self::Capella(inner) => &mut inner.state_roots,
             Self::Deneb(inner) => &mut inner.state_roots,
+            Self::Electra(inner) => &mut inner.state_roots,
         }
     }
     pub fn historical_roots(&self) -> &List<Root, HISTORICAL_ROOTS_LIMIT> {
@@ -2130,6 +2766,7 @@ impl<
             Self::Bellatrix(inner) => &inner.historical_roots,
             Self::Capella(inner) => &inner.historical_roots,
             Self::Deneb(inner) => &inner.historical_roots,
+            Self::Electra(inner) => &inner.historical_roots,
         }
     }
     pub fn historical_roots_mut(&mut self) -> &mut List<Root, HISTORICAL_ROOTS_LIMIT> {
@@ -2139,6 +2776,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.historical_roots,
             Self::Capella(inner) => &mut inner.historical_roots,
             Self::Deneb(inner) => &mut inner.historical_roots,
+            Self::Electra(inner) => &mut inner.historical_roots,
         }
     }
     pub fn eth1_data(&self) -> &Eth1Data {
@@ -2148,6 +2786,7 @@ impl<
             Self::Bellatrix(inner) => &inner.eth1_data,
             Self::Capella(inner) => &inner.eth1_data,
             Self::Deneb(inner) => &inner.eth1_data,
+            Self::Electra(inner) => &inner.eth1_data,
         }
     }
     pub fn eth1_data_mut(&mut self) -> &mut Eth1Data {
@@ -2157,6 +2796,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.eth1_data,
             Self::Capella(inner) => &mut inner.eth1_data,
             Self::Deneb(inner) => &mut inner.eth1_data,
+            Self::Electra(inner) => &mut inner.eth1_data,
         }
     }
     pub fn eth1_data_votes(&self) -> &List<Eth1Data, ETH1_DATA_VOTES_BOUND> {
@@ -2166,6 +2806,7 @@ impl<
             Self::Bellatrix(inner) => &inner.eth1_data_votes,
             Self::Capella(inner) => &inner.eth1_data_votes,
             Self::Deneb(inner) => &inner.eth1_data_votes,
+            Self::Electra(inner) => &inner.eth1_data_votes,
         }
     }
     pub fn eth1_data_votes_mut(&mut self) -> &mut List<Eth1Data, ETH1_DATA_VOTES_BOUND> {
@@ -2175,6 +2816,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.eth1_data_votes,
             Self::Capella(inner) => &mut inner.eth1_data_votes,
             Self::Deneb(inner) => &mut inner.eth1_data_votes,
+            Self::Electra(inner) => &mut inner.eth1_data_votes,
         }
     }
     pub fn eth1_deposit_index(&self) -> u64 {
@@ -2184,6 +2826,7 @@ impl<
             Self::Bellatrix(inner) => inner.eth1_deposit_index,
             Self::Capella(inner) => inner.eth1_deposit_index,
             Self::Deneb(inner) => inner.eth1_deposit_index,
+            Self::Electra(inner) => inner.eth1_deposit_index,
         }
     }
     pub fn validators(&self) -> &List<Validator, VALIDATOR_REGISTRY_LIMIT> {
@@ -2193,6 +2836,7 @@ impl<
             Self::Bellatrix(inner) => &inner.validators,
             Self::Capella(inner) => &inner.validators,
             Self::Deneb(inner) => &inner.validators,
+            Self::Electra(inner) => &inner.validators,
         }
     }
     pub fn validators_mut(&mut self) -> &mut List<Validator, VALIDATOR_REGISTRY_LIMIT> {
@@ -2202,6 +2846,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.validators,
             Self::Capella(inner) => &mut inner.validators,
             Self::Deneb(inner) => &mut inner.validators,
+            Self::Electra(inner) => &mut inner.validators,
         }
     }
     pub fn balances(&self) -> &List<Gwei, VALIDATOR_REGISTRY_LIMIT> {
@@ -2211,6 +2856,7 @@ impl<
             Self::Bellatrix(inner) => &inner.balances,
             Self::Capella(inner) => &inner.balances,
             Self::Deneb(inner) => &inner.balances,
+            Self::Electra(inner) => &inner.balances,
         }
     }
     pub fn balances_mut(&mut self) -> &mut List<Gwei, VALIDATOR_REGISTRY_LIMIT> {
@@ -2220,6 +2866,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.balances,
             Self::Capella(inner) => &mut inner.balances,
             Self::Deneb(inner) => &mut inner.balances,
+            Self::Electra(inner) => &mut inner.balances,
         }
     }
     pub fn randao_mixes(&self) -> &Vector<Bytes32, EPOCHS_PER_HISTORICAL_VECTOR> {
@@ -2229,6 +2876,7 @@ impl<
             Self::Bellatrix(inner) => &inner.randao_mixes,
             Self::Capella(inner) => &inner.randao_mixes,
             Self::Deneb(inner) => &inner.randao_mixes,
+            Self::Electra(inner) => &inner.randao_mixes,
         }
     }
     pub fn randao_mixes_mut(&mut self) -> &mut Vector<Bytes32, EPOCHS_PER_HISTORICAL_VECTOR> {
@@ -2238,6 +2886,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.randao_mixes,
             Self::Capella(inner) => &mut inner.randao_mixes,
             Self::Deneb(inner) => &mut inner.randao_mixes,
+            Self::Electra(inner) => &mut inner.randao_mixes,
         }
     }
     pub fn slashings(&self) -> &Vector<Gwei, EPOCHS_PER_SLASHINGS_VECTOR> {
@@ -2247,6 +2896,7 @@ impl<
             Self::Bellatrix(inner) => &inner.slashings,
             Self::Capella(inner) => &inner.slashings,
             Self::Deneb(inner) => &inner.slashings,
+            Self::Electra(inner) => &inner.slashings,
         }
     }
     pub fn slashings_mut(&mut self) -> &mut Vector<Gwei, EPOCHS_PER_SLASHINGS_VECTOR> {
@@ -2256,6 +2906,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.slashings,
             Self::Capella(inner) => &mut inner.slashings,
             Self::Deneb(inner) => &mut inner.slashings,
+            Self::Electra(inner) => &mut inner.slashings,
         }
     }
     pub fn previous_epoch_attestations(
@@ -2268,6 +2919,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(_) => None,
+            Self::Electra(_) => None,
         }
     }
     pub fn previous_epoch_attestations_mut(
@@ -2281,6 +2933,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(_) => None,
+            Self::Electra(_) => None,
         }
     }
     pub fn current_epoch_attestations(
@@ -2293,6 +2946,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(_) => None,
+            Self::Electra(_) => None,
         }
     }
     pub fn current_epoch_attestations_mut(
@@ -2306,6 +2960,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(_) => None,
+            Self::Electra(_) => None,
         }
     }
     pub fn justification_bits(&self) -> &Bitvector<JUSTIFICATION_BITS_LENGTH> {
@@ -2315,6 +2970,7 @@ impl<
             Self::Bellatrix(inner) => &inner.justification_bits,
             Self::Capella(inner) => &inner.justification_bits,
             Self::Deneb(inner) => &inner.justification_bits,
+            Self::Electra(inner) => &inner.justification_bits,
         }
     }
     pub fn justification_bits_mut(&mut self) -> &mut Bitvector<JUSTIFICATION_BITS_LENGTH> {
@@ -2324,6 +2980,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.justification_bits,
             Self::Capella(inner) => &mut inner.justification_bits,
             Self::Deneb(inner) => &mut inner.justification_bits,
+            Self::Electra(inner) => &mut inner.justification_bits,
         }
     }
     pub fn previous_justified_checkpoint(&self) -> &Checkpoint {
@@ -2333,6 +2990,7 @@ impl<
             Self::Bellatrix(inner) => &inner.previous_justified_checkpoint,
             Self::Capella(inner) => &inner.previous_justified_checkpoint,
             Self::Deneb(inner) => &inner.previous_justified_checkpoint,
+            Self::Electra(inner) => &inner.previous_justified_checkpoint,
         }
     }
     pub fn previous_justified_checkpoint_mut(&mut self) -> &mut Checkpoint {
@@ -2342,6 +3000,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.previous_justified_checkpoint,
             Self::Capella(inner) => &mut inner.previous_justified_checkpoint,
             Self::Deneb(inner) => &mut inner.previous_justified_checkpoint,
+            Self::Electra(inner) => &mut inner.previous_justified_checkpoint,
         }
     }
     pub fn current_justified_checkpoint(&self) -> &Checkpoint {
@@ -2351,6 +3010,7 @@ impl<
             Self::Bellatrix(inner) => &inner.current_justified_checkpoint,
             Self::Capella(inner) => &inner.current_justified_checkpoint,
             Self::Deneb(inner) => &inner.current_justified_checkpoint,
+            Self::Electra(inner) => &inner.current_justified_checkpoint,
         }
     }
     pub fn current_justified_checkpoint_mut(&mut self) -> &mut Checkpoint {
@@ -2360,6 +3020,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.current_justified_checkpoint,
             Self::Capella(inner) => &mut inner.current_justified_checkpoint,
             Self::Deneb(inner) => &mut inner.current_justified_checkpoint,
+            Self::Electra(inner) => &mut inner.current_justified_checkpoint,
         }
     }
     pub fn finalized_checkpoint(&self) -> &Checkpoint {
@@ -2369,6 +3030,7 @@ impl<
             Self::Bellatrix(inner) => &inner.finalized_checkpoint,
             Self::Capella(inner) => &inner.finalized_checkpoint,
             Self::Deneb(inner) => &inner.finalized_checkpoint,
+            Self::Electra(inner) => &inner.finalized_checkpoint,
         }
     }
     pub fn finalized_checkpoint_mut(&mut self) -> &mut Checkpoint {
@@ -2378,6 +3040,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.finalized_checkpoint,
             Self::Capella(inner) => &mut inner.finalized_checkpoint,
             Self::Deneb(inner) => &mut inner.finalized_checkpoint,
+            Self::Electra(inner) => &mut inner.finalized_checkpoint,
         }
     }
     pub fn previous_epoch_participation(
@@ -2389,6 +3052,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.previous_epoch_participation),
             Self::Capella(inner) => Some(&inner.previous_epoch_participation),
             Self::Deneb(inner) => Some(&inner.previous_epoch_participation),
+            Self::Electra(inner) => Some(&inner.previous_epoch_participation),
         }
     }
     pub fn previous_epoch_participation_mut(
@@ -2400,6 +3064,7 @@ impl<
             Self::Bellatrix(inner) => Some(&mut inner.previous_epoch_participation),
             Self::Capella(inner) => Some(&mut inner.previous_epoch_participation),
             Self::Deneb(inner) => Some(&mut inner.previous_epoch_participation),
+            Self::Electra(inner) => Some(&mut inner.previous_epoch_participation),
         }
     }
     pub fn current_epoch_participation(
@@ -2411,6 +3076,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.current_epoch_participation),
             Self::Capella(inner) => Some(&inner.current_epoch_participation),
             Self::Deneb(inner) => Some(&inner.current_epoch_participation),
+            Self::Electra(inner) => Some(&inner.current_epoch_participation),
         }
     }
     pub fn current_epoch_participation_mut(
@@ -2422,6 +3088,7 @@ impl<
             Self::Bellatrix(inner) => Some(&mut inner.current_epoch_participation),
             Self::Capella(inner) => Some(&mut inner.current_epoch_participation),
             Self::Deneb(inner) => Some(&mut inner.current_epoch_participation),
+            Self::Electra(inner) => Some(&mut inner.current_epoch_participation),
         }
     }
     pub fn inactivity_scores(&self) -> Option<&List<u64, VALIDATOR_REGISTRY_LIMIT>> {
@@ -2431,6 +3098,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.inactivity_scores),
             Self::Capella(inner) => Some(&inner.inactivity_scores),
             Self::Deneb(inner) => Some(&inner.inactivity_scores),
+            Self::Electra(inner) => Some(&inner.inactivity_scores),
         }
     }
     pub fn inactivity_scores_mut(&mut self) -> Option<&mut List<u64, VALIDATOR_REGISTRY_LIMIT>> {
@@ -2440,6 +3108,7 @@ impl<
             Self::Bellatrix(inner) => Some(&mut inner.inactivity_scores),
             Self::Capella(inner) => Some(&mut inner.inactivity_scores),
             Self::Deneb(inner) => Some(&mut inner.inactivity_scores),
+            Self::Electra(inner) => Some(&mut inner.inactivity_scores),
         }
     }
     pub fn current_sync_committee(&self) -> Option<&SyncCommittee<SYNC_COMMITTEE_SIZE>> {
@@ -2449,6 +3118,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.current_sync_committee),
             Self::Capella(inner) => Some(&inner.current_sync_committee),
             Self::Deneb(inner) => Some(&inner.current_sync_committee),
+            Self::Electra(inner) => Some(&inner.current_sync_committee),
         }
     }
     pub fn current_sync_committee_mut(
@@ -2460,6 +3130,7 @@ impl<
             Self::Bellatrix(inner) => Some(&mut inner.current_sync_committee),
             Self::Capella(inner) => Some(&mut inner.current_sync_committee),
             Self::Deneb(inner) => Some(&mut inner.current_sync_committee),
+            Self::Electra(inner) => Some(&mut inner.current_sync_committee),
         }
     }
     pub fn next_sync_committee(&self) -> Option<&SyncCommittee<SYNC_COMMITTEE_SIZE>> {
@@ -2469,6 +3140,7 @@ impl<
             Self::Bellatrix(inner) => Some(&inner.next_sync_committee),
             Self::Capella(inner) => Some(&inner.next_sync_committee),
             Self::Deneb(inner) => Some(&inner.next_sync_committee),
+            Self::Electra(inner) => Some(&inner.next_sync_committee),
         }
     }
     pub fn next_sync_committee_mut(&mut self) -> Option<&mut SyncCommittee<SYNC_COMMITTEE_SIZE>> {
@@ -2478,6 +3150,7 @@ impl<
             Self::Bellatrix(inner) => Some(&mut inner.next_sync_committee),
             Self::Capella(inner) => Some(&mut inner.next_sync_committee),
             Self::Deneb(inner) => Some(&mut inner.next_sync_committee),
+            Self::Electra(inner) => Some(&mut inner.next_sync_committee),
         }
     }
     pub fn latest_execution_payload_header(
@@ -2489,6 +3162,7 @@ impl<
             Self::Bellatrix(inner) => Some(From::from(&inner.latest_execution_payload_header)),
             Self::Capella(inner) => Some(From::from(&inner.latest_execution_payload_header)),
             Self::Deneb(inner) => Some(From::from(&inner.latest_execution_payload_header)),
+            Self::Electra(inner) => Some(From::from(&inner.latest_execution_payload_header)),
         }
     }
     pub fn latest_execution_payload_header_mut(
@@ -2500,6 +3174,7 @@ impl<
             Self::Bellatrix(inner) => Some(From::from(&mut inner.latest_execution_payload_header)),
             Self::Capella(inner) => Some(From::from(&mut inner.latest_execution_payload_header)),
             Self::Deneb(inner) => Some(From::from(&mut inner.latest_execution_payload_header)),
+            Self::Electra(inner) => Some(From::from(&mut inner.latest_execution_payload_header)),
         }
     }
     pub fn next_withdrawal_index(&self) -> Option<WithdrawalIndex> {
@@ -2509,6 +3184,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(inner.next_withdrawal_index),
             Self::Deneb(inner) => Some(inner.next_withdrawal_index),
+            Self::Electra(inner) => Some(inner.next_withdrawal_index),
         }
     }
     pub fn next_withdrawal_validator_index(&self) -> Option<ValidatorIndex> {
@@ -2518,6 +3194,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(inner.next_withdrawal_validator_index),
             Self::Deneb(inner) => Some(inner.next_withdrawal_validator_index),
+            Self::Electra(inner) => Some(inner.next_withdrawal_validator_index),
         }
     }
     pub fn historical_summaries(&self) -> Option<&List<HistoricalSummary, HISTORICAL_ROOTS_LIMIT>> {
@@ -2527,6 +3204,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&inner.historical_summaries),
             Self::Deneb(inner) => Some(&inner.historical_summaries),
+            Self::Electra(inner) => Some(&inner.historical_summaries),
         }
     }
     pub fn historical_summaries_mut(
@@ -2538,6 +3216,137 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&mut inner.historical_summaries),
             Self::Deneb(inner) => Some(&mut inner.historical_summaries),
+            Self::Electra(inner) => Some(&mut inner.historical_summaries),
+        }
+    }
+    pub fn deposit_requests_start_index(&self) -> Option<u64> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.deposit_requests_start_index),
+        }
+    }
+    pub fn deposit_balance_to_consume(&self) -> Option<Gwei> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.deposit_balance_to_consume),
+        }
+    }
+    pub fn exit_balance_to_consume(&self) -> Option<Gwei> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.exit_balance_to_consume),
+        }
+    }
+    pub fn earliest_exit_epoch(&self) -> Option<Epoch> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.earliest_exit_epoch),
+        }
+    }
+    pub fn consolidation_balance_to_consume(&self) -> Option<Gwei> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.consolidation_balance_to_consume),
+        }
+    }
+    pub fn earliest_consolidation_epoch(&self) -> Option<Epoch> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(inner.earliest_consolidation_epoch),
+        }
+    }
+    pub fn pending_deposits(&self) -> Option<&List<PendingDeposit, PENDING_DEPOSITS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.pending_deposits),
+        }
+    }
+    pub fn pending_deposits_mut(
+        &mut self,
+    ) -> Option<&mut List<PendingDeposit, PENDING_DEPOSITS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.pending_deposits),
+        }
+    }
+    pub fn pending_partial_withdrawals(
+        &self,
+    ) -> Option<&List<PendingPartialWithdrawal, PENDING_PARTIAL_WITHDRAWALS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.pending_partial_withdrawals),
+        }
+    }
+    pub fn pending_partial_withdrawals_mut(
+        &mut self,
+    ) -> Option<&mut List<PendingPartialWithdrawal, PENDING_PARTIAL_WITHDRAWALS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.pending_partial_withdrawals),
+        }
+    }
+    pub fn pending_consolidations(
+        &self,
+    ) -> Option<&List<PendingConsolidation, PENDING_CONSOLIDATIONS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.pending_consolidations),
+        }
+    }
+    pub fn pending_consolidations_mut(
+        &mut self,
+    ) -> Option<&mut List<PendingConsolidation, PENDING_CONSOLIDATIONS_LIMIT>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(_) => None,
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.pending_consolidations),
         }
     }
 }
@@ -2554,6 +3363,9 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
     >
     From<
         &'a mut phase0::BeaconState<
@@ -2580,6 +3392,9 @@ impl<
         SYNC_COMMITTEE_SIZE,
         BYTES_PER_LOGS_BLOOM,
         MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
     >
 {
     fn from(
@@ -2610,6 +3425,9 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
     >
     From<
         &'a mut altair::BeaconState<
@@ -2636,6 +3454,9 @@ impl<
         SYNC_COMMITTEE_SIZE,
         BYTES_PER_LOGS_BLOOM,
         MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
     >
 {
     fn from(
@@ -2666,6 +3487,9 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
     >
     From<
         &'a mut bellatrix::BeaconState<
@@ -2694,6 +3518,9 @@ impl<
         SYNC_COMMITTEE_SIZE,
         BYTES_PER_LOGS_BLOOM,
         MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
     >
 {
     fn from(
@@ -2726,6 +3553,9 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
     >
     From<
         &'a mut capella::BeaconState<
@@ -2754,6 +3584,9 @@ impl<
         SYNC_COMMITTEE_SIZE,
         BYTES_PER_LOGS_BLOOM,
         MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
     >
 {
     fn from(
@@ -2786,6 +3619,9 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
     >
     From<
         &'a mut deneb::BeaconState<
@@ -2814,6 +3650,9 @@ impl<
         SYNC_COMMITTEE_SIZE,
         BYTES_PER_LOGS_BLOOM,
         MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
     >
 {
     fn from(
@@ -2833,3 +3672,75 @@ impl<
         Self::Deneb(value)
     }
 }
+impl<
+        'a,
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const PENDING_ATTESTATIONS_BOUND: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    >
+    From<
+        &'a mut electra::BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
+        >,
+    >
+    for BeaconStateRefMut<
+        'a,
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >
+{
+    fn from(
+        value: &'a mut electra::BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
+        >,
+    ) -> Self {
+        Self::Electra(value)
+    }
+}
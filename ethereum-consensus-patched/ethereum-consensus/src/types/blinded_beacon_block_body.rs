@@ -4,6 +4,10 @@ use crate::{
     bellatrix::blinded_beacon_block as bellatrix,
     capella::{blinded_beacon_block as capella, SignedBlsToExecutionChange},
     deneb::{blinded_beacon_block as deneb, polynomial_commitments::KzgCommitment},
+    electra::{
+        blinded_beacon_block as electra, Attestation as AttestationElectra,
+        AttesterSlashing as AttesterSlashingElectra, ExecutionRequests,
+    },
     phase0::{
         Attestation, AttesterSlashing, Deposit, Eth1Data, ProposerSlashing, SignedVoluntaryExit,
     },
@@ -12,7 +16,7 @@ use crate::{
     types::execution_payload_header::{ExecutionPayloadHeaderRef, ExecutionPayloadHeaderRefMut},
     Fork as Version,
 };
-#[derive(Debug, Clone, PartialEq, Eq, Merkleized, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Merkleized, serde::Serialize)]
 #[ssz(transparent)]
 #[serde(untagged)]
 pub enum BlindedBeaconBlockBody<
@@ -27,6 +31,13 @@ pub enum BlindedBeaconBlockBody<
     const MAX_EXTRA_DATA_BYTES: usize,
     const MAX_BLS_TO_EXECUTION_CHANGES: usize,
     const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+    const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+    const MAX_ATTESTATIONS_ELECTRA: usize,
+    const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
 > {
     Bellatrix(
         bellatrix::BlindedBeaconBlockBody<
@@ -70,6 +81,25 @@ pub enum BlindedBeaconBlockBody<
             MAX_BLOB_COMMITMENTS_PER_BLOCK,
         >,
     ),
+    Electra(
+        electra::BlindedBeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    ),
 }
 impl<
         const MAX_PROPOSER_SLASHINGS: usize,
@@ -83,6 +113,13 @@ impl<
         const MAX_EXTRA_DATA_BYTES: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     BlindedBeaconBlockBody<
         MAX_PROPOSER_SLASHINGS,
@@ -96,6 +133,13 @@ impl<
         MAX_EXTRA_DATA_BYTES,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     pub fn bellatrix(
@@ -224,11 +268,64 @@ impl<
             _ => None,
         }
     }
+    pub fn electra(
+        &self,
+    ) -> Option<
+        &electra::BlindedBeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
+    pub fn electra_mut(
+        &mut self,
+    ) -> Option<
+        &mut electra::BlindedBeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
     pub fn version(&self) -> Version {
         match self {
             Self::Bellatrix(_) => Version::Bellatrix,
             Self::Capella(_) => Version::Capella,
             Self::Deneb(_) => Version::Deneb,
+            Self::Electra(_) => Version::Electra,
         }
     }
     pub fn randao_reveal(&self) -> &BlsSignature {
@@ -236,6 +333,7 @@ impl<
             Self::Bellatrix(inner) => &inner.randao_reveal,
             Self::Capella(inner) => &inner.randao_reveal,
             Self::Deneb(inner) => &inner.randao_reveal,
+            Self::Electra(inner) => &inner.randao_reveal,
         }
     }
     pub fn randao_reveal_mut(&mut self) -> &mut BlsSignature {
@@ -243,6 +341,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.randao_reveal,
             Self::Capella(inner) => &mut inner.randao_reveal,
             Self::Deneb(inner) => &mut inner.randao_reveal,
+            Self::Electra(inner) => &mut inner.randao_reveal,
         }
     }
     pub fn eth1_data(&self) -> &Eth1Data {
@@ -250,6 +349,7 @@ impl<
             Self::Bellatrix(inner) => &inner.eth1_data,
             Self::Capella(inner) => &inner.eth1_data,
             Self::Deneb(inner) => &inner.eth1_data,
+            Self::Electra(inner) => &inner.eth1_data,
         }
     }
     pub fn eth1_data_mut(&mut self) -> &mut Eth1Data {
@@ -257,6 +357,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.eth1_data,
             Self::Capella(inner) => &mut inner.eth1_data,
             Self::Deneb(inner) => &mut inner.eth1_data,
+            Self::Electra(inner) => &mut inner.eth1_data,
         }
     }
     pub fn graffiti(&self) -> &Bytes32 {
@@ -264,6 +365,7 @@ impl<
             Self::Bellatrix(inner) => &inner.graffiti,
             Self::Capella(inner) => &inner.graffiti,
             Self::Deneb(inner) => &inner.graffiti,
+            Self::Electra(inner) => &inner.graffiti,
         }
     }
     pub fn graffiti_mut(&mut self) -> &mut Bytes32 {
@@ -271,6 +373,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.graffiti,
             Self::Capella(inner) => &mut inner.graffiti,
             Self::Deneb(inner) => &mut inner.graffiti,
+            Self::Electra(inner) => &mut inner.graffiti,
         }
     }
     pub fn proposer_slashings(&self) -> &List<ProposerSlashing, MAX_PROPOSER_SLASHINGS> {
@@ -278,6 +381,7 @@ impl<
             Self::Bellatrix(inner) => &inner.proposer_slashings,
             Self::Capella(inner) => &inner.proposer_slashings,
             Self::Deneb(inner) => &inner.proposer_slashings,
+            Self::Electra(inner) => &inner.proposer_slashings,
         }
     }
     pub fn proposer_slashings_mut(
@@ -287,42 +391,48 @@ impl<
             Self::Bellatrix(inner) => &mut inner.proposer_slashings,
             Self::Capella(inner) => &mut inner.proposer_slashings,
             Self::Deneb(inner) => &mut inner.proposer_slashings,
+            Self::Electra(inner) => &mut inner.proposer_slashings,
         }
     }
     pub fn attester_slashings(
         &self,
-    ) -> &List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS> {
+    ) -> Option<&List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS>> {
         match self {
-            Self::Bellatrix(inner) => &inner.attester_slashings,
-            Self::Capella(inner) => &inner.attester_slashings,
-            Self::Deneb(inner) => &inner.attester_slashings,
+            Self::Bellatrix(inner) => Some(&inner.attester_slashings),
+            Self::Capella(inner) => Some(&inner.attester_slashings),
+            Self::Deneb(inner) => Some(&inner.attester_slashings),
+            Self::Electra(_) => None,
         }
     }
     pub fn attester_slashings_mut(
         &mut self,
-    ) -> &mut List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS> {
+    ) -> Option<&mut List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS>>
+    {
         match self {
-            Self::Bellatrix(inner) => &mut inner.attester_slashings,
-            Self::Capella(inner) => &mut inner.attester_slashings,
-            Self::Deneb(inner) => &mut inner.attester_slashings,
+            Self::Bellatrix(inner) => Some(&mut inner.attester_slashings),
+            Self::Capella(inner) => Some(&mut inner.attester_slashings),
+            Self::Deneb(inner) => Some(&mut inner.attester_slashings),
+            Self::Electra(_) => None,
         }
     }
     pub fn attestations(
         &self,
-    ) -> &List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS> {
+    ) -> Option<&List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS>> {
         match self {
-            Self::Bellatrix(inner) => &inner.attestations,
-            Self::Capella(inner) => &inner.attestations,
-            Self::Deneb(inner) => &inner.attestations,
+            Self::Bellatrix(inner) => Some(&inner.attestations),
+            Self::Capella(inner) => Some(&inner.attestations),
+            Self::Deneb(inner) => Some(&inner.attestations),
+            Self::Electra(_) => None,
         }
     }
     pub fn attestations_mut(
         &mut self,
-    ) -> &mut List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS> {
+    ) -> Option<&mut List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS>> {
         match self {
-            Self::Bellatrix(inner) => &mut inner.attestations,
-            Self::Capella(inner) => &mut inner.attestations,
-            Self::Deneb(inner) => &mut inner.attestations,
+            Self::Bellatrix(inner) => Some(&mut inner.attestations),
+            Self::Capella(inner) => Some(&mut inner.attestations),
+            Self::Deneb(inner) => Some(&mut inner.attestations),
+            Self::Electra(_) => None,
         }
     }
     pub fn deposits(&self) -> &List<Deposit, MAX_DEPOSITS> {
@@ -330,6 +440,7 @@ impl<
             Self::Bellatrix(inner) => &inner.deposits,
             Self::Capella(inner) => &inner.deposits,
             Self::Deneb(inner) => &inner.deposits,
+            Self::Electra(inner) => &inner.deposits,
         }
     }
     pub fn deposits_mut(&mut self) -> &mut List<Deposit, MAX_DEPOSITS> {
@@ -337,6 +448,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.deposits,
             Self::Capella(inner) => &mut inner.deposits,
             Self::Deneb(inner) => &mut inner.deposits,
+            Self::Electra(inner) => &mut inner.deposits,
         }
     }
     pub fn voluntary_exits(&self) -> &List<SignedVoluntaryExit, MAX_VOLUNTARY_EXITS> {
@@ -344,6 +456,7 @@ impl<
             Self::Bellatrix(inner) => &inner.voluntary_exits,
             Self::Capella(inner) => &inner.voluntary_exits,
             Self::Deneb(inner) => &inner.voluntary_exits,
+            Self::Electra(inner) => &inner.voluntary_exits,
         }
     }
     pub fn voluntary_exits_mut(&mut self) -> &mut List<SignedVoluntaryExit, MAX_VOLUNTARY_EXITS> {
@@ -351,6 +464,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.voluntary_exits,
             Self::Capella(inner) => &mut inner.voluntary_exits,
             Self::Deneb(inner) => &mut inner.voluntary_exits,
+            Self::Electra(inner) => &mut inner.voluntary_exits,
         }
     }
     pub fn sync_aggregate(&self) -> &SyncAggregate<SYNC_COMMITTEE_SIZE> {
@@ -358,6 +472,7 @@ impl<
             Self::Bellatrix(inner) => &inner.sync_aggregate,
             Self::Capella(inner) => &inner.sync_aggregate,
             Self::Deneb(inner) => &inner.sync_aggregate,
+            Self::Electra(inner) => &inner.sync_aggregate,
         }
     }
     pub fn sync_aggregate_mut(&mut self) -> &mut SyncAggregate<SYNC_COMMITTEE_SIZE> {
@@ -365,6 +480,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.sync_aggregate,
             Self::Capella(inner) => &mut inner.sync_aggregate,
             Self::Deneb(inner) => &mut inner.sync_aggregate,
+            Self::Electra(inner) => &mut inner.sync_aggregate,
         }
     }
     pub fn execution_payload_header(
@@ -374,6 +490,7 @@ impl<
             Self::Bellatrix(inner) => From::from(&inner.execution_payload_header),
             Self::Capella(inner) => From::from(&inner.execution_payload_header),
             Self::Deneb(inner) => From::from(&inner.execution_payload_header),
+            Self::Electra(inner) => From::from(&inner.execution_payload_header),
         }
     }
     pub fn execution_payload_header_mut(
@@ -383,6 +500,7 @@ impl<
             Self::Bellatrix(inner) => From::from(&mut inner.execution_payload_header),
             Self::Capella(inner) => From::from(&mut inner.execution_payload_header),
             Self::Deneb(inner) => From::from(&mut inner.execution_payload_header),
+            Self::Electra(inner) => From::from(&mut inner.execution_payload_header),
         }
     }
     pub fn bls_to_execution_changes(
@@ -392,6 +510,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&inner.bls_to_execution_changes),
             Self::Deneb(inner) => Some(&inner.bls_to_execution_changes),
+            Self::Electra(inner) => Some(&inner.bls_to_execution_changes),
         }
     }
     pub fn bls_to_execution_changes_mut(
@@ -401,6 +520,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&mut inner.bls_to_execution_changes),
             Self::Deneb(inner) => Some(&mut inner.bls_to_execution_changes),
+            Self::Electra(inner) => Some(&mut inner.bls_to_execution_changes),
         }
     }
     pub fn blob_kzg_commitments(
@@ -410,6 +530,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(inner) => Some(&inner.blob_kzg_commitments),
+            Self::Electra(inner) => Some(&inner.blob_kzg_commitments),
         }
     }
     pub fn blob_kzg_commitments_mut(
@@ -419,6 +540,93 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(inner) => Some(&mut inner.blob_kzg_commitments),
+            Self::Electra(inner) => Some(&mut inner.blob_kzg_commitments),
+        }
+    }
+    pub fn attester_slashings_electra(
+        &self,
+    ) -> Option<
+        &List<AttesterSlashingElectra<MAX_VALIDATORS_PER_SLOT>, MAX_ATTESTER_SLASHINGS_ELECTRA>,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.attester_slashings),
+        }
+    }
+    pub fn attester_slashings_electra_mut(
+        &mut self,
+    ) -> Option<
+        &mut List<AttesterSlashingElectra<MAX_VALIDATORS_PER_SLOT>, MAX_ATTESTER_SLASHINGS_ELECTRA>,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.attester_slashings),
+        }
+    }
+    pub fn attestations_electra(
+        &self,
+    ) -> Option<
+        &List<
+            AttestationElectra<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+            MAX_ATTESTATIONS_ELECTRA,
+        >,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.attestations),
+        }
+    }
+    pub fn attestations_electra_mut(
+        &mut self,
+    ) -> Option<
+        &mut List<
+            AttestationElectra<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+            MAX_ATTESTATIONS_ELECTRA,
+        >,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.attestations),
+        }
+    }
+    pub fn execution_requests(
+        &self,
+    ) -> Option<
+        &ExecutionRequests<
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.execution_requests),
+        }
+    }
+    pub fn execution_requests_mut(
+        &mut self,
+    ) -> Option<
+        &mut ExecutionRequests<
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.execution_requests),
         }
     }
 }
@@ -435,6 +643,13 @@ impl<
         const MAX_EXTRA_DATA_BYTES: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     > serde::Deserialize<'de>
     for BlindedBeaconBlockBody<
         MAX_PROPOSER_SLASHINGS,
@@ -448,6 +663,13 @@ impl<
         MAX_EXTRA_DATA_BYTES,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -455,6 +677,9 @@ impl<
         D: serde::Deserializer<'de>,
     {
         let value = serde_json::Value::deserialize(deserializer)?;
+        if let Ok(inner) = <_ as serde::Deserialize>::deserialize(&value) {
+            return Ok(Self::Electra(inner))
+        }
         if let Ok(inner) = <_ as serde::Deserialize>::deserialize(&value) {
             return Ok(Self::Deneb(inner))
         }
@@ -467,7 +692,7 @@ impl<
         Err(serde::de::Error::custom("no variant could be deserialized from input"))
     }
 }
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum BlindedBeaconBlockBodyRef<
     'a,
     const MAX_PROPOSER_SLASHINGS: usize,
@@ -481,6 +706,13 @@ pub enum BlindedBeaconBlockBodyRef<
     const MAX_EXTRA_DATA_BYTES: usize,
     const MAX_BLS_TO_EXECUTION_CHANGES: usize,
     const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+    const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+    const MAX_ATTESTATIONS_ELECTRA: usize,
+    const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
 > {
     Bellatrix(
         &'a bellatrix::BlindedBeaconBlockBody<
@@ -524,6 +756,25 @@ pub enum BlindedBeaconBlockBodyRef<
             MAX_BLOB_COMMITMENTS_PER_BLOCK,
         >,
     ),
+    Electra(
+        &'a electra::BlindedBeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    ),
 }
 impl<
         'a,
@@ -538,6 +789,13 @@ impl<
         const MAX_EXTRA_DATA_BYTES: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     BlindedBeaconBlockBodyRef<
         'a,
@@ -552,6 +810,13 @@ impl<
         MAX_EXTRA_DATA_BYTES,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     pub fn bellatrix(
@@ -617,11 +882,38 @@ impl<
             _ => None,
         }
     }
+    pub fn electra(
+        &self,
+    ) -> Option<
+        &electra::BlindedBeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
     pub fn version(&self) -> Version {
         match self {
             Self::Bellatrix(_) => Version::Bellatrix,
             Self::Capella(_) => Version::Capella,
             Self::Deneb(_) => Version::Deneb,
+            Self::Electra(_) => Version::Electra,
         }
     }
     pub fn randao_reveal(&self) -> &BlsSignature {
@@ -629,6 +921,7 @@ impl<
             Self::Bellatrix(inner) => &inner.randao_reveal,
             Self::Capella(inner) => &inner.randao_reveal,
             Self::Deneb(inner) => &inner.randao_reveal,
+            Self::Electra(inner) => &inner.randao_reveal,
         }
     }
     pub fn eth1_data(&self) -> &Eth1Data {
@@ -636,6 +929,7 @@ impl<
             Self::Bellatrix(inner) => &inner.eth1_data,
             Self::Capella(inner) => &inner.eth1_data,
             Self::Deneb(inner) => &inner.eth1_data,
+            Self::Electra(inner) => &inner.eth1_data,
         }
     }
     pub fn graffiti(&self) -> &Bytes32 {
@@ -643,6 +937,7 @@ impl<
             Self::Bellatrix(inner) => &inner.graffiti,
             Self::Capella(inner) => &inner.graffiti,
             Self::Deneb(inner) => &inner.graffiti,
+            Self::Electra(inner) => &inner.graffiti,
         }
     }
     pub fn proposer_slashings(&self) -> &List<ProposerSlashing, MAX_PROPOSER_SLASHINGS> {
@@ -650,24 +945,27 @@ impl<
             Self::Bellatrix(inner) => &inner.proposer_slashings,
             Self::Capella(inner) => &inner.proposer_slashings,
             Self::Deneb(inner) => &inner.proposer_slashings,
+            Self::Electra(inner) => &inner.proposer_slashings,
         }
     }
     pub fn attester_slashings(
         &self,
-    ) -> &List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS> {
+    ) -> Option<&List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS>> {
         match self {
-            Self::Bellatrix(inner) => &inner.attester_slashings,
-            Self::Capella(inner) => &inner.attester_slashings,
-            Self::Deneb(inner) => &inner.attester_slashings,
+            Self::Bellatrix(inner) => Some(&inner.attester_slashings),
+            Self::Capella(inner) => Some(&inner.attester_slashings),
+            Self::Deneb(inner) => Some(&inner.attester_slashings),
+            Self::Electra(_) => None,
         }
     }
     pub fn attestations(
         &self,
-    ) -> &List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS> {
+    ) -> Option<&List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS>> {
         match self {
-            Self::Bellatrix(inner) => &inner.attestations,
-            Self::Capella(inner) => &inner.attestations,
-            Self::Deneb(inner) => &inner.attestations,
+            Self::Bellatrix(inner) => Some(&inner.attestations),
+            Self::Capella(inner) => Some(&inner.attestations),
+            Self::Deneb(inner) => Some(&inner.attestations),
+            Self::Electra(_) => None,
         }
     }
     pub fn deposits(&self) -> &List<Deposit, MAX_DEPOSITS> {
@@ -675,6 +973,7 @@ impl<
             Self::Bellatrix(inner) => &inner.deposits,
             Self::Capella(inner) => &inner.deposits,
             Self::Deneb(inner) => &inner.deposits,
+            Self::Electra(inner) => &inner.deposits,
         }
     }
     pub fn voluntary_exits(&self) -> &List<SignedVoluntaryExit, MAX_VOLUNTARY_EXITS> {
@@ -682,6 +981,7 @@ impl<
             Self::Bellatrix(inner) => &inner.voluntary_exits,
             Self::Capella(inner) => &inner.voluntary_exits,
             Self::Deneb(inner) => &inner.voluntary_exits,
+            Self::Electra(inner) => &inner.voluntary_exits,
         }
     }
     pub fn sync_aggregate(&self) -> &SyncAggregate<SYNC_COMMITTEE_SIZE> {
@@ -689,6 +989,7 @@ impl<
             Self::Bellatrix(inner) => &inner.sync_aggregate,
             Self::Capella(inner) => &inner.sync_aggregate,
             Self::Deneb(inner) => &inner.sync_aggregate,
+            Self::Electra(inner) => &inner.sync_aggregate,
         }
     }
     pub fn execution_payload_header(
@@ -698,6 +999,7 @@ impl<
             Self::Bellatrix(inner) => From::from(&inner.execution_payload_header),
             Self::Capella(inner) => From::from(&inner.execution_payload_header),
             Self::Deneb(inner) => From::from(&inner.execution_payload_header),
+            Self::Electra(inner) => From::from(&inner.execution_payload_header),
         }
     }
     pub fn bls_to_execution_changes(
@@ -707,6 +1009,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&inner.bls_to_execution_changes),
             Self::Deneb(inner) => Some(&inner.bls_to_execution_changes),
+            Self::Electra(inner) => Some(&inner.bls_to_execution_changes),
         }
     }
     pub fn blob_kzg_commitments(
@@ -716,6 +1019,50 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(inner) => Some(&inner.blob_kzg_commitments),
+            Self::Electra(inner) => Some(&inner.blob_kzg_commitments),
+        }
+    }
+    pub fn attester_slashings_electra(
+        &self,
+    ) -> Option<
+        &List<AttesterSlashingElectra<MAX_VALIDATORS_PER_SLOT>, MAX_ATTESTER_SLASHINGS_ELECTRA>,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.attester_slashings),
+        }
+    }
+    pub fn attestations_electra(
+        &self,
+    ) -> Option<
+        &List<
+            AttestationElectra<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+            MAX_ATTESTATIONS_ELECTRA,
+        >,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.attestations),
+        }
+    }
+    pub fn execution_requests(
+        &self,
+    ) -> Option<
+        &ExecutionRequests<
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.execution_requests),
         }
     }
 }
@@ -732,6 +1079,13 @@ impl<
         const MAX_EXTRA_DATA_BYTES: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a bellatrix::BlindedBeaconBlockBody<
@@ -759,6 +1113,13 @@ impl<
         MAX_EXTRA_DATA_BYTES,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -790,6 +1151,13 @@ impl<
         const MAX_EXTRA_DATA_BYTES: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a capella::BlindedBeaconBlockBody<
@@ -818,6 +1186,13 @@ impl<
         MAX_EXTRA_DATA_BYTES,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -850,6 +1225,13 @@ impl<
         const MAX_EXTRA_DATA_BYTES: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a deneb::BlindedBeaconBlockBody<
@@ -879,6 +1261,13 @@ impl<
         MAX_EXTRA_DATA_BYTES,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -899,7 +1288,91 @@ impl<
         Self::Deneb(value)
     }
 }
-#[derive(Debug, PartialEq, Eq, Merkleized)]
+impl<
+        'a,
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+        const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+    >
+    From<
+        &'a electra::BlindedBeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    >
+    for BlindedBeaconBlockBodyRef<
+        'a,
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >
+{
+    fn from(
+        value: &'a electra::BlindedBeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    ) -> Self {
+        Self::Electra(value)
+    }
+}
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Merkleized)]
 #[ssz(transparent)]
 pub enum BlindedBeaconBlockBodyRefMut<
     'a,
@@ -914,6 +1387,13 @@ pub enum BlindedBeaconBlockBodyRefMut<
     const MAX_EXTRA_DATA_BYTES: usize,
     const MAX_BLS_TO_EXECUTION_CHANGES: usize,
     const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+    const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+    const MAX_ATTESTATIONS_ELECTRA: usize,
+    const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
 > {
     Bellatrix(
         &'a mut bellatrix::BlindedBeaconBlockBody<
@@ -957,6 +1437,25 @@ pub enum BlindedBeaconBlockBodyRefMut<
             MAX_BLOB_COMMITMENTS_PER_BLOCK,
         >,
     ),
+    Electra(
+        &'a mut electra::BlindedBeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    ),
 }
 impl<
         'a,
@@ -971,6 +1470,13 @@ impl<
         const MAX_EXTRA_DATA_BYTES: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     BlindedBeaconBlockBodyRefMut<
         'a,
@@ -985,6 +1491,13 @@ impl<
         MAX_EXTRA_DATA_BYTES,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     pub fn bellatrix(
@@ -1113,11 +1626,64 @@ impl<
             _ => None,
         }
     }
+    pub fn electra(
+        &self,
+    ) -> Option<
+        &electra::BlindedBeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
+    pub fn electra_mut(
+        &mut self,
+    ) -> Option<
+        &mut electra::BlindedBeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Electra(inner) => Some(inner),
+            _ => None,
+        }
+    }
     pub fn version(&self) -> Version {
         match self {
             Self::Bellatrix(_) => Version::Bellatrix,
             Self::Capella(_) => Version::Capella,
             Self::Deneb(_) => Version::Deneb,
+            Self::Electra(_) => Version::Electra,
         }
     }
     pub fn randao_reveal(&self) -> &BlsSignature {
@@ -1125,6 +1691,7 @@ impl<
             Self::Bellatrix(inner) => &inner.randao_reveal,
             Self::Capella(inner) => &inner.randao_reveal,
             Self::Deneb(inner) => &inner.randao_reveal,
+            Self::Electra(inner) => &inner.randao_reveal,
         }
     }
     pub fn randao_reveal_mut(&mut self) -> &mut BlsSignature {
@@ -1132,6 +1699,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.randao_reveal,
             Self::Capella(inner) => &mut inner.randao_reveal,
             Self::Deneb(inner) => &mut inner.randao_reveal,
+            Self::Electra(inner) => &mut inner.randao_reveal,
         }
     }
     pub fn eth1_data(&self) -> &Eth1Data {
@@ -1139,6 +1707,7 @@ impl<
             Self::Bellatrix(inner) => &inner.eth1_data,
             Self::Capella(inner) => &inner.eth1_data,
             Self::Deneb(inner) => &inner.eth1_data,
+            Self::Electra(inner) => &inner.eth1_data,
         }
     }
     pub fn eth1_data_mut(&mut self) -> &mut Eth1Data {
@@ -1146,6 +1715,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.eth1_data,
             Self::Capella(inner) => &mut inner.eth1_data,
             Self::Deneb(inner) => &mut inner.eth1_data,
+            Self::Electra(inner) => &mut inner.eth1_data,
         }
     }
     pub fn graffiti(&self) -> &Bytes32 {
@@ -1153,6 +1723,7 @@ impl<
             Self::Bellatrix(inner) => &inner.graffiti,
             Self::Capella(inner) => &inner.graffiti,
             Self::Deneb(inner) => &inner.graffiti,
+            Self::Electra(inner) => &inner.graffiti,
         }
     }
     pub fn graffiti_mut(&mut self) -> &mut Bytes32 {
@@ -1160,6 +1731,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.graffiti,
             Self::Capella(inner) => &mut inner.graffiti,
             Self::Deneb(inner) => &mut inner.graffiti,
+            Self::Electra(inner) => &mut inner.graffiti,
         }
     }
     pub fn proposer_slashings(&self) -> &List<ProposerSlashing, MAX_PROPOSER_SLASHINGS> {
@@ -1167,6 +1739,7 @@ impl<
             Self::Bellatrix(inner) => &inner.proposer_slashings,
             Self::Capella(inner) => &inner.proposer_slashings,
             Self::Deneb(inner) => &inner.proposer_slashings,
+            Self::Electra(inner) => &inner.proposer_slashings,
         }
     }
     pub fn proposer_slashings_mut(
@@ -1176,42 +1749,48 @@ impl<
             Self::Bellatrix(inner) => &mut inner.proposer_slashings,
             Self::Capella(inner) => &mut inner.proposer_slashings,
             Self::Deneb(inner) => &mut inner.proposer_slashings,
+            Self::Electra(inner) => &mut inner.proposer_slashings,
         }
     }
     pub fn attester_slashings(
         &self,
-    ) -> &List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS> {
+    ) -> Option<&List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS>> {
         match self {
-            Self::Bellatrix(inner) => &inner.attester_slashings,
-            Self::Capella(inner) => &inner.attester_slashings,
-            Self::Deneb(inner) => &inner.attester_slashings,
+            Self::Bellatrix(inner) => Some(&inner.attester_slashings),
+            Self::Capella(inner) => Some(&inner.attester_slashings),
+            Self::Deneb(inner) => Some(&inner.attester_slashings),
+            Self::Electra(_) => None,
         }
     }
     pub fn attester_slashings_mut(
         &mut self,
-    ) -> &mut List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS> {
+    ) -> Option<&mut List<AttesterSlashing<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTER_SLASHINGS>>
+    {
         match self {
-            Self::Bellatrix(inner) => &mut inner.attester_slashings,
-            Self::Capella(inner) => &mut inner.attester_slashings,
-            Self::Deneb(inner) => &mut inner.attester_slashings,
+            Self::Bellatrix(inner) => Some(&mut inner.attester_slashings),
+            Self::Capella(inner) => Some(&mut inner.attester_slashings),
+            Self::Deneb(inner) => Some(&mut inner.attester_slashings),
+            Self::Electra(_) => None,
         }
     }
     pub fn attestations(
         &self,
-    ) -> &List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS> {
+    ) -> Option<&List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS>> {
         match self {
-            Self::Bellatrix(inner) => &inner.attestations,
-            Self::Capella(inner) => &inner.attestations,
-            Self::Deneb(inner) => &inner.attestations,
+            Self::Bellatrix(inner) => Some(&inner.attestations),
+            Self::Capella(inner) => Some(&inner.attestations),
+            Self::Deneb(inner) => Some(&inner.attestations),
+            Self::Electra(_) => None,
         }
     }
     pub fn attestations_mut(
         &mut self,
-    ) -> &mut List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS> {
+    ) -> Option<&mut List<Attestation<MAX_VALIDATORS_PER_COMMITTEE>, MAX_ATTESTATIONS>> {
         match self {
-            Self::Bellatrix(inner) => &mut inner.attestations,
-            Self::Capella(inner) => &mut inner.attestations,
-            Self::Deneb(inner) => &mut inner.attestations,
+            Self::Bellatrix(inner) => Some(&mut inner.attestations),
+            Self::Capella(inner) => Some(&mut inner.attestations),
+            Self::Deneb(inner) => Some(&mut inner.attestations),
+            Self::Electra(_) => None,
         }
     }
     pub fn deposits(&self) -> &List<Deposit, MAX_DEPOSITS> {
@@ -1219,6 +1798,7 @@ impl<
             Self::Bellatrix(inner) => &inner.deposits,
             Self::Capella(inner) => &inner.deposits,
             Self::Deneb(inner) => &inner.deposits,
+            Self::Electra(inner) => &inner.deposits,
         }
     }
     pub fn deposits_mut(&mut self) -> &mut List<Deposit, MAX_DEPOSITS> {
@@ -1226,6 +1806,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.deposits,
             Self::Capella(inner) => &mut inner.deposits,
             Self::Deneb(inner) => &mut inner.deposits,
+            Self::Electra(inner) => &mut inner.deposits,
         }
     }
     pub fn voluntary_exits(&self) -> &List<SignedVoluntaryExit, MAX_VOLUNTARY_EXITS> {
@@ -1233,6 +1814,7 @@ impl<
             Self::Bellatrix(inner) => &inner.voluntary_exits,
             Self::Capella(inner) => &inner.voluntary_exits,
             Self::Deneb(inner) => &inner.voluntary_exits,
+            Self::Electra(inner) => &inner.voluntary_exits,
         }
     }
     pub fn voluntary_exits_mut(&mut self) -> &mut List<SignedVoluntaryExit, MAX_VOLUNTARY_EXITS> {
@@ -1240,6 +1822,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.voluntary_exits,
             Self::Capella(inner) => &mut inner.voluntary_exits,
             Self::Deneb(inner) => &mut inner.voluntary_exits,
+            Self::Electra(inner) => &mut inner.voluntary_exits,
         }
     }
     pub fn sync_aggregate(&self) -> &SyncAggregate<SYNC_COMMITTEE_SIZE> {
@@ -1247,6 +1830,7 @@ impl<
             Self::Bellatrix(inner) => &inner.sync_aggregate,
             Self::Capella(inner) => &inner.sync_aggregate,
             Self::Deneb(inner) => &inner.sync_aggregate,
+            Self::Electra(inner) => &inner.sync_aggregate,
         }
     }
     pub fn sync_aggregate_mut(&mut self) -> &mut SyncAggregate<SYNC_COMMITTEE_SIZE> {
@@ -1254,6 +1838,7 @@ impl<
             Self::Bellatrix(inner) => &mut inner.sync_aggregate,
             Self::Capella(inner) => &mut inner.sync_aggregate,
             Self::Deneb(inner) => &mut inner.sync_aggregate,
+            Self::Electra(inner) => &mut inner.sync_aggregate,
         }
     }
     pub fn execution_payload_header(
@@ -1263,6 +1848,7 @@ impl<
             Self::Bellatrix(inner) => From::from(&inner.execution_payload_header),
             Self::Capella(inner) => From::from(&inner.execution_payload_header),
             Self::Deneb(inner) => From::from(&inner.execution_payload_header),
+            Self::Electra(inner) => From::from(&inner.execution_payload_header),
         }
     }
     pub fn execution_payload_header_mut(
@@ -1272,6 +1858,7 @@ impl<
             Self::Bellatrix(inner) => From::from(&mut inner.execution_payload_header),
             Self::Capella(inner) => From::from(&mut inner.execution_payload_header),
             Self::Deneb(inner) => From::from(&mut inner.execution_payload_header),
+            Self::Electra(inner) => From::from(&mut inner.execution_payload_header),
         }
     }
     pub fn bls_to_execution_changes(
@@ -1281,6 +1868,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&inner.bls_to_execution_changes),
             Self::Deneb(inner) => Some(&inner.bls_to_execution_changes),
+            Self::Electra(inner) => Some(&inner.bls_to_execution_changes),
         }
     }
     pub fn bls_to_execution_changes_mut(
@@ -1290,6 +1878,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(inner) => Some(&mut inner.bls_to_execution_changes),
             Self::Deneb(inner) => Some(&mut inner.bls_to_execution_changes),
+            Self::Electra(inner) => Some(&mut inner.bls_to_execution_changes),
         }
     }
     pub fn blob_kzg_commitments(
@@ -1299,6 +1888,7 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(inner) => Some(&inner.blob_kzg_commitments),
+            Self::Electra(inner) => Some(&inner.blob_kzg_commitments),
         }
     }
     pub fn blob_kzg_commitments_mut(
@@ -1308,6 +1898,93 @@ impl<
             Self::Bellatrix(_) => None,
             Self::Capella(_) => None,
             Self::Deneb(inner) => Some(&mut inner.blob_kzg_commitments),
+            Self::Electra(inner) => Some(&mut inner.blob_kzg_commitments),
+        }
+    }
+    pub fn attester_slashings_electra(
+        &self,
+    ) -> Option<
+        &List<AttesterSlashingElectra<MAX_VALIDATORS_PER_SLOT>, MAX_ATTESTER_SLASHINGS_ELECTRA>,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.attester_slashings),
+        }
+    }
+    pub fn attester_slashings_electra_mut(
+        &mut self,
+    ) -> Option<
+        &mut List<AttesterSlashingElectra<MAX_VALIDATORS_PER_SLOT>, MAX_ATTESTER_SLASHINGS_ELECTRA>,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.attester_slashings),
+        }
+    }
+    pub fn attestations_electra(
+        &self,
+    ) -> Option<
+        &List<
+            AttestationElectra<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+            MAX_ATTESTATIONS_ELECTRA,
+        >,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.attestations),
+        }
+    }
+    pub fn attestations_electra_mut(
+        &mut self,
+    ) -> Option<
+        &mut List<
+            AttestationElectra<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+            MAX_ATTESTATIONS_ELECTRA,
+        >,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.attestations),
+        }
+    }
+    pub fn execution_requests(
+        &self,
+    ) -> Option<
+        &ExecutionRequests<
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.execution_requests),
+        }
+    }
+    pub fn execution_requests_mut(
+        &mut self,
+    ) -> Option<
+        &mut ExecutionRequests<
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(_) => None,
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&mut inner.execution_requests),
         }
     }
 }
@@ -1324,6 +2001,13 @@ impl<
         const MAX_EXTRA_DATA_BYTES: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a mut bellatrix::BlindedBeaconBlockBody<
@@ -1351,6 +2035,13 @@ impl<
         MAX_EXTRA_DATA_BYTES,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -1382,6 +2073,13 @@ impl<
         const MAX_EXTRA_DATA_BYTES: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a mut capella::BlindedBeaconBlockBody<
@@ -1410,6 +2108,13 @@ impl<
         MAX_EXTRA_DATA_BYTES,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -1442,6 +2147,13 @@ impl<
         const MAX_EXTRA_DATA_BYTES: usize,
         const MAX_BLS_TO_EXECUTION_CHANGES: usize,
         const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
     >
     From<
         &'a mut deneb::BlindedBeaconBlockBody<
@@ -1471,6 +2183,13 @@ impl<
         MAX_EXTRA_DATA_BYTES,
         MAX_BLS_TO_EXECUTION_CHANGES,
         MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     >
 {
     fn from(
@@ -1491,3 +2210,87 @@ impl<
         Self::Deneb(value)
     }
 }
+impl<
+        'a,
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+        const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+    >
+    From<
+        &'a mut electra::BlindedBeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    >
+    for BlindedBeaconBlockBodyRefMut<
+        'a,
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >
+{
+    fn from(
+        value: &'a mut electra::BlindedBeaconBlockBody<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_SLOT,
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    ) -> Self {
+        Self::Electra(value)
+    }
+}
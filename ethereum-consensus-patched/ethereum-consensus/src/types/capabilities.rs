@@ -0,0 +1,211 @@
+//! Accessors for block body fields that are only present from a given fork onwards.
+//!
+//! See `Fork::supports_*` to query for the presence of each field ahead of time.
+use crate::{
+    altair::SyncAggregate,
+    capella::{SignedBlsToExecutionChange, Withdrawal},
+    deneb::polynomial_commitments::KzgCommitment,
+    electra::ExecutionRequests,
+    ssz::prelude::*,
+    types::{BeaconBlock, BeaconBlockBody},
+};
+
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+        const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+    >
+    BeaconBlockBody<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >
+{
+    pub fn withdrawals(&self) -> Option<&List<Withdrawal, MAX_WITHDRAWALS_PER_PAYLOAD>> {
+        match self {
+            Self::Phase0(_) | Self::Altair(_) | Self::Bellatrix(_) => None,
+            Self::Capella(inner) => Some(&inner.execution_payload.withdrawals),
+            Self::Deneb(inner) => Some(&inner.execution_payload.withdrawals),
+            Self::Electra(inner) => Some(&inner.execution_payload.withdrawals),
+        }
+    }
+}
+
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+        const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+    >
+    BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >
+{
+    pub fn sync_aggregate(&self) -> Option<&SyncAggregate<SYNC_COMMITTEE_SIZE>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(inner) => Some(&inner.body.sync_aggregate),
+            Self::Bellatrix(inner) => Some(&inner.body.sync_aggregate),
+            Self::Capella(inner) => Some(&inner.body.sync_aggregate),
+            Self::Deneb(inner) => Some(&inner.body.sync_aggregate),
+            Self::Electra(inner) => Some(&inner.body.sync_aggregate),
+        }
+    }
+
+    pub fn withdrawals(&self) -> Option<&List<Withdrawal, MAX_WITHDRAWALS_PER_PAYLOAD>> {
+        match self {
+            Self::Phase0(_) | Self::Altair(_) | Self::Bellatrix(_) => None,
+            Self::Capella(inner) => Some(&inner.body.execution_payload.withdrawals),
+            Self::Deneb(inner) => Some(&inner.body.execution_payload.withdrawals),
+            Self::Electra(inner) => Some(&inner.body.execution_payload.withdrawals),
+        }
+    }
+
+    pub fn bls_to_execution_changes(
+        &self,
+    ) -> Option<&List<SignedBlsToExecutionChange, MAX_BLS_TO_EXECUTION_CHANGES>> {
+        match self {
+            Self::Phase0(_) | Self::Altair(_) | Self::Bellatrix(_) => None,
+            Self::Capella(inner) => Some(&inner.body.bls_to_execution_changes),
+            Self::Deneb(inner) => Some(&inner.body.bls_to_execution_changes),
+            Self::Electra(inner) => Some(&inner.body.bls_to_execution_changes),
+        }
+    }
+
+    pub fn blob_kzg_commitments(
+        &self,
+    ) -> Option<&List<KzgCommitment, MAX_BLOB_COMMITMENTS_PER_BLOCK>> {
+        match self {
+            Self::Phase0(_) | Self::Altair(_) | Self::Bellatrix(_) | Self::Capella(_) => None,
+            Self::Deneb(inner) => Some(&inner.body.blob_kzg_commitments),
+            Self::Electra(inner) => Some(&inner.body.blob_kzg_commitments),
+        }
+    }
+
+    pub fn execution_requests(
+        &self,
+    ) -> Option<
+        &ExecutionRequests<
+            MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+        >,
+    > {
+        match self {
+            Self::Phase0(_) |
+            Self::Altair(_) |
+            Self::Bellatrix(_) |
+            Self::Capella(_) |
+            Self::Deneb(_) => None,
+            Self::Electra(inner) => Some(&inner.body.execution_requests),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::mainnet::{BeaconBlock, BeaconBlockBody},
+        Fork,
+    };
+
+    #[test]
+    fn test_accessors_match_capabilities() {
+        let blocks = [
+            BeaconBlock::Phase0(Default::default()),
+            BeaconBlock::Altair(Default::default()),
+            BeaconBlock::Bellatrix(Default::default()),
+            BeaconBlock::Capella(Default::default()),
+            BeaconBlock::Deneb(Default::default()),
+            BeaconBlock::Electra(Default::default()),
+        ];
+        for block in &blocks {
+            let fork: Fork = block.version();
+            assert_eq!(block.sync_aggregate().is_some(), fork.supports_sync_aggregate());
+            assert_eq!(block.withdrawals().is_some(), fork.supports_withdrawals());
+            assert_eq!(
+                block.bls_to_execution_changes().is_some(),
+                fork.supports_bls_to_execution_changes()
+            );
+            assert_eq!(block.blob_kzg_commitments().is_some(), fork.supports_blob_commitments());
+            assert_eq!(block.execution_requests().is_some(), fork.supports_execution_requests());
+        }
+
+        let body = BeaconBlockBody::Capella(Default::default());
+        assert!(body.withdrawals().is_some());
+        assert!(body.execution_payload().is_some());
+    }
+}
@@ -0,0 +1,117 @@
+//! Fork-aware decoding for signed blocks read from archives spanning several forks.
+//!
+//! The SSZ encoding of a block does not carry its fork, so the caller supplies it,
+//! e.g. from `Context::fork_for` on the slot the block was stored under.
+use crate::{
+    primitives::{Root, Slot, ValidatorIndex},
+    ssz::prelude::*,
+    types::SignedBeaconBlock,
+    Fork,
+};
+
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+        const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+        const MAX_ATTESTATIONS_ELECTRA: usize,
+        const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+    >
+    SignedBeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >
+{
+    pub fn deserialize_for_fork(fork: Fork, encoding: &[u8]) -> Result<Self, DeserializeError> {
+        let block = match fork {
+            Fork::Phase0 => Self::Phase0(Deserialize::deserialize(encoding)?),
+            Fork::Altair => Self::Altair(Deserialize::deserialize(encoding)?),
+            Fork::Bellatrix => Self::Bellatrix(Deserialize::deserialize(encoding)?),
+            Fork::Capella => Self::Capella(Deserialize::deserialize(encoding)?),
+            Fork::Deneb => Self::Deneb(Deserialize::deserialize(encoding)?),
+            Fork::Electra => Self::Electra(Deserialize::deserialize(encoding)?),
+        };
+        Ok(block)
+    }
+
+    pub fn slot(&self) -> Slot {
+        self.message().slot()
+    }
+
+    pub fn proposer_index(&self) -> ValidatorIndex {
+        self.message().proposer_index()
+    }
+
+    pub fn body_root(&self) -> Result<Root, MerkleizationError> {
+        match self {
+            Self::Phase0(inner) => inner.message.body.hash_tree_root(),
+            Self::Altair(inner) => inner.message.body.hash_tree_root(),
+            Self::Bellatrix(inner) => inner.message.body.hash_tree_root(),
+            Self::Capella(inner) => inner.message.body.hash_tree_root(),
+            Self::Deneb(inner) => inner.message.body.hash_tree_root(),
+            Self::Electra(inner) => inner.message.body.hash_tree_root(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{bellatrix, phase0, ssz::prelude::*, types::minimal::SignedBeaconBlock, Fork};
+
+    #[test]
+    fn test_deserialize_for_fork() {
+        let mut inner = bellatrix::minimal::SignedBeaconBlock::default();
+        inner.message.slot = 33;
+        inner.message.proposer_index = 7;
+        inner.message.body.graffiti[0] = 1;
+        let encoding = serialize(&inner).unwrap();
+
+        let block = SignedBeaconBlock::deserialize_for_fork(Fork::Bellatrix, &encoding).unwrap();
+        assert_eq!(block.version(), Fork::Bellatrix);
+        assert_eq!(block.slot(), 33);
+        assert_eq!(block.proposer_index(), 7);
+        assert_eq!(block.body_root().unwrap(), inner.message.body.hash_tree_root().unwrap());
+        assert_eq!(block.bellatrix(), Some(&inner));
+
+        assert!(SignedBeaconBlock::deserialize_for_fork(Fork::Capella, &encoding).is_err());
+
+        let inner = phase0::minimal::SignedBeaconBlock::default();
+        let encoding = serialize(&inner).unwrap();
+        let block = SignedBeaconBlock::deserialize_for_fork(Fork::Phase0, &encoding).unwrap();
+        assert_eq!(block.phase0(), Some(&inner));
+    }
+}
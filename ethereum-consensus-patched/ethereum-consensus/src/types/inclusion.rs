@@ -0,0 +1,400 @@
+//! Per-attestation statistics on how a block packs attestations, for analyzing proposer
+//! performance.
+use crate::{
+    altair::{add_flag, has_flag, PARTICIPATION_FLAG_WEIGHTS, PROPOSER_WEIGHT, WEIGHT_DENOMINATOR},
+    electra,
+    phase0::{compute_epoch_at_slot, Attestation, AttestationData},
+    primitives::{CommitteeIndex, Epoch, Gwei, ParticipationFlags, Slot, ValidatorIndex},
+    state_transition::{Context, Result},
+    types::{BeaconBlock, BeaconState},
+};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AttestationInclusion {
+    pub slot: Slot,
+    pub committee_index: CommitteeIndex,
+    pub inclusion_delay: Slot,
+    pub attester_count: usize,
+    /// Number of participation bits set for the first time by this attestation, taking into
+    /// account the state and any earlier attestations in the block. Before `altair` this is
+    /// the number of attesters not already included; from `altair` it counts each attester
+    /// and participation flag.
+    pub new_participation_bits: usize,
+    /// The part of the proposer reward earned by including this attestation.
+    pub proposer_reward: Gwei,
+}
+
+/// Compute the `AttestationInclusion` of each attestation in `block`, in block order.
+///
+/// `state` must be the state the block is applied to, advanced to the slot of the block.
+pub fn analyze_attestation_inclusion<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+    const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+    const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+    const MAX_ATTESTATIONS_ELECTRA: usize,
+    const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    block: &BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >,
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    context: &Context,
+) -> Result<Vec<AttestationInclusion>> {
+    let body = block.body();
+    let attestations =
+        body.attestations().map(|attestations| &attestations[..]).unwrap_or_default();
+    let attestations_electra =
+        body.attestations_electra().map(|attestations| &attestations[..]).unwrap_or_default();
+    let current_epoch = compute_epoch_at_slot(state.slot(), context);
+    match state {
+        BeaconState::Phase0(state) => {
+            // attesters already included in the state, keyed by whether the target is the
+            // current epoch
+            let mut included = [HashSet::new(), HashSet::new()];
+            let pending = state.previous_epoch_attestations.iter();
+            for attestation in pending.chain(state.current_epoch_attestations.iter()) {
+                let is_current = attestation.data.target.epoch == current_epoch;
+                included[is_current as usize].extend(crate::phase0::get_attesting_indices(
+                    state,
+                    &attestation.data,
+                    &attestation.aggregation_bits,
+                    context,
+                )?);
+            }
+            let mut stats = Vec::with_capacity(attestations.len());
+            for attestation in attestations.iter() {
+                let data = &attestation.data;
+                let is_current = data.target.epoch == current_epoch;
+                let attesting_indices = crate::phase0::get_attesting_indices(
+                    state,
+                    data,
+                    &attestation.aggregation_bits,
+                    context,
+                )?;
+                let mut entry = new_entry(attestation, state.slot);
+                for index in attesting_indices {
+                    if included[is_current as usize].insert(index) {
+                        entry.new_participation_bits += 1;
+                        entry.proposer_reward +=
+                            crate::phase0::get_proposer_reward(state, index, context)?;
+                    }
+                }
+                stats.push(entry);
+            }
+            Ok(stats)
+        }
+        BeaconState::Altair(state) => analyze_participation(
+            attestations,
+            state.slot,
+            current_epoch,
+            [&state.previous_epoch_participation, &state.current_epoch_participation],
+            |data, delay| {
+                crate::altair::get_attestation_participation_flag_indices(
+                    state, data, delay, context,
+                )
+            },
+            |attestation| {
+                crate::altair::get_attesting_indices(
+                    state,
+                    &attestation.data,
+                    &attestation.aggregation_bits,
+                    context,
+                )
+            },
+            |index| crate::altair::get_base_reward(state, index, context),
+        ),
+        BeaconState::Bellatrix(state) => analyze_participation(
+            attestations,
+            state.slot,
+            current_epoch,
+            [&state.previous_epoch_participation, &state.current_epoch_participation],
+            |data, delay| {
+                crate::bellatrix::get_attestation_participation_flag_indices(
+                    state, data, delay, context,
+                )
+            },
+            |attestation| {
+                crate::bellatrix::get_attesting_indices(
+                    state,
+                    &attestation.data,
+                    &attestation.aggregation_bits,
+                    context,
+                )
+            },
+            |index| crate::bellatrix::get_base_reward(state, index, context),
+        ),
+        BeaconState::Capella(state) => analyze_participation(
+            attestations,
+            state.slot,
+            current_epoch,
+            [&state.previous_epoch_participation, &state.current_epoch_participation],
+            |data, delay| {
+                crate::capella::get_attestation_participation_flag_indices(
+                    state, data, delay, context,
+                )
+            },
+            |attestation| {
+                crate::capella::get_attesting_indices(
+                    state,
+                    &attestation.data,
+                    &attestation.aggregation_bits,
+                    context,
+                )
+            },
+            |index| crate::capella::get_base_reward(state, index, context),
+        ),
+        BeaconState::Deneb(state) => analyze_participation(
+            attestations,
+            state.slot,
+            current_epoch,
+            [&state.previous_epoch_participation, &state.current_epoch_participation],
+            |data, delay| {
+                crate::deneb::get_attestation_participation_flag_indices(
+                    state, data, delay, context,
+                )
+            },
+            |attestation| {
+                crate::deneb::get_attesting_indices(
+                    state,
+                    &attestation.data,
+                    &attestation.aggregation_bits,
+                    context,
+                )
+            },
+            |index| crate::deneb::get_base_reward(state, index, context),
+        ),
+        BeaconState::Electra(state) => analyze_participation(
+            attestations_electra,
+            state.slot,
+            current_epoch,
+            [&state.previous_epoch_participation, &state.current_epoch_participation],
+            |data, delay| {
+                electra::get_attestation_participation_flag_indices(state, data, delay, context)
+            },
+            |attestation| electra::get_attesting_indices(state, attestation, context),
+            |index| electra::get_base_reward(state, index, context),
+        ),
+    }
+}
+
+// The parts of an attestation the statistics are computed from, as `electra` moves the
+// committee index out of the `AttestationData`.
+trait IncludedAttestation {
+    fn data(&self) -> &AttestationData;
+    fn committee_index(&self) -> CommitteeIndex;
+    fn attester_count(&self) -> usize;
+}
+
+impl<const MAX_VALIDATORS_PER_COMMITTEE: usize> IncludedAttestation
+    for Attestation<MAX_VALIDATORS_PER_COMMITTEE>
+{
+    fn data(&self) -> &AttestationData {
+        &self.data
+    }
+
+    fn committee_index(&self) -> CommitteeIndex {
+        self.data.index
+    }
+
+    fn attester_count(&self) -> usize {
+        self.aggregation_bits.count_ones()
+    }
+}
+
+// An aggregate spanning several committees is reported under the first of them.
+impl<const MAX_VALIDATORS_PER_SLOT: usize, const MAX_COMMITTEES_PER_SLOT: usize> IncludedAttestation
+    for electra::Attestation<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>
+{
+    fn data(&self) -> &AttestationData {
+        &self.data
+    }
+
+    fn committee_index(&self) -> CommitteeIndex {
+        electra::get_committee_indices(&self.committee_bits).first().copied().unwrap_or_default()
+    }
+
+    fn attester_count(&self) -> usize {
+        self.aggregation_bits.count_ones()
+    }
+}
+
+fn new_entry(attestation: &impl IncludedAttestation, state_slot: Slot) -> AttestationInclusion {
+    let data = attestation.data();
+    AttestationInclusion {
+        slot: data.slot,
+        committee_index: attestation.committee_index(),
+        inclusion_delay: state_slot.saturating_sub(data.slot),
+        attester_count: attestation.attester_count(),
+        new_participation_bits: 0,
+        proposer_reward: 0,
+    }
+}
+
+// Mirrors the participation updates and proposer reward of `process_attestation` from
+// `altair` onwards, over a copy of the participation of the previous and current epochs.
+fn analyze_participation<A: IncludedAttestation>(
+    attestations: &[A],
+    state_slot: Slot,
+    current_epoch: Epoch,
+    participation: [&[ParticipationFlags]; 2],
+    get_participation_flag_indices: impl Fn(&AttestationData, Slot) -> Result<Vec<usize>>,
+    get_attesting_indices: impl Fn(&A) -> Result<HashSet<ValidatorIndex>>,
+    get_base_reward: impl Fn(ValidatorIndex) -> Result<Gwei>,
+) -> Result<Vec<AttestationInclusion>> {
+    let mut participation = participation.map(|flags| flags.to_vec());
+    let proposer_reward_denominator =
+        (WEIGHT_DENOMINATOR - PROPOSER_WEIGHT) * WEIGHT_DENOMINATOR / PROPOSER_WEIGHT;
+    let mut stats = Vec::with_capacity(attestations.len());
+    for attestation in attestations {
+        let data = attestation.data();
+        let mut entry = new_entry(attestation, state_slot);
+        let flag_indices = get_participation_flag_indices(data, entry.inclusion_delay)?;
+        let epoch_participation = &mut participation[(data.target.epoch == current_epoch) as usize];
+        let mut proposer_reward_numerator = 0;
+        for index in get_attesting_indices(attestation)? {
+            for (flag_index, weight) in PARTICIPATION_FLAG_WEIGHTS.iter().enumerate() {
+                if flag_indices.contains(&flag_index) &&
+                    !has_flag(epoch_participation[index], flag_index)
+                {
+                    epoch_participation[index] = add_flag(epoch_participation[index], flag_index);
+                    entry.new_participation_bits += 1;
+                    proposer_reward_numerator += get_base_reward(index)? * weight;
+                }
+            }
+        }
+        entry.proposer_reward = proposer_reward_numerator / proposer_reward_denominator;
+        stats.push(entry);
+    }
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        phase0::Validator,
+        primitives::{BlsPublicKey, FAR_FUTURE_EPOCH},
+        ssz::prelude::*,
+        types::minimal,
+    };
+
+    fn validator(seed: u8, context: &Context) -> Validator {
+        let mut public_key = BlsPublicKey::default();
+        public_key[0] = seed;
+        Validator {
+            public_key,
+            effective_balance: context.max_effective_balance,
+            exit_epoch: FAR_FUTURE_EPOCH,
+            withdrawable_epoch: FAR_FUTURE_EPOCH,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_attestation_inclusion() {
+        let context = Context::for_minimal();
+        let mut state = crate::altair::minimal::BeaconState::default();
+        for i in 0..64 {
+            state.validators.push(validator(i, &context)).unwrap();
+            state.balances.push(context.max_effective_balance).unwrap();
+            state.previous_epoch_participation.push(0).unwrap();
+            state.current_epoch_participation.push(0).unwrap();
+        }
+        state.slot = 2 * context.slots_per_epoch + 3;
+
+        let slot = state.slot - 1;
+        let committee = crate::altair::get_beacon_committee(&state, slot, 0, &context).unwrap();
+        let mut aggregation_bits =
+            Bitlist::try_from(vec![false; committee.len()].as_slice()).unwrap();
+        aggregation_bits.set(0, true);
+        aggregation_bits.set(1, true);
+        let mut attestation = Attestation {
+            aggregation_bits,
+            data: AttestationData { slot, index: 0, ..Default::default() },
+            ..Default::default()
+        };
+        attestation.data.target.epoch = 2;
+
+        let mut block =
+            crate::altair::minimal::BeaconBlock { slot: state.slot, ..Default::default() };
+        block.body.attestations.push(attestation.clone()).unwrap();
+        attestation.aggregation_bits.set(2, true);
+        block.body.attestations.push(attestation).unwrap();
+
+        let block = minimal::BeaconBlock::Altair(block);
+        let state = minimal::BeaconState::Altair(state);
+        let stats = analyze_attestation_inclusion(&block, &state, &context).unwrap();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].inclusion_delay, 1);
+        assert_eq!(stats[0].attester_count, 2);
+        // timely source, target and head for each attester
+        assert_eq!(stats[0].new_participation_bits, 6);
+        assert!(stats[0].proposer_reward > 0);
+        // only the third attester is new
+        assert_eq!(stats[1].attester_count, 3);
+        assert_eq!(stats[1].new_participation_bits, 3);
+        assert_eq!(stats[1].proposer_reward * 2, stats[0].proposer_reward);
+    }
+}
@@ -0,0 +1,387 @@
+//! A journal of the mutations made to the validator registry of a `BeaconState`.
+//!
+//! Routing balance changes, validator updates and registry appends through a `StateJournal`
+//! records the value each one overwrote. From that single record the changes can be rolled
+//! back, summarized as a diff, or used to find which parts of the state must be re-hashed.
+use crate::{
+    error::Error,
+    phase0::Validator,
+    primitives::{Gwei, ValidatorIndex},
+    state_transition::Result,
+    types::BeaconState,
+};
+use std::collections::{btree_map::Entry, BTreeMap, BTreeSet};
+
+/// A single change to the state, holding what is needed to undo it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mutation {
+    Balance {
+        index: ValidatorIndex,
+        previous: Gwei,
+    },
+    Validator {
+        index: ValidatorIndex,
+        previous: Validator,
+    },
+    /// A new validator was appended to the registry at `index`.
+    Append {
+        index: ValidatorIndex,
+    },
+}
+
+/// The value of some part of the state before and after a set of mutations. `previous` is
+/// `None` for values appended to the state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change<T> {
+    pub previous: Option<T>,
+    pub current: T,
+}
+
+/// The net effect of the mutations in a `StateJournal`. Values written back to what they
+/// were originally are omitted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    pub balances: BTreeMap<ValidatorIndex, Change<Gwei>>,
+    pub validators: BTreeMap<ValidatorIndex, Change<Validator>>,
+}
+
+impl StateDiff {
+    pub fn is_empty(&self) -> bool {
+        self.balances.is_empty() && self.validators.is_empty()
+    }
+}
+
+/// Wraps a `BeaconState` so that each mutation made through it is recorded.
+///
+/// Dropping the journal keeps the mutations made; call `rollback` to revert them.
+pub struct StateJournal<
+    'a,
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+> {
+    state: &'a mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    mutations: Vec<Mutation>,
+}
+
+impl<
+        'a,
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const PENDING_ATTESTATIONS_BOUND: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    >
+    StateJournal<
+        'a,
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >
+{
+    pub fn new(
+        state: &'a mut BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            PENDING_ATTESTATIONS_BOUND,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            PENDING_DEPOSITS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            PENDING_CONSOLIDATIONS_LIMIT,
+        >,
+    ) -> Self {
+        Self { state, mutations: Vec::new() }
+    }
+
+    pub fn state(
+        &self,
+    ) -> &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    > {
+        self.state
+    }
+
+    /// The mutations recorded so far, in the order they were made.
+    pub fn mutations(&self) -> &[Mutation] {
+        &self.mutations
+    }
+
+    pub fn set_balance(&mut self, index: ValidatorIndex, balance: Gwei) -> Result<()> {
+        let balances = self.state.balances_mut();
+        if index >= balances.len() {
+            return Err(Error::OutOfBounds { requested: index, bound: balances.len() })
+        }
+        let previous = std::mem::replace(&mut balances[index], balance);
+        self.mutations.push(Mutation::Balance { index, previous });
+        Ok(())
+    }
+
+    pub fn increase_balance(&mut self, index: ValidatorIndex, delta: Gwei) -> Result<()> {
+        let balance = self.balance(index)?;
+        self.set_balance(index, balance.checked_add(delta).ok_or(Error::Overflow)?)
+    }
+
+    /// Decrease the balance at `index` by `delta`, saturating at zero as `decrease_balance`
+    /// does in the spec.
+    pub fn decrease_balance(&mut self, index: ValidatorIndex, delta: Gwei) -> Result<()> {
+        let balance = self.balance(index)?;
+        self.set_balance(index, balance.saturating_sub(delta))
+    }
+
+    /// Apply `f` to the validator at `index`.
+    pub fn update_validator(
+        &mut self,
+        index: ValidatorIndex,
+        f: impl FnOnce(&mut Validator),
+    ) -> Result<()> {
+        let validators = self.state.validators_mut();
+        if index >= validators.len() {
+            return Err(Error::OutOfBounds { requested: index, bound: validators.len() })
+        }
+        let previous = validators[index].clone();
+        f(&mut validators[index]);
+        self.mutations.push(Mutation::Validator { index, previous });
+        Ok(())
+    }
+
+    /// Append `validator` to the registry with the given `balance`, along with the default
+    /// entries in any other per-validator lists of the state. Returns the index of the new
+    /// validator, or fails if the registry is full.
+    pub fn push_validator(
+        &mut self,
+        validator: Validator,
+        balance: Gwei,
+    ) -> Result<ValidatorIndex> {
+        let index = self.state.validators().len();
+        self.state.validators_mut().push(validator)?;
+        self.state.balances_mut().push(balance)?;
+        if let Some(participation) = self.state.previous_epoch_participation_mut() {
+            participation.push(Default::default())?;
+        }
+        if let Some(participation) = self.state.current_epoch_participation_mut() {
+            participation.push(Default::default())?;
+        }
+        if let Some(scores) = self.state.inactivity_scores_mut() {
+            scores.push(0)?;
+        }
+        self.mutations.push(Mutation::Append { index });
+        Ok(index)
+    }
+
+    /// Indices of the validators whose record or balance has been touched, e.g. to mark
+    /// the corresponding leaves of the registry as needing to be re-hashed.
+    pub fn touched_indices(&self) -> BTreeSet<ValidatorIndex> {
+        self.mutations
+            .iter()
+            .map(|mutation| match mutation {
+                Mutation::Balance { index, .. } |
+                Mutation::Validator { index, .. } |
+                Mutation::Append { index } => *index,
+            })
+            .collect()
+    }
+
+    /// Summarize the net effect of the recorded mutations.
+    pub fn diff(&self) -> StateDiff {
+        let mut balances = BTreeMap::new();
+        let mut validators = BTreeMap::new();
+        // only the first mutation of each value holds its original
+        for mutation in &self.mutations {
+            match mutation {
+                Mutation::Balance { index, previous } => {
+                    balances.entry(*index).or_insert(Some(*previous));
+                }
+                Mutation::Validator { index, previous } => {
+                    if let Entry::Vacant(entry) = validators.entry(*index) {
+                        entry.insert(Some(previous.clone()));
+                    }
+                }
+                Mutation::Append { index } => {
+                    balances.entry(*index).or_insert(None);
+                    validators.entry(*index).or_insert(None);
+                }
+            }
+        }
+
+        let state_balances = self.state.balances();
+        let state_validators = self.state.validators();
+        StateDiff {
+            balances: balances
+                .into_iter()
+                .map(|(index, previous)| {
+                    (index, Change { previous, current: state_balances[index] })
+                })
+                .filter(|(_, change)| change.previous != Some(change.current))
+                .collect(),
+            validators: validators
+                .into_iter()
+                .map(|(index, previous)| {
+                    (index, Change { previous, current: state_validators[index].clone() })
+                })
+                .filter(|(_, change)| change.previous.as_ref() != Some(&change.current))
+                .collect(),
+        }
+    }
+
+    /// Revert every recorded mutation, leaving the state as it was when the journal was
+    /// created.
+    pub fn rollback(mut self) {
+        while let Some(mutation) = self.mutations.pop() {
+            match mutation {
+                Mutation::Balance { index, previous } => {
+                    self.state.balances_mut()[index] = previous;
+                }
+                Mutation::Validator { index, previous } => {
+                    self.state.validators_mut()[index] = previous;
+                }
+                Mutation::Append { .. } => {
+                    self.state.validators_mut().pop();
+                    self.state.balances_mut().pop();
+                    if let Some(participation) = self.state.previous_epoch_participation_mut() {
+                        participation.pop();
+                    }
+                    if let Some(participation) = self.state.current_epoch_participation_mut() {
+                        participation.pop();
+                    }
+                    if let Some(scores) = self.state.inactivity_scores_mut() {
+                        scores.pop();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Keep the mutations made, returning the record of them.
+    pub fn commit(self) -> Vec<Mutation> {
+        self.mutations
+    }
+
+    fn balance(&self, index: ValidatorIndex) -> Result<Gwei> {
+        let balances = self.state.balances();
+        balances
+            .get(index)
+            .copied()
+            .ok_or(Error::OutOfBounds { requested: index, bound: balances.len() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{primitives::FAR_FUTURE_EPOCH, state_transition::Context, types::minimal};
+
+    #[test]
+    fn test_journal_diff_and_rollback() {
+        let context = Context::for_minimal();
+        let mut inner = crate::altair::minimal::BeaconState::default();
+        for _ in 0..4 {
+            inner
+                .validators
+                .push(Validator {
+                    effective_balance: context.max_effective_balance,
+                    exit_epoch: FAR_FUTURE_EPOCH,
+                    withdrawable_epoch: FAR_FUTURE_EPOCH,
+                    ..Default::default()
+                })
+                .unwrap();
+            inner.balances.push(context.max_effective_balance).unwrap();
+            inner.previous_epoch_participation.push(0).unwrap();
+            inner.current_epoch_participation.push(0).unwrap();
+            inner.inactivity_scores.push(0).unwrap();
+        }
+        let mut state = minimal::BeaconState::Altair(inner);
+        let original = state.clone();
+
+        let mut journal = StateJournal::new(&mut state);
+        journal.increase_balance(0, 5).unwrap();
+        journal.decrease_balance(1, context.max_effective_balance + 1).unwrap();
+        journal.increase_balance(2, 7).unwrap();
+        journal.decrease_balance(2, 7).unwrap();
+        journal.update_validator(3, |validator| validator.slashed = true).unwrap();
+        let index = journal.push_validator(Validator::default(), 1).unwrap();
+        assert_eq!(index, 4);
+        assert!(journal.increase_balance(5, 1).is_err());
+
+        assert_eq!(journal.touched_indices(), BTreeSet::from([0, 1, 2, 3, 4]));
+        let diff = journal.diff();
+        assert_eq!(diff.balances.keys().copied().collect::<Vec<_>>(), vec![0, 1, 4]);
+        assert_eq!(diff.balances[&1], Change { previous: Some(32_000_000_000), current: 0 });
+        assert_eq!(diff.balances[&4], Change { previous: None, current: 1 });
+        assert_eq!(diff.validators.keys().copied().collect::<Vec<_>>(), vec![3, 4]);
+        assert!(diff.validators[&3].current.slashed);
+
+        journal.rollback();
+        assert_eq!(state, original);
+
+        let mut journal = StateJournal::new(&mut state);
+        journal.set_balance(0, 1).unwrap();
+        assert_eq!(journal.commit().len(), 1);
+        assert_eq!(state.balances()[0], 1);
+    }
+}
@@ -9,11 +9,17 @@ mod beacon_block_body;
 mod beacon_state;
 mod blinded_beacon_block;
 mod blinded_beacon_block_body;
+mod capabilities;
+mod decoding;
 mod execution_payload;
 mod execution_payload_header;
+#[cfg(feature = "transition")]
+mod inclusion;
+mod journal;
 mod presets;
 mod signed_beacon_block;
 mod signed_blinded_beacon_block;
+mod summary;
 
 pub use beacon_block::*;
 pub use beacon_block_body::*;
@@ -22,7 +28,11 @@ pub use blinded_beacon_block::*;
 pub use blinded_beacon_block_body::*;
 pub use execution_payload::*;
 pub use execution_payload_header::*;
+#[cfg(feature = "transition")]
+pub use inclusion::*;
+pub use journal::*;
 pub use signed_beacon_block::*;
 pub use signed_blinded_beacon_block::*;
+pub use summary::*;
 
-pub use presets::{mainnet, minimal};
+pub use presets::{gnosis, mainnet, minimal};
@@ -6,6 +6,12 @@ use crate::{
     },
     capella::mainnet::{MAX_BLS_TO_EXECUTION_CHANGES, MAX_WITHDRAWALS_PER_PAYLOAD},
     deneb::mainnet::MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    electra::mainnet::{
+        MAX_ATTESTATIONS_ELECTRA, MAX_ATTESTER_SLASHINGS_ELECTRA, MAX_COMMITTEES_PER_SLOT,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD, MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_VALIDATORS_PER_SLOT, MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD, PENDING_CONSOLIDATIONS_LIMIT,
+        PENDING_DEPOSITS_LIMIT, PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+    },
     phase0::mainnet::{
         EPOCHS_PER_HISTORICAL_VECTOR, EPOCHS_PER_SLASHINGS_VECTOR, ETH1_DATA_VOTES_BOUND,
         HISTORICAL_ROOTS_LIMIT, MAX_ATTESTATIONS, MAX_ATTESTER_SLASHINGS, MAX_DEPOSITS,
@@ -30,6 +36,13 @@ pub type BeaconBlockBody = types::BeaconBlockBody<
     MAX_WITHDRAWALS_PER_PAYLOAD,
     MAX_BLS_TO_EXECUTION_CHANGES,
     MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    MAX_VALIDATORS_PER_SLOT,
+    MAX_COMMITTEES_PER_SLOT,
+    MAX_ATTESTER_SLASHINGS_ELECTRA,
+    MAX_ATTESTATIONS_ELECTRA,
+    MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+    MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
 >;
 
 pub type BeaconBlock = types::BeaconBlock<
@@ -47,6 +60,13 @@ pub type BeaconBlock = types::BeaconBlock<
     MAX_WITHDRAWALS_PER_PAYLOAD,
     MAX_BLS_TO_EXECUTION_CHANGES,
     MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    MAX_VALIDATORS_PER_SLOT,
+    MAX_COMMITTEES_PER_SLOT,
+    MAX_ATTESTER_SLASHINGS_ELECTRA,
+    MAX_ATTESTATIONS_ELECTRA,
+    MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+    MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
 >;
 
 pub type SignedBeaconBlock = types::SignedBeaconBlock<
@@ -64,6 +84,13 @@ pub type SignedBeaconBlock = types::SignedBeaconBlock<
     MAX_WITHDRAWALS_PER_PAYLOAD,
     MAX_BLS_TO_EXECUTION_CHANGES,
     MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    MAX_VALIDATORS_PER_SLOT,
+    MAX_COMMITTEES_PER_SLOT,
+    MAX_ATTESTER_SLASHINGS_ELECTRA,
+    MAX_ATTESTATIONS_ELECTRA,
+    MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+    MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
 >;
 
 pub type BlindedBeaconBlockBody = types::BlindedBeaconBlockBody<
@@ -78,6 +105,13 @@ pub type BlindedBeaconBlockBody = types::BlindedBeaconBlockBody<
     MAX_EXTRA_DATA_BYTES,
     MAX_BLS_TO_EXECUTION_CHANGES,
     MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    MAX_VALIDATORS_PER_SLOT,
+    MAX_COMMITTEES_PER_SLOT,
+    MAX_ATTESTER_SLASHINGS_ELECTRA,
+    MAX_ATTESTATIONS_ELECTRA,
+    MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+    MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
 >;
 
 pub type BlindedBeaconBlock = types::BlindedBeaconBlock<
@@ -92,6 +126,13 @@ pub type BlindedBeaconBlock = types::BlindedBeaconBlock<
     MAX_EXTRA_DATA_BYTES,
     MAX_BLS_TO_EXECUTION_CHANGES,
     MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    MAX_VALIDATORS_PER_SLOT,
+    MAX_COMMITTEES_PER_SLOT,
+    MAX_ATTESTER_SLASHINGS_ELECTRA,
+    MAX_ATTESTATIONS_ELECTRA,
+    MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+    MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
 >;
 
 pub type SignedBlindedBeaconBlock = types::SignedBlindedBeaconBlock<
@@ -106,6 +147,13 @@ pub type SignedBlindedBeaconBlock = types::SignedBlindedBeaconBlock<
     MAX_EXTRA_DATA_BYTES,
     MAX_BLS_TO_EXECUTION_CHANGES,
     MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    MAX_VALIDATORS_PER_SLOT,
+    MAX_COMMITTEES_PER_SLOT,
+    MAX_ATTESTER_SLASHINGS_ELECTRA,
+    MAX_ATTESTATIONS_ELECTRA,
+    MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+    MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
 >;
 
 pub type ExecutionPayload = types::ExecutionPayload<
@@ -134,4 +182,7 @@ pub type BeaconState = types::BeaconState<
     SYNC_COMMITTEE_SIZE,
     BYTES_PER_LOGS_BLOOM,
     MAX_EXTRA_DATA_BYTES,
+    PENDING_DEPOSITS_LIMIT,
+    PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+    PENDING_CONSOLIDATIONS_LIMIT,
 >;
@@ -0,0 +1,221 @@
+//! A fork-agnostic summary of a block, for consumers like block explorers and indexers.
+use crate::{
+    primitives::{BlsPublicKey, Bytes32, Gwei, Root, Slot, ValidatorIndex},
+    types::{BeaconState, SignedBeaconBlock},
+    Fork as Version,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BlockSummary {
+    pub version: Version,
+    pub slot: Slot,
+    pub proposer_index: ValidatorIndex,
+    /// The public key of the proposer, if they are present in the provided state.
+    pub proposer_public_key: Option<BlsPublicKey>,
+    pub parent_root: Root,
+    pub state_root: Root,
+    pub graffiti: String,
+    pub attestation_count: usize,
+    pub deposit_count: usize,
+    pub voluntary_exit_count: usize,
+    pub proposer_slashing_count: usize,
+    pub attester_slashing_count: usize,
+    /// Number of sync committee members participating in the block's sync aggregate.
+    pub sync_committee_participants: Option<usize>,
+    pub execution_block_number: Option<u64>,
+    pub transaction_count: Option<usize>,
+    pub gas_used: Option<u64>,
+    pub gas_limit: Option<u64>,
+    pub withdrawals_total: Option<Gwei>,
+    pub bls_to_execution_change_count: Option<usize>,
+    pub blob_count: Option<usize>,
+}
+
+/// Interpret `graffiti` as a UTF-8 string, with any trailing zero bytes removed.
+pub fn graffiti_to_string(graffiti: &Bytes32) -> String {
+    let bytes: &[u8] = graffiti.as_ref();
+    let end = bytes.iter().rposition(|&b| b != 0).map(|i| i + 1).unwrap_or_default();
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+/// Extract a `BlockSummary` from `signed_block`, using `state` to resolve details about
+/// the proposer. Fields that do not exist for the block's fork are set to `None`.
+pub fn summarize_block<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+    const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+    const MAX_ATTESTER_SLASHINGS_ELECTRA: usize,
+    const MAX_ATTESTATIONS_ELECTRA: usize,
+    const MAX_DEPOSIT_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+    const PENDING_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    signed_block: &SignedBeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS_ELECTRA,
+        MAX_ATTESTATIONS_ELECTRA,
+        MAX_DEPOSIT_REQUESTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >,
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+) -> BlockSummary {
+    let block = signed_block.message();
+    let body = block.body();
+    let proposer_index = block.proposer_index();
+    let execution_payload = body.execution_payload();
+    let withdrawals_total = execution_payload
+        .as_ref()
+        .and_then(|payload| payload.withdrawals())
+        .map(|withdrawals| withdrawals.iter().map(|withdrawal| withdrawal.amount).sum());
+
+    BlockSummary {
+        version: signed_block.version(),
+        slot: block.slot(),
+        proposer_index,
+        proposer_public_key: state
+            .validators()
+            .get(proposer_index)
+            .map(|validator| validator.public_key.clone()),
+        parent_root: block.parent_root(),
+        state_root: block.state_root(),
+        graffiti: graffiti_to_string(body.graffiti()),
+        attestation_count: body
+            .attestations()
+            .map(|attestations| attestations.len())
+            .or_else(|| body.attestations_electra().map(|attestations| attestations.len()))
+            .unwrap_or_default(),
+        deposit_count: body.deposits().len(),
+        voluntary_exit_count: body.voluntary_exits().len(),
+        proposer_slashing_count: body.proposer_slashings().len(),
+        attester_slashing_count: body
+            .attester_slashings()
+            .map(|slashings| slashings.len())
+            .or_else(|| body.attester_slashings_electra().map(|slashings| slashings.len()))
+            .unwrap_or_default(),
+        sync_committee_participants: body
+            .sync_aggregate()
+            .map(|aggregate| aggregate.sync_committee_bits.count_ones()),
+        execution_block_number: execution_payload.as_ref().map(|payload| payload.block_number()),
+        transaction_count: execution_payload.as_ref().map(|payload| payload.transactions().len()),
+        gas_used: execution_payload.as_ref().map(|payload| payload.gas_used()),
+        gas_limit: execution_payload.as_ref().map(|payload| payload.gas_limit()),
+        withdrawals_total,
+        bls_to_execution_change_count: body.bls_to_execution_changes().map(|changes| changes.len()),
+        blob_count: body.blob_kzg_commitments().map(|commitments| commitments.len()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        capella::Withdrawal,
+        phase0::Validator,
+        types::mainnet::{BeaconState, SignedBeaconBlock},
+    };
+
+    #[test]
+    fn test_summarize_block() {
+        let mut inner = crate::capella::mainnet::SignedBeaconBlock::default();
+        inner.message.slot = 32;
+        inner.message.proposer_index = 1;
+        inner.message.body.graffiti[..5].copy_from_slice(b"hello");
+        inner.message.body.execution_payload.gas_used = 21_000;
+        for amount in [10, 20] {
+            inner
+                .message
+                .body
+                .execution_payload
+                .withdrawals
+                .push(Withdrawal { amount, ..Default::default() })
+                .unwrap();
+        }
+        let signed_block = SignedBeaconBlock::Capella(inner);
+
+        let mut state = crate::capella::mainnet::BeaconState::default();
+        let validator = Validator {
+            public_key: BlsPublicKey::try_from([1u8; 48].as_ref()).unwrap(),
+            ..Default::default()
+        };
+        state.validators.push(Validator::default()).unwrap();
+        state.validators.push(validator.clone()).unwrap();
+        let state = BeaconState::Capella(state);
+
+        let summary = summarize_block(&signed_block, &state);
+        assert_eq!(summary.version, Version::Capella);
+        assert_eq!(summary.slot, 32);
+        assert_eq!(summary.proposer_public_key, Some(validator.public_key));
+        assert_eq!(summary.graffiti, "hello");
+        assert_eq!(summary.gas_used, Some(21_000));
+        assert_eq!(summary.transaction_count, Some(0));
+        assert_eq!(summary.withdrawals_total, Some(30));
+        assert_eq!(summary.bls_to_execution_change_count, Some(0));
+        assert_eq!(summary.blob_count, None);
+    }
+
+    #[test]
+    fn test_summarize_phase0_block() {
+        let signed_block = SignedBeaconBlock::Phase0(Default::default());
+        let state = BeaconState::Phase0(Default::default());
+        let summary = summarize_block(&signed_block, &state);
+        assert_eq!(summary.graffiti, "");
+        assert!(summary.proposer_public_key.is_none());
+        assert!(summary.sync_committee_participants.is_none());
+        assert!(summary.gas_used.is_none());
+        assert!(summary.withdrawals_total.is_none());
+    }
+}
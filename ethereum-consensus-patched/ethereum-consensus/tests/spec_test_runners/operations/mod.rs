@@ -377,9 +377,9 @@ where
         Self { pre, post, operation, execution_validity, config }
     }
 
-    pub fn execute<F>(&mut self, f: F)
+    pub fn execute<F, R>(&mut self, f: F)
     where
-        F: FnOnce(&mut S, &mut T, &Context, bool) -> Result<()>,
+        F: FnOnce(&mut S, &mut T, &Context, bool) -> Result<R>,
     {
         let context = match self.config {
             Config::Minimal => Context::for_minimal(),
@@ -8,7 +8,7 @@ use std::{
     path::PathBuf,
     rc::Rc,
 };
-use syn::{parse_quote, visit_mut::VisitMut, Ident, Item};
+use syn::{parse_quote, visit_mut::VisitMut, Attribute, Ident, Item};
 
 const SOURCE_ROOT: &str = "ethereum-consensus/src";
 
@@ -20,6 +20,7 @@ pub enum Fork {
     Bellatrix,
     Capella,
     Deneb,
+    Electra,
 }
 
 impl Fork {
@@ -78,6 +79,7 @@ impl Fork {
                 "execution_payload",
                 "genesis",
                 "helpers",
+                "light_client",
                 "withdrawal",
             ],
             Self::Deneb => &[
@@ -92,6 +94,21 @@ impl Fork {
                 "execution_payload",
                 "genesis",
                 "helpers",
+                "light_client",
+            ],
+            Self::Electra => &[
+                "beacon_block",
+                "beacon_state",
+                "blinded_beacon_block",
+                "block_processing",
+                "constants",
+                "epoch_processing",
+                "execution_engine",
+                "genesis",
+                "helpers",
+                "light_client",
+                "operations",
+                "validator",
             ],
         }
     }
@@ -130,6 +147,7 @@ impl Fork {
                     use crate::ssz::prelude::*;
                     use crate::crypto::{hash, fast_aggregate_verify};
 
+                    #[cfg(feature = "transition")]
                     pub use crate::altair::fork::upgrade_to_altair;
                 };
                 fragment.items
@@ -137,13 +155,17 @@ impl Fork {
             Fork::Bellatrix => {
                 let fragment: syn::File = parse_quote! {
                     use std::cmp;
-                    use std::mem;
-                    use std::collections::{HashSet, HashMap};
-                    use std::iter::zip;
+                    use std::collections::HashSet;
                     use crate::ssz::prelude::*;
                     use integer_sqrt::IntegerSquareRoot;
-                    use crate::crypto::{hash, fast_aggregate_verify, eth_aggregate_public_keys, eth_fast_aggregate_verify};
+                    use crate::crypto::{hash, fast_aggregate_verify, eth_aggregate_public_keys};
 
+                    #[cfg(feature = "transition")]
+                    use std::{mem, collections::HashMap, iter::zip};
+                    #[cfg(feature = "transition")]
+                    use crate::crypto::eth_fast_aggregate_verify;
+
+                    #[cfg(feature = "transition")]
                     pub use crate::bellatrix::fork::upgrade_to_bellatrix;
                 };
                 fragment.items
@@ -151,13 +173,17 @@ impl Fork {
             Fork::Capella => {
                 let fragment: syn::File = parse_quote! {
                     use std::cmp;
-                    use std::mem;
-                    use std::collections::{HashSet, HashMap};
-                    use std::iter::zip;
+                    use std::collections::HashSet;
                     use crate::ssz::prelude::*;
                     use integer_sqrt::IntegerSquareRoot;
-                    use crate::crypto::{hash, fast_aggregate_verify, eth_aggregate_public_keys, eth_fast_aggregate_verify};
+                    use crate::crypto::{hash, fast_aggregate_verify, eth_aggregate_public_keys};
+
+                    #[cfg(feature = "transition")]
+                    use std::{mem, collections::HashMap, iter::zip};
+                    #[cfg(feature = "transition")]
+                    use crate::crypto::eth_fast_aggregate_verify;
 
+                    #[cfg(feature = "transition")]
                     pub use crate::capella::fork::upgrade_to_capella;
                 };
                 fragment.items
@@ -165,17 +191,41 @@ impl Fork {
             Fork::Deneb => {
                 let fragment: syn::File = parse_quote! {
                     use std::cmp;
-                    use std::mem;
-                    use std::collections::{HashSet, HashMap};
-                    use std::iter::zip;
+                    use std::collections::HashSet;
                     use crate::ssz::prelude::*;
                     use integer_sqrt::IntegerSquareRoot;
-                    use crate::crypto::{hash, fast_aggregate_verify, eth_aggregate_public_keys, eth_fast_aggregate_verify};
+                    use crate::crypto::{hash, fast_aggregate_verify, eth_aggregate_public_keys};
+
+                    #[cfg(feature = "transition")]
+                    use std::{mem, collections::HashMap, iter::zip};
+                    #[cfg(feature = "transition")]
+                    use crate::crypto::eth_fast_aggregate_verify;
 
+                    #[cfg(feature = "transition")]
                     pub use crate::deneb::fork::upgrade_to_deneb;
                 };
                 fragment.items
             }
+            Fork::Electra => {
+                let fragment: syn::File = parse_quote! {
+                    use std::cmp;
+                    use std::collections::HashSet;
+                    use crate::ssz::prelude::*;
+                    use integer_sqrt::IntegerSquareRoot;
+                    use crate::crypto::{hash, fast_aggregate_verify, eth_aggregate_public_keys};
+
+                    #[cfg(feature = "transition")]
+                    use std::{mem, collections::HashMap, iter::zip};
+                    #[cfg(feature = "transition")]
+                    use crate::crypto::eth_fast_aggregate_verify;
+
+                    use crate::deneb::polynomial_commitments::{KzgCommitment, VersionedHash};
+
+                    #[cfg(feature = "transition")]
+                    pub use crate::electra::fork::upgrade_to_electra;
+                };
+                fragment.items
+            }
         }
     }
 }
@@ -405,12 +455,18 @@ impl Spec {
                 let mut all_arguments = vec![];
                 for name in type_names {
                     if let Some(target_module) = index.get(&name) {
-                        let target_module = self.diff.modules.get(target_module).unwrap();
-                        let container = target_module
-                            .containers
-                            .iter()
-                            .find(|&c| c.name == name)
-                            .expect("internal state integrity");
+                        // containers carried over from the `previous` module are only merged
+                        // once all of its items are visited; other indexed names (e.g. the
+                        // `state_transition` segment of a qualified path) carry no generics
+                        let Some(container) = self
+                            .diff
+                            .modules
+                            .get(target_module)
+                            .and_then(|target| target.containers.iter().find(|&c| c.name == name))
+                            .or_else(|| module.containers.iter().find(|&c| c.name == name))
+                        else {
+                            continue
+                        };
 
                         let arguments = generics_to_arguments(&container.item.generics);
                         let mut editor = ArgumentsEditor::new(&container.name, &arguments);
@@ -474,7 +530,9 @@ impl Spec {
             for c in &module.constants {
                 let ident = &c.item.ident;
                 let fork_name = as_syn_ident(c.fork.name());
+                let attrs = cfg_attrs(&c.item.attrs);
                 let use_stmt = parse_quote! {
+                    #(#attrs)*
                     pub use crate::#fork_name::#module_name::#ident;
                 };
                 self.items.push(use_stmt);
@@ -487,7 +545,9 @@ impl Spec {
                 let item = &container.item;
                 let ident = &item.ident;
                 let fork_name = as_syn_ident(container.fork.name());
+                let attrs = cfg_attrs(&item.attrs);
                 let use_stmt = parse_quote! {
+                    #(#attrs)*
                     pub use crate::#fork_name::#module_name::#ident;
                 };
                 self.items.push(use_stmt);
@@ -499,7 +559,9 @@ impl Spec {
             for t in &module.type_defs {
                 let ident = &t.item.ident;
                 let fork_name = as_syn_ident(t.fork.name());
+                let attrs = cfg_attrs(&t.item.attrs);
                 let use_stmt = parse_quote! {
+                    #(#attrs)*
                     pub use crate::#fork_name::#module_name::#ident;
                 };
                 self.items.push(use_stmt);
@@ -526,7 +588,9 @@ impl Spec {
             } else {
                 let ident = &f.item.sig.ident;
                 let fork_name = as_syn_ident(f.fork.name());
+                let attrs = cfg_attrs(&f.item.attrs);
                 parse_quote! {
+                    #(#attrs)*
                     pub use crate::#fork_name::#module_name::#ident;
                 }
             };
@@ -538,7 +602,9 @@ impl Spec {
             for t in &module.trait_defs {
                 let ident = &t.item.ident;
                 let fork_name = as_syn_ident(t.fork.name());
+                let attrs = cfg_attrs(&t.item.attrs);
                 let use_stmt = parse_quote! {
+                    #(#attrs)*
                     pub use crate::#fork_name::#module_name::#ident;
                 };
                 self.items.push(use_stmt);
@@ -547,14 +613,56 @@ impl Spec {
     }
 }
 
+// Return the `cfg` attributes of `attrs`, e.g. the feature gating an item.
+fn cfg_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs.iter().filter(|attr| attr.path.is_ident("cfg")).cloned().collect()
+}
+
+// Return the `cfg` attributes on each module declared in the `mod.rs` of `fork`, so that the
+// items of a gated module are gated in the generated spec as well.
+fn parse_module_cfgs(fork: &Fork) -> HashMap<String, Vec<Attribute>> {
+    let fork_name = fork.name();
+    let source_path = format!("{SOURCE_ROOT}/{fork_name}/mod.rs");
+    let source = fs::read_to_string(&source_path).expect("exists");
+    let file = syn::parse_file(&source).unwrap();
+    file.items
+        .into_iter()
+        .filter_map(|item| match item {
+            Item::Mod(item) => Some((item.ident.to_string(), cfg_attrs(&item.attrs))),
+            _ => None,
+        })
+        .collect()
+}
+
+fn gate(attrs: &mut Vec<Attribute>, cfgs: &[Attribute]) {
+    for cfg in cfgs.iter().rev() {
+        if !attrs.contains(cfg) {
+            attrs.insert(0, cfg.clone());
+        }
+    }
+}
+
 fn parse_fork_diff_with_symbol_index(fork: &Fork) -> (ForkDiff, HashMap<String, String>) {
     let fork_name = fork.name();
     let mut fork_diff = ForkDiff::default();
     let mut index = HashMap::default();
+    let module_cfgs = parse_module_cfgs(fork);
     for module_name in fork.modules_in_diff() {
         let source_path = format!("{SOURCE_ROOT}/{fork_name}/{module_name}.rs");
         let module_source = fs::read_to_string(&source_path).expect("exists");
-        let file = syn::parse_file(&module_source).unwrap();
+        let mut file = syn::parse_file(&module_source).unwrap();
+        if let Some(cfgs) = module_cfgs.get(*module_name) {
+            for item in file.items.iter_mut() {
+                match item {
+                    Item::Const(item) => gate(&mut item.attrs, cfgs),
+                    Item::Struct(item) => gate(&mut item.attrs, cfgs),
+                    Item::Fn(item) => gate(&mut item.attrs, cfgs),
+                    Item::Type(item) => gate(&mut item.attrs, cfgs),
+                    Item::Trait(item) => gate(&mut item.attrs, cfgs),
+                    _ => {}
+                }
+            }
+        }
         let module =
             fork_diff.modules.entry(module_name.to_string()).or_insert_with(Default::default);
         for item in file.items {
@@ -567,6 +675,7 @@ fn parse_fork_diff_with_symbol_index(fork: &Fork) -> (ForkDiff, HashMap<String,
                 Item::Const(item) => {
                     let item = Constant::new(item, *fork);
                     if item.is_pub() {
+                        index.insert(item.item.ident.to_string(), module_name.to_string());
                         module.constants.push(item);
                     }
                 }
@@ -594,6 +703,9 @@ fn parse_fork_diff_with_symbol_index(fork: &Fork) -> (ForkDiff, HashMap<String,
                 Item::Impl(_) => {
                     println!("skipping item: `impl` block in {source_path}");
                 }
+                Item::Mod(_) => {
+                    println!("skipping item: `mod` in {source_path}");
+                }
                 Item::Trait(item) => {
                     let item = TraitDef::new(item, *fork);
                     if item.is_pub() {
@@ -615,8 +727,15 @@ fn render(fork: &Fork, items: &[Item]) {
     let signing_import = parse_quote! {
         pub use crate::signing::*;
     };
-    let state_transition_import = parse_quote! {
-        pub use crate::state_transition::{Result, Context, Validation};
+    // forks with an execution layer also report the status of each payload
+    let state_transition_import = if *fork >= Fork::Bellatrix {
+        parse_quote! {
+            pub use crate::state_transition::{Result, Context, PayloadStatus, Validation};
+        }
+    } else {
+        parse_quote! {
+            pub use crate::state_transition::{Result, Context, Validation};
+        }
     };
     let error_import = parse_quote! {
         pub use crate::error::*;
@@ -648,6 +767,7 @@ pub fn run() {
         Some(Fork::Bellatrix),
         Some(Fork::Capella),
         Some(Fork::Deneb),
+        Some(Fork::Electra),
     ];
 
     let mut specs = HashMap::<_, Rc<_>>::new();
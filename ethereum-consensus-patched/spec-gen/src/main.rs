@@ -1,18 +1,34 @@
 mod generator;
+mod preset_generator;
+mod schema_generator;
 mod type_generator;
 mod visitors;
 
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 struct Args {
+    #[command(subcommand)]
     command: Command,
 }
 
-#[derive(ValueEnum, Debug, Clone)]
+#[derive(Subcommand, Debug, Clone)]
 enum Command {
+    /// Generate the spec of each fork from the fork diffs
     Forks,
+    /// Generate the fork-agnostic wrapper types
     Types,
+    /// Generate the presets defined under `spec-gen/presets`
+    Presets,
+    /// Generate Rust types from the SSZ containers defined in a document of the `consensus-specs`
+    Schema {
+        /// Markdown (or Python) source defining the containers
+        input: PathBuf,
+        /// File to write the generated types to, instead of printing them
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
 }
 
 fn main() {
@@ -24,5 +40,11 @@ fn main() {
         Command::Types => {
             type_generator::run();
         }
+        Command::Presets => {
+            preset_generator::run();
+        }
+        Command::Schema { input, output } => {
+            schema_generator::run(&input, output.as_deref());
+        }
     }
 }
@@ -0,0 +1,320 @@
+use crate::{generator::Fork, visitors::PresetRenamer};
+use serde::Deserialize;
+use serde_yaml::Value;
+use std::{collections::BTreeMap, fs, path::Path};
+use syn::{parse_quote, Expr, Item, Member, Pat, Stmt};
+
+const SOURCE_ROOT: &str = "ethereum-consensus/src";
+const PRESETS_ROOT: &str = "spec-gen/presets";
+
+// A preset defined in terms of an existing `base` preset, overriding some of its values.
+//
+// Keys of each section are the names of the constants defined in the corresponding module
+// of the base preset, e.g. `SLOTS_PER_EPOCH` in `phase0::minimal`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PresetDefinition {
+    base: String,
+    #[serde(default)]
+    phase0: BTreeMap<String, Value>,
+    #[serde(default)]
+    altair: BTreeMap<String, Value>,
+    #[serde(default)]
+    bellatrix: BTreeMap<String, Value>,
+    #[serde(default)]
+    capella: BTreeMap<String, Value>,
+    #[serde(default)]
+    deneb: BTreeMap<String, Value>,
+    #[serde(default)]
+    electra: BTreeMap<String, Value>,
+    #[serde(default)]
+    config: BTreeMap<String, Value>,
+}
+
+impl PresetDefinition {
+    fn overrides_for(&self, fork: Fork) -> &BTreeMap<String, Value> {
+        match fork {
+            Fork::Phase0 => &self.phase0,
+            Fork::Altair => &self.altair,
+            Fork::Bellatrix => &self.bellatrix,
+            Fork::Capella => &self.capella,
+            Fork::Deneb => &self.deneb,
+            Fork::Electra => &self.electra,
+        }
+    }
+}
+
+// Numbers are used as-is, hex strings become byte arrays (e.g. for a `Version`) and
+// any other string is taken to be a Rust expression, e.g. `FAR_FUTURE_EPOCH`.
+fn as_expr(key: &str, value: &Value) -> Expr {
+    match value {
+        Value::Number(value) => syn::parse_str(&value.to_string()).unwrap(),
+        Value::String(value) => match value.strip_prefix("0x") {
+            Some(hex) => {
+                assert!(hex.len() % 2 == 0, "odd number of hex digits for `{key}`");
+                let bytes = (0..hex.len())
+                    .step_by(2)
+                    .map(|i| {
+                        let byte = u8::from_str_radix(&hex[i..i + 2], 16)
+                            .unwrap_or_else(|_| panic!("invalid hex value for `{key}`"));
+                        byte.to_string()
+                    })
+                    .collect::<Vec<_>>();
+                syn::parse_str(&format!("[{}]", bytes.join(", "))).unwrap()
+            }
+            None => syn::parse_str(value)
+                .unwrap_or_else(|err| panic!("invalid expression for `{key}`: {err}")),
+        },
+        other => panic!("unsupported value for `{key}`: {other:?}"),
+    }
+}
+
+fn generate_module(
+    source_path: &str,
+    target_path: &str,
+    definition: &PresetDefinition,
+    name: &str,
+    overrides: &BTreeMap<String, Value>,
+) {
+    let module_source = fs::read_to_string(source_path)
+        .unwrap_or_else(|_| panic!("missing base preset module {source_path}"));
+    let mut file = syn::parse_file(&module_source).unwrap();
+    PresetRenamer::new(&definition.base, name).edit(&mut file);
+
+    let mut unused = overrides.keys().collect::<Vec<_>>();
+    let mut apply = |key: String, expr: &mut Expr| {
+        if let Some(value) = overrides.get(&key) {
+            *expr = as_expr(&key, value);
+            unused.retain(|unused| **unused != key);
+        }
+    };
+    for item in file.items.iter_mut() {
+        match item {
+            Item::Const(item) => apply(item.ident.to_string(), &mut item.expr),
+            // values without a constant, like `deposit_contract_address`, are bound in `config()`
+            Item::Fn(item) => {
+                for stmt in item.block.stmts.iter_mut() {
+                    match stmt {
+                        Stmt::Local(local) => {
+                            if let (Pat::Ident(pat), Some((_, init))) =
+                                (&local.pat, &mut local.init)
+                            {
+                                apply(pat.ident.to_string().to_uppercase(), init);
+                            }
+                        }
+                        Stmt::Expr(Expr::Struct(config)) => {
+                            for field in config.fields.iter_mut() {
+                                if matches!(&field.member, Member::Named(name) if name == "name") {
+                                    apply("CONFIG_NAME".to_string(), &mut field.expr);
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    assert!(unused.is_empty(), "no values named {unused:?} in {source_path}");
+
+    let warning = format!(
+        " WARNING: Derived by `spec-gen` from `{PRESETS_ROOT}/{name}.yaml`. DO NOT EDIT MANUALLY."
+    );
+    file.attrs.insert(0, parse_quote!(#![doc = #warning]));
+    fs::write(target_path, prettyplease::unparse(&file)).unwrap();
+}
+
+// Insert `new_line` after the line `after` in the file at `path`, unless it is already present.
+fn insert_line_after(path: &str, after: &str, new_line: &str) {
+    let source = fs::read_to_string(path).unwrap();
+    if source.lines().any(|line| line == new_line) {
+        return
+    }
+    let mut lines = source.lines().collect::<Vec<_>>();
+    let index = lines
+        .iter()
+        .position(|line| *line == after)
+        .unwrap_or_else(|| panic!("could not find `{after}` in {path}"));
+    lines.insert(index + 1, new_line);
+    fs::write(path, lines.join("\n") + "\n").unwrap();
+}
+
+// Extend a re-export like `pub use presets::{mainnet, minimal, Preset};` with the new preset.
+fn extend_reexport(path: &str, base: &str, name: &str) {
+    let source = fs::read_to_string(path).unwrap();
+    let line = source
+        .lines()
+        .find(|line| line.starts_with("pub use presets::{"))
+        .unwrap_or_else(|| panic!("could not find re-export of presets in {path}"));
+    let inner = &line["pub use presets::{".len()..line.len() - "};".len()];
+    let mut names = inner.split(", ").collect::<Vec<_>>();
+    if names.contains(&name) {
+        return
+    }
+    let index = names.iter().position(|n| *n == base).expect("base preset is re-exported");
+    names.insert(index + 1, name);
+    let extended = format!("pub use presets::{{{}}};", names.join(", "));
+    fs::write(path, source.replace(line, &extended)).unwrap();
+}
+
+// Copy the block of `source` starting with the line `start` and ending at the next line `end`.
+fn find_block<'a>(source: &'a str, start: &str, end: &str, path: &str) -> &'a str {
+    let begin = source.find(start).unwrap_or_else(|| panic!("could not find `{start}` in {path}"));
+    let len = source[begin..].find(end).expect("block is terminated") + end.len();
+    &source[begin..begin + len]
+}
+
+// Extend `block` of `source` to include the attributes right above it, e.g. a `cfg`.
+fn with_attributes<'a>(source: &'a str, block: &'a str) -> &'a str {
+    let mut begin = source.find(block).expect("block is in source");
+    let end = begin + block.len();
+    while begin > 0 {
+        let line_start = source[..begin - 1].rfind('\n').map_or(0, |i| i + 1);
+        if !source[line_start..begin].starts_with("#[") {
+            break
+        }
+        begin = line_start;
+    }
+    &source[begin..end]
+}
+
+// Add a `Context::for_{name}` constructor and support for `PRESET_BASE: {name}` in config files.
+fn extend_context(base: &str, name: &str) {
+    let path = format!("{SOURCE_ROOT}/state_transition/context.rs");
+    let mut source = fs::read_to_string(&path).unwrap();
+    if source.contains(&format!("fn for_{name}()")) {
+        return
+    }
+    let rename = |block: &str| {
+        block
+            .replace(&format!("::{base}::"), &format!("::{name}::"))
+            .replace(&format!("fn for_{base}()"), &format!("fn for_{name}()"))
+            .replace(&format!("\"{base}\" =>"), &format!("\"{name}\" =>"))
+    };
+
+    let arm =
+        find_block(&source, &format!("            \"{base}\" => {{\n"), "\n            }\n", &path);
+    let insert_at = source.find(arm).unwrap() + arm.len();
+    source.insert_str(insert_at, &rename(arm));
+
+    let constructor =
+        find_block(&source, &format!("    pub fn for_{base}() -> Self {{\n"), "\n    }\n", &path);
+    let insert_at = source.find(constructor).unwrap() + constructor.len();
+    source.insert_str(insert_at, &format!("\n{}", rename(constructor)));
+
+    fs::write(&path, source).unwrap();
+}
+
+fn generate_preset(name: &str, definition: &PresetDefinition) {
+    let base = &definition.base;
+    let no_overrides = BTreeMap::new();
+
+    for fork in
+        [Fork::Phase0, Fork::Altair, Fork::Bellatrix, Fork::Capella, Fork::Deneb, Fork::Electra]
+    {
+        let fork_name = fork.name();
+        let presets_path = format!("{SOURCE_ROOT}/{fork_name}/presets");
+        generate_module(
+            &format!("{presets_path}/{base}.rs"),
+            &format!("{presets_path}/{name}.rs"),
+            definition,
+            name,
+            definition.overrides_for(fork),
+        );
+        let module_path = format!("{presets_path}/mod.rs");
+        insert_line_after(&module_path, &format!("pub mod {base};"), &format!("pub mod {name};"));
+        extend_reexport(&format!("{SOURCE_ROOT}/{fork_name}/mod.rs"), base, name);
+    }
+
+    for module in ["state_transition", "types"] {
+        let presets_path = format!("{SOURCE_ROOT}/{module}/presets");
+        generate_module(
+            &format!("{presets_path}/{base}.rs"),
+            &format!("{presets_path}/{name}.rs"),
+            definition,
+            name,
+            &no_overrides,
+        );
+        let module_path = format!("{presets_path}/mod.rs");
+        insert_line_after(&module_path, &format!("pub mod {base};"), &format!("pub mod {name};"));
+    }
+    extend_reexport(&format!("{SOURCE_ROOT}/types/mod.rs"), base, name);
+
+    let path = format!("{SOURCE_ROOT}/state_transition/mod.rs");
+    let source = fs::read_to_string(&path).unwrap();
+    if !source.contains(&format!("pub mod {name} {{")) {
+        let block = find_block(&source, &format!("pub mod {base} {{\n"), "\n}\n", &path);
+        let block = with_attributes(&source, block);
+        let insert_at = source.find(block).unwrap() + block.len();
+        let mut source = source.clone();
+        source.insert_str(insert_at, &format!("\n{}", block.replace(base, name)));
+        fs::write(&path, source).unwrap();
+    }
+
+    let configs_path = format!("{SOURCE_ROOT}/configs");
+    generate_module(
+        &format!("{configs_path}/{base}.rs"),
+        &format!("{configs_path}/{name}.rs"),
+        definition,
+        name,
+        &definition.config,
+    );
+    insert_line_after(
+        &format!("{configs_path}/mod.rs"),
+        &format!("pub mod {base};"),
+        &format!("pub mod {name};"),
+    );
+
+    extend_context(base, name);
+}
+
+pub fn run() {
+    let Ok(entries) = fs::read_dir(PRESETS_ROOT) else {
+        println!("no presets found under {PRESETS_ROOT}");
+        return
+    };
+    let mut paths = entries
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().map(|ext| ext == "yaml").unwrap_or_default())
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    for path in paths {
+        let name = path.file_stem().and_then(|name| name.to_str()).expect("valid file name");
+        println!(">>> generating preset {name} from {}", path.display());
+        let source = fs::read_to_string(&path).unwrap();
+        let definition: PresetDefinition = serde_yaml::from_str(&source)
+            .unwrap_or_else(|err| panic!("invalid preset definition {}: {err}", path.display()));
+        assert!(
+            Path::new(&format!("{SOURCE_ROOT}/phase0/presets/{}.rs", definition.base)).exists(),
+            "unknown base preset `{}`",
+            definition.base
+        );
+        generate_preset(name, &definition);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_as_expr() {
+        let expr = as_expr("SLOTS_PER_EPOCH", &serde_yaml::from_str("8").unwrap());
+        assert_eq!(expr, parse_quote!(8));
+        let expr = as_expr("GENESIS_FORK_VERSION", &Value::String("0x000000fe".to_string()));
+        assert_eq!(expr, parse_quote!([0, 0, 0, 254]));
+        let expr = as_expr("ALTAIR_FORK_EPOCH", &Value::String("FAR_FUTURE_EPOCH".to_string()));
+        assert_eq!(expr, parse_quote!(FAR_FUTURE_EPOCH));
+    }
+
+    #[test]
+    fn test_with_attributes() {
+        let source = "#[cfg(test)]\nmod a;\n\n#[cfg(feature = \"b\")]\npub mod b {\n}\n";
+        let block = find_block(source, "pub mod b {\n", "\n}\n", "test");
+        assert_eq!(with_attributes(source, block), "#[cfg(feature = \"b\")]\npub mod b {\n}\n");
+        let block = find_block(source, "mod a;", "\n", "test");
+        assert_eq!(with_attributes(source, block), "#[cfg(test)]\nmod a;\n");
+    }
+}
@@ -0,0 +1,421 @@
+use quote::quote;
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs,
+    path::Path,
+};
+use syn::{parse::Parser, parse_quote, Field, Ident, Item, Type};
+
+// Names of custom types in the `consensus-specs` that differ in `crate::primitives`.
+const RENAMES: &[(&str, &str)] = &[("BLSPubkey", "BlsPublicKey"), ("BLSSignature", "BlsSignature")];
+
+// A type expression like `List[Attestation, MAX_ATTESTATIONS]`, where each argument is
+// either another type or a length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TypeExpr {
+    name: String,
+    args: Vec<TypeExpr>,
+}
+
+impl TypeExpr {
+    fn parse(source: &str) -> Self {
+        let source = source.trim();
+        let Some(start) = source.find('[') else {
+            return Self { name: source.to_string(), args: vec![] }
+        };
+        assert!(source.ends_with(']'), "unbalanced brackets in type `{source}`");
+        let inner = &source[start + 1..source.len() - 1];
+        let mut args = vec![];
+        let mut depth = 0;
+        let mut begin = 0;
+        for (i, c) in inner.char_indices() {
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                ',' if depth == 0 => {
+                    args.push(Self::parse(&inner[begin..i]));
+                    begin = i + 1;
+                }
+                _ => {}
+            }
+        }
+        args.push(Self::parse(&inner[begin..]));
+        Self { name: source[..start].trim().to_string(), args }
+    }
+}
+
+#[derive(Debug)]
+struct Container {
+    name: String,
+    fields: Vec<(String, TypeExpr)>,
+}
+
+// The SSZ definitions found in a document of the `consensus-specs`: the custom types declared
+// in its tables (e.g. "| `Slot` | `uint64` | a slot number |") and the containers declared
+// as Python classes.
+#[derive(Debug, Default)]
+struct Schema {
+    aliases: HashMap<String, TypeExpr>,
+    containers: Vec<Container>,
+}
+
+fn is_constant(name: &str) -> bool {
+    name.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_') &&
+        name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+fn is_type_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase()) &&
+        name.contains(|c: char| c.is_ascii_lowercase()) &&
+        name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+// Parse a row of a markdown table like "| `Slot` | `uint64` | a slot number |".
+fn parse_alias(line: &str) -> Option<(String, TypeExpr)> {
+    let mut cells = line.split('|').map(str::trim).filter(|cell| !cell.is_empty());
+    let name = cells.next()?.strip_prefix('`')?.strip_suffix('`')?;
+    let ty = cells.next()?.strip_prefix('`')?.strip_suffix('`')?;
+    if !is_type_name(name) || ty.contains('(') {
+        return None
+    }
+    Some((name.to_string(), TypeExpr::parse(ty)))
+}
+
+// Parse a class declaration like "class Checkpoint(Container):" into its name and base.
+fn parse_class(line: &str) -> Option<(&str, &str)> {
+    let declaration = line.strip_prefix("class ")?.strip_suffix("):")?;
+    let (name, base) = declaration.split_once('(')?;
+    Some((name.trim(), base.trim()))
+}
+
+impl Schema {
+    fn parse(source: &str) -> Self {
+        let mut schema = Self::default();
+        let mut current: Option<Container> = None;
+        let mut in_docstring = false;
+        for line in source.lines() {
+            let trimmed = line.trim();
+            if current.is_some() && !line.is_empty() && !line.starts_with(char::is_whitespace) {
+                schema.containers.extend(current.take());
+            }
+            if let Some(container) = current.as_mut() {
+                if in_docstring {
+                    in_docstring = !trimmed.ends_with("\"\"\"");
+                    continue
+                }
+                if let Some(docstring) = trimmed.strip_prefix("\"\"\"") {
+                    in_docstring = !docstring.ends_with("\"\"\"");
+                    continue
+                }
+                if trimmed.is_empty() || trimmed.starts_with('#') {
+                    continue
+                }
+                let declaration = trimmed.split('#').next().unwrap();
+                let (name, ty) = declaration
+                    .split_once(':')
+                    .unwrap_or_else(|| panic!("invalid field in `{}`: {line}", container.name));
+                container.fields.push((name.trim().to_string(), TypeExpr::parse(ty)));
+            } else if let Some((name, base)) = parse_class(trimmed) {
+                let fields = if base == "Container" {
+                    vec![]
+                } else if let Some(parent) = schema.container(base) {
+                    parent.fields.clone()
+                } else {
+                    println!("skipping class `{name}` with unsupported base `{base}`");
+                    continue
+                };
+                current = Some(Container { name: name.to_string(), fields });
+            } else if trimmed.starts_with('|') {
+                if let Some((name, ty)) = parse_alias(trimmed) {
+                    schema.aliases.insert(name, ty);
+                }
+            }
+        }
+        schema.containers.extend(current);
+        schema
+    }
+
+    fn container(&self, name: &str) -> Option<&Container> {
+        self.containers.iter().find(|container| container.name == name)
+    }
+
+    // Follow custom types to the SSZ type they are defined as.
+    fn resolve<'a>(&'a self, ty: &'a TypeExpr) -> &'a TypeExpr {
+        match self.aliases.get(&ty.name) {
+            Some(alias) if ty.args.is_empty() => self.resolve(alias),
+            _ => ty,
+        }
+    }
+
+    fn is_uint(&self, ty: &TypeExpr) -> bool {
+        self.resolve(ty).name.starts_with("uint")
+    }
+
+    // The constants bounding the type, including those of any containers it refers to,
+    // in the order they first appear.
+    fn collect_constants(&self, ty: &TypeExpr, constants: &mut Vec<String>) {
+        if is_constant(&ty.name) {
+            if !constants.contains(&ty.name) {
+                constants.push(ty.name.clone());
+            }
+        } else if let Some(container) = self.container(&ty.name) {
+            for constant in self.constants(container) {
+                if !constants.contains(&constant) {
+                    constants.push(constant);
+                }
+            }
+        }
+        for arg in &ty.args {
+            self.collect_constants(arg, constants);
+        }
+    }
+
+    fn constants(&self, container: &Container) -> Vec<String> {
+        let mut constants = vec![];
+        for (_, ty) in &container.fields {
+            self.collect_constants(ty, &mut constants);
+        }
+        constants
+    }
+
+    fn as_length(&self, arg: &TypeExpr, context: &str) -> String {
+        assert!(
+            arg.args.is_empty() &&
+                (is_constant(&arg.name) || arg.name.chars().all(|c| c.is_ascii_digit())),
+            "unsupported length `{}` in `{context}`, expected a number or a constant",
+            arg.name
+        );
+        arg.name.clone()
+    }
+
+    // Render the type in Rust, adding any custom types it refers to to `imports`.
+    fn as_rust_type(&self, ty: &TypeExpr, imports: &mut BTreeSet<String>) -> String {
+        let name = ty.name.as_str();
+        let arity = |n: usize| {
+            assert!(ty.args.len() == n, "expected {n} arguments for `{name}`: {ty:?}");
+        };
+        match name {
+            "boolean" => "bool".to_string(),
+            "uint8" | "uint16" | "uint32" | "uint64" | "uint128" => {
+                format!("u{}", &name["uint".len()..])
+            }
+            "uint256" => "U256".to_string(),
+            "List" | "Vector" => {
+                arity(2);
+                let element = self.as_rust_type(&ty.args[0], imports);
+                format!("{name}<{element}, {}>", self.as_length(&ty.args[1], name))
+            }
+            "Bitlist" | "Bitvector" | "ByteList" | "ByteVector" => {
+                arity(1);
+                format!("{name}<{}>", self.as_length(&ty.args[0], name))
+            }
+            _ if !ty.args.is_empty() => panic!("unsupported type `{name}`"),
+            _ => match self.container(name) {
+                Some(container) => {
+                    let constants = self.constants(container);
+                    if constants.is_empty() {
+                        name.to_string()
+                    } else {
+                        format!("{name}<{}>", constants.join(", "))
+                    }
+                }
+                None => {
+                    let name = RENAMES
+                        .iter()
+                        .find(|(spec_name, _)| *spec_name == name)
+                        .map(|(_, rust_name)| rust_name)
+                        .unwrap_or(&name);
+                    imports.insert(name.to_string());
+                    name.to_string()
+                }
+            },
+        }
+    }
+
+    fn as_field(&self, name: &str, ty: &TypeExpr, imports: &mut BTreeSet<String>) -> Field {
+        let ident: Ident = syn::parse_str(name).unwrap();
+        let rust_type: Type = syn::parse_str(&self.as_rust_type(ty, imports)).unwrap();
+        let ty = self.resolve(ty);
+        let is_sequence = matches!(ty.name.as_str(), "List" | "Vector");
+        let field = if self.is_uint(ty) {
+            quote! {
+                #[serde(with = "crate::serde::as_str")]
+                pub #ident: #rust_type
+            }
+        } else if is_sequence && self.is_uint(&ty.args[0]) {
+            quote! {
+                #[serde(with = "crate::serde::seq_of_str")]
+                pub #ident: #rust_type
+            }
+        } else {
+            quote!(pub #ident: #rust_type)
+        };
+        Field::parse_named.parse2(field).unwrap()
+    }
+
+    fn as_items(&self) -> Vec<Item> {
+        let mut imports = BTreeSet::new();
+        let mut items = vec![];
+        for container in &self.containers {
+            let name: Ident = syn::parse_str(&container.name).unwrap();
+            let constants = self
+                .constants(container)
+                .into_iter()
+                .map(|constant| syn::parse_str::<Ident>(&constant).unwrap());
+            let fields = container
+                .fields
+                .iter()
+                .map(|(field, ty)| self.as_field(field, ty, &mut imports))
+                .collect::<Vec<_>>();
+            items.push(parse_quote! {
+                #[derive(
+                    Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize,
+                    serde::Deserialize,
+                )]
+                pub struct #name<#(const #constants: usize),*> {
+                    #(#fields),*
+                }
+            });
+        }
+
+        let import = if imports.is_empty() {
+            parse_quote!(
+                use crate::ssz::prelude::*;
+            )
+        } else {
+            let imports = imports.iter().map(|name| syn::parse_str::<Ident>(name).unwrap());
+            parse_quote!(use crate::{primitives::{#(#imports),*}, ssz::prelude::*};)
+        };
+        let mut all_items = vec![import];
+        all_items.extend(items);
+        all_items
+    }
+}
+
+fn generate(source: &str, origin: &str) -> String {
+    let schema = Schema::parse(source);
+    let note = format!(" Derived by `spec-gen` from the SSZ definitions in `{origin}`.");
+    let file = syn::File {
+        shebang: None,
+        attrs: vec![parse_quote!(#![doc = #note])],
+        items: schema.as_items(),
+    };
+    prettyplease::unparse(&file)
+}
+
+pub fn run(input: &Path, output: Option<&Path>) {
+    let source = fs::read_to_string(input)
+        .unwrap_or_else(|err| panic!("could not read {}: {err}", input.display()));
+    let generated = generate(&source, &input.display().to_string());
+    match output {
+        Some(output) => {
+            println!(">>> generating types from {} to {}", input.display(), output.display());
+            fs::write(output, generated).unwrap();
+        }
+        None => print!("{generated}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+## Custom types
+
+| Name | SSZ equivalent | Description |
+| - | - | - |
+| `Slot` | `uint64` | a slot number |
+| `ValidatorIndex` | `uint64` | a validator registry index |
+
+## Constants
+
+| Name | Value |
+| - | - |
+| `GENESIS_SLOT` | `Slot(0)` |
+
+```python
+class AttestationData(Container):
+    slot: Slot
+    beacon_block_root: Root  # LMD GHOST vote
+```
+
+```python
+class IndexedAttestation(Container):
+    """
+    An attestation along with the indices of its attesters.
+    """
+    attesting_indices: List[ValidatorIndex, MAX_VALIDATORS_PER_COMMITTEE]
+    data: AttestationData
+    signature: BLSSignature
+```
+
+```python
+class AttesterSlashing(Container):
+    attestation_1: IndexedAttestation
+    attestation_2: IndexedAttestation
+    bits: Bitvector[4]
+```
+"#;
+
+    #[test]
+    fn test_parse_type() {
+        let ty = TypeExpr::parse("List[Vector[uint8, 4], MAX_ITEMS]");
+        assert_eq!(ty.name, "List");
+        assert_eq!(ty.args[0], TypeExpr::parse("Vector[uint8, 4]"));
+        assert_eq!(ty.args[1], TypeExpr { name: "MAX_ITEMS".to_string(), args: vec![] });
+    }
+
+    #[test]
+    fn test_parse_schema() {
+        let schema = Schema::parse(SOURCE);
+        assert_eq!(schema.aliases.len(), 2);
+        let names = schema.containers.iter().map(|c| c.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["AttestationData", "IndexedAttestation", "AttesterSlashing"]);
+        let slashing = schema.container("AttesterSlashing").unwrap();
+        assert_eq!(schema.constants(slashing), ["MAX_VALIDATORS_PER_COMMITTEE"]);
+    }
+
+    #[test]
+    fn test_generate() {
+        let expected: syn::File = parse_quote! {
+            //! Derived by `spec-gen` from the SSZ definitions in `beacon-chain.md`.
+            use crate::{primitives::{BlsSignature, Root, Slot, ValidatorIndex}, ssz::prelude::*};
+            #[derive(
+                Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize,
+                serde::Deserialize,
+            )]
+            pub struct AttestationData {
+                #[serde(with = "crate::serde::as_str")]
+                pub slot: Slot,
+                pub beacon_block_root: Root
+            }
+            #[derive(
+                Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize,
+                serde::Deserialize,
+            )]
+            pub struct IndexedAttestation<const MAX_VALIDATORS_PER_COMMITTEE: usize> {
+                #[serde(with = "crate::serde::seq_of_str")]
+                pub attesting_indices: List<ValidatorIndex, MAX_VALIDATORS_PER_COMMITTEE>,
+                pub data: AttestationData,
+                pub signature: BlsSignature
+            }
+            #[derive(
+                Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize,
+                serde::Deserialize,
+            )]
+            pub struct AttesterSlashing<const MAX_VALIDATORS_PER_COMMITTEE: usize> {
+                pub attestation_1: IndexedAttestation<MAX_VALIDATORS_PER_COMMITTEE>,
+                pub attestation_2: IndexedAttestation<MAX_VALIDATORS_PER_COMMITTEE>,
+                pub bits: Bitvector<4>
+            }
+        };
+        assert_eq!(generate(SOURCE, "beacon-chain.md"), prettyplease::unparse(&expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported length")]
+    fn test_unsupported_length() {
+        Schema::parse("class A(Container):\n    a: List[uint8, 2 * N]\n").as_items();
+    }
+}
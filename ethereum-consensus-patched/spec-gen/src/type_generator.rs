@@ -1,6 +1,8 @@
 use crate::{
     generator::Fork,
-    visitors::{as_syn_ident, generics_to_arguments, ToGenericsVisitor, TypeNameVisitor},
+    visitors::{
+        as_syn_ident, generics_to_arguments, ToGenericsVisitor, TypeNameVisitor, TypeRenamer,
+    },
 };
 use convert_case::{Case, Casing};
 use std::{collections::HashSet, fs};
@@ -14,7 +16,7 @@ const SOURCE_ROOT: &str = "ethereum-consensus/src";
 fn is_copy(ident: &str) -> bool {
     matches!(
         ident.to_string().as_str(),
-        "Root" | "Slot" | "ValidatorIndex" | "WithdrawalIndex" | "u64"
+        "Root" | "Slot" | "Epoch" | "Gwei" | "ValidatorIndex" | "WithdrawalIndex" | "u64"
     )
 }
 
@@ -115,6 +117,21 @@ impl Type {
         }
     }
 
+    // Some fields keep their name but change type in a later fork, e.g. when the container they
+    // hold is redefined. The `Fork` indicates where the new type takes over: the original accessor
+    // returns `None` from there on and a second accessor, suffixed with the fork name, covers the
+    // new type. The listed type names are suffixed in the same way so both can be imported.
+    fn field_redefinitions(&self) -> Option<(Fork, Vec<&str>, Vec<&str>)> {
+        match self {
+            Self::BeaconBlockBody | Self::BlindedBeaconBlockBody => Some((
+                Fork::Electra,
+                vec!["attester_slashings", "attestations"],
+                vec!["AttesterSlashing", "Attestation"],
+            )),
+            _ => None,
+        }
+    }
+
     fn imports(&self) -> syn::Item {
         match self {
             Self::BeaconBlockBody => parse_quote! {
@@ -123,6 +140,10 @@ impl Type {
                     bellatrix::beacon_block as bellatrix,
                     capella::beacon_block as capella,
                     deneb::{beacon_block as deneb, polynomial_commitments::KzgCommitment},
+                    electra::{
+                        beacon_block as electra, Attestation as AttestationElectra,
+                        AttesterSlashing as AttesterSlashingElectra, ExecutionRequests,
+                    },
                     phase0::{
                         beacon_block as phase0, Attestation, AttesterSlashing, Deposit, Eth1Data, ProposerSlashing,
                         SignedVoluntaryExit,
@@ -140,6 +161,10 @@ impl Type {
                     bellatrix::blinded_beacon_block as bellatrix,
                     capella::blinded_beacon_block as capella,
                     deneb::{blinded_beacon_block as deneb, polynomial_commitments::KzgCommitment},
+                    electra::{
+                        blinded_beacon_block as electra, Attestation as AttestationElectra,
+                        AttesterSlashing as AttesterSlashingElectra, ExecutionRequests,
+                    },
                     phase0::{
                         Attestation, AttesterSlashing, Deposit, Eth1Data, ProposerSlashing,
                         SignedVoluntaryExit,
@@ -158,6 +183,7 @@ impl Type {
                     bellatrix::beacon_block as bellatrix,
                     capella::beacon_block as capella,
                     deneb::beacon_block as deneb,
+                    electra::beacon_block as electra,
                     phase0::beacon_block as phase0,
                     primitives::{Slot, ValidatorIndex, Root},
                     ssz::prelude::*,
@@ -170,6 +196,7 @@ impl Type {
                     bellatrix::blinded_beacon_block as bellatrix,
                     capella::blinded_beacon_block as capella,
                     deneb::blinded_beacon_block as deneb,
+                    electra::blinded_beacon_block as electra,
                     primitives::{Slot, ValidatorIndex, Root},
                     ssz::prelude::*,
                     Fork as Version,
@@ -182,6 +209,7 @@ impl Type {
                     bellatrix::beacon_block as bellatrix,
                     capella::beacon_block as capella,
                     deneb::beacon_block as deneb,
+                    electra::beacon_block as electra,
                     phase0::beacon_block as phase0,
                     primitives::BlsSignature,
                     ssz::prelude::*,
@@ -194,6 +222,7 @@ impl Type {
                     bellatrix::blinded_beacon_block as bellatrix,
                     capella::blinded_beacon_block as capella,
                     deneb::blinded_beacon_block as deneb,
+                    electra::blinded_beacon_block as electra,
                     primitives::BlsSignature,
                     ssz::prelude::*,
                     Fork as Version,
@@ -227,9 +256,10 @@ impl Type {
                     bellatrix::beacon_state as bellatrix,
                     capella::beacon_state::{HistoricalSummary, self as capella},
                     deneb::beacon_state as deneb,
+                    electra::{beacon_state as electra, PendingConsolidation, PendingDeposit, PendingPartialWithdrawal},
                     phase0::beacon_state::{self as phase0, Fork},
                     phase0::{JUSTIFICATION_BITS_LENGTH, beacon_block::BeaconBlockHeader, validator::Validator, operations::{PendingAttestation, Checkpoint, Eth1Data}},
-                    primitives::{Root, ValidatorIndex, WithdrawalIndex, ParticipationFlags, Slot, Gwei, Bytes32},
+                    primitives::{Root, ValidatorIndex, WithdrawalIndex, ParticipationFlags, Slot, Epoch, Gwei, Bytes32},
                     ssz::prelude::*,
                     Fork as Version,
                     types::execution_payload_header::{ExecutionPayloadHeaderRef, ExecutionPayloadHeaderRefMut},
@@ -291,8 +321,13 @@ struct Variant {
 #[derive(Debug)]
 struct FieldDefn {
     fork: Fork,
+    // name of the accessor, which differs from `ident` for a redefined field
+    name: Ident,
     ident: Ident,
     type_def: syn::Type,
+    // generics referenced by the type of this field in any fork, as a polymorphic field
+    // refers to the merged type which need not be accretive
+    type_generics: Vec<Ident>,
 }
 
 fn derive_merge_generics(variants: &[Variant]) -> Vec<Ident> {
@@ -307,7 +342,11 @@ fn derive_merge_generics(variants: &[Variant]) -> Vec<Ident> {
     all_generics
 }
 
-fn derive_merge_type(fork_sequence: &[Fork], defns: Vec<TypeDefn>) -> MergeType {
+fn derive_merge_type(
+    target_type: &Type,
+    fork_sequence: &[Fork],
+    defns: Vec<TypeDefn>,
+) -> MergeType {
     let mut variants = vec![];
     let mut fields: Vec<FieldDefn> = vec![];
     let mut seen_fields = HashSet::new();
@@ -320,17 +359,44 @@ fn derive_merge_type(fork_sequence: &[Fork], defns: Vec<TypeDefn>) -> MergeType
 
         for field in &defn.item.fields {
             let ident = field.ident.clone().unwrap();
-            let mut field_defn =
-                FieldDefn { fork: *fork, ident: ident.clone(), type_def: field.ty.clone() };
-            if seen_fields.contains(&ident) {
-                let target = fields.iter().position(|field| field.ident == ident).unwrap();
+            let mut type_def = field.ty.clone();
+            let mut name = ident.clone();
+            if let Some((redefinition_fork, redefined_fields, renamed_types)) =
+                target_type.field_redefinitions()
+            {
+                if fork >= &redefinition_fork &&
+                    redefined_fields.contains(&ident.to_string().as_str())
+                {
+                    let suffix = format!("{redefinition_fork:?}");
+                    TypeRenamer::new(&renamed_types, &suffix).edit(&mut type_def);
+                    name = as_syn_ident(format!("{ident}_{}", redefinition_fork.name()));
+                }
+            }
+            let mut visitor = ToGenericsVisitor::default();
+            visitor.visit_type(&type_def);
+            let mut field_defn = FieldDefn {
+                fork: *fork,
+                name: name.clone(),
+                ident,
+                type_def,
+                type_generics: visitor.bounds,
+            };
+            if seen_fields.contains(&name) {
+                let target = fields.iter().position(|field| field.name == name).unwrap();
                 let field_to_update = &mut fields[target];
                 field_defn.fork = field_to_update.fork;
+                let mut type_generics = field_to_update.type_generics.clone();
+                for ident in field_defn.type_generics.drain(..) {
+                    if !type_generics.contains(&ident) {
+                        type_generics.push(ident);
+                    }
+                }
+                field_defn.type_generics = type_generics;
                 let _ = std::mem::replace(field_to_update, field_defn);
             } else {
                 fields.push(field_defn);
             }
-            seen_fields.insert(ident);
+            seen_fields.insert(name);
         }
     }
 
@@ -370,7 +436,7 @@ fn derive_type_defn(target_type: &Type, merge_type: &MergeType) -> (Item, Generi
         })
         .collect::<Vec<syn::Variant>>();
     let enum_defn = parse_quote! {
-        #[derive(Debug, Clone, PartialEq, Eq, Merkleized, serde::Serialize)]
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Merkleized, serde::Serialize)]
         #[ssz(transparent)]
         #[serde(untagged)]
         pub enum #type_name #generics {
@@ -387,14 +453,24 @@ fn derive_method_set(
     ref_type: &Option<RefType>,
 ) -> Vec<ImplItemMethod> {
     let ident = &field_defn.ident;
-    let deletion_fork = target_type.field_deletions().and_then(|(fork, fields)| {
-        let identifier = ident.to_string();
-        if fields.contains(&identifier.as_str()) {
-            Some(fork)
-        } else {
-            None
-        }
-    });
+    let name = &field_defn.name;
+    let is_redefinition = name != ident;
+    let deletion_fork = target_type
+        .field_deletions()
+        .or_else(|| {
+            target_type
+                .field_redefinitions()
+                .map(|(fork, fields, _)| (fork, fields))
+                .filter(|_| !is_redefinition)
+        })
+        .and_then(|(fork, fields)| {
+            let identifier = ident.to_string();
+            if fields.contains(&identifier.as_str()) {
+                Some(fork)
+            } else {
+                None
+            }
+        });
     let field_defined_in_all_forks = field_defn.fork == fork_sequence[0];
     let is_optional = deletion_fork.is_some() || !field_defined_in_all_forks;
     let is_polymorphic = target_type.polymorphic_fields().contains(&ident.to_string().as_ref());
@@ -403,10 +479,7 @@ fn derive_method_set(
         let base = target_type.get_polymorphic_type();
         let name = as_syn_ident(format!("{base}Ref"));
 
-        let ty = &field_defn.type_def;
-        let mut visitor = ToGenericsVisitor::default();
-        visitor.visit_type(ty);
-        let bounds = visitor.bounds;
+        let bounds = &field_defn.type_generics;
         let arguments: AngleBracketedGenericArguments = parse_quote! {
             <
             #(#bounds),*
@@ -441,22 +514,19 @@ fn derive_method_set(
         deletion_fork,
     );
     let immut_ref = parse_quote! {
-        pub fn #ident(&self) -> #ty {
+        pub fn #name(&self) -> #ty {
             match self {
                 #(#match_arms)*
             }
         }
     };
 
-    let mut_ident = as_syn_ident(format!("{ident}_mut"));
+    let mut_ident = as_syn_ident(format!("{name}_mut"));
     let ty_mut: syn::Type = if is_polymorphic {
         let base = target_type.get_polymorphic_type();
         let name = as_syn_ident(format!("{base}RefMut"));
 
-        let ty = &field_defn.type_def;
-        let mut visitor = ToGenericsVisitor::default();
-        visitor.visit_type(ty);
-        let bounds = visitor.bounds;
+        let bounds = &field_defn.type_generics;
         let arguments: AngleBracketedGenericArguments = parse_quote! {
             <
             #(#bounds),*
@@ -673,9 +743,9 @@ fn derive_ref_impl_type(
         })
         .collect::<Vec<syn::Variant>>();
     let derive_attr: Attribute = if is_mut {
-        parse_quote!(#[derive(Debug, PartialEq, Eq, Merkleized)])
+        parse_quote!(#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Merkleized)])
     } else {
-        parse_quote!(#[derive(Debug, PartialEq, Eq)])
+        parse_quote!(#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)])
     };
     let ssz_attr: Option<Attribute> =
         if is_mut { Some(parse_quote!(#[ssz(transparent)])) } else { None };
@@ -829,7 +899,8 @@ fn render(target_type: &Type, items: Vec<Item>) {
 }
 
 pub fn run() {
-    let fork_sequence = &[Fork::Phase0, Fork::Altair, Fork::Bellatrix, Fork::Capella, Fork::Deneb];
+    let fork_sequence =
+        &[Fork::Phase0, Fork::Altair, Fork::Bellatrix, Fork::Capella, Fork::Deneb, Fork::Electra];
     let types = [
         Type::BeaconBlockBody,
         Type::BeaconBlock,
@@ -844,7 +915,7 @@ pub fn run() {
 
     for target_type in &types {
         let defns = load_type_defns(target_type, fork_sequence);
-        let merge_type = derive_merge_type(fork_sequence, defns);
+        let merge_type = derive_merge_type(target_type, fork_sequence, defns);
         let output = as_syn(target_type, &merge_type);
         render(target_type, output)
     }
@@ -860,5 +931,6 @@ mod tests {
         assert_eq!(Fork::Phase0.cmp(&Fork::Altair), Ordering::Less);
         assert_eq!(Fork::Bellatrix.cmp(&Fork::Altair), Ordering::Greater);
         assert_eq!(Fork::Deneb.cmp(&Fork::Altair), Ordering::Greater);
+        assert_eq!(Fork::Electra.cmp(&Fork::Deneb), Ordering::Greater);
     }
 }
@@ -242,3 +242,59 @@ impl<'a> VisitMut for ArgumentsEditor<'a> {
         self.in_context = false;
     }
 }
+
+// Rewrites references to one preset, e.g. `phase0::minimal::PRESET` or `"minimal"`, to another.
+pub struct PresetRenamer<'a> {
+    from: &'a str,
+    to: &'a str,
+}
+
+impl<'a> PresetRenamer<'a> {
+    pub fn new(from: &'a str, to: &'a str) -> Self {
+        Self { from, to }
+    }
+
+    pub fn edit(&mut self, file: &mut syn::File) {
+        self.visit_file_mut(file);
+    }
+}
+
+impl<'a> VisitMut for PresetRenamer<'a> {
+    fn visit_ident_mut(&mut self, i: &mut Ident) {
+        if i == self.from {
+            *i = Ident::new(self.to, i.span());
+        }
+    }
+
+    fn visit_lit_str_mut(&mut self, i: &mut syn::LitStr) {
+        if i.value() == self.from {
+            *i = syn::LitStr::new(self.to, i.span());
+        }
+    }
+}
+
+// Appends `suffix` to the named types, e.g. `Attestation` to `AttestationElectra`, so a type
+// redefined in a later fork can be referenced alongside the original.
+pub struct TypeRenamer<'a> {
+    names: &'a [&'a str],
+    suffix: &'a str,
+}
+
+impl<'a> TypeRenamer<'a> {
+    pub fn new(names: &'a [&'a str], suffix: &'a str) -> Self {
+        Self { names, suffix }
+    }
+
+    pub fn edit(&mut self, ty: &mut Type) {
+        self.visit_type_mut(ty);
+    }
+}
+
+impl<'a> VisitMut for TypeRenamer<'a> {
+    fn visit_path_segment_mut(&mut self, i: &mut syn::PathSegment) {
+        if self.names.iter().any(|name| i.ident == name) {
+            i.ident = as_syn_ident(format!("{}{}", i.ident, self.suffix));
+        }
+        visit_mut::visit_path_segment_mut(self, i);
+    }
+}
@@ -1,6 +1,6 @@
 //! A thin wrapper around BLST with a more more idiomatic and ergonomic API
 use alloc::string::{String, ToString};
-use alloc::{vec, vec::Vec};
+use alloc::vec::Vec;
 use ssz_rs::prelude::*;
 
 use blst::min_pk as bls;
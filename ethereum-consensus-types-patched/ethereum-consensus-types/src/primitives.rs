@@ -1,4 +1,4 @@
-use alloc::{vec, vec::Vec};
+use alloc::vec::Vec;
 pub use ssz_rs::prelude::U256;
 use ssz_rs::prelude::*;
 
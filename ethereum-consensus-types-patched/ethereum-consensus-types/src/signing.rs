@@ -13,7 +13,7 @@ pub fn compute_signing_root(
     object_root: Node,
     domain: Domain,
 ) -> Result<Root, ssz_rs::MerkleizationError> {
-    let mut s = SigningData {
+    let s = SigningData {
         object_root,
         domain,
     };
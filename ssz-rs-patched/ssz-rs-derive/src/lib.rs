@@ -2,10 +2,11 @@
 //! native Rust structs and enums.
 //! Refer to the `examples` in the `ssz_rs` crate for a better idea on how to use this derive macro.
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote, quote_spanned};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{
-    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Field, Fields, Generics,
-    Ident, Meta, NestedMeta, PathArguments,
+    parse_macro_input, parse_quote, spanned::Spanned, Attribute, Data, DataEnum, DataStruct,
+    DeriveInput, Field, Fields, FieldsNamed, GenericArgument, Generics, Ident, Index, Lit, Member,
+    Meta, NestedMeta, Path, PathArguments, Type, Visibility,
 };
 
 // NOTE: copied here from `ssz_rs` crate as it is unlikely to change
@@ -14,31 +15,41 @@ const BYTES_PER_CHUNK: usize = 32;
 
 const SSZ_HELPER_ATTRIBUTE: &str = "ssz";
 
-fn derive_serialize_impl(data: &Data) -> TokenStream {
+// Containers with at most this many fields are merkleized in a buffer on the stack.
+const MAX_STACK_MERKLEIZATION_FIELDS: usize = 16;
+
+fn derive_serialize_impl(data: &Data, helper_attr: Option<&HelperAttr>) -> TokenStream {
+    // the encoding of a "newtype" is that of the wrapped value
+    if let Some((_, member)) = wrapped_field(data, helper_attr) {
+        return quote! {
+            fn serialize(&self, buffer: &mut Vec<u8>) -> Result<usize, ssz_rs::SerializeError> {
+                self.#member.serialize(buffer)
+            }
+
+            fn serialize_to_sink(
+                &self,
+                sink: &mut dyn ssz_rs::EncodingSink,
+            ) -> Result<usize, ssz_rs::SerializeError> {
+                self.#member.serialize_to_sink(sink)
+            }
+        }
+    }
     match data {
         Data::Struct(ref data) => {
             let fields = match data.fields {
                 // "regular" struct with 1+ fields
                 Fields::Named(ref fields) => &fields.named,
-                // "tuple" struct
-                // only support the case with one unnamed field, to support "newtype" pattern
-                Fields::Unnamed(..) => {
-                    return quote! {
-                        fn serialize(&self, buffer: &mut Vec<u8>) -> Result<usize, ssz_rs::SerializeError> {
-                                self.0.serialize(buffer)
-                        }
-                    }
-                }
                 _ => unimplemented!(
                     "this type of struct is currently not supported by this derive macro"
                 ),
             };
-            let serialization_by_field = fields.iter().map(|f| match &f.ident {
-                Some(field_name) => quote_spanned! { f.span() =>
-                    serializer.with_element(&self.#field_name)?;
-                },
-                None => panic!("should have already returned an impl"),
+            let serialization_by_field = fields.iter().map(|f| {
+                let element = field_element(f);
+                quote_spanned! { f.span() =>
+                    serializer.with_element(&#element)?;
+                }
             });
+            let elements = fields.iter().map(field_element).collect::<Vec<_>>();
 
             quote! {
                 fn serialize(&self, buffer: &mut Vec<u8>) -> Result<usize, ssz_rs::SerializeError> {
@@ -48,6 +59,20 @@ fn derive_serialize_impl(data: &Data) -> TokenStream {
 
                     serializer.serialize(buffer)
                 }
+
+                fn serialize_to_sink(
+                    &self,
+                    sink: &mut dyn ssz_rs::EncodingSink,
+                ) -> Result<usize, ssz_rs::SerializeError> {
+                    let mut serializer = ssz_rs::__internal::StreamingSerializer::default();
+
+                    #(serializer.measure(&#elements)?;)*
+                    let total_length = serializer.total_length()?;
+                    #(serializer.write_fixed(&#elements, sink)?;)*
+                    #(serializer.write_variable(&#elements, sink)?;)*
+
+                    Ok(total_length)
+                }
             }
         }
         Data::Enum(ref data) => {
@@ -75,43 +100,87 @@ fn derive_serialize_impl(data: &Data) -> TokenStream {
                 }
             });
 
+            let serialization_to_sink_by_variant =
+                data.variants.iter().enumerate().map(|(i, variant)| {
+                    let variant_name = &variant.ident;
+                    match &variant.fields {
+                        Fields::Unnamed(..) => {
+                            quote_spanned! { variant.span() =>
+                                Self::#variant_name(value) => {
+                                    let selector = #i as u8;
+                                    let selector_bytes = selector.serialize_to_sink(sink)?;
+                                    let value_bytes = value.serialize_to_sink(sink)?;
+                                    Ok(selector_bytes + value_bytes)
+                                }
+                            }
+                        }
+                        Fields::Unit => {
+                            quote_spanned! { variant.span() =>
+                                Self::None => 0u8.serialize_to_sink(sink),
+                            }
+                        }
+                        _ => unreachable!(),
+                    }
+                });
+
             quote! {
                 fn serialize(&self, buffer: &mut Vec<u8>) -> Result<usize, ssz_rs::SerializeError> {
                     match self {
                         #(#serialization_by_variant)*
                     }
                 }
+
+                fn serialize_to_sink(
+                    &self,
+                    sink: &mut dyn ssz_rs::EncodingSink,
+                ) -> Result<usize, ssz_rs::SerializeError> {
+                    match self {
+                        #(#serialization_to_sink_by_variant)*
+                    }
+                }
             }
         }
         Data::Union(..) => unreachable!("data was already validated to exclude union types"),
     }
 }
 
-fn derive_deserialize_impl(data: &Data) -> TokenStream {
+fn derive_deserialize_impl(
+    data: &Data,
+    name: &Ident,
+    helper_attr: Option<&HelperAttr>,
+) -> TokenStream {
+    if let Some((f, member)) = wrapped_field(data, helper_attr) {
+        let field_type = &f.ty;
+        return quote! {
+            fn deserialize(encoding: &[u8]) -> Result<Self, ssz_rs::DeserializeError> {
+                let result = <#field_type>::deserialize(&encoding)?;
+                Ok(Self { #member: result })
+            }
+
+            fn deserialize_from_source(
+                source: &mut dyn ssz_rs::DecodingSource,
+                len: Option<usize>,
+            ) -> Result<Self, ssz_rs::DeserializeError> {
+                let result = <#field_type>::deserialize_from_source(source, len)?;
+                Ok(Self { #member: result })
+            }
+
+            fn validate(encoding: &[u8]) -> Result<(), ssz_rs::DeserializeError> {
+                <#field_type>::validate(encoding)
+            }
+        }
+    }
     match data {
         Data::Struct(ref data) => {
             let fields = match data.fields {
                 // "regular" struct with 1+ fields
                 Fields::Named(ref fields) => &fields.named,
-                // "tuple" struct
-                // only support the case with one unnamed field, to support "newtype" pattern
-                Fields::Unnamed(ref fields) => {
-                    // SAFETY: index is safe because Punctuated always has a first element; qed
-                    let f = &fields.unnamed[0];
-                    let field_type = &f.ty;
-                    return quote! {
-                        fn deserialize(encoding: &[u8]) -> Result<Self, ssz_rs::DeserializeError> {
-                            let result = <#field_type>::deserialize(&encoding)?;
-                            Ok(Self(result))
-                        }
-                    }
-                }
                 _ => unimplemented!(
                     "this type of struct is currently not supported by this derive macro"
                 ),
             };
             let deserialization_by_field = fields.iter().map(|f| {
-                let field_type = &f.ty;
+                let field_type = field_encoding_type(f);
                 match &f.ident {
                     Some(_) => quote_spanned! { f.span() =>
                         deserializer.parse::<#field_type>(encoding)?;
@@ -122,14 +191,73 @@ fn derive_deserialize_impl(data: &Data) -> TokenStream {
 
             let initialization_by_field = fields.iter().enumerate().map(|(i, f)| {
                 let field_type = &f.ty;
+                let deserialize = match with_module(f) {
+                    Some(_) => {
+                        let marker = with_marker(f);
+                        quote! { <#marker as ssz_rs::__internal::EncodeWith>::deserialize }
+                    }
+                    None => quote! { <#field_type>::deserialize },
+                };
                 match &f.ident {
-                    Some(field_name) => quote_spanned! { f.span() =>
-                        #field_name: <#field_type>::deserialize(&encoding[spans[2*#i]..spans[2*#i+1]])?,
-                    },
+                    Some(field_name) => {
+                        let part = field_part(name, field_name);
+                        quote_spanned! { f.span() =>
+                            #field_name: #deserialize(&encoding[spans[2*#i]..spans[2*#i+1]])
+                                .map_err(|err| {
+                                    let len = spans[2*#i+1] - spans[2*#i];
+                                    ssz_rs::__internal::variable_part_error(err, len)
+                                        .in_part(#part, spans[2*#i])
+                                })?,
+                        }
+                    }
                     None => panic!("should have already returned an impl"),
                 }
             });
 
+            let streaming_deserialization_by_field = fields.iter().map(|f| {
+                let field_type = &f.ty;
+                quote_spanned! { f.span() =>
+                    deserializer.parse::<#field_type>(source)?;
+                }
+            });
+
+            // NOTE: fields are initialized in the order they are declared, which is the order
+            // their variable-size parts are read
+            let streaming_initialization_by_field = fields.iter().map(|f| {
+                let field_type = &f.ty;
+                let field_name = f.ident.as_ref().expect("field is named");
+                let part = field_part(name, field_name);
+                quote_spanned! { f.span() =>
+                    #field_name: deserializer.next::<#field_type>(source, #part)?,
+                }
+            });
+
+            // NOTE: a container with a field given `with` is read into a buffer before it is
+            // decoded
+            let deserialize_from_source_impl = fields.iter().all(|f| with_module(f).is_none()).then(|| quote! {
+                fn deserialize_from_source(
+                    source: &mut dyn ssz_rs::DecodingSource,
+                    len: Option<usize>,
+                ) -> Result<Self, ssz_rs::DeserializeError> {
+                    let mut deserializer = ssz_rs::__internal::StreamingDeserializer::new(len);
+
+                    #(#streaming_deserialization_by_field)*
+
+                    deserializer.finalize(source)?;
+
+                    Ok(Self {
+                        #(#streaming_initialization_by_field)*
+                    })
+                }
+            });
+
+            let validation_by_field = fields.iter().map(|f| {
+                let field_type = field_encoding_type(f);
+                quote_spanned! { f.span() =>
+                    validator.validate_field::<#field_type>()?;
+                }
+            });
+
             quote! {
                 fn deserialize(encoding: &[u8]) -> Result<Self, ssz_rs::DeserializeError> {
                     let mut deserializer = ssz_rs::__internal::ContainerDeserializer::default();
@@ -142,6 +270,19 @@ fn derive_deserialize_impl(data: &Data) -> TokenStream {
                         #(#initialization_by_field)*
                     })
                 }
+
+                #deserialize_from_source_impl
+
+                fn validate(encoding: &[u8]) -> Result<(), ssz_rs::DeserializeError> {
+                    let mut validator = ssz_rs::__internal::ContainerValidator::new(
+                        encoding,
+                        <Self as ssz_rs::Container>::FIELD_SIZES,
+                    )?;
+
+                    #(#validation_by_field)*
+
+                    Ok(())
+                }
             }
         }
         Data::Enum(ref data) => {
@@ -180,7 +321,66 @@ fn derive_deserialize_impl(data: &Data) -> TokenStream {
                     }
                 });
 
+            let streaming_deserialization_by_variant = data.variants.iter().enumerate().map(|(i, variant)| {
+                let i = i as u8;
+                let variant_name = &variant.ident;
+                match &variant.fields {
+                    Fields::Unnamed(inner) => {
+                        let variant_type = &inner.unnamed[0];
+                        quote_spanned! { variant.span() =>
+                            (#i, remaining) => {
+                                let value = <#variant_type>::deserialize_from_source(source, remaining)?;
+                                Ok(Self::#variant_name(value))
+                            }
+                        }
+                    }
+                    Fields::Unit => {
+                        quote_spanned! { variant.span() =>
+                            (0, remaining) => {
+                                ssz_rs::__internal::expect_empty(source, 1, remaining)?;
+                                Ok(Self::None)
+                            }
+                        }
+                    }
+                    _ => unreachable!(),
+                }
+            });
+
+            let validation_by_variant = data.variants.iter().enumerate().map(|(i, variant)| {
+                let i = i as u8;
+                match &variant.fields {
+                    Fields::Unnamed(inner) => {
+                        let variant_type = &inner.unnamed[0];
+                        quote_spanned! { variant.span() =>
+                            #i => <#variant_type>::validate(&encoding[1..]),
+                        }
+                    }
+                    Fields::Unit => {
+                        quote_spanned! { variant.span() =>
+                            0 if encoding.len() != 1 => {
+                                Err(ssz_rs::DeserializeError::AdditionalInput {
+                                    provided: encoding.len(),
+                                    expected: 1,
+                                })
+                            }
+                            0 => Ok(()),
+                        }
+                    }
+                    _ => unreachable!(),
+                }
+            });
+
             quote! {
+                fn deserialize_from_source(
+                    source: &mut dyn ssz_rs::DecodingSource,
+                    len: Option<usize>,
+                ) -> Result<Self, ssz_rs::DeserializeError> {
+                    match ssz_rs::__internal::read_selector(source, len)? {
+                        #(#streaming_deserialization_by_variant)*
+                        (b, _) => Err(ssz_rs::DeserializeError::InvalidByte(b)),
+                    }
+                }
+
                 fn deserialize(encoding: &[u8]) -> Result<Self, ssz_rs::DeserializeError> {
                     if encoding.is_empty() {
                         return Err(ssz_rs::DeserializeError::ExpectedFurtherInput {
@@ -195,105 +395,213 @@ fn derive_deserialize_impl(data: &Data) -> TokenStream {
                         b => Err(ssz_rs::DeserializeError::InvalidByte(b)),
                     }
                 }
+
+                fn validate(encoding: &[u8]) -> Result<(), ssz_rs::DeserializeError> {
+                    if encoding.is_empty() {
+                        return Err(ssz_rs::DeserializeError::ExpectedFurtherInput {
+                            provided: 0,
+                            expected: 1,
+                        });
+                    }
+
+                    // SAFETY: index is safe because encoding isn't empty; qed
+                    match encoding[0] {
+                        #(#validation_by_variant)*
+                        b => Err(ssz_rs::DeserializeError::InvalidByte(b)),
+                    }
+                }
             }
         }
         Data::Union(..) => unreachable!("data was already validated to exclude union types"),
     }
 }
 
-fn derive_variable_size_impl(data: &Data) -> TokenStream {
+fn derive_fixed_size_impl(data: &Data, helper_attr: Option<&HelperAttr>) -> TokenStream {
+    if let Some((f, _)) = wrapped_field(data, helper_attr) {
+        let field_type = &f.ty;
+        return quote! { <#field_type as ssz_rs::Serializable>::FIXED_SIZE }
+    }
     match data {
         Data::Struct(ref data) => {
             let fields = match data.fields {
                 Fields::Named(ref fields) => &fields.named,
-                Fields::Unnamed(ref fields) => &fields.unnamed,
                 _ => unimplemented!(
                     "this type of struct is currently not supported by this derive macro"
                 ),
             };
             let impl_by_field = fields.iter().map(|f| {
-                let field_type = &f.ty;
+                let field_type = field_encoding_type(f);
                 quote_spanned! { f.span() =>
-                    <#field_type>::is_variable_size()
+                    <#field_type as ssz_rs::Serializable>::FIXED_SIZE
                 }
             });
 
             quote! {
-                #(#impl_by_field)|| *
+                ssz_rs::__internal::fixed_size_of_fields(&[#(#impl_by_field),*])
             }
         }
         Data::Enum(..) => {
-            quote! { true }
+            quote! { None }
         }
         Data::Union(..) => unreachable!("data was already validated to exclude union types"),
     }
 }
 
-fn derive_size_hint_impl(data: &Data) -> TokenStream {
+fn derive_serialized_length_impl(data: &Data, helper_attr: Option<&HelperAttr>) -> TokenStream {
+    if let Some((_, member)) = wrapped_field(data, helper_attr) {
+        return quote! {
+            fn serialized_length(&self) -> Result<usize, ssz_rs::SerializeError> {
+                self.#member.serialized_length()
+            }
+        }
+    }
     match data {
         Data::Struct(ref data) => {
             let fields = match data.fields {
                 Fields::Named(ref fields) => &fields.named,
-                Fields::Unnamed(ref fields) => &fields.unnamed,
                 _ => unimplemented!(
                     "this type of struct is currently not supported by this derive macro"
                 ),
             };
-            let impl_by_field = fields.iter().map(|f| {
-                let field_type = &f.ty;
-                quote_spanned! { f.span() =>
-                    <#field_type>::size_hint()
+            let elements = fields.iter().map(field_element);
+
+            quote! {
+                fn serialized_length(&self) -> Result<usize, ssz_rs::SerializeError> {
+                    let mut serializer = ssz_rs::__internal::StreamingSerializer::default();
+                    #(serializer.measure(&#elements)?;)*
+                    serializer.total_length()
+                }
+            }
+        }
+        Data::Enum(ref data) => {
+            let length_by_variant = data.variants.iter().map(|variant| {
+                let variant_name = &variant.ident;
+                match &variant.fields {
+                    Fields::Unnamed(..) => {
+                        quote_spanned! { variant.span() =>
+                            Self::#variant_name(value) => Ok(1 + value.serialized_length()?),
+                        }
+                    }
+                    Fields::Unit => {
+                        quote_spanned! { variant.span() =>
+                            Self::None => Ok(1),
+                        }
+                    }
+                    _ => unreachable!(),
                 }
             });
 
             quote! {
-                if Self::is_variable_size() {
-                    0
-                } else {
-                    #(#impl_by_field)+ *
+                fn serialized_length(&self) -> Result<usize, ssz_rs::SerializeError> {
+                    match self {
+                        #(#length_by_variant)*
+                    }
                 }
             }
         }
-        Data::Enum(..) => {
-            quote! { 0 }
-        }
         Data::Union(..) => unreachable!("data was already validated to exclude union types"),
     }
 }
 
+// Computes `chunks`, the hash tree roots of each field of a container in order.
+// Write the root of each field into a buffer `chunks`. If `leaf_count` is given, the buffer is
+// an array on the stack, padded with "zero" chunks up to that many leaves.
+fn derive_field_chunks_impl<'a>(
+    fields: impl IntoIterator<Item = &'a Field>,
+    leaf_count: Option<usize>,
+) -> TokenStream {
+    let fields = fields.into_iter().collect::<Vec<_>>();
+    let field_count = fields.len();
+    let impl_by_field = fields.iter().enumerate().map(|(i, f)| match (&f.ident, with_module(f)) {
+        (Some(field_name), Some(module)) => quote_spanned! { f.span() =>
+            let chunk = #module::hash_tree_root(&self.#field_name)?;
+            let range = #i*#BYTES_PER_CHUNK..(#i+1)*#BYTES_PER_CHUNK;
+            chunks[range].copy_from_slice(chunk.as_ref());
+        },
+        (Some(field_name), None) => quote_spanned! { f.span() =>
+            let chunk = self.#field_name.hash_tree_root()?;
+            let range = #i*#BYTES_PER_CHUNK..(#i+1)*#BYTES_PER_CHUNK;
+            chunks[range].copy_from_slice(chunk.as_ref());
+        },
+        (None, _) => quote_spanned! { f.span() =>
+            let chunk = self.0.hash_tree_root()?;
+            let range = #i*#BYTES_PER_CHUNK..(#i+1)*#BYTES_PER_CHUNK;
+            chunks[range].copy_from_slice(chunk.as_ref());
+        },
+    });
+    let buffer = match leaf_count {
+        Some(leaf_count) => quote! { [0u8; #leaf_count * #BYTES_PER_CHUNK] },
+        None => quote! { ssz_rs::__internal::zeroed_chunks(#field_count) },
+    };
+    quote! {
+        let mut chunks = #buffer;
+        #(#impl_by_field)*
+    }
+}
+
+// The generics of an impl of a trait for a type with `generics`, where each type parameter is
+// bounded by `bound` on top of the bounds it was declared with, e.g. so that a field of type `T`
+// of a `Signed<T>` container can be serialized in the impl of `Serializable` for `Signed<T>`.
+fn bounded_generics(generics: &Generics, bound: TokenStream) -> Generics {
+    let mut generics = generics.clone();
+    let type_params = generics.type_params().map(|param| param.ident.clone()).collect::<Vec<_>>();
+    let where_clause = generics.make_where_clause();
+    for param in type_params {
+        where_clause.predicates.push(parse_quote! { #param: #bound });
+    }
+    generics
+}
+
 fn derive_merkleization_impl(
     data: &Data,
     name: &Ident,
     generics: &Generics,
     helper_attr: Option<&HelperAttr>,
 ) -> TokenStream {
+    if let Some(attr @ (HelperAttr::StableContainer(..) | HelperAttr::Profile(..))) = helper_attr {
+        return derive_stable_merkleization_impl(data, name, generics, attr)
+    }
+    let generics = bounded_generics(generics, quote! { ssz_rs::SimpleSerialize });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    // the tree of a "newtype" is that of the wrapped value
+    if let Some((_, member)) = wrapped_field(data, helper_attr) {
+        return quote! {
+            impl #impl_generics ssz_rs::Merkleized for #name #ty_generics #where_clause {
+                fn hash_tree_root(&self) -> Result<ssz_rs::Node, ssz_rs::MerkleizationError> {
+                    self.#member.hash_tree_root()
+                }
+            }
+        }
+    }
     let method = match data {
         Data::Struct(ref data) => {
             let fields = match data.fields {
                 Fields::Named(ref fields) => &fields.named,
-                Fields::Unnamed(ref fields) => &fields.unnamed,
                 _ => unimplemented!(
                     "this type of struct is currently not supported by this derive macro"
                 ),
             };
-            let field_count = fields.iter().len();
-            let impl_by_field = fields.iter().enumerate().map(|(i, f)| match &f.ident {
-                Some(field_name) => quote_spanned! { f.span() =>
-                    let chunk = self.#field_name.hash_tree_root()?;
-                    let range = #i*#BYTES_PER_CHUNK..(#i+1)*#BYTES_PER_CHUNK;
-                    chunks[range].copy_from_slice(chunk.as_ref());
-                },
-                None => quote_spanned! { f.span() =>
-                    let chunk = self.0.hash_tree_root()?;
-                    let range = #i*#BYTES_PER_CHUNK..(#i+1)*#BYTES_PER_CHUNK;
-                    chunks[range].copy_from_slice(chunk.as_ref());
-                },
-            });
-            quote! {
-                fn hash_tree_root(&mut self) -> Result<ssz_rs::Node, ssz_rs::MerkleizationError> {
-                    let mut chunks = vec![0u8; #field_count * #BYTES_PER_CHUNK];
-                    #(#impl_by_field)*
-                    ssz_rs::__internal::merkleize(&chunks, None)
+            // avoid any heap allocation for small containers, as these are often hashed in bulk
+            if !fields.is_empty() && fields.len() <= MAX_STACK_MERKLEIZATION_FIELDS {
+                let chunks_impl =
+                    derive_field_chunks_impl(fields, Some(fields.len().next_power_of_two()));
+                quote! {
+                    fn hash_tree_root(&self) -> Result<ssz_rs::Node, ssz_rs::MerkleizationError> {
+                        ssz_rs::__internal::measure_hash_tree_root::<Self>(|| {
+                            #chunks_impl
+                            Ok(ssz_rs::__internal::merkleize_in_place(&mut chunks))
+                        })
+                    }
+                }
+            } else {
+                let chunks_impl = derive_field_chunks_impl(fields, None);
+                quote! {
+                    fn hash_tree_root(&self) -> Result<ssz_rs::Node, ssz_rs::MerkleizationError> {
+                        ssz_rs::__internal::measure_hash_tree_root::<Self>(|| {
+                            #chunks_impl
+                            ssz_rs::__internal::merkleize(&chunks, None)
+                        })
+                    }
                 }
             }
         }
@@ -303,7 +611,7 @@ fn derive_merkleization_impl(
                 match &variant.fields {
                     Fields::Unnamed(..) => {
                         // NOTE: validated to only be `transparent` operation at this point...
-                        if helper_attr.is_some() {
+                        if matches!(helper_attr, Some(HelperAttr::Transparent)) {
                             quote_spanned! { variant.span() =>
                                Self::#variant_name(value) => value.hash_tree_root(),
                             }
@@ -329,7 +637,7 @@ fn derive_merkleization_impl(
                 }
             });
             quote! {
-                fn hash_tree_root(&mut self) -> Result<ssz_rs::Node, ssz_rs::MerkleizationError> {
+                fn hash_tree_root(&self) -> Result<ssz_rs::Node, ssz_rs::MerkleizationError> {
                     match self {
                             #(#hash_tree_root_by_variant)*
                     }
@@ -338,14 +646,241 @@ fn derive_merkleization_impl(
         }
         Data::Union(..) => unreachable!("data was already validated to exclude union types"),
     };
-    let (impl_generics, ty_generics, _) = generics.split_for_impl();
     quote! {
-        impl #impl_generics ssz_rs::Merkleized for #name #ty_generics {
+        impl #impl_generics ssz_rs::Merkleized for #name #ty_generics #where_clause {
             #method
         }
     }
 }
 
+fn derive_prove_impl(
+    data: &Data,
+    name: &Ident,
+    generics: &Generics,
+    helper_attr: Option<&HelperAttr>,
+) -> TokenStream {
+    if let Some(attr @ (HelperAttr::StableContainer(..) | HelperAttr::Profile(..))) = helper_attr {
+        return derive_stable_prove_impl(data, name, generics, attr)
+    }
+    let wrapped = wrapped_field(data, helper_attr);
+    let body = match data {
+        // the tree of a "newtype" has the same root as the wrapped value
+        Data::Struct(..) if wrapped.is_some() => {
+            let (_, member) = wrapped.expect("struct is a newtype");
+            quote! {
+                ssz_rs::Prove::prove_path(&self.#member, path, branch)
+            }
+        }
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => {
+                let prove_by_field = fields.named.iter().enumerate().map(|(i, f)| {
+                    let field_name = f.ident.as_ref().expect("field is named");
+                    let field_name_str = field_name.to_string();
+                    match with_module(f) {
+                        // the tree of a field given `with` is opaque below its root
+                        Some(module) => quote_spanned! { f.span() =>
+                            #field_name_str => match rest.first() {
+                                Some(element) => return Err(ssz_rs::MerkleizationError::InvalidPathElement(element.clone())),
+                                None => (#i, (#module::hash_tree_root(&self.#field_name)?, 0)),
+                            },
+                        },
+                        None => quote_spanned! { f.span() =>
+                            #field_name_str => {
+                                (#i, ssz_rs::Prove::prove_path(&self.#field_name, rest, branch)?)
+                            }
+                        },
+                    }
+                });
+                let chunks_impl = derive_field_chunks_impl(&fields.named, None);
+                quote! {
+                    let (element, rest) = match path.split_first() {
+                        Some(next) => next,
+                        None => return Ok((ssz_rs::Merkleized::hash_tree_root(self)?, 0)),
+                    };
+                    let start = branch.len();
+                    let (field_index, (leaf, index)) = match element {
+                        ssz_rs::PathElement::Field(field) => match field.as_str() {
+                            #(#prove_by_field)*
+                            _ => return Err(ssz_rs::MerkleizationError::InvalidPathElement(element.clone())),
+                        },
+                        _ => return Err(ssz_rs::MerkleizationError::InvalidPathElement(element.clone())),
+                    };
+                    let inner_depth = branch.len() - start;
+                    #chunks_impl
+                    ssz_rs::__internal::extend_branch(&chunks, None, field_index, branch)?;
+                    Ok((leaf, (field_index << inner_depth) | index))
+                }
+            }
+            _ => unimplemented!(
+                "this type of struct is currently not supported by this derive macro"
+            ),
+        },
+        Data::Enum(ref data) => {
+            if matches!(helper_attr, Some(HelperAttr::Transparent)) {
+                let prove_by_variant = data.variants.iter().map(|variant| {
+                    let variant_name = &variant.ident;
+                    quote_spanned! { variant.span() =>
+                        Self::#variant_name(value) => ssz_rs::Prove::prove_path(value, path, branch),
+                    }
+                });
+                quote! {
+                    match self {
+                        #(#prove_by_variant)*
+                    }
+                }
+            } else {
+                let prove_by_variant =
+                    data.variants.iter().enumerate().filter_map(|(i, variant)| {
+                        let variant_name = &variant.ident;
+                        match &variant.fields {
+                            Fields::Unnamed(..) => Some(quote_spanned! { variant.span() =>
+                                (ssz_rs::PathElement::Index(#i), Self::#variant_name(value)) => {
+                                    let proof = ssz_rs::Prove::prove_path(value, rest, branch)?;
+                                    let selector: usize = #i;
+                                    branch.push(ssz_rs::Merkleized::hash_tree_root(&selector)?);
+                                    Ok(proof)
+                                }
+                            }),
+                            _ => None,
+                        }
+                    });
+                quote! {
+                    let (element, rest) = match path.split_first() {
+                        Some(next) => next,
+                        None => return Ok((ssz_rs::Merkleized::hash_tree_root(self)?, 0)),
+                    };
+                    match (element, self) {
+                        #(#prove_by_variant)*
+                        _ => Err(ssz_rs::MerkleizationError::InvalidPathElement(element.clone())),
+                    }
+                }
+            }
+        }
+        Data::Union(..) => unreachable!("data was already validated to exclude union types"),
+    };
+    let generics = bounded_generics(generics, quote! { ssz_rs::SimpleSerialize });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics ssz_rs::Prove for #name #ty_generics #where_clause {
+            fn prove_path(
+                &self,
+                path: ssz_rs::Path,
+                branch: &mut Vec<ssz_rs::Node>,
+            ) -> Result<(ssz_rs::Node, usize), ssz_rs::MerkleizationError> {
+                #body
+            }
+        }
+    }
+}
+
+// Returns the field wrapped by a "newtype", i.e. a tuple struct with one field or a `transparent`
+// struct with one named field, along with the member to access it by.
+fn wrapped_field<'a>(
+    data: &'a Data,
+    helper_attr: Option<&HelperAttr>,
+) -> Option<(&'a Field, Member)> {
+    let Data::Struct(data) = data else { return None };
+    match &data.fields {
+        // SAFETY: index is safe because Punctuated always has a first element; qed
+        Fields::Unnamed(fields) => Some((&fields.unnamed[0], Member::Unnamed(Index::from(0)))),
+        Fields::Named(fields) if matches!(helper_attr, Some(HelperAttr::Transparent)) => {
+            let field = &fields.named[0];
+            Some((field, Member::Named(field.ident.clone().expect("field is named"))))
+        }
+        _ => None,
+    }
+}
+
+// Returns the module given to a field by `#[ssz(with = "module")]`, which encodes and merkleizes
+// the field in place of its type.
+//
+// Panics if the field has any other `ssz` attribute.
+fn with_module(field: &Field) -> Option<Path> {
+    let mut module = None;
+    for attr in filter_ssz_attrs(field.attrs.iter()) {
+        let args = match attr.parse_meta().unwrap() {
+            Meta::List(args) => args.nested,
+            _ => panic!("only list-like attributes are supported"),
+        };
+        for arg in args {
+            match arg {
+                NestedMeta::Meta(Meta::NameValue(arg)) if arg.path.is_ident("with") => {
+                    let Lit::Str(path) = arg.lit else {
+                        panic!("`with` takes the path of a module as a string")
+                    };
+                    if module.is_some() {
+                        panic!("`with` can only be given once per field")
+                    }
+                    module = Some(path.parse().expect("`with` takes the path of a module"));
+                }
+                _ => panic!("only `with` is supported as an attribute of a field"),
+            }
+        }
+    }
+    module
+}
+
+// Returns the part of a container locating an error decoding the field `field_name`.
+fn field_part(name: &Ident, field_name: &Ident) -> TokenStream {
+    let (name, field_name) = (name.to_string(), field_name.to_string());
+    quote! { ssz_rs::DecodingPart::Field { container: #name, name: #field_name } }
+}
+
+// Returns the name of the type implementing `EncodeWith` for a field given `with`.
+fn with_marker(field: &Field) -> Ident {
+    format_ident!("__SszWith_{}", field.ident.as_ref().expect("field is named"))
+}
+
+fn derive_with_marker(field: &Field) -> Option<TokenStream> {
+    let module = with_module(field)?;
+    let marker = with_marker(field);
+    let field_type = &field.ty;
+    Some(quote_spanned! { field.span() =>
+        #[allow(non_camel_case_types)]
+        struct #marker;
+
+        impl ssz_rs::__internal::EncodeWith for #marker {
+            type Value = #field_type;
+
+            const FIXED_SIZE: Option<usize> = #module::FIXED_SIZE;
+
+            fn serialize(
+                value: &Self::Value,
+                buffer: &mut Vec<u8>,
+            ) -> Result<usize, ssz_rs::SerializeError> {
+                #module::serialize(value, buffer)
+            }
+
+            fn deserialize(encoding: &[u8]) -> Result<Self::Value, ssz_rs::DeserializeError> {
+                #module::deserialize(encoding)
+            }
+        }
+    })
+}
+
+// Returns the type a field of a container is encoded as.
+fn field_encoding_type(field: &Field) -> TokenStream {
+    match with_module(field) {
+        Some(_) => {
+            let marker = with_marker(field);
+            quote! { ssz_rs::__internal::With<'static, #marker> }
+        }
+        None => field.ty.to_token_stream(),
+    }
+}
+
+// Returns an expression for the value of a field of `self` to encode.
+fn field_element(field: &Field) -> TokenStream {
+    let field_name = field.ident.as_ref().expect("field is named");
+    match with_module(field) {
+        Some(_) => {
+            let marker = with_marker(field);
+            quote! { ssz_rs::__internal::With::<#marker>::Borrowed(&self.#field_name) }
+        }
+        None => quote! { self.#field_name },
+    }
+}
+
 fn is_valid_none_identifier(ident: &Ident) -> bool {
     *ident == format_ident!("None")
 }
@@ -382,10 +917,26 @@ fn validate_derive_input(data: &Data, helper_attrs: &[HelperAttr]) {
     if let Some(attr) = helper_attrs.first() {
         match attr {
             HelperAttr::Transparent => is_transparent = true,
+            HelperAttr::StableContainer(..) | HelperAttr::Profile(..) => {
+                return validate_stable_input(data, attr)
+            }
+            HelperAttr::SpecCheck(..) => {
+                if !matches!(data, Data::Struct(DataStruct { fields: Fields::Named(..), .. })) {
+                    panic!("`spec_check` option is only compatible with structs with named fields")
+                }
+            }
         }
     }
-    if is_transparent && !matches!(data, Data::Enum(..)) {
-        panic!("`transparent` option is only compatible with enums")
+    let is_newtype = match data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields.named.len() == 1,
+            Fields::Unnamed(..) => true,
+            Fields::Unit => false,
+        },
+        _ => false,
+    };
+    if is_transparent && !(is_newtype || matches!(data, Data::Enum(..))) {
+        panic!("`transparent` option is only compatible with enums and structs with one field")
     }
     match data {
         Data::Struct(ref data) => match data.fields {
@@ -393,7 +944,13 @@ fn validate_derive_input(data: &Data, helper_attrs: &[HelperAttr]) {
                 if fields.named.is_empty() {
                     panic!("ssz_rs containers with no fields are illegal")
                 }
-                validate_no_attrs(fields.named.iter())
+                if is_transparent {
+                    validate_no_attrs(fields.named.iter())
+                } else {
+                    fields.named.iter().for_each(|f| {
+                        with_module(f);
+                    })
+                }
             }
             Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => {
                 validate_no_attrs(fields.unnamed.iter())
@@ -462,49 +1019,971 @@ fn validate_derive_input(data: &Data, helper_attrs: &[HelperAttr]) {
     }
 }
 
+// Validates a struct to derive as an EIP-7495 stable container or profile.
+fn validate_stable_input(data: &Data, helper_attr: &HelperAttr) {
+    let fields = match data {
+        Data::Struct(DataStruct { fields: Fields::Named(fields), .. }) => &fields.named,
+        _ => panic!("stable containers and profiles must be structs with named fields"),
+    };
+    if fields.is_empty() {
+        panic!("ssz_rs containers with no fields are illegal")
+    }
+    validate_no_attrs(fields.iter());
+    if let HelperAttr::StableContainer(capacity) = helper_attr {
+        if fields.len() > *capacity {
+            panic!("a stable container cannot have more fields than its capacity")
+        }
+        if fields.iter().any(|f| optional_value_type(&f.ty).is_none()) {
+            panic!("every field of a stable container must be an `Optional`")
+        }
+    }
+}
+
+// Returns `T` if `ty` is `Optional<T>`.
+fn optional_value_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(ty) = ty else { return None };
+    let segment = ty.path.segments.last()?;
+    if segment.ident != "Optional" {
+        return None
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// A field of a stable container or profile.
+struct StableField<'a> {
+    field: &'a Field,
+    // the type of the value of the field, which is only present if `is_optional` is `false` or
+    // the field is `Some`
+    value_type: &'a Type,
+    is_optional: bool,
+    // the index of the field in the tree
+    index: TokenStream,
+}
+
+// The fields of a stable container or profile, along with the number of fields its tree has
+// room for and the number of bits in the bitvector leading its encoding, if any.
+fn stable_layout<'a>(
+    fields: &'a FieldsNamed,
+    helper_attr: &HelperAttr,
+) -> (Vec<StableField<'a>>, TokenStream, Option<usize>) {
+    let stable_fields = fields
+        .named
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let index = match helper_attr {
+                HelperAttr::Profile(..) => quote! { <Self as ssz_rs::Profile>::FIELD_INDICES[#i] },
+                _ => quote! { #i },
+            };
+            match optional_value_type(&field.ty) {
+                Some(value_type) => StableField { field, value_type, is_optional: true, index },
+                None => StableField { field, value_type: &field.ty, is_optional: false, index },
+            }
+        })
+        .collect::<Vec<_>>();
+    let optional_count = stable_fields.iter().filter(|f| f.is_optional).count();
+    match helper_attr {
+        HelperAttr::StableContainer(capacity) => {
+            (stable_fields, quote! { #capacity }, Some(*capacity))
+        }
+        HelperAttr::Profile(base) => {
+            let bit_count = if optional_count > 0 { Some(optional_count) } else { None };
+            (stable_fields, quote! { <#base as ssz_rs::StableContainer>::CAPACITY }, bit_count)
+        }
+        HelperAttr::Transparent | HelperAttr::SpecCheck(..) => {
+            unreachable!("only stable containers and profiles have a layout")
+        }
+    }
+}
+
+fn named_fields(data: &Data) -> &FieldsNamed {
+    match data {
+        Data::Struct(DataStruct { fields: Fields::Named(fields), .. }) => fields,
+        _ => unreachable!("data was already validated to be a struct with named fields"),
+    }
+}
+
+fn derive_stable_serializable_impl(
+    data: &Data,
+    name: &Ident,
+    generics: &Generics,
+    helper_attr: &HelperAttr,
+) -> TokenStream {
+    let fields = named_fields(data);
+    let (stable_fields, _, bit_count) = stable_layout(fields, helper_attr);
+    let optional_fields = stable_fields.iter().filter(|f| f.is_optional).collect::<Vec<_>>();
+    let optional_count = optional_fields.len();
+
+    let write_active_fields = bit_count.map(|bit_count| {
+        let field_names = optional_fields.iter().map(|f| &f.field.ident);
+        quote! {
+            let active_fields = [#(Option::is_some(&self.#field_names)),*];
+            ssz_rs::__internal::serialize_active_fields(&active_fields, #bit_count, buffer);
+        }
+    });
+    let serialization_by_field = stable_fields.iter().map(|f| {
+        let field_name = &f.field.ident;
+        if f.is_optional {
+            quote_spanned! { f.field.span() =>
+                if let Some(value) = Option::as_ref(&self.#field_name) {
+                    serializer.with_element(value)?;
+                }
+            }
+        } else {
+            quote_spanned! { f.field.span() =>
+                serializer.with_element(&self.#field_name)?;
+            }
+        }
+    });
+
+    let read_active_fields = bit_count.map(|bit_count| {
+        quote! {
+            let (active_fields, encoding) = ssz_rs::__internal::deserialize_active_fields(
+                encoding,
+                #bit_count,
+                #optional_count,
+            )?;
+        }
+    });
+    let mut optional_index = 0usize..;
+    let (deserialization_by_field, initialization_by_field): (Vec<_>, Vec<_>) = stable_fields
+        .iter()
+        .map(|f| {
+            let field_name = &f.field.ident;
+            let value_type = f.value_type;
+            if f.is_optional {
+                let i = optional_index.next().expect("range is unbounded");
+                let deserialization = quote_spanned! { f.field.span() =>
+                    if active_fields[#i] {
+                        deserializer.parse::<#value_type>(encoding)?;
+                    }
+                };
+                let initialization = quote_spanned! { f.field.span() =>
+                    #field_name: if active_fields[#i] {
+                        let part = parts.next().expect("a part was parsed for each field present");
+                        Some(<#value_type>::deserialize(part)?).into()
+                    } else {
+                        Default::default()
+                    },
+                };
+                (deserialization, initialization)
+            } else {
+                let deserialization = quote_spanned! { f.field.span() =>
+                    deserializer.parse::<#value_type>(encoding)?;
+                };
+                let initialization = quote_spanned! { f.field.span() =>
+                    #field_name: {
+                        let part = parts.next().expect("a part was parsed for each field present");
+                        <#value_type>::deserialize(part)?
+                    },
+                };
+                (deserialization, initialization)
+            }
+        })
+        .unzip();
+
+    let fixed_size = match bit_count {
+        Some(..) => quote! { None },
+        None => {
+            let field_types = fields.named.iter().map(|f| &f.ty);
+            quote! {
+                ssz_rs::__internal::fixed_size_of_fields(
+                    &[#(<#field_types as ssz_rs::Serializable>::FIXED_SIZE),*],
+                )
+            }
+        }
+    };
+
+    let generics = bounded_generics(generics, quote! { ssz_rs::Serializable });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let stable_impl = match helper_attr {
+        HelperAttr::StableContainer(capacity) => {
+            let field_names =
+                fields.named.iter().map(|f| f.ident.as_ref().expect("field is named").to_string());
+            quote! {
+                impl #impl_generics ssz_rs::StableContainer for #name #ty_generics #where_clause {
+                    const CAPACITY: usize = #capacity;
+                    const FIELD_NAMES: &'static [&'static str] = &[#(#field_names),*];
+                }
+            }
+        }
+        HelperAttr::Profile(base) => {
+            let field_names =
+                fields.named.iter().map(|f| f.ident.as_ref().expect("field is named").to_string());
+            quote! {
+                impl #impl_generics ssz_rs::Profile for #name #ty_generics #where_clause {
+                    type Base = #base;
+
+                    const FIELD_INDICES: &'static [usize] =
+                        &ssz_rs::__internal::profile_field_indices(
+                            <#base as ssz_rs::StableContainer>::FIELD_NAMES,
+                            [#(#field_names),*],
+                        );
+                }
+            }
+        }
+        HelperAttr::Transparent | HelperAttr::SpecCheck(..) => {
+            unreachable!("only stable containers and profiles have a layout")
+        }
+    };
+
+    quote! {
+        impl #impl_generics ssz_rs::Serialize for #name #ty_generics #where_clause {
+            fn serialize(&self, buffer: &mut Vec<u8>) -> Result<usize, ssz_rs::SerializeError> {
+                let start = buffer.len();
+                #write_active_fields
+                let mut serializer = ssz_rs::__internal::Serializer::default();
+
+                #(#serialization_by_field)*
+
+                serializer.serialize(buffer)?;
+                Ok(buffer.len() - start)
+            }
+        }
+
+        impl #impl_generics ssz_rs::Deserialize for #name #ty_generics #where_clause {
+            fn deserialize(encoding: &[u8]) -> Result<Self, ssz_rs::DeserializeError> {
+                #read_active_fields
+                let mut deserializer = ssz_rs::__internal::ContainerDeserializer::default();
+
+                #(#deserialization_by_field)*
+
+                let spans = deserializer.finalize(encoding)?;
+                let mut parts = spans.chunks_exact(2).map(|span| &encoding[span[0]..span[1]]);
+
+                Ok(Self {
+                    #(#initialization_by_field)*
+                })
+            }
+        }
+
+        impl #impl_generics ssz_rs::Serializable for #name #ty_generics #where_clause {
+            const FIXED_SIZE: Option<usize> = #fixed_size;
+        }
+
+        #stable_impl
+    }
+}
+
+// Computes `roots`, the index of each field of a stable container or profile in its tree along
+// with the root of the field, if present.
+fn derive_stable_roots_impl(stable_fields: &[StableField]) -> TokenStream {
+    let root_by_field = stable_fields.iter().map(|f| {
+        let field_name = &f.field.ident;
+        let index = &f.index;
+        if f.is_optional {
+            quote_spanned! { f.field.span() =>
+                (
+                    #index,
+                    Option::as_ref(&self.#field_name)
+                        .map(ssz_rs::Merkleized::hash_tree_root)
+                        .transpose()?,
+                )
+            }
+        } else {
+            quote_spanned! { f.field.span() =>
+                (#index, Some(ssz_rs::Merkleized::hash_tree_root(&self.#field_name)?))
+            }
+        }
+    });
+    quote! {
+        let roots = [#(#root_by_field),*];
+    }
+}
+
+fn derive_stable_merkleization_impl(
+    data: &Data,
+    name: &Ident,
+    generics: &Generics,
+    helper_attr: &HelperAttr,
+) -> TokenStream {
+    let (stable_fields, capacity, _) = stable_layout(named_fields(data), helper_attr);
+    let roots_impl = derive_stable_roots_impl(&stable_fields);
+    let generics = bounded_generics(generics, quote! { ssz_rs::SimpleSerialize });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics ssz_rs::Merkleized for #name #ty_generics #where_clause {
+            fn hash_tree_root(&self) -> Result<ssz_rs::Node, ssz_rs::MerkleizationError> {
+                ssz_rs::__internal::measure_hash_tree_root::<Self>(|| {
+                    #roots_impl
+                    ssz_rs::__internal::merkleize_stable_fields(&roots, #capacity)
+                })
+            }
+        }
+    }
+}
+
+fn derive_stable_prove_impl(
+    data: &Data,
+    name: &Ident,
+    generics: &Generics,
+    helper_attr: &HelperAttr,
+) -> TokenStream {
+    let (stable_fields, capacity, _) = stable_layout(named_fields(data), helper_attr);
+    let prove_by_field = stable_fields.iter().map(|f| {
+        let field_name = f.field.ident.as_ref().expect("field is named");
+        let field_name_str = field_name.to_string();
+        let index = &f.index;
+        if f.is_optional {
+            quote_spanned! { f.field.span() =>
+                #field_name_str => match Option::as_ref(&self.#field_name) {
+                    Some(value) => (#index, ssz_rs::Prove::prove_path(value, rest, branch)?),
+                    None => return Err(ssz_rs::MerkleizationError::InvalidPathElement(element.clone())),
+                },
+            }
+        } else {
+            quote_spanned! { f.field.span() =>
+                #field_name_str => {
+                    (#index, ssz_rs::Prove::prove_path(&self.#field_name, rest, branch)?)
+                }
+            }
+        }
+    });
+    let roots_impl = derive_stable_roots_impl(&stable_fields);
+    let generics = bounded_generics(generics, quote! { ssz_rs::SimpleSerialize });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics ssz_rs::Prove for #name #ty_generics #where_clause {
+            fn prove_path(
+                &self,
+                path: ssz_rs::Path,
+                branch: &mut Vec<ssz_rs::Node>,
+            ) -> Result<(ssz_rs::Node, usize), ssz_rs::MerkleizationError> {
+                let (element, rest) = match path.split_first() {
+                    Some(next) => next,
+                    None => return Ok((ssz_rs::Merkleized::hash_tree_root(self)?, 0)),
+                };
+                let start = branch.len();
+                let (field_index, (leaf, index)) = match element {
+                    ssz_rs::PathElement::Field(field) => match field.as_str() {
+                        #(#prove_by_field)*
+                        _ => return Err(ssz_rs::MerkleizationError::InvalidPathElement(element.clone())),
+                    },
+                    _ => return Err(ssz_rs::MerkleizationError::InvalidPathElement(element.clone())),
+                };
+                let inner_depth = branch.len() - start;
+                #roots_impl
+                ssz_rs::__internal::extend_stable_branch(&roots, #capacity, field_index, branch)?;
+                Ok((leaf, (field_index << inner_depth) | index))
+            }
+        }
+    }
+}
+
 fn derive_serializable_impl(
     data: &Data,
     name: &Ident,
     generics: &Generics,
+    helper_attr: Option<&HelperAttr>,
 ) -> proc_macro2::TokenStream {
-    let serialize_impl = derive_serialize_impl(data);
-    let deserialize_impl = derive_deserialize_impl(data);
-    let is_variable_size_impl = derive_variable_size_impl(data);
-    let size_hint_impl = derive_size_hint_impl(data);
+    if let Some(attr @ (HelperAttr::StableContainer(..) | HelperAttr::Profile(..))) = helper_attr {
+        return derive_stable_serializable_impl(data, name, generics, attr)
+    }
+    let serialize_impl = derive_serialize_impl(data, helper_attr);
+    let deserialize_impl = derive_deserialize_impl(data, name, helper_attr);
+    let fixed_size_impl = derive_fixed_size_impl(data, helper_attr);
+    let serialized_length_impl = derive_serialized_length_impl(data, helper_attr);
 
-    let (impl_generics, ty_generics, _) = generics.split_for_impl();
-    quote! {
-        impl #impl_generics ssz_rs::Serialize for #name #ty_generics {
+    let bounded = bounded_generics(generics, quote! { ssz_rs::Serializable });
+    let (impl_generics, ty_generics, where_clause) = bounded.split_for_impl();
+    let spec_check = match helper_attr {
+        Some(HelperAttr::SpecCheck(path)) => derive_spec_check(data, name, generics, path),
+        _ => quote! {},
+    };
+    let container_impl = match data {
+        _ if wrapped_field(data, helper_attr).is_some() => quote! {},
+        Data::Struct(DataStruct { fields: Fields::Named(fields), .. }) => {
+            let field_types = fields.named.iter().map(field_encoding_type);
+            quote! {
+                impl #impl_generics ssz_rs::Container for #name #ty_generics #where_clause {
+                    const FIELD_SIZES: &'static [Option<usize>] =
+                        &[#(<#field_types as ssz_rs::Serializable>::FIXED_SIZE),*];
+                }
+            }
+        }
+        Data::Enum(data) => derive_union_impl(data, name, generics),
+        _ => quote! {},
+    };
+    let expansion = quote! {
+        impl #impl_generics ssz_rs::Serialize for #name #ty_generics #where_clause {
             #serialize_impl
         }
 
-        impl #impl_generics ssz_rs::Deserialize for #name #ty_generics {
+        impl #impl_generics ssz_rs::Deserialize for #name #ty_generics #where_clause {
             #deserialize_impl
         }
 
-        impl #impl_generics ssz_rs::Serializable for #name #ty_generics {
-            fn is_variable_size() -> bool {
-                #is_variable_size_impl
+        impl #impl_generics ssz_rs::Serializable for #name #ty_generics #where_clause {
+            const FIXED_SIZE: Option<usize> = #fixed_size_impl;
+
+            #serialized_length_impl
+        }
+
+        #container_impl
+
+        #spec_check
+    };
+    // the markers for fields given `with` are scoped to the impls that use them
+    let with_markers = match data {
+        Data::Struct(DataStruct { fields: Fields::Named(fields), .. })
+            if wrapped_field(data, helper_attr).is_none() =>
+        {
+            fields.named.iter().filter_map(derive_with_marker).collect::<Vec<_>>()
+        }
+        _ => vec![],
+    };
+    if with_markers.is_empty() {
+        return expansion
+    }
+    quote! {
+        const _: () = {
+            #(#with_markers)*
+
+            #expansion
+        };
+    }
+}
+
+fn derive_union_impl(data: &DataEnum, name: &Ident, generics: &Generics) -> TokenStream {
+    let variant_count = data.variants.len();
+    let (selector_by_variant, deserialization_by_variant): (Vec<_>, Vec<_>) = data
+        .variants
+        .iter()
+        .enumerate()
+        .map(|(i, variant)| {
+            // NOTE: this is "safe" as the number of legal variants fits into `u8`
+            let i = i as u8;
+            let variant_name = &variant.ident;
+            match &variant.fields {
+                Fields::Unnamed(inner) => {
+                    // SAFETY: index is safe because Punctuated always has a first element; qed
+                    let variant_type = &inner.unnamed[0];
+                    let selector = quote_spanned! { variant.span() =>
+                        Self::#variant_name(..) => #i,
+                    };
+                    let deserialization = quote_spanned! { variant.span() =>
+                        #i => Ok(Self::#variant_name(<#variant_type>::deserialize(encoding)?)),
+                    };
+                    (selector, deserialization)
+                }
+                Fields::Unit => {
+                    let selector = quote_spanned! { variant.span() =>
+                        Self::None => 0,
+                    };
+                    let deserialization = quote_spanned! { variant.span() =>
+                        0 if encoding.is_empty() => Ok(Self::None),
+                        0 => Err(ssz_rs::DeserializeError::AdditionalInput {
+                            provided: encoding.len(),
+                            expected: 0,
+                        }),
+                    };
+                    (selector, deserialization)
+                }
+                _ => unreachable!(),
             }
+        })
+        .unzip();
 
-            fn size_hint() -> usize {
-                #size_hint_impl
+    let generics = bounded_generics(generics, quote! { ssz_rs::Serializable });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics ssz_rs::Union for #name #ty_generics #where_clause {
+            fn variant_count() -> usize {
+                #variant_count
+            }
+
+            fn selector(&self) -> u8 {
+                match self {
+                    #(#selector_by_variant)*
+                }
+            }
+
+            fn deserialize_variant(
+                selector: u8,
+                encoding: &[u8],
+            ) -> Result<Self, ssz_rs::DeserializeError> {
+                match selector {
+                    #(#deserialization_by_variant)*
+                    b => Err(ssz_rs::DeserializeError::InvalidByte(b)),
+                }
             }
         }
     }
 }
 
 fn derive_simple_serialize_impl(name: &Ident, generics: &Generics) -> proc_macro2::TokenStream {
-    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let generics = bounded_generics(generics, quote! { ssz_rs::SimpleSerialize });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ssz_rs::SimpleSerialize for #name #ty_generics #where_clause {}
+    }
+}
+
+// Builds a value of `name` out of an arbitrary value for each field, or for the fields of one
+// variant, e.g. so that round trips through the encoding and the root can be fuzzed.
+fn derive_arbitrary_impl(data: &Data, name: &Ident, generics: &Generics) -> TokenStream {
+    let arbitrary = quote! { ssz_rs::__internal::arbitrary };
+    let construct = |fields: &Fields, path: TokenStream| match fields {
+        Fields::Named(fields) => {
+            let field_names = fields.named.iter().map(|field| &field.ident);
+            quote! { #path { #(#field_names: #arbitrary::Arbitrary::arbitrary(u)?),* } }
+        }
+        Fields::Unnamed(fields) => {
+            let values =
+                fields.unnamed.iter().map(|_| quote! { #arbitrary::Arbitrary::arbitrary(u)? });
+            quote! { #path(#(#values),*) }
+        }
+        Fields::Unit => path,
+    };
+    let body = match data {
+        Data::Struct(data) => construct(&data.fields, quote! { Self }),
+        Data::Enum(data) => {
+            let variant_count = data.variants.len();
+            let values = data.variants.iter().enumerate().map(|(i, variant)| {
+                let variant_name = &variant.ident;
+                let value = construct(&variant.fields, quote! { Self::#variant_name });
+                quote! { #i => #value, }
+            });
+            quote! {
+                match u.choose_index(#variant_count)? {
+                    #(#values)*
+                    _ => unreachable!("index is less than the number of variants"),
+                }
+            }
+        }
+        Data::Union(..) => unreachable!("data was already validated to exclude union types"),
+    };
+
+    let mut arbitrary_generics =
+        bounded_generics(generics, quote! { #arbitrary::Arbitrary<'arbitrary> });
+    arbitrary_generics.params.insert(0, parse_quote! { 'arbitrary });
+    let (impl_generics, _, where_clause) = arbitrary_generics.split_for_impl();
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #arbitrary::Arbitrary<'arbitrary> for #name #ty_generics #where_clause {
+            fn arbitrary(
+                u: &mut #arbitrary::Unstructured<'arbitrary>,
+            ) -> #arbitrary::Result<Self> {
+                Ok(#body)
+            }
+        }
+    }
+}
+
+// Describes the layout of `name` in terms of the descriptions of the types of its fields.
+fn derive_type_info_impl(
+    data: &Data,
+    name: &Ident,
+    generics: &Generics,
+    helper_attr: Option<&HelperAttr>,
+) -> TokenStream {
+    let generics = bounded_generics(generics, quote! { ssz_rs::TypeInfo });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let type_name = name.to_string();
+    let type_info = match data {
+        _ if wrapped_field(data, helper_attr).is_some() => {
+            let (field, _) = wrapped_field(data, helper_attr).expect("field is wrapped");
+            let ty = &field.ty;
+            quote! { <#ty as ssz_rs::TypeInfo>::type_info() }
+        }
+        Data::Struct(DataStruct { fields: Fields::Named(fields), .. }) => {
+            let fields = fields.named.iter().enumerate().map(|(i, field)| {
+                if with_module(field).is_some() {
+                    panic!("type info cannot be derived for fields given `with`")
+                }
+                let field_name = field.ident.as_ref().expect("field is named").to_string();
+                let ty = &field.ty;
+                let index = match helper_attr {
+                    Some(HelperAttr::Profile(..)) => {
+                        quote! { <Self as ssz_rs::Profile>::FIELD_INDICES[#i] }
+                    }
+                    _ => quote! { #i },
+                };
+                quote! {
+                    ssz_rs::FieldDescriptor {
+                        name: #field_name,
+                        index: #index,
+                        ty: <#ty as ssz_rs::TypeInfo>::type_info(),
+                    }
+                }
+            });
+            let kind = match helper_attr {
+                Some(HelperAttr::StableContainer(capacity)) => quote! {
+                    ssz_rs::TypeKind::StableContainer {
+                        name: #type_name,
+                        capacity: #capacity,
+                        fields: [#(#fields),*].into(),
+                    }
+                },
+                Some(HelperAttr::Profile(base)) => quote! {
+                    ssz_rs::TypeKind::Profile {
+                        name: #type_name,
+                        capacity: <#base as ssz_rs::StableContainer>::CAPACITY,
+                        fields: [#(#fields),*].into(),
+                    }
+                },
+                _ => quote! {
+                    ssz_rs::TypeKind::Container { name: #type_name, fields: [#(#fields),*].into() }
+                },
+            };
+            quote! {
+                ssz_rs::TypeDescriptor {
+                    fixed_size: <Self as ssz_rs::Serializable>::FIXED_SIZE,
+                    kind: #kind,
+                }
+            }
+        }
+        Data::Enum(..) if matches!(helper_attr, Some(HelperAttr::Transparent)) => {
+            panic!("type info cannot be derived for transparent enums")
+        }
+        Data::Enum(data) => {
+            let variants = data.variants.iter().map(|variant| match &variant.fields {
+                Fields::Unnamed(inner) => {
+                    let ty = &inner.unnamed[0].ty;
+                    quote! { Some(<#ty as ssz_rs::TypeInfo>::type_info()) }
+                }
+                _ => quote! { None },
+            });
+            quote! {
+                ssz_rs::TypeDescriptor {
+                    fixed_size: <Self as ssz_rs::Serializable>::FIXED_SIZE,
+                    kind: ssz_rs::TypeKind::Union {
+                        name: #type_name,
+                        variants: [#(#variants),*].into(),
+                    },
+                }
+            }
+        }
+        _ => unreachable!("data was already validated"),
+    };
+
+    quote! {
+        impl #impl_generics ssz_rs::TypeInfo for #name #ty_generics #where_clause {
+            fn type_info() -> ssz_rs::TypeDescriptor {
+                #type_info
+            }
+        }
+    }
+}
+
+// Defines a view of the container `name`, along with an implementation of `Viewable` for `name`.
+// The view locates the encoding of each field up front and reads a field only when accessed.
+fn derive_viewable_impl(
+    data: &Data,
+    name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    helper_attr: Option<&HelperAttr>,
+) -> TokenStream {
+    if generics.lifetimes().next().is_some() {
+        panic!("views cannot be derived for types with lifetime parameters")
+    }
+    let mut view_generics = generics.clone();
+    view_generics.params.insert(0, parse_quote!('a));
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+    let (view_impl_generics, view_ty_generics, _) = view_generics.split_for_impl();
+    let predicates =
+        where_clause.map(|clause| &clause.predicates).into_iter().flatten().collect::<Vec<_>>();
+
+    // the encoding of a "newtype" is that of the wrapped value
+    if let Some((f, _)) = wrapped_field(data, helper_attr) {
+        let field_type = &f.ty;
+        return quote! {
+            impl #view_impl_generics ssz_rs::Viewable<'a> for #name #ty_generics
+            where
+                #field_type: ssz_rs::Viewable<'a>,
+                #(#predicates,)*
+            {
+                type View = <#field_type as ssz_rs::Viewable<'a>>::View;
+            }
+        }
+    }
+    let fields = match data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) if fields.named.iter().any(|f| with_module(f).is_some()) => {
+                panic!("views cannot be derived for containers with fields given `with`")
+            }
+            Fields::Named(ref fields) => &fields.named,
+            _ => unimplemented!(
+                "this type of struct is currently not supported by this derive macro"
+            ),
+        },
+        _ => panic!("views can only be derived for structs"),
+    };
+
+    let view_name = format_ident!("{name}View");
+    let field_types = fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+    let accessor_by_field = fields.iter().enumerate().map(|(i, f)| {
+        let field_name = f.ident.as_ref().expect("field is named");
+        let field_type = &f.ty;
+        let field_vis = &f.vis;
+        quote_spanned! { f.span() =>
+            #field_vis fn #field_name(
+                &self,
+            ) -> Result<<#field_type as ssz_rs::Viewable<'a>>::View, ssz_rs::DeserializeError> {
+                let encoding = &self.encoding[self.spans[2*#i]..self.spans[2*#i+1]];
+                ssz_rs::DeserializeView::deserialize_view(encoding)
+            }
+        }
+    });
+    let doc = format!("A view of an encoded [`{name}`], reading each field as it is accessed.");
+
+    quote! {
+        #[doc = #doc]
+        #vis struct #view_name #view_impl_generics #where_clause {
+            encoding: &'a [u8],
+            spans: Vec<usize>,
+            _type: ::core::marker::PhantomData<fn() -> #name #ty_generics>,
+        }
+
+        impl #view_impl_generics #view_name #view_ty_generics
+        where
+            #(#field_types: ssz_rs::Viewable<'a>,)*
+            #(#predicates,)*
+        {
+            /// The encoding this view reads from.
+            pub fn as_bytes(&self) -> &'a [u8] {
+                self.encoding
+            }
+
+            #(#accessor_by_field)*
+        }
+
+        impl #view_impl_generics ssz_rs::DeserializeView<'a> for #view_name #view_ty_generics
+        where
+            #(#field_types: ssz_rs::Viewable<'a>,)*
+            #(#predicates,)*
+        {
+            fn deserialize_view(encoding: &'a [u8]) -> Result<Self, ssz_rs::DeserializeError> {
+                let mut deserializer = ssz_rs::__internal::ContainerDeserializer::default();
+
+                #(deserializer.parse::<#field_types>(encoding)?;)*
+
+                let spans = deserializer.finalize(encoding)?;
+                Ok(Self { encoding, spans, _type: ::core::marker::PhantomData })
+            }
+        }
+
+        impl #view_impl_generics ssz_rs::Viewable<'a> for #name #ty_generics
+        where
+            #(#field_types: ssz_rs::Viewable<'a>,)*
+            #(#predicates,)*
+        {
+            type View = #view_name #view_ty_generics;
+        }
+    }
+}
+
+// An SSZ type in the notation of the spec, e.g. `List[uint64, 16]` for a `List<u64, 16>`, where
+// lengths are "types" without arguments.
+#[derive(PartialEq)]
+struct SpecType {
+    name: String,
+    args: Vec<SpecType>,
+}
+
+impl std::fmt::Display for SpecType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some((first, rest)) = self.args.split_first() {
+            write!(f, "[{first}")?;
+            for arg in rest {
+                write!(f, ", {arg}")?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+// Types of the spec taking type or length arguments; the arguments of any other type, e.g. the
+// const generics of a container, are not part of its name in the spec.
+const PARAMETERIZED_SPEC_TYPES: &[&str] =
+    &["List", "Vector", "Bitlist", "Bitvector", "ByteList", "ByteVector", "Optional"];
 
+impl SpecType {
+    fn named(name: String) -> Self {
+        Self { name, args: vec![] }
+    }
+
+    fn parse(source: &str) -> Self {
+        let source = source.trim();
+        let Some(start) = source.find('[') else { return Self::named(source.to_string()) };
+        let inner = source[start + 1..].strip_suffix(']').unwrap_or_else(|| {
+            panic!("unbalanced brackets in the spec type `{source}`");
+        });
+        let mut args = vec![];
+        let mut depth = 0;
+        let mut begin = 0;
+        for (i, c) in inner.char_indices() {
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                ',' if depth == 0 => {
+                    args.push(Self::parse(&inner[begin..i]));
+                    begin = i + 1;
+                }
+                _ => {}
+            }
+        }
+        args.push(Self::parse(&inner[begin..]));
+        Self { name: source[..start].trim().to_string(), args }
+    }
+
+    fn from_type(ty: &Type) -> Self {
+        match ty {
+            Type::Array(array) => {
+                let length = Self::named(array.len.to_token_stream().to_string());
+                Self {
+                    name: "Vector".to_string(),
+                    args: vec![Self::from_type(&array.elem), length],
+                }
+            }
+            Type::Group(group) => Self::from_type(&group.elem),
+            Type::Paren(paren) => Self::from_type(&paren.elem),
+            Type::Path(path) if path.qself.is_none() => {
+                let segment = path.path.segments.last().expect("path is not empty");
+                let name = match segment.ident.to_string().as_str() {
+                    uint @ ("u8" | "u16" | "u32" | "u64" | "u128") => format!("uint{}", &uint[1..]),
+                    "U256" => "uint256".to_string(),
+                    "bool" => "boolean".to_string(),
+                    name => name.to_string(),
+                };
+                let args = match &segment.arguments {
+                    PathArguments::AngleBracketed(args)
+                        if PARAMETERIZED_SPEC_TYPES.contains(&name.as_str()) =>
+                    {
+                        args.args
+                            .iter()
+                            .map(|arg| match arg {
+                                GenericArgument::Type(ty) => Self::from_type(ty),
+                                arg => Self::named(arg.to_token_stream().to_string()),
+                            })
+                            .collect()
+                    }
+                    _ => vec![],
+                };
+                Self { name, args }
+            }
+            ty => Self::named(ty.to_token_stream().to_string()),
+        }
+    }
+
+    // Whether `self` (from Rust) matches `spec`, deferring any length given by a constant in Rust
+    // and by a number in the spec to an assertion added to `assertions`.
+    fn matches(
+        &self,
+        spec: &SpecType,
+        const_params: &[String],
+        assertions: &mut Vec<(String, String)>,
+    ) -> bool {
+        if self.args.len() != spec.args.len() {
+            return false
+        }
+        let is_number = |name: &str| name.chars().all(|c| c.is_ascii_digit());
+        if self.name != spec.name {
+            let is_deferred =
+                self.args.is_empty() && is_number(&spec.name) && !const_params.contains(&self.name);
+            if !is_deferred {
+                return false
+            }
+            assertions.push((self.name.clone(), spec.name.clone()));
+        }
+        self.args
+            .iter()
+            .zip(&spec.args)
+            .all(|(arg, spec)| arg.matches(spec, const_params, assertions))
+    }
+}
+
+// Checks the fields of the container `name` against their description under the same name in
+// the JSON file at `path`, relative to the manifest of the crate being compiled, e.g.
+// `{"Checkpoint": [{"name": "epoch", "type": "Epoch"}, {"name": "root", "type": "Root"}]}`.
+//
+// Panics if the names, order or types of the fields differ. A length given by a constant in
+// Rust but by a number in the spec is checked by a `const` assertion, so that the build fails
+// if the value of the constant differs.
+fn derive_spec_check(data: &Data, name: &Ident, generics: &Generics, path: &str) -> TokenStream {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let full_path = std::path::Path::new(&manifest_dir).join(path);
+    let full_path = full_path.to_str().expect("path is valid UTF-8");
+    let source = std::fs::read_to_string(full_path)
+        .unwrap_or_else(|err| panic!("could not read the spec at `{full_path}`: {err}"));
+    let spec: serde_json::Value = serde_json::from_str(&source)
+        .unwrap_or_else(|err| panic!("could not parse the spec at `{full_path}`: {err}"));
+    let spec_fields = spec
+        .get(name.to_string())
+        .and_then(|fields| fields.as_array())
+        .unwrap_or_else(|| panic!("the spec at `{path}` has no list of fields for `{name}`"));
+
+    let fields = &named_fields(data).named;
+    if fields.len() != spec_fields.len() {
+        panic!(
+            "`{name}` has {} fields but the spec at `{path}` has {}",
+            fields.len(),
+            spec_fields.len()
+        )
+    }
+    let const_params =
+        generics.const_params().map(|param| param.ident.to_string()).collect::<Vec<_>>();
+    let mut assertions = vec![];
+    for (i, (field, spec_field)) in fields.iter().zip(spec_fields).enumerate() {
+        let field_name = field.ident.as_ref().expect("field is named").to_string();
+        let spec_name = spec_field["name"].as_str().unwrap_or_else(|| {
+            panic!("field {i} of `{name}` in the spec at `{path}` has no `name`");
+        });
+        if field_name != spec_name {
+            panic!("field {i} of `{name}` is `{field_name}` but the spec at `{path}` has `{spec_name}`")
+        }
+        // the type of a field given `with` a module is not its SSZ type
+        if with_module(field).is_some() {
+            continue
+        }
+        let spec_type = spec_field["type"].as_str().unwrap_or_else(|| {
+            panic!("field `{spec_name}` of `{name}` in the spec at `{path}` has no `type`");
+        });
+        let spec_type = SpecType::parse(spec_type);
+        let field_type = SpecType::from_type(&field.ty);
+        if !field_type.matches(&spec_type, &const_params, &mut assertions) {
+            panic!(
+                "field `{field_name}` of `{name}` is `{field_type}` but the spec at `{path}` has `{spec_type}`"
+            )
+        }
+    }
+
+    let assertions = assertions.into_iter().map(|(constant, value)| {
+        let message =
+            format!("`{constant}` of `{name}` differs from `{value}` in the spec at `{path}`");
+        let constant = syn::parse_str::<syn::Expr>(&constant).expect("length is an expression");
+        let value = syn::parse_str::<syn::Expr>(&value).expect("length is a number");
+        quote! {
+            const _: () = assert!((#constant) as usize == #value, #message);
+        }
+    });
     quote! {
-        impl #impl_generics ssz_rs::SimpleSerialize for #name #ty_generics {}
+        // rebuild whenever the spec changes
+        const _: &[u8] = include_bytes!(#full_path);
+
+        #(#assertions)*
     }
 }
 
-#[derive(Debug)]
 enum HelperAttr {
     Transparent,
+    // the EIP-7495 `StableContainer[N]` with room for this many fields
+    StableContainer(usize),
+    // the EIP-7495 `Profile[B]` of this base
+    Profile(Path),
+    // the path of a JSON description of the fields to check against
+    SpecCheck(String),
 }
 
 fn parse_helper_attr(ident: &Ident) -> HelperAttr {
@@ -514,6 +1993,38 @@ fn parse_helper_attr(ident: &Ident) -> HelperAttr {
     }
 }
 
+fn parse_helper_attr_with_value(ident: &Ident, value: &Lit) -> HelperAttr {
+    match (ident.to_string().as_str(), value) {
+        ("stable_container", Lit::Int(capacity)) => {
+            let capacity = capacity.base10_parse().expect("capacity is a `usize`");
+            HelperAttr::StableContainer(capacity)
+        }
+        ("profile", Lit::Str(base)) => {
+            HelperAttr::Profile(base.parse().expect("base is the path of a stable container"))
+        }
+        ("spec_check", Lit::Str(path)) => HelperAttr::SpecCheck(path.value()),
+        (ident, _) => panic!("unsupported helper attribute:{ident}"),
+    }
+}
+
+// The `arbitrary` argument, asking for an implementation of `arbitrary::Arbitrary`.
+const ARBITRARY_HELPER_ARGUMENT: &str = "arbitrary";
+
+fn is_arbitrary_arg(arg: &NestedMeta) -> bool {
+    matches!(arg, NestedMeta::Meta(Meta::Path(path)) if path.is_ident(ARBITRARY_HELPER_ARGUMENT))
+}
+
+// Whether the type opts into an implementation of `arbitrary::Arbitrary` with
+// `#[ssz(arbitrary)]`, which combines with any other helper attribute. The consumer gates it,
+// e.g. with `#[cfg_attr(feature = "arbitrary", ssz(arbitrary))]`, as the implementation
+// requires the `arbitrary` feature of `ssz_rs`.
+fn wants_arbitrary(input: &DeriveInput) -> bool {
+    filter_ssz_attrs(input.attrs.iter()).any(|attr| match attr.parse_meta().unwrap() {
+        Meta::List(args) => args.nested.iter().any(is_arbitrary_arg),
+        _ => false,
+    })
+}
+
 fn extract_helper_attrs(input: &DeriveInput) -> Vec<HelperAttr> {
     filter_ssz_attrs(input.attrs.iter())
         .flat_map(|attr| {
@@ -522,6 +2033,7 @@ fn extract_helper_attrs(input: &DeriveInput) -> Vec<HelperAttr> {
                 Meta::List(args) => args
                     .nested
                     .iter()
+                    .filter(|arg| !is_arbitrary_arg(arg))
                     .map(|arg| match arg {
                         NestedMeta::Meta(meta) => {
                             if let Meta::Path(path) = meta {
@@ -532,6 +2044,10 @@ fn extract_helper_attrs(input: &DeriveInput) -> Vec<HelperAttr> {
                                     PathArguments::None => parse_helper_attr(&path.ident),
                                     _ => panic!("no arguments are supported to attribute symbols"),
                                 }
+                            } else if let Meta::NameValue(arg) = meta {
+                                let ident =
+                                    arg.path.get_ident().expect("argument is an identifier");
+                                parse_helper_attr_with_value(ident, &arg.lit)
                             } else {
                                 panic!("unsupported argument to helper attribute");
                             }
@@ -552,11 +2068,12 @@ pub fn derive_serializable(input: proc_macro::TokenStream) -> proc_macro::TokenS
     let data = &input.data;
     let helper_attrs = extract_helper_attrs(&input);
     validate_derive_input(data, &helper_attrs);
+    let helper_attr = helper_attrs.first();
 
     let name = &input.ident;
     let generics = &input.generics;
 
-    let expansion = derive_serializable_impl(data, name, generics);
+    let expansion = derive_serializable_impl(data, name, generics, helper_attr);
     proc_macro::TokenStream::from(expansion)
 }
 
@@ -589,17 +2106,65 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let generics = &input.generics;
     let merkleization_impl = derive_merkleization_impl(data, name, generics, helper_attr);
 
-    let serializable_impl = derive_serializable_impl(data, name, generics);
+    let prove_impl = derive_prove_impl(data, name, generics, helper_attr);
+
+    let serializable_impl = derive_serializable_impl(data, name, generics, helper_attr);
 
     let simple_serialize_impl = derive_simple_serialize_impl(name, generics);
 
+    let arbitrary_impl = if wants_arbitrary(&input) {
+        derive_arbitrary_impl(data, name, generics)
+    } else {
+        quote! {}
+    };
+
     let expansion = quote! {
         #serializable_impl
 
         #merkleization_impl
 
+        #prove_impl
+
         #simple_serialize_impl
+
+        #arbitrary_impl
     };
 
     proc_macro::TokenStream::from(expansion)
 }
+
+#[proc_macro_derive(Viewable)]
+pub fn derive_viewable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let data = &input.data;
+    let helper_attrs = extract_helper_attrs(&input);
+    validate_derive_input(data, &helper_attrs);
+    if matches!(
+        helper_attrs.first(),
+        Some(HelperAttr::StableContainer(..) | HelperAttr::Profile(..))
+    ) {
+        panic!("views cannot be derived for stable containers or profiles")
+    }
+
+    let name = &input.ident;
+    let generics = &input.generics;
+
+    let expansion = derive_viewable_impl(data, name, &input.vis, generics, helper_attrs.first());
+    proc_macro::TokenStream::from(expansion)
+}
+
+#[proc_macro_derive(TypeInfo, attributes(ssz))]
+pub fn derive_type_info(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let data = &input.data;
+    let helper_attrs = extract_helper_attrs(&input);
+    validate_derive_input(data, &helper_attrs);
+
+    let name = &input.ident;
+    let generics = &input.generics;
+
+    let expansion = derive_type_info_impl(data, name, generics, helper_attrs.first());
+    proc_macro::TokenStream::from(expansion)
+}
@@ -19,9 +19,232 @@ struct Wrapper(Foo);
 
 #[test]
 fn test_transparent_helper() {
-    let mut f = Foo { a: 23, b: 445 };
+    let f = Foo { a: 23, b: 445 };
     let f_root = f.hash_tree_root().unwrap();
-    let mut bar = Bar::B(f);
+    let bar = Bar::B(f);
     let bar_root = bar.hash_tree_root().unwrap();
     assert_eq!(f_root, bar_root);
 }
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, SimpleSerialize)]
+#[ssz(transparent)]
+struct BlockRoot(Node);
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, SimpleSerialize)]
+struct StateRoot(Node);
+
+#[derive(Debug, Default, SimpleSerialize)]
+struct Header {
+    block_root: BlockRoot,
+    state_root: StateRoot,
+}
+
+#[test]
+fn test_newtype_roots() {
+    let node = Node::try_from([7u8; 32].as_ref()).unwrap();
+    let block_root = BlockRoot(node);
+    assert_eq!(block_root.hash_tree_root().unwrap(), node);
+    let encoding = serialize(&block_root).unwrap();
+    assert_eq!(encoding, serialize(&node).unwrap());
+    assert_eq!(BlockRoot::deserialize(&encoding).unwrap(), block_root);
+
+    let header = Header { block_root, state_root: StateRoot(node) };
+    let root = header.hash_tree_root().unwrap();
+    let proof = header.prove(&["block_root".into()]).unwrap();
+    assert_eq!(proof.leaf, node);
+    assert!(proof.verify(root).is_ok());
+
+    #[derive(Debug, Default, SimpleSerialize)]
+    struct RawHeader {
+        block_root: Node,
+        state_root: Node,
+    }
+    let raw = RawHeader { block_root: node, state_root: node };
+    assert_eq!(root, raw.hash_tree_root().unwrap());
+    assert_eq!(serialize(&header).unwrap(), serialize(&raw).unwrap());
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, SimpleSerialize)]
+#[ssz(transparent)]
+struct Slot {
+    value: u64,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, SimpleSerialize)]
+#[ssz(transparent)]
+struct Graffiti {
+    bytes: List<u8, 32>,
+}
+
+#[derive(Debug, Default, SimpleSerialize)]
+struct Block {
+    slot: Slot,
+    graffiti: Graffiti,
+}
+
+#[test]
+fn test_transparent_named_field() {
+    let slot = Slot { value: 12 };
+    assert_eq!(slot.hash_tree_root().unwrap(), 12u64.hash_tree_root().unwrap());
+    let encoding = serialize(&slot).unwrap();
+    assert_eq!(encoding, serialize(&12u64).unwrap());
+    assert_eq!(Slot::deserialize(&encoding).unwrap(), slot);
+    assert_eq!(Slot::FIXED_SIZE, Some(8));
+
+    let bytes = List::<u8, 32>::try_from(vec![1, 2, 3]).unwrap();
+    let graffiti = Graffiti { bytes: bytes.clone() };
+    assert_eq!(graffiti.hash_tree_root().unwrap(), bytes.hash_tree_root().unwrap());
+    assert_eq!(serialize(&graffiti).unwrap(), serialize(&bytes).unwrap());
+    assert_eq!(graffiti.serialized_length().unwrap(), 3);
+
+    let block = Block { slot, graffiti };
+    let root = block.hash_tree_root().unwrap();
+    let proof = block.prove(&["graffiti".into(), 1.into()]).unwrap();
+    assert!(proof.verify(root).is_ok());
+    let encoding = serialize(&block).unwrap();
+    let recovered = Block::deserialize(&encoding).unwrap();
+    assert_eq!((recovered.slot, recovered.graffiti), (block.slot, block.graffiti));
+}
+
+mod ipv4 {
+    use ssz_rs::prelude::*;
+    use std::net::Ipv4Addr;
+
+    pub const FIXED_SIZE: Option<usize> = Some(4);
+
+    pub fn serialize(value: &Ipv4Addr, buffer: &mut Vec<u8>) -> Result<usize, SerializeError> {
+        buffer.extend_from_slice(&value.octets());
+        Ok(4)
+    }
+
+    pub fn deserialize(encoding: &[u8]) -> Result<Ipv4Addr, DeserializeError> {
+        <[u8; 4]>::deserialize(encoding).map(Ipv4Addr::from)
+    }
+
+    pub fn hash_tree_root(value: &Ipv4Addr) -> Result<Node, MerkleizationError> {
+        u32::from_le_bytes(value.octets()).hash_tree_root()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, SimpleSerialize)]
+struct Peer {
+    port: u16,
+    #[ssz(with = "ipv4")]
+    address: std::net::Ipv4Addr,
+    name: List<u8, 32>,
+}
+
+#[test]
+fn test_with_module() {
+    let peer = Peer {
+        port: 9000,
+        address: std::net::Ipv4Addr::new(10, 0, 0, 1),
+        name: List::try_from(b"lighthouse".to_vec()).unwrap(),
+    };
+    let encoding = serialize(&peer).unwrap();
+    assert_eq!(encoding[..10], [0x28, 0x23, 10, 0, 0, 1, 10, 0, 0, 0]);
+    assert_eq!(Peer::deserialize(&encoding).unwrap(), peer);
+    assert_eq!(peer.serialized_length().unwrap(), encoding.len());
+    assert_eq!(Peer::FIELD_SIZES, [Some(2), Some(4), None]);
+    assert!(Peer::deserialize(&encoding[..5]).is_err());
+
+    let address = u32::from_le_bytes([10, 0, 0, 1]);
+    let root = Vector::<Node, 3>::try_from(vec![
+        9000u16.hash_tree_root().unwrap(),
+        address.hash_tree_root().unwrap(),
+        peer.name.hash_tree_root().unwrap(),
+    ])
+    .unwrap()
+    .hash_tree_root()
+    .unwrap();
+    assert_eq!(peer.hash_tree_root().unwrap(), root);
+    let proof = peer.prove(&["address".into()]).unwrap();
+    assert!(proof.verify(root).is_ok());
+    assert!(peer.prove(&["address".into(), 0.into()]).is_err());
+}
+
+#[derive(Debug, Default, PartialEq, SimpleSerialize, TypeInfo, Viewable)]
+struct Signed<T: SimpleSerialize> {
+    message: T,
+    signature: Vector<u8, 4>,
+}
+
+#[derive(Debug, Default, PartialEq, SimpleSerialize)]
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+#[derive(Debug, Default, PartialEq, SimpleSerialize)]
+struct Batch<T>
+where
+    T: SimpleSerialize,
+{
+    items: List<T, 4>,
+}
+
+#[derive(Debug, PartialEq, SimpleSerialize)]
+enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+#[test]
+fn test_generic_containers() {
+    #[derive(Debug, SimpleSerialize)]
+    struct SignedFoo {
+        message: Foo,
+        signature: Vector<u8, 4>,
+    }
+
+    let signature = Vector::try_from(vec![1, 2, 3, 4]).unwrap();
+    let signed = Signed { message: Foo { a: 1, b: 2 }, signature: signature.clone() };
+    let concrete = SignedFoo { message: Foo { a: 1, b: 2 }, signature };
+    assert_eq!(serialize(&signed).unwrap(), serialize(&concrete).unwrap());
+    let root = signed.hash_tree_root().unwrap();
+    assert_eq!(root, concrete.hash_tree_root().unwrap());
+    let proof = signed.prove(&["message".into(), "b".into()]).unwrap();
+    assert!(proof.verify(root).is_ok());
+    let encoding = serialize(&Signed { message: 9u64, signature: Vector::default() }).unwrap();
+    let view = view::<Signed<u64>>(&encoding).unwrap();
+    assert_eq!(view.message().unwrap(), 9);
+    assert_eq!(Signed::<u64>::type_info().to_string(), "Signed");
+
+    let pair = Pair { first: 7u16, second: Batch { items: List::try_from(vec![true]).unwrap() } };
+    let encoding = serialize(&pair).unwrap();
+    assert_eq!(Pair::deserialize(&encoding).unwrap(), pair);
+
+    let value: Either<u8, Pair<u8, u8>> = Either::Right(Pair { first: 1, second: 2 });
+    let encoding = serialize(&value).unwrap();
+    assert_eq!(encoding, [1, 1, 2]);
+    assert_eq!(Either::deserialize(&encoding).unwrap(), value);
+}
+
+type Epoch = u64;
+type Root = Node;
+type ValidatorIndex = u64;
+
+const MAX_VALIDATORS: usize = 2048;
+
+#[derive(Debug, Default, SimpleSerialize)]
+#[ssz(spec_check = "tests/spec.json")]
+struct Checkpoint {
+    epoch: Epoch,
+    root: Root,
+}
+
+#[derive(Debug, Default, SimpleSerialize)]
+#[ssz(spec_check = "tests/spec.json")]
+struct PendingAttestation<const MAX_VALIDATORS_PER_COMMITTEE: usize> {
+    aggregation_bits: Bitlist<MAX_VALIDATORS>,
+    data: Checkpoint,
+    inclusion_delay: u64,
+    proposer_indices: List<ValidatorIndex, MAX_VALIDATORS_PER_COMMITTEE>,
+    roots: [Root; 4],
+}
+
+#[test]
+fn test_spec_check() {
+    let attestation = PendingAttestation::<4>::default();
+    assert_eq!(serialize(&attestation).unwrap().len(), 4 + 40 + 8 + 4 + 128 + 1);
+}
@@ -1,22 +1,36 @@
 use crate::{
-    de::{deserialize_homogeneous_composite, Deserialize, DeserializeError},
+    de::{
+        deserialize_homogeneous_composite, deserialize_homogeneous_composite_from_source,
+        DecodingSource, Deserialize, DeserializeError,
+    },
     error::{InstanceError, TypeError},
     lib::*,
-    merkleization::{elements_to_chunks, merkleize, pack, MerkleizationError, Merkleized, Node},
-    ser::{Serialize, SerializeError, Serializer},
-    Serializable, SimpleSerialize,
+    merkleization::{
+        elements_to_chunks, merkleize, pack, prove_element, MerkleizationError, Merkleized, Node,
+        Path, Prove,
+    },
+    ser::{
+        serialize_elements, serialize_elements_to_sink, serialized_length_of_elements,
+        EncodingSink, Serialize, SerializeError,
+    },
+    vector::check_length,
+    Serializable, SimpleSerialize, Vector,
 };
 
 impl<T, const N: usize> Serializable for [T; N]
 where
     T: Serializable,
 {
-    fn is_variable_size() -> bool {
-        T::is_variable_size()
-    }
+    const FIXED_SIZE: Option<usize> = match T::FIXED_SIZE {
+        Some(size) => Some(size * N),
+        None => None,
+    };
 
-    fn size_hint() -> usize {
-        T::size_hint() * N
+    fn serialized_length(&self) -> Result<usize, SerializeError> {
+        if N == 0 {
+            return Err(TypeError::InvalidBound(N).into())
+        }
+        serialized_length_of_elements(self)
     }
 }
 
@@ -28,11 +42,14 @@ where
         if N == 0 {
             return Err(TypeError::InvalidBound(N).into())
         }
-        let mut serializer = Serializer::default();
-        for element in self {
-            serializer.with_element(element)?;
+        serialize_elements(self.as_slice(), buffer)
+    }
+
+    fn serialize_to_sink(&self, sink: &mut dyn EncodingSink) -> Result<usize, SerializeError> {
+        if N == 0 {
+            return Err(TypeError::InvalidBound(N).into())
         }
-        serializer.serialize(buffer)
+        serialize_elements_to_sink(self, sink)
     }
 }
 
@@ -41,40 +58,38 @@ where
     T: Serializable,
 {
     fn deserialize(encoding: &[u8]) -> Result<Self, DeserializeError> {
+        check_length::<T, N>(encoding)?;
+        let elements = deserialize_homogeneous_composite(encoding)?;
+        elements.try_into().map_err(|elements: Vec<T>| {
+            InstanceError::Exact { required: N, provided: elements.len() }.into()
+        })
+    }
+
+    fn deserialize_from_source(
+        source: &mut dyn DecodingSource,
+        len: Option<usize>,
+    ) -> Result<Self, DeserializeError> {
         if N == 0 {
             return Err(TypeError::InvalidBound(N).into())
         }
-
-        if !T::is_variable_size() {
-            let expected_length = N * T::size_hint();
-            if encoding.len() < expected_length {
-                return Err(DeserializeError::ExpectedFurtherInput {
-                    provided: encoding.len(),
-                    expected: expected_length,
-                })
-            }
-            if encoding.len() > expected_length {
-                return Err(DeserializeError::AdditionalInput {
-                    provided: encoding.len(),
-                    expected: expected_length,
-                })
-            }
-        }
-        let elements = deserialize_homogeneous_composite(encoding)?;
+        let elements = deserialize_homogeneous_composite_from_source(source, len, N)?;
         elements.try_into().map_err(|elements: Vec<T>| {
             InstanceError::Exact { required: N, provided: elements.len() }.into()
         })
     }
+
+    fn validate(encoding: &[u8]) -> Result<(), DeserializeError> {
+        Vector::<T, N>::validate(encoding)
+    }
 }
 
 impl<T, const N: usize> Merkleized for [T; N]
 where
     T: SimpleSerialize,
 {
-    fn hash_tree_root(&mut self) -> Result<Node, MerkleizationError> {
+    fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
         if T::is_composite_type() {
-            let count = self.len();
-            let chunks = elements_to_chunks(self.iter_mut().enumerate(), count)?;
+            let chunks = elements_to_chunks(self.as_slice())?;
             merkleize(&chunks, None)
         } else {
             let chunks = pack(self)?;
@@ -87,6 +102,22 @@ where
     }
 }
 
+impl<T, const N: usize> Prove for [T; N]
+where
+    T: SimpleSerialize,
+{
+    fn prove_path(
+        &self,
+        path: Path,
+        branch: &mut Vec<Node>,
+    ) -> Result<(Node, usize), MerkleizationError> {
+        if path.is_empty() {
+            return Ok((self.hash_tree_root()?, 0))
+        }
+        prove_element(self, None, path, branch)
+    }
+}
+
 impl<T, const N: usize> SimpleSerialize for [T; N] where T: SimpleSerialize {}
 
 #[cfg(test)]
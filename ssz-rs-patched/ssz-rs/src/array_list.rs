@@ -0,0 +1,363 @@
+use crate::{
+    de::{
+        deserialize_homogeneous_composite, deserialize_homogeneous_composite_from_source,
+        validate_homogeneous_composite, DecodingSource, Deserialize, DeserializeError,
+    },
+    error::{Error, InstanceError},
+    lib::*,
+    list::{check_count, check_remainder},
+    merkleization::{
+        elements_to_chunks, merkleize, mix_in_length, pack, prove_element, MerkleizationError,
+        Merkleized, Node, Path, PathElement, Prove, BYTES_PER_CHUNK,
+    },
+    ser::{
+        serialize_elements, serialize_elements_to_sink, serialized_length_of_elements,
+        EncodingSink, Serialize, SerializeError,
+    },
+    Serializable, SimpleSerialize,
+};
+
+/// A `List<T, N>` whose elements are held inline, in an array of `N` of them, rather than on the
+/// heap.
+///
+/// It has the same encoding and root as the `List` with the same elements, so either can stand
+/// in for the other. Slots past the length of the list hold `T::default()`. This suits small
+/// bounds on targets that should not allocate for each list; encoding, decoding and
+/// merkleization still use the same scratch buffers as they do for a `List`.
+#[derive(Clone)]
+pub struct ArrayList<T: Serializable + Default, const N: usize> {
+    data: [T; N],
+    len: usize,
+}
+
+impl<T: Serializable + Default, const N: usize> ArrayList<T, N> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data[..self.len]
+    }
+
+    /// Collect the elements yielded by `iter`, failing if there are more than `N` of them.
+    ///
+    /// As with `List::try_from_iter`, no more than `N + 1` elements are taken from `iter`.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Error> {
+        let mut list = Self::default();
+        let mut iter = iter.into_iter();
+        for element in iter.by_ref().take(N) {
+            list.data[list.len] = element;
+            list.len += 1;
+        }
+        if iter.next().is_some() {
+            let provided = (N + 1).saturating_add(iter.size_hint().0);
+            return Err(Error::Instance(InstanceError::Bounded { bound: N, provided }))
+        }
+        Ok(list)
+    }
+
+    /// Append `element`, failing if the list already holds `N` elements.
+    pub fn push(&mut self, element: T) -> Result<(), Error> {
+        if self.len == N {
+            return Err(Error::Instance(InstanceError::Bounded { bound: N, provided: N + 1 }))
+        }
+        self.data[self.len] = element;
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None
+        }
+        self.len -= 1;
+        Some(core::mem::take(&mut self.data[self.len]))
+    }
+
+    /// Keep the first `len` elements, dropping the rest, if there are more than `len`.
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.pop();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+}
+
+impl<T: Serializable + Default, const N: usize> Default for ArrayList<T, N> {
+    fn default() -> Self {
+        Self { data: core::array::from_fn(|_| T::default()), len: 0 }
+    }
+}
+
+impl<T: Serializable + Default + fmt::Debug, const N: usize> fmt::Debug for ArrayList<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let data = self.as_slice();
+        if f.alternate() {
+            write!(f, "ArrayList<{}, {}>(len={}){:#?}", any::type_name::<T>(), N, self.len, data)
+        } else {
+            write!(f, "ArrayList<{}, {}>(len={}){:?}", any::type_name::<T>(), N, self.len, data)
+        }
+    }
+}
+
+impl<T: Serializable + Default + PartialEq, const N: usize> PartialEq for ArrayList<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Serializable + Default + Eq, const N: usize> Eq for ArrayList<T, N> {}
+
+impl<T: Serializable + Default + hash::Hash, const N: usize> hash::Hash for ArrayList<T, N> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T: Serializable + Default + PartialOrd, const N: usize> PartialOrd for ArrayList<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Serializable + Default + Ord, const N: usize> Ord for ArrayList<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Serializable + Default, const N: usize> AsRef<[T]> for ArrayList<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Serializable + Default, const N: usize> Deref for ArrayList<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T: Serializable + Default, Idx: SliceIndex<[T]>, const N: usize> Index<Idx>
+    for ArrayList<T, N>
+{
+    type Output = <Idx as SliceIndex<[T]>>::Output;
+
+    fn index(&self, index: Idx) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T: Serializable + Default, Idx: SliceIndex<[T]>, const N: usize> IndexMut<Idx>
+    for ArrayList<T, N>
+{
+    fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
+        &mut self.data[..self.len][index]
+    }
+}
+
+impl<T: Serializable + Default + Clone, const N: usize> TryFrom<&[T]> for ArrayList<T, N> {
+    type Error = Error;
+
+    fn try_from(data: &[T]) -> Result<Self, Self::Error> {
+        Self::try_from_iter(data.iter().cloned())
+    }
+}
+
+impl<T: Serializable + Default, const N: usize> From<ArrayList<T, N>> for crate::List<T, N> {
+    fn from(list: ArrayList<T, N>) -> Self {
+        let len = list.len;
+        let data = list.data.into_iter().take(len).collect::<Vec<_>>();
+        data.try_into()
+            .map_err(|(_, err)| err)
+            .expect("an ArrayList holds no more elements than the List with the same bound")
+    }
+}
+
+impl<T: Serializable + Default, const N: usize> Serializable for ArrayList<T, N> {
+    const FIXED_SIZE: Option<usize> = None;
+
+    fn serialized_length(&self) -> Result<usize, SerializeError> {
+        serialized_length_of_elements(self.as_slice())
+    }
+}
+
+impl<T: Serializable + Default, const N: usize> Serialize for ArrayList<T, N> {
+    fn serialize(&self, buffer: &mut Vec<u8>) -> Result<usize, SerializeError> {
+        serialize_elements(self.as_slice(), buffer)
+    }
+
+    fn serialize_to_sink(&self, sink: &mut dyn EncodingSink) -> Result<usize, SerializeError> {
+        serialize_elements_to_sink(self.as_slice(), sink)
+    }
+}
+
+impl<T: Serializable + Default, const N: usize> ArrayList<T, N> {
+    fn from_elements(elements: Vec<T>) -> Result<Self, DeserializeError> {
+        Self::try_from_iter(elements).map_err(|err| match err {
+            Error::Instance(err) => DeserializeError::InvalidInstance(err),
+            _ => unreachable!("no other error variant allowed here"),
+        })
+    }
+}
+
+impl<T: Serializable + Default, const N: usize> Deserialize for ArrayList<T, N> {
+    fn deserialize(encoding: &[u8]) -> Result<Self, DeserializeError> {
+        check_remainder::<T>(encoding)?;
+        Self::from_elements(deserialize_homogeneous_composite(encoding)?)
+    }
+
+    fn deserialize_from_source(
+        source: &mut dyn DecodingSource,
+        len: Option<usize>,
+    ) -> Result<Self, DeserializeError> {
+        Self::from_elements(deserialize_homogeneous_composite_from_source(source, len, N)?)
+    }
+
+    fn validate(encoding: &[u8]) -> Result<(), DeserializeError> {
+        check_remainder::<T>(encoding)?;
+        check_count::<N>(validate_homogeneous_composite::<T>(encoding)?)
+    }
+}
+
+impl<T: SimpleSerialize + Default, const N: usize> ArrayList<T, N> {
+    // Number of chunks the tree of the list's data is padded to, as for a `List`
+    fn chunk_limit() -> usize {
+        if T::is_composite_type() {
+            N
+        } else {
+            (N * T::size_hint()).div_ceil(BYTES_PER_CHUNK)
+        }
+    }
+
+    fn compute_data_root(&self) -> Result<Node, MerkleizationError> {
+        let chunks = if T::is_composite_type() {
+            elements_to_chunks(self.as_slice())?
+        } else {
+            pack(self.as_slice())?
+        };
+        merkleize(&chunks, Some(Self::chunk_limit()))
+    }
+}
+
+impl<T: SimpleSerialize + Default, const N: usize> Merkleized for ArrayList<T, N> {
+    fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
+        Ok(mix_in_length(&self.compute_data_root()?, self.len))
+    }
+}
+
+impl<T: SimpleSerialize + Default, const N: usize> Prove for ArrayList<T, N> {
+    fn prove_path(
+        &self,
+        path: Path,
+        branch: &mut Vec<Node>,
+    ) -> Result<(Node, usize), MerkleizationError> {
+        match path {
+            [] => Ok((self.hash_tree_root()?, 0)),
+            [PathElement::Length] => {
+                branch.push(self.compute_data_root()?);
+                Ok((self.len.hash_tree_root()?, 1))
+            }
+            [PathElement::Length, element, ..] => {
+                Err(MerkleizationError::InvalidPathElement(element.clone()))
+            }
+            path => {
+                let len = self.len;
+                let elements = &self.data[..len];
+                let proof = prove_element(elements, Some(Self::chunk_limit()), path, branch)?;
+                branch.push(len.hash_tree_root()?);
+                Ok(proof)
+            }
+        }
+    }
+}
+
+impl<T: SimpleSerialize + Default, const N: usize> SimpleSerialize for ArrayList<T, N> {}
+
+#[cfg(feature = "serde")]
+impl<T: Serializable + Default + serde::Serialize, const N: usize> serde::Serialize
+    for ArrayList<T, N>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, const N: usize> serde::Deserialize<'de> for ArrayList<T, N>
+where
+    T: Serializable + Default + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let data = Vec::<T>::deserialize(deserializer)?;
+        Self::try_from_iter(data).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, const N: usize> arbitrary::Arbitrary<'a> for ArrayList<T, N>
+where
+    T: Serializable + Default + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut result = Self::default();
+        for element in u.arbitrary_iter()?.take(N) {
+            result.data[result.len] = element?;
+            result.len += 1;
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn matches_list() {
+        let mut list = ArrayList::<u16, 5>::default();
+        for i in 1..=5 {
+            list.push(i).unwrap();
+        }
+        assert!(list.push(6).is_err());
+        assert_eq!(list.pop(), Some(5));
+        let other = List::<u16, 5>::try_from(vec![1, 2, 3, 4]).unwrap();
+        let encoding = serialize(&list).unwrap();
+        assert_eq!(encoding, serialize(&other).unwrap());
+        assert_eq!(ArrayList::<u16, 5>::deserialize(&encoding).unwrap(), list);
+        assert_eq!(list.hash_tree_root().unwrap(), other.hash_tree_root().unwrap());
+        let path = &[2.into()];
+        assert_eq!(list.prove(path).unwrap(), other.prove(path).unwrap());
+        assert_eq!(List::from(list.clone()), other);
+
+        list.truncate(1);
+        assert_eq!(list.as_slice(), [1]);
+        assert!(ArrayList::<u16, 5>::deserialize(&[0; 12]).is_err());
+
+        let inner = List::<u8, 4>::try_from(vec![1, 2]).unwrap();
+        let mut nested = ArrayList::<List<u8, 4>, 3>::default();
+        nested.push(inner.clone()).unwrap();
+        nested.push(List::default()).unwrap();
+        let other = List::<List<u8, 4>, 3>::try_from(vec![inner, List::default()]).unwrap();
+        let encoding = serialize(&nested).unwrap();
+        assert_eq!(encoding, serialize(&other).unwrap());
+        assert_eq!(ArrayList::<List<u8, 4>, 3>::deserialize(&encoding).unwrap(), nested);
+        let path = &[0.into(), 1.into()];
+        assert_eq!(nested.prove(path).unwrap(), other.prove(path).unwrap());
+    }
+}
@@ -1,9 +1,11 @@
 use crate::{
+    bitvector::combine_bits,
     de::{Deserialize, DeserializeError},
     error::{Error, InstanceError},
     lib::*,
     merkleization::{
-        merkleize, mix_in_length, pack_bytes, MerkleizationError, Merkleized, Node, BITS_PER_CHUNK,
+        merkleize, mix_in_length, pack_bytes, prove_bit, record_allocation, MerkleizationError,
+        Merkleized, Node, Path, PathElement, Proof, Prove, BITS_PER_CHUNK,
     },
     ser::{Serialize, SerializeError},
     Serializable, SimpleSerialize,
@@ -20,7 +22,11 @@ fn byte_length(bound: usize) -> usize {
 type BitlistInner = BitVec<u8, Lsb0>;
 
 /// A homogenous collection of a variable number of boolean values.
-#[derive(PartialEq, Eq, Clone)]
+///
+/// The methods of the underlying `BitVec`, e.g. `count_ones` and `iter_ones`, are available
+/// through `Deref`. The set operations defined here combine bitlists of the same length, e.g.
+/// the aggregation bits of attestations to the same committee.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub struct Bitlist<const N: usize>(BitlistInner);
 
 impl<const N: usize> fmt::Debug for Bitlist<N> {
@@ -64,7 +70,72 @@ impl<const N: usize> Bitlist<N> {
         })
     }
 
+    /// Build a bitlist of `len` bits with the bits at each of `indices` set, failing if `len`
+    /// is more than `N` or any index is not less than `len`.
+    pub fn from_set_indices<I: IntoIterator<Item = usize>>(
+        len: usize,
+        indices: I,
+    ) -> Result<Self, Error> {
+        if len > N {
+            return Err(Error::Instance(InstanceError::Bounded { bound: N, provided: len }))
+        }
+        let mut result = Self(BitVec::repeat(false, len));
+        for index in indices {
+            if index >= len {
+                return Err(Error::Instance(InstanceError::Bounded {
+                    bound: len,
+                    provided: index + 1,
+                }))
+            }
+            result.0.set(index, true);
+        }
+        Ok(result)
+    }
+
+    // Combine `self` with `other` a byte at a time, failing if they differ in length.
+    fn combine(&self, other: &Self, op: impl Fn(u8, u8) -> u8) -> Result<Self, Error> {
+        if self.len() != other.len() {
+            return Err(Error::Instance(InstanceError::Exact {
+                required: self.len(),
+                provided: other.len(),
+            }))
+        }
+        let mut result = self.clone();
+        combine_bits(&mut result.0, &other.0, op);
+        Ok(result)
+    }
+
+    /// Return the bits set in either `self` or `other`, failing if they differ in length.
+    pub fn union(&self, other: &Self) -> Result<Self, Error> {
+        self.combine(other, |a, b| a | b)
+    }
+
+    /// Return the bits set in both `self` and `other`, failing if they differ in length.
+    pub fn intersection(&self, other: &Self) -> Result<Self, Error> {
+        self.combine(other, |a, b| a & b)
+    }
+
+    /// Return the bits set in `self` but not in `other`, failing if they differ in length.
+    pub fn difference(&self, other: &Self) -> Result<Self, Error> {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    /// Whether every bit set in `self` is also set in `other`, failing if they differ in
+    /// length.
+    pub fn subset_of(&self, other: &Self) -> Result<bool, Error> {
+        Ok(self.difference(other)?.not_any())
+    }
+
+    /// Build a proof of the length of the bitlist against its hash tree root, which can be
+    /// checked with [`Proof::verify_length`] by someone holding none of the bits.
+    pub fn prove_length(&self) -> Result<Proof, MerkleizationError> {
+        let chunks = self.pack_bits()?;
+        let branch = vec![merkleize(&chunks, Some(Self::chunk_count()))?];
+        Ok(Proof { leaf: self.len().hash_tree_root()?, branch, index: 1 })
+    }
+
     fn pack_bits(&self) -> Result<Vec<u8>, MerkleizationError> {
+        record_allocation();
         let mut data = vec![];
         let _ = self.serialize_with_length(&mut data, false)?;
         pack_bytes(&mut data);
@@ -116,12 +187,14 @@ impl<const N: usize> DerefMut for Bitlist<N> {
 }
 
 impl<const N: usize> Serializable for Bitlist<N> {
-    fn is_variable_size() -> bool {
-        true
-    }
+    const FIXED_SIZE: Option<usize> = None;
 
-    fn size_hint() -> usize {
-        0
+    fn serialized_length(&self) -> Result<usize, SerializeError> {
+        if self.len() > N {
+            return Err(InstanceError::Bounded { bound: N, provided: self.len() }.into())
+        }
+        // account for the delimiting bit
+        Ok((self.len() + 1).div_ceil(BITS_PER_BYTE))
     }
 }
 
@@ -133,55 +206,93 @@ impl<const N: usize> Serialize for Bitlist<N> {
 
 impl<const N: usize> Deserialize for Bitlist<N> {
     fn deserialize(encoding: &[u8]) -> Result<Self, DeserializeError> {
-        // validate byte length - min
-        if encoding.is_empty() {
-            return Err(DeserializeError::ExpectedFurtherInput { provided: 0, expected: 1 })
-        }
-
-        // validate byte length - max
-        let max_len = byte_length(N);
-        if encoding.len() > max_len {
-            return Err(DeserializeError::AdditionalInput {
-                provided: encoding.len(),
-                expected: max_len,
-            })
-        }
-
+        let len = decoded_length(encoding, N)?;
+        // SAFETY: `encoding` is not empty, as it holds at least the marker bit; qed
         let (last_byte, prefix) = encoding.split_last().unwrap();
-        if *last_byte == 0u8 {
-            return Err(DeserializeError::InvalidByte(*last_byte))
-        }
-
         let mut result = BitlistInner::from_slice(prefix);
         let last = BitlistInner::from_element(*last_byte);
+        result.extend_from_bitslice(&last[..len - result.len()]);
+        Ok(Self(result))
+    }
 
-        // validate bit length satisfies bound `N`
-        // SAFETY: checked subtraction is unnecessary,
-        // as last_byte != 0, so last.trailing_zeros <= 7; qed
-        // therefore: bit_length >= 1
-        let bit_length = BITS_PER_BYTE - last.trailing_zeros();
-        let additional_members = bit_length - 1; // skip marker bit
-        let total_members = result.len() + additional_members;
-        if total_members > N {
-            return Err(DeserializeError::InvalidInstance(InstanceError::Bounded {
-                bound: N,
-                provided: total_members,
-            }))
-        }
+    fn validate(encoding: &[u8]) -> Result<(), DeserializeError> {
+        decoded_length(encoding, N).map(|_| ())
+    }
+}
 
-        result.extend_from_bitslice(&last[..additional_members]);
-        Ok(Self(result))
+// Check `encoding` is that of a bitlist of up to `bound` bits, returning the number of bits it
+// holds.
+pub(crate) fn decoded_length(encoding: &[u8], bound: usize) -> Result<usize, DeserializeError> {
+    // validate byte length - min
+    if encoding.is_empty() {
+        return Err(DeserializeError::ExpectedFurtherInput { provided: 0, expected: 1 })
     }
+
+    // validate byte length - max
+    let max_len = byte_length(bound);
+    if encoding.len() > max_len {
+        return Err(DeserializeError::AdditionalInput {
+            provided: encoding.len(),
+            expected: max_len,
+        })
+    }
+
+    let (last_byte, prefix) = encoding.split_last().unwrap();
+    if *last_byte == 0u8 {
+        return Err(DeserializeError::InvalidByte(*last_byte))
+    }
+
+    // validate bit length satisfies `bound`
+    // SAFETY: checked subtraction is unnecessary,
+    // as last_byte != 0, so last_byte.leading_zeros() <= 7; qed
+    // therefore: bit_length >= 1
+    let bit_length = BITS_PER_BYTE - last_byte.leading_zeros() as usize;
+    let additional_members = bit_length - 1; // skip marker bit
+    let total_members = prefix.len() * BITS_PER_BYTE + additional_members;
+    if total_members > bound {
+        return Err(DeserializeError::InvalidInstance(InstanceError::Bounded {
+            bound,
+            provided: total_members,
+        }))
+    }
+    Ok(total_members)
 }
 
 impl<const N: usize> Merkleized for Bitlist<N> {
-    fn hash_tree_root(&mut self) -> Result<Node, MerkleizationError> {
+    fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
         let chunks = self.pack_bits()?;
         let data_root = merkleize(&chunks, Some(Self::chunk_count()))?;
         Ok(mix_in_length(&data_root, self.len()))
     }
 }
 
+impl<const N: usize> Prove for Bitlist<N> {
+    fn prove_path(
+        &self,
+        path: Path,
+        branch: &mut Vec<Node>,
+    ) -> Result<(Node, usize), MerkleizationError> {
+        match path {
+            [] => Ok((self.hash_tree_root()?, 0)),
+            [PathElement::Length] => {
+                let chunks = self.pack_bits()?;
+                branch.push(merkleize(&chunks, Some(Self::chunk_count()))?);
+                Ok((self.len().hash_tree_root()?, 1))
+            }
+            [PathElement::Length, element, ..] => {
+                Err(MerkleizationError::InvalidPathElement(element.clone()))
+            }
+            path => {
+                let chunks = self.pack_bits()?;
+                let proof =
+                    prove_bit(&chunks, self.len(), Some(Self::chunk_count()), path, branch)?;
+                branch.push(self.len().hash_tree_root()?);
+                Ok(proof)
+            }
+        }
+    }
+}
+
 impl<const N: usize> SimpleSerialize for Bitlist<N> {}
 
 impl<const N: usize> TryFrom<&[u8]> for Bitlist<N> {
@@ -232,6 +343,17 @@ impl<'de, const N: usize> serde::Deserialize<'de> for Bitlist<N> {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, const N: usize> arbitrary::Arbitrary<'a> for Bitlist<N> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut result = Self::default();
+        for bit in u.arbitrary_iter()?.take(N) {
+            result.push(bit?);
+        }
+        Ok(result)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,6 +361,26 @@ mod tests {
 
     const COUNT: usize = 256;
 
+    #[test]
+    fn set_operations() {
+        let a = Bitlist::<16>::from_set_indices(10, [1, 2, 9]).unwrap();
+        let b = Bitlist::<16>::from_set_indices(10, [2, 8]).unwrap();
+        let union = a.union(&b).unwrap();
+        assert_eq!(union.iter_ones().collect::<Vec<_>>(), [1, 2, 8, 9]);
+        assert_eq!(union.len(), 10);
+        assert_eq!(a.intersection(&b).unwrap().iter_ones().collect::<Vec<_>>(), [2]);
+        let difference = b.difference(&a).unwrap();
+        assert_eq!(serialize(&difference).unwrap(), [0, 0b0000_0101]);
+        assert_eq!(union.count_ones(), 4);
+
+        assert!(a.subset_of(&union).unwrap());
+        assert!(!union.subset_of(&a).unwrap());
+        let short = Bitlist::<16>::from_set_indices(9, [1]).unwrap();
+        assert!(a.union(&short).is_err());
+        assert!(Bitlist::<16>::from_set_indices(10, [10]).is_err());
+        assert!(Bitlist::<16>::from_set_indices(17, []).is_err());
+    }
+
     #[test]
     fn encode_bitlist() {
         let value: Bitlist<COUNT> = Bitlist::default();
@@ -2,7 +2,10 @@ use crate::{
     de::{Deserialize, DeserializeError},
     error::{Error, InstanceError, TypeError},
     lib::*,
-    merkleization::{merkleize, pack_bytes, MerkleizationError, Merkleized, Node, BITS_PER_CHUNK},
+    merkleization::{
+        merkleize, pack_bytes, prove_bit, record_allocation, MerkleizationError, Merkleized, Node,
+        Path, Prove, BITS_PER_CHUNK,
+    },
     ser::{Serialize, SerializeError},
     Serializable, SimpleSerialize,
 };
@@ -13,22 +16,38 @@ use bitvec::{
 
 const BITS_PER_BYTE: usize = crate::BITS_PER_BYTE as usize;
 
-fn byte_length(bound: usize) -> usize {
+const fn byte_length(bound: usize) -> usize {
     (bound + BITS_PER_BYTE - 1) / BITS_PER_BYTE
 }
 
 type BitvectorInner = BitVec<u8, Lsb0>;
 
+// Combine each byte of `bits` with the byte of `other` in the same place, clearing the bits past
+// the end afterwards as the encoding of `bits` is taken from its bytes.
+pub(crate) fn combine_bits(
+    bits: &mut BitVec<u8, Lsb0>,
+    other: &BitVec<u8, Lsb0>,
+    op: impl Fn(u8, u8) -> u8,
+) {
+    for (byte, other) in bits.as_raw_mut_slice().iter_mut().zip(other.as_raw_slice()) {
+        *byte = op(*byte, *other);
+    }
+    bits.set_uninitialized(false);
+}
+
 /// A homogenous collection of a fixed number of boolean values.
 ///
 /// NOTE: a `Bitvector` of length `0` is illegal.
 ///
+/// The methods of the underlying `BitVec`, e.g. `count_ones` and `iter_ones`, are available
+/// through `Deref` and work a byte at a time, as do the set operations defined here.
+///
 /// NOTE: once `const_generics` and `const_evaluatable_checked` features stabilize,
 /// this type can use something like
 /// bitvec::array::BitArray<T, {N / 8}> where T: BitRegister, [T; {N / 8}]: BitViewSized
 ///
 /// Refer: <https://stackoverflow.com/a/65462213>
-#[derive(PartialEq, Eq, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub struct Bitvector<const N: usize>(BitvectorInner);
 
 impl<const N: usize> fmt::Debug for Bitvector<N> {
@@ -76,7 +95,53 @@ impl<const N: usize> Bitvector<N> {
         })
     }
 
+    /// Build a bitvector with the bits at each of `indices` set, failing if any is not less
+    /// than `N`.
+    pub fn from_set_indices<I: IntoIterator<Item = usize>>(indices: I) -> Result<Self, Error> {
+        if N == 0 {
+            return Err(Error::Type(TypeError::InvalidBound(N)))
+        }
+        let mut result = Self::default();
+        for index in indices {
+            if index >= N {
+                return Err(Error::Instance(InstanceError::Bounded {
+                    bound: N,
+                    provided: index + 1,
+                }))
+            }
+            result.0.set(index, true);
+        }
+        Ok(result)
+    }
+
+    /// Return the bits set in either `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        combine_bits(&mut result.0, &other.0, |a, b| a | b);
+        result
+    }
+
+    /// Return the bits set in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        combine_bits(&mut result.0, &other.0, |a, b| a & b);
+        result
+    }
+
+    /// Return the bits set in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        combine_bits(&mut result.0, &other.0, |a, b| a & !b);
+        result
+    }
+
+    /// Whether every bit set in `self` is also set in `other`.
+    pub fn subset_of(&self, other: &Self) -> bool {
+        self.difference(other).not_any()
+    }
+
     fn pack_bits(&self) -> Result<Vec<u8>, MerkleizationError> {
+        record_allocation();
         let mut data = vec![];
         let _ = self.serialize(&mut data)?;
         pack_bytes(&mut data);
@@ -103,13 +168,7 @@ impl<const N: usize> DerefMut for Bitvector<N> {
 }
 
 impl<const N: usize> Serializable for Bitvector<N> {
-    fn is_variable_size() -> bool {
-        false
-    }
-
-    fn size_hint() -> usize {
-        byte_length(N)
-    }
+    const FIXED_SIZE: Option<usize> = Some(byte_length(N));
 }
 
 impl<const N: usize> Serialize for Bitvector<N> {
@@ -128,47 +187,71 @@ impl<const N: usize> Serialize for Bitvector<N> {
 
 impl<const N: usize> Deserialize for Bitvector<N> {
     fn deserialize(encoding: &[u8]) -> Result<Self, DeserializeError> {
-        if N == 0 {
-            return Err(TypeError::InvalidBound(N).into())
-        }
-
-        let expected_length = byte_length(N);
-        if encoding.len() < expected_length {
-            return Err(DeserializeError::ExpectedFurtherInput {
-                provided: encoding.len(),
-                expected: expected_length,
-            })
-        }
-        if encoding.len() > expected_length {
-            return Err(DeserializeError::AdditionalInput {
-                provided: encoding.len(),
-                expected: expected_length,
-            })
-        }
-
+        Self::validate(encoding)?;
         let mut result = Self::default();
         for (slot, byte) in result.chunks_mut(BITS_PER_BYTE).zip(encoding.iter().copied()) {
             slot.store_le(byte);
         }
-        let remainder_count = N % BITS_PER_BYTE;
-        if remainder_count != 0 {
-            let last_byte = encoding.last().unwrap();
-            let remainder_bits = last_byte >> remainder_count;
-            if remainder_bits != 0 {
-                return Err(DeserializeError::InvalidByte(*last_byte))
-            }
-        }
         Ok(result)
     }
+
+    fn validate(encoding: &[u8]) -> Result<(), DeserializeError> {
+        check_encoding(encoding, N)
+    }
+}
+
+// Check `encoding` is that of a bitvector of `bound` bits.
+pub(crate) fn check_encoding(encoding: &[u8], bound: usize) -> Result<(), DeserializeError> {
+    if bound == 0 {
+        return Err(TypeError::InvalidBound(bound).into())
+    }
+
+    let expected_length = byte_length(bound);
+    if encoding.len() < expected_length {
+        return Err(DeserializeError::ExpectedFurtherInput {
+            provided: encoding.len(),
+            expected: expected_length,
+        })
+    }
+    if encoding.len() > expected_length {
+        return Err(DeserializeError::AdditionalInput {
+            provided: encoding.len(),
+            expected: expected_length,
+        })
+    }
+
+    let remainder_count = bound % BITS_PER_BYTE;
+    if remainder_count != 0 {
+        let last_byte = encoding.last().unwrap();
+        let remainder_bits = last_byte >> remainder_count;
+        if remainder_bits != 0 {
+            return Err(DeserializeError::InvalidByte(*last_byte))
+        }
+    }
+    Ok(())
 }
 
 impl<const N: usize> Merkleized for Bitvector<N> {
-    fn hash_tree_root(&mut self) -> Result<Node, MerkleizationError> {
+    fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
         let chunks = self.pack_bits()?;
         merkleize(&chunks, Some(Self::chunk_count()))
     }
 }
 
+impl<const N: usize> Prove for Bitvector<N> {
+    fn prove_path(
+        &self,
+        path: Path,
+        branch: &mut Vec<Node>,
+    ) -> Result<(Node, usize), MerkleizationError> {
+        if path.is_empty() {
+            return Ok((self.hash_tree_root()?, 0))
+        }
+        let chunks = self.pack_bits()?;
+        prove_bit(&chunks, N, Some(Self::chunk_count()), path, branch)
+    }
+}
+
 impl<const N: usize> SimpleSerialize for Bitvector<N> {}
 
 impl<const N: usize> TryFrom<&[u8]> for Bitvector<N> {
@@ -218,6 +301,20 @@ impl<'de, const N: usize> serde::Deserialize<'de> for Bitvector<N> {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, const N: usize> arbitrary::Arbitrary<'a> for Bitvector<N> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        if N == 0 {
+            return Err(arbitrary::Error::IncorrectFormat)
+        }
+        let mut result = Self::default();
+        for i in 0..N {
+            result.set(i, u.arbitrary()?);
+        }
+        Ok(result)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,6 +322,23 @@ mod tests {
 
     const COUNT: usize = 12;
 
+    #[test]
+    fn set_operations() {
+        let a = Bitvector::<12>::from_set_indices([0, 3, 4, 11]).unwrap();
+        let b = Bitvector::<12>::from_set_indices([3, 5, 11]).unwrap();
+        assert_eq!(a.union(&b).iter_ones().collect::<Vec<_>>(), [0, 3, 4, 5, 11]);
+        assert_eq!(a.intersection(&b).iter_ones().collect::<Vec<_>>(), [3, 11]);
+        let difference = b.difference(&a);
+        assert_eq!(difference.iter_ones().collect::<Vec<_>>(), [5]);
+        // the padding of the last byte stays clear
+        assert_eq!(serialize(&difference).unwrap(), [0b0010_0000, 0]);
+        assert_eq!(a.count_ones(), 4);
+
+        assert!(a.intersection(&b).subset_of(&a));
+        assert!(!a.subset_of(&b));
+        assert!(Bitvector::<12>::from_set_indices([12]).is_err());
+    }
+
     #[test]
     fn encode_bitvector() {
         let value: Bitvector<4> = Bitvector::default();
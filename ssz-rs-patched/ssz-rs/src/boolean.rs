@@ -1,19 +1,13 @@
 use crate::{
     de::{Deserialize, DeserializeError},
     lib::*,
-    merkleization::{MerkleizationError, Merkleized, Node},
-    ser::{Serialize, SerializeError},
+    merkleization::{prove_basic, MerkleizationError, Merkleized, Node, Path, Prove},
+    ser::{EncodingSink, Serialize, SerializeError},
     Serializable, SimpleSerialize,
 };
 
 impl Serializable for bool {
-    fn is_variable_size() -> bool {
-        false
-    }
-
-    fn size_hint() -> usize {
-        1
-    }
+    const FIXED_SIZE: Option<usize> = Some(1);
 }
 
 impl Serialize for bool {
@@ -22,6 +16,11 @@ impl Serialize for bool {
         buffer.push(value);
         Ok(1)
     }
+
+    fn serialize_to_sink(&self, sink: &mut dyn EncodingSink) -> Result<usize, SerializeError> {
+        sink.write(&[u8::from(*self)])?;
+        Ok(1)
+    }
 }
 
 impl Deserialize for bool {
@@ -40,7 +39,7 @@ impl Deserialize for bool {
 }
 
 impl Merkleized for bool {
-    fn hash_tree_root(&mut self) -> Result<Node, MerkleizationError> {
+    fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
         let mut node = Node::default();
         if *self {
             node.as_mut()[0] = 1;
@@ -53,6 +52,16 @@ impl Merkleized for bool {
     }
 }
 
+impl Prove for bool {
+    fn prove_path(
+        &self,
+        path: Path,
+        _: &mut Vec<Node>,
+    ) -> Result<(Node, usize), MerkleizationError> {
+        prove_basic(self, path)
+    }
+}
+
 impl SimpleSerialize for bool {}
 
 #[cfg(test)]
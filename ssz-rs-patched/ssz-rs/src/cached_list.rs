@@ -0,0 +1,664 @@
+use crate::{
+    de::{DecodingSource, Deserialize, DeserializeError},
+    error::{Error, InstanceError},
+    iter::chunks_of,
+    lib::*,
+    list::List,
+    merkleization::{
+        hash_nodes, mix_in_length, zero_hash, MerkleizationError, Merkleized, Node, Path, Prove,
+        BYTES_PER_CHUNK,
+    },
+    ser::{EncodingSink, Serialize, SerializeError},
+    Serializable, SimpleSerialize,
+};
+use sha2::{Digest, Sha256};
+
+/// A `List` which caches the internal nodes of its hash tree, so that after changing a few
+/// elements the hash tree root is computed by only rehashing the branches above them.
+///
+/// Mutation is only possible through methods that record which elements changed, so the cache
+/// can never go stale. Elements which are themselves composite are rehashed in full when
+/// changed; nest `CachedList`s to cache deeper levels of a structure.
+///
+/// The cache is updated when the root is computed, through a shared reference, so a
+/// `CachedList` is not `Sync`.
+#[derive(Clone)]
+pub struct CachedList<T: Serializable, const N: usize> {
+    data: List<T, N>,
+    cache: RefCell<TreeCache>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TreeCache {
+    // `layers[0]` holds the chunks of `data` and each following layer holds the parents of
+    // the layer below, up to the root of the smallest perfect tree containing every chunk.
+    layers: Vec<Vec<Node>>,
+    // Indices of chunks that have changed since the cache was last updated. An index may be
+    // past the current chunk count if the list has shrunk.
+    dirty: BTreeSet<usize>,
+    // A run of changed chunks, kept apart from `dirty` so that rewriting a large part of the
+    // list does not cost an entry per chunk.
+    dirty_range: Range<usize>,
+}
+
+impl<T, const N: usize> fmt::Debug for CachedList<T, N>
+where
+    T: Serializable + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("CachedList").field("data", &self.data).finish()
+    }
+}
+
+impl<T, const N: usize> Default for CachedList<T, N>
+where
+    T: SimpleSerialize,
+{
+    fn default() -> Self {
+        Self::from(List::default())
+    }
+}
+
+impl<T, const N: usize> PartialEq for CachedList<T, N>
+where
+    T: Serializable + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<T, const N: usize> Eq for CachedList<T, N> where T: Serializable + Eq {}
+
+impl<T, const N: usize> hash::Hash for CachedList<T, N>
+where
+    T: Serializable + hash::Hash,
+{
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+    }
+}
+
+impl<T, const N: usize> PartialOrd for CachedList<T, N>
+where
+    T: Serializable + PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.data.partial_cmp(&other.data)
+    }
+}
+
+impl<T, const N: usize> Ord for CachedList<T, N>
+where
+    T: Serializable + Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.data.cmp(&other.data)
+    }
+}
+
+impl<T, const N: usize> From<List<T, N>> for CachedList<T, N>
+where
+    T: SimpleSerialize,
+{
+    fn from(data: List<T, N>) -> Self {
+        let dirty = (0..Self::chunks_for(data.len())).collect();
+        Self {
+            data,
+            cache: RefCell::new(TreeCache { layers: vec![], dirty, ..Default::default() }),
+        }
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T>> for CachedList<T, N>
+where
+    T: SimpleSerialize,
+{
+    type Error = (Vec<T>, Error);
+
+    fn try_from(data: Vec<T>) -> Result<Self, Self::Error> {
+        List::try_from(data).map(Self::from)
+    }
+}
+
+impl<T, const N: usize> Deref for CachedList<T, N>
+where
+    T: Serializable,
+{
+    type Target = List<T, N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T, const N: usize> Index<usize> for CachedList<T, N>
+where
+    T: SimpleSerialize,
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for CachedList<T, N>
+where
+    T: SimpleSerialize,
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect("index is in bounds")
+    }
+}
+
+impl<T, const N: usize> CachedList<T, N>
+where
+    T: SimpleSerialize,
+{
+    pub fn into_inner(self) -> List<T, N> {
+        self.data
+    }
+
+    fn elements_per_chunk() -> usize {
+        if T::is_composite_type() {
+            1
+        } else {
+            BYTES_PER_CHUNK / T::size_hint()
+        }
+    }
+
+    // Number of chunks needed to hold `count` elements.
+    fn chunks_for(count: usize) -> usize {
+        match count {
+            0 => 0,
+            count => (count - 1) / Self::elements_per_chunk() + 1,
+        }
+    }
+
+    fn chunk_limit() -> usize {
+        Self::chunks_for(N)
+    }
+
+    fn chunk_count(&self) -> usize {
+        Self::chunks_for(self.data.len())
+    }
+
+    fn mark_dirty(&mut self, index: usize) {
+        self.cache.get_mut().dirty.insert(index / Self::elements_per_chunk());
+    }
+
+    fn mark_range_dirty(&mut self, elements: Range<usize>) {
+        if elements.is_empty() {
+            return
+        }
+        let per_chunk = Self::elements_per_chunk();
+        let range = elements.start / per_chunk..Self::chunks_for(elements.end);
+        let cache = self.cache.get_mut();
+        let current = cache.dirty_range.clone();
+        cache.dirty_range = if current.is_empty() {
+            range
+        } else if range.start <= current.end && current.start <= range.end {
+            current.start.min(range.start)..current.end.max(range.end)
+        } else {
+            // track the longer of the two runs as a range and the other chunk by chunk
+            let (longer, shorter) =
+                if range.len() > current.len() { (range, current) } else { (current, range) };
+            cache.dirty.extend(shorter);
+            longer
+        };
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.data.len() {
+            return None
+        }
+        self.mark_dirty(index);
+        Some(&mut self.data[index])
+    }
+
+    // Record the list was shortened to `len` elements, so the last chunk may have lost elements
+    // and the branch to the first chunk past the end has to be rehashed.
+    fn mark_truncated(&mut self, len: usize) {
+        self.mark_dirty(len);
+        self.cache.get_mut().dirty.insert(Self::chunks_for(len));
+    }
+
+    /// Append `element`, failing if the list already holds `N` elements.
+    pub fn push(&mut self, element: T) -> Result<(), Error> {
+        let index = self.data.len();
+        self.data.push(element)?;
+        self.mark_dirty(index);
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let element = self.data.pop()?;
+        self.mark_dirty(self.data.len());
+        Some(element)
+    }
+
+    /// Keep the first `len` elements, dropping the rest, if there are more than `len`.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.data.len() {
+            self.data.truncate(len);
+            self.mark_truncated(len);
+        }
+    }
+
+    /// Append a copy of each of `elements`, failing without changing the list if it would
+    /// hold more than `N` elements.
+    pub fn extend_from_slice(&mut self, elements: &[T]) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        let start = self.data.len();
+        self.data.extend_from_slice(elements)?;
+        self.mark_range_dirty(start..self.data.len());
+        Ok(())
+    }
+
+    /// Keep only the elements for which `f` returns `true`, in order.
+    ///
+    /// Every element after the first one removed has moved, so its chunk is rehashed.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut index = 0;
+        let mut first_removed = None;
+        self.data.retain(|element| {
+            let keep = f(element);
+            if !keep && first_removed.is_none() {
+                first_removed = Some(index);
+            }
+            index += 1;
+            keep
+        });
+        if let Some(first_removed) = first_removed {
+            let len = self.data.len();
+            self.mark_range_dirty(first_removed..len);
+            self.mark_truncated(len);
+        }
+    }
+
+    /// Overwrite the elements starting at `start` with `values`.
+    ///
+    /// The changed chunks are recorded as a single range, which is rehashed layer by layer
+    /// without tracking each chunk, so prefer this to `get_mut` when rewriting many
+    /// contiguous elements, e.g. every validator balance at an epoch boundary.
+    pub fn update_range<I>(&mut self, start: usize, values: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let values = values.into_iter();
+        let end = start.saturating_add(values.len());
+        if end > self.data.len() {
+            return Err(Error::Instance(InstanceError::Bounded {
+                bound: self.data.len(),
+                provided: end,
+            }))
+        }
+        for (element, value) in self.data[start..end].iter_mut().zip(values) {
+            *element = value;
+        }
+        self.mark_range_dirty(start..end);
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.data.clear();
+        *self.cache.get_mut() = TreeCache::default();
+    }
+
+    fn compute_chunk(&self, index: usize) -> Result<Node, MerkleizationError> {
+        if T::is_composite_type() {
+            self.data[index].hash_tree_root()
+        } else {
+            let per_chunk = Self::elements_per_chunk();
+            let end = usize::min((index + 1) * per_chunk, self.data.len());
+            let chunk = chunks_of(&self.data[index * per_chunk..end]).next().transpose()?;
+            Ok(chunk.map(Node::from).unwrap_or_default())
+        }
+    }
+
+    // Compute the chunks in `range` into `out`, packing basic elements all at once.
+    fn compute_chunks(
+        &self,
+        range: Range<usize>,
+        out: &mut [Node],
+    ) -> Result<(), MerkleizationError> {
+        if range.is_empty() {
+            return Ok(())
+        }
+        if T::is_composite_type() {
+            for (node, element) in out.iter_mut().zip(&self.data[range]) {
+                *node = element.hash_tree_root()?;
+            }
+        } else {
+            let per_chunk = Self::elements_per_chunk();
+            let end = usize::min(range.end * per_chunk, self.data.len());
+            let chunks = chunks_of(&self.data[range.start * per_chunk..end]);
+            for (node, chunk) in out.iter_mut().zip(chunks) {
+                *node = Node::from(chunk?);
+            }
+        }
+        Ok(())
+    }
+
+    // Bring the cached layers up to date with `data`, rehashing only the dirty branches.
+    fn update(&self, cache: &mut TreeCache) -> Result<(), MerkleizationError> {
+        let chunk_count = self.chunk_count();
+        let height = chunk_count.next_power_of_two().trailing_zeros() as usize;
+        cache.layers.resize_with(height + 1, Vec::new);
+        for (h, layer) in cache.layers.iter_mut().enumerate() {
+            let layer_count = (chunk_count + (1 << h) - 1) >> h;
+            layer.resize(layer_count, Node::default());
+        }
+
+        let mut dirty = core::mem::take(&mut cache.dirty);
+        let mut range = core::mem::take(&mut cache.dirty_range);
+        range.end = range.end.min(chunk_count);
+        range.start = range.start.min(range.end);
+        let mut result = self.compute_chunks(range.clone(), &mut cache.layers[0][range.clone()]);
+        for &index in dirty.iter().filter(|&&index| index < chunk_count && !range.contains(&index))
+        {
+            if result.is_err() {
+                break
+            }
+            result = self.compute_chunk(index).map(|chunk| cache.layers[0][index] = chunk);
+        }
+        if let Err(err) = result {
+            // leave the cache to be updated on the next attempt
+            cache.dirty = dirty;
+            cache.dirty_range = range;
+            return Err(err)
+        }
+
+        let mut hasher = Sha256::new();
+        for h in 0..height {
+            range = if range.is_empty() { 0..0 } else { range.start / 2..(range.end - 1) / 2 + 1 };
+            let parents = dirty
+                .iter()
+                .map(|index| index / 2)
+                .filter(|parent| !range.contains(parent))
+                .collect::<BTreeSet<_>>();
+            let (lower, upper) = cache.layers.split_at_mut(h + 1);
+            let (children, layer) = (&lower[h], &mut upper[0]);
+            let layer_count = layer.len();
+            range.end = range.end.min(layer_count);
+            for parent in range.clone().chain(parents.iter().copied()) {
+                if parent >= layer_count {
+                    continue
+                }
+                let left = &children[2 * parent];
+                let right = children.get(2 * parent + 1).map(|node| node.as_ref());
+                let right = right.unwrap_or(zero_hash(h));
+                hash_nodes(&mut hasher, left, right, &mut layer[parent]);
+            }
+            dirty = parents;
+        }
+        Ok(())
+    }
+
+    fn compute_hash_tree_root(&self) -> Result<Node, MerkleizationError> {
+        // the cache only holds SHA-256 nodes
+        #[cfg(feature = "std")]
+        if crate::merkleization::has_scoped_hasher() {
+            return self.data.hash_tree_root()
+        }
+        let mut cache = self.cache.borrow_mut();
+        self.update(&mut cache)?;
+        let chunk_count = self.chunk_count();
+        let leaf_count = Self::chunk_limit().next_power_of_two();
+        let depth = leaf_count.trailing_zeros() as usize;
+        if chunk_count == 0 {
+            let root = Node::from(*zero_hash(depth));
+            return Ok(mix_in_length(&root, 0))
+        }
+        // extend the cached tree up to the depth implied by the limit with "zero" subtrees
+        let height = cache.layers.len() - 1;
+        let mut root = cache.layers[height][0];
+        let mut hasher = Sha256::new();
+        for h in height..depth {
+            let left = root;
+            hash_nodes(&mut hasher, &left, zero_hash(h), &mut root);
+        }
+        Ok(mix_in_length(&root, self.data.len()))
+    }
+}
+
+impl<T, const N: usize> Serializable for CachedList<T, N>
+where
+    T: SimpleSerialize,
+{
+    const FIXED_SIZE: Option<usize> = None;
+
+    fn serialized_length(&self) -> Result<usize, SerializeError> {
+        self.data.serialized_length()
+    }
+}
+
+impl<T, const N: usize> Serialize for CachedList<T, N>
+where
+    T: SimpleSerialize,
+{
+    fn serialize(&self, buffer: &mut Vec<u8>) -> Result<usize, SerializeError> {
+        self.data.serialize(buffer)
+    }
+
+    fn serialize_to_sink(&self, sink: &mut dyn EncodingSink) -> Result<usize, SerializeError> {
+        self.data.serialize_to_sink(sink)
+    }
+}
+
+impl<T, const N: usize> Deserialize for CachedList<T, N>
+where
+    T: SimpleSerialize,
+{
+    fn deserialize(encoding: &[u8]) -> Result<Self, DeserializeError> {
+        List::deserialize(encoding).map(Self::from)
+    }
+
+    fn deserialize_from_source(
+        source: &mut dyn DecodingSource,
+        len: Option<usize>,
+    ) -> Result<Self, DeserializeError> {
+        List::deserialize_from_source(source, len).map(Self::from)
+    }
+
+    fn validate(encoding: &[u8]) -> Result<(), DeserializeError> {
+        List::<T, N>::validate(encoding)
+    }
+}
+
+impl<T, const N: usize> Merkleized for CachedList<T, N>
+where
+    T: SimpleSerialize,
+{
+    fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
+        self.compute_hash_tree_root()
+    }
+}
+
+impl<T, const N: usize> Prove for CachedList<T, N>
+where
+    T: SimpleSerialize,
+{
+    fn prove_path(
+        &self,
+        path: Path,
+        branch: &mut Vec<Node>,
+    ) -> Result<(Node, usize), MerkleizationError> {
+        if path.is_empty() {
+            return Ok((self.hash_tree_root()?, 0))
+        }
+        self.data.prove_path(path, branch)
+    }
+}
+
+impl<T, const N: usize> SimpleSerialize for CachedList<T, N> where T: SimpleSerialize {}
+
+#[cfg(feature = "serde")]
+impl<T: SimpleSerialize + serde::Serialize, const N: usize> serde::Serialize for CachedList<T, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&self.data, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: SimpleSerialize + serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de>
+    for CachedList<T, N>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        <List<T, N> as serde::Deserialize>::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Debug, Default, Clone, PartialEq, Eq, SimpleSerialize)]
+    struct Foo {
+        a: u64,
+        b: Vector<u8, 3>,
+    }
+
+    #[test]
+    fn test_cached_list_of_basic_type() {
+        let data = (0..100u16).collect::<Vec<_>>();
+        let mut list = List::<u16, 1000>::try_from(data.clone()).unwrap();
+        let mut cached = CachedList::<u16, 1000>::try_from(data).unwrap();
+        assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+
+        cached[17] = 4242;
+        list[17] = 4242;
+        assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+
+        for i in 0..40 {
+            cached.push(i).unwrap();
+            list.push(i).unwrap();
+            assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+        }
+        for _ in 0..140 {
+            assert_eq!(cached.pop(), list.pop());
+            assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+        }
+        assert!(cached.pop().is_none());
+    }
+
+    #[test]
+    fn test_cached_list_of_composite_type() {
+        let data = (0..9).map(|a| Foo { a, ..Default::default() }).collect::<Vec<_>>();
+        let mut list = List::<Foo, 32>::try_from(data.clone()).unwrap();
+        let mut cached = CachedList::<Foo, 32>::try_from(data).unwrap();
+        assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+
+        cached.get_mut(8).unwrap().b[1] = 3;
+        list[8].b[1] = 3;
+        assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+        assert!(cached.cache.borrow().dirty.is_empty());
+
+        cached.clear();
+        assert_eq!(
+            cached.hash_tree_root().unwrap(),
+            List::<Foo, 32>::default().hash_tree_root().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cached_list_update_range() {
+        let data = (0..300u64).collect::<Vec<_>>();
+        let mut list = List::<u64, 1024>::try_from(data.clone()).unwrap();
+        let mut cached = CachedList::<u64, 1024>::try_from(data).unwrap();
+        assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+
+        for (start, count) in [(0, 300), (5, 1), (7, 50), (290, 10), (100, 0)] {
+            let values = (0..count).map(|i| (start + i) as u64 * 3 + 1).collect::<Vec<_>>();
+            cached.update_range(start, values.clone()).unwrap();
+            list[start..start + count].copy_from_slice(&values);
+            assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+        }
+
+        // disjoint runs and single changes between roots
+        cached.update_range(3, vec![11, 12]).unwrap();
+        cached.update_range(200, vec![13; 40]).unwrap();
+        cached[150] = 14;
+        cached.update_range(250, vec![15; 5]).unwrap();
+        list[3..5].copy_from_slice(&[11, 12]);
+        list[200..240].copy_from_slice(&[13; 40]);
+        list[150] = 14;
+        list[250..255].copy_from_slice(&[15; 5]);
+        assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+
+        // a range past the end after shrinking
+        cached.update_range(280, vec![16; 20]).unwrap();
+        for _ in 0..100 {
+            cached.pop();
+            list.pop();
+        }
+        assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+
+        assert!(cached.update_range(199, vec![0, 0]).is_err());
+
+        let data = (0..9).map(|a| Foo { a, ..Default::default() }).collect::<Vec<_>>();
+        let mut list = List::<Foo, 32>::try_from(data.clone()).unwrap();
+        let mut cached = CachedList::<Foo, 32>::try_from(data).unwrap();
+        cached.hash_tree_root().unwrap();
+        let values = (0..4u32)
+            .map(|a| Foo { a: a as u64 + 100, b: Vector::try_from(vec![1, 2, 3]).unwrap() });
+        cached.update_range(4, values.clone()).unwrap();
+        for (i, value) in values.enumerate() {
+            list[4 + i] = value;
+        }
+        assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+    }
+
+    #[test]
+    fn test_cached_list_resize() {
+        let data = (0..70u32).collect::<Vec<_>>();
+        let mut list = List::<u32, 256>::try_from(data.clone()).unwrap();
+        let mut cached = CachedList::<u32, 256>::try_from(data).unwrap();
+        assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+
+        for len in [70, 65, 64, 13, 0] {
+            cached.truncate(len);
+            list.truncate(len);
+            assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+            let values = (0..len as u32 + 20).collect::<Vec<_>>();
+            cached.extend_from_slice(&values).unwrap();
+            list.extend_from_slice(&values).unwrap();
+            assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+        }
+
+        cached.retain(|&element| element % 3 != 0);
+        list.retain(|&element| element % 3 != 0);
+        assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+        cached.retain(|&element| element > 10);
+        list.retain(|&element| element > 10);
+        assert_eq!(cached.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+
+        let values = vec![0; 256 - cached.len()];
+        assert!(cached.extend_from_slice(&[0; 257]).is_err());
+        cached.extend_from_slice(&values).unwrap();
+        assert!(cached.push(1).is_err());
+    }
+
+    #[test]
+    fn test_cached_list_serialization() {
+        let cached = CachedList::<u32, 8>::try_from(vec![1, 2, 3]).unwrap();
+        let encoding = serialize(&cached).unwrap();
+        let recovered = CachedList::<u32, 8>::deserialize(&encoding).unwrap();
+        assert_eq!(recovered, cached);
+        assert_eq!(
+            recovered.hash_tree_root().unwrap(),
+            cached.into_inner().hash_tree_root().unwrap()
+        );
+    }
+}
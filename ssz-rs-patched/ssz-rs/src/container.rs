@@ -1,3 +1,113 @@
+use crate::{
+    de::{variable_part_error, Deserialize, DeserializeError},
+    lib::*,
+    ser::{Serialize, SerializeError, BYTES_PER_LENGTH_OFFSET},
+    Serializable,
+};
+
+/// A container, i.e. a struct deriving `Serializable`, whose fields can be read one at a time
+/// from its encoding.
+pub trait Container: Serializable {
+    /// The fixed size of each field in order of declaration, or `None` for variable-size fields.
+    const FIELD_SIZES: &'static [Option<usize>];
+
+    /// Decode the field at `index`, in order of declaration, from the `encoding` of a container
+    /// of this type, without decoding any other field.
+    ///
+    /// Only the offsets needed to locate the field are checked, so this succeeds for some
+    /// encodings that [`Deserialize::deserialize`](crate::Deserialize::deserialize) rejects.
+    ///
+    /// # Panics
+    ///
+    /// If there is no field at `index` or its size differs from that of `T`, which must be the
+    /// type of the field.
+    fn deserialize_field<T: Serializable>(
+        encoding: &[u8],
+        index: usize,
+    ) -> Result<T, DeserializeError> {
+        let field_size = Self::FIELD_SIZES[index];
+        assert_eq!(field_size, T::FIXED_SIZE, "`T` must be the type of field {index}");
+        let fixed_part_length = |sizes: &[Option<usize>]| {
+            sizes.iter().map(|size| size.unwrap_or(BYTES_PER_LENGTH_OFFSET)).sum::<usize>()
+        };
+        let position = fixed_part_length(&Self::FIELD_SIZES[..index]);
+        let read = |range: Range<usize>| {
+            encoding.get(range.clone()).ok_or(DeserializeError::ExpectedFurtherInput {
+                provided: encoding.len(),
+                expected: range.end,
+            })
+        };
+
+        if let Some(size) = field_size {
+            return T::deserialize(read(position..position + size)?)
+        }
+        let read_offset = |position: usize| -> Result<usize, DeserializeError> {
+            Ok(u32::deserialize(read(position..position + BYTES_PER_LENGTH_OFFSET)?)? as usize)
+        };
+        let start = read_offset(position)?;
+        let fixed_length = fixed_part_length(Self::FIELD_SIZES);
+        if start < fixed_length {
+            return Err(DeserializeError::OffsetIntoFixedPortion { offset: start, fixed_length })
+        }
+        // the field ends where the next variable-size field starts, if there is one
+        let next_variable = Self::FIELD_SIZES[index + 1..].iter().position(Option::is_none);
+        let end = match next_variable {
+            Some(i) => read_offset(fixed_part_length(&Self::FIELD_SIZES[..index + 1 + i]))?,
+            None => encoding.len(),
+        };
+        if end < start {
+            return Err(DeserializeError::OffsetNotIncreasing { start, end })
+        }
+        T::deserialize(read(start..end)?).map_err(|err| variable_part_error(err, end - start))
+    }
+}
+
+/// The functions of the module given to `#[ssz(with = "module")]` on a field of type `Value`,
+/// which the derive macro implements for a type private to the expansion.
+pub trait EncodeWith {
+    type Value;
+
+    const FIXED_SIZE: Option<usize>;
+
+    fn serialize(value: &Self::Value, buffer: &mut Vec<u8>) -> Result<usize, SerializeError>;
+
+    fn deserialize(encoding: &[u8]) -> Result<Self::Value, DeserializeError>;
+}
+
+/// A field given to `#[ssz(with = "module")]`, which is encoded with the functions of `module`.
+///
+/// The derive macro borrows a field to encode it and decodes it with `W` directly, so that
+/// this can stand in for the field wherever a `Serializable` element of a container is needed.
+pub enum With<'a, W: EncodeWith> {
+    Borrowed(&'a W::Value),
+    Owned(W::Value),
+}
+
+impl<W: EncodeWith> With<'_, W> {
+    fn value(&self) -> &W::Value {
+        match self {
+            Self::Borrowed(value) => value,
+            Self::Owned(value) => value,
+        }
+    }
+}
+
+impl<W: EncodeWith> Serializable for With<'_, W> {
+    const FIXED_SIZE: Option<usize> = W::FIXED_SIZE;
+}
+
+impl<W: EncodeWith> Serialize for With<'_, W> {
+    fn serialize(&self, buffer: &mut Vec<u8>) -> Result<usize, SerializeError> {
+        W::serialize(self.value(), buffer)
+    }
+}
+
+impl<W: EncodeWith> Deserialize for With<'_, W> {
+    fn deserialize(encoding: &[u8]) -> Result<Self, DeserializeError> {
+        W::deserialize(encoding).map(Self::Owned)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -19,6 +129,7 @@ mod tests {
     }
 
     #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize)]
+    #[cfg_attr(feature = "arbitrary", ssz(arbitrary))]
     struct SomeContainer {
         a: u32,
         b: bool,
@@ -59,9 +170,11 @@ mod tests {
     }
 
     #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize)]
+    #[cfg_attr(feature = "arbitrary", ssz(arbitrary))]
     struct TupleStruct(u8);
 
     #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize)]
+    #[cfg_attr(feature = "arbitrary", ssz(arbitrary))]
     struct AnotherVarTestStruct {
         a: List<u16, 1024>,
         b: u16,
@@ -222,4 +335,366 @@ mod tests {
         let recovered = SerializableStruct::deserialize(&buffer).expect("can decode");
         assert_eq!(value, recovered);
     }
+
+    #[derive(Debug, PartialEq, Eq, SimpleSerialize)]
+    #[cfg_attr(feature = "arbitrary", ssz(arbitrary))]
+    enum Payload {
+        A(u16),
+        B(AnotherVarTestStruct),
+    }
+
+    #[derive(Debug, PartialEq, Eq, SimpleSerialize)]
+    #[cfg_attr(feature = "arbitrary", ssz(arbitrary))]
+    struct NestedContainer {
+        a: List<AnotherVarTestStruct, 8>,
+        b: Vector<Payload, 2>,
+        c: Option<SomeContainer>,
+        d: TupleStruct,
+    }
+
+    #[test]
+    fn encode_container_to_writer() {
+        let inner = |n: u16| AnotherVarTestStruct {
+            a: List::try_from((0..n).collect::<Vec<_>>()).unwrap(),
+            b: n,
+            c: n as u8,
+        };
+        let value = NestedContainer {
+            a: List::try_from(vec![inner(3), inner(0), inner(5)]).unwrap(),
+            b: Vector::try_from(vec![Payload::A(7), Payload::B(inner(2))]).unwrap(),
+            c: Some(SomeContainer { a: 1, b: true, c: List::try_from(vec![true]).unwrap() }),
+            d: TupleStruct(9),
+        };
+        let encoding = serialize(&value).unwrap();
+
+        let mut writer = std::io::Cursor::new(vec![]);
+        let bytes_written = value.serialize_to(&mut writer).unwrap();
+        assert_eq!(bytes_written, encoding.len());
+        assert_eq!(writer.into_inner(), encoding);
+
+        let mut writer = [0u8; 16];
+        let result = value.serialize_to(&mut writer.as_mut_slice());
+        assert!(matches!(result, Err(SerializeError::Io(..))));
+    }
+
+    #[test]
+    fn encode_container_into_buffer() {
+        let value = SomeContainer { a: 3, b: true, c: List::try_from(vec![true, false]).unwrap() };
+        let encoding = serialize(&value).unwrap();
+
+        let mut buffer = [0u8; 64];
+        let bytes_written = value.serialize_into(&mut buffer).unwrap();
+        assert_eq!(&buffer[..bytes_written], encoding);
+
+        let mut buffer = vec![0u8; encoding.len()];
+        assert_eq!(value.serialize_into(&mut buffer).unwrap(), encoding.len());
+        assert_eq!(buffer, encoding);
+
+        let result = value.serialize_into(&mut buffer[..encoding.len() - 1]);
+        assert!(matches!(
+            result,
+            Err(SerializeError::BufferTooSmall { required, provided })
+                if required == encoding.len() && provided == encoding.len() - 1
+        ));
+    }
+
+    #[test]
+    fn serialized_length_matches_encoding() {
+        const BASIC_SIZE: Option<usize> = fixed_size::<BasicContainer>();
+        assert_eq!(BASIC_SIZE, Some(5));
+        assert!(!is_fixed_size::<SomeContainer>());
+        assert_eq!(fixed_size::<Vector<BasicContainer, 3>>(), Some(15));
+        assert_eq!(fixed_size::<TupleStruct>(), Some(1));
+        assert_eq!(fixed_size::<Payload>(), None);
+
+        let inner = |n: u16| AnotherVarTestStruct {
+            a: List::try_from((0..n).collect::<Vec<_>>()).unwrap(),
+            b: n,
+            c: n as u8,
+        };
+        let values = [
+            NestedContainer {
+                a: List::try_from(vec![inner(3), inner(0), inner(5)]).unwrap(),
+                b: Vector::try_from(vec![Payload::A(7), Payload::B(inner(2))]).unwrap(),
+                c: Some(SomeContainer { a: 1, b: true, c: List::try_from(vec![true; 9]).unwrap() }),
+                d: TupleStruct(9),
+            },
+            NestedContainer {
+                a: Default::default(),
+                b: Vector::try_from(vec![Payload::A(7), Payload::A(8)]).unwrap(),
+                c: None,
+                d: TupleStruct(0),
+            },
+        ];
+        for value in values {
+            assert_eq!(value.serialized_length().unwrap(), serialize(&value).unwrap().len());
+        }
+    }
+
+    #[test]
+    fn decode_single_field() {
+        let value = VarTestStruct { a: 7, b: List::try_from(vec![1, 2, 3]).unwrap(), c: 9 };
+        let encoding = serialize(&value).unwrap();
+        assert_eq!(VarTestStruct::deserialize_field::<u16>(&encoding, 0).unwrap(), 7);
+        assert_eq!(
+            VarTestStruct::deserialize_field::<List<u16, 1024>>(&encoding, 1).unwrap(),
+            value.b
+        );
+        assert_eq!(VarTestStruct::deserialize_field::<u8>(&encoding, 2).unwrap(), 9);
+
+        let value = NestedContainer {
+            a: List::try_from(vec![AnotherVarTestStruct::default()]).unwrap(),
+            b: Vector::try_from(vec![Payload::A(7), Payload::A(8)]).unwrap(),
+            c: None,
+            d: TupleStruct(4),
+        };
+        let encoding = serialize(&value).unwrap();
+        assert_eq!(
+            NestedContainer::deserialize_field::<Vector<Payload, 2>>(&encoding, 1).unwrap(),
+            value.b
+        );
+        assert_eq!(
+            NestedContainer::deserialize_field::<Option<SomeContainer>>(&encoding, 2).unwrap(),
+            None
+        );
+        assert_eq!(
+            NestedContainer::deserialize_field::<TupleStruct>(&encoding, 3).unwrap(),
+            value.d
+        );
+
+        let result = NestedContainer::deserialize_field::<TupleStruct>(&encoding[..12], 3);
+        assert!(matches!(result, Err(DeserializeError::ExpectedFurtherInput { .. })));
+    }
+
+    #[derive(Debug, SimpleSerialize)]
+    struct Flags {
+        flag: bool,
+        flags: List<bool, 4>,
+    }
+
+    #[derive(Debug, SimpleSerialize)]
+    struct FlagSets {
+        count: u32,
+        sets: List<Flags, 4>,
+    }
+
+    #[test]
+    fn decode_error_location() {
+        let value = FlagSets {
+            count: 2,
+            sets: List::try_from(vec![
+                Flags { flag: true, flags: List::try_from(vec![true]).unwrap() },
+                Flags { flag: false, flags: List::try_from(vec![true, false]).unwrap() },
+            ])
+            .unwrap(),
+        };
+        let mut encoding = serialize(&value).unwrap();
+        // the second flag of the second set
+        encoding[28] = 2;
+
+        let err = FlagSets::deserialize(&encoding).unwrap_err();
+        assert!(matches!(err.cause(), DeserializeError::InvalidByte(2)));
+        let (parts, offset) = err.location();
+        assert_eq!(
+            parts,
+            [
+                &DecodingPart::Field { container: "FlagSets", name: "sets" },
+                &DecodingPart::Element(1),
+                &DecodingPart::Field { container: "Flags", name: "flags" },
+                &DecodingPart::Element(1),
+            ]
+        );
+        assert_eq!(offset, 28);
+        assert_eq!(
+            err.to_string(),
+            "FlagSets.sets[1].flags[1]: invalid byte 2 when decoding data of the expected type at \
+             byte 28"
+        );
+
+        let streamed = FlagSets::deserialize_from(&mut TrickleReader(&encoding)).unwrap_err();
+        assert_eq!(streamed.to_string(), err.to_string());
+    }
+
+    // Yields at most three bytes per read, to exercise reads that come up short.
+    struct TrickleReader<'a>(&'a [u8]);
+
+    impl std::io::Read for TrickleReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let count = buf.len().min(self.0.len()).min(3);
+            buf[..count].copy_from_slice(&self.0[..count]);
+            self.0 = &self.0[count..];
+            Ok(count)
+        }
+    }
+
+    #[test]
+    fn decode_container_from_reader() {
+        let inner = |n: u16| AnotherVarTestStruct {
+            a: List::try_from((0..n).collect::<Vec<_>>()).unwrap(),
+            b: n,
+            c: n as u8,
+        };
+        let value = NestedContainer {
+            a: List::try_from(vec![inner(3), inner(0), inner(5)]).unwrap(),
+            b: Vector::try_from(vec![Payload::B(inner(2)), Payload::A(7)]).unwrap(),
+            c: Some(SomeContainer { a: 1, b: true, c: List::try_from(vec![true]).unwrap() }),
+            d: TupleStruct(9),
+        };
+        let encoding = serialize(&value).unwrap();
+
+        let recovered = NestedContainer::deserialize_from(&mut TrickleReader(&encoding)).unwrap();
+        assert_eq!(recovered, value);
+        let recovered = NestedContainer::deserialize_from_source(
+            &mut encoding.as_slice(),
+            Some(encoding.len()),
+        )
+        .unwrap();
+        assert_eq!(recovered, value);
+
+        let truncated = &encoding[..encoding.len() / 2];
+        let err = NestedContainer::deserialize_from(&mut TrickleReader(truncated)).unwrap_err();
+        assert!(matches!(err.cause(), DeserializeError::ExpectedFurtherInput { .. }));
+        let result = BasicContainer::deserialize_from(&mut TrickleReader(&[1, 0, 0, 0, 1, 0]));
+        assert!(matches!(result, Err(DeserializeError::AdditionalInput { .. })));
+        let err = Bar::deserialize_from(&mut TrickleReader(&[4, 0, 0, 0, 1, 0, 0])).unwrap_err();
+        assert!(matches!(err.cause(), DeserializeError::ExpectedFurtherInput { .. }));
+    }
+
+    #[test]
+    fn decode_container_with_limit() {
+        let value = VarTestStruct { a: 1, b: List::try_from(vec![2; 100]).unwrap(), c: 3 };
+        let encoding = serialize(&value).unwrap();
+        let len = encoding.len();
+        assert_eq!(VarTestStruct::deserialize_with_limit(&encoding, len).unwrap(), value);
+        let err = VarTestStruct::deserialize_with_limit(&encoding, len - 1).unwrap_err();
+        assert!(
+            matches!(err, DeserializeError::LimitExceeded { limit, required } if limit == len - 1 && required == len)
+        );
+
+        let recovered =
+            VarTestStruct::deserialize_from_with_limit(&mut TrickleReader(&encoding), len).unwrap();
+        assert_eq!(recovered, value);
+        let err = VarTestStruct::deserialize_from_with_limit(&mut TrickleReader(&encoding), 64)
+            .unwrap_err();
+        assert!(matches!(err.cause(), DeserializeError::LimitExceeded { .. }));
+
+        #[derive(Debug, SimpleSerialize)]
+        struct Blobs {
+            a: List<u8, 1_000_000>,
+            b: List<u8, 1_000_000>,
+        }
+        // the second offset claims the first list is nearly a megabyte long
+        let mut encoding = vec![8, 0, 0, 0];
+        encoding.extend_from_slice(&999_992u32.to_le_bytes());
+        encoding.extend_from_slice(&[0; 16]);
+        let err = Blobs::deserialize_from_with_limit(&mut encoding.as_slice(), 1024).unwrap_err();
+        assert!(matches!(err, DeserializeError::LimitExceeded { limit: 1016, required: 999_984 }));
+    }
+
+    #[test]
+    fn classify_malformed_containers() {
+        let value = VarTestStruct { a: 1, b: List::try_from(vec![2, 3]).unwrap(), c: 4 };
+        let encoding = serialize(&value).unwrap();
+        assert!(VarTestStruct::validate(&encoding).is_ok());
+
+        let check = |encoding: &[u8], expected: fn(&DeserializeError) -> bool| {
+            let err = VarTestStruct::deserialize(encoding).unwrap_err();
+            assert!(expected(err.cause()), "{err}");
+            let err = VarTestStruct::validate(encoding).unwrap_err();
+            assert!(expected(err.cause()), "{err}");
+        };
+        let mut malformed = encoding.clone();
+        malformed[2] = 6;
+        check(&malformed, |err| {
+            matches!(err, DeserializeError::OffsetIntoFixedPortion { offset: 6, fixed_length: 7 })
+        });
+        malformed[2] = 8;
+        check(&malformed, |err| {
+            matches!(err, DeserializeError::OffsetPastFixedPortion { offset: 8, fixed_length: 7 })
+        });
+        malformed[2] = 7;
+        malformed.push(0);
+        check(&malformed, |err| matches!(err, DeserializeError::AdditionalInput { .. }));
+
+        #[derive(Debug, SimpleSerialize)]
+        struct Pair {
+            a: List<u8, 8>,
+            b: List<u8, 8>,
+        }
+        let err = Pair::validate(&[8, 0, 0, 0, 7, 0, 0, 0]).unwrap_err();
+        assert!(matches!(err, DeserializeError::OffsetNotIncreasing { start: 8, end: 7 }));
+
+        // the first of two elements is empty, though a `VarTestStruct` is at least 7 bytes long
+        let mut malformed = vec![8, 0, 0, 0, 8, 0, 0, 0];
+        malformed.extend_from_slice(&encoding);
+        let err = List::<VarTestStruct, 2>::deserialize(&malformed).unwrap_err();
+        assert!(matches!(err.cause(), DeserializeError::EmptyVariablePart));
+        let err = List::<VarTestStruct, 2>::validate(&malformed).unwrap_err();
+        assert!(matches!(err, DeserializeError::EmptyVariablePart));
+    }
+
+    #[test]
+    fn validate_agrees_with_deserialize() {
+        let inner = |n: u16| AnotherVarTestStruct {
+            a: List::try_from((0..n).collect::<Vec<_>>()).unwrap(),
+            b: n,
+            c: n as u8,
+        };
+        let value = NestedContainer {
+            a: List::try_from(vec![inner(2), inner(0)]).unwrap(),
+            b: Vector::try_from(vec![Payload::B(inner(1)), Payload::A(7)]).unwrap(),
+            c: Some(SomeContainer { a: 1, b: true, c: List::try_from(vec![true]).unwrap() }),
+            d: TupleStruct(9),
+        };
+        let encoding = serialize(&value).unwrap();
+
+        // every error is of the same kind, for a few replacements of each byte and each length
+        let outcome = |result: Result<(), DeserializeError>| {
+            result.map_err(|err| core::mem::discriminant(err.cause()))
+        };
+        let mut mutations = vec![];
+        for i in 0..encoding.len() {
+            for byte in [0, 1, 2, 0x80, 0xff] {
+                let mut mutated = encoding.clone();
+                mutated[i] = byte;
+                mutations.push(mutated);
+            }
+            mutations.push(encoding[..i].to_vec());
+        }
+        for mutated in mutations {
+            let decoded = NestedContainer::deserialize(&mutated).map(|_| ());
+            assert_eq!(outcome(NestedContainer::validate(&mutated)), outcome(decoded));
+        }
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_roundtrip() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        #[derive(Debug, PartialEq, Eq, SimpleSerialize)]
+        #[ssz(arbitrary)]
+        struct Everything {
+            list: List<u16, 8>,
+            roots: Vector<Node, 2>,
+            bits: Bitlist<12>,
+            flags: Bitvector<10>,
+            balance: U256,
+            maybe: Optional<u8>,
+            nested: NestedContainer,
+        }
+
+        let noise =
+            (0..4096u32).map(|i| (i.wrapping_mul(2654435761) >> 13) as u8).collect::<Vec<_>>();
+        let mut lengths = vec![];
+        for start in (0..2048).step_by(97) {
+            let value = Everything::arbitrary(&mut Unstructured::new(&noise[start..])).unwrap();
+            lengths.push(value.list.len());
+            let encoding = serialize(&value).unwrap();
+            let recovered = Everything::deserialize(&encoding).unwrap();
+            assert_eq!(recovered, value);
+            assert_eq!(recovered.hash_tree_root().unwrap(), value.hash_tree_root().unwrap());
+        }
+        assert!(lengths.iter().any(|&len| len > 0));
+    }
 }
@@ -24,6 +24,71 @@ pub enum DeserializeError {
     OffsetNotIncreasing { start: usize, end: usize },
     /// An offset was absent when expected.
     MissingOffset,
+    /// The first offset of a container points into its fixed-size part, which is
+    /// `fixed_length` bytes long.
+    OffsetIntoFixedPortion { offset: usize, fixed_length: usize },
+    /// The first offset of a container leaves a gap after its fixed-size part, which is
+    /// `fixed_length` bytes long.
+    OffsetPastFixedPortion { offset: usize, fixed_length: usize },
+    /// A variable-size part of a composite value is empty, but its type has no empty encoding.
+    EmptyVariablePart,
+    /// The encoding is, or declares a part making it, at least `required` bytes long, beyond the
+    /// `limit` given to [`Deserialize::deserialize_with_limit`].
+    LimitExceeded { limit: usize, required: usize },
+    /// The encoding could not be read.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+    /// The part of a composite value at `part` could not be deserialized, where the encoding of
+    /// the part starts `offset` bytes into that of the value.
+    InPart { part: DecodingPart, offset: usize, error: Box<DeserializeError> },
+}
+
+/// A part of a composite value, locating where a [`DeserializeError`] was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodingPart {
+    /// The field `name` of a value of the container type `container`.
+    Field { container: &'static str, name: &'static str },
+    /// The element at this index of a homogeneous composite type.
+    Element(usize),
+}
+
+impl DeserializeError {
+    /// Locate this error in `part` of a composite value, whose encoding starts `offset` bytes into
+    /// that of the value.
+    pub fn in_part(self, part: DecodingPart, offset: usize) -> Self {
+        Self::InPart { part, offset, error: Box::new(self) }
+    }
+
+    /// Returns the error found in the innermost part of the value, without its location.
+    pub fn cause(&self) -> &DeserializeError {
+        match self {
+            Self::InPart { error, .. } => error.cause(),
+            err => err,
+        }
+    }
+
+    /// Returns the parts of the value leading to where the error was found, outermost first,
+    /// along with the offset into the encoding of the value where the innermost part starts.
+    pub fn location(&self) -> (Vec<&DecodingPart>, usize) {
+        let mut parts = vec![];
+        let mut start = 0;
+        let mut err = self;
+        while let Self::InPart { part, offset, error } = err {
+            parts.push(part);
+            start += offset;
+            err = error;
+        }
+        (parts, start)
+    }
+}
+
+impl Display for DecodingPart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodingPart::Field { name, .. } => write!(f, ".{name}"),
+            DecodingPart::Element(index) => write!(f, "[{index}]"),
+        }
+    }
 }
 
 impl From<InstanceError> for DeserializeError {
@@ -52,6 +117,23 @@ impl Display for DeserializeError {
             DeserializeError::InvalidOffsetsLength(len) => write!(f, "the offsets length provided {len} is not a multiple of the size per length offset {BYTES_PER_LENGTH_OFFSET} bytes"),
             DeserializeError::OffsetNotIncreasing { start, end } => write!(f, "invalid offset points to byte {end} before byte {start}"),
             DeserializeError::MissingOffset => write!(f, "an offset was missing when deserializing a variable-sized type"),
+            DeserializeError::OffsetIntoFixedPortion { offset, fixed_length } => write!(f, "the first offset points to byte {offset} within the fixed-size part of {fixed_length} byte(s)"),
+            DeserializeError::OffsetPastFixedPortion { offset, fixed_length } => write!(f, "the first offset points to byte {offset} past the end of the fixed-size part of {fixed_length} byte(s)"),
+            DeserializeError::EmptyVariablePart => write!(f, "a variable-size part is empty, but its type has no empty encoding"),
+            DeserializeError::LimitExceeded { limit, required } => write!(f, "the encoding requires at least {required} byte(s), beyond the limit of {limit} byte(s)"),
+            #[cfg(feature = "std")]
+            DeserializeError::Io(err) => write!(f, "could not read encoding: {err}"),
+            DeserializeError::InPart { .. } => {
+                // e.g. `BeaconState.validators[1021].pubkey: ... at byte 18443201`
+                let (parts, offset) = self.location();
+                if let Some(DecodingPart::Field { container, .. }) = parts.first() {
+                    write!(f, "{container}")?;
+                }
+                for part in parts {
+                    write!(f, "{part}")?;
+                }
+                write!(f, ": {} at byte {offset}", self.cause())
+            }
         }
     }
 }
@@ -65,6 +147,238 @@ pub trait Deserialize {
     fn deserialize(encoding: &[u8]) -> Result<Self, DeserializeError>
     where
         Self: Sized;
+
+    /// Deserialize this value from the next `len` bytes of `source` or, if `len` is `None`, from
+    /// all that remains of it.
+    ///
+    /// By default, the encoding is read into a buffer that is then decoded with
+    /// [`Deserialize::deserialize`]; composite types instead read and decode each of their parts
+    /// in turn, so that nothing close to the size of the whole encoding is buffered.
+    fn deserialize_from_source(
+        source: &mut dyn DecodingSource,
+        len: Option<usize>,
+    ) -> Result<Self, DeserializeError>
+    where
+        Self: Sized,
+    {
+        let mut buffer = Vec::new();
+        read_part(source, len, &mut buffer)?;
+        Self::deserialize(&buffer)
+    }
+
+    /// Deserialize this value from all that remains of `reader`, e.g. a file, as it is read.
+    ///
+    /// Consider wrapping `reader` in a [`BufReader`](std::io::BufReader), as the encoding is read
+    /// in many small pieces.
+    #[cfg(feature = "std")]
+    fn deserialize_from<R: std::io::Read>(reader: &mut R) -> Result<Self, DeserializeError>
+    where
+        Self: Sized,
+    {
+        Self::deserialize_from_source(&mut IoSource(reader), None)
+    }
+
+    /// Check `encoding` is that of a value of this type, without decoding it.
+    ///
+    /// This fails exactly when [`Deserialize::deserialize`] does, with the same error, although
+    /// it may not be located in a part of the value (see [`DeserializeError::cause`]). The types
+    /// of this crate, along with derived containers and unions, are checked in place without
+    /// allocating, e.g. to cheaply classify malformed input; by default, the value is decoded
+    /// and discarded.
+    fn validate(encoding: &[u8]) -> Result<(), DeserializeError>
+    where
+        Self: Sized,
+    {
+        Self::deserialize(encoding).map(|_| ())
+    }
+
+    /// Deserialize this value from `encoding`, failing without decoding anything if it is longer
+    /// than `max_len` bytes, e.g. to bound the memory spent on untrusted input.
+    fn deserialize_with_limit(encoding: &[u8], max_len: usize) -> Result<Self, DeserializeError>
+    where
+        Self: Sized,
+    {
+        if encoding.len() > max_len {
+            return Err(DeserializeError::LimitExceeded { limit: max_len, required: encoding.len() })
+        }
+        Self::deserialize(encoding)
+    }
+
+    /// Deserialize this value from `reader` as [`Deserialize::deserialize_from`] does, reading at
+    /// most `max_len` bytes of it.
+    ///
+    /// Where an offset declares a part of the encoding reaching past `max_len` bytes, decoding
+    /// fails before any buffer is allocated for the part.
+    #[cfg(feature = "std")]
+    fn deserialize_from_with_limit<R: std::io::Read>(
+        reader: &mut R,
+        max_len: usize,
+    ) -> Result<Self, DeserializeError>
+    where
+        Self: Sized,
+    {
+        Self::deserialize_from_source(&mut LimitedSource::new(&mut IoSource(reader), max_len), None)
+    }
+}
+
+/// A source of an SSZ encoding, read in order by [`Deserialize::deserialize_from_source`].
+pub trait DecodingSource {
+    /// Read into `buffer`, returning the number of bytes read, or 0 once the source is exhausted.
+    fn read(&mut self, buffer: &mut [u8]) -> Result<usize, DeserializeError>;
+
+    /// The number of bytes that may still be read, if reading is limited.
+    fn limit(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl DecodingSource for &[u8] {
+    fn read(&mut self, buffer: &mut [u8]) -> Result<usize, DeserializeError> {
+        let count = buffer.len().min(self.len());
+        let (read, rest) = self.split_at(count);
+        buffer[..count].copy_from_slice(read);
+        *self = rest;
+        Ok(count)
+    }
+}
+
+#[cfg(feature = "std")]
+struct IoSource<'a, R>(&'a mut R);
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> DecodingSource for IoSource<'_, R> {
+    fn read(&mut self, buffer: &mut [u8]) -> Result<usize, DeserializeError> {
+        loop {
+            match self.0.read(buffer) {
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                result => return result.map_err(DeserializeError::Io),
+            }
+        }
+    }
+}
+
+/// A [`DecodingSource`] reading at most a given number of bytes of another, which fails with
+/// [`DeserializeError::LimitExceeded`] rather than read any further.
+pub struct LimitedSource<'a> {
+    inner: &'a mut dyn DecodingSource,
+    limit: usize,
+    read: usize,
+}
+
+impl<'a> LimitedSource<'a> {
+    pub fn new(inner: &'a mut dyn DecodingSource, limit: usize) -> Self {
+        Self { inner, limit, read: 0 }
+    }
+}
+
+impl DecodingSource for LimitedSource<'_> {
+    fn read(&mut self, buffer: &mut [u8]) -> Result<usize, DeserializeError> {
+        let remaining = self.limit - self.read;
+        if remaining == 0 && !buffer.is_empty() {
+            // the limit is only exceeded if there is more to read
+            let mut probe = [0u8; 1];
+            if self.inner.read(&mut probe)? != 0 {
+                return Err(DeserializeError::LimitExceeded {
+                    limit: self.limit,
+                    required: self.limit + 1,
+                })
+            }
+            return Ok(0)
+        }
+        let request = buffer.len().min(remaining);
+        let count = self.inner.read(&mut buffer[..request])?;
+        self.read += count;
+        Ok(count)
+    }
+
+    fn limit(&self) -> Option<usize> {
+        let remaining = self.limit - self.read;
+        Some(self.inner.limit().map_or(remaining, |limit| limit.min(remaining)))
+    }
+}
+
+// Check the next `len` bytes of `source` can be read within its limit, if any.
+fn check_limit(source: &dyn DecodingSource, len: usize) -> Result<(), DeserializeError> {
+    match source.limit() {
+        Some(limit) if len > limit => Err(DeserializeError::LimitExceeded { limit, required: len }),
+        _ => Ok(()),
+    }
+}
+
+// Parts of an encoding are read at most this many bytes at a time, so that memory is only
+// allocated for input that has arrived rather than for whatever length the input claims.
+const READ_CHUNK_SIZE: usize = 1 << 16;
+
+// Append the next `len` bytes of `source` or, if `len` is `None`, all that remains to `buffer`.
+pub(crate) fn read_part(
+    source: &mut dyn DecodingSource,
+    len: Option<usize>,
+    buffer: &mut Vec<u8>,
+) -> Result<(), DeserializeError> {
+    if let Some(len) = len {
+        check_limit(source, len)?;
+    }
+    let start = buffer.len();
+    loop {
+        let read_so_far = buffer.len() - start;
+        let request = match len {
+            Some(len) => (len - read_so_far).min(READ_CHUNK_SIZE),
+            None => READ_CHUNK_SIZE,
+        };
+        if request == 0 {
+            return Ok(())
+        }
+        let end = buffer.len();
+        buffer.resize(end + request, 0);
+        let count = source.read(&mut buffer[end..])?;
+        buffer.truncate(end + count);
+        if count == 0 {
+            return match len {
+                Some(len) => Err(DeserializeError::ExpectedFurtherInput {
+                    provided: read_so_far,
+                    expected: len,
+                }),
+                None => Ok(()),
+            }
+        }
+    }
+}
+
+// Fill `buffer` from `source`, returning the number of bytes read, which is less than the length
+// of `buffer` only if `source` was exhausted.
+fn read_up_to(
+    source: &mut dyn DecodingSource,
+    buffer: &mut [u8],
+) -> Result<usize, DeserializeError> {
+    let mut read_so_far = 0;
+    while read_so_far < buffer.len() {
+        let count = source.read(&mut buffer[read_so_far..])?;
+        if count == 0 {
+            break
+        }
+        read_so_far += count;
+    }
+    Ok(read_so_far)
+}
+
+fn read_exact(source: &mut dyn DecodingSource, buffer: &mut [u8]) -> Result<(), DeserializeError> {
+    let count = read_up_to(source, buffer)?;
+    if count < buffer.len() {
+        return Err(DeserializeError::ExpectedFurtherInput {
+            provided: count,
+            expected: buffer.len(),
+        })
+    }
+    Ok(())
+}
+
+// Check `source` is exhausted after `consumed` bytes of it were read.
+fn expect_end(source: &mut dyn DecodingSource, consumed: usize) -> Result<(), DeserializeError> {
+    let mut probe = [0u8; 1];
+    if read_up_to(source, &mut probe)? != 0 {
+        return Err(DeserializeError::AdditionalInput { provided: consumed + 1, expected: consumed })
+    }
+    Ok(())
 }
 
 fn deserialize_fixed_homogeneous_composite<T>(encoding: &[u8]) -> Result<Vec<T>, DeserializeError>
@@ -75,8 +389,9 @@ where
     debug_assert_eq!(encoding.len() % T::size_hint(), 0);
 
     let mut elements = vec![];
-    for chunk in encoding.chunks_exact(T::size_hint()) {
-        let element = T::deserialize(chunk)?;
+    for (i, chunk) in encoding.chunks_exact(T::size_hint()).enumerate() {
+        let element = T::deserialize(chunk)
+            .map_err(|err| err.in_part(DecodingPart::Element(i), i * T::size_hint()))?;
         elements.push(element);
     }
     Ok(elements)
@@ -106,7 +421,7 @@ where
             expected: offsets_len,
         })
     }
-    if offsets_len % BYTES_PER_LENGTH_OFFSET != 0 {
+    if offsets_len == 0 || !offsets_len.is_multiple_of(BYTES_PER_LENGTH_OFFSET) {
         return Err(DeserializeError::InvalidOffsetsLength(offsets_len))
     }
 
@@ -118,16 +433,24 @@ where
 
     let element_count = offsets_len / BYTES_PER_LENGTH_OFFSET;
     let mut result = Vec::with_capacity(element_count);
-    for span in offsets.windows(2) {
+    for (i, span) in offsets.windows(2).enumerate() {
         // SAFETY: index is safe because span is a pair; qed
         let start = span[0];
         let end = span[1];
         if start > end {
             return Err(DeserializeError::OffsetNotIncreasing { start, end })
         }
+        if end > encoding.len() {
+            return Err(DeserializeError::ExpectedFurtherInput {
+                provided: encoding.len(),
+                expected: end,
+            })
+        }
 
-        // SAFETY: index is safe because start <= end; qed
-        let element = T::deserialize(&encoding[start..end])?;
+        // SAFETY: index is safe because start <= end <= encoding.len(); qed
+        let element = T::deserialize(&encoding[start..end]).map_err(|err| {
+            variable_part_error(err, end - start).in_part(DecodingPart::Element(i), start)
+        })?;
         result.push(element);
     }
     Ok(result)
@@ -144,6 +467,252 @@ where
     }
 }
 
+// Check the encoding of the elements of a homogeneous composite type as
+// `deserialize_homogeneous_composite` would decode them, returning the number of elements.
+pub(crate) fn validate_homogeneous_composite<T>(encoding: &[u8]) -> Result<usize, DeserializeError>
+where
+    T: Serializable,
+{
+    if !T::is_variable_size() {
+        // NOTE: Callers have already validated `encoding` is correctly sized
+        debug_assert_eq!(encoding.len() % T::size_hint(), 0);
+        for chunk in encoding.chunks_exact(T::size_hint()) {
+            T::validate(chunk)?;
+        }
+        return Ok(encoding.len() / T::size_hint())
+    }
+
+    if encoding.is_empty() {
+        return Ok(0)
+    }
+    let offsets_len = read_offset(encoding, 0)?;
+    if encoding.len() < offsets_len {
+        return Err(DeserializeError::ExpectedFurtherInput {
+            provided: encoding.len(),
+            expected: offsets_len,
+        })
+    }
+    if offsets_len == 0 || !offsets_len.is_multiple_of(BYTES_PER_LENGTH_OFFSET) {
+        return Err(DeserializeError::InvalidOffsetsLength(offsets_len))
+    }
+
+    let element_count = offsets_len / BYTES_PER_LENGTH_OFFSET;
+    let mut start = offsets_len;
+    for i in 0..element_count {
+        let end = if i + 1 < element_count {
+            read_offset(encoding, (i + 1) * BYTES_PER_LENGTH_OFFSET)?
+        } else {
+            encoding.len()
+        };
+        if start > end {
+            return Err(DeserializeError::OffsetNotIncreasing { start, end })
+        }
+        if end > encoding.len() {
+            return Err(DeserializeError::ExpectedFurtherInput {
+                provided: encoding.len(),
+                expected: end,
+            })
+        }
+        T::validate(&encoding[start..end]).map_err(|err| variable_part_error(err, end - start))?;
+        start = end;
+    }
+    Ok(element_count)
+}
+
+// Read the offset at `position` in `encoding`.
+pub(crate) fn read_offset(encoding: &[u8], position: usize) -> Result<usize, DeserializeError> {
+    let end = position + BYTES_PER_LENGTH_OFFSET;
+    let offset = encoding.get(position..end).ok_or(DeserializeError::ExpectedFurtherInput {
+        provided: encoding.len(),
+        expected: end,
+    })?;
+    Ok(u32::deserialize(offset)? as usize)
+}
+
+// Returns the error to report for a failure to decode a variable-size part `len` bytes long; as
+// nothing is decoded from an empty part, it can only fail because its type has no empty encoding.
+pub fn variable_part_error(err: DeserializeError, len: usize) -> DeserializeError {
+    if len == 0 {
+        DeserializeError::EmptyVariablePart
+    } else {
+        err
+    }
+}
+
+// Read the selector of a union from `source`, returning it along with the length of the value
+// that follows, if the length of the whole encoding is known.
+pub fn read_selector(
+    source: &mut dyn DecodingSource,
+    len: Option<usize>,
+) -> Result<(u8, Option<usize>), DeserializeError> {
+    if len == Some(0) {
+        return Err(DeserializeError::ExpectedFurtherInput { provided: 0, expected: 1 })
+    }
+    let mut selector = [0u8; 1];
+    read_exact(source, &mut selector)?;
+    Ok((selector[0], len.map(|len| len - 1)))
+}
+
+// Check nothing remains of an encoding after `consumed` bytes of it were read, where `remaining`
+// is the length left, if known.
+pub fn expect_empty(
+    source: &mut dyn DecodingSource,
+    consumed: usize,
+    remaining: Option<usize>,
+) -> Result<(), DeserializeError> {
+    match remaining {
+        Some(0) => Ok(()),
+        Some(remaining) => Err(DeserializeError::AdditionalInput {
+            provided: consumed + remaining,
+            expected: consumed,
+        }),
+        None => expect_end(source, consumed),
+    }
+}
+
+// Read the elements of a homogeneous composite type from the next `len` bytes of `source` or, if
+// `len` is `None`, from all that remains, failing if there are more than `max_count` elements.
+pub(crate) fn deserialize_homogeneous_composite_from_source<T>(
+    source: &mut dyn DecodingSource,
+    len: Option<usize>,
+    max_count: usize,
+) -> Result<Vec<T>, DeserializeError>
+where
+    T: Serializable,
+{
+    let too_many = |provided| InstanceError::Bounded { bound: max_count, provided }.into();
+    let mut elements = vec![];
+    if let Some(len) = len {
+        check_limit(source, len)?;
+    }
+
+    if !T::is_variable_size() {
+        let element_size = T::size_hint();
+        if let Some(len) = len {
+            let remainder = len % element_size;
+            if remainder != 0 {
+                return Err(DeserializeError::AdditionalInput {
+                    provided: len,
+                    expected: len - remainder,
+                })
+            }
+            if len / element_size > max_count {
+                return Err(too_many(len / element_size))
+            }
+        }
+        // read as many whole elements at a time as fit in a chunk
+        let elements_per_read = (READ_CHUNK_SIZE / element_size).max(1);
+        let mut buffer = vec![0u8; elements_per_read * element_size];
+        let mut remaining = len;
+        loop {
+            let request = remaining.map_or(buffer.len(), |remaining| remaining.min(buffer.len()));
+            if request == 0 {
+                break
+            }
+            let count = read_up_to(source, &mut buffer[..request])?;
+            if count < request && (remaining.is_some() || count % element_size != 0) {
+                let provided = elements.len() * element_size + count;
+                let expected = len.unwrap_or(provided - provided % element_size + element_size);
+                return Err(DeserializeError::ExpectedFurtherInput { provided, expected })
+            }
+            for chunk in buffer[..count].chunks_exact(element_size) {
+                let (index, offset) = (elements.len(), elements.len() * element_size);
+                elements.push(
+                    T::deserialize(chunk)
+                        .map_err(|err| err.in_part(DecodingPart::Element(index), offset))?,
+                );
+            }
+            if elements.len() > max_count {
+                return Err(too_many(elements.len()))
+            }
+            if count < request {
+                break
+            }
+            remaining = remaining.map(|remaining| remaining - count);
+        }
+        return Ok(elements)
+    }
+
+    if len == Some(0) {
+        return Ok(elements)
+    }
+    let mut first_offset = [0u8; BYTES_PER_LENGTH_OFFSET];
+    let count = read_up_to(source, &mut first_offset)?;
+    if count == 0 && len.is_none() {
+        return Ok(elements)
+    }
+    if count < BYTES_PER_LENGTH_OFFSET {
+        return Err(DeserializeError::ExpectedFurtherInput {
+            provided: count,
+            expected: BYTES_PER_LENGTH_OFFSET,
+        })
+    }
+    let offsets_len = u32::from_le_bytes(first_offset) as usize;
+    if offsets_len == 0 || !offsets_len.is_multiple_of(BYTES_PER_LENGTH_OFFSET) {
+        return Err(DeserializeError::InvalidOffsetsLength(offsets_len))
+    }
+    if let Some(len) = len {
+        if len < offsets_len {
+            return Err(DeserializeError::ExpectedFurtherInput {
+                provided: len,
+                expected: offsets_len,
+            })
+        }
+    }
+    let element_count = offsets_len / BYTES_PER_LENGTH_OFFSET;
+    if element_count > max_count {
+        return Err(too_many(element_count))
+    }
+
+    let mut offsets = first_offset.to_vec();
+    read_part(source, Some(offsets_len - BYTES_PER_LENGTH_OFFSET), &mut offsets)?;
+    let offsets = offsets
+        .chunks_exact(BYTES_PER_LENGTH_OFFSET)
+        .map(|chunk| u32::deserialize(chunk).map(|offset| offset as usize))
+        .collect::<Result<Vec<usize>, DeserializeError>>()?;
+    for (i, &start) in offsets.iter().enumerate() {
+        let end = match offsets.get(i + 1) {
+            Some(&end) => Some(end),
+            None => len,
+        };
+        let element_len = match end {
+            Some(end) if end < start => {
+                return Err(DeserializeError::OffsetNotIncreasing { start, end })
+            }
+            Some(end) => Some(end - start),
+            None => None,
+        };
+        let element = T::deserialize_from_source(source, element_len).map_err(|err| {
+            let err = match element_len {
+                Some(len) => variable_part_error(err, len),
+                None => err,
+            };
+            err.in_part(DecodingPart::Element(i), start)
+        })?;
+        elements.push(element);
+    }
+    Ok(elements)
+}
+
+// Check the variable-size parts of a container, whose fixed-size part is `fixed_length` bytes long
+// and whose encoding is `len` bytes long, start right after the fixed-size part.
+fn check_first_offset(
+    offset: usize,
+    fixed_length: usize,
+    len: usize,
+) -> Result<(), DeserializeError> {
+    if offset > len {
+        return Err(DeserializeError::ExpectedFurtherInput { provided: len, expected: offset })
+    }
+    if offset < fixed_length {
+        return Err(DeserializeError::OffsetIntoFixedPortion { offset, fixed_length })
+    }
+    if offset > fixed_length {
+        return Err(DeserializeError::OffsetPastFixedPortion { offset, fixed_length })
+    }
+    Ok(())
+}
+
 #[derive(Debug)]
 enum Segment {
     Fixed(usize, usize),
@@ -188,15 +757,15 @@ impl ContainerDeserializer {
 
                 if *previous_offset > encoding.len() {
                     return Err(DeserializeError::ExpectedFurtherInput {
-                        provided: encoding.len() - previous_offset,
-                        expected: next_offset - previous_offset,
+                        provided: encoding.len(),
+                        expected: *previous_offset,
                     })
                 }
 
                 if next_offset > encoding.len() {
                     return Err(DeserializeError::ExpectedFurtherInput {
-                        provided: encoding.len() - next_offset,
-                        expected: next_offset - previous_offset,
+                        provided: encoding.len(),
+                        expected: next_offset,
                     })
                 }
             }
@@ -231,6 +800,9 @@ impl ContainerDeserializer {
     // For example, if some container has three fields, the result will have 6 indices into
     // `encoding` for the (start, end) of the encoding of each field.
     pub fn finalize(mut self, encoding: &[u8]) -> Result<Vec<usize>, DeserializeError> {
+        if let Some(&first_offset) = self.offsets.first() {
+            check_first_offset(first_offset, self.total_bytes_read, encoding.len())?;
+        }
         self.offsets.push(encoding.len());
 
         let mut spans = vec![];
@@ -282,3 +854,262 @@ impl ContainerDeserializer {
         Ok(spans)
     }
 }
+
+#[derive(Debug)]
+enum StreamedSegment {
+    // a fixed-size part along with where it starts
+    Fixed(usize, Vec<u8>),
+    // the length of a variable-size part, or `None` if it is the rest of the input
+    Variable(Option<usize>),
+}
+
+// `StreamingDeserializer` reads a possibly variable heterogenous composite type from a source,
+// part by part.
+// Intended use:
+// - call `parse` for each field of the container in order, reading its fixed-size part or offset
+// - call `finalize` to find the length of each variable-size part from the offsets
+// - call `next` for each field of the container in order, to decode it
+// NOTE: mainly intended for private use in the proc derive macro.
+#[derive(Debug)]
+pub struct StreamingDeserializer {
+    len: Option<usize>,
+    segments: Vec<StreamedSegment>,
+    offsets: Vec<usize>,
+    fixed_length: usize,
+    next_segment: usize,
+}
+
+impl StreamingDeserializer {
+    pub fn new(len: Option<usize>) -> Self {
+        Self { len, segments: vec![], offsets: vec![], fixed_length: 0, next_segment: 0 }
+    }
+
+    pub fn parse<T: Serializable>(
+        &mut self,
+        source: &mut dyn DecodingSource,
+    ) -> Result<(), DeserializeError> {
+        let part_length =
+            if T::is_variable_size() { BYTES_PER_LENGTH_OFFSET } else { T::size_hint() };
+        if let (Some(len), true) = (self.len, self.segments.is_empty()) {
+            check_limit(source, len)?;
+        }
+        if let Some(len) = self.len {
+            if self.fixed_length + part_length > len {
+                return Err(DeserializeError::ExpectedFurtherInput {
+                    provided: len,
+                    expected: self.fixed_length + part_length,
+                })
+            }
+        }
+        let mut part = vec![0u8; part_length];
+        read_exact(source, &mut part)?;
+        self.fixed_length += part_length;
+        if T::is_variable_size() {
+            self.offsets.push(u32::deserialize(&part)? as usize);
+            self.segments.push(StreamedSegment::Variable(None));
+        } else {
+            self.segments.push(StreamedSegment::Fixed(self.fixed_length - part_length, part));
+        }
+        Ok(())
+    }
+
+    pub fn finalize(&mut self, source: &mut dyn DecodingSource) -> Result<(), DeserializeError> {
+        let Some(&first_offset) = self.offsets.first() else {
+            let remaining = self.len.map(|len| len - self.fixed_length);
+            return expect_empty(source, self.fixed_length, remaining)
+        };
+        check_first_offset(first_offset, self.fixed_length, self.len.unwrap_or(usize::MAX))?;
+
+        // SAFETY: `offsets` is not empty; qed
+        let last_offset = self.offsets[self.offsets.len() - 1];
+        if last_offset > first_offset {
+            check_limit(source, last_offset - first_offset)?;
+        }
+
+        let mut lengths = vec![];
+        for (i, &start) in self.offsets.iter().enumerate() {
+            let end = match self.offsets.get(i + 1) {
+                Some(&end) => end,
+                None => match self.len {
+                    Some(len) if len < start => {
+                        return Err(DeserializeError::ExpectedFurtherInput {
+                            provided: len,
+                            expected: start,
+                        })
+                    }
+                    Some(len) => len,
+                    None => {
+                        lengths.push(None);
+                        break
+                    }
+                },
+            };
+            if end < start {
+                return Err(DeserializeError::OffsetNotIncreasing { start, end })
+            }
+            lengths.push(Some(end - start));
+        }
+
+        let variable_segments = self
+            .segments
+            .iter_mut()
+            .filter(|segment| matches!(segment, StreamedSegment::Variable(..)));
+        for (segment, length) in variable_segments.zip(lengths) {
+            *segment = StreamedSegment::Variable(length);
+        }
+        Ok(())
+    }
+
+    // NOTE: any error is located in `part` of the container.
+    pub fn next<T: Serializable>(
+        &mut self,
+        source: &mut dyn DecodingSource,
+        part: DecodingPart,
+    ) -> Result<T, DeserializeError> {
+        let segment =
+            self.segments.get(self.next_segment).ok_or(DeserializeError::MissingOffset)?;
+        self.next_segment += 1;
+        match segment {
+            StreamedSegment::Fixed(start, part_encoding) => {
+                T::deserialize(part_encoding).map_err(|err| err.in_part(part, *start))
+            }
+            StreamedSegment::Variable(len) => {
+                // variable-size parts are read in order, so one starts at the next offset
+                let variable_index = self.segments[..self.next_segment - 1]
+                    .iter()
+                    .filter(|segment| matches!(segment, StreamedSegment::Variable(..)))
+                    .count();
+                let start = self.offsets[variable_index];
+                T::deserialize_from_source(source, *len).map_err(|err| {
+                    let err = match len {
+                        Some(len) => variable_part_error(err, *len),
+                        None => err,
+                    };
+                    err.in_part(part, start)
+                })
+            }
+        }
+    }
+}
+
+// `ContainerValidator` checks the encoding of a container as `ContainerDeserializer` and the
+// decoding of each field would, without allocating.
+// Intended use:
+// - call `new` with the fixed size of each field of the container, to check its offsets
+// - call `validate_field` for each field of the container in order, to check its encoding
+// NOTE: mainly intended for private use in the proc derive macro.
+#[derive(Debug)]
+pub struct ContainerValidator<'a> {
+    encoding: &'a [u8],
+    field_sizes: &'a [Option<usize>],
+    // where the fixed-size part of the next field starts
+    position: usize,
+    next_field: usize,
+}
+
+impl<'a> ContainerValidator<'a> {
+    pub fn new(
+        encoding: &'a [u8],
+        field_sizes: &'a [Option<usize>],
+    ) -> Result<Self, DeserializeError> {
+        let mut fixed_length = 0;
+        let mut offsets = None;
+        for size in field_sizes {
+            match size {
+                Some(size) => {
+                    if encoding.len() < fixed_length + size {
+                        return Err(DeserializeError::ExpectedFurtherInput {
+                            provided: encoding.len() - fixed_length,
+                            expected: *size,
+                        })
+                    }
+                    fixed_length += size;
+                }
+                None => {
+                    if encoding.len() < fixed_length + BYTES_PER_LENGTH_OFFSET {
+                        return Err(DeserializeError::ExpectedFurtherInput {
+                            provided: encoding.len() - fixed_length,
+                            expected: BYTES_PER_LENGTH_OFFSET,
+                        })
+                    }
+                    let offset = read_offset(encoding, fixed_length)?;
+                    if let Some((_, previous_offset)) = offsets {
+                        if offset < previous_offset {
+                            return Err(DeserializeError::OffsetNotIncreasing {
+                                start: previous_offset,
+                                end: offset,
+                            })
+                        }
+                        if previous_offset > encoding.len() {
+                            return Err(DeserializeError::ExpectedFurtherInput {
+                                provided: encoding.len(),
+                                expected: previous_offset,
+                            })
+                        }
+                        if offset > encoding.len() {
+                            return Err(DeserializeError::ExpectedFurtherInput {
+                                provided: encoding.len(),
+                                expected: offset,
+                            })
+                        }
+                    }
+                    let first_offset = offsets.map_or(offset, |(first_offset, _)| first_offset);
+                    offsets = Some((first_offset, offset));
+                    fixed_length += BYTES_PER_LENGTH_OFFSET;
+                }
+            }
+        }
+        match offsets {
+            Some((first_offset, _)) => {
+                check_first_offset(first_offset, fixed_length, encoding.len())?
+            }
+            None if encoding.len() > fixed_length => {
+                return Err(DeserializeError::AdditionalInput {
+                    provided: encoding.len(),
+                    expected: fixed_length,
+                })
+            }
+            None => {}
+        }
+        Ok(Self { encoding, field_sizes, position: 0, next_field: 0 })
+    }
+
+    pub fn validate_field<T: Deserialize>(&mut self) -> Result<(), DeserializeError> {
+        let is_variable_size = self.field_sizes[self.next_field].is_none();
+        let (_, part) = self.next_part()?;
+        T::validate(part).map_err(|err| {
+            if is_variable_size {
+                variable_part_error(err, part.len())
+            } else {
+                err
+            }
+        })
+    }
+
+    // Returns the encoding of the next field along with where it starts.
+    pub(crate) fn next_part(&mut self) -> Result<(usize, &'a [u8]), DeserializeError> {
+        let index = self.next_field;
+        self.next_field += 1;
+        let start = self.position;
+        // SAFETY: indices are safe because `new` checked each part is within the encoding; qed
+        if let Some(size) = self.field_sizes[index] {
+            self.position += size;
+            return Ok((start, &self.encoding[start..self.position]))
+        }
+        self.position += BYTES_PER_LENGTH_OFFSET;
+        let start = read_offset(self.encoding, start)?;
+        // the part ends where that of the next variable-size field starts, if there is one
+        let mut position = self.position;
+        let mut end = self.encoding.len();
+        for size in &self.field_sizes[index + 1..] {
+            match size {
+                Some(size) => position += size,
+                None => {
+                    end = read_offset(self.encoding, position)?;
+                    break
+                }
+            }
+        }
+        Ok((start, &self.encoding[start..end]))
+    }
+}
@@ -0,0 +1,477 @@
+//! Decoding of SSZ values whose type is only known at runtime, from a [`TypeDescriptor`].
+//!
+//! Tools like block explorers and debuggers can decode a payload of an unknown or experimental
+//! type from a description of its layout, e.g. one built by hand or from the [`TypeInfo`] of a
+//! related type, into a [`Value`] to inspect or print:
+//!
+//! ```
+//! # use ssz_rs::{dynamic::{decode_dynamic, Value}, prelude::*, TypeDescriptor};
+//! #[derive(Default, SimpleSerialize, TypeInfo)]
+//! struct Checkpoint {
+//!     epoch: u64,
+//!     root: Node,
+//! }
+//!
+//! let encoding = serialize(&Checkpoint { epoch: 3, ..Default::default() }).unwrap();
+//! let value = decode_dynamic(&TypeDescriptor::of::<Checkpoint>(), &encoding).unwrap();
+//! assert_eq!(value.field("epoch"), Some(&Value::Uint(U256::from(3))));
+//! ```
+//!
+//! [`TypeInfo`]: crate::TypeInfo
+use crate::{
+    bitlist, bitvector,
+    de::{
+        read_offset, variable_part_error, ContainerValidator, DecodingPart, Deserialize,
+        DeserializeError,
+    },
+    error::{InstanceError, TypeError},
+    lib::*,
+    ser::BYTES_PER_LENGTH_OFFSET,
+    stable_container::deserialize_active_fields,
+    type_info::{FieldDescriptor, TypeDescriptor, TypeKind},
+    uint::U256,
+    BITS_PER_BYTE,
+};
+
+/// A value of an SSZ type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Bool(bool),
+    /// An unsigned integer of any size.
+    Uint(U256),
+    /// A vector or list of `uint8`.
+    Bytes(Vec<u8>),
+    /// A bitvector or bitlist.
+    Bits(Vec<bool>),
+    Vector(Vec<Value>),
+    /// A list or progressive list.
+    List(Vec<Value>),
+    /// A container, or a stable container or profile with an `Optional` for each field that may
+    /// be absent.
+    Container {
+        name: &'static str,
+        fields: Vec<(&'static str, Value)>,
+    },
+    Union {
+        name: &'static str,
+        selector: u8,
+        value: Option<Box<Value>>,
+    },
+    Optional(Option<Box<Value>>),
+}
+
+impl Value {
+    /// The value of the field `name` of a container, if it is one with such a field.
+    pub fn field(&self, name: &str) -> Option<&Value> {
+        match self {
+            Self::Container { fields, .. } => {
+                fields.iter().find(|(field, _)| *field == name).map(|(_, value)| value)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Decode `encoding` as a value of the type described by `descriptor`.
+///
+/// Fails on the same encodings as deserializing the type itself would.
+pub fn decode_dynamic(
+    descriptor: &TypeDescriptor,
+    encoding: &[u8],
+) -> Result<Value, DeserializeError> {
+    match &descriptor.kind {
+        TypeKind::Boolean => bool::deserialize(encoding).map(Value::Bool),
+        TypeKind::Uint { bits } => {
+            expect_length(encoding, bits / BITS_PER_BYTE as usize)?;
+            let value = U256::try_from_le_slice(encoding).ok_or(TypeError::InvalidBound(*bits))?;
+            Ok(Value::Uint(value))
+        }
+        TypeKind::Vector { length, .. } if *length == 0 => {
+            Err(TypeError::InvalidBound(*length).into())
+        }
+        TypeKind::Vector { element, length } => {
+            if let Some(size) = element.fixed_size {
+                expect_length(encoding, length * size)?;
+            }
+            if is_byte(element) {
+                return Ok(Value::Bytes(encoding.to_vec()))
+            }
+            let elements = decode_elements(element, encoding)?;
+            if elements.len() != *length {
+                let provided = elements.len();
+                return Err(InstanceError::Exact { required: *length, provided }.into())
+            }
+            Ok(Value::Vector(elements))
+        }
+        TypeKind::List { element, limit } => {
+            let value = decode_list(element, encoding)?;
+            let count = match &value {
+                Value::Bytes(bytes) => bytes.len(),
+                Value::List(elements) => elements.len(),
+                _ => unreachable!("a list decodes to bytes or a list"),
+            };
+            if count > *limit {
+                return Err(InstanceError::Bounded { bound: *limit, provided: count }.into())
+            }
+            Ok(value)
+        }
+        TypeKind::ProgressiveList { element } => decode_list(element, encoding),
+        TypeKind::Bitvector { length } => Ok(Value::Bits(decode_bits(encoding, *length, false)?)),
+        TypeKind::Bitlist { limit } => Ok(Value::Bits(decode_bits(encoding, *limit, true)?)),
+        TypeKind::Container { name, fields } => {
+            let fields = fields.iter().map(|field| (field, &field.ty)).collect::<Vec<_>>();
+            let values = decode_fields(name, &fields, encoding)?;
+            let fields = fields.iter().map(|(field, _)| field.name).zip(values).collect();
+            Ok(Value::Container { name, fields })
+        }
+        TypeKind::Union { name, variants } => {
+            let (&selector, value) = encoding
+                .split_first()
+                .ok_or(DeserializeError::ExpectedFurtherInput { provided: 0, expected: 1 })?;
+            let value = match variants.get(selector as usize) {
+                Some(Some(ty)) => Some(Box::new(decode_dynamic(ty, value)?)),
+                Some(None) if value.is_empty() => None,
+                Some(None) => {
+                    return Err(DeserializeError::AdditionalInput {
+                        provided: encoding.len(),
+                        expected: 1,
+                    })
+                }
+                None => return Err(DeserializeError::InvalidByte(selector)),
+            };
+            Ok(Value::Union { name, selector, value })
+        }
+        TypeKind::Optional { value } => match encoding.split_first() {
+            None => Ok(Value::Optional(None)),
+            Some((1, encoding)) => {
+                Ok(Value::Optional(Some(Box::new(decode_dynamic(value, encoding)?))))
+            }
+            Some((&b, _)) => Err(DeserializeError::InvalidByte(b)),
+        },
+        TypeKind::StableContainer { name, capacity, fields } => {
+            decode_stable_fields(name, fields, Some(*capacity), encoding)
+        }
+        TypeKind::Profile { name, fields, .. } => {
+            // only the fields that may be absent have a bit, and without any there is no bitvector
+            let optional_count =
+                fields.iter().filter(|field| optional_value(field).is_some()).count();
+            let bit_count = (optional_count > 0).then_some(optional_count);
+            decode_stable_fields(name, fields, bit_count, encoding)
+        }
+    }
+}
+
+fn is_byte(descriptor: &TypeDescriptor) -> bool {
+    matches!(descriptor.kind, TypeKind::Uint { bits: 8 })
+}
+
+fn expect_length(encoding: &[u8], expected: usize) -> Result<(), DeserializeError> {
+    if encoding.len() < expected {
+        return Err(DeserializeError::ExpectedFurtherInput { provided: encoding.len(), expected })
+    }
+    if encoding.len() > expected {
+        return Err(DeserializeError::AdditionalInput { provided: encoding.len(), expected })
+    }
+    Ok(())
+}
+
+fn decode_list(element: &TypeDescriptor, encoding: &[u8]) -> Result<Value, DeserializeError> {
+    if let Some(size) = element.fixed_size {
+        let remainder = encoding.len() % size;
+        if remainder != 0 {
+            return Err(DeserializeError::AdditionalInput {
+                provided: encoding.len(),
+                expected: encoding.len() - remainder,
+            })
+        }
+    }
+    if is_byte(element) {
+        return Ok(Value::Bytes(encoding.to_vec()))
+    }
+    decode_elements(element, encoding).map(Value::List)
+}
+
+// Decode the elements of a homogeneous composite type, as `deserialize_homogeneous_composite`
+// does, given the length of the encoding is a multiple of that of fixed-size elements.
+fn decode_elements(
+    element: &TypeDescriptor,
+    encoding: &[u8],
+) -> Result<Vec<Value>, DeserializeError> {
+    let parts = match element.fixed_size {
+        Some(size) => {
+            encoding.chunks_exact(size).enumerate().map(|(i, part)| (i * size, part)).collect()
+        }
+        None => variable_parts(encoding)?,
+    };
+    parts
+        .into_iter()
+        .enumerate()
+        .map(|(i, (start, part))| {
+            decode_dynamic(element, part).map_err(|err| {
+                let err = if element.fixed_size.is_none() {
+                    variable_part_error(err, part.len())
+                } else {
+                    err
+                };
+                err.in_part(DecodingPart::Element(i), start)
+            })
+        })
+        .collect()
+}
+
+// Split the encoding of variable-size elements at their offsets, returning where each starts.
+fn variable_parts(encoding: &[u8]) -> Result<Vec<(usize, &[u8])>, DeserializeError> {
+    if encoding.is_empty() {
+        return Ok(vec![])
+    }
+    let offsets_len = read_offset(encoding, 0)?;
+    if encoding.len() < offsets_len {
+        return Err(DeserializeError::ExpectedFurtherInput {
+            provided: encoding.len(),
+            expected: offsets_len,
+        })
+    }
+    if offsets_len == 0 || !offsets_len.is_multiple_of(BYTES_PER_LENGTH_OFFSET) {
+        return Err(DeserializeError::InvalidOffsetsLength(offsets_len))
+    }
+
+    let element_count = offsets_len / BYTES_PER_LENGTH_OFFSET;
+    let mut parts = Vec::with_capacity(element_count);
+    let mut start = offsets_len;
+    for i in 0..element_count {
+        let end = if i + 1 < element_count {
+            read_offset(encoding, (i + 1) * BYTES_PER_LENGTH_OFFSET)?
+        } else {
+            encoding.len()
+        };
+        if start > end {
+            return Err(DeserializeError::OffsetNotIncreasing { start, end })
+        }
+        if end > encoding.len() {
+            return Err(DeserializeError::ExpectedFurtherInput {
+                provided: encoding.len(),
+                expected: end,
+            })
+        }
+        parts.push((start, &encoding[start..end]));
+        start = end;
+    }
+    Ok(parts)
+}
+
+// Decode the bits of a bitvector of `bound` bits or, if `is_list`, a bitlist of up to `bound`.
+fn decode_bits(
+    encoding: &[u8],
+    bound: usize,
+    is_list: bool,
+) -> Result<Vec<bool>, DeserializeError> {
+    let len = if is_list {
+        bitlist::decoded_length(encoding, bound)?
+    } else {
+        bitvector::check_encoding(encoding, bound)?;
+        bound
+    };
+    let bits_per_byte = BITS_PER_BYTE as usize;
+    Ok((0..len).map(|i| encoding[i / bits_per_byte] >> (i % bits_per_byte) & 1 == 1).collect())
+}
+
+// Decode the fields of a container given the type of each of them.
+fn decode_fields(
+    container: &'static str,
+    fields: &[(&FieldDescriptor, &TypeDescriptor)],
+    encoding: &[u8],
+) -> Result<Vec<Value>, DeserializeError> {
+    let field_sizes = fields.iter().map(|(_, ty)| ty.fixed_size).collect::<Vec<_>>();
+    let mut validator = ContainerValidator::new(encoding, &field_sizes)?;
+    fields
+        .iter()
+        .map(|(field, ty)| {
+            let (start, part) = validator.next_part()?;
+            decode_dynamic(ty, part).map_err(|err| {
+                let err = if ty.fixed_size.is_none() {
+                    variable_part_error(err, part.len())
+                } else {
+                    err
+                };
+                err.in_part(DecodingPart::Field { container, name: field.name }, start)
+            })
+        })
+        .collect()
+}
+
+// Returns the type of the value of a field of a stable container or profile that may be absent.
+fn optional_value(field: &FieldDescriptor) -> Option<&TypeDescriptor> {
+    match &field.ty.kind {
+        TypeKind::Optional { value } => Some(value),
+        _ => None,
+    }
+}
+
+// Decode a stable container or profile, led by a bitvector of `bit_count` bits for the fields
+// that may be absent, if there is one.
+fn decode_stable_fields(
+    name: &'static str,
+    fields: &[FieldDescriptor],
+    bit_count: Option<usize>,
+    encoding: &[u8],
+) -> Result<Value, DeserializeError> {
+    let optional_count = fields.iter().filter(|field| optional_value(field).is_some()).count();
+    let (active_fields, encoding) = match bit_count {
+        Some(bit_count) => deserialize_active_fields(encoding, bit_count, optional_count)?,
+        None => (vec![], encoding),
+    };
+    let mut active = active_fields.into_iter();
+    // each field along with the type of its value, if present
+    let present = fields
+        .iter()
+        .map(|field| match optional_value(field) {
+            Some(value) => active.next().unwrap_or_default().then_some((field, value)),
+            None => Some((field, &field.ty)),
+        })
+        .collect::<Vec<_>>();
+
+    let parts = present.iter().flatten().copied().collect::<Vec<_>>();
+    let mut values = decode_fields(name, &parts, encoding)?.into_iter();
+    let fields = fields
+        .iter()
+        .zip(present)
+        .map(|(field, present)| {
+            let value = match (present, optional_value(field)) {
+                (Some(_), Some(_)) => Value::Optional(Some(Box::new(
+                    values.next().expect("a value per field present"),
+                ))),
+                (Some(_), None) => values.next().expect("a value per field present"),
+                (None, _) => Value::Optional(None),
+            };
+            (field.name, value)
+        })
+        .collect();
+    Ok(Value::Container { name, fields })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Default, Debug, Clone, PartialEq, Eq, SimpleSerialize, TypeInfo)]
+    struct Header {
+        slot: u64,
+        root: Node,
+        flags: Bitvector<4>,
+    }
+
+    #[derive(Debug, PartialEq, Eq, SimpleSerialize, TypeInfo)]
+    enum Payload {
+        None,
+        Header(Header),
+        Count(u16),
+    }
+
+    #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize, TypeInfo)]
+    struct Block {
+        header: Header,
+        balances: List<u64, 100>,
+        headers: Vector<Header, 2>,
+        bits: Bitlist<300>,
+        extra: Optional<Header>,
+        payload: Option<Payload>,
+        history: ProgressiveList<List<u8, 4>>,
+    }
+
+    #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize, TypeInfo)]
+    #[ssz(stable_container = 4)]
+    struct Shape {
+        side: Optional<u16>,
+        color: Optional<u8>,
+        radius: Optional<u16>,
+    }
+
+    #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize, TypeInfo)]
+    #[ssz(profile = "Shape")]
+    struct Circle {
+        color: Optional<u8>,
+        radius: u16,
+    }
+
+    fn block() -> Block {
+        let header = Header { slot: 5, root: Node::from([1u8; 32]), flags: Bitvector::default() };
+        Block {
+            header: header.clone(),
+            balances: List::try_from(vec![32, 31]).unwrap(),
+            headers: Vector::from([header.clone(), Header::default()]),
+            bits: Bitlist::try_from([true, false, true].as_slice()).unwrap(),
+            extra: Optional::some(header),
+            payload: Some(Payload::Count(9)),
+            history: vec![List::try_from(vec![1, 2]).unwrap(), List::default()].into(),
+        }
+    }
+
+    #[test]
+    fn decode_values() {
+        let encoding = serialize(&block()).unwrap();
+        let value = decode_dynamic(&Block::type_info(), &encoding).unwrap();
+        let uint = |n: u64| Value::Uint(U256::from(n));
+
+        let header = value.field("header").unwrap();
+        assert_eq!(header.field("slot"), Some(&uint(5)));
+        assert_eq!(header.field("root"), Some(&Value::Bytes(vec![1; 32])));
+        assert_eq!(header.field("flags"), Some(&Value::Bits(vec![false; 4])));
+        assert_eq!(value.field("balances"), Some(&Value::List(vec![uint(32), uint(31)])));
+        assert!(
+            matches!(value.field("headers"), Some(Value::Vector(headers)) if headers.len() == 2)
+        );
+        assert_eq!(value.field("bits"), Some(&Value::Bits(vec![true, false, true])));
+        assert_eq!(value.field("extra"), Some(&Value::Optional(Some(Box::new(header.clone())))));
+        let Some(Value::Union { selector: 1, value: Some(payload), .. }) = value.field("payload")
+        else {
+            panic!("unexpected payload {:?}", value.field("payload"))
+        };
+        assert!(
+            matches!(&**payload, Value::Union { name: "Payload", selector: 2, value: Some(count) } if **count == uint(9))
+        );
+        assert_eq!(
+            value.field("history"),
+            Some(&Value::List(vec![Value::Bytes(vec![1, 2]), Value::Bytes(vec![])]))
+        );
+    }
+
+    #[test]
+    fn decode_stable_containers() {
+        let shape =
+            Shape { side: Optional::none(), color: Optional::some(1), radius: Optional::some(7) };
+        let encoding = serialize(&shape).unwrap();
+        let value = decode_dynamic(&Shape::type_info(), &encoding).unwrap();
+        assert_eq!(value.field("side"), Some(&Value::Optional(None)));
+        assert_eq!(
+            value.field("radius"),
+            Some(&Value::Optional(Some(Box::new(Value::Uint(U256::from(7))))))
+        );
+
+        let circle = Circle { color: Optional::none(), radius: 7 };
+        let encoding = serialize(&circle).unwrap();
+        let value = decode_dynamic(&Circle::type_info(), &encoding).unwrap();
+        assert_eq!(value.field("color"), Some(&Value::Optional(None)));
+        assert_eq!(value.field("radius"), Some(&Value::Uint(U256::from(7))));
+    }
+
+    #[test]
+    fn decode_dynamic_agrees_with_deserialize() {
+        let encoding = serialize(&block()).unwrap();
+        let descriptor = Block::type_info();
+
+        let outcome = |result: Result<(), DeserializeError>| {
+            result.map_err(|err| core::mem::discriminant(err.cause()))
+        };
+        for i in 0..encoding.len() {
+            for byte in [0, 1, 2, 0x80, 0xff] {
+                let mut mutated = encoding.clone();
+                mutated[i] = byte;
+                let expected = outcome(Block::deserialize(&mutated).map(|_| ()));
+                assert_eq!(outcome(decode_dynamic(&descriptor, &mutated).map(|_| ())), expected);
+            }
+            let expected = outcome(Block::deserialize(&encoding[..i]).map(|_| ()));
+            assert_eq!(outcome(decode_dynamic(&descriptor, &encoding[..i]).map(|_| ())), expected);
+        }
+    }
+}
@@ -94,3 +94,33 @@ impl Display for InstanceError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for InstanceError {}
+
+/// An invalid hex string.
+#[derive(Debug)]
+pub enum HexError {
+    /// The character at the given index (after any `0x` prefix) is not a hex digit.
+    InvalidCharacter(usize),
+    /// The digits do not make a whole number of bytes.
+    OddLength,
+    /// The decoded bytes do not fit the target type.
+    Instance(InstanceError),
+}
+
+impl From<InstanceError> for HexError {
+    fn from(err: InstanceError) -> Self {
+        Self::Instance(err)
+    }
+}
+
+impl Display for HexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCharacter(index) => write!(f, "invalid hex digit at index {index}"),
+            Self::OddLength => write!(f, "odd number of hex digits"),
+            Self::Instance(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HexError {}
@@ -0,0 +1,237 @@
+use crate::{
+    lib::*,
+    merkleization::{MerkleizationError, Merkleized, BYTES_PER_CHUNK},
+    ser::Serialize,
+    Serializable,
+};
+
+/// A contiguous run of elements covering the merkle chunks in `chunks`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkBatch<'a, T> {
+    pub chunks: Range<usize>,
+    pub elements: &'a [T],
+}
+
+/// An iterator over the elements of a homogeneous collection in batches that are aligned
+/// to the merkle chunks of the collection.
+///
+/// Each batch covers a fixed number of whole chunks, the last possibly fewer, so that a
+/// consumer backed by a chunk-addressed store can read each batch with one sequential access.
+/// [`ChunkedIter::read_ahead`] reports the chunks of the next batch, and
+/// [`ChunkedIter::with_read_ahead`] hands them to a [`ReadAhead`] store as each batch is
+/// yielded, so it can fetch them while the current batch is processed.
+pub struct ChunkedIter<'a, T> {
+    elements: &'a [T],
+    elements_per_chunk: usize,
+    chunks_per_batch: usize,
+    next_chunk: usize,
+}
+
+impl<'a, T> ChunkedIter<'a, T>
+where
+    T: Serializable + Merkleized,
+{
+    pub(crate) fn new(elements: &'a [T], chunks_per_batch: usize) -> Self {
+        let elements_per_chunk = if T::is_composite_type() {
+            1
+        } else {
+            usize::max(1, BYTES_PER_CHUNK / T::size_hint())
+        };
+        Self {
+            elements,
+            elements_per_chunk,
+            chunks_per_batch: chunks_per_batch.max(1),
+            next_chunk: 0,
+        }
+    }
+}
+
+impl<'a, T> ChunkedIter<'a, T> {
+    /// Returns the range of chunks the next batch covers, if any.
+    pub fn read_ahead(&self) -> Option<Range<usize>> {
+        let chunk_count = self.elements.len().div_ceil(self.elements_per_chunk);
+        if self.next_chunk >= chunk_count {
+            return None
+        }
+        Some(self.next_chunk..usize::min(self.next_chunk + self.chunks_per_batch, chunk_count))
+    }
+
+    /// Tell `store` the chunks of the following batch as each batch is yielded.
+    pub fn with_read_ahead<R: ReadAhead>(self, store: R) -> ReadAheadIter<'a, T, R> {
+        ReadAheadIter { inner: self, store }
+    }
+}
+
+impl<'a, T> Iterator for ChunkedIter<'a, T> {
+    type Item = ChunkBatch<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunks = self.read_ahead()?;
+        self.next_chunk = chunks.end;
+        let start = chunks.start * self.elements_per_chunk;
+        let end = usize::min(chunks.end * self.elements_per_chunk, self.elements.len());
+        Some(ChunkBatch { chunks, elements: &self.elements[start..end] })
+    }
+}
+
+/// A store holding the chunks of a collection, e.g. on disk, that can fetch chunks ahead of a
+/// scan reading them.
+///
+/// Any closure taking a range of chunks is a `ReadAhead`.
+pub trait ReadAhead {
+    /// Called with the chunks a scan will read next, while it processes the chunks before them.
+    fn prefetch(&mut self, chunks: Range<usize>);
+}
+
+impl<F: FnMut(Range<usize>)> ReadAhead for F {
+    fn prefetch(&mut self, chunks: Range<usize>) {
+        self(chunks)
+    }
+}
+
+/// A [`ChunkedIter`] telling a [`ReadAhead`] store which chunks it reads next.
+pub struct ReadAheadIter<'a, T, R> {
+    inner: ChunkedIter<'a, T>,
+    store: R,
+}
+
+impl<'a, T, R> ReadAheadIter<'a, T, R> {
+    /// Returns the store, e.g. to read the chunks of a batch from it.
+    pub fn store(&mut self) -> &mut R {
+        &mut self.store
+    }
+}
+
+impl<'a, T, R: ReadAhead> Iterator for ReadAheadIter<'a, T, R> {
+    type Item = ChunkBatch<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch = self.inner.next()?;
+        if let Some(chunks) = self.inner.read_ahead() {
+            self.store.prefetch(chunks);
+        }
+        Some(batch)
+    }
+}
+
+/// An iterator over the chunks that the serializations of a sequence of basic values are
+/// packed into, produced one at a time rather than collected into a buffer.
+///
+/// Yields the same chunks as packing the values for merkleization, with the last chunk padded
+/// with zeros. Only a chunk's worth of serialized bytes is held at once.
+pub struct ChunksOf<'a, T> {
+    values: core::slice::Iter<'a, T>,
+    buffer: Vec<u8>,
+}
+
+/// Return an iterator over the chunks `values` are packed into.
+pub fn chunks_of<T: Serialize>(values: &[T]) -> ChunksOf<'_, T> {
+    ChunksOf { values: values.iter(), buffer: Vec::with_capacity(2 * BYTES_PER_CHUNK) }
+}
+
+impl<'a, T: Serialize> Iterator for ChunksOf<'a, T> {
+    type Item = Result<[u8; BYTES_PER_CHUNK], MerkleizationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buffer.len() < BYTES_PER_CHUNK {
+            let Some(value) = self.values.next() else { break };
+            if let Err(err) = value.serialize(&mut self.buffer) {
+                self.values = [].iter();
+                self.buffer.clear();
+                return Some(Err(err.into()))
+            }
+        }
+        if self.buffer.is_empty() {
+            return None
+        }
+        let mut chunk = [0u8; BYTES_PER_CHUNK];
+        let len = usize::min(self.buffer.len(), BYTES_PER_CHUNK);
+        chunk[..len].copy_from_slice(&self.buffer[..len]);
+        self.buffer.drain(..len);
+        Some(Ok(chunk))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{merkleization::pack, prelude::*};
+
+    #[test]
+    fn test_chunks_of_matches_pack() {
+        let values = (0..37).collect::<Vec<u16>>();
+        let chunks = chunks_of(&values).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.concat(), pack(&values).unwrap());
+
+        let values = [U256::from(1), U256::MAX];
+        let chunks = chunks_of(&values).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(chunks.concat(), pack(&values).unwrap());
+
+        let values = [true, false, true];
+        let chunks = chunks_of(&values).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(chunks.concat(), pack(&values).unwrap());
+
+        assert!(chunks_of::<u64>(&[]).next().is_none());
+    }
+
+    #[test]
+    fn test_chunked_iter_basic() {
+        let list = List::<u64, 64>::try_from((0..10).collect::<Vec<u64>>()).unwrap();
+        let mut iter = list.chunked_iter(2);
+
+        let batch = iter.next().unwrap();
+        assert_eq!(batch.chunks, 0..2);
+        assert_eq!(batch.elements, &[0, 1, 2, 3, 4, 5, 6, 7]);
+
+        let batch = iter.next().unwrap();
+        assert_eq!(batch.chunks, 2..3);
+        assert_eq!(batch.elements, &[8, 9]);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_chunked_iter_read_ahead() {
+        let list = List::<u64, 64>::try_from((0..42).collect::<Vec<u64>>()).unwrap();
+        let mut iter = list.chunked_iter(4);
+        assert_eq!(iter.read_ahead(), Some(0..4));
+        iter.next();
+        assert_eq!(iter.read_ahead(), Some(4..8));
+
+        // records the chunks it is asked to fetch
+        #[derive(Default)]
+        struct Store(Vec<Range<usize>>);
+
+        impl ReadAhead for Store {
+            fn prefetch(&mut self, chunks: Range<usize>) {
+                self.0.push(chunks);
+            }
+        }
+
+        // each batch but the first is prefetched as the one before it is yielded
+        let mut iter = list.chunked_iter(4).with_read_ahead(Store::default());
+        let batch = iter.next().unwrap();
+        assert_eq!(batch.chunks, 0..4);
+        assert_eq!(iter.store().0, vec![4..8]);
+        let batches = iter.by_ref().map(|batch| batch.chunks).collect::<Vec<_>>();
+        assert_eq!(batches, vec![4..8, 8..11]);
+        assert_eq!(iter.store().0, vec![4..8, 8..11]);
+
+        let mut prefetched = vec![];
+        let batches = list
+            .chunked_iter(16)
+            .with_read_ahead(|chunks| prefetched.push(chunks))
+            .collect::<Vec<_>>();
+        assert_eq!(batches.len(), 1);
+        assert!(prefetched.is_empty());
+    }
+
+    #[test]
+    fn test_chunked_iter_composite() {
+        let vector = Vector::<List<u8, 4>, 3>::default();
+        let batches = vector.chunked_iter(2).collect::<Vec<_>>();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].elements.len(), 2);
+        assert_eq!(batches[1].chunks, 2..3);
+    }
+}
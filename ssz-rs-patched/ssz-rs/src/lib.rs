@@ -35,22 +35,43 @@
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 
+// `rayon` would panic on its first use in a browser, where threads cannot be spawned
+#[cfg(all(feature = "rayon", target_arch = "wasm32"))]
+compile_error!("the `rayon` feature is not supported on `wasm32` targets");
+
 mod array;
+mod array_list;
 mod bitlist;
 mod bitvector;
 mod boolean;
+mod cached_list;
 mod container;
 mod de;
+pub mod dynamic;
 mod error;
+mod iter;
 mod list;
 mod merkleization;
+mod optional;
+mod progressive_list;
+#[cfg(feature = "proptest")]
+pub mod proptest;
 mod ser;
 #[cfg(feature = "serde")]
 pub mod serde;
+#[cfg(feature = "snappy")]
+pub mod snappy;
+mod stable_container;
+mod type_info;
 mod uint;
 mod union;
 pub mod utils;
 mod vector;
+mod view;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+use crate::ser::Discard;
 
 mod lib {
     mod core {
@@ -60,14 +81,15 @@ mod lib {
         pub use std::*;
     }
 
-    pub use self::core::{any, cmp, fmt, iter, slice};
+    pub use self::core::{any, cmp, fmt, hash, iter, slice};
 
     pub use self::{
         cmp::Ordering,
         core::{
             array::TryFromSliceError,
+            cell::RefCell,
             fmt::{Debug, Display, Formatter},
-            ops::{Deref, DerefMut, Index, IndexMut},
+            ops::{Deref, DerefMut, Index, IndexMut, Range},
             slice::SliceIndex,
             str::FromStr,
         },
@@ -75,12 +97,23 @@ mod lib {
     };
 
     #[cfg(not(feature = "std"))]
-    pub use alloc::{format, string::String, vec, vec::Vec};
+    pub use alloc::{
+        boxed::Box,
+        collections::{BTreeMap, BTreeSet},
+        format,
+        string::String,
+        sync::Arc,
+        vec,
+        vec::Vec,
+    };
 
     #[cfg(feature = "std")]
-    pub use std::vec::Vec;
+    pub use std::{
+        collections::{BTreeMap, BTreeSet},
+        sync::Arc,
+        vec::Vec,
+    };
 
-    #[cfg(feature = "serde")]
     pub use self::core::marker::PhantomData;
 }
 
@@ -89,33 +122,99 @@ pub(crate) const BITS_PER_BYTE: u32 = 8;
 /// `Serializable` is a trait for types that can be
 /// serialized and deserialized according to the SSZ spec.
 pub trait Serializable: Serialize + Deserialize {
+    /// The length of the encoding of any value of this type, or `None` if this type is
+    /// variable-size. Unlike the methods below, this can be read in `const` contexts.
+    const FIXED_SIZE: Option<usize>;
+
     // is this type variable or fixed size?
-    fn is_variable_size() -> bool;
+    fn is_variable_size() -> bool {
+        Self::FIXED_SIZE.is_none()
+    }
 
     // expected number of bytes for the serialization of this type
     // or 0 if unknown ahead of time
-    fn size_hint() -> usize;
+    fn size_hint() -> usize {
+        Self::FIXED_SIZE.unwrap_or(0)
+    }
+
+    /// The length of the encoding of this value, found without encoding it where possible.
+    ///
+    /// This fails where [`Serialize::serialize`] would, e.g. for a `List` holding more elements
+    /// than its bound.
+    fn serialized_length(&self) -> Result<usize, SerializeError>
+    where
+        Self: Sized,
+    {
+        match Self::FIXED_SIZE {
+            Some(size) => Ok(size),
+            None => self.serialize_to_sink(&mut Discard),
+        }
+    }
 }
 
 /// `SimpleSerialize` is a trait for types conforming to the SSZ spec.
 /// These types can be encoded and decoded while also supporting the
 /// merkelization scheme of SSZ.
-pub trait SimpleSerialize: Serializable + Merkleized {}
+pub trait SimpleSerialize: Serializable + Merkleized + Prove {}
 
 mod exports {
     pub use crate::{
+        array_list::ArrayList,
         bitlist::Bitlist,
         bitvector::Bitvector,
-        de::{Deserialize, DeserializeError},
-        error::{Error as SimpleSerializeError, InstanceError, TypeError},
+        cached_list::CachedList,
+        container::Container,
+        de::{DecodingPart, DecodingSource, Deserialize, DeserializeError, LimitedSource},
+        error::{Error as SimpleSerializeError, HexError, InstanceError, TypeError},
+        iter::{chunks_of, ChunkBatch, ChunkedIter, ChunksOf, ReadAhead, ReadAheadIter},
         list::List,
-        merkleization::{is_valid_merkle_branch, MerkleizationError, Merkleized, Node},
-        ser::{Serialize, SerializeError},
+        merkleization::{
+            format_path, get_helper_indices, hash_chunks, hash_tree_roots, hash_tree_roots_into,
+            is_valid_merkle_branch, is_valid_merkle_branch_with_hasher, merkleize_with_hasher,
+            mix_in_length, mix_in_length_with_hasher, parse_path, verify_multiproof,
+            verify_multiproof_with_hasher, verify_proofs, verify_proofs_with_hasher, zero_hash,
+            Branch, GeneralizedIndex, Hasher, MerkleTree, MerkleizationError, Merkleized,
+            MerkleizerStream, Multiproof, Node, Path, PathElement, Proof, Prove, SparseChunks,
+            SparseMerkleTree, MAX_MERKLE_TREE_DEPTH,
+        },
+        optional::Optional,
+        progressive_list::{merkleize_progressive, ProgressiveList},
+        ser::{EncodingSink, Serialize, SerializeError},
+        stable_container::{Profile, StableContainer},
+        type_info::{FieldDescriptor, TypeDescriptor, TypeInfo, TypeKind},
         uint::U256,
-        utils::{deserialize, serialize},
+        union::Union,
+        utils::{deserialize, fixed_size, is_fixed_size, serialize},
         vector::Vector,
+        view::{view, DeserializeView, ListView, VectorView, Viewable},
         Serializable, SimpleSerialize,
     };
+
+    #[cfg(feature = "std")]
+    pub use crate::merkleization::{
+        hash_tree_root_diff, hash_tree_root_with_hasher, hash_tree_root_with_hasher_and_witness,
+        hash_tree_root_with_progress, hash_tree_root_with_witness, HashStep, Progress,
+        ProgressTracker, WitnessTrace,
+    };
+
+    #[cfg(feature = "metrics")]
+    pub use crate::merkleization::{
+        hash_tree_root_with_metrics, HashMetrics, MetricsByType, MetricsObserver,
+    };
+
+    #[cfg(feature = "rayon")]
+    pub use crate::merkleization::{par_hash_tree_roots, par_hash_tree_roots_into};
+
+    #[cfg(feature = "keccak")]
+    pub use crate::merkleization::Keccak256;
+
+    #[cfg(feature = "poseidon")]
+    pub use crate::merkleization::Poseidon;
+
+    #[cfg(feature = "no-alloc")]
+    pub use crate::merkleization::{
+        merkleize_bytes_into, merkleize_into, pack_bytes_into, packed_len,
+    };
 }
 
 pub use crate::exports::*;
@@ -128,7 +227,7 @@ pub mod prelude {
     // expose this so the derive macro has everything in scope
     // with a simple `prelude` import
     pub use crate as ssz_rs;
-    pub use ssz_rs_derive::{Merkleized, Serializable, SimpleSerialize};
+    pub use ssz_rs_derive::{Merkleized, Serializable, SimpleSerialize, TypeInfo, Viewable};
 }
 
 #[doc(hidden)]
@@ -136,8 +235,22 @@ pub mod prelude {
 pub mod __internal {
     // exported for derive macro to avoid code duplication...
     pub use crate::{
-        de::ContainerDeserializer,
-        merkleization::{merkleize, mix_in_selector},
-        ser::Serializer,
+        container::{EncodeWith, With},
+        de::{
+            expect_empty, read_selector, variable_part_error, ContainerDeserializer,
+            ContainerValidator, StreamingDeserializer,
+        },
+        merkleization::{
+            extend_branch, measure_hash_tree_root, merkleize, merkleize_in_place, mix_in_selector,
+            zeroed_chunks,
+        },
+        ser::{fixed_size_of_fields, Serializer, StreamingSerializer},
+        stable_container::{
+            deserialize_active_fields, extend_stable_branch, merkleize_stable_fields,
+            profile_field_indices, serialize_active_fields,
+        },
     };
+
+    #[cfg(feature = "arbitrary")]
+    pub use arbitrary;
 }
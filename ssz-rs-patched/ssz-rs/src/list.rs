@@ -1,12 +1,20 @@
 use crate::{
-    de::{deserialize_homogeneous_composite, Deserialize, DeserializeError},
-    error::{Error, InstanceError},
+    de::{
+        deserialize_homogeneous_composite, deserialize_homogeneous_composite_from_source,
+        validate_homogeneous_composite, DecodingSource, Deserialize, DeserializeError,
+    },
+    error::{Error, HexError, InstanceError},
+    iter::ChunkedIter,
     lib::*,
     merkleization::{
-        elements_to_chunks, merkleize, mix_in_length, pack, MerkleizationError, Merkleized, Node,
-        BYTES_PER_CHUNK,
+        elements_to_chunks, merkleize, mix_in_length, pack, prove_element, MerkleizationError,
+        Merkleized, Node, Path, PathElement, Proof, Prove, BYTES_PER_CHUNK,
+    },
+    ser::{
+        serialize_elements, serialize_elements_to_sink, serialized_length_of_elements,
+        EncodingSink, Serialize, SerializeError,
     },
-    ser::{Serialize, SerializeError, Serializer},
+    utils::{decode_hex, write_bytes_to_lower_hex_digits, write_bytes_to_lower_hex_display},
     Serializable, SimpleSerialize,
 };
 
@@ -36,6 +44,38 @@ where
     }
 }
 
+impl<const N: usize> List<u8, N> {
+    /// Decode a `List` of at most `N` bytes from hex digits, with or without a `0x` prefix.
+    pub fn from_hex(s: &str) -> Result<Self, HexError> {
+        let data = decode_hex(s)?;
+        if data.len() > N {
+            return Err(InstanceError::Bounded { bound: N, provided: data.len() }.into())
+        }
+        Ok(Self { data })
+    }
+}
+
+/// Abbreviated `0x`-prefixed hex, e.g. `0x0102…0f10`; use `{:#x}` for every byte.
+impl<const N: usize> fmt::Display for List<u8, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_bytes_to_lower_hex_display(f, self.data.iter())
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for List<u8, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_bytes_to_lower_hex_digits(f, &self.data)
+    }
+}
+
+impl<const N: usize> FromStr for List<u8, N> {
+    type Err = HexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
 impl<T, const N: usize> Default for List<T, N>
 where
     T: Serializable,
@@ -60,6 +100,33 @@ where
 
 impl<T, const N: usize> Eq for List<T, N> where T: Serializable + Eq {}
 
+impl<T, const N: usize> hash::Hash for List<T, N>
+where
+    T: Serializable + hash::Hash,
+{
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+    }
+}
+
+impl<T, const N: usize> PartialOrd for List<T, N>
+where
+    T: Serializable + PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.data.partial_cmp(&other.data)
+    }
+}
+
+impl<T, const N: usize> Ord for List<T, N>
+where
+    T: Serializable + Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.data.cmp(&other.data)
+    }
+}
+
 impl<T, const N: usize> TryFrom<Vec<T>> for List<T, N>
 where
     T: Serializable,
@@ -92,6 +159,36 @@ where
     }
 }
 
+impl<T, const N: usize, const M: usize> TryFrom<[T; M]> for List<T, N>
+where
+    T: Serializable,
+{
+    type Error = ([T; M], Error);
+
+    fn try_from(data: [T; M]) -> Result<Self, Self::Error> {
+        if M > N {
+            Err((data, Error::Instance(InstanceError::Bounded { bound: N, provided: M })))
+        } else {
+            Ok(Self { data: data.into() })
+        }
+    }
+}
+
+impl<T, const N: usize, const M: usize> TryFrom<List<T, N>> for [T; M]
+where
+    T: Serializable,
+{
+    type Error = (List<T, N>, Error);
+
+    fn try_from(list: List<T, N>) -> Result<Self, Self::Error> {
+        if list.len() != M {
+            let len = list.len();
+            return Err((list, Error::Instance(InstanceError::Exact { required: M, provided: len })))
+        }
+        Ok(list.data.try_into().unwrap_or_else(|_| unreachable!("the length was checked above")))
+    }
+}
+
 impl<T, const N: usize> Deref for List<T, N>
 where
     T: Serializable,
@@ -130,12 +227,13 @@ impl<T, const N: usize> Serializable for List<T, N>
 where
     T: Serializable,
 {
-    fn is_variable_size() -> bool {
-        true
-    }
+    const FIXED_SIZE: Option<usize> = None;
 
-    fn size_hint() -> usize {
-        0
+    fn serialized_length(&self) -> Result<usize, SerializeError> {
+        if self.len() > N {
+            return Err(InstanceError::Bounded { bound: N, provided: self.len() }.into())
+        }
+        serialized_length_of_elements(&self.data)
     }
 }
 
@@ -147,11 +245,14 @@ where
         if self.len() > N {
             return Err(InstanceError::Bounded { bound: N, provided: self.len() }.into())
         }
-        let mut serializer = Serializer::default();
-        for element in &self.data {
-            serializer.with_element(element)?;
+        serialize_elements(&self.data, buffer)
+    }
+
+    fn serialize_to_sink(&self, sink: &mut dyn EncodingSink) -> Result<usize, SerializeError> {
+        if self.len() > N {
+            return Err(InstanceError::Bounded { bound: N, provided: self.len() }.into())
         }
-        serializer.serialize(buffer)
+        serialize_elements_to_sink(&self.data, sink)
     }
 }
 
@@ -160,42 +261,111 @@ where
     T: Serializable,
 {
     fn deserialize(encoding: &[u8]) -> Result<Self, DeserializeError> {
-        if !T::is_variable_size() {
-            let remainder = encoding.len() % T::size_hint();
-            if remainder != 0 {
-                return Err(DeserializeError::AdditionalInput {
-                    provided: encoding.len(),
-                    // SAFETY: checked subtraction is unnecessary, as encoding.len() > remainder;
-                    // qed
-                    expected: encoding.len() - remainder,
-                })
-            }
-        }
-
+        check_remainder::<T>(encoding)?;
         let result = deserialize_homogeneous_composite(encoding)?;
-        if result.len() > N {
-            return Err(InstanceError::Bounded { bound: N, provided: result.len() }.into())
-        }
+        check_count::<N>(result.len())?;
         let result = result.try_into().map_err(|(_, err)| match err {
             Error::Instance(err) => DeserializeError::InvalidInstance(err),
             _ => unreachable!("no other error variant allowed here"),
         })?;
         Ok(result)
     }
+
+    fn deserialize_from_source(
+        source: &mut dyn DecodingSource,
+        len: Option<usize>,
+    ) -> Result<Self, DeserializeError> {
+        let result = deserialize_homogeneous_composite_from_source(source, len, N)?;
+        Ok(Self { data: result })
+    }
+
+    fn validate(encoding: &[u8]) -> Result<(), DeserializeError> {
+        check_remainder::<T>(encoding)?;
+        check_count::<N>(validate_homogeneous_composite::<T>(encoding)?)
+    }
+}
+
+// Check the length of the `encoding` of a list of elements of type `T` is a whole number of
+// elements, where they are of fixed size.
+pub(crate) fn check_remainder<T: Serializable>(encoding: &[u8]) -> Result<(), DeserializeError> {
+    if !T::is_variable_size() {
+        let remainder = encoding.len() % T::size_hint();
+        if remainder != 0 {
+            return Err(DeserializeError::AdditionalInput {
+                provided: encoding.len(),
+                // SAFETY: checked subtraction is unnecessary, as encoding.len() > remainder;
+                // qed
+                expected: encoding.len() - remainder,
+            })
+        }
+    }
+    Ok(())
+}
+
+// Check a list bounded to `N` elements has no more than `N`.
+pub(crate) fn check_count<const N: usize>(count: usize) -> Result<(), DeserializeError> {
+    if count > N {
+        return Err(InstanceError::Bounded { bound: N, provided: count }.into())
+    }
+    Ok(())
 }
 
 impl<T, const N: usize> List<T, N>
 where
     T: Serializable,
 {
-    pub fn push(&mut self, element: T) {
+    /// Collect the elements yielded by `iter`, failing if there are more than `N` of them.
+    ///
+    /// No more than `N + 1` elements are taken from `iter`, so it need not end; when there are
+    /// too many, the number provided is estimated from the size hint of what remains.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Error> {
+        let mut iter = iter.into_iter();
+        let mut data = Vec::with_capacity(iter.size_hint().0.min(N));
+        data.extend(iter.by_ref().take(N));
+        if iter.next().is_some() {
+            let provided = (N + 1).saturating_add(iter.size_hint().0);
+            return Err(Error::Instance(InstanceError::Bounded { bound: N, provided }))
+        }
+        Ok(Self { data })
+    }
+
+    /// Append `element`, failing if the list already holds `N` elements.
+    pub fn push(&mut self, element: T) -> Result<(), Error> {
+        if self.data.len() == N {
+            return Err(Error::Instance(InstanceError::Bounded { bound: N, provided: N + 1 }))
+        }
         self.data.push(element);
+        Ok(())
     }
 
     pub fn pop(&mut self) -> Option<T> {
         self.data.pop()
     }
 
+    /// Keep the first `len` elements, dropping the rest, if there are more than `len`.
+    pub fn truncate(&mut self, len: usize) {
+        self.data.truncate(len);
+    }
+
+    /// Append a copy of each of `elements`, failing without changing the list if it would
+    /// hold more than `N` elements.
+    pub fn extend_from_slice(&mut self, elements: &[T]) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        let len = self.data.len() + elements.len();
+        if len > N {
+            return Err(Error::Instance(InstanceError::Bounded { bound: N, provided: len }))
+        }
+        self.data.extend_from_slice(elements);
+        Ok(())
+    }
+
+    /// Keep only the elements for which `f` returns `true`, in order.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.data.retain(f);
+    }
+
     pub fn clear(&mut self) {
         self.data.clear();
     }
@@ -203,6 +373,14 @@ where
     pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         IterMut { inner: self.data.iter_mut() }
     }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
 }
 
 pub struct IterMut<'a, T> {
@@ -226,29 +404,80 @@ where
         (N * T::size_hint() + BYTES_PER_CHUNK - 1) / BYTES_PER_CHUNK
     }
 
-    fn compute_hash_tree_root(&mut self) -> Result<Node, MerkleizationError> {
+    /// Iterate over the elements in batches of `chunks_per_batch` merkle chunks.
+    /// See `ChunkedIter` for more details.
+    pub fn chunked_iter(&self, chunks_per_batch: usize) -> ChunkedIter<'_, T> {
+        ChunkedIter::new(&self.data, chunks_per_batch)
+    }
+
+    // Number of chunks the tree of the list's data is padded to
+    fn chunk_limit() -> usize {
         if T::is_composite_type() {
-            let count = self.len();
-            let chunks = elements_to_chunks(self.data.iter_mut().enumerate(), count)?;
-            let data_root = merkleize(&chunks, Some(N))?;
-            Ok(mix_in_length(&data_root, self.len()))
+            N
+        } else {
+            Self::chunk_count()
+        }
+    }
+
+    fn compute_data_root(&self) -> Result<Node, MerkleizationError> {
+        if T::is_composite_type() {
+            let chunks = elements_to_chunks(&self.data)?;
+            merkleize(&chunks, Some(Self::chunk_limit()))
         } else {
             let chunks = pack(self)?;
-            let data_root = merkleize(&chunks, Some(Self::chunk_count()))?;
-            Ok(mix_in_length(&data_root, self.len()))
+            merkleize(&chunks, Some(Self::chunk_limit()))
         }
     }
+
+    fn compute_hash_tree_root(&self) -> Result<Node, MerkleizationError> {
+        let data_root = self.compute_data_root()?;
+        Ok(mix_in_length(&data_root, self.len()))
+    }
+
+    /// Build a proof of the length of the list against its hash tree root, which can be checked
+    /// with [`Proof::verify_length`] by someone holding none of the elements.
+    pub fn prove_length(&self) -> Result<Proof, MerkleizationError> {
+        let branch = vec![self.compute_data_root()?];
+        Ok(Proof { leaf: self.len().hash_tree_root()?, branch, index: 1 })
+    }
 }
 
 impl<T, const N: usize> Merkleized for List<T, N>
 where
     T: SimpleSerialize,
 {
-    fn hash_tree_root(&mut self) -> Result<Node, MerkleizationError> {
+    fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
         self.compute_hash_tree_root()
     }
 }
 
+impl<T, const N: usize> Prove for List<T, N>
+where
+    T: SimpleSerialize,
+{
+    fn prove_path(
+        &self,
+        path: Path,
+        branch: &mut Vec<Node>,
+    ) -> Result<(Node, usize), MerkleizationError> {
+        match path {
+            [] => Ok((self.hash_tree_root()?, 0)),
+            [PathElement::Length] => {
+                branch.push(self.compute_data_root()?);
+                Ok((self.len().hash_tree_root()?, 1))
+            }
+            [PathElement::Length, element, ..] => {
+                Err(MerkleizationError::InvalidPathElement(element.clone()))
+            }
+            path => {
+                let proof = prove_element(&self.data, Some(Self::chunk_limit()), path, branch)?;
+                branch.push(self.len().hash_tree_root()?);
+                Ok(proof)
+            }
+        }
+    }
+}
+
 impl<T, const N: usize> SimpleSerialize for List<T, N> where T: SimpleSerialize {}
 
 #[cfg(feature = "serde")]
@@ -283,10 +512,21 @@ impl<'de, T: Serializable + serde::Deserialize<'de>, const N: usize> serde::Dese
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, T, const N: usize> arbitrary::Arbitrary<'a> for List<T, N>
+where
+    T: Serializable + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let data = u.arbitrary_iter()?.take(N).collect::<arbitrary::Result<Vec<T>>>()?;
+        Ok(Self { data })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::serialize;
+    use crate::{serialize, Vector};
 
     const COUNT: usize = 32;
 
@@ -294,7 +534,7 @@ mod tests {
     fn encode_list() {
         let mut value: List<u16, COUNT> = List::default();
         for _ in 0..COUNT {
-            value.push(33u16);
+            value.push(33u16).unwrap();
         }
         let encoding = serialize(&value).expect("can encode");
         let expected = [
@@ -306,6 +546,38 @@ mod tests {
         assert_eq!(encoding, expected);
     }
 
+    #[test]
+    fn list_from_iter() {
+        let list = List::<u16, 8>::try_from_iter((0..5).map(|i| i * 3)).unwrap();
+        assert_eq!(list.as_ref(), [0, 3, 6, 9, 12]);
+        assert!(List::<u16, 8>::try_from_iter([]).unwrap().is_empty());
+        assert!(matches!(
+            List::<u16, 8>::try_from_iter(0..10),
+            Err(Error::Instance(InstanceError::Bounded { bound: 8, provided: 10 }))
+        ));
+        assert!(List::<u16, 8>::try_from_iter(0..).is_err());
+    }
+
+    #[test]
+    fn mutate_list() {
+        let mut value = List::<u8, 4>::default();
+        value.push(1).unwrap();
+        value.extend_from_slice(&[2, 3, 4]).unwrap();
+        assert!(matches!(
+            value.push(5),
+            Err(Error::Instance(InstanceError::Bounded { bound: 4, provided: 5 }))
+        ));
+        assert_eq!(value.as_ref(), [1, 2, 3, 4]);
+
+        value.truncate(2);
+        assert!(value.extend_from_slice(&[5, 6, 7]).is_err());
+        assert_eq!(value.as_ref(), [1, 2]);
+        value.extend_from_slice(&[5, 6]).unwrap();
+        value.retain(|&element| element % 2 == 0);
+        assert_eq!(value.as_ref(), [2, 6]);
+        assert_eq!(value.pop(), Some(6));
+    }
+
     #[test]
     fn decode_list() {
         let bytes = vec![
@@ -348,10 +620,10 @@ mod tests {
         type Foo = List<List<u8, 16>, 32>;
 
         let mut value = Foo::default();
-        value.push(Default::default());
+        value.push(Default::default()).unwrap();
         let encoding = ssz_rs::serialize(&value).unwrap();
 
-        let mut recovered: Foo = ssz_rs::deserialize(&encoding).unwrap();
+        let recovered: Foo = ssz_rs::deserialize(&encoding).unwrap();
         assert_eq!(value, recovered);
 
         let _ = recovered.hash_tree_root().unwrap();
@@ -383,6 +655,71 @@ mod tests {
         assert_eq!(input, recovered_input);
     }
 
+    #[test]
+    fn test_hex() {
+        type L = List<u8, 4>;
+        let input = L::from_hex("0x0116").unwrap();
+        assert_eq!(input.as_ref(), [1, 22]);
+        assert_eq!(format!("{input}"), "0x0116");
+        assert_eq!(format!("{input:#x}"), "0x0116");
+        assert_eq!(format!("{:x}", L::default()), "");
+        assert_eq!("".parse::<L>().unwrap(), L::default());
+        assert!(matches!(
+            "0x0102030405".parse::<L>(),
+            Err(HexError::Instance(InstanceError::Bounded { bound: 4, provided: 5 }))
+        ));
+    }
+
+    #[test]
+    fn test_ordering() {
+        let short = List::<u16, 4>::try_from(vec![1, 2]).unwrap();
+        let long = List::<u16, 4>::try_from(vec![1, 2, 0]).unwrap();
+        let other = List::<u16, 4>::try_from(vec![3]).unwrap();
+        assert!(short < long && long < other);
+
+        let index = BTreeMap::from([(other.clone(), 2), (short.clone(), 0), (long.clone(), 1)]);
+        assert_eq!(index.keys().cloned().collect::<Vec<_>>(), [short, long, other.clone()]);
+        let keys = std::collections::HashSet::from([other.clone(), other]);
+        assert_eq!(keys.len(), 1);
+    }
+
+    #[test]
+    fn test_bytes() {
+        let data = (0..40).collect::<Vec<u8>>();
+        let mut list = List::<u8, 64>::try_from(data.clone()).unwrap();
+        assert_eq!(serialize(&list).unwrap(), data);
+
+        // the same chunks, with the length of the list in bytes mixed in
+        let wide = Vector::<u16, 20>::try_from_iter(
+            data.chunks(2).map(|pair| u16::from_le_bytes([pair[0], pair[1]])),
+        )
+        .unwrap();
+        let root = crate::merkleization::mix_in_length(&wide.hash_tree_root().unwrap(), 40);
+        assert_eq!(list.hash_tree_root().unwrap(), root);
+
+        list.as_mut_slice()[39] = 0;
+        assert_eq!(list.as_slice().last(), Some(&0));
+        assert_ne!(list.hash_tree_root().unwrap(), root);
+    }
+
+    #[test]
+    fn test_arrays() {
+        let signature = [9u8; 96];
+        let list = List::<u8, 128>::try_from(signature).unwrap();
+        assert_eq!(list.as_slice(), signature);
+        let (data, err) = List::<u8, 64>::try_from(signature).unwrap_err();
+        assert_eq!(data, signature);
+        assert!(matches!(err, Error::Instance(InstanceError::Bounded { bound: 64, provided: 96 })));
+
+        assert_eq!(<[u8; 96]>::try_from(list.clone()).unwrap(), signature);
+        let (list, err) = <[u8; 48]>::try_from(list).unwrap_err();
+        assert_eq!(list.len(), 96);
+        assert!(matches!(
+            err,
+            Error::Instance(InstanceError::Exact { required: 48, provided: 96 })
+        ));
+    }
+
     #[test]
     #[should_panic]
     fn test_illegal_serde() {
@@ -0,0 +1,215 @@
+use crate::merkleization::{
+    hash_tree_root_with_witness, Hasher, MerkleizationError, Merkleized, Node, WitnessTrace,
+    BYTES_PER_CHUNK,
+};
+use std::cell::RefCell;
+
+pub(crate) type HashFn = Box<dyn FnMut(&[u8], &[u8]) -> [u8; BYTES_PER_CHUNK]>;
+
+struct Backend {
+    hash: HashFn,
+    zero_hash: fn(usize) -> Node,
+}
+
+thread_local! {
+    static BACKEND: RefCell<Option<Backend>> = const { RefCell::new(None) };
+}
+
+pub(crate) fn is_active() -> bool {
+    BACKEND.with(|backend| backend.borrow().is_some())
+}
+
+// Hashes with whatever `Hasher` was installed for the current thread by
+// `hash_tree_root_with_hasher`, so that the merkleization routines shared by every type can
+// switch hash functions without each `Merkleized` implementation being made generic.
+#[derive(Default)]
+pub(crate) struct ScopedHasher;
+
+impl Hasher for ScopedHasher {
+    fn hash(&mut self, left: &[u8], right: &[u8]) -> [u8; BYTES_PER_CHUNK] {
+        BACKEND.with(|backend| {
+            let mut backend = backend.borrow_mut();
+            let backend = backend.as_mut().expect("hasher is installed");
+            (backend.hash)(left, right)
+        })
+    }
+
+    fn zero_hash(height: usize) -> Node {
+        let zero_hash = BACKEND.with(|backend| {
+            backend.borrow().as_ref().map(|backend| backend.zero_hash).expect("hasher is installed")
+        });
+        zero_hash(height)
+    }
+}
+
+/// Compute the hash tree root of `value` as [`Merkleized::hash_tree_root`] does, but combining
+/// nodes with `H` rather than SHA-256.
+///
+/// Values are chunked and packed exactly as for SSZ, so the result is a tree of the same shape
+/// that, for example, a circuit using an arithmetization-friendly `H` can verify cheaply.
+/// Caches (e.g. that of a [`CachedList`](crate::CachedList)) are bypassed and left untouched.
+///
+/// The hasher is scoped to the current thread. Calls may be nested, in which case the inner
+/// call uses its own hasher.
+pub fn hash_tree_root_with_hasher<H, T>(value: &T) -> Result<Node, MerkleizationError>
+where
+    H: Hasher + 'static,
+    T: Merkleized + ?Sized,
+{
+    let mut hasher = H::default();
+    with_hash_fn(Box::new(move |left, right| hasher.hash(left, right)), H::zero_hash, || {
+        value.hash_tree_root()
+    })
+}
+
+/// Compute the hash tree root of `value` with `H` as [`hash_tree_root_with_hasher`] does,
+/// recording every hash invocation as [`hash_tree_root_with_witness`] does.
+///
+/// The trace can be checked with [`WitnessTrace::verify_with_hasher`], e.g. before handing it
+/// to a circuit that re-computes it with `H`.
+pub fn hash_tree_root_with_hasher_and_witness<H, T>(
+    value: &T,
+) -> Result<WitnessTrace, MerkleizationError>
+where
+    H: Hasher + 'static,
+    T: Merkleized + ?Sized,
+{
+    let mut hasher = H::default();
+    with_hash_fn(Box::new(move |left, right| hasher.hash(left, right)), H::zero_hash, || {
+        hash_tree_root_with_witness(value)
+    })
+}
+
+// Restores the backend that was installed before it when dropped, so that a panic in `f`
+// (e.g. from a `Hasher` rejecting its input) does not leave the thread hashing with it.
+struct RestoreBackend(Option<Backend>);
+
+impl Drop for RestoreBackend {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        BACKEND.with(|current| current.replace(previous));
+    }
+}
+
+// Run `f` with `hash` installed as the hash function for the current thread.
+pub(crate) fn with_hash_fn<R>(
+    hash: HashFn,
+    zero_hash: fn(usize) -> Node,
+    f: impl FnOnce() -> R,
+) -> R {
+    let backend = Backend { hash, zero_hash };
+    let _restore = RestoreBackend(BACKEND.with(|current| current.replace(Some(backend))));
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        merkleization::{merkleize_with_hasher, mix_in_length_with_hasher, pack},
+        prelude::*,
+    };
+    use sha2::Sha256;
+
+    // Folds the two chunks together rather than hashing them, so results differ from SHA-256.
+    #[derive(Default)]
+    struct XorHasher;
+
+    impl Hasher for XorHasher {
+        fn hash(&mut self, left: &[u8], right: &[u8]) -> [u8; BYTES_PER_CHUNK] {
+            let mut out = [0u8; BYTES_PER_CHUNK];
+            for (i, byte) in out.iter_mut().enumerate() {
+                *byte = left[i].rotate_left(1) ^ right[i] ^ 0x5a;
+            }
+            out
+        }
+    }
+
+    #[derive(Default, Debug, SimpleSerialize)]
+    struct Foo {
+        a: u64,
+        b: List<u16, 32>,
+        c: Vector<bool, 4>,
+    }
+
+    #[test]
+    fn test_hash_tree_root_with_hasher() {
+        let list = List::<u64, 64>::try_from((0..9).collect::<Vec<u64>>()).unwrap();
+        let root = hash_tree_root_with_hasher::<XorHasher, _>(&list).unwrap();
+        let chunks = pack(&list).unwrap();
+        let data_root = merkleize_with_hasher::<XorHasher>(&chunks, Some(16)).unwrap();
+        assert_eq!(root, mix_in_length_with_hasher::<XorHasher>(&data_root, 9));
+        assert_ne!(root, list.hash_tree_root().unwrap());
+
+        let foo = Foo { a: 7, b: List::try_from(vec![1, 2, 3]).unwrap(), c: Default::default() };
+        assert_eq!(
+            hash_tree_root_with_hasher::<Sha256, _>(&foo).unwrap(),
+            foo.hash_tree_root().unwrap()
+        );
+        assert_ne!(
+            hash_tree_root_with_hasher::<XorHasher, _>(&foo).unwrap(),
+            foo.hash_tree_root().unwrap()
+        );
+        assert!(!is_active());
+    }
+
+    #[test]
+    fn test_witness_with_hasher() {
+        let foo = Foo { a: 7, b: List::try_from(vec![1, 2, 3]).unwrap(), c: Default::default() };
+        let trace = hash_tree_root_with_hasher_and_witness::<XorHasher, _>(&foo).unwrap();
+        assert_eq!(trace.root, hash_tree_root_with_hasher::<XorHasher, _>(&foo).unwrap());
+        assert!(trace.verify_with_hasher::<XorHasher>());
+        assert!(!trace.verify());
+
+        let trace = hash_tree_root_with_hasher_and_witness::<Sha256, _>(&foo).unwrap();
+        assert_eq!(trace, hash_tree_root_with_witness(&foo).unwrap());
+    }
+
+    #[test]
+    fn test_hash_tree_root_with_hasher_skips_cache() {
+        let list = List::<u64, 64>::try_from((0..9).collect::<Vec<u64>>()).unwrap();
+        let cached = CachedList::from(list.clone());
+        let expected = cached.hash_tree_root().unwrap();
+        assert_eq!(
+            hash_tree_root_with_hasher::<XorHasher, _>(&cached).unwrap(),
+            hash_tree_root_with_hasher::<XorHasher, _>(&list).unwrap()
+        );
+        assert_eq!(cached.hash_tree_root().unwrap(), expected);
+    }
+
+    #[derive(Default)]
+    struct PanickingHasher;
+
+    impl Hasher for PanickingHasher {
+        fn hash(&mut self, _: &[u8], _: &[u8]) -> [u8; BYTES_PER_CHUNK] {
+            panic!("hasher failed")
+        }
+    }
+
+    #[test]
+    fn test_hasher_is_uninstalled_on_panic() {
+        let foo = Foo { a: 7, b: List::try_from(vec![1, 2, 3]).unwrap(), c: Default::default() };
+        let expected = foo.hash_tree_root().unwrap();
+        let result = std::panic::catch_unwind(|| {
+            hash_tree_root_with_hasher::<PanickingHasher, _>(&foo).unwrap()
+        });
+        assert!(result.is_err());
+        assert!(!is_active());
+        assert_eq!(foo.hash_tree_root().unwrap(), expected);
+
+        // an outer hasher is reinstated after a panic in a nested call
+        let root = with_hash_fn(
+            Box::new(|left, right| XorHasher.hash(left, right)),
+            XorHasher::zero_hash,
+            || {
+                let result = std::panic::catch_unwind(|| {
+                    hash_tree_root_with_hasher::<PanickingHasher, _>(&foo).unwrap()
+                });
+                assert!(result.is_err());
+                foo.hash_tree_root().unwrap()
+            },
+        );
+        assert_eq!(root, hash_tree_root_with_hasher::<XorHasher, _>(&foo).unwrap());
+        assert!(!is_active());
+    }
+}
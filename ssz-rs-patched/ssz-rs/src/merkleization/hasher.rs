@@ -0,0 +1,235 @@
+use crate::merkleization::{zero_hash, Node, BYTES_PER_CHUNK};
+use sha2::{Digest, Sha256};
+#[cfg(feature = "keccak")]
+pub use sha3::Keccak256;
+
+/// A hash function used to combine sibling nodes when building Merkle trees.
+///
+/// SSZ specifies SHA-256, which is what all hash tree roots in this crate use. Implementing
+/// this trait allows merkleizing chunks and verifying proofs with another function, e.g.
+/// an accelerated SHA-256 or a hash that is cheap to prove inside a zkVM.
+pub trait Hasher: Default {
+    /// Return the hash of the concatenation `left || right` of two chunks.
+    fn hash(&mut self, left: &[u8], right: &[u8]) -> [u8; BYTES_PER_CHUNK];
+
+    /// Return the root of a tree of the given `height` with only "zero" leaves.
+    ///
+    /// The default implementation hashes `height` times, so implementors should override it
+    /// with a lookup into precomputed values where possible.
+    fn zero_hash(height: usize) -> Node {
+        let mut hasher = Self::default();
+        let mut node = Node::default();
+        for _ in 0..height {
+            node = Node::from(hasher.hash(&node, &node));
+        }
+        node
+    }
+}
+
+impl Hasher for Sha256 {
+    fn hash(&mut self, left: &[u8], right: &[u8]) -> [u8; BYTES_PER_CHUNK] {
+        self.update(left);
+        self.update(right);
+        self.finalize_reset().into()
+    }
+
+    fn zero_hash(height: usize) -> Node {
+        Node::from(*zero_hash(height))
+    }
+}
+
+// Roots built with Keccak-256 are not SSZ hash tree roots, but can be recomputed cheaply by
+// the EVM with the `KECCAK256` opcode.
+#[cfg(feature = "keccak")]
+impl Hasher for Keccak256 {
+    fn hash(&mut self, left: &[u8], right: &[u8]) -> [u8; BYTES_PER_CHUNK] {
+        self.update(left);
+        self.update(right);
+        self.finalize_reset().into()
+    }
+
+    #[cfg(feature = "std")]
+    fn zero_hash(height: usize) -> Node {
+        use crate::merkleization::MAX_MERKLE_TREE_DEPTH;
+        use std::sync::OnceLock;
+
+        static ZERO_HASHES: OnceLock<Vec<Node>> = OnceLock::new();
+        let zero_hashes = ZERO_HASHES.get_or_init(|| {
+            let mut hasher = Keccak256::default();
+            let mut zero_hashes = vec![Node::default()];
+            for height in 1..MAX_MERKLE_TREE_DEPTH {
+                let node = &zero_hashes[height - 1];
+                zero_hashes.push(Node::from(hasher.hash(node, node)));
+            }
+            zero_hashes
+        });
+        zero_hashes[height]
+    }
+}
+
+/// Poseidon over the BN254 scalar field, with the parameters used by circom, as an example of
+/// an arithmetization-friendly `Hasher`.
+///
+/// A chunk does not fit in a single field element, so each is split into two 16-byte halves
+/// and the node is the big-endian encoding of the Poseidon hash of the four halves of `left`
+/// and `right`. Roots built with it are *not* SSZ hash tree roots.
+#[cfg(feature = "poseidon")]
+pub struct Poseidon(light_poseidon::Poseidon<ark_bn254::Fr>);
+
+#[cfg(feature = "poseidon")]
+impl Default for Poseidon {
+    fn default() -> Self {
+        Self(
+            light_poseidon::Poseidon::<ark_bn254::Fr>::new_circom(4)
+                .expect("4 inputs are supported"),
+        )
+    }
+}
+
+#[cfg(feature = "poseidon")]
+impl Hasher for Poseidon {
+    fn hash(&mut self, left: &[u8], right: &[u8]) -> [u8; BYTES_PER_CHUNK] {
+        use light_poseidon::PoseidonBytesHasher;
+
+        let (left_high, left_low) = left.split_at(BYTES_PER_CHUNK / 2);
+        let (right_high, right_low) = right.split_at(BYTES_PER_CHUNK / 2);
+        self.0
+            .hash_bytes_be(&[left_high, left_low, right_high, right_low])
+            .expect("16-byte inputs are less than the modulus")
+    }
+
+    fn zero_hash(height: usize) -> Node {
+        use crate::merkleization::MAX_MERKLE_TREE_DEPTH;
+        use std::sync::OnceLock;
+
+        static ZERO_HASHES: OnceLock<Vec<Node>> = OnceLock::new();
+        let zero_hashes = ZERO_HASHES.get_or_init(|| {
+            let mut hasher = Poseidon::default();
+            let mut zero_hashes = vec![Node::default()];
+            for height in 1..MAX_MERKLE_TREE_DEPTH {
+                let node = &zero_hashes[height - 1];
+                zero_hashes.push(Node::from(hasher.hash(node, node)));
+            }
+            zero_hashes
+        });
+        zero_hashes[height]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkleization::{merkleize, merkleize_with_hasher, MAX_MERKLE_TREE_DEPTH};
+
+    // Folds the two chunks together rather than hashing them, to check the hasher is used.
+    #[derive(Default)]
+    struct XorHasher;
+
+    impl Hasher for XorHasher {
+        fn hash(&mut self, left: &[u8], right: &[u8]) -> [u8; BYTES_PER_CHUNK] {
+            let mut out = [0u8; BYTES_PER_CHUNK];
+            for (i, byte) in out.iter_mut().enumerate() {
+                *byte = left[i].rotate_left(1) ^ right[i];
+            }
+            out
+        }
+    }
+
+    #[test]
+    fn test_default_zero_hashes() {
+        for height in 0..MAX_MERKLE_TREE_DEPTH {
+            let mut hasher = Sha256::default();
+            let expected = Sha256::zero_hash(height);
+            let mut node = Node::default();
+            for _ in 0..height {
+                node = Node::from(hasher.hash(&node, &node));
+            }
+            assert_eq!(node, expected);
+        }
+    }
+
+    #[test]
+    fn test_merkleize_with_hasher() {
+        let chunks = (0..3 * BYTES_PER_CHUNK).map(|i| i as u8).collect::<Vec<_>>();
+        assert_eq!(
+            merkleize_with_hasher::<Sha256>(&chunks, Some(16)).unwrap(),
+            merkleize(&chunks, Some(16)).unwrap()
+        );
+
+        let root = merkleize_with_hasher::<XorHasher>(&chunks, Some(4)).unwrap();
+        let mut hasher = XorHasher;
+        let left = hasher.hash(&chunks[..32], &chunks[32..64]);
+        let right = hasher.hash(&chunks[64..], &[0u8; 32]);
+        assert_eq!(root, Node::from(hasher.hash(&left, &right)));
+    }
+
+    #[cfg(feature = "keccak")]
+    #[test]
+    fn test_merkleize_with_keccak() {
+        use crate::merkleization::mix_in_length_with_hasher;
+
+        let mut node = Node::default();
+        for height in 0..MAX_MERKLE_TREE_DEPTH {
+            assert_eq!(Keccak256::zero_hash(height), node);
+            let digest = Keccak256::new().chain_update(node).chain_update(node).finalize();
+            node = Node::try_from(digest.as_slice()).unwrap();
+        }
+
+        let chunks = (0..3 * BYTES_PER_CHUNK).map(|i| i as u8).collect::<Vec<_>>();
+        let root = merkleize_with_hasher::<Keccak256>(&chunks, Some(4)).unwrap();
+        let keccak = |left: &[u8], right: &[u8]| -> [u8; 32] {
+            Keccak256::new().chain_update(left).chain_update(right).finalize().into()
+        };
+        let left = keccak(&chunks[..32], &chunks[32..64]);
+        let right = keccak(&chunks[64..], &[0u8; 32]);
+        assert_eq!(root, Node::from(keccak(&left, &right)));
+        assert_ne!(root, merkleize(&chunks, Some(4)).unwrap());
+
+        let mut length = [0u8; 32];
+        length[0] = 3;
+        assert_eq!(
+            mix_in_length_with_hasher::<Keccak256>(&root, 3),
+            Node::from(keccak(&root, &length))
+        );
+    }
+
+    #[cfg(feature = "poseidon")]
+    #[test]
+    fn test_merkleize_with_poseidon() {
+        use crate::{
+            merkleization::{mix_in_length_with_hasher, pack},
+            prelude::*,
+            utils::decode_hex,
+        };
+
+        // the halves of `left` and `right` are the field elements 1 through 4, whose hash is
+        // a circomlibjs test vector
+        let mut left = [0u8; 32];
+        left[15] = 1;
+        left[31] = 2;
+        let mut right = [0u8; 32];
+        right[15] = 3;
+        right[31] = 4;
+        let expected =
+            decode_hex("0x299c867db6c1fdd79dcefa40e4510b9837e60ebb1ce0663dbaa525df65250465")
+                .unwrap();
+        let mut hasher = Poseidon::default();
+        assert_eq!(hasher.hash(&left, &right).as_slice(), expected);
+        assert_ne!(hasher.hash(&right, &left).as_slice(), expected);
+
+        let mut node = Node::default();
+        for height in 0..MAX_MERKLE_TREE_DEPTH {
+            assert_eq!(Poseidon::zero_hash(height), node);
+            node = Node::from(hasher.hash(&node, &node));
+        }
+
+        let list = List::<u64, 64>::try_from((0..9).collect::<Vec<u64>>()).unwrap();
+        let chunks = pack(&list).unwrap();
+        let data_root = merkleize_with_hasher::<Poseidon>(&chunks, Some(16)).unwrap();
+        assert_eq!(
+            hash_tree_root_with_hasher::<Poseidon, _>(&list).unwrap(),
+            mix_in_length_with_hasher::<Poseidon>(&data_root, 9)
+        );
+        assert_ne!(data_root, merkleize(&chunks, Some(16)).unwrap());
+    }
+}
@@ -0,0 +1,325 @@
+use crate::{
+    lib::*,
+    merkleization::{MerkleizationError, Merkleized, Node},
+};
+use std::cell::{Cell, RefCell};
+
+/// Counts of the work done while computing a hash tree root.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HashMetrics {
+    /// Invocations of the hash function, i.e. compressions of two nodes into their parent.
+    pub hashes: u64,
+    /// Bytes of serialized values packed into chunks, including any padding of the last chunk.
+    pub bytes_packed: u64,
+    /// Buffers allocated to hold chunks or a layer of a tree.
+    pub allocations: u64,
+}
+
+impl HashMetrics {
+    fn since(&self, earlier: &Self) -> Self {
+        Self {
+            hashes: self.hashes - earlier.hashes,
+            bytes_packed: self.bytes_packed - earlier.bytes_packed,
+            allocations: self.allocations - earlier.allocations,
+        }
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.hashes += other.hashes;
+        self.bytes_packed += other.bytes_packed;
+        self.allocations += other.allocations;
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Receives the metrics of each container merkleized by [`hash_tree_root_with_metrics`].
+///
+/// Any closure taking the name of a type and its metrics is an observer.
+pub trait MetricsObserver {
+    /// Called once for each call to `hash_tree_root` of a container of the type `type_name`,
+    /// innermost first, with the work done by that call excluding the containers within it.
+    fn on_hash_tree_root(&mut self, type_name: &'static str, metrics: &HashMetrics);
+}
+
+impl<F: FnMut(&'static str, &HashMetrics)> MetricsObserver for F {
+    fn on_hash_tree_root(&mut self, type_name: &'static str, metrics: &HashMetrics) {
+        self(type_name, metrics)
+    }
+}
+
+/// An observer summing the metrics reported for each type, e.g. over every root computed for a
+/// block.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MetricsByType(pub BTreeMap<&'static str, HashMetrics>);
+
+impl MetricsObserver for MetricsByType {
+    fn on_hash_tree_root(&mut self, type_name: &'static str, metrics: &HashMetrics) {
+        self.0.entry(type_name).or_default().add(metrics);
+    }
+}
+
+thread_local! {
+    static MEASURING: Cell<bool> = const { Cell::new(false) };
+    static COUNTS: Cell<HashMetrics> = const {
+        Cell::new(HashMetrics { hashes: 0, bytes_packed: 0, allocations: 0 })
+    };
+    // the metrics of the containers nested within each container being merkleized
+    static NESTED: RefCell<Vec<HashMetrics>> = const { RefCell::new(Vec::new()) };
+    static REPORTS: RefCell<Vec<(&'static str, HashMetrics)>> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) fn is_measuring() -> bool {
+    MEASURING.with(Cell::get)
+}
+
+fn count(update: impl FnOnce(&mut HashMetrics)) {
+    if !is_measuring() {
+        return
+    }
+    COUNTS.with(|counts| {
+        let mut metrics = counts.get();
+        update(&mut metrics);
+        counts.set(metrics);
+    });
+}
+
+pub(crate) fn record_hash() {
+    count(|metrics| metrics.hashes += 1);
+}
+
+pub(crate) fn record_packed(len: usize) {
+    count(|metrics| metrics.bytes_packed += len as u64);
+}
+
+pub(crate) fn record_allocation() {
+    count(|metrics| metrics.allocations += 1);
+}
+
+// Pops the frame `measure_as` pushed onto `NESTED` if its computation unwinds instead.
+struct PopNestedOnUnwind;
+
+impl Drop for PopNestedOnUnwind {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            NESTED.with(|nested| nested.borrow_mut().pop());
+        }
+    }
+}
+
+// Restores whether the thread is measuring when `hash_tree_root_with_metrics` returns, dropping
+// the reports made since `first_report` if its computation unwinds instead.
+struct RestoreMeasuring {
+    was_measuring: bool,
+    first_report: usize,
+}
+
+impl Drop for RestoreMeasuring {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            REPORTS.with(|reports| reports.borrow_mut().truncate(self.first_report));
+        }
+        MEASURING.with(|measuring| measuring.set(self.was_measuring));
+    }
+}
+
+// Run `f`, computing the hash tree root of a `T`, reporting its metrics under the name of `T`.
+// Reports with nothing to attribute are dropped if `skip_empty`.
+pub(crate) fn measure_as<T: ?Sized>(
+    skip_empty: bool,
+    f: impl FnOnce() -> Result<Node, MerkleizationError>,
+) -> Result<Node, MerkleizationError> {
+    let start = COUNTS.with(Cell::get);
+    NESTED.with(|nested| nested.borrow_mut().push(HashMetrics::default()));
+    let pop_on_unwind = PopNestedOnUnwind;
+    let result = f();
+    drop(pop_on_unwind);
+    let total = COUNTS.with(Cell::get).since(&start);
+    let nested = NESTED.with(|nested| {
+        let mut nested = nested.borrow_mut();
+        let inner = nested.pop().expect("pushed above");
+        if let Some(outer) = nested.last_mut() {
+            outer.add(&total);
+        }
+        inner
+    });
+    let own = total.since(&nested);
+    if !(skip_empty && own.is_empty()) {
+        REPORTS.with(|reports| reports.borrow_mut().push((core::any::type_name::<T>(), own)));
+    }
+    result
+}
+
+/// Compute the hash tree root of `value`, counting the work done along the way and reporting the
+/// share of it done for each container within `value` to `observer`.
+///
+/// Returns the root along with the metrics of the whole computation. Work done outside of any
+/// derived container, e.g. for a `T` that is a list, is reported under the name of `T`, so the
+/// reported metrics always sum to the total.
+///
+/// Metrics are gathered on the current thread, so with the `rayon` feature large collections are
+/// hashed serially while they are. Calls may be nested, in which case the outer call also counts
+/// and reports the work of the inner one.
+pub fn hash_tree_root_with_metrics<T: Merkleized + ?Sized>(
+    value: &T,
+    observer: &mut impl MetricsObserver,
+) -> Result<(Node, HashMetrics), MerkleizationError> {
+    let was_measuring = MEASURING.with(|measuring| measuring.replace(true));
+    let start = COUNTS.with(Cell::get);
+    let first_report = REPORTS.with(|reports| reports.borrow().len());
+    let restore = RestoreMeasuring { was_measuring, first_report };
+    let result = measure_as::<T>(true, || value.hash_tree_root());
+    let total = COUNTS.with(Cell::get).since(&start);
+    let reports = REPORTS.with(|reports| {
+        let mut reports = reports.borrow_mut();
+        if was_measuring {
+            reports[first_report..].to_vec()
+        } else {
+            core::mem::take(&mut *reports)
+        }
+    });
+    drop(restore);
+    for (type_name, metrics) in reports {
+        observer.on_hash_tree_root(type_name, &metrics);
+    }
+    Ok((result?, total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{merkleization::hash_tree_root_with_witness, prelude::*};
+
+    #[derive(Default, Debug, SimpleSerialize)]
+    struct Validator {
+        pubkey: Vector<u8, 48>,
+        effective_balance: u64,
+        slashed: bool,
+    }
+
+    #[derive(Default, Debug, SimpleSerialize)]
+    struct State {
+        slot: u64,
+        validators: List<Validator, 1024>,
+        balances: List<u64, 1024>,
+    }
+
+    fn state() -> State {
+        let validators = (0..5)
+            .map(|i| Validator { effective_balance: i, ..Default::default() })
+            .collect::<Vec<_>>();
+        State {
+            slot: 3,
+            validators: List::try_from(validators).unwrap(),
+            balances: List::try_from(vec![32; 5]).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_metrics_by_type() {
+        let state = state();
+        let mut by_type = MetricsByType::default();
+        let (root, total) = hash_tree_root_with_metrics(&state, &mut by_type).unwrap();
+        assert_eq!(root, state.hash_tree_root().unwrap());
+        assert_eq!(total.hashes, hash_tree_root_with_witness(&state).unwrap().len() as u64);
+
+        let mut sum = HashMetrics::default();
+        for metrics in by_type.0.values() {
+            sum.add(metrics);
+        }
+        assert_eq!(sum, total);
+
+        let validator = by_type.0[core::any::type_name::<Validator>()];
+        // per validator, two chunks of the public key and a tree of four leaves
+        assert_eq!(validator.hashes, 5 * (1 + 3));
+        assert_eq!(validator.bytes_packed, 5 * 64);
+        let state = by_type.0[core::any::type_name::<State>()];
+        assert_eq!(state.bytes_packed, 64);
+        assert!(state.allocations > 0);
+        assert_eq!(by_type.0.len(), 2);
+    }
+
+    #[test]
+    fn test_work_outside_containers() {
+        let validators = state().validators;
+        let mut reports = vec![];
+        let mut observer = |type_name: &'static str, metrics: &HashMetrics| {
+            reports.push((type_name, *metrics));
+        };
+        let (_, total) = hash_tree_root_with_metrics(&validators, &mut observer).unwrap();
+        assert_eq!(reports.len(), 6);
+        let (type_name, list) = reports.pop().unwrap();
+        assert_eq!(type_name, core::any::type_name::<List<Validator, 1024>>());
+        // 3 + 2 + 1 hashes over the 5 roots, 7 more up to 1024 leaves and 1 to mix in the length
+        assert_eq!(list.hashes, 6 + 7 + 1);
+        assert_eq!(total.hashes, list.hashes + 5 * 4);
+        assert!(!is_measuring());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_metrics_with_par_hash_tree_roots() {
+        use crate::merkleization::{merkleize, par_hash_tree_roots};
+
+        // hashes its elements with `par_hash_tree_roots`, as e.g. a registry of validators might
+        #[derive(Default, Debug, SimpleSerialize)]
+        struct Registry {
+            validators: List<Validator, 1024>,
+        }
+
+        struct ParRegistry(Registry);
+
+        impl Merkleized for ParRegistry {
+            fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
+                let roots = par_hash_tree_roots(&self.0.validators)?;
+                let chunks =
+                    roots.iter().flat_map(|root| root.as_ref().to_vec()).collect::<Vec<_>>();
+                merkleize(&chunks, None)
+            }
+        }
+
+        let validators = (0..200)
+            .map(|i| Validator { effective_balance: i, ..Default::default() })
+            .collect::<Vec<_>>();
+        let registry = ParRegistry(Registry { validators: List::try_from(validators).unwrap() });
+        let mut by_type = MetricsByType::default();
+        let (_, total) = hash_tree_root_with_metrics(&registry, &mut by_type).unwrap();
+        let validator = by_type.0[core::any::type_name::<Validator>()];
+        assert_eq!(validator.hashes, 200 * (1 + 3));
+        // 100 + 50 + 25 + 13 + 7 + 4 + 2 + 1 hashes over the 200 roots, padding with zero hashes
+        assert_eq!(total.hashes, validator.hashes + 202);
+        assert!(!is_measuring());
+    }
+
+    #[test]
+    fn test_metrics_are_unwound_on_panic() {
+        // panics while merkleizing a container, after the containers before it are reported
+        struct Panicking(State);
+
+        impl Merkleized for Panicking {
+            fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
+                self.0.hash_tree_root()?;
+                measure_as::<State>(false, || panic!("cannot merkleize"))
+            }
+        }
+
+        let value = Panicking(state());
+        let result = std::panic::catch_unwind(|| {
+            hash_tree_root_with_metrics(&value, &mut MetricsByType::default())
+        });
+        assert!(result.is_err());
+        assert!(!is_measuring());
+        assert!(NESTED.with(|nested| nested.borrow().is_empty()));
+        assert!(REPORTS.with(|reports| reports.borrow().is_empty()));
+
+        // later calls on the thread are unaffected
+        let mut by_type = MetricsByType::default();
+        let (_, total) = hash_tree_root_with_metrics(&value.0, &mut by_type).unwrap();
+        let (_, expected) =
+            hash_tree_root_with_metrics(&state(), &mut MetricsByType::default()).unwrap();
+        assert_eq!(total, expected);
+        assert_eq!(by_type.0.len(), 2);
+    }
+}
@@ -1,5 +1,24 @@
+#[cfg(feature = "std")]
+mod backend;
+mod hasher;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "no-alloc")]
+mod no_alloc;
 mod node;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "std")]
+mod progress;
 mod proofs;
+mod sha256;
+#[cfg(feature = "simd")]
+mod simd;
+mod sparse;
+mod stream;
+mod tree;
+#[cfg(feature = "std")]
+mod witness;
 
 use crate::{
     lib::*,
@@ -7,8 +26,83 @@ use crate::{
 };
 use sha2::{Digest, Sha256};
 
+#[cfg(feature = "std")]
+pub(crate) use backend::is_active as has_scoped_hasher;
+#[cfg(feature = "std")]
+pub use backend::{hash_tree_root_with_hasher, hash_tree_root_with_hasher_and_witness};
+pub use hasher::Hasher;
+#[cfg(feature = "keccak")]
+pub use hasher::Keccak256;
+#[cfg(feature = "poseidon")]
+pub use hasher::Poseidon;
+#[cfg(feature = "metrics")]
+pub use metrics::{hash_tree_root_with_metrics, HashMetrics, MetricsByType, MetricsObserver};
+#[cfg(feature = "no-alloc")]
+pub use no_alloc::{merkleize_bytes_into, merkleize_into, pack_bytes_into, packed_len};
 pub use node::Node;
-pub use proofs::is_valid_merkle_branch;
+#[cfg(feature = "rayon")]
+pub use parallel::{par_hash_tree_roots, par_hash_tree_roots_into};
+#[cfg(feature = "std")]
+pub use progress::{hash_tree_root_with_progress, Progress, ProgressTracker};
+pub use proofs::{
+    extend_branch, format_path, get_helper_indices, is_valid_merkle_branch,
+    is_valid_merkle_branch_with_hasher, parse_path, verify_multiproof,
+    verify_multiproof_with_hasher, verify_proofs, verify_proofs_with_hasher, Branch,
+    GeneralizedIndex, Multiproof, Path, PathElement, Proof, Prove,
+};
+pub(crate) use proofs::{prove_basic, prove_bit, prove_element};
+pub use sparse::{SparseChunks, SparseMerkleTree};
+pub use stream::MerkleizerStream;
+pub use tree::MerkleTree;
+#[cfg(feature = "std")]
+pub use witness::{hash_tree_root_diff, hash_tree_root_with_witness, HashStep, WitnessTrace};
+
+fn record_hash(left: &[u8], right: &[u8], parent: &[u8]) {
+    // witness capture relies on thread-local state, so is only available with `std`
+    #[cfg(feature = "std")]
+    witness::record_hash(left, right, parent);
+    #[cfg(not(feature = "std"))]
+    let _ = (left, right, parent);
+    #[cfg(feature = "metrics")]
+    metrics::record_hash();
+}
+
+#[cfg(feature = "metrics")]
+pub(crate) use metrics::record_allocation;
+#[cfg(feature = "metrics")]
+use metrics::record_packed;
+
+#[cfg(not(feature = "metrics"))]
+#[inline(always)]
+fn record_packed(_: usize) {}
+
+#[cfg(not(feature = "metrics"))]
+#[inline(always)]
+pub(crate) fn record_allocation() {}
+
+/// Compute the hash tree root of a container of type `T` with `f`, attributing the work done to
+/// `T` while metrics are gathered with the `metrics` feature.
+#[inline]
+pub fn measure_hash_tree_root<T: ?Sized>(
+    f: impl FnOnce() -> Result<Node, MerkleizationError>,
+) -> Result<Node, MerkleizationError> {
+    #[cfg(feature = "metrics")]
+    if metrics::is_measuring() {
+        return metrics::measure_as::<T>(false, f)
+    }
+    f()
+}
+
+// Whether hashes must all be made on the current thread, as witness traces and metrics are
+// gathered per thread.
+#[cfg(feature = "rayon")]
+pub(crate) fn is_observed() -> bool {
+    #[cfg(feature = "metrics")]
+    if metrics::is_measuring() {
+        return true
+    }
+    witness::is_recording()
+}
 
 pub(crate) const BYTES_PER_CHUNK: usize = 32;
 pub(crate) const BITS_PER_CHUNK: usize = BYTES_PER_CHUNK * (crate::BITS_PER_BYTE as usize);
@@ -16,7 +110,7 @@ pub(crate) const BITS_PER_CHUNK: usize = BYTES_PER_CHUNK * (crate::BITS_PER_BYTE
 /// A `Merkleized` type provides a "hash tree root" following the SSZ spec.
 pub trait Merkleized {
     /// Compute the "hash tree root" of `Self`.
-    fn hash_tree_root(&mut self) -> Result<Node, MerkleizationError>;
+    fn hash_tree_root(&self) -> Result<Node, MerkleizationError>;
 
     /// Indicate the "composite" nature of `Self`.
     fn is_composite_type() -> bool {
@@ -33,6 +127,17 @@ pub enum MerkleizationError {
     InputExceedsLimit(usize),
     /// Proof verification failed
     InvalidProof,
+    /// A Merkle branch does not have one witness for each level of the given depth
+    InvalidBranchLength { depth: usize, length: usize },
+    /// The witness at the given position of a Merkle branch (counting from the leaf) is not a
+    /// valid node
+    InvalidWitness(usize),
+    /// The root computed from a Merkle branch is not the expected one
+    RootMismatch { expected: Node, computed: Node },
+    /// A path element does not exist in the type being proven
+    InvalidPathElement(PathElement),
+    /// A path string could not be parsed, at the given byte offset
+    InvalidPathSyntax(usize),
 }
 
 impl From<SerializeError> for MerkleizationError {
@@ -49,6 +154,21 @@ impl Display for MerkleizationError {
             }
             Self::InputExceedsLimit(size) => write!(f, "data exceeds the declared limit {size}"),
             Self::InvalidProof => write!(f, "merkle proof verification failed"),
+            Self::InvalidBranchLength { depth, length } => {
+                write!(f, "merkle branch for depth {depth} has {length} witnesses")
+            }
+            Self::InvalidWitness(position) => {
+                write!(f, "witness at position {position} of merkle branch is not a valid node")
+            }
+            Self::RootMismatch { expected, computed } => {
+                write!(f, "merkle branch leads to root {computed} but expected {expected}")
+            }
+            Self::InvalidPathElement(element) => {
+                write!(f, "path element {element:?} is not valid for this type")
+            }
+            Self::InvalidPathSyntax(position) => {
+                write!(f, "invalid path syntax at position {position}")
+            }
         }
     }
 }
@@ -66,6 +186,7 @@ pub fn pack_bytes(buffer: &mut Vec<u8>) {
         let bytes_to_pad = BYTES_PER_CHUNK - incomplete_chunk_len;
         buffer.resize(buffer.len() + bytes_to_pad, 0);
     }
+    record_packed(buffer.len());
 }
 
 // Packs serializations of `values` into the return buffer with the
@@ -74,21 +195,35 @@ pub fn pack<T>(values: &[T]) -> Result<Vec<u8>, MerkleizationError>
 where
     T: Serialize,
 {
+    record_allocation();
     let mut buffer = vec![];
-    for value in values {
-        value.serialize(&mut buffer)?;
-    }
+    T::serialize_fixed_elements(values, &mut buffer)?;
     pack_bytes(&mut buffer);
     Ok(buffer)
 }
 
-fn hash_nodes(hasher: &mut Sha256, a: &[u8], b: &[u8], out: &mut [u8]) {
-    hasher.update(a);
-    hasher.update(b);
-    out.copy_from_slice(&hasher.finalize_reset());
+pub(crate) fn hash_nodes<H: Hasher>(hasher: &mut H, a: &[u8], b: &[u8], out: &mut [u8]) {
+    let digest = hasher.hash(a, b);
+    record_hash(a, b, &digest);
+    out.copy_from_slice(&digest);
+}
+
+/// Return the hash of the concatenation of two chunks, the parent of `left` and `right` in
+/// an SSZ Merkle tree.
+///
+/// Panics if either input is not exactly `32` bytes.
+pub fn hash_chunks(left: impl AsRef<[u8]>, right: impl AsRef<[u8]>) -> Node {
+    let (left, right) = (left.as_ref(), right.as_ref());
+    assert_eq!(left.len(), BYTES_PER_CHUNK, "left input is one chunk");
+    assert_eq!(right.len(), BYTES_PER_CHUNK, "right input is one chunk");
+    let mut parent = Node::default();
+    hash_nodes(&mut Sha256::new(), left, right, &mut parent);
+    parent
 }
 
-const MAX_MERKLE_TREE_DEPTH: usize = 64;
+/// The greatest depth of a tree whose root can be computed, which is one more than the
+/// greatest height accepted by [`zero_hash`].
+pub const MAX_MERKLE_TREE_DEPTH: usize = 64;
 
 #[derive(Debug)]
 struct Context {
@@ -103,8 +238,44 @@ impl Index<usize> for Context {
     }
 }
 
-// Grab the precomputed context from the build stage
-include!(concat!(env!("OUT_DIR"), "/context.rs"));
+const fn compute_zero_hashes() -> [u8; MAX_MERKLE_TREE_DEPTH * BYTES_PER_CHUNK] {
+    let mut buffer = [0u8; MAX_MERKLE_TREE_DEPTH * BYTES_PER_CHUNK];
+    let mut node = [0u8; BYTES_PER_CHUNK];
+    let mut height = 1;
+    while height < MAX_MERKLE_TREE_DEPTH {
+        node = sha256::hash_pair(&node, &node);
+        let mut i = 0;
+        while i < BYTES_PER_CHUNK {
+            buffer[height * BYTES_PER_CHUNK + i] = node[i];
+            i += 1;
+        }
+        height += 1;
+    }
+    buffer
+}
+
+// The roots of "zero" subtrees are computed during constant evaluation, so the table is
+// embedded in the binary without needing a build script.
+static CONTEXT: Context = Context { zero_hashes: compute_zero_hashes() };
+
+/// Return the root of a tree of the given `height` with only "zero" leaves, from a table
+/// computed at compile time.
+///
+/// Panics if `height >= MAX_MERKLE_TREE_DEPTH`.
+///
+/// For example, the branch of any leaf of an empty tree is just these roots:
+///
+/// ```
+/// # use ssz_rs::prelude::*;
+/// assert_eq!(zero_hash(1), hash_chunks(zero_hash(0), zero_hash(0)).as_ref());
+///
+/// let branch = (0..8).map(zero_hash).collect::<Vec<_>>();
+/// let root = Node::from(*zero_hash(8));
+/// assert!(is_valid_merkle_branch(Node::default(), &branch, 8, 42, root).is_ok());
+/// ```
+pub fn zero_hash(height: usize) -> &'static [u8; BYTES_PER_CHUNK] {
+    CONTEXT[height].try_into().expect("is one chunk")
+}
 
 /// Return the root of the Merklization of a binary tree formed from `chunks`.
 ///
@@ -123,7 +294,7 @@ include!(concat!(env!("OUT_DIR"), "/context.rs"));
 /// Invariant: `leaf_count.next_power_of_two() == leaf_count`
 /// Invariant: `leaf_count != 0`
 /// Invariant: `leaf_count.trailing_zeros() < MAX_MERKLE_TREE_DEPTH`
-fn merkleize_chunks_with_virtual_padding(
+fn merkleize_chunks_with_virtual_padding<H: Hasher>(
     chunks: &[u8],
     leaf_count: usize,
 ) -> Result<Node, MerkleizationError> {
@@ -141,18 +312,20 @@ fn merkleize_chunks_with_virtual_padding(
         let depth = height - 1;
         // SAFETY: index is safe while depth == leaf_count.trailing_zeros() < MAX_MERKLE_TREE_DEPTH;
         // qed
-        return Ok(CONTEXT[depth as usize].try_into().expect("can produce a single root chunk"))
+        return Ok(H::zero_hash(depth as usize))
     }
 
+    record_allocation();
     let mut layer = chunks.to_vec();
     // SAFETY: checked subtraction is unnecessary, as we return early when chunk_count == 0; qed
     let mut last_index = chunk_count - 1;
-    let mut hasher = Sha256::new();
+    let mut hasher = H::default();
     // for each layer of the tree, starting from the bottom and walking up to the root:
     for k in (1..height).rev() {
         // for each pair of nodes in this layer:
         for i in (0..2usize.pow(k)).step_by(2) {
             let parent_index = i / 2;
+            let zero: Node;
             let (parent, left, right) = match i.cmp(&last_index) {
                 Ordering::Less => {
                     // SAFETY: index is safe because (i+1)*BYTES_PER_CHUNK < layer.len():
@@ -195,8 +368,8 @@ fn merkleize_chunks_with_virtual_padding(
                     // SAFETY: index is safe because depth < CONTEXT.len():
                     // depth <= height - 1 == leaf_count.trailing_zeros()
                     // leaf_count.trailing_zeros() < MAX_MERKLE_TREE_DEPTH == CONTEXT.len(); qed
-                    let right = &CONTEXT[depth as usize];
-                    (parent, left, right)
+                    zero = H::zero_hash(depth as usize);
+                    (parent, left, zero.as_ref())
                 }
                 _ => break,
             };
@@ -204,9 +377,9 @@ fn merkleize_chunks_with_virtual_padding(
                 // NOTE: nodes share memory here and so we can't use the `hash_nodes` utility
                 // as the disjunct nature is reflect in that functions type signature
                 // so instead we will just replicate here.
-                hasher.update(&left);
-                hasher.update(right);
-                left.copy_from_slice(&hasher.finalize_reset());
+                let digest = hasher.hash(left, right);
+                record_hash(left, right, &digest);
+                left.copy_from_slice(&digest);
             } else {
                 // SAFETY: index is safe because parent.len() % BYTES_PER_CHUNK == 0 and
                 // parent isn't empty; qed
@@ -223,47 +396,212 @@ fn merkleize_chunks_with_virtual_padding(
     Ok(layer[..BYTES_PER_CHUNK].try_into().expect("can produce a single root chunk"))
 }
 
+/// Return the root of the perfect binary tree with `chunks` as its leaves, without allocating.
+/// `chunks` is used as scratch space and so is overwritten.
+///
+/// Callers must ensure the number of chunks is a power of two, padding with "zero" chunks as
+/// needed; this yields the same root as [`merkleize`] with no `limit`.
+pub fn merkleize_in_place(chunks: &mut [u8]) -> Node {
+    #[cfg(feature = "std")]
+    if backend::is_active() {
+        return merkleize_in_place_with_hasher::<backend::ScopedHasher>(chunks)
+    }
+    merkleize_in_place_with_hasher::<Sha256>(chunks)
+}
+
+fn merkleize_in_place_with_hasher<H: Hasher>(chunks: &mut [u8]) -> Node {
+    debug_assert!((chunks.len() / BYTES_PER_CHUNK).is_power_of_two());
+    let mut hasher = H::default();
+    let mut count = chunks.len() / BYTES_PER_CHUNK;
+    while count > 1 {
+        for i in 0..count / 2 {
+            let mut parent = [0u8; BYTES_PER_CHUNK];
+            let children = &chunks[2 * i * BYTES_PER_CHUNK..(2 * i + 2) * BYTES_PER_CHUNK];
+            let (left, right) = children.split_at(BYTES_PER_CHUNK);
+            hash_nodes(&mut hasher, left, right, &mut parent);
+            chunks[i * BYTES_PER_CHUNK..(i + 1) * BYTES_PER_CHUNK].copy_from_slice(&parent);
+        }
+        count /= 2;
+    }
+    chunks[..BYTES_PER_CHUNK].try_into().expect("can produce a single root chunk")
+}
+
+/// Chunk count from which the `simd` feature hashes the tree a whole layer at a time.
+#[cfg(feature = "simd")]
+const SIMD_MERKLEIZATION_THRESHOLD: usize = 16;
+
+// Hash each pair of nodes in `children` into the corresponding node of `parents`.
+#[cfg(all(feature = "rayon", not(feature = "simd")))]
+fn hash_layer(children: &[u8], parents: &mut [u8]) {
+    let mut hasher = Sha256::new();
+    for (children, parent) in
+        children.chunks_exact(2 * BYTES_PER_CHUNK).zip(parents.chunks_exact_mut(BYTES_PER_CHUNK))
+    {
+        let (left, right) = children.split_at(BYTES_PER_CHUNK);
+        hash_nodes(&mut hasher, left, right, parent);
+    }
+}
+
+#[cfg(feature = "simd")]
+use simd::hash_layer;
+
+// Same contract as `merkleize_chunks_with_virtual_padding` but each layer of the tree is
+// materialized in full so that `hash_layer` can compute all of its parents in one call.
+// Requires `chunks` to be non-empty.
+#[cfg(any(feature = "rayon", feature = "simd"))]
+fn merkleize_chunks_by_layer(
+    chunks: &[u8],
+    leaf_count: usize,
+    hash_layer: impl Fn(&[u8], &mut [u8]),
+) -> Node {
+    let height = leaf_count.trailing_zeros() as usize;
+    record_allocation();
+    let mut layer = chunks.to_vec();
+    for depth in 0..height {
+        if layer.len() == BYTES_PER_CHUNK {
+            // only the leftmost node is "real", so the rest of the way up is hashing with
+            // "zero" subtrees
+            let mut hasher = Sha256::new();
+            let mut root = [0u8; BYTES_PER_CHUNK];
+            for depth in depth..height {
+                hash_nodes(&mut hasher, &layer, &CONTEXT[depth], &mut root);
+                layer.copy_from_slice(&root);
+            }
+            break
+        }
+        if (layer.len() / BYTES_PER_CHUNK) & 1 == 1 {
+            layer.extend_from_slice(&CONTEXT[depth]);
+        }
+        record_allocation();
+        let mut parents = vec![0u8; layer.len() / 2];
+        hash_layer(&layer, &mut parents);
+        layer = parents;
+    }
+    layer[..BYTES_PER_CHUNK].try_into().expect("can produce a single root chunk")
+}
+
+fn leaf_count_for(chunk_count: usize, limit: Option<usize>) -> Result<usize, MerkleizationError> {
+    match limit {
+        Some(limit) if limit < chunk_count => Err(MerkleizationError::InputExceedsLimit(limit)),
+        Some(limit) => Ok(limit.next_power_of_two()),
+        None => Ok(chunk_count.next_power_of_two()),
+    }
+}
+
 // Return the root of the Merklization of a binary tree formed from `chunks`.
 // Invariant: `chunks.len() % BYTES_PER_CHUNK == 0`
 pub fn merkleize(chunks: &[u8], limit: Option<usize>) -> Result<Node, MerkleizationError> {
     debug_assert!(chunks.len() % BYTES_PER_CHUNK == 0);
     let chunk_count = chunks.len() / BYTES_PER_CHUNK;
-    let mut leaf_count = chunk_count.next_power_of_two();
-    if let Some(limit) = limit {
-        if limit < chunk_count {
-            return Err(MerkleizationError::InputExceedsLimit(limit))
-        }
-        leaf_count = limit.next_power_of_two();
+    let leaf_count = leaf_count_for(chunk_count, limit)?;
+    // NOTE: a hasher installed by `hash_tree_root_with_hasher` takes precedence over any
+    // accelerated SHA-256
+    #[cfg(feature = "std")]
+    if backend::is_active() {
+        return merkleize_with_hasher::<backend::ScopedHasher>(chunks, limit)
+    }
+    if let Some(root) = sparse::merkleize_if_sparse::<Sha256>(chunks, leaf_count) {
+        return Ok(root)
+    }
+    // NOTE: hash serially while a witness or metrics are gathered for this thread
+    #[cfg(feature = "rayon")]
+    if chunk_count >= parallel::PARALLEL_MERKLEIZATION_THRESHOLD && !is_observed() {
+        return Ok(parallel::merkleize_chunks_in_parallel(chunks, leaf_count))
+    }
+    #[cfg(feature = "simd")]
+    if chunk_count >= SIMD_MERKLEIZATION_THRESHOLD {
+        return Ok(merkleize_chunks_by_layer(chunks, leaf_count, hash_layer))
     }
-    merkleize_chunks_with_virtual_padding(chunks, leaf_count)
+    merkleize_chunks_with_virtual_padding::<Sha256>(chunks, leaf_count)
 }
 
-fn mix_in_decoration(root: &Node, mut decoration: usize) -> Node {
+/// Return the root of the Merklization of a binary tree formed from `chunks`, as `merkleize`
+/// does, but combining nodes with `H` rather than SHA-256.
+///
+/// Invariant: `chunks.len() % BYTES_PER_CHUNK == 0`
+pub fn merkleize_with_hasher<H: Hasher>(
+    chunks: &[u8],
+    limit: Option<usize>,
+) -> Result<Node, MerkleizationError> {
+    let leaf_count = leaf_count_for(chunks.len() / BYTES_PER_CHUNK, limit)?;
+    if let Some(root) = sparse::merkleize_if_sparse::<H>(chunks, leaf_count) {
+        return Ok(root)
+    }
+    merkleize_chunks_with_virtual_padding::<H>(chunks, leaf_count)
+}
+
+fn mix_in_decoration<H: Hasher>(root: &Node, decoration: usize) -> Node {
     let decoration_data = decoration.hash_tree_root().expect("can merkleize usize");
 
-    let mut hasher = Sha256::new();
-    let mut output = vec![0u8; BYTES_PER_CHUNK];
+    let mut hasher = H::default();
+    let mut output = zeroed_chunks(1);
     hash_nodes(&mut hasher, root.as_ref(), decoration_data.as_ref(), &mut output);
     output.as_slice().try_into().expect("can extract root")
 }
 
-pub(crate) fn mix_in_length(root: &Node, length: usize) -> Node {
-    mix_in_decoration(root, length)
+/// Mix the `length` of a collection into the `root` of its data, giving the hash tree root of
+/// a list or bitlist.
+pub fn mix_in_length(root: &Node, length: usize) -> Node {
+    #[cfg(feature = "std")]
+    if backend::is_active() {
+        return mix_in_decoration::<backend::ScopedHasher>(root, length)
+    }
+    mix_in_decoration::<Sha256>(root, length)
+}
+
+/// Mix the `length` of a collection into the `root` of its data, combining them with `H`.
+pub fn mix_in_length_with_hasher<H: Hasher>(root: &Node, length: usize) -> Node {
+    mix_in_decoration::<H>(root, length)
 }
 
 pub fn mix_in_selector(root: &Node, selector: usize) -> Node {
-    mix_in_decoration(root, selector)
+    #[cfg(feature = "std")]
+    if backend::is_active() {
+        return mix_in_decoration::<backend::ScopedHasher>(root, selector)
+    }
+    mix_in_decoration::<Sha256>(root, selector)
 }
 
-pub(crate) fn elements_to_chunks<'a, T: Merkleized + 'a>(
-    elements: impl Iterator<Item = (usize, &'a mut T)>,
-    count: usize,
+/// Compute the hash tree root of each of `values`.
+///
+/// With the `rayon` feature, `par_hash_tree_roots` spreads the work across a thread pool.
+pub fn hash_tree_roots<T: Merkleized>(values: &[T]) -> Result<Vec<Node>, MerkleizationError> {
+    let mut roots = Vec::new();
+    hash_tree_roots_into(values, &mut roots)?;
+    Ok(roots)
+}
+
+/// Compute the hash tree root of each of `values` into `roots`, replacing its contents.
+///
+/// Reusing `roots` across calls, e.g. for each new state seen by an indexer, avoids allocating
+/// a buffer for the roots every time.
+pub fn hash_tree_roots_into<T: Merkleized>(
+    values: &[T],
+    roots: &mut Vec<Node>,
+) -> Result<(), MerkleizationError> {
+    roots.clear();
+    roots.resize(values.len(), Node::default());
+    for (root, value) in roots.iter_mut().zip(values) {
+        *root = value.hash_tree_root()?;
+    }
+    Ok(())
+}
+
+/// Allocate a buffer of `count` chunks of zeroes, e.g. to write the roots of the fields of a
+/// container into.
+pub fn zeroed_chunks(count: usize) -> Vec<u8> {
+    record_allocation();
+    vec![0u8; count * BYTES_PER_CHUNK]
+}
+
+// Write the hash tree root of each element of a composite collection into one buffer, giving
+// the leaves of its tree.
+pub(crate) fn elements_to_chunks<T: Merkleized>(
+    elements: &[T],
 ) -> Result<Vec<u8>, MerkleizationError> {
-    let mut chunks = vec![0u8; count * BYTES_PER_CHUNK];
-    for (i, elem) in elements {
-        let chunk = elem.hash_tree_root()?;
-        let range = i * BYTES_PER_CHUNK..(i + 1) * BYTES_PER_CHUNK;
-        chunks[range].copy_from_slice(chunk.as_ref());
+    let mut chunks = zeroed_chunks(elements.len());
+    for (chunk, element) in chunks.chunks_exact_mut(BYTES_PER_CHUNK).zip(elements) {
+        chunk.copy_from_slice(element.hash_tree_root()?.as_ref());
     }
     Ok(chunks)
 }
@@ -415,40 +753,82 @@ mod tests {
     #[test]
     fn test_merkleize_chunks() {
         let chunks = vec![1u8; 3 * BYTES_PER_CHUNK];
-        let root = merkleize_chunks_with_virtual_padding(&chunks, 4).expect("can merkleize");
+        let root =
+            merkleize_chunks_with_virtual_padding::<Sha256>(&chunks, 4).expect("can merkleize");
         assert_eq!(
             root.as_ref(),
             hex!("65aa94f2b59e517abd400cab655f42821374e433e41b8fe599f6bb15484adcec")
         );
 
         let chunks = vec![1u8; 5 * BYTES_PER_CHUNK];
-        let root = merkleize_chunks_with_virtual_padding(&chunks, 8).expect("can merkleize");
+        let root =
+            merkleize_chunks_with_virtual_padding::<Sha256>(&chunks, 8).expect("can merkleize");
         assert_eq!(
             root.as_ref(),
             hex!("0ae67e34cba4ad2bbfea5dc39e6679b444021522d861fab00f05063c54341289")
         );
 
         let chunks = vec![1u8; 6 * BYTES_PER_CHUNK];
-        let root = merkleize_chunks_with_virtual_padding(&chunks, 8).expect("can merkleize");
+        let root =
+            merkleize_chunks_with_virtual_padding::<Sha256>(&chunks, 8).expect("can merkleize");
         assert_eq!(
             root.as_ref(),
             hex!("0ef7df63c204ef203d76145627b8083c49aa7c55ebdee2967556f55a4f65a238")
         );
     }
 
+    #[test]
+    fn test_hash_chunks_and_zero_hashes() {
+        let left = Node::from([1u8; 32]);
+        let right = Node::from([2u8; 32]);
+        let mut expected = Node::default();
+        hash_nodes(&mut Sha256::new(), &left, &right, &mut expected);
+        assert_eq!(hash_chunks(left, right), expected);
+
+        assert_eq!(zero_hash(0), &[0u8; 32]);
+        for height in 1..MAX_MERKLE_TREE_DEPTH {
+            let child = zero_hash(height - 1);
+            assert_eq!(hash_chunks(child, child), Node::from(*zero_hash(height)));
+        }
+    }
+
+    #[test]
+    fn test_merkleize_in_place() {
+        for count in [1, 2, 4, 8, 16] {
+            let chunks = (0..count * BYTES_PER_CHUNK).map(|i| i as u8).collect::<Vec<_>>();
+            let expected = merkleize(&chunks, None).expect("can merkleize");
+            let mut scratch = chunks.clone();
+            assert_eq!(merkleize_in_place(&mut scratch), expected);
+        }
+
+        #[derive(Debug, SimpleSerialize)]
+        struct Foo {
+            a: u64,
+            b: Node,
+            c: bool,
+        }
+
+        let foo = Foo { a: 7, b: Node::try_from([3u8; 32].as_ref()).unwrap(), c: true };
+        let mut chunks = vec![0u8; 3 * BYTES_PER_CHUNK];
+        chunks[..8].copy_from_slice(&7u64.to_le_bytes());
+        chunks[BYTES_PER_CHUNK..2 * BYTES_PER_CHUNK].copy_from_slice(&[3u8; 32]);
+        chunks[2 * BYTES_PER_CHUNK] = 1;
+        assert_eq!(foo.hash_tree_root().unwrap(), merkleize(&chunks, None).unwrap());
+    }
+
     #[test]
     fn test_merkleize_chunks_with_many_virtual_nodes() {
         let chunks = vec![1u8; 5 * BYTES_PER_CHUNK];
-        let root =
-            merkleize_chunks_with_virtual_padding(&chunks, 2usize.pow(10)).expect("can merkleize");
+        let root = merkleize_chunks_with_virtual_padding::<Sha256>(&chunks, 2usize.pow(10))
+            .expect("can merkleize");
         assert_eq!(
             root.as_ref(),
             hex!("2647cb9e26bd83eeb0982814b2ac4d6cc4a65d0d98637f1a73a4c06d3db0e6ce")
         );
 
         let chunks = vec![1u8; 70 * BYTES_PER_CHUNK];
-        let root =
-            merkleize_chunks_with_virtual_padding(&chunks, 2usize.pow(63)).expect("can merkleize");
+        let root = merkleize_chunks_with_virtual_padding::<Sha256>(&chunks, 2usize.pow(63))
+            .expect("can merkleize");
         assert_eq!(
             root.as_ref(),
             hex!("9317695d95b5a3b46e976b5a9cbfcfccb600accaddeda9ac867cc9669b862979")
@@ -457,7 +837,7 @@ mod tests {
 
     #[test]
     fn test_hash_tree_root_of_list() {
-        let mut a_list = List::<u16, 1024>::try_from(vec![
+        let a_list = List::<u16, 1024>::try_from(vec![
             65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535,
             65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535,
             65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535, 65535,
@@ -496,7 +876,7 @@ mod tests {
 
     #[test]
     fn test_hash_tree_root_of_empty_list() {
-        let mut a_list = List::<u16, 1024>::try_from(vec![]).unwrap();
+        let a_list = List::<u16, 1024>::try_from(vec![]).unwrap();
         let root = a_list.hash_tree_root().expect("can compute root");
         assert_eq!(
             root.as_ref(),
@@ -553,7 +933,7 @@ mod tests {
             hex!("7078155bf8f0dc42d8afccec8d9b5aeb54f0a2e8e58fcef3e723f6a867232ce7")
         );
 
-        let mut original_foo = foo.clone();
+        let original_foo = foo.clone();
 
         foo.b[2] = 44u32;
         foo.d.pop();
@@ -594,7 +974,7 @@ mod tests {
 
     #[test]
     fn test_simple_serialize_of_root() {
-        let mut root = Node::default();
+        let root = Node::default();
         let mut result = vec![];
         let _ = root.serialize(&mut result).expect("can encode");
         let expected_encoding = vec![0; 32];
@@ -614,7 +994,7 @@ mod tests {
             a: U256,
         }
 
-        let mut foo = Foo { a: U256::from(68) };
+        let foo = Foo { a: U256::from(68) };
         let foo_root = foo.hash_tree_root().unwrap();
         let expected_root =
             hex::decode("4400000000000000000000000000000000000000000000000000000000000000")
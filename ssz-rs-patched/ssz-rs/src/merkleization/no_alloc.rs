@@ -0,0 +1,182 @@
+//! Merkleization into caller-provided buffers, for targets where every heap allocation is
+//! expensive (e.g. a zkVM guest).
+//!
+//! Unlike [`merkleize`](crate::merkleization::merkleize), nothing here copies the leaves into a
+//! scratch buffer; only the root of each complete subtree seen so far is kept, in a fixed-size
+//! array on the stack.
+use crate::merkleization::{
+    hash_nodes, leaf_count_for, zero_hash, MerkleizationError, BYTES_PER_CHUNK,
+    MAX_MERKLE_TREE_DEPTH,
+};
+use sha2::{Digest, Sha256};
+
+type Chunk = [u8; BYTES_PER_CHUNK];
+
+// Roots of the complete subtrees waiting for their right sibling, one slot per height.
+struct Subtrees {
+    hasher: Sha256,
+    roots: [Chunk; MAX_MERKLE_TREE_DEPTH],
+    // bit `height` is set when `roots[height]` holds a pending subtree
+    occupied: u64,
+    chunk_count: usize,
+}
+
+impl Subtrees {
+    fn new() -> Self {
+        Self {
+            hasher: Sha256::new(),
+            roots: [[0u8; BYTES_PER_CHUNK]; MAX_MERKLE_TREE_DEPTH],
+            occupied: 0,
+            chunk_count: 0,
+        }
+    }
+
+    fn push(&mut self, chunk: &[u8]) {
+        let mut node: Chunk = chunk.try_into().expect("is one chunk");
+        let mut height = 0;
+        while self.occupied & (1 << height) != 0 {
+            let mut parent = [0u8; BYTES_PER_CHUNK];
+            hash_nodes(&mut self.hasher, &self.roots[height], &node, &mut parent);
+            node = parent;
+            self.occupied &= !(1 << height);
+            height += 1;
+        }
+        self.roots[height] = node;
+        self.occupied |= 1 << height;
+        self.chunk_count += 1;
+    }
+
+    fn finalize(
+        mut self,
+        limit: Option<usize>,
+        out: &mut [u8; BYTES_PER_CHUNK],
+    ) -> Result<(), MerkleizationError> {
+        let leaf_count = leaf_count_for(self.chunk_count, limit)?;
+        let height = leaf_count.trailing_zeros() as usize;
+        if self.chunk_count == 0 {
+            out.copy_from_slice(zero_hash(height));
+            return Ok(())
+        }
+        // fold the pending subtrees together from the bottom, treating any missing right
+        // sibling as a "zero" subtree
+        let mut root: Option<Chunk> = None;
+        for depth in 0..height {
+            let subtree = (self.occupied & (1 << depth) != 0).then_some(self.roots[depth]);
+            let (left, right) = match (subtree, root) {
+                (Some(left), Some(right)) => (left, right),
+                (Some(left), None) | (None, Some(left)) => (left, *zero_hash(depth)),
+                (None, None) => continue,
+            };
+            let mut parent = [0u8; BYTES_PER_CHUNK];
+            hash_nodes(&mut self.hasher, &left, &right, &mut parent);
+            root = Some(parent);
+        }
+        // with no padding required, the complete tree is the only pending subtree
+        *out = root.unwrap_or(self.roots[height]);
+        Ok(())
+    }
+}
+
+/// Write the root of the Merklization of a binary tree formed from `chunks` to `out`,
+/// without allocating.
+///
+/// The root is the same as [`merkleize`](crate::merkleization::merkleize) returns for the
+/// same arguments.
+///
+/// Invariant: `chunks.len() % BYTES_PER_CHUNK == 0`
+pub fn merkleize_into(
+    chunks: &[u8],
+    limit: Option<usize>,
+    out: &mut [u8; BYTES_PER_CHUNK],
+) -> Result<(), MerkleizationError> {
+    let mut subtrees = Subtrees::new();
+    for chunk in chunks.chunks_exact(BYTES_PER_CHUNK) {
+        subtrees.push(chunk);
+    }
+    subtrees.finalize(limit, out)
+}
+
+/// Write the root of the Merklization of `bytes`, packed into chunks as by
+/// [`pack_bytes`](crate::merkleization::pack_bytes), to `out` without allocating.
+///
+/// As the final partial chunk is padded on the stack, `bytes` can be the serialization of
+/// a sequence of basic values as is.
+pub fn merkleize_bytes_into(
+    bytes: &[u8],
+    limit: Option<usize>,
+    out: &mut [u8; BYTES_PER_CHUNK],
+) -> Result<(), MerkleizationError> {
+    let mut subtrees = Subtrees::new();
+    let mut chunks = bytes.chunks_exact(BYTES_PER_CHUNK);
+    for chunk in &mut chunks {
+        subtrees.push(chunk);
+    }
+    let remainder = chunks.remainder();
+    if !remainder.is_empty() {
+        let mut chunk = [0u8; BYTES_PER_CHUNK];
+        chunk[..remainder.len()].copy_from_slice(remainder);
+        subtrees.push(&chunk);
+    }
+    subtrees.finalize(limit, out)
+}
+
+/// Return the length of `len` bytes once padded to a whole number of chunks.
+pub const fn packed_len(len: usize) -> usize {
+    // NOTE: relies on `BYTES_PER_CHUNK` being a power of two
+    (len + BYTES_PER_CHUNK - 1) & !(BYTES_PER_CHUNK - 1)
+}
+
+/// Copy `bytes` to the start of `buffer`, zero-padding to a whole number of chunks, and
+/// return the length of the packed data.
+///
+/// Returns an error if `buffer` is shorter than `packed_len(bytes.len())`.
+pub fn pack_bytes_into(bytes: &[u8], buffer: &mut [u8]) -> Result<usize, MerkleizationError> {
+    let len = packed_len(bytes.len());
+    if buffer.len() < len {
+        return Err(MerkleizationError::InputExceedsLimit(buffer.len()))
+    }
+    buffer[..bytes.len()].copy_from_slice(bytes);
+    buffer[bytes.len()..len].fill(0);
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkleization::{merkleize, pack_bytes};
+
+    #[test]
+    fn test_merkleize_into_matches_merkleize() {
+        for count in [0, 1, 2, 3, 4, 5, 8, 13, 64, 100] {
+            let chunks = (0..count * BYTES_PER_CHUNK).map(|i| (i % 239) as u8).collect::<Vec<_>>();
+            for limit in [None, Some(count), Some(128), Some(1 << 40)] {
+                let mut root = [0u8; BYTES_PER_CHUNK];
+                merkleize_into(&chunks, limit, &mut root).unwrap();
+                assert_eq!(root, merkleize(&chunks, limit).unwrap().as_ref());
+            }
+        }
+
+        let mut root = [0u8; BYTES_PER_CHUNK];
+        let result = merkleize_into(&[0u8; 3 * BYTES_PER_CHUNK], Some(2), &mut root);
+        assert!(matches!(result, Err(MerkleizationError::InputExceedsLimit(2))));
+    }
+
+    #[test]
+    fn test_packing_into_buffer() {
+        for len in [0, 1, 31, 32, 33, 100] {
+            let bytes = (0..len).map(|i| (i % 5 + 1) as u8).collect::<Vec<_>>();
+            let mut expected = bytes.clone();
+            pack_bytes(&mut expected);
+
+            let mut buffer = [0xffu8; 128];
+            let packed = pack_bytes_into(&bytes, &mut buffer).unwrap();
+            assert_eq!(packed, packed_len(len));
+            assert_eq!(&buffer[..packed], expected.as_slice());
+
+            let mut root = [0u8; BYTES_PER_CHUNK];
+            merkleize_bytes_into(&bytes, Some(8), &mut root).unwrap();
+            assert_eq!(root, merkleize(&expected, Some(8)).unwrap().as_ref());
+        }
+        assert!(pack_bytes_into(&[1u8; 33], &mut [0u8; 32]).is_err());
+    }
+}
@@ -1,12 +1,16 @@
 use crate::{
+    error::{HexError, InstanceError},
     lib::*,
-    merkleization::BYTES_PER_CHUNK,
+    merkleization::{MerkleizationError, Merkleized, Path, Prove, BYTES_PER_CHUNK},
     prelude::*,
-    utils::{write_bytes_to_lower_hex, write_bytes_to_lower_hex_display},
+    utils::{
+        decode_hex, write_bytes_to_lower_hex, write_bytes_to_lower_hex_digits,
+        write_bytes_to_lower_hex_display,
+    },
 };
 
 /// A node in a merkle tree.
-#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, SimpleSerialize)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serializable)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize, borsh::BorshSerialize, borsh::BorshDeserialize)
@@ -27,6 +31,32 @@ impl fmt::Display for Node {
     }
 }
 
+impl fmt::LowerHex for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_bytes_to_lower_hex_digits(f, self.0)
+    }
+}
+
+impl Node {
+    /// Decode a `Node` from 64 hex digits, with or without a `0x` prefix.
+    pub fn from_hex(s: &str) -> Result<Self, HexError> {
+        let data = decode_hex(s)?;
+        let provided = data.len();
+        let node = data
+            .try_into()
+            .map_err(|_| InstanceError::Exact { required: BYTES_PER_CHUNK, provided })?;
+        Ok(Self(node))
+    }
+}
+
+impl FromStr for Node {
+    type Err = HexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
 impl Deref for Node {
     type Target = [u8];
 
@@ -41,6 +71,37 @@ impl DerefMut for Node {
     }
 }
 
+impl AsRef<[u8]> for Node {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+// A `Node` is exactly one chunk, so it is its own hash tree root.
+impl Merkleized for Node {
+    fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
+        Ok(*self)
+    }
+}
+
+impl Prove for Node {
+    fn prove_path(
+        &self,
+        path: Path,
+        branch: &mut Vec<Node>,
+    ) -> Result<(Node, usize), MerkleizationError> {
+        self.0.prove_path(path, branch)
+    }
+}
+
+impl SimpleSerialize for Node {}
+
+impl From<[u8; BYTES_PER_CHUNK]> for Node {
+    fn from(value: [u8; BYTES_PER_CHUNK]) -> Self {
+        Self(value)
+    }
+}
+
 impl TryFrom<&[u8]> for Node {
     type Error = TryFromSliceError;
 
@@ -49,6 +110,13 @@ impl TryFrom<&[u8]> for Node {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Node {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(u.arbitrary()?))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,5 +137,18 @@ mod tests {
         assert_eq!(dbg, "0x1717171717171717171717171717171717171717171717171717171717171717");
         let display = format!("{node}");
         assert_eq!(display, "0x1717…1717");
+        assert_eq!(format!("{node:x}"), "17".repeat(32));
+        assert_eq!(format!("{node:#x}"), dbg);
+    }
+
+    #[test]
+    fn test_from_hex() {
+        let node = Node::from([23u8; 32]);
+        assert_eq!(Node::from_hex(&format!("{node:x}")).unwrap(), node);
+        assert_eq!(format!("{node:#x}").parse::<Node>().unwrap(), node);
+        assert!(matches!(
+            Node::from_hex("0x1717"),
+            Err(HexError::Instance(InstanceError::Exact { required: 32, provided: 2 }))
+        ));
     }
 }
@@ -0,0 +1,112 @@
+use crate::merkleization::{
+    backend, hash_layer, hash_tree_roots_into, is_observed, merkleize_chunks_by_layer,
+    MerkleizationError, Merkleized, Node, BYTES_PER_CHUNK,
+};
+use rayon::prelude::*;
+
+/// Chunk count from which hashing each layer of the tree is split across the `rayon` thread
+/// pool. Below this, the cost of coordinating the threads outweighs the hashing saved.
+pub(crate) const PARALLEL_MERKLEIZATION_THRESHOLD: usize = 1 << 12;
+
+// Number of parent nodes each task computes when a layer is split across threads.
+const PARENTS_PER_TASK: usize = 64;
+
+// Same contract as `merkleize_chunks_with_virtual_padding` but the hashing within a layer is
+// done in parallel. Requires `chunks` to be non-empty.
+pub(crate) fn merkleize_chunks_in_parallel(chunks: &[u8], leaf_count: usize) -> Node {
+    merkleize_chunks_by_layer(chunks, leaf_count, |children, parents| {
+        parents
+            .par_chunks_mut(PARENTS_PER_TASK * BYTES_PER_CHUNK)
+            .zip(children.par_chunks(2 * PARENTS_PER_TASK * BYTES_PER_CHUNK))
+            .for_each(|(parents, children)| hash_layer(children, parents));
+    })
+}
+
+// Number of values each task computes the roots of in `par_hash_tree_roots`.
+const ROOTS_PER_TASK: usize = 64;
+
+/// Compute the hash tree root of each of `values` as `hash_tree_roots` does, splitting the
+/// values across the `rayon` thread pool, e.g. for the validators of a registry.
+///
+/// The roots are computed serially on the calling thread while a hasher installed by
+/// `hash_tree_root_with_hasher`, a witness or metrics are active there, as those are scoped to
+/// it.
+pub fn par_hash_tree_roots<T>(values: &[T]) -> Result<Vec<Node>, MerkleizationError>
+where
+    T: Merkleized + Sync,
+{
+    let mut roots = Vec::new();
+    par_hash_tree_roots_into(values, &mut roots)?;
+    Ok(roots)
+}
+
+/// Compute the hash tree roots of `values` into `roots` as `hash_tree_roots_into` does, in
+/// parallel like `par_hash_tree_roots`.
+pub fn par_hash_tree_roots_into<T>(
+    values: &[T],
+    roots: &mut Vec<Node>,
+) -> Result<(), MerkleizationError>
+where
+    T: Merkleized + Sync,
+{
+    if values.len() <= ROOTS_PER_TASK || backend::is_active() || is_observed() {
+        return hash_tree_roots_into(values, roots)
+    }
+    roots.clear();
+    roots.resize(values.len(), Node::default());
+    roots.par_chunks_mut(ROOTS_PER_TASK).zip(values.par_chunks(ROOTS_PER_TASK)).try_for_each(
+        |(roots, values)| {
+            for (root, value) in roots.iter_mut().zip(values) {
+                *root = value.hash_tree_root()?;
+            }
+            Ok::<_, MerkleizationError>(())
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkleization::{merkleize, merkleize_chunks_with_virtual_padding};
+    use sha2::Sha256;
+
+    #[test]
+    fn test_parallel_merkleization_matches_serial() {
+        for (count, leaf_count) in [(1, 1), (1, 8), (5, 8), (4097, 8192), (5000, 1 << 20)] {
+            let chunks = (0..count * BYTES_PER_CHUNK).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+            let expected =
+                merkleize_chunks_with_virtual_padding::<Sha256>(&chunks, leaf_count).unwrap();
+            assert_eq!(merkleize_chunks_in_parallel(&chunks, leaf_count), expected);
+        }
+
+        let chunks = vec![7u8; PARALLEL_MERKLEIZATION_THRESHOLD * BYTES_PER_CHUNK];
+        let expected = merkleize_chunks_with_virtual_padding::<Sha256>(
+            &chunks,
+            PARALLEL_MERKLEIZATION_THRESHOLD,
+        )
+        .unwrap();
+        assert_eq!(merkleize(&chunks, None).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parallel_hash_tree_roots_match_serial() {
+        use crate::prelude::*;
+
+        let values = (0..1000u64)
+            .map(|i| List::<u64, 16>::try_from(vec![i; (i % 16) as usize]).unwrap())
+            .collect::<Vec<_>>();
+        let expected =
+            values.iter().map(|value| value.hash_tree_root().unwrap()).collect::<Vec<_>>();
+        assert_eq!(hash_tree_roots(&values).unwrap(), expected);
+        assert_eq!(par_hash_tree_roots(&values).unwrap(), expected);
+        assert_eq!(par_hash_tree_roots(&values[..10]).unwrap(), expected[..10]);
+
+        let mut roots = vec![Node::default(); 5000];
+        par_hash_tree_roots_into(&values, &mut roots).unwrap();
+        assert_eq!(roots, expected);
+        par_hash_tree_roots_into(&values[..10], &mut roots).unwrap();
+        assert_eq!(roots, expected[..10]);
+        hash_tree_roots_into(&values[500..], &mut roots).unwrap();
+        assert_eq!(roots, expected[500..]);
+    }
+}
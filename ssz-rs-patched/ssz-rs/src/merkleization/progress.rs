@@ -0,0 +1,180 @@
+use crate::merkleization::{
+    backend::with_hash_fn, Hasher, MerkleizationError, Merkleized, Node, BYTES_PER_CHUNK,
+};
+use sha2::{Digest, Sha256};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+/// How far a long-running operation has got, as reported to an observer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// Units of work done so far, e.g. hashes computed or deposits processed.
+    pub completed: u64,
+    /// Units of work the operation is expected to take.
+    pub total: u64,
+    /// Time since the operation started.
+    pub elapsed: Duration,
+}
+
+impl Progress {
+    /// Percentage of the work done, from 0 to 100.
+    pub fn percent(&self) -> f64 {
+        if self.total == 0 {
+            return 100.0
+        }
+        (self.completed.min(self.total) as f64 * 100.0) / self.total as f64
+    }
+
+    /// Units of work done per second, on average.
+    pub fn throughput(&self) -> f64 {
+        let seconds = self.elapsed.as_secs_f64();
+        if seconds == 0.0 {
+            return 0.0
+        }
+        self.completed as f64 / seconds
+    }
+}
+
+/// Counts the units of work done by an operation, reporting a [`Progress`] to `observer` each
+/// time another percent of the `total` is completed.
+///
+/// To consume reports from another thread, e.g. one rendering a progress bar, use an observer
+/// that forwards them over a channel: `move |progress: &Progress| tx.send(*progress).ok();`.
+pub struct ProgressTracker<F> {
+    observer: F,
+    total: u64,
+    completed: u64,
+    started: Instant,
+    last_percent: Option<u64>,
+}
+
+impl<F: FnMut(&Progress)> ProgressTracker<F> {
+    pub fn new(total: u64, observer: F) -> Self {
+        Self { observer, total, completed: 0, started: Instant::now(), last_percent: None }
+    }
+
+    pub fn progress(&self) -> Progress {
+        Progress { completed: self.completed, total: self.total, elapsed: self.started.elapsed() }
+    }
+
+    /// Record another `steps` units of work as done.
+    pub fn advance(&mut self, steps: u64) {
+        self.completed += steps;
+        if self.total == 0 {
+            return
+        }
+        let percent = self.completed.min(self.total) * 100 / self.total;
+        if self.last_percent.map(|last| percent > last).unwrap_or(true) {
+            self.last_percent = Some(percent);
+            let progress = self.progress();
+            (self.observer)(&progress);
+        }
+    }
+
+    /// Report completion of the operation, unless that was already reported.
+    pub fn finish(&mut self) {
+        if self.last_percent != Some(100) {
+            self.last_percent = Some(100);
+            let progress = self.progress();
+            (self.observer)(&progress);
+        }
+    }
+}
+
+/// Compute the hash tree root of `value` as [`Merkleized::hash_tree_root`] does, reporting
+/// progress to `observer` in terms of the hashes computed.
+///
+/// The number of hashes required is found beforehand by merkleizing `value` without hashing, which
+/// is cheap in comparison. The root itself is computed like [`hash_tree_root_with_hasher`] with
+/// SHA-256, so it is not accelerated and caches (e.g. of a [`CachedList`](crate::CachedList)) are
+/// not used; prefer [`Merkleized::hash_tree_root`] when progress is not needed.
+///
+/// [`hash_tree_root_with_hasher`]: crate::hash_tree_root_with_hasher
+pub fn hash_tree_root_with_progress<T>(
+    value: &T,
+    observer: impl FnMut(&Progress) + 'static,
+) -> Result<Node, MerkleizationError>
+where
+    T: Merkleized + ?Sized,
+{
+    let count = Rc::new(Cell::new(0u64));
+    let counter = count.clone();
+    let count_hash = move |_: &[u8], _: &[u8]| {
+        counter.set(counter.get() + 1);
+        [0u8; BYTES_PER_CHUNK]
+    };
+    with_hash_fn(Box::new(count_hash), |_| Node::default(), || value.hash_tree_root())?;
+
+    let tracker = Rc::new(RefCell::new(ProgressTracker::new(count.get(), observer)));
+    let mut hasher = Sha256::new();
+    let hash = {
+        let tracker = tracker.clone();
+        move |left: &[u8], right: &[u8]| {
+            tracker.borrow_mut().advance(1);
+            Hasher::hash(&mut hasher, left, right)
+        }
+    };
+    let root =
+        with_hash_fn(Box::new(hash), <Sha256 as Hasher>::zero_hash, || value.hash_tree_root())?;
+    tracker.borrow_mut().finish();
+    Ok(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Default, Debug, SimpleSerialize)]
+    struct Foo {
+        a: u64,
+        b: List<u64, 1024>,
+        c: Vector<u8, 96>,
+    }
+
+    #[test]
+    fn test_hash_tree_root_with_progress() {
+        let foo = Foo {
+            a: 7,
+            b: List::try_from((0..500).collect::<Vec<_>>()).unwrap(),
+            c: Default::default(),
+        };
+        let reports = Rc::new(RefCell::new(vec![]));
+        let observed = reports.clone();
+        let root = hash_tree_root_with_progress(&foo, move |progress: &Progress| {
+            observed.borrow_mut().push(*progress)
+        })
+        .unwrap();
+        assert_eq!(root, foo.hash_tree_root().unwrap());
+
+        let reports = reports.borrow();
+        let last = reports.last().unwrap();
+        assert_eq!(last.completed, last.total);
+        assert_eq!(last.percent(), 100.0);
+        assert!(reports.len() <= 101);
+        assert!(reports.windows(2).all(|pair| pair[0].completed < pair[1].completed));
+    }
+
+    #[test]
+    fn test_progress_tracker() {
+        let mut reports = vec![];
+        let mut tracker = ProgressTracker::new(1000, |progress: &Progress| reports.push(*progress));
+        for _ in 0..1000 {
+            tracker.advance(1);
+        }
+        tracker.finish();
+        assert_eq!(reports.len(), 101);
+        assert_eq!(reports[1].completed, 10);
+        assert_eq!(reports[50].percent(), 50.0);
+
+        let mut reports = vec![];
+        let mut tracker = ProgressTracker::new(0, |progress: &Progress| reports.push(*progress));
+        tracker.finish();
+        tracker.finish();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].percent(), 100.0);
+    }
+}
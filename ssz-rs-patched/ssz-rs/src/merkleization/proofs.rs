@@ -1,45 +1,815 @@
-use crate::merkleization::{MerkleizationError as Error, Node};
+use crate::{
+    de::{Deserialize, DeserializeError},
+    error::InstanceError,
+    lib::*,
+    list::List,
+    merkleization::{
+        elements_to_chunks, hash_nodes, pack, Hasher, MerkleizationError as Error, Merkleized,
+        Node, BITS_PER_CHUNK, BYTES_PER_CHUNK, CONTEXT, MAX_MERKLE_TREE_DEPTH,
+    },
+    prelude::*,
+    ser::{Serialize, SerializeError},
+    Serializable, SimpleSerialize,
+};
 use sha2::{Digest, Sha256};
 
-/// `is_valid_merkle_branch` verifies the Merkle proof
-/// against the `root` given the other metadata.
-pub fn is_valid_merkle_branch<T: AsRef<[u8]>>(
+/// An element of a `Path` into the hash tree of some type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathElement {
+    /// An element of a vector or list, or the value of a union with the given selector.
+    Index(usize),
+    /// A named field of a container.
+    Field(String),
+    /// The length mixed into the root of a list.
+    Length,
+}
+
+impl From<usize> for PathElement {
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+impl From<&str> for PathElement {
+    fn from(field: &str) -> Self {
+        Self::Field(field.into())
+    }
+}
+
+/// A sequence of elements, from the root downwards, locating a node in a hash tree.
+pub type Path<'a> = &'a [PathElement];
+
+// The name standing for `PathElement::Length` in a path string, as in the consensus specs.
+const LENGTH_NAME: &str = "__len__";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ParseState {
+    Start,
+    AfterDot,
+    AfterElement,
+}
+
+/// Parse a path written like `validators[1234].effective_balance` into its elements.
+///
+/// Field names are separated by `.` and may be followed by any number of `[index]`, which may also
+/// start the path. The name `__len__` stands for the length of a list, e.g. `balances.__len__`.
+/// The empty string is the path to the root.
+///
+/// Only the syntax is checked here; whether the path exists in a given type is checked once it is
+/// used, e.g. by [`Prove::prove`].
+pub fn parse_path(path: &str) -> Result<Vec<PathElement>, Error> {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut elements = vec![];
+    let mut state = ParseState::Start;
+    let mut position = 0;
+    while let Some(next) = path[position..].chars().next() {
+        match next {
+            '[' if state != ParseState::AfterDot => {
+                let end = path[position..]
+                    .find(']')
+                    .map(|offset| position + offset)
+                    .ok_or(Error::InvalidPathSyntax(position))?;
+                let digits = &path[position + 1..end];
+                if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                    return Err(Error::InvalidPathSyntax(position + 1))
+                }
+                let index = digits.parse().map_err(|_| Error::InvalidPathSyntax(position + 1))?;
+                elements.push(PathElement::Index(index));
+                position = end + 1;
+                state = ParseState::AfterElement;
+            }
+            '.' if state == ParseState::AfterElement => {
+                position += 1;
+                state = ParseState::AfterDot;
+            }
+            c if is_name_char(c) && state != ParseState::AfterElement => {
+                let end = path[position..]
+                    .find(|c: char| !is_name_char(c))
+                    .map(|offset| position + offset)
+                    .unwrap_or(path.len());
+                let name = &path[position..end];
+                if name == LENGTH_NAME {
+                    elements.push(PathElement::Length);
+                } else {
+                    elements.push(PathElement::Field(name.into()));
+                }
+                position = end;
+                state = ParseState::AfterElement;
+            }
+            _ => return Err(Error::InvalidPathSyntax(position)),
+        }
+    }
+    if state == ParseState::AfterDot {
+        return Err(Error::InvalidPathSyntax(path.len()))
+    }
+    Ok(elements)
+}
+
+/// Write `path` in the syntax accepted by [`parse_path`].
+pub fn format_path(path: Path) -> String {
+    let mut result = String::new();
+    for element in path {
+        let name = match element {
+            PathElement::Index(index) => {
+                result.push_str(&format!("[{index}]"));
+                continue
+            }
+            PathElement::Field(name) => name.as_str(),
+            PathElement::Length => LENGTH_NAME,
+        };
+        if !result.is_empty() {
+            result.push('.');
+        }
+        result.push_str(name);
+    }
+    result
+}
+
+/// The index of a node in a binary tree, where the root has index 1 and the children of the
+/// node at index `i` have indices `2i` and `2i + 1`.
+pub type GeneralizedIndex = usize;
+
+/// A Merkle proof of `leaf` at `index` among the nodes at depth `branch.len()` in a tree.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Proof {
+    pub leaf: Node,
+    /// The sibling of each node on the path from the leaf to the root, starting at the leaf.
+    pub branch: Vec<Node>,
+    pub index: usize,
+}
+
+impl Proof {
+    pub fn depth(&self) -> usize {
+        self.branch.len()
+    }
+
+    /// The generalized index of the leaf, as defined in the SSZ spec.
+    ///
+    /// Returns an error if the proof is too deep for the index to fit in a
+    /// `GeneralizedIndex`, which no proof into a tree of supported depth is.
+    pub fn generalized_index(&self) -> Result<GeneralizedIndex, Error> {
+        generalized_index_at(self.depth(), self.index)
+    }
+
+    pub fn verify(&self, root: Node) -> Result<(), Error> {
+        self.verify_with_hasher::<Sha256>(root)
+    }
+
+    /// Verify the proof against `root` for a tree built with the hasher `H`.
+    pub fn verify_with_hasher<H: Hasher>(&self, root: Node) -> Result<(), Error> {
+        is_valid_merkle_branch_with_hasher::<H, _>(
+            self.leaf,
+            &self.branch,
+            self.depth(),
+            self.index,
+            root,
+        )
+    }
+
+    /// Verify a proof of the length of a list or bitlist, i.e. of the node at a
+    /// `PathElement::Length`, against `root` and return that length.
+    ///
+    /// Only the proof is checked, so callers should also check that `self.generalized_index()`
+    /// locates the length of the list they expect, e.g. `3` for a proof against the root of
+    /// the list itself.
+    pub fn verify_length(&self, root: Node) -> Result<usize, Error> {
+        self.verify(root)?;
+        // the length is mixed in as the right sibling of the root of the data
+        if self.index & 1 == 0 {
+            return Err(Error::InvalidProof)
+        }
+        let (length, padding) = self.leaf.as_ref().split_at(8);
+        if padding.iter().any(|&byte| byte != 0) {
+            return Err(Error::InvalidProof)
+        }
+        let length = u64::from_le_bytes(length.try_into().expect("is 8 bytes"));
+        usize::try_from(length).map_err(|_| Error::InvalidProof)
+    }
+}
+
+/// A `Prove` type can produce Merkle proofs for any node in its hash tree reachable by a `Path`.
+pub trait Prove: Merkleized {
+    /// Descend into `self` along `path`, returning the node found and its index relative to
+    /// the root of `self`.
+    ///
+    /// The siblings of the nodes along the way are pushed to `branch`, deepest first.
+    fn prove_path(&self, path: Path, branch: &mut Vec<Node>) -> Result<(Node, usize), Error>;
+
+    /// Build a proof of the node at `path` against the hash tree root of `self`.
+    fn prove(&self, path: Path) -> Result<Proof, Error> {
+        let mut branch = vec![];
+        let (leaf, index) = self.prove_path(path, &mut branch)?;
+        Ok(Proof { leaf, branch, index })
+    }
+
+    /// Build a single proof of the nodes at every path in `paths` against the hash tree root of
+    /// `self`, sharing the witnesses common to their branches.
+    fn multiprove(&self, paths: &[Path]) -> Result<Multiproof, Error> {
+        let proofs = paths.iter().map(|path| self.prove(path)).collect::<Result<Vec<_>, _>>()?;
+        Multiproof::from_proofs(&proofs)
+    }
+}
+
+// The generalized index of the node at `index` among the `2**depth` nodes at `depth`, which
+// for a proof read off the wire may not fit in a `GeneralizedIndex`.
+fn generalized_index_at(depth: usize, index: usize) -> Result<GeneralizedIndex, Error> {
+    u32::try_from(depth)
+        .ok()
+        .and_then(|depth| 1usize.checked_shl(depth))
+        .and_then(|first| first.checked_add(index))
+        .ok_or(Error::InvalidProof)
+}
+
+/// A proof of many nodes of one tree against its root.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Multiproof {
+    pub indices: Vec<GeneralizedIndex>,
+    pub leaves: Vec<Node>,
+    /// The nodes at `get_helper_indices(&indices)`, in that order.
+    pub witnesses: Vec<Node>,
+}
+
+impl Multiproof {
+    /// Combine single proofs against the same root into one multiproof.
+    pub fn from_proofs(proofs: &[Proof]) -> Result<Self, Error> {
+        let indices = proofs.iter().map(Proof::generalized_index).collect::<Result<Vec<_>, _>>()?;
+        let mut nodes = BTreeMap::new();
+        for (proof, &generalized_index) in proofs.iter().zip(&indices) {
+            let siblings = get_branch_indices(generalized_index).into_iter().zip(&proof.branch);
+            for (index, node) in siblings.chain([(generalized_index, &proof.leaf)]) {
+                // the proofs must agree on any node they share
+                if *nodes.entry(index).or_insert(node) != node {
+                    return Err(Error::InvalidProof)
+                }
+            }
+        }
+        let witnesses =
+            get_helper_indices(&indices).into_iter().map(|index| *nodes[&index]).collect();
+        let leaves = proofs.iter().map(|proof| proof.leaf).collect();
+        Ok(Self { indices, leaves, witnesses })
+    }
+
+    pub fn verify(&self, root: Node) -> Result<(), Error> {
+        self.verify_with_hasher::<Sha256>(root)
+    }
+
+    /// Verify the multiproof against `root` for a tree built with the hasher `H`.
+    pub fn verify_with_hasher<H: Hasher>(&self, root: Node) -> Result<(), Error> {
+        verify_multiproof_with_hasher::<H>(root, &self.indices, &self.leaves, &self.witnesses)
+    }
+}
+
+// Bound on the number of indices, leaves and witnesses of a `Multiproof` in its SSZ encoding.
+const MAX_MULTIPROOF_NODES: usize = 1 << 16;
+
+// The SSZ representations of `Proof` and `Multiproof`, which give each a canonical encoding
+// and hash tree root. Indices are encoded as `u64`.
+#[derive(Debug, Default, SimpleSerialize)]
+struct ProofContainer {
     leaf: Node,
-    branch: &[T],
-    depth: usize,
-    index: usize,
+    branch: List<Node, MAX_MERKLE_TREE_DEPTH>,
+    index: u64,
+}
+
+#[derive(Debug, Default, SimpleSerialize)]
+struct MultiproofContainer {
+    indices: List<u64, MAX_MULTIPROOF_NODES>,
+    leaves: List<Node, MAX_MULTIPROOF_NODES>,
+    witnesses: List<Node, MAX_MULTIPROOF_NODES>,
+}
+
+fn bounded<T: Serializable, const N: usize>(data: Vec<T>) -> Result<List<T, N>, InstanceError> {
+    List::try_from(data)
+        .map_err(|(data, _)| InstanceError::Bounded { bound: N, provided: data.len() })
+}
+
+impl TryFrom<&Proof> for ProofContainer {
+    type Error = InstanceError;
+
+    fn try_from(proof: &Proof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            leaf: proof.leaf,
+            branch: bounded(proof.branch.clone())?,
+            index: proof.index as u64,
+        })
+    }
+}
+
+impl From<ProofContainer> for Proof {
+    fn from(container: ProofContainer) -> Self {
+        Self {
+            leaf: container.leaf,
+            branch: container.branch.to_vec(),
+            index: container.index as usize,
+        }
+    }
+}
+
+impl TryFrom<&Multiproof> for MultiproofContainer {
+    type Error = InstanceError;
+
+    fn try_from(proof: &Multiproof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            indices: bounded(proof.indices.iter().map(|&index| index as u64).collect())?,
+            leaves: bounded(proof.leaves.clone())?,
+            witnesses: bounded(proof.witnesses.clone())?,
+        })
+    }
+}
+
+impl From<MultiproofContainer> for Multiproof {
+    fn from(container: MultiproofContainer) -> Self {
+        Self {
+            indices: container.indices.iter().map(|&index| index as usize).collect(),
+            leaves: container.leaves.to_vec(),
+            witnesses: container.witnesses.to_vec(),
+        }
+    }
+}
+
+// Implement the SSZ traits for `$name` by way of its container representation `$container`.
+macro_rules! impl_simple_serialize_via {
+    ($name:ident, $container:ident) => {
+        impl Serializable for $name {
+            const FIXED_SIZE: Option<usize> = None;
+
+            fn serialized_length(&self) -> Result<usize, SerializeError> {
+                $container::try_from(self)?.serialized_length()
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize(&self, buffer: &mut Vec<u8>) -> Result<usize, SerializeError> {
+                $container::try_from(self)?.serialize(buffer)
+            }
+        }
+
+        impl Deserialize for $name {
+            fn deserialize(encoding: &[u8]) -> Result<Self, DeserializeError> {
+                $container::deserialize(encoding).map(Self::from)
+            }
+
+            fn validate(encoding: &[u8]) -> Result<(), DeserializeError> {
+                $container::validate(encoding)
+            }
+        }
+
+        impl Merkleized for $name {
+            fn hash_tree_root(&self) -> Result<Node, Error> {
+                $container::try_from(&*self).map_err(SerializeError::from)?.hash_tree_root()
+            }
+        }
+
+        impl Prove for $name {
+            fn prove_path(
+                &self,
+                path: Path,
+                branch: &mut Vec<Node>,
+            ) -> Result<(Node, usize), Error> {
+                $container::try_from(&*self).map_err(SerializeError::from)?.prove_path(path, branch)
+            }
+        }
+
+        impl SimpleSerialize for $name {}
+    };
+}
+
+impl_simple_serialize_via!(Proof, ProofContainer);
+impl_simple_serialize_via!(Multiproof, MultiproofContainer);
+
+// The indices of the siblings of each node on the path from `index` to the root, deepest first.
+fn get_branch_indices(index: GeneralizedIndex) -> Vec<GeneralizedIndex> {
+    let mut indices = vec![];
+    let mut index = index;
+    while index > 1 {
+        indices.push(index ^ 1);
+        index /= 2;
+    }
+    indices
+}
+
+// The indices of each node on the path from `index` to the root, excluding the root.
+fn get_path_indices(index: GeneralizedIndex) -> Vec<GeneralizedIndex> {
+    let mut indices = vec![];
+    let mut index = index;
+    while index > 1 {
+        indices.push(index);
+        index /= 2;
+    }
+    indices
+}
+
+/// Return the indices of the nodes, beyond the nodes at `indices` themselves, needed to
+/// compute the root of the tree, in descending order.
+pub fn get_helper_indices(indices: &[GeneralizedIndex]) -> Vec<GeneralizedIndex> {
+    let mut helpers = BTreeSet::new();
+    let mut paths = BTreeSet::new();
+    for &index in indices {
+        helpers.extend(get_branch_indices(index));
+        paths.extend(get_path_indices(index));
+    }
+    helpers.retain(|index| !paths.contains(index));
+    helpers.into_iter().rev().collect()
+}
+
+/// Verify each of `proofs` against `root`, returning the result for each proof in order.
+///
+/// Proofs are checked in order of generalized index and the nodes authenticated by one
+/// proof are reused by the next, so a batch of proofs into the same tree costs far fewer
+/// hashes than verifying each one separately.
+pub fn verify_proofs(root: Node, proofs: &[Proof]) -> Vec<Result<(), Error>> {
+    verify_proofs_with_hasher::<Sha256>(root, proofs)
+}
+
+/// Verify a batch of proofs as `verify_proofs` does, for a tree built with the hasher `H`.
+pub fn verify_proofs_with_hasher<H: Hasher>(
     root: Node,
+    proofs: &[Proof],
+) -> Vec<Result<(), Error>> {
+    let mut results = proofs.iter().map(|_| Ok(())).collect::<Vec<_>>();
+    let mut order = vec![];
+    for (i, proof) in proofs.iter().enumerate() {
+        match proof.generalized_index() {
+            Ok(generalized_index) => order.push((generalized_index, i)),
+            Err(err) => results[i] = Err(err),
+        }
+    }
+    order.sort_unstable();
+
+    let mut hasher = H::default();
+    // nodes known to be in the tree with `root`; for any node here, so is its sibling and the
+    // sibling of each of its ancestors
+    let mut known = BTreeMap::from([(1, root)]);
+    for (generalized_index, i) in order {
+        results[i] =
+            verify_proof_against_known(&mut hasher, &proofs[i], generalized_index, &mut known);
+    }
+    results
+}
+
+fn verify_proof_against_known<H: Hasher>(
+    hasher: &mut H,
+    proof: &Proof,
+    mut index: GeneralizedIndex,
+    known: &mut BTreeMap<GeneralizedIndex, Node>,
+) -> Result<(), Error> {
+    let mut node = proof.leaf;
+    let mut path = Vec::with_capacity(2 * proof.depth());
+    for (depth, sibling) in proof.branch.iter().enumerate() {
+        if known.contains_key(&index) {
+            return check_known_path(&proof.branch[depth..], index, node, known, path)
+        }
+        let (left, right) = if index & 1 == 0 { (node, *sibling) } else { (*sibling, node) };
+        path.push((index, node));
+        path.push((index ^ 1, *sibling));
+        hash_nodes(hasher, &left, &right, &mut node);
+        index /= 2;
+    }
+    check_known_path(&[], index, node, known, path)
+}
+
+// Check `node` at `index` and the remaining `branch` above it match the nodes already known,
+// recording `path` as known if so.
+fn check_known_path(
+    branch: &[Node],
+    mut index: GeneralizedIndex,
+    node: Node,
+    known: &mut BTreeMap<GeneralizedIndex, Node>,
+    path: Vec<(GeneralizedIndex, Node)>,
 ) -> Result<(), Error> {
-    if branch.len() != depth {
+    if known.get(&index) != Some(&node) {
+        return Err(Error::InvalidProof)
+    }
+    for sibling in branch {
+        if index <= 1 || known.get(&(index ^ 1)) != Some(sibling) {
+            return Err(Error::InvalidProof)
+        }
+        index /= 2;
+    }
+    if index != 1 {
         return Err(Error::InvalidProof)
     }
+    known.extend(path);
+    Ok(())
+}
 
-    let mut derived_root = leaf;
-    let mut hasher = Sha256::new();
+/// Verify the nodes `leaves` at `indices` are in the tree with root `root`, given the nodes
+/// `witnesses` at the helper indices returned by `get_helper_indices(indices)`.
+pub fn verify_multiproof(
+    root: Node,
+    indices: &[GeneralizedIndex],
+    leaves: &[Node],
+    witnesses: &[Node],
+) -> Result<(), Error> {
+    verify_multiproof_with_hasher::<Sha256>(root, indices, leaves, witnesses)
+}
 
-    for (i, node) in branch.iter().enumerate() {
-        let node = Node::try_from(node.as_ref()).map_err(|_| Error::InvalidProof)?;
+/// Verify a multiproof as `verify_multiproof` does, for a tree built with the hasher `H`.
+pub fn verify_multiproof_with_hasher<H: Hasher>(
+    root: Node,
+    indices: &[GeneralizedIndex],
+    leaves: &[Node],
+    witnesses: &[Node],
+) -> Result<(), Error> {
+    let helper_indices = get_helper_indices(indices);
+    if indices.len() != leaves.len() || helper_indices.len() != witnesses.len() {
+        return Err(Error::InvalidProof)
+    }
+    let mut nodes = BTreeMap::new();
+    for (&index, node) in indices.iter().zip(leaves).chain(helper_indices.iter().zip(witnesses)) {
+        if *nodes.entry(index).or_insert(*node) != *node {
+            return Err(Error::InvalidProof)
+        }
+    }
 
-        if (index / 2usize.pow(i as u32)) % 2 != 0 {
-            hasher.update(node.as_ref());
-            hasher.update(derived_root.as_ref());
-        } else {
-            hasher.update(derived_root.as_ref());
-            hasher.update(node.as_ref());
+    let mut hasher = H::default();
+    // walk up from the deepest node, computing each parent once both children are known
+    let mut pending = nodes.keys().copied().collect::<BTreeSet<_>>();
+    while let Some(index) = pending.pop_last() {
+        if index <= 1 {
+            break
+        }
+        let parent = index / 2;
+        if nodes.contains_key(&parent) {
+            continue
         }
-        derived_root.copy_from_slice(&hasher.finalize_reset());
+        let (Some(left), Some(right)) = (nodes.get(&(index & !1)), nodes.get(&(index | 1))) else {
+            continue
+        };
+        let mut node = Node::default();
+        hash_nodes(&mut hasher, left, right, &mut node);
+        nodes.insert(parent, node);
+        pending.insert(parent);
     }
 
-    if derived_root == root {
+    if nodes.get(&1) == Some(&root) {
         Ok(())
     } else {
         Err(Error::InvalidProof)
     }
 }
 
+// Basic types have no inner structure, so the only node that can be proven is the root.
+pub(crate) fn prove_basic<T: Merkleized + ?Sized>(
+    value: &T,
+    path: Path,
+) -> Result<(Node, usize), Error> {
+    match path.first() {
+        Some(element) => Err(Error::InvalidPathElement(element.clone())),
+        None => Ok((value.hash_tree_root()?, 0)),
+    }
+}
+
+/// Push the siblings of the chunk at `chunk_index` to `branch`, where the chunks form the
+/// bottom layer of a tree padded to `limit` chunks in the same way as `merkleize`.
+///
+/// Invariant: `chunks.len() % BYTES_PER_CHUNK == 0`
+///
+/// Returns the depth of the tree.
+pub fn extend_branch(
+    chunks: &[u8],
+    limit: Option<usize>,
+    chunk_index: usize,
+    branch: &mut Vec<Node>,
+) -> Result<usize, Error> {
+    let chunk_count = chunks.len() / BYTES_PER_CHUNK;
+    let leaf_count = match limit {
+        Some(limit) if limit < chunk_count => return Err(Error::InputExceedsLimit(limit)),
+        Some(limit) => limit.next_power_of_two(),
+        None => chunk_count.next_power_of_two(),
+    };
+    let depth = leaf_count.trailing_zeros() as usize;
+
+    let mut layer = chunks.to_vec();
+    let mut index = chunk_index;
+    let mut hasher = Sha256::new();
+    for height in 0..depth {
+        let layer_count = layer.len() / BYTES_PER_CHUNK;
+        let sibling = index ^ 1;
+        let node = if sibling < layer_count {
+            &layer[sibling * BYTES_PER_CHUNK..(sibling + 1) * BYTES_PER_CHUNK]
+        } else {
+            &CONTEXT[height]
+        };
+        branch.push(node.try_into().expect("is one chunk"));
+
+        let mut parents = vec![0u8; (layer_count - layer_count / 2) * BYTES_PER_CHUNK];
+        for (i, parent) in parents.chunks_exact_mut(BYTES_PER_CHUNK).enumerate() {
+            let left = &layer[2 * i * BYTES_PER_CHUNK..(2 * i + 1) * BYTES_PER_CHUNK];
+            let right = if 2 * i + 1 < layer_count {
+                &layer[(2 * i + 1) * BYTES_PER_CHUNK..(2 * i + 2) * BYTES_PER_CHUNK]
+            } else {
+                &CONTEXT[height]
+            };
+            hash_nodes(&mut hasher, left, right, parent);
+        }
+        layer = parents;
+        index /= 2;
+    }
+    Ok(depth)
+}
+
+/// Descend along `path` into the element it selects of a homogeneous sequence, whose tree is
+/// padded to `limit` chunks as in `merkleize`.
+pub(crate) fn prove_element<T: SimpleSerialize>(
+    elements: &[T],
+    limit: Option<usize>,
+    path: Path,
+    branch: &mut Vec<Node>,
+) -> Result<(Node, usize), Error> {
+    let (element, rest) = path.split_first().expect("path is not empty");
+    let index = match element {
+        PathElement::Index(index) if *index < elements.len() => *index,
+        element => return Err(Error::InvalidPathElement(element.clone())),
+    };
+    if T::is_composite_type() {
+        let start = branch.len();
+        let (leaf, inner_index) = elements[index].prove_path(rest, branch)?;
+        let inner_depth = branch.len() - start;
+        let chunks = elements_to_chunks(elements)?;
+        extend_branch(&chunks, limit, index, branch)?;
+        Ok((leaf, (index << inner_depth) | inner_index))
+    } else {
+        // basic elements are packed, so the proof can only go as far as the enclosing chunk
+        if let Some(element) = rest.first() {
+            return Err(Error::InvalidPathElement(element.clone()))
+        }
+        let chunk_index = index * T::size_hint() / BYTES_PER_CHUNK;
+        let chunks = pack(elements)?;
+        let leaf = chunks[chunk_index * BYTES_PER_CHUNK..(chunk_index + 1) * BYTES_PER_CHUNK]
+            .try_into()
+            .expect("is one chunk");
+        extend_branch(&chunks, limit, chunk_index, branch)?;
+        Ok((leaf, chunk_index))
+    }
+}
+
+/// Descend along `path` into the bit it selects of packed `chunks`, returning the chunk
+/// containing the bit.
+pub(crate) fn prove_bit(
+    chunks: &[u8],
+    bit_count: usize,
+    limit: Option<usize>,
+    path: Path,
+    branch: &mut Vec<Node>,
+) -> Result<(Node, usize), Error> {
+    let (element, rest) = path.split_first().expect("path is not empty");
+    let chunk_index = match element {
+        PathElement::Index(index) if *index < bit_count => *index / BITS_PER_CHUNK,
+        element => return Err(Error::InvalidPathElement(element.clone())),
+    };
+    if let Some(element) = rest.first() {
+        return Err(Error::InvalidPathElement(element.clone()))
+    }
+    let leaf = chunks[chunk_index * BYTES_PER_CHUNK..(chunk_index + 1) * BYTES_PER_CHUNK]
+        .try_into()
+        .expect("is one chunk");
+    extend_branch(chunks, limit, chunk_index, branch)?;
+    Ok((leaf, chunk_index))
+}
+
+/// The witnesses from the node at `index` among the `2**depth` nodes at `depth` in a tree up to
+/// its root, starting with the sibling of that node.
+///
+/// A `Branch` always has one witness per level, so it can be checked against any leaf without
+/// re-validating its shape.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Branch {
+    depth: usize,
+    index: usize,
+    witnesses: Vec<Node>,
+}
+
+impl Branch {
+    pub fn new(depth: usize, index: usize, witnesses: Vec<Node>) -> Result<Self, Error> {
+        if witnesses.len() != depth {
+            return Err(Error::InvalidBranchLength { depth, length: witnesses.len() })
+        }
+        Ok(Self { depth, index, witnesses })
+    }
+
+    /// Build a branch from witnesses given as byte slices, e.g. as read off the wire.
+    pub fn from_slices<T: AsRef<[u8]>>(
+        depth: usize,
+        index: usize,
+        witnesses: &[T],
+    ) -> Result<Self, Error> {
+        if witnesses.len() != depth {
+            return Err(Error::InvalidBranchLength { depth, length: witnesses.len() })
+        }
+        let witnesses = witnesses
+            .iter()
+            .enumerate()
+            .map(|(position, witness)| {
+                Node::try_from(witness.as_ref()).map_err(|_| Error::InvalidWitness(position))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { depth, index, witnesses })
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn witnesses(&self) -> &[Node] {
+        &self.witnesses
+    }
+
+    /// The generalized index of the node this branch starts from, or an error if it does not
+    /// fit in a `GeneralizedIndex`.
+    pub fn generalized_index(&self) -> Result<GeneralizedIndex, Error> {
+        generalized_index_at(self.depth, self.index)
+    }
+
+    /// The root of the tree containing `leaf` at the position of this branch.
+    pub fn compute_root(&self, leaf: Node) -> Node {
+        self.compute_root_with_hasher::<Sha256>(leaf)
+    }
+
+    /// The root of the tree containing `leaf`, for a tree built with the hasher `H`.
+    pub fn compute_root_with_hasher<H: Hasher>(&self, leaf: Node) -> Node {
+        let mut hasher = H::default();
+        let mut node = leaf;
+        for (i, witness) in self.witnesses.iter().enumerate() {
+            let digest = if (self.index >> i) & 1 == 1 {
+                hasher.hash(witness.as_ref(), node.as_ref())
+            } else {
+                hasher.hash(node.as_ref(), witness.as_ref())
+            };
+            node.copy_from_slice(&digest);
+        }
+        node
+    }
+
+    /// Check that this branch leads from `leaf` to `root`.
+    pub fn verify(&self, leaf: Node, root: Node) -> Result<(), Error> {
+        self.verify_with_hasher::<Sha256>(leaf, root)
+    }
+
+    /// Check that this branch leads from `leaf` to `root` in a tree built with the hasher `H`.
+    pub fn verify_with_hasher<H: Hasher>(&self, leaf: Node, root: Node) -> Result<(), Error> {
+        let computed = self.compute_root_with_hasher::<H>(leaf);
+        if computed == root {
+            Ok(())
+        } else {
+            Err(Error::RootMismatch { expected: root, computed })
+        }
+    }
+}
+
+/// `is_valid_merkle_branch` verifies the Merkle proof
+/// against the `root` given the other metadata.
+pub fn is_valid_merkle_branch<T: AsRef<[u8]>>(
+    leaf: Node,
+    branch: &[T],
+    depth: usize,
+    index: usize,
+    root: Node,
+) -> Result<(), Error> {
+    is_valid_merkle_branch_with_hasher::<Sha256, T>(leaf, branch, depth, index, root)
+}
+
+/// Verify a Merkle branch as `is_valid_merkle_branch` does, for a tree built with the
+/// hasher `H`.
+pub fn is_valid_merkle_branch_with_hasher<H: Hasher, T: AsRef<[u8]>>(
+    leaf: Node,
+    branch: &[T],
+    depth: usize,
+    index: usize,
+    root: Node,
+) -> Result<(), Error> {
+    Branch::from_slices(depth, index, branch)?.verify_with_hasher::<H>(leaf, root)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
+
+    #[derive(Debug, Default, SimpleSerialize)]
+    struct Inner {
+        x: u8,
+        flags: Bitvector<300>,
+    }
+
+    #[derive(Debug, Default, SimpleSerialize)]
+    struct Outer {
+        a: u64,
+        b: List<u64, 8>,
+        c: Inner,
+        d: Vector<Inner, 3>,
+        e: Option<Inner>,
+    }
+
+    fn path(elements: &[PathElement]) -> Vec<PathElement> {
+        elements.to_vec()
+    }
 
     fn decode_node_from_hex(hex: &str) -> Node {
         let bytes = hex::decode(hex).expect("is hex");
@@ -67,4 +837,273 @@ mod tests {
 
         assert!(is_valid_merkle_branch(leaf, &branch, depth, index, root).is_ok());
     }
+
+    #[test]
+    fn test_invalid_merkle_branch() {
+        let value = Outer { a: 3, b: List::try_from(vec![1, 2, 3]).unwrap(), ..Default::default() };
+        let root = value.hash_tree_root().unwrap();
+        let proof = value.prove(&path(&["b".into(), 1.into()])).unwrap();
+        let depth = proof.depth();
+        let index = proof.index;
+
+        let branch = Branch::new(depth, index, proof.branch.clone()).unwrap();
+        assert_eq!(branch.generalized_index().unwrap(), proof.generalized_index().unwrap());
+        assert_eq!(branch.compute_root(proof.leaf), root);
+        assert!(branch.verify(proof.leaf, root).is_ok());
+        let computed = branch.compute_root(Node::default());
+        assert!(matches!(
+            branch.verify(Node::default(), root),
+            Err(Error::RootMismatch { expected, computed: c }) if expected == root && c == computed
+        ));
+
+        assert!(matches!(
+            Branch::new(depth + 1, index, proof.branch.clone()),
+            Err(Error::InvalidBranchLength { depth: d, length }) if d == depth + 1 && length == depth
+        ));
+        assert!(matches!(
+            is_valid_merkle_branch(proof.leaf, &proof.branch[1..], depth, index, root),
+            Err(Error::InvalidBranchLength { .. })
+        ));
+        let mut witnesses = proof.branch.iter().map(|node| node.to_vec()).collect::<Vec<_>>();
+        witnesses[2].pop();
+        assert!(matches!(
+            is_valid_merkle_branch(proof.leaf, &witnesses, depth, index, root),
+            Err(Error::InvalidWitness(2))
+        ));
+    }
+
+    #[test]
+    fn test_prove_paths() {
+        let mut value = Outer {
+            a: 3,
+            b: List::try_from(vec![1, 2, 3, 4, 5, 6]).unwrap(),
+            e: Some(Inner { x: 7, ..Default::default() }),
+            ..Default::default()
+        };
+        value.c.x = 7;
+        value.c.flags.set(260, true);
+        value.d[1].x = 9;
+        let root = value.hash_tree_root().unwrap();
+
+        let cases = [
+            (path(&["a".into()]), 8, 3u64.hash_tree_root().unwrap()),
+            (path(&["b".into(), 5.into()]), 37, pack(&[5u64, 6]).unwrap()[..].try_into().unwrap()),
+            (path(&["b".into(), PathElement::Length]), 19, 6usize.hash_tree_root().unwrap()),
+            (path(&["c".into(), "x".into()]), 20, 7u8.hash_tree_root().unwrap()),
+            (path(&["d".into(), 1.into(), "x".into()]), 90, 9u8.hash_tree_root().unwrap()),
+            (path(&["e".into(), 1.into(), "x".into()]), 48, 7u8.hash_tree_root().unwrap()),
+            (path(&[]), 1, root),
+        ];
+        for (path, generalized_index, leaf) in cases {
+            let proof = value.prove(&path).unwrap();
+            assert_eq!(proof.generalized_index().unwrap(), generalized_index, "{path:?}");
+            assert_eq!(proof.leaf, leaf, "{path:?}");
+            assert!(proof.verify(root).is_ok(), "{path:?}");
+        }
+
+        let proof = value.prove(&path(&["c".into(), "flags".into(), 260.into()])).unwrap();
+        assert_eq!(proof.generalized_index().unwrap(), 43);
+        assert_eq!(proof.leaf[260 / 8 - 32], 1 << (260 % 8));
+        assert!(proof.verify(root).is_ok());
+    }
+
+    #[test]
+    fn test_prove_invalid_paths() {
+        let value = Outer::default();
+        for path in [
+            path(&["z".into()]),
+            path(&[0.into()]),
+            path(&["a".into(), 0.into()]),
+            path(&["b".into(), 0.into(), 0.into()]),
+            path(&["b".into(), 8.into()]),
+            path(&["d".into(), 3.into()]),
+            path(&["e".into(), 1.into()]),
+        ] {
+            assert!(
+                matches!(value.prove(&path), Err(MerkleizationError::InvalidPathElement(..))),
+                "{path:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_length_proofs() {
+        let list = List::<u64, 64>::try_from(vec![1, 2, 3, 4, 5]).unwrap();
+        let proof = list.prove_length().unwrap();
+        assert_eq!(proof.generalized_index().unwrap(), 3);
+        assert_eq!(proof.verify_length(list.hash_tree_root().unwrap()).unwrap(), 5);
+        assert_eq!(proof, list.clone().prove(&[PathElement::Length]).unwrap());
+        let mut other = list.clone();
+        other.push(6).unwrap();
+        assert!(proof.verify_length(other.hash_tree_root().unwrap()).is_err());
+
+        let bits = Bitlist::<300>::try_from([true, false, true].as_ref()).unwrap();
+        let proof = bits.prove_length().unwrap();
+        assert_eq!(proof.verify_length(bits.hash_tree_root().unwrap()).unwrap(), 3);
+
+        let value =
+            Outer { b: List::try_from(vec![1, 2, 3, 4, 5, 6]).unwrap(), ..Default::default() };
+        let root = value.hash_tree_root().unwrap();
+        let proof = value.prove(&["b".into(), PathElement::Length]).unwrap();
+        assert_eq!(proof.generalized_index().unwrap(), 19);
+        assert_eq!(proof.verify_length(root).unwrap(), 6);
+        // other nodes are not lengths
+        let proof = value.prove(&["b".into(), 5.into()]).unwrap();
+        assert!(proof.verify_length(root).is_err());
+    }
+
+    #[test]
+    fn test_parse_path() {
+        let cases = [
+            ("", path(&[])),
+            ("a", path(&["a".into()])),
+            (
+                "validators[1234].effective_balance",
+                path(&["validators".into(), 1234.into(), "effective_balance".into()]),
+            ),
+            ("d[1].flags[260]", path(&["d".into(), 1.into(), "flags".into(), 260.into()])),
+            ("b.__len__", path(&["b".into(), PathElement::Length])),
+            ("[3][0].x", path(&[3.into(), 0.into(), "x".into()])),
+        ];
+        for (string, expected) in cases {
+            assert_eq!(parse_path(string).unwrap(), expected, "{string}");
+            assert_eq!(format_path(&expected), string);
+        }
+
+        for (string, position) in [
+            (".a", 0),
+            ("a.", 2),
+            ("a..b", 2),
+            ("a[", 1),
+            ("a[]", 2),
+            ("a[-1]", 2),
+            ("a[+1]", 2),
+            ("a[1]b", 4),
+            ("a.[1]", 2),
+            ("a b", 1),
+        ] {
+            assert!(
+                matches!(parse_path(string), Err(MerkleizationError::InvalidPathSyntax(p)) if p == position),
+                "{string}"
+            );
+        }
+
+        let mut value = Outer::default();
+        value.d[1].x = 9;
+        let proof = value.prove(&parse_path("d[1].x").unwrap()).unwrap();
+        assert_eq!(proof.generalized_index().unwrap(), 90);
+        assert!(value.prove(&parse_path("d[1].y").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_helper_indices() {
+        // spec example: proving 9 and 14 in a tree of depth 3
+        assert_eq!(get_helper_indices(&[9, 14]), vec![15, 8, 6, 5]);
+        assert_eq!(get_helper_indices(&[8, 9]), vec![5, 3]);
+        assert!(get_helper_indices(&[1]).is_empty());
+    }
+
+    #[test]
+    fn test_multiproof() {
+        let mut value = Outer {
+            a: 3,
+            b: List::try_from(vec![1, 2, 3, 4, 5, 6]).unwrap(),
+            ..Default::default()
+        };
+        value.d[2].x = 4;
+        let root = value.hash_tree_root().unwrap();
+
+        let paths = [
+            path(&["a".into()]),
+            path(&["b".into(), 5.into()]),
+            path(&["b".into(), PathElement::Length]),
+            path(&["d".into(), 2.into(), "x".into()]),
+        ];
+        let paths = paths.iter().map(|path| path.as_slice()).collect::<Vec<_>>();
+        let multiproof = value.multiprove(&paths).unwrap();
+        assert_eq!(multiproof.indices, vec![8, 37, 19, 92]);
+        assert!(multiproof.verify(root).is_ok());
+
+        let branch_count = paths.iter().map(|path| value.prove(path).unwrap().depth()).sum();
+        assert!(multiproof.witnesses.len() < branch_count);
+
+        let mut tampered = multiproof.clone();
+        tampered.leaves[1] = Node::default();
+        assert!(tampered.verify(root).is_err());
+        let mut truncated = multiproof;
+        truncated.witnesses.pop();
+        assert!(truncated.verify(root).is_err());
+
+        let deep = Proof { leaf: root, branch: vec![Node::default(); 64], index: 0 };
+        assert!(Multiproof::from_proofs(&[deep]).is_err());
+        let wide = Proof { leaf: root, branch: vec![Node::default(); 63], index: usize::MAX };
+        assert!(Multiproof::from_proofs(&[wide]).is_err());
+    }
+
+    #[test]
+    fn test_proof_encoding() {
+        let value = Outer { a: 3, ..Default::default() };
+        let root = value.hash_tree_root().unwrap();
+        let paths = [path(&["a".into()]), path(&["c".into(), "x".into()])];
+        let paths = paths.iter().map(|path| path.as_slice()).collect::<Vec<_>>();
+
+        let mut proof = value.prove(paths[0]).unwrap();
+        let encoding = serialize(&proof).unwrap();
+        let decoded = Proof::deserialize(&encoding).unwrap();
+        assert!(decoded.verify(root).is_ok());
+        assert_eq!(decoded, proof);
+        let container = ProofContainer::try_from(&proof).unwrap();
+        assert_eq!(proof.hash_tree_root().unwrap(), container.hash_tree_root().unwrap());
+
+        let multiproof = value.multiprove(&paths).unwrap();
+        let decoded = Multiproof::deserialize(&serialize(&multiproof).unwrap()).unwrap();
+        assert!(decoded.verify(root).is_ok());
+        assert_eq!(decoded, multiproof);
+
+        proof.branch = vec![Node::default(); MAX_MERKLE_TREE_DEPTH + 1];
+        assert!(serialize(&proof).is_err());
+    }
+
+    #[test]
+    fn test_verify_proofs() {
+        let value = Outer {
+            a: 3,
+            b: List::try_from(vec![1, 2, 3, 4, 5, 6]).unwrap(),
+            ..Default::default()
+        };
+        let root = value.hash_tree_root().unwrap();
+        let paths = [
+            path(&["b".into(), 5.into()]),
+            path(&["a".into()]),
+            path(&["b".into(), 1.into()]),
+            path(&["b".into(), PathElement::Length]),
+            path(&["d".into(), 2.into(), "x".into()]),
+        ];
+        let mut proofs = paths.iter().map(|path| value.prove(path).unwrap()).collect::<Vec<_>>();
+        assert!(verify_proofs(root, &proofs).iter().all(Result::is_ok));
+
+        // a bad leaf, a bad witness above a shared node, and a proof for another tree
+        proofs[2].leaf = Node::default();
+        let depth = proofs[0].depth();
+        proofs[0].branch[depth - 1] = Node::default();
+        proofs.push(Proof { leaf: root, branch: vec![], index: 0 });
+        proofs.push(Proof { leaf: Node::default(), branch: vec![], index: 0 });
+        let results = verify_proofs(root, &proofs);
+        for (proof, result) in proofs.iter().zip(&results) {
+            assert_eq!(proof.verify(root).is_ok(), result.is_ok());
+        }
+        assert_eq!(
+            results.iter().map(Result::is_ok).collect::<Vec<_>>(),
+            vec![false, true, false, true, true, true, false]
+        );
+
+        // too deep for a generalized index, e.g. as decoded from untrusted input
+        let deep = Proof { leaf: root, branch: vec![Node::default(); 64], index: 1 };
+        assert!(deep.generalized_index().is_err());
+        let deep = Proof::deserialize(&serialize(&deep).unwrap()).unwrap();
+        proofs.push(deep);
+        let results = verify_proofs(root, &proofs);
+        assert!(matches!(results.last(), Some(Err(Error::InvalidProof))));
+        assert!(results[1].is_ok());
+    }
 }
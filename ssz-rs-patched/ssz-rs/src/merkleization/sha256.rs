@@ -0,0 +1,141 @@
+//! A SHA-256 of two chunks that can be evaluated at compile time, so that tables of hashes
+//! like the roots of "zero" subtrees can be computed without a build script.
+//!
+//! Runtime hashing uses the `sha2` crate; this is only suitable for constant evaluation.
+use crate::merkleization::BYTES_PER_CHUNK;
+
+pub(crate) const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+pub(crate) const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+pub(crate) const fn small_sigma0(x: u32) -> u32 {
+    x.rotate_right(7) ^ x.rotate_right(18) ^ (x >> 3)
+}
+
+pub(crate) const fn small_sigma1(x: u32) -> u32 {
+    x.rotate_right(17) ^ x.rotate_right(19) ^ (x >> 10)
+}
+
+const fn big_sigma0(x: u32) -> u32 {
+    x.rotate_right(2) ^ x.rotate_right(13) ^ x.rotate_right(22)
+}
+
+const fn big_sigma1(x: u32) -> u32 {
+    x.rotate_right(6) ^ x.rotate_right(11) ^ x.rotate_right(25)
+}
+
+const fn compress(state: [u32; 8], block: &[u8; 64]) -> [u32; 8] {
+    let mut w = [0u32; 64];
+    let mut t = 0;
+    while t < 16 {
+        w[t] = u32::from_be_bytes([
+            block[4 * t],
+            block[4 * t + 1],
+            block[4 * t + 2],
+            block[4 * t + 3],
+        ]);
+        t += 1;
+    }
+    while t < 64 {
+        w[t] = small_sigma1(w[t - 2])
+            .wrapping_add(w[t - 7])
+            .wrapping_add(small_sigma0(w[t - 15]))
+            .wrapping_add(w[t - 16]);
+        t += 1;
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+    let mut round = 0;
+    while round < 64 {
+        let ch = (e & f) ^ (!e & g);
+        let t1 = h
+            .wrapping_add(big_sigma1(e))
+            .wrapping_add(ch)
+            .wrapping_add(K[round])
+            .wrapping_add(w[round]);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = big_sigma0(a).wrapping_add(maj);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+        round += 1;
+    }
+    [
+        state[0].wrapping_add(a),
+        state[1].wrapping_add(b),
+        state[2].wrapping_add(c),
+        state[3].wrapping_add(d),
+        state[4].wrapping_add(e),
+        state[5].wrapping_add(f),
+        state[6].wrapping_add(g),
+        state[7].wrapping_add(h),
+    ]
+}
+
+/// Return the SHA-256 hash of `left || right`.
+pub(crate) const fn hash_pair(
+    left: &[u8; BYTES_PER_CHUNK],
+    right: &[u8; BYTES_PER_CHUNK],
+) -> [u8; BYTES_PER_CHUNK] {
+    let mut block = [0u8; 64];
+    let mut i = 0;
+    while i < BYTES_PER_CHUNK {
+        block[i] = left[i];
+        block[BYTES_PER_CHUNK + i] = right[i];
+        i += 1;
+    }
+    let state = compress(IV, &block);
+
+    // every message is 512 bits long, so is followed by the same padding block
+    let mut padding = [0u8; 64];
+    padding[0] = 0x80;
+    padding[62] = 0x02;
+    let state = compress(state, &padding);
+
+    let mut digest = [0u8; BYTES_PER_CHUNK];
+    let mut i = 0;
+    while i < 8 {
+        let word = state[i].to_be_bytes();
+        digest[4 * i] = word[0];
+        digest[4 * i + 1] = word[1];
+        digest[4 * i + 2] = word[2];
+        digest[4 * i + 3] = word[3];
+        i += 1;
+    }
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn test_hash_pair_matches_sha256() {
+        let left = [7u8; BYTES_PER_CHUNK];
+        let mut right = [0u8; BYTES_PER_CHUNK];
+        for (i, byte) in right.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        for (left, right) in [(left, right), (right, left), ([0u8; 32], [0xffu8; 32])] {
+            let expected = Sha256::new().chain_update(left).chain_update(right).finalize();
+            assert_eq!(hash_pair(&left, &right).as_slice(), expected.as_slice());
+        }
+    }
+}
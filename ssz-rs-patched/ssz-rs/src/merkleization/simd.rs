@@ -0,0 +1,206 @@
+//! Multi-message SHA-256 over pairs of nodes.
+//!
+//! Every hash in a Merkle tree has the same shape: a single 64-byte message (two child nodes)
+//! followed by a fixed padding block. This module runs `LANES` such hashes in lockstep, keeping
+//! each word of the working state as an array with one entry per message so that the round
+//! function compiles down to vector instructions (AVX2 on `x86_64`, NEON on `aarch64`).
+use crate::merkleization::{
+    record_hash,
+    sha256::{small_sigma0, small_sigma1, IV, K},
+    BYTES_PER_CHUNK,
+};
+use sha2::{Digest, Sha256};
+
+// Number of node pairs hashed per call into the vectorized compression function.
+const LANES: usize = 8;
+
+type Lanes = [u32; LANES];
+
+// The second block of every 64-byte message is the same padding: a single set bit, zeros and
+// the message length of 512 bits. Its schedule (with the round constants folded in) is
+// identical for every hash and so is computed once at build time.
+const PADDING_SCHEDULE: [u32; 64] = {
+    let mut w = [0u32; 64];
+    w[0] = 0x80000000;
+    w[15] = 512;
+    let mut i = 16;
+    while i < 64 {
+        w[i] = small_sigma1(w[i - 2])
+            .wrapping_add(w[i - 7])
+            .wrapping_add(small_sigma0(w[i - 15]))
+            .wrapping_add(w[i - 16]);
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 64 {
+        w[i] = w[i].wrapping_add(K[i]);
+        i += 1;
+    }
+    w
+};
+
+#[inline(always)]
+fn map(f: impl Fn(usize) -> u32) -> Lanes {
+    let mut out = [0u32; LANES];
+    for (lane, out) in out.iter_mut().enumerate() {
+        *out = f(lane);
+    }
+    out
+}
+
+#[inline(always)]
+fn add(a: &Lanes, b: &Lanes) -> Lanes {
+    map(|i| a[i].wrapping_add(b[i]))
+}
+
+#[inline(always)]
+fn big_sigma0(x: &Lanes) -> Lanes {
+    map(|i| x[i].rotate_right(2) ^ x[i].rotate_right(13) ^ x[i].rotate_right(22))
+}
+
+#[inline(always)]
+fn big_sigma1(x: &Lanes) -> Lanes {
+    map(|i| x[i].rotate_right(6) ^ x[i].rotate_right(11) ^ x[i].rotate_right(25))
+}
+
+// Run the 64 rounds of the compression function over `state` where `schedule(round)` yields
+// the message word for that round with the round constant already added.
+#[inline(always)]
+fn compress(state: &mut [Lanes; 8], schedule: impl Fn(usize) -> Lanes) {
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for round in 0..64 {
+        let w = schedule(round);
+        let ch = map(|i| (e[i] & f[i]) ^ (!e[i] & g[i]));
+        let t1 = add(&add(&add(&h, &big_sigma1(&e)), &ch), &w);
+        let maj = map(|i| (a[i] & b[i]) ^ (a[i] & c[i]) ^ (b[i] & c[i]));
+        let t2 = add(&big_sigma0(&a), &maj);
+        h = g;
+        g = f;
+        f = e;
+        e = add(&d, &t1);
+        d = c;
+        c = b;
+        b = a;
+        a = add(&t1, &t2);
+    }
+    for (word, delta) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *word = add(word, &delta);
+    }
+}
+
+// Hash `LANES` consecutive 64-byte messages from `children` into `LANES` consecutive digests
+// in `parents`.
+#[inline(always)]
+fn hash_batch(children: &[u8], parents: &mut [u8]) {
+    let mut schedule = [[0u32; LANES]; 64];
+    for (t, word) in schedule.iter_mut().enumerate().take(16) {
+        *word = map(|i| {
+            let offset = i * 2 * BYTES_PER_CHUNK + t * 4;
+            u32::from_be_bytes(children[offset..offset + 4].try_into().expect("is one word"))
+        });
+    }
+    for t in 16..64 {
+        schedule[t] = map(|i| {
+            small_sigma1(schedule[t - 2][i])
+                .wrapping_add(schedule[t - 7][i])
+                .wrapping_add(small_sigma0(schedule[t - 15][i]))
+                .wrapping_add(schedule[t - 16][i])
+        });
+    }
+    for (t, word) in schedule.iter_mut().enumerate() {
+        *word = map(|i| word[i].wrapping_add(K[t]));
+    }
+
+    let mut state = IV.map(|word| [word; LANES]);
+    compress(&mut state, |round| schedule[round]);
+    compress(&mut state, |round| [PADDING_SCHEDULE[round]; LANES]);
+
+    for (i, parent) in parents.chunks_exact_mut(BYTES_PER_CHUNK).enumerate() {
+        for (word, out) in state.iter().zip(parent.chunks_exact_mut(4)) {
+            out.copy_from_slice(&word[i].to_be_bytes());
+        }
+    }
+}
+
+#[inline(always)]
+fn hash_layer_portable(children: &[u8], parents: &mut [u8]) {
+    const BATCH: usize = LANES * BYTES_PER_CHUNK;
+    let mut child_batches = children.chunks_exact(2 * BATCH);
+    let mut parent_batches = parents.chunks_exact_mut(BATCH);
+    for (children, parents) in (&mut child_batches).zip(&mut parent_batches) {
+        hash_batch(children, parents);
+    }
+
+    let mut hasher = Sha256::new();
+    let remainder = child_batches.remainder().chunks_exact(2 * BYTES_PER_CHUNK);
+    for (children, parent) in
+        remainder.zip(parent_batches.into_remainder().chunks_exact_mut(BYTES_PER_CHUNK))
+    {
+        hasher.update(children);
+        parent.copy_from_slice(&hasher.finalize_reset());
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn hash_layer_avx2(children: &[u8], parents: &mut [u8]) {
+    hash_layer_portable(children, parents)
+}
+
+/// Hash each pair of nodes in `children` into the corresponding node of `parents`.
+///
+/// Invariant: `children.len() == 2 * parents.len()`
+/// Invariant: `parents.len() % BYTES_PER_CHUNK == 0`
+pub(crate) fn hash_layer(children: &[u8], parents: &mut [u8]) {
+    debug_assert_eq!(children.len(), 2 * parents.len());
+    #[cfg(target_arch = "x86_64")]
+    if std::is_x86_feature_detected!("avx2") {
+        // SAFETY: the CPU was just checked to support the instructions enabled for this function
+        unsafe { hash_layer_avx2(children, parents) }
+    } else {
+        hash_layer_portable(children, parents)
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    hash_layer_portable(children, parents);
+
+    // hashing bypasses `hash_nodes`, so record each step for any witness being taken
+    for (children, parent) in
+        children.chunks_exact(2 * BYTES_PER_CHUNK).zip(parents.chunks_exact(BYTES_PER_CHUNK))
+    {
+        let (left, right) = children.split_at(BYTES_PER_CHUNK);
+        record_hash(left, right, parent);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkleization::{leaf_count_for, merkleize, merkleize_chunks_with_virtual_padding};
+
+    #[test]
+    fn test_hash_layer_matches_sha256() {
+        for pairs in 0..=20 {
+            let children =
+                (0..pairs * 2 * BYTES_PER_CHUNK).map(|i| (i * 7 % 256) as u8).collect::<Vec<_>>();
+            let mut parents = vec![0u8; pairs * BYTES_PER_CHUNK];
+            hash_layer(&children, &mut parents);
+            for (children, parent) in
+                children.chunks(2 * BYTES_PER_CHUNK).zip(parents.chunks(BYTES_PER_CHUNK))
+            {
+                assert_eq!(Sha256::digest(children).as_slice(), parent);
+            }
+        }
+    }
+
+    #[test]
+    fn test_simd_merkleization_matches_serial() {
+        for (count, limit) in [(16, None), (17, None), (100, Some(1 << 10)), (1000, Some(1 << 40))]
+        {
+            let chunks = (0..count * BYTES_PER_CHUNK).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+            let leaf_count = leaf_count_for(count, limit).unwrap();
+            let expected =
+                merkleize_chunks_with_virtual_padding::<Sha256>(&chunks, leaf_count).unwrap();
+            assert_eq!(merkleize(&chunks, limit).unwrap(), expected);
+        }
+    }
+}
@@ -0,0 +1,434 @@
+#[cfg(feature = "std")]
+use crate::merkleization::backend;
+use crate::{
+    lib::*,
+    merkleization::{
+        hash_nodes, leaf_count_for, mix_in_length, Hasher, MerkleizationError, Node, Proof,
+        BYTES_PER_CHUNK, MAX_MERKLE_TREE_DEPTH,
+    },
+};
+use sha2::Sha256;
+
+/// Chunk count from which `merkleize` looks for a buffer of chunks that is mostly zero, to
+/// hash only the chunks which are not.
+const SPARSE_MERKLEIZATION_THRESHOLD: usize = 64;
+
+/// A binary Merkle tree of fixed depth where only the non-zero nodes are stored.
+///
+/// Leaves default to the zero node, so the tree can have up to `2**depth` leaves while
+/// using memory proportional to the number of leaves actually set. Hashing follows SSZ
+/// merkleization, so the root of a tree matches `merkleize` over the same leaves padded to
+/// `2**depth` chunks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseMerkleTree {
+    depth: usize,
+    // keyed by (height, index), where leaves are at height 0
+    nodes: BTreeMap<(usize, usize), Node>,
+}
+
+impl SparseMerkleTree {
+    /// Create an empty tree with `2**depth` leaves.
+    pub fn new(depth: usize) -> Result<Self, MerkleizationError> {
+        // the root of an empty tree of depth `d` is the zero hash at index `d`, and the index
+        // of every leaf must fit in a `usize`
+        let max_depth = MAX_MERKLE_TREE_DEPTH.min(usize::BITS as usize);
+        if depth >= max_depth {
+            return Err(MerkleizationError::InputExceedsLimit(max_depth - 1))
+        }
+        Ok(Self { depth, nodes: BTreeMap::new() })
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn capacity(&self) -> usize {
+        1 << self.depth
+    }
+
+    fn node<H: Hasher>(&self, height: usize, index: usize) -> Node {
+        match self.nodes.get(&(height, index)) {
+            Some(node) => *node,
+            None => H::zero_hash(height),
+        }
+    }
+
+    pub fn root(&self) -> Node {
+        #[cfg(feature = "std")]
+        if backend::is_active() {
+            return self.node::<backend::ScopedHasher>(self.depth, 0)
+        }
+        self.node::<Sha256>(self.depth, 0)
+    }
+
+    /// The root with the number of leaves in use mixed in, as for the root of an SSZ list or
+    /// of the deposit contract.
+    pub fn root_with_length(&self, length: usize) -> Node {
+        mix_in_length(&self.root(), length)
+    }
+
+    pub fn get(&self, index: usize) -> Node {
+        self.node::<Sha256>(0, index)
+    }
+
+    /// Set the leaf at `index` and update the nodes above it.
+    ///
+    /// Nodes are hashed as by `merkleize`, i.e. with the hasher installed by
+    /// `hash_tree_root_with_hasher` if any, so a tree must be read under the same hasher it was
+    /// built with.
+    pub fn insert(&mut self, index: usize, leaf: Node) -> Result<(), MerkleizationError> {
+        #[cfg(feature = "std")]
+        if backend::is_active() {
+            return self.insert_with_hasher::<backend::ScopedHasher>(index, leaf)
+        }
+        self.insert_with_hasher::<Sha256>(index, leaf)
+    }
+
+    /// Set the leaf at `index` and update the nodes above it, combining nodes with `H`.
+    pub fn insert_with_hasher<H: Hasher>(
+        &mut self,
+        index: usize,
+        leaf: Node,
+    ) -> Result<(), MerkleizationError> {
+        if index >= self.capacity() {
+            return Err(MerkleizationError::InputExceedsLimit(self.capacity()))
+        }
+        let mut hasher = H::default();
+        let mut node = leaf;
+        let mut index = index;
+        for height in 0..=self.depth {
+            if node == H::zero_hash(height) {
+                self.nodes.remove(&(height, index));
+            } else {
+                self.nodes.insert((height, index), node);
+            }
+            if height == self.depth {
+                break
+            }
+            let sibling = self.node::<H>(height, index ^ 1);
+            let (left, right) = if index & 1 == 0 { (node, sibling) } else { (sibling, node) };
+            hash_nodes(&mut hasher, left.as_ref(), right.as_ref(), node.as_mut());
+            index /= 2;
+        }
+        Ok(())
+    }
+
+    /// Build a proof of the leaf at `index` against `self.root()`.
+    pub fn prove(&self, index: usize) -> Result<Proof, MerkleizationError> {
+        if index >= self.capacity() {
+            return Err(MerkleizationError::InputExceedsLimit(self.capacity()))
+        }
+        #[cfg(feature = "std")]
+        if backend::is_active() {
+            return Ok(self.prove_with_hasher::<backend::ScopedHasher>(index))
+        }
+        Ok(self.prove_with_hasher::<Sha256>(index))
+    }
+
+    fn prove_with_hasher<H: Hasher>(&self, index: usize) -> Proof {
+        let branch =
+            (0..self.depth).map(|height| self.node::<H>(height, (index >> height) ^ 1)).collect();
+        Proof { leaf: self.get(index), branch, index }
+    }
+
+    /// Verify `proof` is a proof of a leaf of a tree of this depth against `self.root()`.
+    pub fn verify(&self, proof: &Proof) -> Result<(), MerkleizationError> {
+        if proof.depth() != self.depth {
+            return Err(MerkleizationError::InvalidProof)
+        }
+        #[cfg(feature = "std")]
+        if backend::is_active() {
+            return proof.verify_with_hasher::<backend::ScopedHasher>(self.root())
+        }
+        proof.verify(self.root())
+    }
+}
+
+/// The chunks of a value to merkleize, of which only those that are not zero are stored.
+///
+/// The chunks are the leaves of a virtual tree of zero chunks, so computing its root takes time
+/// proportional to the number of chunks set (times the depth of the tree) rather than to the
+/// number of leaves, e.g. for a large and mostly empty `Vector<u8, N>`. Roots match those of
+/// `merkleize` over the same chunks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseChunks {
+    leaf_count: usize,
+    chunks: BTreeMap<usize, Node>,
+}
+
+impl SparseChunks {
+    /// Create an empty set of chunks for a tree of `limit` chunks, padded to the next power of
+    /// two as by `merkleize`.
+    pub fn new(limit: usize) -> Result<Self, MerkleizationError> {
+        let leaf_count = leaf_count_for(0, Some(limit))?;
+        if leaf_count.trailing_zeros() as usize >= MAX_MERKLE_TREE_DEPTH {
+            return Err(MerkleizationError::InputExceedsLimit(limit))
+        }
+        Ok(Self { leaf_count, chunks: BTreeMap::new() })
+    }
+
+    /// Collect the chunks of `chunks` which are not zero, for a tree of `limit` chunks or,
+    /// without a limit, of as many chunks as given.
+    ///
+    /// Invariant: `chunks.len() % BYTES_PER_CHUNK == 0`
+    pub fn from_chunks(chunks: &[u8], limit: Option<usize>) -> Result<Self, MerkleizationError> {
+        let chunk_count = chunks.len() / BYTES_PER_CHUNK;
+        let mut sparse = Self::new(leaf_count_for(chunk_count, limit)?)?;
+        sparse.chunks = populated_chunks(chunks).collect();
+        Ok(sparse)
+    }
+
+    /// The number of leaves of the tree, a power of two.
+    pub fn leaf_count(&self) -> usize {
+        self.leaf_count
+    }
+
+    /// The number of chunks which are not zero.
+    pub fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Node {
+        self.chunks.get(&index).copied().unwrap_or_default()
+    }
+
+    /// Set the chunk at `index`, which a zero `chunk` clears.
+    pub fn insert(&mut self, index: usize, chunk: Node) -> Result<(), MerkleizationError> {
+        if index >= self.leaf_count {
+            return Err(MerkleizationError::InputExceedsLimit(self.leaf_count))
+        }
+        if chunk == Node::default() {
+            self.chunks.remove(&index);
+        } else {
+            self.chunks.insert(index, chunk);
+        }
+        Ok(())
+    }
+
+    pub fn root(&self) -> Node {
+        #[cfg(feature = "std")]
+        if backend::is_active() {
+            return self.root_with_hasher::<backend::ScopedHasher>()
+        }
+        self.root_with_hasher::<Sha256>()
+    }
+
+    /// The root of the tree, combining nodes with `H`.
+    pub fn root_with_hasher<H: Hasher>(&self) -> Node {
+        merkleize_sparse::<H>(
+            self.chunks.iter().map(|(index, chunk)| (*index, *chunk)).collect(),
+            self.leaf_count,
+        )
+    }
+}
+
+fn populated_chunks(chunks: &[u8]) -> impl Iterator<Item = (usize, Node)> + '_ {
+    chunks
+        .chunks_exact(BYTES_PER_CHUNK)
+        .enumerate()
+        .filter(|(_, chunk)| chunk.iter().any(|&byte| byte != 0))
+        .map(|(index, chunk)| (index, chunk.try_into().expect("is one chunk")))
+}
+
+// Compute the root of a tree of `leaf_count` leaves (a power of two) where the leaves not in
+// `layer` are zero, given in ascending order of their index.
+fn merkleize_sparse<H: Hasher>(mut layer: Vec<(usize, Node)>, leaf_count: usize) -> Node {
+    let depth = leaf_count.trailing_zeros() as usize;
+    if layer.is_empty() {
+        return H::zero_hash(depth)
+    }
+    let mut hasher = H::default();
+    for height in 0..depth {
+        let zero = H::zero_hash(height);
+        let mut parents = Vec::with_capacity(layer.len().div_ceil(2));
+        let mut i = 0;
+        while i < layer.len() {
+            let (index, node) = layer[i];
+            let sibling = layer.get(i + 1).filter(|(sibling, _)| *sibling == index + 1);
+            let (left, right) = match sibling {
+                Some((_, sibling)) if index & 1 == 0 => {
+                    i += 1;
+                    (node, *sibling)
+                }
+                _ if index & 1 == 0 => (node, zero),
+                _ => (zero, node),
+            };
+            let mut parent = Node::default();
+            hash_nodes(&mut hasher, left.as_ref(), right.as_ref(), parent.as_mut());
+            parents.push((index / 2, parent));
+            i += 1;
+        }
+        layer = parents;
+    }
+    layer[0].1
+}
+
+// Compute the root of the tree of `leaf_count` leaves over `chunks` from only the chunks which
+// are not zero, if few enough are to make that cheaper than hashing every chunk.
+pub(crate) fn merkleize_if_sparse<H: Hasher>(chunks: &[u8], leaf_count: usize) -> Option<Node> {
+    let chunk_count = chunks.len() / BYTES_PER_CHUNK;
+    if chunk_count < SPARSE_MERKLEIZATION_THRESHOLD {
+        return None
+    }
+    // each chunk set costs up to one hash for each level of the tree, against about one hash
+    // per chunk to hash them all
+    let depth = leaf_count.trailing_zeros() as usize;
+    let budget = chunk_count / (2 * depth);
+    let mut populated = Vec::new();
+    for chunk in populated_chunks(chunks) {
+        if populated.len() == budget {
+            return None
+        }
+        populated.push(chunk);
+    }
+    Some(merkleize_sparse::<H>(populated, leaf_count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkleization::{
+        merkleize, merkleize_chunks_with_virtual_padding, MAX_MERKLE_TREE_DEPTH,
+    };
+
+    fn leaf(i: u8) -> Node {
+        Node::try_from([i; 32].as_ref()).unwrap()
+    }
+
+    #[test]
+    fn test_sparse_tree_matches_merkleize() {
+        let mut tree = SparseMerkleTree::new(10).unwrap();
+        assert_eq!(tree.root(), merkleize(&[], Some(1024)).unwrap());
+
+        let mut chunks = vec![0u8; 6 * 32];
+        for i in [0, 3, 5] {
+            tree.insert(i, leaf(i as u8 + 1)).unwrap();
+            chunks[i * 32..(i + 1) * 32].copy_from_slice(leaf(i as u8 + 1).as_ref());
+        }
+        assert_eq!(tree.root(), merkleize(&chunks, Some(1024)).unwrap());
+
+        // clearing a leaf prunes the nodes above it
+        tree.insert(5, Node::default()).unwrap();
+        tree.insert(3, Node::default()).unwrap();
+        tree.insert(0, Node::default()).unwrap();
+        assert!(tree.nodes.is_empty());
+        assert_eq!(tree.root(), SparseMerkleTree::new(10).unwrap().root());
+    }
+
+    #[test]
+    fn test_sparse_chunks() {
+        let mut chunks = vec![0u8; 1000 * 32];
+        for i in [0, 1, 2, 511, 999] {
+            chunks[i * 32..(i + 1) * 32].copy_from_slice(leaf(i as u8).as_ref());
+        }
+        let expected = merkleize_chunks_with_virtual_padding::<Sha256>(&chunks, 1 << 20).unwrap();
+        let sparse = SparseChunks::from_chunks(&chunks, Some(1 << 20)).unwrap();
+        // the chunk at index 0 is zero
+        assert_eq!(sparse.len(), 4);
+        assert_eq!(sparse.root(), expected);
+        assert_eq!(merkleize(&chunks, Some(1 << 20)).unwrap(), expected);
+        assert_eq!(merkleize_if_sparse::<Sha256>(&chunks, 1 << 20), Some(expected));
+
+        let mut other = SparseChunks::new(1 << 20).unwrap();
+        for i in [1, 2, 511, 999] {
+            other.insert(i, leaf(i as u8)).unwrap();
+        }
+        assert_eq!(other, sparse);
+        other.insert((1 << 20) - 1, leaf(1)).unwrap();
+        chunks.resize(32 << 20, 0);
+        chunks[(32 << 20) - 32..].copy_from_slice(leaf(1).as_ref());
+        assert_eq!(other.root(), merkleize(&chunks, None).unwrap());
+        assert!(other.insert(1 << 20, leaf(1)).is_err());
+
+        assert_eq!(SparseChunks::new(0).unwrap().root(), merkleize(&[], Some(0)).unwrap());
+        assert_eq!(SparseChunks::new(8).unwrap().root(), merkleize(&[], Some(8)).unwrap());
+
+        // too many chunks are set to gain anything from skipping the others
+        let dense = (0..1000 * 32).map(|i| i as u8).collect::<Vec<_>>();
+        assert_eq!(merkleize_if_sparse::<Sha256>(&dense, 1024), None);
+    }
+
+    #[test]
+    fn test_sparse_tree_proofs() {
+        let mut tree = SparseMerkleTree::new(32).unwrap();
+        tree.insert(7, leaf(1)).unwrap();
+        tree.insert(1 << 20, leaf(2)).unwrap();
+
+        for index in [7, 1 << 20, 12345] {
+            let proof = tree.prove(index).unwrap();
+            assert_eq!(proof.leaf, tree.get(index));
+            assert!(tree.verify(&proof).is_ok());
+        }
+
+        let mut proof = tree.prove(7).unwrap();
+        proof.leaf = leaf(3);
+        assert!(tree.verify(&proof).is_err());
+
+        assert!(tree.insert(1 << 32, leaf(1)).is_err());
+        assert!(SparseMerkleTree::new(64).is_err());
+    }
+
+    #[test]
+    fn test_sparse_tree_depth_fits_usize() {
+        assert!(SparseMerkleTree::new(usize::BITS as usize).is_err());
+        let depth = MAX_MERKLE_TREE_DEPTH.min(usize::BITS as usize) - 1;
+        let mut tree = SparseMerkleTree::new(depth).unwrap();
+        assert_eq!(tree.capacity(), 1 << depth);
+        tree.insert(tree.capacity() - 1, leaf(1)).unwrap();
+        assert!(tree.verify(&tree.prove(tree.capacity() - 1).unwrap()).is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sparse_tree_with_scoped_hasher() {
+        use crate::merkleization::{hash_tree_root_with_hasher, merkleize_with_hasher, Merkleized};
+        use sha2::Digest;
+
+        // SHA-256 over a prefixed input, so that roots differ from those of SSZ
+        #[derive(Default)]
+        struct PrefixedSha256(Sha256);
+
+        impl Hasher for PrefixedSha256 {
+            fn hash(&mut self, left: &[u8], right: &[u8]) -> [u8; BYTES_PER_CHUNK] {
+                self.0.update([1]);
+                self.0.hash(left, right)
+            }
+        }
+
+        // merkleizes its leaves with a sparse tree, checking a proof along the way
+        struct Leaves(Vec<(usize, Node)>);
+
+        impl Merkleized for Leaves {
+            fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
+                let mut tree = SparseMerkleTree::new(10)?;
+                for (index, leaf) in &self.0 {
+                    tree.insert(*index, *leaf)?;
+                }
+                tree.verify(&tree.prove(self.0[0].0)?)?;
+                Ok(tree.root())
+            }
+        }
+
+        let leaves = Leaves(vec![(0, leaf(1)), (3, leaf(2)), (1000, leaf(3))]);
+        let mut chunks = vec![0u8; 1001 * 32];
+        for (index, leaf) in &leaves.0 {
+            chunks[index * 32..(index + 1) * 32].copy_from_slice(leaf.as_ref());
+        }
+        let expected = merkleize_with_hasher::<PrefixedSha256>(&chunks, Some(1024)).unwrap();
+        assert_ne!(expected, merkleize(&chunks, Some(1024)).unwrap());
+        assert_eq!(hash_tree_root_with_hasher::<PrefixedSha256, _>(&leaves).unwrap(), expected);
+        assert_eq!(leaves.hash_tree_root().unwrap(), merkleize(&chunks, Some(1024)).unwrap());
+        let empty = Leaves(vec![(0, Node::default())]);
+        assert_eq!(
+            hash_tree_root_with_hasher::<PrefixedSha256, _>(&empty).unwrap(),
+            PrefixedSha256::zero_hash(10)
+        );
+
+        let sparse = SparseChunks::from_chunks(&chunks, Some(1024)).unwrap();
+        assert_eq!(sparse.root_with_hasher::<PrefixedSha256>(), expected);
+    }
+}
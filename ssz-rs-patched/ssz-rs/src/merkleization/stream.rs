@@ -0,0 +1,148 @@
+use crate::{
+    lib::*,
+    merkleization::{
+        hash_nodes, leaf_count_for, Hasher, MerkleizationError, Node, BYTES_PER_CHUNK,
+    },
+};
+use sha2::Sha256;
+
+/// Computes the same root as [`merkleize`](crate::merkleization::merkleize) from chunks fed in
+/// one at a time, so the leaves never have to be held in memory together.
+///
+/// Only the root of each complete subtree seen so far is kept, one per height of the tree,
+/// so memory use is logarithmic in the number of chunks.
+///
+/// Bytes passed to `update` are packed into chunks as by
+/// [`pack_bytes`](crate::merkleization::pack_bytes), so the serializations of basic values can be
+/// streamed directly.
+#[derive(Debug, Clone, Default)]
+pub struct MerkleizerStream<H: Hasher = Sha256> {
+    hasher: H,
+    // `subtrees[height]` is the root of a complete subtree of that height waiting for its
+    // right sibling
+    subtrees: Vec<Option<Node>>,
+    chunk_count: usize,
+    partial_chunk: [u8; BYTES_PER_CHUNK],
+    partial_len: usize,
+}
+
+impl MerkleizerStream {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<H: Hasher> MerkleizerStream<H> {
+    /// Number of chunks folded into the tree so far, including any partially filled chunk.
+    pub fn chunk_count(&self) -> usize {
+        self.chunk_count + (self.partial_len != 0) as usize
+    }
+
+    /// Append `bytes` to the leaves of the tree.
+    pub fn update(&mut self, mut bytes: &[u8]) {
+        if self.partial_len != 0 {
+            let take = bytes.len().min(BYTES_PER_CHUNK - self.partial_len);
+            self.partial_chunk[self.partial_len..self.partial_len + take]
+                .copy_from_slice(&bytes[..take]);
+            self.partial_len += take;
+            bytes = &bytes[take..];
+            if self.partial_len < BYTES_PER_CHUNK {
+                return
+            }
+            let chunk = Node::from(self.partial_chunk);
+            self.partial_len = 0;
+            self.push_chunk(chunk);
+        }
+        let mut chunks = bytes.chunks_exact(BYTES_PER_CHUNK);
+        for chunk in &mut chunks {
+            self.push_chunk(chunk.try_into().expect("is one chunk"));
+        }
+        let remainder = chunks.remainder();
+        self.partial_chunk[..remainder.len()].copy_from_slice(remainder);
+        self.partial_len = remainder.len();
+    }
+
+    fn push_chunk(&mut self, mut node: Node) {
+        let mut height = 0;
+        while let Some(Some(left)) = self.subtrees.get_mut(height).map(Option::take) {
+            let mut parent = Node::default();
+            hash_nodes(&mut self.hasher, left.as_ref(), node.as_ref(), parent.as_mut());
+            node = parent;
+            height += 1;
+        }
+        if height == self.subtrees.len() {
+            self.subtrees.push(None);
+        }
+        self.subtrees[height] = Some(node);
+        self.chunk_count += 1;
+    }
+
+    /// Return the root of the tree formed from all chunks given to `update`, padded with
+    /// "zero" chunks up to `limit` if provided, or else the next power of two.
+    pub fn finalize(mut self, limit: Option<usize>) -> Result<Node, MerkleizationError> {
+        if self.partial_len != 0 {
+            self.partial_chunk[self.partial_len..].fill(0);
+            let chunk = Node::from(self.partial_chunk);
+            self.push_chunk(chunk);
+        }
+        let leaf_count = leaf_count_for(self.chunk_count, limit)?;
+        let height = leaf_count.trailing_zeros() as usize;
+        if self.chunk_count == 0 {
+            return Ok(H::zero_hash(height))
+        }
+        // fold the pending subtrees together from the bottom, treating any missing right
+        // sibling as a "zero" subtree
+        let mut root: Option<Node> = None;
+        for depth in 0..height {
+            let subtree = self.subtrees.get(depth).copied().flatten();
+            let (left, right) = match (subtree, root) {
+                (Some(left), Some(right)) => (left, right),
+                (Some(left), None) => (left, H::zero_hash(depth)),
+                (None, Some(left)) => (left, H::zero_hash(depth)),
+                (None, None) => continue,
+            };
+            let mut parent = Node::default();
+            hash_nodes(&mut self.hasher, left.as_ref(), right.as_ref(), parent.as_mut());
+            root = Some(parent);
+        }
+        // with no padding required, the complete tree is the only pending subtree
+        Ok(root.or_else(|| self.subtrees.get(height).copied().flatten()).expect("has a root"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkleization::{merkleize, pack_bytes};
+
+    #[test]
+    fn test_stream_matches_merkleize() {
+        for count in [0, 1, 2, 3, 4, 5, 8, 13, 64, 100] {
+            let chunks = (0..count * BYTES_PER_CHUNK).map(|i| (i % 253) as u8).collect::<Vec<_>>();
+            for limit in [None, Some(count), Some(128), Some(1 << 40)] {
+                let mut stream = MerkleizerStream::new();
+                for chunk in chunks.chunks(BYTES_PER_CHUNK) {
+                    stream.update(chunk);
+                }
+                assert_eq!(stream.chunk_count(), count);
+                assert_eq!(stream.finalize(limit).unwrap(), merkleize(&chunks, limit).unwrap());
+            }
+        }
+
+        let mut stream = MerkleizerStream::new();
+        stream.update(&[0u8; 3 * BYTES_PER_CHUNK]);
+        assert!(matches!(stream.finalize(Some(2)), Err(MerkleizationError::InputExceedsLimit(2))));
+    }
+
+    #[test]
+    fn test_stream_packs_bytes() {
+        let bytes = (0..1000).map(|i| (i % 7) as u8).collect::<Vec<_>>();
+        let mut stream = MerkleizerStream::new();
+        for piece in bytes.chunks(45) {
+            stream.update(piece);
+        }
+        let mut chunks = bytes.clone();
+        pack_bytes(&mut chunks);
+        assert_eq!(stream.finalize(None).unwrap(), merkleize(&chunks, None).unwrap());
+    }
+}
@@ -0,0 +1,294 @@
+use crate::{
+    lib::*,
+    merkleization::{
+        hash_nodes, mix_in_length, zero_hash, GeneralizedIndex, MerkleizationError, Node, Proof,
+        BYTES_PER_CHUNK, MAX_MERKLE_TREE_DEPTH,
+    },
+};
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TreeNode {
+    Leaf(Node),
+    // the root of a subtree of the given height with only "zero" leaves
+    Zero(usize),
+    Branch { root: Node, left: Arc<TreeNode>, right: Arc<TreeNode> },
+}
+
+impl TreeNode {
+    fn root(&self) -> Node {
+        match self {
+            Self::Leaf(node) => *node,
+            Self::Zero(height) => Node::from(*zero_hash(*height)),
+            Self::Branch { root, .. } => *root,
+        }
+    }
+
+    fn branch(hasher: &mut Sha256, left: Arc<TreeNode>, right: Arc<TreeNode>) -> Self {
+        let mut root = Node::default();
+        hash_nodes(hasher, left.root().as_ref(), right.root().as_ref(), root.as_mut());
+        Self::Branch { root, left, right }
+    }
+
+    fn build(hasher: &mut Sha256, height: usize, chunks: &[u8]) -> Self {
+        if chunks.is_empty() {
+            return Self::Zero(height)
+        }
+        if height == 0 {
+            return Self::Leaf(chunks.try_into().expect("is one chunk"))
+        }
+        let split = chunks.len().min((1 << (height - 1)) * BYTES_PER_CHUNK);
+        let (left, right) = chunks.split_at(split);
+        let left = Arc::new(Self::build(hasher, height - 1, left));
+        let right = Arc::new(Self::build(hasher, height - 1, right));
+        Self::branch(hasher, left, right)
+    }
+
+    fn children(&self) -> (Arc<TreeNode>, Arc<TreeNode>) {
+        match self {
+            Self::Branch { left, right, .. } => (left.clone(), right.clone()),
+            Self::Zero(height) => {
+                let child = Arc::new(Self::Zero(height - 1));
+                (child.clone(), child)
+            }
+            Self::Leaf(_) => unreachable!("leaves have no children"),
+        }
+    }
+
+    // Push the generalized index of every node of this subtree, of the given `height` and at
+    // `index`, whose root differs from that of the node at the same position in `other`.
+    fn diff(
+        &self,
+        other: &Self,
+        height: usize,
+        index: GeneralizedIndex,
+        changed: &mut Vec<GeneralizedIndex>,
+    ) {
+        if core::ptr::eq(self, other) || self.root() == other.root() {
+            return
+        }
+        changed.push(index);
+        if height == 0 {
+            return
+        }
+        let (left, right) = self.children();
+        let (other_left, other_right) = other.children();
+        left.diff(&other_left, height - 1, 2 * index, changed);
+        right.diff(&other_right, height - 1, 2 * index + 1, changed);
+    }
+
+    // Set the leaf at `index` of this subtree of the given `height`, copying only the nodes
+    // along the path that are shared with other trees.
+    fn set(&mut self, hasher: &mut Sha256, height: usize, index: usize, leaf: Node) {
+        if height == 0 {
+            *self = Self::Leaf(leaf);
+            return
+        }
+        if let Self::Zero(_) = self {
+            let child = Arc::new(Self::Zero(height - 1));
+            *self = Self::Branch { root: Node::default(), left: child.clone(), right: child };
+        }
+        let Self::Branch { root, left, right } = self else { unreachable!("is a branch") };
+        let half = 1 << (height - 1);
+        if index < half {
+            Arc::make_mut(left).set(hasher, height - 1, index, leaf);
+        } else {
+            Arc::make_mut(right).set(hasher, height - 1, index - half, leaf);
+        }
+        hash_nodes(hasher, left.root().as_ref(), right.root().as_ref(), root.as_mut());
+    }
+}
+
+/// A binary Merkle tree of fixed depth that retains every intermediate node, so that
+/// updating a leaf only re-hashes the path from it to the root.
+///
+/// Subtrees are reference counted and shared between clones of a tree, so a clone is cheap
+/// and subsequent updates to either copy only the nodes along the updated path. This suits
+/// keeping the tree of each of a series of closely related values, e.g. the states of
+/// consecutive slots.
+///
+/// Hashing follows SSZ merkleization, so the root of a tree matches `merkleize` over the
+/// same leaves padded to `2**depth` chunks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleTree {
+    depth: usize,
+    root: Arc<TreeNode>,
+}
+
+impl MerkleTree {
+    /// Create a tree with `2**depth` leaves, all of which are zero.
+    pub fn new(depth: usize) -> Result<Self, MerkleizationError> {
+        Self::from_chunks(&[], depth)
+    }
+
+    /// Create a tree with `2**depth` leaves from `chunks`, padding with zero leaves.
+    ///
+    /// Invariant: `chunks.len() % BYTES_PER_CHUNK == 0`
+    pub fn from_chunks(chunks: &[u8], depth: usize) -> Result<Self, MerkleizationError> {
+        if depth >= MAX_MERKLE_TREE_DEPTH {
+            return Err(MerkleizationError::InputExceedsLimit(MAX_MERKLE_TREE_DEPTH - 1))
+        }
+        let capacity = 1 << depth;
+        if chunks.len() / BYTES_PER_CHUNK > capacity {
+            return Err(MerkleizationError::InputExceedsLimit(capacity))
+        }
+        let root = TreeNode::build(&mut Sha256::new(), depth, chunks);
+        Ok(Self { depth, root: Arc::new(root) })
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn capacity(&self) -> usize {
+        1 << self.depth
+    }
+
+    pub fn root(&self) -> Node {
+        self.root.root()
+    }
+
+    /// The root with `length` mixed in, as for the root of an SSZ list.
+    pub fn root_with_length(&self, length: usize) -> Node {
+        mix_in_length(&self.root(), length)
+    }
+
+    pub fn get(&self, index: usize) -> Result<Node, MerkleizationError> {
+        Ok(self.path(index)?.0)
+    }
+
+    /// Set the leaf at `index` and update the nodes above it.
+    pub fn set(&mut self, index: usize, leaf: Node) -> Result<(), MerkleizationError> {
+        if index >= self.capacity() {
+            return Err(MerkleizationError::InputExceedsLimit(self.capacity()))
+        }
+        Arc::make_mut(&mut self.root).set(&mut Sha256::new(), self.depth, index, leaf);
+        Ok(())
+    }
+
+    /// Build a proof of the leaf at `index` against `self.root()`.
+    pub fn prove(&self, index: usize) -> Result<Proof, MerkleizationError> {
+        let (leaf, branch) = self.path(index)?;
+        Ok(Proof { leaf, branch, index })
+    }
+
+    /// Return the generalized indices of the nodes whose roots differ between `self` and
+    /// `other`, in ascending order.
+    ///
+    /// A changed node implies a change to each of its ancestors, so the result includes every
+    /// node on the path from the root to each changed leaf. Subtrees shared between the two trees,
+    /// e.g. by cloning one from the other, are skipped without being compared node by node.
+    ///
+    /// Trees of different depths are not comparable below the root, so only the root is reported
+    /// for them (if their roots differ).
+    pub fn diff(&self, other: &Self) -> Vec<GeneralizedIndex> {
+        if self.depth != other.depth {
+            return if self.root() == other.root() { vec![] } else { vec![1] }
+        }
+        let mut changed = vec![];
+        self.root.diff(&other.root, self.depth, 1, &mut changed);
+        changed.sort_unstable();
+        changed
+    }
+
+    // Return the leaf at `index` and its branch, starting from the leaf.
+    fn path(&self, index: usize) -> Result<(Node, Vec<Node>), MerkleizationError> {
+        if index >= self.capacity() {
+            return Err(MerkleizationError::InputExceedsLimit(self.capacity()))
+        }
+        let mut branch = Vec::with_capacity(self.depth);
+        let mut node = self.root.as_ref();
+        for height in (1..=self.depth).rev() {
+            match node {
+                TreeNode::Branch { left, right, .. } => {
+                    let (next, sibling) = if (index >> (height - 1)) & 1 == 0 {
+                        (left, right)
+                    } else {
+                        (right, left)
+                    };
+                    branch.push(sibling.root());
+                    node = next.as_ref();
+                }
+                TreeNode::Zero(_) => {
+                    // every node below here is also zero
+                    branch.extend((0..height).rev().map(|height| Node::from(*zero_hash(height))));
+                    branch.reverse();
+                    return Ok((Node::default(), branch))
+                }
+                TreeNode::Leaf(_) => unreachable!("leaves are only at height 0"),
+            }
+        }
+        branch.reverse();
+        Ok((node.root(), branch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkleization::merkleize;
+
+    fn chunks(count: usize) -> Vec<u8> {
+        (0..count * BYTES_PER_CHUNK).map(|i| (i % 241) as u8).collect()
+    }
+
+    #[test]
+    fn test_tree_matches_merkleize() {
+        for (count, depth) in [(0, 0), (1, 0), (3, 2), (5, 4), (16, 4), (100, 20)] {
+            let chunks = chunks(count);
+            let tree = MerkleTree::from_chunks(&chunks, depth).unwrap();
+            assert_eq!(tree.root(), merkleize(&chunks, Some(1 << depth)).unwrap());
+        }
+        assert!(MerkleTree::from_chunks(&chunks(5), 2).is_err());
+
+        let mut chunks = chunks(10);
+        let mut tree = MerkleTree::from_chunks(&chunks, 5).unwrap();
+        let snapshot = tree.clone();
+        let leaf = Node::try_from([9u8; 32].as_ref()).unwrap();
+        for index in [3, 17] {
+            tree.set(index, leaf).unwrap();
+            chunks.resize(chunks.len().max((index + 1) * BYTES_PER_CHUNK), 0);
+            chunks[index * BYTES_PER_CHUNK..(index + 1) * BYTES_PER_CHUNK]
+                .copy_from_slice(leaf.as_ref());
+        }
+        assert_eq!(tree.root(), merkleize(&chunks, Some(32)).unwrap());
+        assert_eq!(tree.get(17).unwrap(), leaf);
+        assert!(tree.set(32, leaf).is_err());
+        // updates are not visible through earlier clones
+        assert_eq!(snapshot.get(17).unwrap(), Node::default());
+        assert_ne!(snapshot.root(), tree.root());
+    }
+
+    #[test]
+    fn test_tree_proofs() {
+        let mut tree = MerkleTree::from_chunks(&chunks(6), 10).unwrap();
+        tree.set(700, Node::try_from([1u8; 32].as_ref()).unwrap()).unwrap();
+        for index in [0, 5, 6, 700, 1023] {
+            let proof = tree.prove(index).unwrap();
+            assert_eq!(proof.leaf, tree.get(index).unwrap());
+            assert_eq!(proof.depth(), 10);
+            assert!(proof.verify(tree.root()).is_ok());
+        }
+        assert!(tree.prove(1024).is_err());
+    }
+
+    #[test]
+    fn test_tree_diff() {
+        let tree = MerkleTree::from_chunks(&chunks(5), 3).unwrap();
+        assert!(tree.diff(&tree.clone()).is_empty());
+
+        let mut other = tree.clone();
+        other.set(2, Node::try_from([1u8; 32].as_ref()).unwrap()).unwrap();
+        // the leaf at index 2 has generalized index 8 + 2, under 5 and 2
+        assert_eq!(tree.diff(&other), vec![1, 2, 5, 10]);
+        assert_eq!(other.diff(&tree), vec![1, 2, 5, 10]);
+
+        other.set(7, Node::try_from([1u8; 32].as_ref()).unwrap()).unwrap();
+        assert_eq!(tree.diff(&other), vec![1, 2, 3, 5, 7, 10, 15]);
+
+        // trees built independently compare by value
+        let rebuilt = MerkleTree::from_chunks(&chunks(5), 3).unwrap();
+        assert!(tree.diff(&rebuilt).is_empty());
+        assert_eq!(tree.diff(&MerkleTree::new(4).unwrap()), vec![1]);
+    }
+}
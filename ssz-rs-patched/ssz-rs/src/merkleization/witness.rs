@@ -0,0 +1,267 @@
+use crate::{
+    lib::*,
+    merkleization::{
+        zero_hash, GeneralizedIndex, Hasher, MerkleizationError, Merkleized, Node, BYTES_PER_CHUNK,
+        MAX_MERKLE_TREE_DEPTH,
+    },
+};
+use sha2::Sha256;
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+};
+
+/// A single invocation of the hash function made while computing a hash tree root.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HashStep {
+    pub left: Node,
+    pub right: Node,
+    pub parent: Node,
+}
+
+/// The sequence of hash invocations made while computing a hash tree root, in the order
+/// they were made.
+///
+/// Hashes of virtual "zero" subtrees are precomputed and so only appear as inputs to a step,
+/// never as the output of one.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WitnessTrace {
+    pub root: Node,
+    pub steps: Vec<HashStep>,
+}
+
+impl WitnessTrace {
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Re-compute every step of the trace and check the final output against `root`.
+    pub fn verify(&self) -> bool {
+        self.verify_with_hasher::<Sha256>()
+    }
+
+    /// Check the trace as `verify` does, for a trace recorded while computing a root with
+    /// [`hash_tree_root_with_hasher`](crate::hash_tree_root_with_hasher) and the hasher `H`.
+    pub fn verify_with_hasher<H: Hasher>(&self) -> bool {
+        let mut hasher = H::default();
+        for step in &self.steps {
+            if hasher.hash(step.left.as_ref(), step.right.as_ref()) != step.parent.as_ref() {
+                return false
+            }
+        }
+        match self.steps.last() {
+            Some(step) => step.parent == self.root,
+            None => true,
+        }
+    }
+
+    /// For each step, the positions of the earlier steps whose outputs are its left and right
+    /// inputs, or `None` for an input which is a chunk of the value or the root of a "zero"
+    /// subtree.
+    ///
+    /// This gives the wiring of a circuit re-computing the trace: only inputs without an
+    /// earlier step need to be supplied to it.
+    pub fn wiring(&self) -> Vec<[Option<usize>; 2]> {
+        let mut outputs = HashMap::with_capacity(self.steps.len());
+        let mut wiring = Vec::with_capacity(self.steps.len());
+        for (position, step) in self.steps.iter().enumerate() {
+            wiring.push([outputs.get(&step.left).copied(), outputs.get(&step.right).copied()]);
+            outputs.insert(step.parent, position);
+        }
+        wiring
+    }
+
+    /// Encode the trace as the concatenation of `left || right || parent` for each step.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(self.steps.len() * 3 * BYTES_PER_CHUNK);
+        for step in &self.steps {
+            buffer.extend_from_slice(step.left.as_ref());
+            buffer.extend_from_slice(step.right.as_ref());
+            buffer.extend_from_slice(step.parent.as_ref());
+        }
+        buffer
+    }
+
+    // Map the output of each step, and each root of a "zero" subtree, to its two children.
+    fn children(&self) -> HashMap<Node, (Node, Node)> {
+        let mut children = (1..MAX_MERKLE_TREE_DEPTH)
+            .map(|height| {
+                let child = Node::from(*zero_hash(height - 1));
+                (Node::from(*zero_hash(height)), (child, child))
+            })
+            .collect::<HashMap<_, _>>();
+        children.extend(self.steps.iter().map(|step| (step.parent, (step.left, step.right))));
+        children
+    }
+
+    /// Return the generalized indices of the nodes whose roots differ between the hash tree
+    /// recorded in `self` and that recorded in `other`, in ascending order.
+    ///
+    /// The trees are walked down from their roots through the recorded steps, so differences are
+    /// located within nested types down to the chunks that changed. Nodes with no recorded
+    /// children, e.g. a root taken from the cache of a `CachedList`, are compared as leaves.
+    pub fn diff(&self, other: &Self) -> Vec<GeneralizedIndex> {
+        let children = self.children();
+        let other_children = other.children();
+        let mut changed = vec![];
+        let mut pending = vec![(self.root, other.root, 1)];
+        while let Some((node, other_node, index)) = pending.pop() {
+            if node == other_node {
+                continue
+            }
+            changed.push(index);
+            if let (Some(&(left, right)), Some(&(other_left, other_right))) =
+                (children.get(&node), other_children.get(&other_node))
+            {
+                pending.push((left, other_left, 2 * index));
+                pending.push((right, other_right, 2 * index + 1));
+            }
+        }
+        changed.sort_unstable();
+        changed
+    }
+}
+
+thread_local! {
+    static RECORDING: Cell<bool> = const { Cell::new(false) };
+    static STEPS: RefCell<Vec<HashStep>> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) fn is_recording() -> bool {
+    RECORDING.with(Cell::get)
+}
+
+pub(crate) fn record_hash(left: &[u8], right: &[u8], parent: &[u8]) {
+    if !is_recording() {
+        return
+    }
+    let step = HashStep {
+        left: left.try_into().expect("is one chunk"),
+        right: right.try_into().expect("is one chunk"),
+        parent: parent.try_into().expect("is one chunk"),
+    };
+    STEPS.with(|steps| steps.borrow_mut().push(step));
+}
+
+/// Compute the hash tree root of `value`, recording every hash invocation made along the way.
+///
+/// Recording is scoped to the current thread. Calls may be nested, in which case the outer
+/// trace also contains the steps of the inner one.
+pub fn hash_tree_root_with_witness<T: Merkleized + ?Sized>(
+    value: &T,
+) -> Result<WitnessTrace, MerkleizationError> {
+    let was_recording = RECORDING.with(|recording| recording.replace(true));
+    let start = STEPS.with(|steps| steps.borrow().len());
+    let result = value.hash_tree_root();
+    let steps = STEPS.with(|steps| {
+        let mut steps = steps.borrow_mut();
+        if was_recording {
+            steps[start..].to_vec()
+        } else {
+            core::mem::take(&mut *steps)
+        }
+    });
+    RECORDING.with(|recording| recording.set(was_recording));
+    Ok(WitnessTrace { root: result?, steps })
+}
+
+/// Return the generalized indices of the nodes of the hash tree of `before` that differ in that
+/// of `after`, in ascending order, as for [`WitnessTrace::diff`].
+///
+/// This locates which parts of a value account for a change to (or unexpected mismatch of) its
+/// hash tree root, e.g. to sync only the changed parts of a state.
+pub fn hash_tree_root_diff<T: Merkleized + ?Sized>(
+    before: &T,
+    after: &T,
+) -> Result<Vec<GeneralizedIndex>, MerkleizationError> {
+    let before = hash_tree_root_with_witness(before)?;
+    let after = hash_tree_root_with_witness(after)?;
+    Ok(before.diff(&after))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_witness_of_list() {
+        let list = List::<u64, 16>::try_from((0..9).collect::<Vec<u64>>()).unwrap();
+        let expected_root = list.hash_tree_root().unwrap();
+        let trace = hash_tree_root_with_witness(&list).unwrap();
+        assert_eq!(trace.root, expected_root);
+        // 3 chunks padded to 4, plus mixing in the length
+        assert_eq!(trace.len(), 4);
+        assert!(trace.verify());
+        assert_eq!(trace.to_bytes().len(), trace.len() * 3 * 32);
+
+        let mut tampered = trace.clone();
+        tampered.steps[0].left = Node::default();
+        assert!(!tampered.verify());
+    }
+
+    #[test]
+    fn test_witness_wiring() {
+        let list = List::<u64, 16>::try_from((0..9).collect::<Vec<u64>>()).unwrap();
+        let trace = hash_tree_root_with_witness(&list).unwrap();
+        // the two pairs of chunks, the root of the data and then the length mixed in
+        assert_eq!(
+            trace.wiring(),
+            vec![[None, None], [None, None], [Some(0), Some(1)], [Some(2), None]]
+        );
+
+        let trace = hash_tree_root_with_witness(&Foo::default()).unwrap();
+        let wiring = trace.wiring();
+        assert!(wiring
+            .iter()
+            .enumerate()
+            .all(|(i, inputs)| inputs.iter().flatten().all(|&j| j < i)));
+        assert_eq!(wiring.last().unwrap().iter().flatten().count(), 2);
+    }
+
+    #[test]
+    fn test_witness_of_basic_type() {
+        let trace = hash_tree_root_with_witness(&42u64).unwrap();
+        assert!(trace.is_empty());
+        assert!(trace.verify());
+        // nothing is recorded outside of a capture
+        let list = List::<u64, 16>::try_from(vec![1, 2, 3, 4, 5]).unwrap();
+        list.hash_tree_root().unwrap();
+        assert!(STEPS.with(|steps| steps.borrow().is_empty()));
+    }
+
+    #[derive(Default, Debug, Clone, SimpleSerialize)]
+    struct Foo {
+        a: u64,
+        b: List<u64, 16>,
+        c: Vector<u8, 4>,
+    }
+
+    #[test]
+    fn test_hash_tree_root_diff() {
+        let foo =
+            Foo { a: 1, b: List::try_from(vec![1, 2, 3, 4, 5]).unwrap(), c: Default::default() };
+        assert!(hash_tree_root_diff(&foo, &foo.clone()).unwrap().is_empty());
+
+        let mut other = foo.clone();
+        other.b[4] = 42;
+        // `b` is the field at generalized index 5 (of 3 fields, padded to 4), whose data is at
+        // 10 with its second chunk (holding the element at index 4) at 10 * 4 + 1
+        assert_eq!(hash_tree_root_diff(&foo, &other).unwrap(), vec![1, 2, 5, 10, 20, 41]);
+
+        other.b.push(6).unwrap();
+        // the length, at 11, changes too
+        assert_eq!(hash_tree_root_diff(&foo, &other).unwrap(), vec![1, 2, 5, 10, 11, 20, 41]);
+
+        let mut other = foo.clone();
+        other.a = 2;
+        other.c[0] = 1;
+        assert_eq!(hash_tree_root_diff(&foo, &other).unwrap(), vec![1, 2, 3, 4, 6]);
+    }
+}
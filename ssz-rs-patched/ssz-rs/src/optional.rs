@@ -0,0 +1,213 @@
+use crate::{
+    de::{Deserialize, DeserializeError},
+    lib::*,
+    merkleization::{
+        mix_in_length, prove_element, MerkleizationError, Merkleized, Node, Path, PathElement,
+        Prove,
+    },
+    ser::{Serialize, SerializeError},
+    Serializable, SimpleSerialize,
+};
+
+/// An optional value, i.e. the `Optional[T]` type of [EIP-6475].
+///
+/// Unlike an `Option`, which is the union `Union[None, T]`, this is merkleized like a
+/// `List<T, 1>` holding the value, if any. It is encoded as nothing when absent and as the
+/// byte `0x01` followed by the encoding of the value when present.
+///
+/// [EIP-6475]: https://eips.ethereum.org/EIPS/eip-6475
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct Optional<T: Serializable>(Option<T>);
+
+impl<T: Serializable> Optional<T> {
+    pub fn some(value: T) -> Self {
+        Self(Some(value))
+    }
+
+    pub fn none() -> Self {
+        Self(None)
+    }
+
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T: Serializable> Default for Optional<T> {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<T: Serializable> From<Option<T>> for Optional<T> {
+    fn from(value: Option<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Serializable> From<Optional<T>> for Option<T> {
+    fn from(value: Optional<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T: Serializable> Deref for Optional<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Serializable> DerefMut for Optional<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Serializable> Serializable for Optional<T> {
+    const FIXED_SIZE: Option<usize> = None;
+
+    fn serialized_length(&self) -> Result<usize, SerializeError> {
+        match &self.0 {
+            Some(value) => Ok(1 + value.serialized_length()?),
+            None => Ok(0),
+        }
+    }
+}
+
+impl<T: Serializable> Serialize for Optional<T> {
+    fn serialize(&self, buffer: &mut Vec<u8>) -> Result<usize, SerializeError> {
+        match &self.0 {
+            Some(value) => {
+                buffer.push(1);
+                Ok(1 + value.serialize(buffer)?)
+            }
+            None => Ok(0),
+        }
+    }
+}
+
+impl<T: Serializable> Deserialize for Optional<T> {
+    fn deserialize(encoding: &[u8]) -> Result<Self, DeserializeError> {
+        match encoding.split_first() {
+            None => Ok(Self::none()),
+            Some((1, value)) => T::deserialize(value).map(Self::some),
+            Some((&b, _)) => Err(DeserializeError::InvalidByte(b)),
+        }
+    }
+
+    fn validate(encoding: &[u8]) -> Result<(), DeserializeError> {
+        match encoding.split_first() {
+            None => Ok(()),
+            Some((1, value)) => T::validate(value),
+            Some((&b, _)) => Err(DeserializeError::InvalidByte(b)),
+        }
+    }
+}
+
+impl<T: SimpleSerialize> Merkleized for Optional<T> {
+    fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
+        // a basic value is packed into a single chunk, which is its hash tree root
+        match &self.0 {
+            Some(value) => Ok(mix_in_length(&value.hash_tree_root()?, 1)),
+            None => Ok(mix_in_length(&Node::default(), 0)),
+        }
+    }
+}
+
+impl<T: SimpleSerialize> Prove for Optional<T> {
+    fn prove_path(
+        &self,
+        path: Path,
+        branch: &mut Vec<Node>,
+    ) -> Result<(Node, usize), MerkleizationError> {
+        let len = usize::from(self.0.is_some());
+        match path {
+            [] => Ok((self.hash_tree_root()?, 0)),
+            [PathElement::Length] => {
+                let data_root = match &self.0 {
+                    Some(value) => value.hash_tree_root()?,
+                    None => Node::default(),
+                };
+                branch.push(data_root);
+                Ok((len.hash_tree_root()?, 1))
+            }
+            [PathElement::Length, element, ..] => {
+                Err(MerkleizationError::InvalidPathElement(element.clone()))
+            }
+            path => {
+                let proof = prove_element(self.0.as_slice(), Some(1), path, branch)?;
+                branch.push(len.hash_tree_root()?);
+                Ok(proof)
+            }
+        }
+    }
+}
+
+impl<T: SimpleSerialize> SimpleSerialize for Optional<T> {}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T> arbitrary::Arbitrary<'a> for Optional<T>
+where
+    T: Serializable + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(u.arbitrary()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize)]
+    struct Foo {
+        a: u64,
+        b: Optional<u64>,
+        c: Optional<List<u8, 8>>,
+    }
+
+    #[test]
+    fn test_encoding() {
+        let value = Optional::some(7u16);
+        assert_eq!(serialize(&value).unwrap(), [1, 7, 0]);
+        assert_eq!(serialize(&Optional::<u16>::none()).unwrap(), [] as [u8; 0]);
+        assert_eq!(Optional::<u16>::deserialize(&[1, 7, 0]).unwrap(), value);
+        assert_eq!(Optional::<u16>::deserialize(&[]).unwrap(), Optional::none());
+        assert!(matches!(
+            Optional::<u16>::deserialize(&[0]),
+            Err(DeserializeError::InvalidByte(0))
+        ));
+
+        let foo = Foo { a: 1, b: Optional::some(2), c: Optional::none() };
+        let encoding = serialize(&foo).unwrap();
+        assert_eq!(encoding.len(), 8 + 4 + 4 + 9);
+        assert_eq!(Foo::deserialize(&encoding).unwrap(), foo);
+        assert_eq!(foo.serialized_length().unwrap(), encoding.len());
+    }
+
+    #[test]
+    fn test_merkleization() {
+        let list = List::<u64, 1>::try_from(vec![7]).unwrap();
+        assert_eq!(Optional::some(7u64).hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+        let empty = List::<u64, 1>::default();
+        assert_eq!(
+            Optional::<u64>::none().hash_tree_root().unwrap(),
+            empty.hash_tree_root().unwrap()
+        );
+
+        let inner = List::<u8, 8>::try_from(vec![1, 2, 3]).unwrap();
+        let list = List::<List<u8, 8>, 1>::try_from(vec![inner.clone()]).unwrap();
+        let value = Optional::some(inner);
+        assert_eq!(value.hash_tree_root().unwrap(), list.hash_tree_root().unwrap());
+
+        let path = &[0.into(), PathElement::Length];
+        let proof = value.prove(path).unwrap();
+        assert_eq!(proof, list.prove(path).unwrap());
+        assert!(proof.verify(value.hash_tree_root().unwrap()).is_ok());
+        assert!(value.prove(&[PathElement::Length]).is_ok());
+        assert!(Optional::<u64>::none().prove(&[0.into()]).is_err());
+    }
+}
@@ -0,0 +1,349 @@
+use crate::{
+    de::{
+        deserialize_homogeneous_composite, deserialize_homogeneous_composite_from_source,
+        validate_homogeneous_composite, DecodingSource, Deserialize, DeserializeError,
+    },
+    lib::*,
+    list::check_remainder,
+    merkleization::{
+        elements_to_chunks, extend_branch, hash_chunks, merkleize, mix_in_length, pack,
+        MerkleizationError, Merkleized, Node, Path, PathElement, Prove, BYTES_PER_CHUNK,
+    },
+    ser::{
+        serialize_elements, serialize_elements_to_sink, serialized_length_of_elements,
+        EncodingSink, Serialize, SerializeError,
+    },
+    Serializable, SimpleSerialize,
+};
+
+// Each subtree of a progressive tree has this many times the leaves of the one before it.
+const SUBTREE_GROWTH: usize = 4;
+
+/// A homogeneous collection of any number of values, i.e. the `ProgressiveList[T]` type of
+/// [EIP-7916].
+///
+/// It is encoded exactly like a [`List`](crate::List), but has no bound: rather than padding
+/// its chunks to a fixed limit, they are merkleized with [`merkleize_progressive`], so the
+/// index of an element in the tree never changes and the tree grows only as needed.
+///
+/// [EIP-7916]: https://eips.ethereum.org/EIPS/eip-7916
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct ProgressiveList<T: Serializable> {
+    data: Vec<T>,
+}
+
+impl<T: Serializable> Default for ProgressiveList<T> {
+    fn default() -> Self {
+        Self { data: vec![] }
+    }
+}
+
+impl<T: Serializable> From<Vec<T>> for ProgressiveList<T> {
+    fn from(data: Vec<T>) -> Self {
+        Self { data }
+    }
+}
+
+impl<T: Serializable> From<ProgressiveList<T>> for Vec<T> {
+    fn from(value: ProgressiveList<T>) -> Self {
+        value.data
+    }
+}
+
+impl<T: Serializable> AsRef<[T]> for ProgressiveList<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T: Serializable> Deref for ProgressiveList<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T: Serializable, Idx: SliceIndex<[T]>> Index<Idx> for ProgressiveList<T> {
+    type Output = <Idx as SliceIndex<[T]>>::Output;
+
+    fn index(&self, index: Idx) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<T: Serializable, Idx: SliceIndex<[T]>> IndexMut<Idx> for ProgressiveList<T> {
+    fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<T: Serializable> ProgressiveList<T> {
+    pub fn push(&mut self, element: T) {
+        self.data.push(element);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.data.pop()
+    }
+
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+}
+
+impl<T: Serializable> Serializable for ProgressiveList<T> {
+    const FIXED_SIZE: Option<usize> = None;
+
+    fn serialized_length(&self) -> Result<usize, SerializeError> {
+        serialized_length_of_elements(&self.data)
+    }
+}
+
+impl<T: Serializable> Serialize for ProgressiveList<T> {
+    fn serialize(&self, buffer: &mut Vec<u8>) -> Result<usize, SerializeError> {
+        serialize_elements(&self.data, buffer)
+    }
+
+    fn serialize_to_sink(&self, sink: &mut dyn EncodingSink) -> Result<usize, SerializeError> {
+        serialize_elements_to_sink(&self.data, sink)
+    }
+}
+
+impl<T: Serializable> Deserialize for ProgressiveList<T> {
+    fn deserialize(encoding: &[u8]) -> Result<Self, DeserializeError> {
+        check_remainder::<T>(encoding)?;
+        deserialize_homogeneous_composite(encoding).map(Self::from)
+    }
+
+    fn deserialize_from_source(
+        source: &mut dyn DecodingSource,
+        len: Option<usize>,
+    ) -> Result<Self, DeserializeError> {
+        deserialize_homogeneous_composite_from_source(source, len, usize::MAX).map(Self::from)
+    }
+
+    fn validate(encoding: &[u8]) -> Result<(), DeserializeError> {
+        check_remainder::<T>(encoding)?;
+        validate_homogeneous_composite::<T>(encoding).map(|_| ())
+    }
+}
+
+impl<T: SimpleSerialize> ProgressiveList<T> {
+    fn chunks(&self) -> Result<Vec<u8>, MerkleizationError> {
+        if T::is_composite_type() {
+            elements_to_chunks(&self.data)
+        } else {
+            pack(&self.data)
+        }
+    }
+
+    fn compute_data_root(&self) -> Result<Node, MerkleizationError> {
+        merkleize_progressive(&self.chunks()?)
+    }
+}
+
+impl<T: SimpleSerialize> Merkleized for ProgressiveList<T> {
+    fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
+        Ok(mix_in_length(&self.compute_data_root()?, self.len()))
+    }
+}
+
+impl<T: SimpleSerialize> Prove for ProgressiveList<T> {
+    fn prove_path(
+        &self,
+        path: Path,
+        branch: &mut Vec<Node>,
+    ) -> Result<(Node, usize), MerkleizationError> {
+        let (element, rest) = match path.split_first() {
+            Some(next) => next,
+            None => return Ok((self.hash_tree_root()?, 0)),
+        };
+        let index = match element {
+            PathElement::Length => {
+                if let Some(element) = rest.first() {
+                    return Err(MerkleizationError::InvalidPathElement(element.clone()))
+                }
+                branch.push(self.compute_data_root()?);
+                return Ok((self.len().hash_tree_root()?, 1))
+            }
+            PathElement::Index(index) if *index < self.len() => *index,
+            element => return Err(MerkleizationError::InvalidPathElement(element.clone())),
+        };
+        let proof = if T::is_composite_type() {
+            let start = branch.len();
+            let (leaf, inner_index) = self.data[index].prove_path(rest, branch)?;
+            let inner_depth = branch.len() - start;
+            let index = extend_progressive_branch(&self.chunks()?, index, branch)?;
+            (leaf, (index << inner_depth) | inner_index)
+        } else {
+            // basic elements are packed, so the proof can only go as far as the enclosing chunk
+            if let Some(element) = rest.first() {
+                return Err(MerkleizationError::InvalidPathElement(element.clone()))
+            }
+            let chunk_index = index * T::size_hint() / BYTES_PER_CHUNK;
+            let chunks = self.chunks()?;
+            let leaf = chunks[chunk_index * BYTES_PER_CHUNK..(chunk_index + 1) * BYTES_PER_CHUNK]
+                .try_into()
+                .expect("is one chunk");
+            (leaf, extend_progressive_branch(&chunks, chunk_index, branch)?)
+        };
+        branch.push(self.len().hash_tree_root()?);
+        Ok(proof)
+    }
+}
+
+impl<T: SimpleSerialize> SimpleSerialize for ProgressiveList<T> {}
+
+/// Return the root of the progressive Merkle tree of `chunks`, as defined in [EIP-7916].
+///
+/// The first chunk is the right child of the root, and the left child is the root of a tree
+/// of the rest of the chunks, built the same way: each level holds the next chunks in a
+/// subtree with four times the leaves of the subtree on the level above, i.e. `1`, `4`, `16`,
+/// etc., until no chunks are left and the left child is a zero chunk.
+///
+/// [EIP-7916]: https://eips.ethereum.org/EIPS/eip-7916
+pub fn merkleize_progressive(chunks: &[u8]) -> Result<Node, MerkleizationError> {
+    debug_assert!(chunks.len().is_multiple_of(BYTES_PER_CHUNK));
+    merkleize_progressive_from(chunks, 1)
+}
+
+// Iterate over the subtrees of a progressive tree of `chunks`, yielding the index of the first
+// chunk of each subtree along with its chunks and the number of leaves it has.
+fn subtrees(chunks: &[u8]) -> impl Iterator<Item = (usize, &[u8], usize)> {
+    let chunk_count = chunks.len() / BYTES_PER_CHUNK;
+    let mut start = 0;
+    let mut leaf_count = 1;
+    iter::from_fn(move || {
+        if start >= chunk_count {
+            return None
+        }
+        let end = (start + leaf_count).min(chunk_count);
+        let subtree = (start, &chunks[start * BYTES_PER_CHUNK..end * BYTES_PER_CHUNK], leaf_count);
+        start += leaf_count;
+        leaf_count *= SUBTREE_GROWTH;
+        Some(subtree)
+    })
+}
+
+// Extend `branch` from the chunk at `chunk_index` of a progressive tree up to its root,
+// returning the index of the chunk among the nodes at the depth of the branch.
+fn extend_progressive_branch(
+    chunks: &[u8],
+    chunk_index: usize,
+    branch: &mut Vec<Node>,
+) -> Result<usize, MerkleizationError> {
+    let subtrees = subtrees(chunks).collect::<Vec<_>>();
+    let level = subtrees
+        .iter()
+        .position(|&(start, _, leaf_count)| chunk_index < start + leaf_count)
+        .expect("chunk is in the tree");
+    let (start, subtree, leaf_count) = subtrees[level];
+    let depth = extend_branch(subtree, Some(leaf_count), chunk_index - start, branch)?;
+    // the subtree is the right child of a node whose left child holds the chunks after it
+    let rest = &chunks[(start + subtree.len() / BYTES_PER_CHUNK) * BYTES_PER_CHUNK..];
+    branch.push(merkleize_progressive_from(rest, leaf_count * SUBTREE_GROWTH)?);
+    // ...which is the left child of a node whose right child is the subtree on the level above
+    for &(_, subtree, leaf_count) in subtrees[..level].iter().rev() {
+        branch.push(merkleize(subtree, Some(leaf_count))?);
+    }
+    Ok((1 << depth) | (chunk_index - start))
+}
+
+// Merkleize `chunks` as the part of a progressive tree whose first subtree has `leaf_count`
+// leaves.
+fn merkleize_progressive_from(
+    chunks: &[u8],
+    leaf_count: usize,
+) -> Result<Node, MerkleizationError> {
+    let mut roots = vec![];
+    let mut rest = chunks;
+    let mut leaf_count = leaf_count;
+    while !rest.is_empty() {
+        let (subtree, next) = rest.split_at((leaf_count * BYTES_PER_CHUNK).min(rest.len()));
+        roots.push(merkleize(subtree, Some(leaf_count))?);
+        rest = next;
+        leaf_count *= SUBTREE_GROWTH;
+    }
+    Ok(roots.iter().rev().fold(Node::default(), hash_chunks))
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T> arbitrary::Arbitrary<'a> for ProgressiveList<T>
+where
+    T: Serializable + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self { data: u.arbitrary()? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[derive(Default, Debug, Clone, PartialEq, Eq, SimpleSerialize)]
+    struct Foo {
+        a: u64,
+        b: List<u8, 4>,
+    }
+
+    fn foo(i: u8) -> Foo {
+        Foo { a: i as u64, b: List::try_from(vec![i; 2]).unwrap() }
+    }
+
+    #[test]
+    fn test_encoding() {
+        let elements = (0..6).map(foo).collect::<Vec<_>>();
+        let value = ProgressiveList::from(elements.clone());
+        let list = List::<Foo, 8>::try_from(elements).unwrap();
+        let encoding = serialize(&value).unwrap();
+        assert_eq!(encoding, serialize(&list).unwrap());
+        assert_eq!(value.serialized_length().unwrap(), encoding.len());
+        assert_eq!(ProgressiveList::<Foo>::deserialize(&encoding).unwrap(), value);
+        assert_eq!(ProgressiveList::<Foo>::deserialize_from(&mut &encoding[..]).unwrap(), value);
+
+        let value = ProgressiveList::from(vec![1u16, 2, 3]);
+        assert_eq!(serialize(&value).unwrap(), [1, 0, 2, 0, 3, 0]);
+        assert!(ProgressiveList::<u16>::deserialize(&[1, 0, 2]).is_err());
+    }
+
+    #[test]
+    fn test_merkleization() {
+        let value = ProgressiveList::from((0..6).map(foo).collect::<Vec<_>>());
+        let roots = (0..6).map(|i| foo(i).hash_tree_root().unwrap()).collect::<Vec<_>>();
+        assert_eq!(
+            merkleize_progressive(roots[5].as_ref()).unwrap(),
+            hash_chunks(Node::default(), roots[5])
+        );
+        // the first root, then the next four, then the sixth in a subtree of sixteen
+        let mut leaves = vec![Node::default(); 16];
+        leaves[0] = roots[5];
+        let subtree = Vector::<Node, 16>::try_from(leaves).unwrap().hash_tree_root().unwrap();
+        let third = hash_chunks(Node::default(), subtree);
+        let second = Vector::<Node, 4>::try_from(roots[1..5].to_vec()).unwrap();
+        let data_root = hash_chunks(hash_chunks(third, second.hash_tree_root().unwrap()), roots[0]);
+        let root = mix_in_length(&data_root, 6);
+        assert_eq!(value.hash_tree_root().unwrap(), root);
+        assert_eq!(
+            ProgressiveList::<Foo>::default().hash_tree_root().unwrap(),
+            mix_in_length(&Node::default(), 0)
+        );
+
+        for i in 0..6 {
+            let proof = value.prove(&[i.into(), "b".into()]).unwrap();
+            assert!(proof.verify(root).is_ok());
+        }
+        let proof = value.prove(&[PathElement::Length]).unwrap();
+        assert!(proof.verify(root).is_ok());
+        assert!(value.prove(&[6.into()]).is_err());
+
+        let value = ProgressiveList::from((0..100u16).collect::<Vec<_>>());
+        let root = value.hash_tree_root().unwrap();
+        for i in [0, 15, 16, 99] {
+            let proof = value.prove(&[i.into()]).unwrap();
+            assert!(proof.verify(root).is_ok());
+        }
+    }
+}
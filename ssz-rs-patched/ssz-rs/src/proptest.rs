@@ -0,0 +1,162 @@
+//! Strategies generating values of SSZ types for property tests with [`proptest`].
+//!
+//! Each collection with a bound takes a [`Length`] choosing how many elements to generate, e.g.
+//! only the boundary cases of an empty and a full list. Along with [`check_roundtrip`], a
+//! property of a type fits on one line:
+//!
+//! ```
+//! # use proptest::prelude::*;
+//! # use ssz_rs::{proptest::{check_roundtrip, list, Length}, List};
+//! proptest!(|(value in list::<u64, _, 16>(any::<u64>(), Length::Boundary))| {
+//!     check_roundtrip(&value)?;
+//! });
+//! ```
+use crate::{
+    lib::*, utils::serialize, Bitlist, Bitvector, List, Node, Optional, SimpleSerialize, Vector,
+    U256,
+};
+use proptest::{collection::vec, prelude::*, sample::select, test_runner::TestCaseError};
+
+/// How many elements to generate for a collection bounded to `N` of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Length {
+    /// Any number up to the bound.
+    Any,
+    /// No elements.
+    Empty,
+    /// As many elements as the bound allows.
+    Max,
+    /// Zero, one, one short of the bound or the bound itself.
+    Boundary,
+    /// Any number up to the given one, or to the bound if it is lower, e.g. to keep collections
+    /// with a large bound small.
+    UpTo(usize),
+}
+
+impl Length {
+    /// A strategy for the number of elements of a collection bounded to `bound` of them.
+    pub fn strategy(self, bound: usize) -> BoxedStrategy<usize> {
+        match self {
+            Self::Any => (0..=bound).boxed(),
+            Self::Empty => Just(0).boxed(),
+            Self::Max => Just(bound).boxed(),
+            Self::Boundary => select(vec![0, 1.min(bound), bound.saturating_sub(1), bound]).boxed(),
+            Self::UpTo(len) => (0..=len.min(bound)).boxed(),
+        }
+    }
+}
+
+/// A `List<T, N>` of elements generated by `element`.
+pub fn list<T, S, const N: usize>(element: S, length: Length) -> impl Strategy<Value = List<T, N>>
+where
+    T: SimpleSerialize + fmt::Debug,
+    S: Strategy<Value = T>,
+{
+    let element = Arc::new(element);
+    length
+        .strategy(N)
+        .prop_flat_map(move |len| vec(Arc::clone(&element), len))
+        .prop_map(|data| List::try_from_iter(data).expect("the length is within the bound"))
+}
+
+/// A `Vector<T, N>` of elements generated by `element`.
+pub fn vector<T, S, const N: usize>(element: S) -> impl Strategy<Value = Vector<T, N>>
+where
+    T: SimpleSerialize + fmt::Debug,
+    S: Strategy<Value = T>,
+{
+    vec(element, N)
+        .prop_map(|data| Vector::try_from_iter(data).expect("there are exactly `N` elements"))
+}
+
+/// A `Bitlist<N>` of arbitrary bits.
+pub fn bitlist<const N: usize>(length: Length) -> impl Strategy<Value = Bitlist<N>> {
+    length.strategy(N).prop_flat_map(|len| vec(any::<bool>(), len)).prop_map(|bits| {
+        Bitlist::try_from(bits.as_slice()).expect("the length is within the bound")
+    })
+}
+
+/// A `Bitvector<N>` of arbitrary bits.
+pub fn bitvector<const N: usize>() -> impl Strategy<Value = Bitvector<N>> {
+    vec(any::<bool>(), N)
+        .prop_map(|bits| Bitvector::try_from(bits.as_slice()).expect("there are exactly `N` bits"))
+}
+
+/// An `Optional<T>` holding a value generated by `element`, or nothing.
+pub fn optional<T, S>(element: S) -> impl Strategy<Value = Optional<T>>
+where
+    T: SimpleSerialize + fmt::Debug,
+    S: Strategy<Value = T>,
+{
+    proptest::option::of(element).prop_map(Optional::from)
+}
+
+/// An arbitrary `U256`.
+pub fn u256() -> impl Strategy<Value = U256> {
+    any::<[u8; 32]>().prop_map(U256::from_le_bytes)
+}
+
+/// An arbitrary `Node`.
+pub fn node() -> impl Strategy<Value = Node> {
+    any::<[u8; 32]>().prop_map(Node::from)
+}
+
+/// Check that `value` decodes from its encoding to an equal value with the same root.
+pub fn check_roundtrip<T>(value: &T) -> Result<(), TestCaseError>
+where
+    T: SimpleSerialize + PartialEq + fmt::Debug,
+{
+    let encoding = serialize(value).map_err(|err| TestCaseError::fail(err.to_string()))?;
+    let recovered =
+        T::deserialize(&encoding).map_err(|err| TestCaseError::fail(err.to_string()))?;
+    prop_assert_eq!(&recovered, value);
+    let root = value.hash_tree_root().map_err(|err| TestCaseError::fail(err.to_string()))?;
+    prop_assert_eq!(recovered.hash_tree_root().ok(), Some(root));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize)]
+    struct Checkpoint {
+        roots: Vector<Node, 2>,
+        balance: U256,
+        bits: Bitlist<9>,
+        flags: Bitvector<3>,
+        slot: Optional<u64>,
+    }
+
+    fn checkpoint() -> impl Strategy<Value = Checkpoint> {
+        (vector(node()), u256(), bitlist(Length::Any), bitvector(), optional(any::<u64>()))
+            .prop_map(|(roots, balance, bits, flags, slot)| Checkpoint {
+                roots,
+                balance,
+                bits,
+                flags,
+                slot,
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip_lists(value in list::<u16, _, 5>(any::<u16>(), Length::Boundary)) {
+            prop_assert!([0, 1, 4, 5].contains(&value.len()));
+            check_roundtrip(&value)?;
+        }
+
+        #[test]
+        fn roundtrip_containers(value in list::<_, _, 3>(checkpoint(), Length::Max)) {
+            prop_assert_eq!(value.len(), 3);
+            check_roundtrip(&value)?;
+        }
+
+        #[test]
+        fn bounded_lengths(value in bitlist::<1024>(Length::UpTo(8))) {
+            prop_assert!(value.len() <= 8);
+            check_roundtrip(&value)?;
+        }
+    }
+}
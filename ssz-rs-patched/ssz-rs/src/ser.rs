@@ -17,6 +17,11 @@ pub enum SerializeError {
     InvalidInstance(InstanceError),
     /// An invalid type was encountered.
     InvalidType(TypeError),
+    /// The encoding of `required` bytes did not fit in a buffer of `provided` bytes.
+    BufferTooSmall { required: usize, provided: usize },
+    /// The encoding could not be written.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
 }
 
 impl From<InstanceError> for SerializeError {
@@ -40,6 +45,12 @@ impl Display for SerializeError {
             ),
             SerializeError::InvalidInstance(err) => write!(f, "invalid instance: {err}"),
             SerializeError::InvalidType(err) => write!(f, "invalid type: {err}"),
+            SerializeError::BufferTooSmall { required, provided } => write!(
+                f,
+                "the encoding needs {required} bytes but the buffer only holds {provided} bytes"
+            ),
+            #[cfg(feature = "std")]
+            SerializeError::Io(err) => write!(f, "could not write encoding: {err}"),
         }
     }
 }
@@ -53,6 +64,111 @@ pub trait Serialize {
     ///
     /// Returns the number of bytes written.
     fn serialize(&self, buffer: &mut Vec<u8>) -> Result<usize, SerializeError>;
+
+    /// Write an encoding of `self` to `sink`, in order.
+    ///
+    /// Returns the number of bytes written. By default, the encoding is built in a buffer that is
+    /// then written; composite types instead write each of their parts as they go, so that
+    /// nothing close to the size of the whole encoding is buffered.
+    fn serialize_to_sink(&self, sink: &mut dyn EncodingSink) -> Result<usize, SerializeError> {
+        let mut buffer = Vec::new();
+        let bytes_written = self.serialize(&mut buffer)?;
+        sink.write(&buffer)?;
+        Ok(bytes_written)
+    }
+
+    /// Write the encodings of `elements`, values of a fixed-size type, to `sink` one after another
+    /// as for the elements of a `Vector` or `List`.
+    ///
+    /// Returns the number of bytes written. By default, each element is written in turn; a type
+    /// encoded as its bytes in memory, like `u8`, instead writes the whole slice at once.
+    fn serialize_fixed_elements(
+        elements: &[Self],
+        sink: &mut dyn EncodingSink,
+    ) -> Result<usize, SerializeError>
+    where
+        Self: Sized,
+    {
+        let mut bytes_written = 0;
+        for element in elements {
+            bytes_written += element.serialize_to_sink(sink)?;
+        }
+        Ok(bytes_written)
+    }
+
+    /// Write an encoding of `self` to `writer`, e.g. a file or a socket, as it is produced.
+    ///
+    /// Returns the number of bytes written. Consider wrapping `writer` in a
+    /// [`BufWriter`](std::io::BufWriter), as the encoding is written in many small pieces.
+    #[cfg(feature = "std")]
+    fn serialize_to<W: std::io::Write>(&self, writer: &mut W) -> Result<usize, SerializeError> {
+        self.serialize_to_sink(&mut IoSink(writer))
+    }
+
+    /// Write an encoding of `self` to the start of `buffer`, e.g. one taken from a pool, rather
+    /// than to a growing `Vec`.
+    ///
+    /// Returns the number of bytes written. If the encoding does not fit, this fails with
+    /// [`SerializeError::BufferTooSmall`] giving its full length, and the contents of `buffer` are
+    /// unspecified.
+    fn serialize_into(&self, buffer: &mut [u8]) -> Result<usize, SerializeError> {
+        let provided = buffer.len();
+        let mut sink = SliceSink { buffer, written: 0 };
+        self.serialize_to_sink(&mut sink)?;
+        if sink.written > provided {
+            return Err(SerializeError::BufferTooSmall { required: sink.written, provided })
+        }
+        Ok(sink.written)
+    }
+}
+
+/// A destination for an SSZ encoding, written in order by [`Serialize::serialize_to_sink`].
+pub trait EncodingSink {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), SerializeError>;
+}
+
+impl EncodingSink for Vec<u8> {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), SerializeError> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+struct IoSink<'a, W>(&'a mut W);
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> EncodingSink for IoSink<'_, W> {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), SerializeError> {
+        self.0.write_all(bytes).map_err(SerializeError::Io)
+    }
+}
+
+// Copies the encoding to a fixed buffer, counting (but dropping) any bytes past its end so that
+// the length needed can be reported.
+struct SliceSink<'a> {
+    buffer: &'a mut [u8],
+    written: usize,
+}
+
+impl EncodingSink for SliceSink<'_> {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), SerializeError> {
+        let end = self.written + bytes.len();
+        if let Some(target) = self.buffer.get_mut(self.written..end) {
+            target.copy_from_slice(bytes);
+        }
+        self.written = end;
+        Ok(())
+    }
+}
+
+// Discards the encoding written to it, so that its length can be found.
+pub(crate) struct Discard;
+
+impl EncodingSink for Discard {
+    fn write(&mut self, _: &[u8]) -> Result<(), SerializeError> {
+        Ok(())
+    }
 }
 
 // Part represents either a fixed sized part of the serialization
@@ -116,3 +232,139 @@ impl Serializer {
         Ok(())
     }
 }
+
+// `StreamingSerializer` writes the encoding of a composite type to a sink, part by part.
+// Intended use:
+// - call `measure` for each element in order, to find the length of each variable-size part
+// - call `total_length` to check the encoding is valid
+// - call `write_fixed` for each element in order, writing each fixed-size part or offset
+// - call `write_variable` for each element in order, writing each variable-size part
+// NOTE: mainly intended for private use in the proc derive macro.
+#[derive(Default)]
+pub struct StreamingSerializer {
+    variable_lengths: Vec<usize>,
+    fixed_lengths_sum: usize,
+    variable_lengths_sum: usize,
+    // offset of the next variable-size part, relative to the start of the encoding
+    running_length: usize,
+    next_variable: usize,
+}
+
+impl StreamingSerializer {
+    pub fn measure<T: Serializable>(&mut self, element: &T) -> Result<(), SerializeError> {
+        if T::is_variable_size() {
+            let length = element.serialized_length()?;
+            self.variable_lengths.push(length);
+            self.fixed_lengths_sum += BYTES_PER_LENGTH_OFFSET;
+            self.variable_lengths_sum += length;
+        } else {
+            self.fixed_lengths_sum += T::size_hint();
+        }
+        Ok(())
+    }
+
+    pub fn total_length(&mut self) -> Result<usize, SerializeError> {
+        let total_size = self.fixed_lengths_sum + self.variable_lengths_sum;
+        if total_size as u64 >= MAXIMUM_LENGTH {
+            return Err(SerializeError::MaximumEncodedLengthReached(total_size))
+        }
+        self.running_length = self.fixed_lengths_sum;
+        Ok(total_size)
+    }
+
+    pub fn write_fixed<T: Serializable>(
+        &mut self,
+        element: &T,
+        sink: &mut dyn EncodingSink,
+    ) -> Result<(), SerializeError> {
+        if T::is_variable_size() {
+            // SAFETY: `running_length` fits in `u32` if the total size check holds
+            sink.write(&(self.running_length as u32).to_le_bytes())?;
+            self.running_length += self.variable_lengths[self.next_variable];
+            self.next_variable += 1;
+        } else {
+            element.serialize_to_sink(sink)?;
+        }
+        Ok(())
+    }
+
+    pub fn write_variable<T: Serializable>(
+        &mut self,
+        element: &T,
+        sink: &mut dyn EncodingSink,
+    ) -> Result<(), SerializeError> {
+        if T::is_variable_size() {
+            element.serialize_to_sink(sink)?;
+        }
+        Ok(())
+    }
+}
+
+// The fixed size of a container with fields of the given fixed sizes, if all of them are fixed.
+pub const fn fixed_size_of_fields(field_sizes: &[Option<usize>]) -> Option<usize> {
+    let mut total = 0;
+    let mut i = 0;
+    while i < field_sizes.len() {
+        match field_sizes[i] {
+            Some(size) => total += size,
+            None => return None,
+        }
+        i += 1;
+    }
+    Some(total)
+}
+
+// The length of the encoding of a homogeneous composite type with the given `elements`.
+pub(crate) fn serialized_length_of_elements<T: Serializable>(
+    elements: &[T],
+) -> Result<usize, SerializeError> {
+    let mut serializer = StreamingSerializer::default();
+    match T::FIXED_SIZE {
+        Some(size) => serializer.fixed_lengths_sum = size * elements.len(),
+        None => {
+            for element in elements {
+                serializer.measure(element)?;
+            }
+        }
+    }
+    serializer.total_length()
+}
+
+// Append the encoding of a homogeneous composite type with the given `elements` to `buffer`.
+pub(crate) fn serialize_elements<T: Serializable>(
+    elements: &[T],
+    buffer: &mut Vec<u8>,
+) -> Result<usize, SerializeError> {
+    if T::is_variable_size() {
+        let mut serializer = Serializer::default();
+        for element in elements {
+            serializer.with_element(element)?;
+        }
+        return serializer.serialize(buffer)
+    }
+    serialize_elements_to_sink(elements, buffer)
+}
+
+// Write the encoding of a homogeneous composite type with the given `elements` to `sink`.
+pub(crate) fn serialize_elements_to_sink<T: Serializable>(
+    elements: &[T],
+    sink: &mut dyn EncodingSink,
+) -> Result<usize, SerializeError> {
+    if T::FIXED_SIZE.is_some() {
+        let total_length = serialized_length_of_elements(elements)?;
+        T::serialize_fixed_elements(elements, sink)?;
+        return Ok(total_length)
+    }
+    let mut serializer = StreamingSerializer::default();
+    for element in elements {
+        serializer.measure(element)?;
+    }
+    let total_length = serializer.total_length()?;
+    for element in elements {
+        serializer.write_fixed(element, sink)?;
+    }
+    for element in elements {
+        serializer.write_variable(element, sink)?;
+    }
+    Ok(total_length)
+}
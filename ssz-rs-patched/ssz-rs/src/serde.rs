@@ -1,3 +1,12 @@
+//! Helpers to (de)serialize SSZ types following the conventions of the Ethereum beacon APIs.
+//!
+//! With the `serde` feature, [`Node`](crate::Node), [`Bitlist`](crate::Bitlist) and
+//! [`Bitvector`](crate::Bitvector) are represented as `0x`-prefixed hex, while
+//! [`List`](crate::List) and [`Vector`](crate::Vector) are sequences of their elements. Other
+//! conventions of the APIs are selected per field of a container with `#[serde(with = "...")]`:
+//! - [`as_str`] for the decimal strings of `u64` and `U256` values
+//! - [`seq_of_str`] for sequences of them, e.g. a `List<u64, N>`
+//! - [`as_hex`] for byte types, e.g. a `List<u8, N>` or `Vector<u8, N>`
 use crate::lib::*;
 use hex::FromHexError;
 
@@ -44,7 +53,7 @@ pub mod as_str {
     where
         S: serde::Serializer,
     {
-        serializer.collect_str(&data.to_string())
+        serializer.collect_str(&data)
     }
 
     pub fn deserialize<'de, D, T, E>(deserializer: D) -> Result<T, D::Error>
@@ -58,6 +67,87 @@ pub mod as_str {
     }
 }
 
+/// Represent a sequence of values, e.g. a `List<u64, N>`, as a sequence of their string
+/// representations.
+pub mod seq_of_str {
+    use super::*;
+    use serde::{de::Deserializer, ser::SerializeSeq};
+
+    pub fn serialize<S, T, U>(data: T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+        T: AsRef<[U]>,
+        U: Display,
+    {
+        let mut seq = serializer.serialize_seq(Some(data.as_ref().len()))?;
+        for elem in data.as_ref().iter() {
+            seq.serialize_element(&format_args!("{elem}"))?;
+        }
+        seq.end()
+    }
+
+    struct Visitor<T>(PhantomData<Vec<T>>);
+
+    impl<'de, T: FromStr> serde::de::Visitor<'de> for Visitor<T> {
+        type Value = Vec<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("sequence of string")
+        }
+
+        fn visit_seq<S>(self, mut access: S) -> Result<Self::Value, S::Error>
+        where
+            S: serde::de::SeqAccess<'de>,
+        {
+            let mut coll = Vec::with_capacity(access.size_hint().unwrap_or(0));
+
+            while let Some(elem) = access.next_element::<String>()? {
+                let recovered_elem = T::from_str(&elem).map_err(|_| {
+                    serde::de::Error::custom("failure to parse element of sequence from string")
+                })?;
+                coll.push(recovered_elem);
+            }
+            Ok(coll)
+        }
+    }
+
+    pub fn deserialize<'de, D, T, U>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: TryFrom<Vec<U>>,
+        U: FromStr,
+    {
+        let data = deserializer.deserialize_seq(Visitor(PhantomData))?;
+        T::try_from(data).map_err(|_| serde::de::Error::custom("failure to parse collection"))
+    }
+}
+
+/// Represent a `Bitlist` or `Bitvector` as an array of booleans rather than as the hex
+/// encoding of its serialization, e.g. with `#[serde(with = "ssz_rs::serde::as_bools")]`.
+pub mod as_bools {
+    use super::*;
+    use bitvec::prelude::{BitVec, Lsb0};
+    use serde::Deserialize;
+
+    pub fn serialize<S, T>(data: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+        T: Deref<Target = BitVec<u8, Lsb0>>,
+    {
+        serializer.collect_seq(data.iter().by_vals())
+    }
+
+    pub fn deserialize<'de, D, T, E>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        T: for<'a> TryFrom<&'a [bool], Error = E>,
+        E: Display,
+    {
+        let bits = Vec::<bool>::deserialize(deserializer)?;
+        T::try_from(&bits).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -139,4 +229,80 @@ mod test {
         let roundtrip_value: ComplexTestStruct = serde_json::from_value(json_repr).unwrap();
         assert_eq!(value, roundtrip_value);
     }
+
+    #[derive(PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+    struct BitsTestStruct {
+        #[serde(with = "crate::serde::as_bools")]
+        a: Bitvector<3>,
+        #[serde(with = "crate::serde::as_bools")]
+        b: Bitlist<8>,
+        c: Bitlist<8>,
+    }
+
+    #[test]
+    fn test_bits_as_bools() {
+        let value = BitsTestStruct {
+            a: Bitvector::try_from([true, false, true].as_ref()).unwrap(),
+            b: Bitlist::try_from([false, true].as_ref()).unwrap(),
+            c: Bitlist::try_from([false, true].as_ref()).unwrap(),
+        };
+        let json_repr = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json_repr,
+            serde_json::json!({ "a": [true, false, true], "b": [false, true], "c": "0x06" })
+        );
+        let roundtrip_value: BitsTestStruct = serde_json::from_value(json_repr).unwrap();
+        assert_eq!(value, roundtrip_value);
+
+        let invalid = serde_json::json!({ "a": [true], "b": [], "c": "0x01" });
+        assert!(serde_json::from_value::<BitsTestStruct>(invalid).is_err());
+    }
+
+    #[derive(PartialEq, Eq, Debug, SimpleSerialize, serde::Serialize, serde::Deserialize)]
+    struct ApiTestStruct {
+        #[serde(with = "crate::serde::as_str")]
+        slot: u64,
+        #[serde(with = "crate::serde::as_str")]
+        base_fee_per_gas: U256,
+        #[serde(with = "crate::serde::seq_of_str")]
+        balances: List<u64, 8>,
+        #[serde(with = "crate::serde::as_hex")]
+        extra_data: List<u8, 32>,
+        #[serde(with = "crate::serde::as_hex")]
+        fee_recipient: Vector<u8, 4>,
+        parent_root: Node,
+        aggregation_bits: Bitlist<8>,
+    }
+
+    #[test]
+    fn test_beacon_api_conventions() {
+        let value = ApiTestStruct {
+            slot: 7,
+            base_fee_per_gas: U256::from(1_000_000_007u64),
+            balances: List::try_from(vec![32_000_000_000, 1]).unwrap(),
+            extra_data: List::try_from(vec![0xab, 0xcd]).unwrap(),
+            fee_recipient: Vector::try_from(vec![1, 2, 3, 4]).unwrap(),
+            parent_root: Node::try_from([0x11u8; 32].as_ref()).unwrap(),
+            aggregation_bits: Bitlist::try_from([true, false].as_ref()).unwrap(),
+        };
+        let json_repr = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json_repr,
+            serde_json::json!({
+                "slot": "7",
+                "base_fee_per_gas": "1000000007",
+                "balances": ["32000000000", "1"],
+                "extra_data": "0xabcd",
+                "fee_recipient": "0x01020304",
+                "parent_root": format!("0x{}", "11".repeat(32)),
+                "aggregation_bits": "0x05",
+            })
+        );
+        let roundtrip_value: ApiTestStruct = serde_json::from_value(json_repr).unwrap();
+        assert_eq!(value, roundtrip_value);
+
+        let mut invalid = serde_json::to_value(&value).unwrap();
+        invalid["balances"] = serde_json::json!(vec!["1"; 9]);
+        assert!(serde_json::from_value::<ApiTestStruct>(invalid).is_err());
+    }
 }
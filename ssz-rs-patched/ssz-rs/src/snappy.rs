@@ -0,0 +1,232 @@
+//! Snappy-compressed SSZ, as exchanged by Ethereum consensus clients.
+//!
+//! Gossip messages are compressed as a single snappy block with [`encode`] and [`decode`], while
+//! req/resp chunks are prefixed with the length of their encoding as an unsigned varint and then
+//! compressed with the snappy frame format, with [`encode_framed`] and [`decode_framed`].
+//!
+//! When decoding, the length of the encoding is read from its header and checked against the
+//! given `max_size` and the type being decoded before anything is decompressed, so a small
+//! message cannot claim to expand to an arbitrarily large one.
+use crate::{de::DeserializeError, ser::SerializeError, utils::serialize, Serializable};
+use snap::{raw, read::FrameDecoder, write::FrameEncoder};
+use std::{
+    fmt,
+    io::{Read, Write},
+};
+
+// An unsigned varint takes at most this many bytes to encode a `u64`.
+const MAX_VARINT_LENGTH: usize = 10;
+
+/// An error encoding or decoding snappy-compressed SSZ.
+#[derive(Debug)]
+pub enum Error {
+    /// The value could not be serialized.
+    Serialize(SerializeError),
+    /// The decompressed data could not be deserialized.
+    Deserialize(DeserializeError),
+    /// The compressed data was invalid.
+    Snappy(snap::Error),
+    /// The frame format could not be read or written.
+    Io(std::io::Error),
+    /// The encoding is declared to be `size` bytes long, which is more than `max_size`, or than
+    /// any value of the type being decoded.
+    TooLarge { size: usize, max_size: usize },
+    /// The encoding is declared to be `size` bytes long, which is less than any value of the
+    /// type being decoded.
+    TooSmall { size: usize, min_size: usize },
+    /// The length prefix of a framed encoding was missing or invalid.
+    InvalidLengthPrefix,
+    /// The decompressed data did not match the length declared for it.
+    LengthMismatch { declared: usize, provided: usize },
+}
+
+impl From<SerializeError> for Error {
+    fn from(err: SerializeError) -> Self {
+        Self::Serialize(err)
+    }
+}
+
+impl From<DeserializeError> for Error {
+    fn from(err: DeserializeError) -> Self {
+        Self::Deserialize(err)
+    }
+}
+
+impl From<snap::Error> for Error {
+    fn from(err: snap::Error) -> Self {
+        Self::Snappy(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Serialize(err) => write!(f, "could not serialize: {err}"),
+            Self::Deserialize(err) => write!(f, "could not deserialize: {err}"),
+            Self::Snappy(err) => write!(f, "invalid snappy data: {err}"),
+            Self::Io(err) => write!(f, "could not read snappy frames: {err}"),
+            Self::TooLarge { size, max_size } => {
+                write!(f, "declared length {size} exceeds the maximum of {max_size}")
+            }
+            Self::TooSmall { size, min_size } => {
+                write!(f, "declared length {size} is less than the minimum of {min_size}")
+            }
+            Self::InvalidLengthPrefix => write!(f, "missing or invalid length prefix"),
+            Self::LengthMismatch { declared, provided } => {
+                write!(f, "declared length {declared} but decompressed {provided} bytes")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Serialize `value` and compress it as a single snappy block, as for gossip.
+pub fn encode<T: Serializable>(value: &T) -> Result<Vec<u8>, Error> {
+    let encoding = serialize(value)?;
+    Ok(raw::Encoder::new().compress_vec(&encoding)?)
+}
+
+/// Decompress a single snappy block and deserialize it, as for gossip.
+///
+/// Fails without decompressing anything if the block declares a length over `max_size`.
+pub fn decode<T: Serializable>(compressed: &[u8], max_size: usize) -> Result<T, Error> {
+    let size = raw::decompress_len(compressed)?;
+    check_size::<T>(size, max_size)?;
+    let encoding = raw::Decoder::new().decompress_vec(compressed)?;
+    Ok(T::deserialize(&encoding)?)
+}
+
+/// Serialize `value` and prefix it with its length before compressing it with the snappy frame
+/// format, as for a req/resp chunk.
+pub fn encode_framed<T: Serializable>(value: &T) -> Result<Vec<u8>, Error> {
+    let encoding = serialize(value)?;
+    let mut output = Vec::with_capacity(MAX_VARINT_LENGTH + raw::max_compress_len(encoding.len()));
+    write_varint(encoding.len() as u64, &mut output);
+    let mut encoder = FrameEncoder::new(output);
+    encoder.write_all(&encoding)?;
+    encoder.into_inner().map_err(|err| Error::Io(err.into_error()))
+}
+
+/// Read the length prefix of a req/resp chunk and decompress and deserialize what follows.
+///
+/// Fails without decompressing anything if the prefix declares a length over `max_size`.
+pub fn decode_framed<T: Serializable>(data: &[u8], max_size: usize) -> Result<T, Error> {
+    let (size, prefix_length) = read_varint(data).ok_or(Error::InvalidLengthPrefix)?;
+    let size = usize::try_from(size).map_err(|_| Error::InvalidLengthPrefix)?;
+    check_size::<T>(size, max_size)?;
+
+    let mut decoder = FrameDecoder::new(&data[prefix_length..]);
+    let mut encoding = Vec::with_capacity(size);
+    // read one byte past the declared length, to detect any excess
+    decoder.by_ref().take(size as u64 + 1).read_to_end(&mut encoding)?;
+    if encoding.len() != size {
+        return Err(Error::LengthMismatch { declared: size, provided: encoding.len() })
+    }
+    Ok(T::deserialize(&encoding)?)
+}
+
+fn check_size<T: Serializable>(size: usize, max_size: usize) -> Result<(), Error> {
+    let max_size = T::FIXED_SIZE.unwrap_or(max_size).min(max_size);
+    if size > max_size {
+        return Err(Error::TooLarge { size, max_size })
+    }
+    if let Some(min_size) = T::FIXED_SIZE {
+        if size < min_size {
+            return Err(Error::TooSmall { size, min_size })
+        }
+    }
+    Ok(())
+}
+
+fn write_varint(mut value: u64, output: &mut Vec<u8>) {
+    while value >= 0x80 {
+        output.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    output.push(value as u8);
+}
+
+// Returns the value along with the number of bytes it was read from.
+fn read_varint(data: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0u64;
+    for (i, &byte) in data.iter().take(MAX_VARINT_LENGTH).enumerate() {
+        let bits = u64::from(byte & 0x7f);
+        let shift = 7 * i as u32;
+        if shift == 63 && bits > 1 {
+            return None
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1))
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize)]
+    struct Message {
+        slot: u64,
+        data: List<u8, 1024>,
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let message = Message { slot: 12, data: List::try_from(vec![7u8; 700]).unwrap() };
+        let encoded = encode(&message).unwrap();
+        assert!(encoded.len() < 100);
+        assert_eq!(decode::<Message>(&encoded, 1024).unwrap(), message);
+
+        let framed = encode_framed(&message).unwrap();
+        assert_eq!(decode_framed::<Message>(&framed, 1024).unwrap(), message);
+        assert_eq!(decode_framed::<u64>(&encode_framed(&9u64).unwrap(), 1024).unwrap(), 9);
+    }
+
+    #[test]
+    fn test_rejects_declared_size() {
+        let message = Message { slot: 12, data: List::try_from(vec![7u8; 700]).unwrap() };
+        let encoded = encode(&message).unwrap();
+        let result = decode::<Message>(&encoded, 512);
+        assert!(matches!(result, Err(Error::TooLarge { size: 712, max_size: 512 })));
+
+        let framed = encode_framed(&message).unwrap();
+        let result = decode_framed::<Message>(&framed, 512);
+        assert!(matches!(result, Err(Error::TooLarge { size: 712, max_size: 512 })));
+
+        // a prefix claiming more or less than the data decompresses to
+        let mut lying = vec![];
+        write_varint(800, &mut lying);
+        lying.extend_from_slice(&framed[2..]);
+        let result = decode_framed::<Message>(&lying, 1024);
+        assert!(matches!(result, Err(Error::LengthMismatch { declared: 800, provided: 712 })));
+        lying[..2].copy_from_slice(&[0xc8, 0x01]);
+        let result = decode_framed::<Message>(&lying, 1024);
+        assert!(matches!(result, Err(Error::LengthMismatch { declared: 200, provided: 201 })));
+
+        let result = decode_framed::<u64>(&encode_framed(&9u32).unwrap(), 1024);
+        assert!(matches!(result, Err(Error::TooSmall { size: 4, min_size: 8 })));
+        assert!(matches!(decode_framed::<u64>(&[0x80; 11], 1024), Err(Error::InvalidLengthPrefix)));
+    }
+
+    #[test]
+    fn test_varint() {
+        for value in [0, 1, 127, 128, 300, u32::MAX as u64, u64::MAX] {
+            let mut encoding = vec![];
+            write_varint(value, &mut encoding);
+            assert_eq!(read_varint(&encoding), Some((value, encoding.len())));
+        }
+        assert_eq!(read_varint(&[0xac, 0x02]), Some((300, 2)));
+        assert_eq!(read_varint(&[0x80]), None);
+    }
+}
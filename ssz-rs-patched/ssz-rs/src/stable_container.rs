@@ -0,0 +1,288 @@
+use crate::{
+    de::DeserializeError,
+    lib::*,
+    merkleization::{
+        extend_branch, hash_chunks, merkleize, pack_bytes, record_allocation, zeroed_chunks,
+        MerkleizationError, Node, BYTES_PER_CHUNK,
+    },
+    Serializable,
+};
+
+const BITS_PER_BYTE: usize = 8;
+
+/// A container whose fields are all optional and whose tree has room for a fixed number of
+/// fields, i.e. the `StableContainer[N]` type of [EIP-7495].
+///
+/// Derive `SimpleSerialize` with `#[ssz(stable_container = N)]` on a struct whose fields are
+/// each an [`Optional`](crate::Optional). The encoding is a `Bitvector<N>` of the fields that
+/// are present followed by the encoding of a container of only those fields. The root mixes
+/// the root of that bitvector into the roots of the fields merkleized as if the container had
+/// `N` of them, with a zero chunk for each absent field, so the index of a field in the tree
+/// stays the same as fields are added.
+///
+/// [EIP-7495]: https://eips.ethereum.org/EIPS/eip-7495
+pub trait StableContainer: Serializable {
+    /// The number of fields the container has room for, `N`.
+    const CAPACITY: usize;
+    /// The name of each field in order of declaration, which fixes its index in the tree.
+    const FIELD_NAMES: &'static [&'static str];
+}
+
+/// A subset of the fields of the [`StableContainer`] `Base`, i.e. the `Profile[B]` type of
+/// [EIP-7495].
+///
+/// Derive `SimpleSerialize` with `#[ssz(profile = "Base")]` on a struct whose fields have the
+/// names of fields of `Base`, in the same order. Fields that are an
+/// [`Optional`](crate::Optional) stay optional and all others are always present. A profile has
+/// the root of the value of `Base` with the same fields, while its encoding leaves out the
+/// fields that are always present from the leading bitvector, which is omitted altogether if
+/// there are no optional fields.
+///
+/// [EIP-7495]: https://eips.ethereum.org/EIPS/eip-7495
+pub trait Profile: Serializable {
+    type Base: StableContainer;
+
+    /// The index in `Base` of each field, in order of declaration.
+    const FIELD_INDICES: &'static [usize];
+}
+
+/// Find the index of each of `names` in `base_names`, failing to compile if a name is missing
+/// or the names are out of order.
+pub const fn profile_field_indices<const K: usize>(
+    base_names: &[&str],
+    names: [&str; K],
+) -> [usize; K] {
+    let mut indices = [0; K];
+    let mut i = 0;
+    let mut next = 0;
+    while i < K {
+        while next < base_names.len() && !str_eq(base_names[next], names[i]) {
+            next += 1;
+        }
+        if next == base_names.len() {
+            panic!("the fields of a profile must be fields of its base, in the same order")
+        }
+        indices[i] = next;
+        next += 1;
+        i += 1;
+    }
+    indices
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Append the bitvector of `bit_count` bits set for each field in `active`.
+pub fn serialize_active_fields(active: &[bool], bit_count: usize, buffer: &mut Vec<u8>) {
+    let start = buffer.len();
+    buffer.resize(start + bit_count.div_ceil(BITS_PER_BYTE), 0);
+    for (i, _) in active.iter().enumerate().filter(|(_, &is_active)| is_active) {
+        buffer[start + i / BITS_PER_BYTE] |= 1 << (i % BITS_PER_BYTE);
+    }
+}
+
+/// Read the bitvector of `bit_count` bits leading `encoding`, returning whether each of the
+/// first `field_count` fields is present along with the rest of the encoding.
+///
+/// Fails if any later bit is set, as there is no field for it.
+pub fn deserialize_active_fields(
+    encoding: &[u8],
+    bit_count: usize,
+    field_count: usize,
+) -> Result<(Vec<bool>, &[u8]), DeserializeError> {
+    let byte_count = bit_count.div_ceil(BITS_PER_BYTE);
+    if encoding.len() < byte_count {
+        return Err(DeserializeError::ExpectedFurtherInput {
+            provided: encoding.len(),
+            expected: byte_count,
+        })
+    }
+    let (bits, rest) = encoding.split_at(byte_count);
+    let is_set = |i: usize| bits[i / BITS_PER_BYTE] >> (i % BITS_PER_BYTE) & 1 == 1;
+    if let Some(i) = (field_count..byte_count * BITS_PER_BYTE).find(|&i| is_set(i)) {
+        return Err(DeserializeError::InvalidByte(bits[i / BITS_PER_BYTE]))
+    }
+    Ok(((0..field_count).map(is_set).collect(), rest))
+}
+
+// Returns the chunks of a tree with room for `capacity` fields, holding the root of each present
+// field at its index, along with the root of the bitvector of present fields.
+fn stable_chunks(
+    roots: &[(usize, Option<Node>)],
+    capacity: usize,
+) -> Result<(Vec<u8>, Node), MerkleizationError> {
+    let mut chunks = zeroed_chunks(capacity);
+    record_allocation();
+    let mut active_fields = vec![0u8; capacity.div_ceil(BITS_PER_BYTE)];
+    for &(index, root) in roots {
+        if let Some(root) = root {
+            chunks[index * BYTES_PER_CHUNK..(index + 1) * BYTES_PER_CHUNK]
+                .copy_from_slice(root.as_ref());
+            active_fields[index / BITS_PER_BYTE] |= 1 << (index % BITS_PER_BYTE);
+        }
+    }
+    pack_bytes(&mut active_fields);
+    let active_fields_root = merkleize(&active_fields, Some(capacity.div_ceil(256)))?;
+    Ok((chunks, active_fields_root))
+}
+
+/// Compute the root of a stable container with room for `capacity` fields from the index of
+/// each field and its root, if present.
+pub fn merkleize_stable_fields(
+    roots: &[(usize, Option<Node>)],
+    capacity: usize,
+) -> Result<Node, MerkleizationError> {
+    let (chunks, active_fields_root) = stable_chunks(roots, capacity)?;
+    let data_root = merkleize(&chunks, Some(capacity))?;
+    Ok(hash_chunks(data_root, active_fields_root))
+}
+
+/// Extend `branch` from the field at `field_index` of a stable container up to its root, as
+/// `extend_branch` does for other containers.
+pub fn extend_stable_branch(
+    roots: &[(usize, Option<Node>)],
+    capacity: usize,
+    field_index: usize,
+    branch: &mut Vec<Node>,
+) -> Result<(), MerkleizationError> {
+    let (chunks, active_fields_root) = stable_chunks(roots, capacity)?;
+    extend_branch(&chunks, Some(capacity), field_index, branch)?;
+    branch.push(active_fields_root);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize)]
+    #[ssz(stable_container = 4)]
+    struct Shape {
+        side: Optional<u16>,
+        color: Optional<u8>,
+        radius: Optional<u16>,
+    }
+
+    #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize)]
+    #[ssz(profile = "Shape")]
+    struct Square {
+        side: u16,
+        color: u8,
+    }
+
+    #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize)]
+    #[ssz(profile = "Shape")]
+    struct Circle {
+        color: Optional<u8>,
+        radius: u16,
+    }
+
+    #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize)]
+    #[ssz(stable_container = 8)]
+    struct Message {
+        slot: Optional<u64>,
+        data: Optional<List<u8, 32>>,
+    }
+
+    #[test]
+    fn test_stable_container() {
+        let shape =
+            Shape { side: Optional::some(0x42), color: Optional::some(1), ..Default::default() };
+        let encoding = serialize(&shape).unwrap();
+        assert_eq!(encoding, [0x03, 0x42, 0x00, 0x01]);
+        assert_eq!(Shape::deserialize(&encoding).unwrap(), shape);
+        assert_eq!(shape.serialized_length().unwrap(), encoding.len());
+        assert_eq!(Shape::FIELD_NAMES, ["side", "color", "radius"]);
+
+        // a bit set for a field the container does not have
+        assert!(matches!(
+            Shape::deserialize(&[0x0b, 0x42, 0x00, 0x01]),
+            Err(DeserializeError::InvalidByte(0x0b))
+        ));
+        assert!(Shape::deserialize(&[0x03, 0x42, 0x00]).is_err());
+        assert_eq!(Shape::deserialize(&[0x00]).unwrap(), Shape::default());
+
+        let message = Message {
+            data: Optional::some(List::try_from(vec![1, 2]).unwrap()),
+            ..Default::default()
+        };
+        let encoding = serialize(&message).unwrap();
+        assert_eq!(encoding, [0x02, 0x04, 0x00, 0x00, 0x00, 0x01, 0x02]);
+        assert_eq!(Message::deserialize(&encoding).unwrap(), message);
+    }
+
+    #[test]
+    fn test_stable_container_merkleization() {
+        let shape =
+            Shape { side: Optional::some(0x42), color: Optional::some(1), ..Default::default() };
+        let fields = Vector::<Node, 4>::try_from(vec![
+            0x42u16.hash_tree_root().unwrap(),
+            1u8.hash_tree_root().unwrap(),
+            Node::default(),
+            Node::default(),
+        ])
+        .unwrap();
+        let mut active_fields = Bitvector::<4>::default();
+        active_fields.set(0, true);
+        active_fields.set(1, true);
+        let root =
+            hash_chunks(fields.hash_tree_root().unwrap(), active_fields.hash_tree_root().unwrap());
+        assert_eq!(shape.hash_tree_root().unwrap(), root);
+        // the root given for this value by the test vectors of the EIP
+        assert_eq!(
+            format!("{root:?}"),
+            "0xbfdb6fda9d02805e640c0f5767b8d1bb9ff4211498a5e2d7c0f36e1b88ce57ff"
+        );
+
+        let proof = shape.prove(&["color".into()]).unwrap();
+        assert_eq!((proof.index, proof.depth()), (1, 3));
+        assert!(proof.verify(root).is_ok());
+        assert!(shape.prove(&["radius".into()]).is_err());
+    }
+
+    #[test]
+    fn test_profile() {
+        let square = Square { side: 0x42, color: 1 };
+        let encoding = serialize(&square).unwrap();
+        assert_eq!(encoding, [0x42, 0x00, 0x01]);
+        assert_eq!(Square::deserialize(&encoding).unwrap(), square);
+        assert_eq!(Square::FIXED_SIZE, Some(3));
+        assert_eq!(Square::FIELD_INDICES, [0, 1]);
+
+        let shape =
+            Shape { side: Optional::some(0x42), color: Optional::some(1), ..Default::default() };
+        let root = shape.hash_tree_root().unwrap();
+        assert_eq!(square.hash_tree_root().unwrap(), root);
+        let proof = square.prove(&["color".into()]).unwrap();
+        assert!(proof.verify(root).is_ok());
+
+        let circle = Circle { color: Optional::none(), radius: 7 };
+        let encoding = serialize(&circle).unwrap();
+        assert_eq!(encoding, [0x00, 0x07, 0x00]);
+        assert_eq!(Circle::deserialize(&encoding).unwrap(), circle);
+        assert_eq!(Circle::FIELD_INDICES, [1, 2]);
+        let shape = Shape { radius: Optional::some(7), ..Default::default() };
+        assert_eq!(circle.hash_tree_root().unwrap(), shape.hash_tree_root().unwrap());
+
+        let circle = Circle { color: Optional::some(1), radius: 7 };
+        let encoding = serialize(&circle).unwrap();
+        assert_eq!(encoding, [0x01, 0x01, 0x07, 0x00]);
+        assert_eq!(Circle::deserialize(&encoding).unwrap(), circle);
+        assert!(matches!(
+            Circle::deserialize(&[0x02, 0x07, 0x00]),
+            Err(DeserializeError::InvalidByte(0x02))
+        ));
+    }
+}
@@ -0,0 +1,480 @@
+use crate::{
+    array_list::ArrayList,
+    bitlist::Bitlist,
+    bitvector::Bitvector,
+    cached_list::CachedList,
+    lib::*,
+    list::List,
+    merkleization::{GeneralizedIndex, MerkleizationError, Node, Path, PathElement},
+    optional::Optional,
+    progressive_list::ProgressiveList,
+    uint::U256,
+    vector::Vector,
+    Serializable, SimpleSerialize, BITS_PER_BYTE,
+};
+
+const BITS_PER_CHUNK: usize = 256;
+const BYTES_PER_CHUNK: usize = 32;
+// each subtree of a progressive tree has this many times the leaves of the one before
+const SUBTREE_GROWTH: usize = 4;
+
+/// A type whose layout can be described at runtime, e.g. to explore or pretty-print values of
+/// types not known ahead of time.
+///
+/// Derive it with `#[derive(TypeInfo)]` for containers, unions, stable containers and profiles.
+pub trait TypeInfo: Serializable {
+    fn type_info() -> TypeDescriptor;
+}
+
+/// The layout of an SSZ type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeDescriptor {
+    /// The length of the encoding of any value of this type, or `None` if it is variable-size.
+    pub fixed_size: Option<usize>,
+    pub kind: TypeKind,
+}
+
+/// The kinds of SSZ types, along with what distinguishes types of each kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeKind {
+    Boolean,
+    Uint {
+        bits: usize,
+    },
+    Vector {
+        element: Box<TypeDescriptor>,
+        length: usize,
+    },
+    List {
+        element: Box<TypeDescriptor>,
+        limit: usize,
+    },
+    Bitvector {
+        length: usize,
+    },
+    Bitlist {
+        limit: usize,
+    },
+    Container {
+        name: &'static str,
+        fields: Vec<FieldDescriptor>,
+    },
+    /// A union, with the type of the value for each selector, if any.
+    Union {
+        name: &'static str,
+        variants: Vec<Option<TypeDescriptor>>,
+    },
+    Optional {
+        value: Box<TypeDescriptor>,
+    },
+    ProgressiveList {
+        element: Box<TypeDescriptor>,
+    },
+    /// A stable container with room for `capacity` fields, each of which is an `Optional`.
+    StableContainer {
+        name: &'static str,
+        capacity: usize,
+        fields: Vec<FieldDescriptor>,
+    },
+    /// A profile of a stable container with room for `capacity` fields, whose fields are
+    /// merkleized at their index in the base.
+    Profile {
+        name: &'static str,
+        capacity: usize,
+        fields: Vec<FieldDescriptor>,
+    },
+}
+
+/// A field of a container, at `index` among the leaves of the tree of its fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDescriptor {
+    pub name: &'static str,
+    pub index: usize,
+    pub ty: TypeDescriptor,
+}
+
+impl TypeDescriptor {
+    /// Describe the type `T`.
+    pub fn of<T: TypeInfo>() -> Self {
+        T::type_info()
+    }
+
+    /// Whether values of this type are packed into chunks with other values when in a
+    /// collection, rather than taking a chunk each.
+    pub fn is_basic(&self) -> bool {
+        matches!(self.kind, TypeKind::Boolean | TypeKind::Uint { .. })
+    }
+
+    /// The fields of a container, stable container or profile, or nothing for other types.
+    pub fn fields(&self) -> &[FieldDescriptor] {
+        match &self.kind {
+            TypeKind::Container { fields, .. } |
+            TypeKind::StableContainer { fields, .. } |
+            TypeKind::Profile { fields, .. } => fields,
+            _ => &[],
+        }
+    }
+
+    /// The generalized index of the node at `path` in the tree of any value of this type, as
+    /// found in a proof of that node.
+    ///
+    /// A path into the elements of a collection of basic values ends at the chunk holding the
+    /// element.
+    pub fn generalized_index(&self, path: Path) -> Result<GeneralizedIndex, MerkleizationError> {
+        let mut index = 1;
+        let mut ty = Some(self);
+        for element in path {
+            let invalid = || MerkleizationError::InvalidPathElement(element.clone());
+            let current = ty.ok_or_else(invalid)?;
+            let (subtree_index, next) = current.locate(element).ok_or_else(invalid)?;
+            index = concat_indices(index, subtree_index);
+            ty = next;
+        }
+        Ok(index)
+    }
+
+    // Returns the generalized index of the node at `element` in the tree of this type along
+    // with its type, unless it is a chunk of packed basic values or a length.
+    fn locate(&self, element: &PathElement) -> Option<(GeneralizedIndex, Option<&Self>)> {
+        match (&self.kind, element) {
+            (
+                TypeKind::List { .. } |
+                TypeKind::Bitlist { .. } |
+                TypeKind::Optional { .. } |
+                TypeKind::ProgressiveList { .. },
+                PathElement::Length,
+            ) => Some((3, None)),
+            (TypeKind::Vector { element, length }, &PathElement::Index(i)) if i < *length => {
+                let (chunk, next) = element_chunk(element, i);
+                let depth = chunk_depth(chunk_count(element, *length));
+                Some(((1 << depth) | chunk, next))
+            }
+            (TypeKind::List { element, limit }, &PathElement::Index(i)) if i < *limit => {
+                let (chunk, next) = element_chunk(element, i);
+                let depth = chunk_depth(chunk_count(element, *limit));
+                Some(((2 << depth) | chunk, next))
+            }
+            (TypeKind::Bitvector { length }, &PathElement::Index(i)) if i < *length => {
+                let depth = chunk_depth(length.div_ceil(BITS_PER_CHUNK));
+                Some(((1 << depth) | (i / BITS_PER_CHUNK), None))
+            }
+            (TypeKind::Bitlist { limit }, &PathElement::Index(i)) if i < *limit => {
+                let depth = chunk_depth(limit.div_ceil(BITS_PER_CHUNK));
+                Some(((2 << depth) | (i / BITS_PER_CHUNK), None))
+            }
+            (TypeKind::Optional { value }, PathElement::Index(0)) => {
+                Some((2, (!value.is_basic()).then_some(&**value)))
+            }
+            (TypeKind::ProgressiveList { element }, &PathElement::Index(i)) => {
+                let (chunk, next) = element_chunk(element, i);
+                Some((progressive_index(chunk), next))
+            }
+            (TypeKind::Union { variants, .. }, &PathElement::Index(selector)) => {
+                let value = variants.get(selector)?.as_ref()?;
+                Some((2, (!value.is_basic()).then_some(value)))
+            }
+            (TypeKind::Container { fields, .. }, PathElement::Field(name)) => {
+                let field = fields.iter().find(|field| field.name == name)?;
+                let depth = chunk_depth(fields.len());
+                Some(((1 << depth) | field.index, Some(&field.ty)))
+            }
+            (
+                TypeKind::StableContainer { fields, capacity, .. } |
+                TypeKind::Profile { fields, capacity, .. },
+                PathElement::Field(name),
+            ) => {
+                let field = fields.iter().find(|field| field.name == name)?;
+                // the tree holds the value of an optional field, rather than the field itself
+                let ty = match &field.ty.kind {
+                    TypeKind::Optional { value } => value,
+                    _ => &field.ty,
+                };
+                let depth = chunk_depth(*capacity);
+                Some(((2 << depth) | field.index, Some(ty)))
+            }
+            _ => None,
+        }
+    }
+}
+
+// Returns the index of the chunk holding element `i` of a collection of `element`s, along with
+// the type of the element if it has a tree of its own.
+fn element_chunk(element: &TypeDescriptor, i: usize) -> (usize, Option<&TypeDescriptor>) {
+    if element.is_basic() {
+        let size = element.fixed_size.expect("basic types are fixed-size");
+        (i * size / BYTES_PER_CHUNK, None)
+    } else {
+        (i, Some(element))
+    }
+}
+
+// Returns the number of chunks of a collection of `count` elements.
+fn chunk_count(element: &TypeDescriptor, count: usize) -> usize {
+    if element.is_basic() {
+        let size = element.fixed_size.expect("basic types are fixed-size");
+        (count * size).div_ceil(BYTES_PER_CHUNK)
+    } else {
+        count
+    }
+}
+
+// Returns the depth of a tree with room for `chunk_count` chunks.
+fn chunk_depth(chunk_count: usize) -> u32 {
+    chunk_count.max(1).next_power_of_two().trailing_zeros()
+}
+
+// Returns the generalized index of `chunk` in a list merkleized as a progressive tree, whose
+// data is the left child of the root.
+fn progressive_index(chunk: usize) -> GeneralizedIndex {
+    let (mut start, mut leaf_count, mut level) = (0, 1, 0);
+    while chunk >= start + leaf_count {
+        start += leaf_count;
+        leaf_count *= SUBTREE_GROWTH;
+        level += 1;
+    }
+    // left once for each subtree before this one, then right into this one
+    let subtree = (((2 << level) | 1) << leaf_count.trailing_zeros()) | (chunk - start);
+    concat_indices(2, subtree)
+}
+
+// Returns the generalized index of the node at `subtree_index` in the subtree rooted at `index`.
+fn concat_indices(index: GeneralizedIndex, subtree_index: GeneralizedIndex) -> GeneralizedIndex {
+    let depth = usize::BITS - 1 - subtree_index.leading_zeros();
+    (index << depth) | (subtree_index ^ (1 << depth))
+}
+
+impl fmt::Display for TypeDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            TypeKind::Boolean => write!(f, "boolean"),
+            TypeKind::Uint { bits } => write!(f, "uint{bits}"),
+            TypeKind::Vector { element, length } => write!(f, "Vector[{element}, {length}]"),
+            TypeKind::List { element, limit } => write!(f, "List[{element}, {limit}]"),
+            TypeKind::Bitvector { length } => write!(f, "Bitvector[{length}]"),
+            TypeKind::Bitlist { limit } => write!(f, "Bitlist[{limit}]"),
+            TypeKind::Optional { value } => write!(f, "Optional[{value}]"),
+            TypeKind::ProgressiveList { element } => write!(f, "ProgressiveList[{element}]"),
+            TypeKind::Container { name, .. } |
+            TypeKind::Union { name, .. } |
+            TypeKind::StableContainer { name, .. } |
+            TypeKind::Profile { name, .. } => write!(f, "{name}"),
+        }
+    }
+}
+
+fn describe<T: Serializable>(kind: TypeKind) -> TypeDescriptor {
+    TypeDescriptor { fixed_size: T::FIXED_SIZE, kind }
+}
+
+impl TypeInfo for bool {
+    fn type_info() -> TypeDescriptor {
+        describe::<Self>(TypeKind::Boolean)
+    }
+}
+
+macro_rules! impl_uint_type_info {
+    ($($uint:ty),*) => {
+        $(
+            impl TypeInfo for $uint {
+                fn type_info() -> TypeDescriptor {
+                    describe::<Self>(TypeKind::Uint { bits: Self::size_hint() * BITS_PER_BYTE as usize })
+                }
+            }
+        )*
+    };
+}
+
+impl_uint_type_info!(u8, u16, u32, u64, u128, usize, U256);
+
+impl TypeInfo for Node {
+    fn type_info() -> TypeDescriptor {
+        <[u8; BYTES_PER_CHUNK]>::type_info()
+    }
+}
+
+impl<T: TypeInfo, const N: usize> TypeInfo for [T; N] {
+    fn type_info() -> TypeDescriptor {
+        describe::<Self>(TypeKind::Vector { element: Box::new(T::type_info()), length: N })
+    }
+}
+
+impl<T: TypeInfo, const N: usize> TypeInfo for Vector<T, N> {
+    fn type_info() -> TypeDescriptor {
+        describe::<Self>(TypeKind::Vector { element: Box::new(T::type_info()), length: N })
+    }
+}
+
+impl<T: TypeInfo, const N: usize> TypeInfo for List<T, N> {
+    fn type_info() -> TypeDescriptor {
+        describe::<Self>(TypeKind::List { element: Box::new(T::type_info()), limit: N })
+    }
+}
+
+impl<T: TypeInfo + SimpleSerialize, const N: usize> TypeInfo for CachedList<T, N> {
+    fn type_info() -> TypeDescriptor {
+        List::<T, N>::type_info()
+    }
+}
+
+impl<T: TypeInfo + Default, const N: usize> TypeInfo for ArrayList<T, N> {
+    fn type_info() -> TypeDescriptor {
+        List::<T, N>::type_info()
+    }
+}
+
+impl<const N: usize> TypeInfo for Bitvector<N> {
+    fn type_info() -> TypeDescriptor {
+        describe::<Self>(TypeKind::Bitvector { length: N })
+    }
+}
+
+impl<const N: usize> TypeInfo for Bitlist<N> {
+    fn type_info() -> TypeDescriptor {
+        describe::<Self>(TypeKind::Bitlist { limit: N })
+    }
+}
+
+impl<T: TypeInfo> TypeInfo for Optional<T> {
+    fn type_info() -> TypeDescriptor {
+        describe::<Self>(TypeKind::Optional { value: Box::new(T::type_info()) })
+    }
+}
+
+impl<T: TypeInfo> TypeInfo for ProgressiveList<T> {
+    fn type_info() -> TypeDescriptor {
+        describe::<Self>(TypeKind::ProgressiveList { element: Box::new(T::type_info()) })
+    }
+}
+
+impl<T: TypeInfo> TypeInfo for Option<T> {
+    fn type_info() -> TypeDescriptor {
+        describe::<Self>(TypeKind::Union {
+            name: "Option",
+            variants: vec![None, Some(T::type_info())],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[derive(Default, Debug, Clone, PartialEq, Eq, SimpleSerialize, TypeInfo)]
+    struct Header {
+        slot: u64,
+        root: Node,
+        flags: Bitvector<4>,
+    }
+
+    #[derive(Debug, PartialEq, Eq, SimpleSerialize, TypeInfo)]
+    enum Payload {
+        None,
+        Header(Header),
+        Count(u16),
+    }
+
+    #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize, TypeInfo)]
+    struct Block {
+        header: Header,
+        balances: List<u64, 100>,
+        headers: Vector<Header, 3>,
+        bits: Bitlist<300>,
+        extra: Optional<Header>,
+        payload: Option<Payload>,
+        history: ProgressiveList<Header>,
+    }
+
+    #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize, TypeInfo)]
+    #[ssz(stable_container = 4)]
+    struct Shape {
+        side: Optional<u16>,
+        color: Optional<u8>,
+        radius: Optional<u16>,
+    }
+
+    #[derive(Default, Debug, PartialEq, Eq, SimpleSerialize, TypeInfo)]
+    #[ssz(profile = "Shape")]
+    struct Circle {
+        color: Optional<u8>,
+        radius: u16,
+    }
+
+    #[test]
+    fn describe_types() {
+        let info = Block::type_info();
+        assert_eq!(info.fixed_size, None);
+        let fields = info.fields();
+        assert_eq!(fields.len(), 7);
+        assert_eq!((fields[1].name, fields[1].index), ("balances", 1));
+        assert_eq!(fields[0].ty.fixed_size, Some(8 + 32 + 1));
+        assert_eq!(fields[0].ty.fields()[1].ty.to_string(), "Vector[uint8, 32]");
+        let names = fields.iter().map(|field| field.ty.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "Header",
+                "List[uint64, 100]",
+                "Vector[Header, 3]",
+                "Bitlist[300]",
+                "Optional[Header]",
+                "Option",
+                "ProgressiveList[Header]"
+            ]
+        );
+        match Payload::type_info().kind {
+            TypeKind::Union { name, variants } => {
+                assert_eq!(name, "Payload");
+                assert_eq!(variants[0], None);
+                assert_eq!(variants[2], Some(u16::type_info()));
+            }
+            kind => panic!("unexpected kind {kind:?}"),
+        }
+        assert_eq!(U256::type_info().to_string(), "uint256");
+
+        let info = Circle::type_info();
+        let indices = info.fields().iter().map(|field| field.index).collect::<Vec<_>>();
+        assert_eq!(indices, [1, 2]);
+        assert!(matches!(info.kind, TypeKind::Profile { capacity: 4, .. }));
+    }
+
+    #[test]
+    fn generalized_indices_match_proofs() {
+        let header = Header { slot: 3, ..Default::default() };
+        let block = Block {
+            balances: List::try_from(vec![1; 20]).unwrap(),
+            headers: Vector::try_from(vec![header.clone(), header.clone(), header.clone()])
+                .unwrap(),
+            bits: Bitlist::try_from([true; 280].as_slice()).unwrap(),
+            extra: Optional::some(header.clone()),
+            payload: Some(Payload::Header(header.clone())),
+            history: ProgressiveList::from(vec![header; 7]),
+            ..Default::default()
+        };
+        let info = Block::type_info();
+        let paths: Vec<Vec<PathElement>> = vec![
+            vec!["header".into(), "root".into()],
+            vec!["balances".into(), 13.into()],
+            vec!["balances".into(), PathElement::Length],
+            vec!["headers".into(), 2.into(), "flags".into()],
+            vec!["bits".into(), 270.into()],
+            vec!["extra".into(), 0.into(), "slot".into()],
+            vec!["payload".into(), 1.into(), 1.into(), "slot".into()],
+            vec!["history".into(), 0.into()],
+            vec!["history".into(), 6.into(), "root".into()],
+            vec!["history".into(), PathElement::Length],
+        ];
+        for path in paths {
+            let proof = block.prove(&path).unwrap();
+            assert_eq!(info.generalized_index(&path).unwrap(), proof.generalized_index().unwrap());
+        }
+
+        let circle = Circle { color: Optional::some(1), radius: 7 };
+        let path = &["radius".into()];
+        let index = Circle::type_info().generalized_index(path).unwrap();
+        assert_eq!(index, circle.prove(path).unwrap().generalized_index().unwrap());
+
+        assert!(info.generalized_index(&["balances".into(), 100.into()]).is_err());
+        assert!(info.generalized_index(&["header".into(), "slot".into(), 0.into()]).is_err());
+        assert!(info.generalized_index(&["missing".into()]).is_err());
+    }
+}
@@ -1,26 +1,24 @@
 use crate::{
     de::{Deserialize, DeserializeError},
     lib::*,
-    merkleization::{pack_bytes, MerkleizationError, Merkleized, Node},
-    ser::{Serialize, SerializeError},
+    merkleization::{prove_basic, MerkleizationError, Merkleized, Node, Path, Prove},
+    ser::{EncodingSink, Serialize, SerializeError},
     Serializable, SimpleSerialize, BITS_PER_BYTE,
 };
 
 #[inline]
-fn bits_to_bytes(count: u32) -> usize {
+const fn bits_to_bytes(count: u32) -> usize {
     (count / BITS_PER_BYTE) as usize
 }
 
+// Items given after the type are added to its impl of `Serialize`.
 macro_rules! define_uint {
     ($uint:ty) => {
+        define_uint!($uint, {});
+    };
+    ($uint:ty, { $($serialize_item:item)* }) => {
         impl Serializable for $uint {
-            fn is_variable_size() -> bool {
-                false
-            }
-
-            fn size_hint() -> usize {
-                bits_to_bytes(<$uint>::BITS)
-            }
+            const FIXED_SIZE: Option<usize> = Some(bits_to_bytes(<$uint>::BITS));
         }
 
         impl Serialize for $uint {
@@ -28,6 +26,16 @@ macro_rules! define_uint {
                 buffer.extend_from_slice(&self.to_le_bytes());
                 Ok(bits_to_bytes(<$uint>::BITS))
             }
+
+            fn serialize_to_sink(
+                &self,
+                sink: &mut dyn EncodingSink,
+            ) -> Result<usize, SerializeError> {
+                sink.write(&self.to_le_bytes())?;
+                Ok(bits_to_bytes(<$uint>::BITS))
+            }
+
+            $($serialize_item)*
         }
 
         impl Deserialize for $uint {
@@ -53,11 +61,11 @@ macro_rules! define_uint {
         }
 
         impl Merkleized for $uint {
-            fn hash_tree_root(&mut self) -> Result<Node, MerkleizationError> {
-                let mut root = vec![];
-                let _ = self.serialize(&mut root)?;
-                pack_bytes(&mut root);
-                Ok(root.as_slice().try_into().expect("is valid root"))
+            fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
+                let mut root = Node::default();
+                let size = bits_to_bytes(<$uint>::BITS);
+                root[..size].copy_from_slice(&self.to_le_bytes());
+                Ok(root)
             }
 
             fn is_composite_type() -> bool {
@@ -65,11 +73,30 @@ macro_rules! define_uint {
             }
         }
 
+        impl Prove for $uint {
+            fn prove_path(
+                &self,
+                path: Path,
+                _: &mut Vec<Node>,
+            ) -> Result<(Node, usize), MerkleizationError> {
+                prove_basic(self, path)
+            }
+        }
+
         impl SimpleSerialize for $uint {}
     };
 }
 
-define_uint!(u8);
+define_uint!(u8, {
+    // bytes are their own encoding, so any number of them is written at once
+    fn serialize_fixed_elements(
+        elements: &[Self],
+        sink: &mut dyn EncodingSink,
+    ) -> Result<usize, SerializeError> {
+        sink.write(elements)?;
+        Ok(elements.len())
+    }
+});
 define_uint!(u16);
 define_uint!(u32);
 define_uint!(u64);
@@ -77,18 +104,27 @@ define_uint!(u128);
 define_uint!(usize);
 
 /// An unsigned integer represented by 256 bits
+///
+/// This is the `Uint<256, 4>` of [`ruint`](https://docs.rs/ruint), which supplies the arithmetic
+/// (`checked_*`, `wrapping_*` and `saturating_*` methods along with the operators), `From` and
+/// `TryFrom` conversions for the primitive integers, `Display` and `FromStr` in decimal or with a
+/// `0x` prefix in hex. Enable the `primitive-types` feature to convert to and from the `U256` of
+/// `primitive-types`.
+///
+/// ```
+/// # use ssz_rs::U256;
+/// let balance = U256::from(32_000_000_000u64);
+/// let total = balance.checked_mul(U256::from(2)).unwrap();
+/// assert_eq!(total, "64000000000".parse::<U256>().unwrap());
+/// assert_eq!(u64::try_from(total).unwrap(), 64_000_000_000);
+/// assert_eq!(format!("{total:#x}"), "0xee6b28000");
+/// ```
 pub type U256 = alloy_primitives::U256;
 
 const U256_BYTE_COUNT: usize = 32;
 
 impl Serializable for U256 {
-    fn is_variable_size() -> bool {
-        false
-    }
-
-    fn size_hint() -> usize {
-        U256_BYTE_COUNT
-    }
+    const FIXED_SIZE: Option<usize> = Some(U256_BYTE_COUNT);
 }
 
 impl Serialize for U256 {
@@ -96,6 +132,11 @@ impl Serialize for U256 {
         buffer.extend_from_slice(self.as_le_slice());
         Ok(Self::size_hint())
     }
+
+    fn serialize_to_sink(&self, sink: &mut dyn EncodingSink) -> Result<usize, SerializeError> {
+        sink.write(self.as_le_slice())?;
+        Ok(Self::size_hint())
+    }
 }
 
 impl Deserialize for U256 {
@@ -121,7 +162,7 @@ impl Deserialize for U256 {
 }
 
 impl Merkleized for U256 {
-    fn hash_tree_root(&mut self) -> Result<Node, MerkleizationError> {
+    fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
         Ok(Node::try_from(self.as_le_bytes().as_ref()).expect("is right size"))
     }
 
@@ -130,6 +171,16 @@ impl Merkleized for U256 {
     }
 }
 
+impl Prove for U256 {
+    fn prove_path(
+        &self,
+        path: Path,
+        _: &mut Vec<Node>,
+    ) -> Result<(Node, usize), MerkleizationError> {
+        prove_basic(self, path)
+    }
+}
+
 impl SimpleSerialize for U256 {}
 
 #[cfg(test)]
@@ -249,6 +300,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn merkleize_u128() {
+        use crate::{hash_chunks, List, Vector};
+
+        let x = 0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10u128;
+        let mut expected = Node::default();
+        expected[..16].copy_from_slice(&x.to_le_bytes());
+        assert_eq!(x.hash_tree_root().unwrap(), expected);
+
+        // two values are packed into each chunk
+        let vector = Vector::<u128, 3>::try_from(vec![x, 1, 2]).unwrap();
+        let mut first = Node::default();
+        first[..16].copy_from_slice(&x.to_le_bytes());
+        first[16] = 1;
+        let mut second = Node::default();
+        second[0] = 2;
+        let root = hash_chunks(first, second);
+        assert_eq!(vector.hash_tree_root().unwrap(), root);
+        let proof = vector.prove(&[2.into()]).unwrap();
+        assert_eq!((proof.leaf, proof.index), (second, 1));
+        assert!(proof.verify(root).is_ok());
+
+        let list = List::<u128, 3>::try_from(vec![x, 1, 2]).unwrap();
+        assert_eq!(list.hash_tree_root().unwrap(), crate::mix_in_length(&root, 3));
+    }
+
     #[test]
     fn test_serde() {
         let x = U256::from(23);
@@ -1,8 +1,10 @@
 use crate::{
-    de::{Deserialize, DeserializeError},
+    de::{expect_empty, read_selector, DecodingSource, Deserialize, DeserializeError},
     lib::*,
-    merkleization::{mix_in_selector, MerkleizationError, Merkleized, Node},
-    ser::{Serialize, SerializeError},
+    merkleization::{
+        mix_in_selector, MerkleizationError, Merkleized, Node, Path, PathElement, Prove,
+    },
+    ser::{EncodingSink, Serialize, SerializeError},
     Serializable, SimpleSerialize,
 };
 
@@ -14,12 +16,13 @@ use crate::{
 /// }
 /// The SSZ schema for this value would be `Union[None, T]`.
 impl<T: Serializable> Serializable for Option<T> {
-    fn is_variable_size() -> bool {
-        true
-    }
+    const FIXED_SIZE: Option<usize> = None;
 
-    fn size_hint() -> usize {
-        0
+    fn serialized_length(&self) -> Result<usize, SerializeError> {
+        match self {
+            Some(data) => Ok(1 + data.serialized_length()?),
+            None => Ok(1),
+        }
     }
 }
 
@@ -37,6 +40,17 @@ where
             None => 0u8.serialize(buffer),
         }
     }
+
+    fn serialize_to_sink(&self, sink: &mut dyn EncodingSink) -> Result<usize, SerializeError> {
+        match self {
+            Some(data) => {
+                let selector_bytes = 1u8.serialize_to_sink(sink)?;
+                let value_bytes = data.serialize_to_sink(sink)?;
+                Ok(selector_bytes + value_bytes)
+            }
+            None => 0u8.serialize_to_sink(sink),
+        }
+    }
 }
 
 impl<T> Deserialize for Option<T>
@@ -67,13 +81,74 @@ where
             b => Err(DeserializeError::InvalidByte(b)),
         }
     }
+
+    fn deserialize_from_source(
+        source: &mut dyn DecodingSource,
+        len: Option<usize>,
+    ) -> Result<Self, DeserializeError> {
+        match read_selector(source, len)? {
+            (0, remaining) => expect_empty(source, 1, remaining).map(|_| None),
+            (1, remaining) => T::deserialize_from_source(source, remaining).map(Some),
+            (b, _) => Err(DeserializeError::InvalidByte(b)),
+        }
+    }
+
+    fn validate(encoding: &[u8]) -> Result<(), DeserializeError> {
+        match encoding.split_first() {
+            None => Err(DeserializeError::ExpectedFurtherInput { provided: 0, expected: 1 }),
+            Some((0, [])) => Ok(()),
+            Some((0, _)) => {
+                Err(DeserializeError::AdditionalInput { provided: encoding.len(), expected: 1 })
+            }
+            Some((1, value)) => T::validate(value),
+            Some((&b, _)) => Err(DeserializeError::InvalidByte(b)),
+        }
+    }
+}
+
+/// A union, i.e. an `Option` or an `enum` deriving `Serializable`, encoded as a selector
+/// identifying its variant followed by the encoding of that variant's value, if any.
+pub trait Union: Serializable {
+    /// The number of variants, whose selectors run from 0 up to this.
+    fn variant_count() -> usize;
+
+    /// The selector of the variant of this value.
+    fn selector(&self) -> u8;
+
+    /// Decode a value of the variant with `selector` from the `encoding` of the variant's value
+    /// alone, i.e. excluding the selector.
+    fn deserialize_variant(selector: u8, encoding: &[u8]) -> Result<Self, DeserializeError>
+    where
+        Self: Sized;
+}
+
+impl<T> Union for Option<T>
+where
+    T: Serializable,
+{
+    fn variant_count() -> usize {
+        2
+    }
+
+    fn selector(&self) -> u8 {
+        u8::from(self.is_some())
+    }
+
+    fn deserialize_variant(selector: u8, encoding: &[u8]) -> Result<Self, DeserializeError> {
+        match selector {
+            0 if encoding.is_empty() => Ok(None),
+            0 => Err(DeserializeError::AdditionalInput { provided: encoding.len(), expected: 0 }),
+            1 => T::deserialize(encoding).map(Some),
+            b => Err(DeserializeError::InvalidByte(b)),
+        }
+    }
 }
 
 impl<T> Merkleized for Option<T>
 where
     T: SimpleSerialize,
 {
-    fn hash_tree_root(&mut self) -> Result<Node, MerkleizationError> {
+    fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
         match self {
             Some(value) => Ok(mix_in_selector(&value.hash_tree_root()?, 1)),
             None => Ok(mix_in_selector(&Node::default(), 0)),
@@ -81,6 +156,27 @@ where
     }
 }
 
+impl<T> Prove for Option<T>
+where
+    T: SimpleSerialize,
+{
+    fn prove_path(
+        &self,
+        path: Path,
+        branch: &mut Vec<Node>,
+    ) -> Result<(Node, usize), MerkleizationError> {
+        match (path.split_first(), self) {
+            (None, this) => Ok((this.hash_tree_root()?, 0)),
+            (Some((PathElement::Index(1), rest)), Some(value)) => {
+                let proof = value.prove_path(rest, branch)?;
+                branch.push(1usize.hash_tree_root()?);
+                Ok(proof)
+            }
+            (Some((element, _)), _) => Err(MerkleizationError::InvalidPathElement(element.clone())),
+        }
+    }
+}
+
 impl<T> SimpleSerialize for Option<T> where T: SimpleSerialize {}
 
 #[cfg(test)]
@@ -327,6 +423,39 @@ mod tests {
         assert_eq!(result, value);
     }
 
+    fn decode_any_union<U: Union>(encoding: &[u8]) -> Vec<U> {
+        (0..U::variant_count() as u8)
+            .filter_map(|selector| U::deserialize_variant(selector, encoding).ok())
+            .collect()
+    }
+
+    #[test]
+    fn union_selectors() {
+        assert_eq!(Boo::variant_count(), 4);
+        assert_eq!(AnotherOption::variant_count(), 3);
+        assert_eq!(Option::<u8>::variant_count(), 2);
+        assert_eq!(Boo::D(Default::default()).selector(), 3);
+        assert_eq!(AnotherOption::None.selector(), 0);
+        assert_eq!(AnotherOption::B(1).selector(), 2);
+        assert_eq!(Some(1u8).selector(), 1);
+
+        let value = Boo::C(List::try_from(vec![123u8, 253u8]).unwrap());
+        let encoding = serialize(&value).unwrap();
+        let recovered = Boo::deserialize_variant(encoding[0], &encoding[1..]).unwrap();
+        assert_eq!(recovered, value);
+        assert_eq!(
+            decode_any_union::<Boo>(&encoding[1..]),
+            vec![
+                Boo::C(List::try_from(vec![123u8, 253u8]).unwrap()),
+                Boo::D(Vector::try_from(vec![123u8, 253u8]).unwrap())
+            ]
+        );
+        assert_eq!(decode_any_union::<AnotherOption>(&[]), vec![AnotherOption::None]);
+        assert_eq!(decode_any_union::<Option<u8>>(&[5]), vec![Some(5)]);
+        let result = Boo::deserialize_variant(4, &encoding[1..]);
+        assert!(matches!(result, Err(DeserializeError::InvalidByte(4))));
+    }
+
     #[test]
     fn roundtrip_union() {
         let value = Boo::default();
@@ -1,4 +1,4 @@
-use crate::{de::DeserializeError, lib::*, ser::SerializeError, Serializable};
+use crate::{de::DeserializeError, error::HexError, lib::*, ser::SerializeError, Serializable};
 
 /// `serialize` is a convenience function for taking a value that
 /// implements `SimpleSerialize` and attempting to encode it to
@@ -22,6 +22,31 @@ where
     T::deserialize(encoding)
 }
 
+/// The length of the encoding of any value of type `T`, or `None` if `T` is variable-size.
+///
+/// Being a `const fn`, this can size buffers at compile time, e.g. `[0u8;
+/// fixed_size::<T>().unwrap()]`.
+pub const fn fixed_size<T: Serializable>() -> Option<usize> {
+    T::FIXED_SIZE
+}
+
+/// Whether every value of type `T` has an encoding of the same length.
+pub const fn is_fixed_size<T: Serializable>() -> bool {
+    T::FIXED_SIZE.is_some()
+}
+
+/// Decode a string of hex digits, with or without a `0x` prefix, to the bytes they encode.
+pub fn decode_hex(s: &str) -> Result<Vec<u8>, HexError> {
+    let digits = s.strip_prefix("0x").unwrap_or(s).as_bytes();
+    if !digits.len().is_multiple_of(2) {
+        return Err(HexError::OddLength)
+    }
+    let digit = |index: usize| {
+        (digits[index] as char).to_digit(16).ok_or(HexError::InvalidCharacter(index))
+    };
+    (0..digits.len()).step_by(2).map(|i| Ok(((digit(i)? << 4) | digit(i + 1)?) as u8)).collect()
+}
+
 #[inline]
 fn write_hex_from_bytes<D: AsRef<[u8]>>(f: &mut fmt::Formatter<'_>, data: D) -> fmt::Result {
     for i in data.as_ref() {
@@ -38,6 +63,18 @@ pub fn write_bytes_to_lower_hex<T: AsRef<[u8]>>(
     write_hex_from_bytes(f, data)
 }
 
+/// Write `data` as for `fmt::LowerHex`: only the hex digits, unless the alternate form (`{:#x}`)
+/// asks for the `0x` prefix.
+pub fn write_bytes_to_lower_hex_digits<T: AsRef<[u8]>>(
+    f: &mut fmt::Formatter<'_>,
+    data: T,
+) -> fmt::Result {
+    if f.alternate() {
+        write!(f, "0x")?;
+    }
+    write_hex_from_bytes(f, data)
+}
+
 pub fn write_bytes_to_lower_hex_display<T: AsRef<[u8]> + ExactSizeIterator>(
     f: &mut fmt::Formatter<'_>,
     data: T,
@@ -80,4 +117,13 @@ mod tests {
         let s = format!("{data}");
         assert_eq!(s, "0x000102");
     }
+
+    #[test]
+    fn test_decode_hex() {
+        assert_eq!(decode_hex("0x00ff1A").unwrap(), [0, 255, 26]);
+        assert_eq!(decode_hex("00ff1a").unwrap(), [0, 255, 26]);
+        assert!(decode_hex("0x").unwrap().is_empty());
+        assert!(matches!(decode_hex("0x123"), Err(HexError::OddLength)));
+        assert!(matches!(decode_hex("0x12g4"), Err(HexError::InvalidCharacter(2))));
+    }
 }
@@ -1,9 +1,20 @@
 use crate::{
-    de::{deserialize_homogeneous_composite, Deserialize, DeserializeError},
-    error::{Error, InstanceError, TypeError},
+    de::{
+        deserialize_homogeneous_composite, deserialize_homogeneous_composite_from_source,
+        validate_homogeneous_composite, DecodingSource, Deserialize, DeserializeError,
+    },
+    error::{Error, HexError, InstanceError, TypeError},
+    iter::ChunkedIter,
     lib::*,
-    merkleization::{elements_to_chunks, merkleize, pack, MerkleizationError, Merkleized, Node},
-    ser::{Serialize, SerializeError, Serializer},
+    merkleization::{
+        elements_to_chunks, merkleize, pack, prove_element, MerkleizationError, Merkleized, Node,
+        Path, Prove,
+    },
+    ser::{
+        serialize_elements, serialize_elements_to_sink, serialized_length_of_elements,
+        EncodingSink, Serialize, SerializeError,
+    },
+    utils::{decode_hex, write_bytes_to_lower_hex_digits, write_bytes_to_lower_hex_display},
     Serializable, SimpleSerialize,
 };
 
@@ -29,6 +40,24 @@ impl<T: Serializable + PartialEq, const N: usize> PartialEq for Vector<T, N> {
 
 impl<T: Serializable + Eq, const N: usize> Eq for Vector<T, N> {}
 
+impl<T: Serializable + hash::Hash, const N: usize> hash::Hash for Vector<T, N> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+    }
+}
+
+impl<T: Serializable + PartialOrd, const N: usize> PartialOrd for Vector<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.data.partial_cmp(&other.data)
+    }
+}
+
+impl<T: Serializable + Ord, const N: usize> Ord for Vector<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.data.cmp(&other.data)
+    }
+}
+
 impl<T: Serializable, const N: usize> TryFrom<Vec<T>> for Vector<T, N> {
     type Error = (Vec<T>, Error);
 
@@ -45,6 +74,20 @@ impl<T: Serializable, const N: usize> TryFrom<Vec<T>> for Vector<T, N> {
     }
 }
 
+/// NOTE: panics if `N` is `0`, as there are no legal vectors of this type.
+impl<T: Serializable, const N: usize> From<[T; N]> for Vector<T, N> {
+    fn from(data: [T; N]) -> Self {
+        assert!(N > 0);
+        Self { data: data.into() }
+    }
+}
+
+impl<T: Serializable, const N: usize> From<Vector<T, N>> for [T; N] {
+    fn from(vector: Vector<T, N>) -> Self {
+        vector.data.try_into().unwrap_or_else(|_| unreachable!("a vector has exactly `N` elements"))
+    }
+}
+
 impl<T, const N: usize> TryFrom<&[T]> for Vector<T, N>
 where
     T: Serializable + Clone,
@@ -77,6 +120,38 @@ where
     }
 }
 
+impl<const N: usize> Vector<u8, N> {
+    /// Decode a `Vector` of exactly `N` bytes from hex digits, with or without a `0x` prefix.
+    pub fn from_hex(s: &str) -> Result<Self, HexError> {
+        let data = decode_hex(s)?;
+        if data.len() != N {
+            return Err(InstanceError::Exact { required: N, provided: data.len() }.into())
+        }
+        Ok(Self { data })
+    }
+}
+
+/// Abbreviated `0x`-prefixed hex, e.g. `0x0102…0f10`; use `{:#x}` for every byte.
+impl<const N: usize> fmt::Display for Vector<u8, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_bytes_to_lower_hex_display(f, self.data.iter())
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for Vector<u8, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_bytes_to_lower_hex_digits(f, &self.data)
+    }
+}
+
+impl<const N: usize> FromStr for Vector<u8, N> {
+    type Err = HexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
 impl<T, const N: usize> Default for Vector<T, N>
 where
     T: Serializable + Default,
@@ -137,12 +212,16 @@ impl<T, const N: usize> Serializable for Vector<T, N>
 where
     T: Serializable,
 {
-    fn is_variable_size() -> bool {
-        T::is_variable_size()
-    }
+    const FIXED_SIZE: Option<usize> = match T::FIXED_SIZE {
+        Some(size) => Some(size * N),
+        None => None,
+    };
 
-    fn size_hint() -> usize {
-        T::size_hint() * N
+    fn serialized_length(&self) -> Result<usize, SerializeError> {
+        if N == 0 {
+            return Err(TypeError::InvalidBound(N).into())
+        }
+        serialized_length_of_elements(&self.data)
     }
 }
 
@@ -154,11 +233,14 @@ where
         if N == 0 {
             return Err(TypeError::InvalidBound(N).into())
         }
-        let mut serializer = Serializer::default();
-        for element in &self.data {
-            serializer.with_element(element)?;
+        serialize_elements(&self.data, buffer)
+    }
+
+    fn serialize_to_sink(&self, sink: &mut dyn EncodingSink) -> Result<usize, SerializeError> {
+        if N == 0 {
+            return Err(TypeError::InvalidBound(N).into())
         }
-        serializer.serialize(buffer)
+        serialize_elements_to_sink(&self.data, sink)
     }
 }
 
@@ -167,26 +249,63 @@ where
     T: Serializable,
 {
     fn deserialize(encoding: &[u8]) -> Result<Self, DeserializeError> {
+        check_length::<T, N>(encoding)?;
+        let inner = deserialize_homogeneous_composite(encoding)?;
+        Self::from_elements(inner)
+    }
+
+    fn validate(encoding: &[u8]) -> Result<(), DeserializeError> {
+        check_length::<T, N>(encoding)?;
+        let count = validate_homogeneous_composite::<T>(encoding)?;
+        if count != N {
+            return Err(InstanceError::Exact { required: N, provided: count }.into())
+        }
+        Ok(())
+    }
+
+    fn deserialize_from_source(
+        source: &mut dyn DecodingSource,
+        len: Option<usize>,
+    ) -> Result<Self, DeserializeError> {
         if N == 0 {
             return Err(TypeError::InvalidBound(N).into())
         }
-        if !T::is_variable_size() {
-            let expected_length = N * T::size_hint();
-            if encoding.len() < expected_length {
-                return Err(DeserializeError::ExpectedFurtherInput {
-                    provided: encoding.len(),
-                    expected: expected_length,
-                })
-            }
-            if encoding.len() > expected_length {
-                return Err(DeserializeError::AdditionalInput {
-                    provided: encoding.len(),
-                    expected: expected_length,
-                })
-            }
+        let inner = deserialize_homogeneous_composite_from_source(source, len, N)?;
+        Self::from_elements(inner)
+    }
+}
+
+// Check the length of the `encoding` of a vector of `N` elements of type `T`, where it is fixed.
+pub(crate) fn check_length<T: Serializable, const N: usize>(
+    encoding: &[u8],
+) -> Result<(), DeserializeError> {
+    if N == 0 {
+        return Err(TypeError::InvalidBound(N).into())
+    }
+    if !T::is_variable_size() {
+        let expected_length = N * T::size_hint();
+        if encoding.len() < expected_length {
+            return Err(DeserializeError::ExpectedFurtherInput {
+                provided: encoding.len(),
+                expected: expected_length,
+            })
         }
-        let inner = deserialize_homogeneous_composite(encoding)?;
-        inner.try_into().map_err(|(_, err)| match err {
+        if encoding.len() > expected_length {
+            return Err(DeserializeError::AdditionalInput {
+                provided: encoding.len(),
+                expected: expected_length,
+            })
+        }
+    }
+    Ok(())
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Serializable,
+{
+    fn from_elements(elements: Vec<T>) -> Result<Self, DeserializeError> {
+        elements.try_into().map_err(|(_, err)| match err {
             Error::Deserialize(err) => err,
             Error::Instance(err) => DeserializeError::InvalidInstance(err),
             Error::Type(err) => DeserializeError::InvalidType(err),
@@ -199,10 +318,48 @@ impl<T, const N: usize> Vector<T, N>
 where
     T: Serializable,
 {
+    /// Collect the elements yielded by `iter`, failing unless there are exactly `N` of them.
+    ///
+    /// No more than `N + 1` elements are taken from `iter`, so it need not end; when there are
+    /// too many, the number provided is estimated from the size hint of what remains.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Error> {
+        if N == 0 {
+            return Err(Error::Type(TypeError::InvalidBound(N)))
+        }
+        let mut iter = iter.into_iter();
+        let mut data = Vec::with_capacity(N);
+        data.extend(iter.by_ref().take(N));
+        if data.len() < N {
+            return Err(Error::Instance(InstanceError::Exact { required: N, provided: data.len() }))
+        }
+        if iter.next().is_some() {
+            let provided = (N + 1).saturating_add(iter.size_hint().0);
+            return Err(Error::Instance(InstanceError::Exact { required: N, provided }))
+        }
+        Ok(Self { data })
+    }
+
     pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         let inner = self.data.iter_mut();
         IterMut { inner }
     }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Copy the elements into an array, e.g. to pass a `Vector<u8, 48>` to an API taking the
+    /// `[u8; 48]` of a public key. Use `<[T; N]>::from` to move them instead.
+    pub fn to_array(&self) -> [T; N]
+    where
+        T: Clone,
+    {
+        self.clone().into()
+    }
 }
 
 pub struct IterMut<'a, T: 'a> {
@@ -221,10 +378,15 @@ impl<T, const N: usize> Vector<T, N>
 where
     T: SimpleSerialize,
 {
-    fn compute_hash_tree_root(&mut self) -> Result<Node, MerkleizationError> {
+    /// Iterate over the elements in batches of `chunks_per_batch` merkle chunks.
+    /// See `ChunkedIter` for more details.
+    pub fn chunked_iter(&self, chunks_per_batch: usize) -> ChunkedIter<'_, T> {
+        ChunkedIter::new(&self.data, chunks_per_batch)
+    }
+
+    fn compute_hash_tree_root(&self) -> Result<Node, MerkleizationError> {
         if T::is_composite_type() {
-            let count = self.len();
-            let chunks = elements_to_chunks(self.data.iter_mut().enumerate(), count)?;
+            let chunks = elements_to_chunks(&self.data)?;
             merkleize(&chunks, None)
         } else {
             let chunks = pack(&self.data)?;
@@ -237,11 +399,27 @@ impl<T, const N: usize> Merkleized for Vector<T, N>
 where
     T: SimpleSerialize,
 {
-    fn hash_tree_root(&mut self) -> Result<Node, MerkleizationError> {
+    fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
         self.compute_hash_tree_root()
     }
 }
 
+impl<T, const N: usize> Prove for Vector<T, N>
+where
+    T: SimpleSerialize,
+{
+    fn prove_path(
+        &self,
+        path: Path,
+        branch: &mut Vec<Node>,
+    ) -> Result<(Node, usize), MerkleizationError> {
+        if path.is_empty() {
+            return Ok((self.hash_tree_root()?, 0))
+        }
+        prove_element(&self.data, None, path, branch)
+    }
+}
+
 impl<T, const N: usize> SimpleSerialize for Vector<T, N> where T: SimpleSerialize {}
 
 #[cfg(feature = "serde")]
@@ -276,6 +454,20 @@ impl<'de, T: Serializable + serde::Deserialize<'de>, const N: usize> serde::Dese
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, T, const N: usize> arbitrary::Arbitrary<'a> for Vector<T, N>
+where
+    T: Serializable + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        if N == 0 {
+            return Err(arbitrary::Error::IncorrectFormat)
+        }
+        let data = (0..N).map(|_| u.arbitrary()).collect::<arbitrary::Result<Vec<T>>>()?;
+        Ok(Self { data })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -293,6 +485,38 @@ mod tests {
         assert_eq!(vector[10..], [0u8; 10]);
     }
 
+    #[test]
+    fn test_try_from_iter() {
+        let key = [7u8; 64];
+        let vector = Vector::<u8, 48>::try_from_iter(key[..48].iter().copied()).unwrap();
+        assert_eq!(vector.as_ref(), &key[..48]);
+        assert_eq!(vector.data.capacity(), 48);
+
+        assert!(matches!(
+            Vector::<u8, 48>::try_from_iter(key.iter().copied()),
+            Err(Error::Instance(InstanceError::Exact { required: 48, provided: 64 }))
+        ));
+        assert!(matches!(
+            Vector::<u8, 48>::try_from_iter(key[..47].iter().copied()),
+            Err(Error::Instance(InstanceError::Exact { required: 48, provided: 47 }))
+        ));
+        assert!(Vector::<u64, 4>::try_from_iter(0..).is_err());
+        assert!(Vector::<u8, 0>::try_from_iter([]).is_err());
+    }
+
+    #[test]
+    fn test_arrays() {
+        let pubkey: [u8; 48] = core::array::from_fn(|i| i as u8);
+        let vector = Vector::<u8, 48>::from(pubkey);
+        assert_eq!(vector.to_array(), pubkey);
+        assert_eq!(<[u8; 48]>::from(vector), pubkey);
+
+        // elements are moved out without cloning them
+        let lists = [List::<u8, 4>::try_from(vec![1]).unwrap(), List::default()];
+        let [first, second] = Vector::from(lists.clone()).into();
+        assert_eq!([first, second], lists);
+    }
+
     #[test]
     #[should_panic]
     fn test_try_from_invalid() {
@@ -404,6 +628,39 @@ mod tests {
         assert_eq!(input, recovered_input);
     }
 
+    #[test]
+    fn test_hex() {
+        type V = Vector<u8, 5>;
+        let input = V::from_hex("0x01001621ff").unwrap();
+        assert_eq!(input.as_ref(), [1, 0, 22, 33, 255]);
+        assert_eq!(format!("{input}"), "0x0100…21ff");
+        assert_eq!(format!("{input:x}"), "01001621ff");
+        assert_eq!(format!("{input:#x}").parse::<V>().unwrap(), input);
+        assert!(matches!(
+            "0x0100".parse::<V>(),
+            Err(HexError::Instance(InstanceError::Exact { required: 5, provided: 2 }))
+        ));
+    }
+
+    #[test]
+    fn test_bytes() {
+        let data: [u8; 40] = core::array::from_fn(|i| i as u8);
+        let mut vector = Vector::<u8, 40>::from(data);
+        assert_eq!(serialize(&vector).unwrap(), data);
+        let mut buffer = [0u8; 40];
+        assert_eq!(vector.serialize_into(&mut buffer).unwrap(), 40);
+        assert_eq!(buffer, data);
+
+        let mut last = [0u8; 32];
+        last[..8].copy_from_slice(&data[32..]);
+        let expected = crate::merkleization::hash_chunks(&data[..32], last);
+        assert_eq!(vector.hash_tree_root().unwrap(), expected);
+
+        vector.as_mut_slice()[0] = 255;
+        assert_eq!(vector.as_slice()[..2], [255, 1]);
+        assert_ne!(vector.hash_tree_root().unwrap(), expected);
+    }
+
     #[test]
     #[should_panic]
     fn test_illegal_serde() {
@@ -0,0 +1,381 @@
+use crate::{
+    de::{Deserialize, DeserializeError},
+    error::{InstanceError, TypeError},
+    lib::*,
+    ser::BYTES_PER_LENGTH_OFFSET,
+    Bitlist, Bitvector, List, Node, Serializable, Vector, U256,
+};
+
+/// A type that can be read from an SSZ encoding borrowed for `'a`, without copying it.
+///
+/// Building a view only checks what is needed to locate its parts, e.g. the size of a fixed-size
+/// encoding or the offset of the first element of a list. Each part is checked as it is read, so
+/// reading a few fields of a large value costs little more than locating them.
+pub trait DeserializeView<'a>: Sized {
+    fn deserialize_view(encoding: &'a [u8]) -> Result<Self, DeserializeError>;
+}
+
+/// A type whose SSZ encoding can be read through its `View` in place of decoding the value.
+///
+/// Basic types are their own views, as they are as cheap to decode as to locate. Containers can
+/// derive an implementation with `#[derive(Viewable)]`, which defines a view type named after the
+/// container, e.g. `BeaconStateView` for `BeaconState`.
+pub trait Viewable<'a>: Serializable {
+    type View: DeserializeView<'a>;
+}
+
+/// Read a view of a `T` from `encoding`.
+pub fn view<'a, T: Viewable<'a>>(encoding: &'a [u8]) -> Result<T::View, DeserializeError> {
+    T::View::deserialize_view(encoding)
+}
+
+macro_rules! impl_view_by_value {
+    ($($type:ty),*) => {
+        $(
+            impl<'a> DeserializeView<'a> for $type {
+                fn deserialize_view(encoding: &'a [u8]) -> Result<Self, DeserializeError> {
+                    <$type>::deserialize(encoding)
+                }
+            }
+
+            impl<'a> Viewable<'a> for $type {
+                type View = Self;
+            }
+        )*
+    };
+}
+
+impl_view_by_value!(bool, u8, u16, u32, u64, u128, usize, U256, Node);
+
+// Bitfields pack a bit per element, so are decoded rather than viewed.
+impl<'a, const N: usize> DeserializeView<'a> for Bitvector<N> {
+    fn deserialize_view(encoding: &'a [u8]) -> Result<Self, DeserializeError> {
+        Self::deserialize(encoding)
+    }
+}
+
+impl<'a, const N: usize> Viewable<'a> for Bitvector<N> {
+    type View = Self;
+}
+
+impl<'a, const N: usize> DeserializeView<'a> for Bitlist<N> {
+    fn deserialize_view(encoding: &'a [u8]) -> Result<Self, DeserializeError> {
+        Self::deserialize(encoding)
+    }
+}
+
+impl<'a, const N: usize> Viewable<'a> for Bitlist<N> {
+    type View = Self;
+}
+
+fn read_offset(encoding: &[u8], index: usize) -> Result<usize, DeserializeError> {
+    let start = index * BYTES_PER_LENGTH_OFFSET;
+    let end = start + BYTES_PER_LENGTH_OFFSET;
+    let offset = encoding.get(start..end).ok_or(DeserializeError::ExpectedFurtherInput {
+        provided: encoding.len(),
+        expected: end,
+    })?;
+    Ok(u32::deserialize(offset)? as usize)
+}
+
+// Locates the elements of a homogeneous composite type within its encoding.
+#[derive(Debug, Clone, Copy)]
+struct Elements<'a> {
+    encoding: &'a [u8],
+    len: usize,
+    // `None` if elements are variable-size, in which case they are found by their offsets
+    element_size: Option<usize>,
+}
+
+impl<'a> Elements<'a> {
+    fn new<T: Serializable>(encoding: &'a [u8]) -> Result<Self, DeserializeError> {
+        if !T::is_variable_size() {
+            let element_size = T::size_hint();
+            let remainder = encoding.len() % element_size;
+            if remainder != 0 {
+                return Err(DeserializeError::AdditionalInput {
+                    provided: encoding.len(),
+                    expected: encoding.len() - remainder,
+                })
+            }
+            let len = encoding.len() / element_size;
+            return Ok(Self { encoding, len, element_size: Some(element_size) })
+        }
+
+        if encoding.is_empty() {
+            return Ok(Self { encoding, len: 0, element_size: None })
+        }
+        // the first offset is also the length of the offsets preceding the elements
+        let offsets_len = read_offset(encoding, 0)?;
+        if encoding.len() < offsets_len {
+            return Err(DeserializeError::ExpectedFurtherInput {
+                provided: encoding.len(),
+                expected: offsets_len,
+            })
+        }
+        if offsets_len == 0 || !offsets_len.is_multiple_of(BYTES_PER_LENGTH_OFFSET) {
+            return Err(DeserializeError::InvalidOffsetsLength(offsets_len))
+        }
+        Ok(Self { encoding, len: offsets_len / BYTES_PER_LENGTH_OFFSET, element_size: None })
+    }
+
+    fn get(&self, index: usize) -> Option<Result<&'a [u8], DeserializeError>> {
+        if index >= self.len {
+            return None
+        }
+        let element = match self.element_size {
+            Some(size) => Ok(&self.encoding[index * size..(index + 1) * size]),
+            None => self.find_variable_size(index),
+        };
+        Some(element)
+    }
+
+    fn find_variable_size(&self, index: usize) -> Result<&'a [u8], DeserializeError> {
+        let start = read_offset(self.encoding, index)?;
+        let end = if index + 1 < self.len {
+            read_offset(self.encoding, index + 1)?
+        } else {
+            self.encoding.len()
+        };
+        if start > end {
+            return Err(DeserializeError::OffsetNotIncreasing { start, end })
+        }
+        self.encoding.get(start..end).ok_or(DeserializeError::ExpectedFurtherInput {
+            provided: self.encoding.len(),
+            expected: end,
+        })
+    }
+}
+
+macro_rules! define_homogeneous_view {
+    ($(#[$attr:meta])* $view:ident) => {
+        $(#[$attr])*
+        pub struct $view<'a, T, const N: usize> {
+            elements: Elements<'a>,
+            _element: PhantomData<fn() -> T>,
+        }
+
+        impl<'a, T, const N: usize> Clone for $view<'a, T, N> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<'a, T, const N: usize> Copy for $view<'a, T, N> {}
+
+        impl<'a, T, const N: usize> Debug for $view<'a, T, N> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($view))
+                    .field("len", &self.elements.len)
+                    .field("encoding_len", &self.elements.encoding.len())
+                    .finish()
+            }
+        }
+
+        impl<'a, T, const N: usize> $view<'a, T, N>
+        where
+            T: Viewable<'a>,
+        {
+            pub fn len(&self) -> usize {
+                self.elements.len
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.elements.len == 0
+            }
+
+            /// The encoding this view reads from.
+            pub fn as_bytes(&self) -> &'a [u8] {
+                self.elements.encoding
+            }
+
+            /// Read the element at `index`, or `None` if out of bounds.
+            pub fn get(&self, index: usize) -> Option<Result<T::View, DeserializeError>> {
+                self.elements.get(index).map(|element| element.and_then(T::View::deserialize_view))
+            }
+
+            pub fn iter(&self) -> impl Iterator<Item = Result<T::View, DeserializeError>> + 'a
+            where
+                T: 'a,
+            {
+                let elements = self.elements;
+                (0..elements.len).map(move |index| {
+                    let element = elements.get(index).expect("index is in bounds");
+                    element.and_then(T::View::deserialize_view)
+                })
+            }
+        }
+    };
+}
+
+define_homogeneous_view!(
+    /// A view of an encoded [`List`].
+    ListView
+);
+
+define_homogeneous_view!(
+    /// A view of an encoded [`Vector`], or of an array.
+    VectorView
+);
+
+impl<'a, T, const N: usize> DeserializeView<'a> for ListView<'a, T, N>
+where
+    T: Viewable<'a>,
+{
+    fn deserialize_view(encoding: &'a [u8]) -> Result<Self, DeserializeError> {
+        let elements = Elements::new::<T>(encoding)?;
+        if elements.len > N {
+            return Err(InstanceError::Bounded { bound: N, provided: elements.len }.into())
+        }
+        Ok(Self { elements, _element: PhantomData })
+    }
+}
+
+impl<'a, T, const N: usize> DeserializeView<'a> for VectorView<'a, T, N>
+where
+    T: Viewable<'a>,
+{
+    fn deserialize_view(encoding: &'a [u8]) -> Result<Self, DeserializeError> {
+        if N == 0 {
+            return Err(TypeError::InvalidBound(N).into())
+        }
+        if !T::is_variable_size() {
+            let expected = N * T::size_hint();
+            if encoding.len() < expected {
+                return Err(DeserializeError::ExpectedFurtherInput {
+                    provided: encoding.len(),
+                    expected,
+                })
+            }
+            if encoding.len() > expected {
+                return Err(DeserializeError::AdditionalInput { provided: encoding.len(), expected })
+            }
+        }
+        let elements = Elements::new::<T>(encoding)?;
+        if elements.len != N {
+            return Err(InstanceError::Exact { required: N, provided: elements.len }.into())
+        }
+        Ok(Self { elements, _element: PhantomData })
+    }
+}
+
+impl<'a, T, const N: usize> Viewable<'a> for List<T, N>
+where
+    T: Viewable<'a>,
+{
+    type View = ListView<'a, T, N>;
+}
+
+impl<'a, T, const N: usize> Viewable<'a> for Vector<T, N>
+where
+    T: Viewable<'a>,
+{
+    type View = VectorView<'a, T, N>;
+}
+
+impl<'a, T, const N: usize> Viewable<'a> for [T; N]
+where
+    T: Viewable<'a>,
+{
+    type View = VectorView<'a, T, N>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Debug, Default, Clone, PartialEq, Eq, SimpleSerialize, Viewable)]
+    pub struct Validator {
+        pub pubkey: Vector<u8, 48>,
+        pub effective_balance: u64,
+        pub slashed: bool,
+    }
+
+    #[derive(Debug, Default, Clone, PartialEq, Eq, SimpleSerialize, Viewable)]
+    pub struct Attestation {
+        pub aggregation_bits: Bitlist<64>,
+        pub index: u64,
+    }
+
+    #[derive(Debug, Default, PartialEq, Eq, SimpleSerialize, Viewable)]
+    pub struct State<const N: usize> {
+        pub slot: u64,
+        pub validators: List<Validator, N>,
+        pub attestations: List<Attestation, 16>,
+        pub roots: Vector<Node, 4>,
+    }
+
+    fn state() -> State<1024> {
+        let validators = (0..300)
+            .map(|i| Validator {
+                pubkey: Vector::try_from(vec![i as u8; 48]).unwrap(),
+                effective_balance: i * 1_000,
+                slashed: i % 7 == 0,
+            })
+            .collect::<Vec<_>>();
+        let attestations = (0..3)
+            .map(|i| Attestation {
+                aggregation_bits: Bitlist::try_from(vec![true; i * 10 + 1].as_slice()).unwrap(),
+                index: i as u64,
+            })
+            .collect::<Vec<_>>();
+        State {
+            slot: 42,
+            validators: List::try_from(validators).unwrap(),
+            attestations: List::try_from(attestations).unwrap(),
+            roots: Vector::try_from(vec![Node::from([9u8; 32]); 4]).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_container_view() {
+        let state = state();
+        let encoding = serialize(&state).unwrap();
+        let view = view::<State<1024>>(&encoding).unwrap();
+        assert_eq!(view.slot().unwrap(), 42);
+
+        let validators = view.validators().unwrap();
+        assert_eq!(validators.len(), 300);
+        let validator = validators.get(21).unwrap().unwrap();
+        assert_eq!(validator.effective_balance().unwrap(), 21_000);
+        assert!(validator.slashed().unwrap());
+        assert_eq!(validator.pubkey().unwrap().as_bytes(), &[21u8; 48]);
+        assert!(validators.get(300).is_none());
+
+        let attestations = view.attestations().unwrap();
+        let indices = attestations.iter().map(|a| a.unwrap().index().unwrap()).collect::<Vec<_>>();
+        assert_eq!(indices, vec![0, 1, 2]);
+        let bits = attestations.get(2).unwrap().unwrap().aggregation_bits().unwrap();
+        assert_eq!(bits, state.attestations[2].aggregation_bits);
+
+        let roots = view.roots().unwrap();
+        assert_eq!(roots.iter().collect::<Result<Vec<_>, _>>().unwrap(), state.roots.to_vec());
+        let validators = List::<Validator, 1024>::deserialize(validators.as_bytes()).unwrap();
+        assert_eq!(validators, state.validators);
+    }
+
+    #[test]
+    fn test_view_checks_lazily() {
+        let state = state();
+        let mut encoding = serialize(&state).unwrap();
+        // the encoding of `slashed` of the validator at index 5 is corrupted
+        let validators_start = u32::deserialize(&encoding[8..12]).unwrap() as usize;
+        let validator_size = Validator::size_hint();
+        encoding[validators_start + 6 * validator_size - 1] = 2;
+        assert!(State::<1024>::deserialize(&encoding).is_err());
+
+        let state_view = view::<State<1024>>(&encoding).unwrap();
+        let validators = state_view.validators().unwrap();
+        assert!(validators.get(4).unwrap().unwrap().slashed().is_ok());
+        let validator = validators.get(5).unwrap().unwrap();
+        assert!(matches!(validator.slashed(), Err(DeserializeError::InvalidByte(2))));
+
+        assert!(view::<State<1024>>(&encoding[..100]).is_err());
+        assert!(State::<1024>::deserialize(&encoding[..100]).is_err());
+        assert!(matches!(
+            view::<State<16>>(&serialize(&state).unwrap()).unwrap().validators(),
+            Err(DeserializeError::InvalidInstance(InstanceError::Bounded { bound: 16, .. }))
+        ));
+    }
+}
@@ -0,0 +1,204 @@
+//! Bindings exposing SSZ types to JavaScript with [`wasm-bindgen`](https://docs.rs/wasm-bindgen).
+//!
+//! JavaScript refers to a type by the name it was [`register`]ed under and exchanges values with
+//! it as JSON, following the conventions of the [`serde`](crate::serde) module, and encodings
+//! and roots as a `Uint8Array`. The exported functions are `serialize(typeName, json)`,
+//! `deserialize(typeName, bytes)` and `hashTreeRoot(typeName, bytes)`.
+//!
+//! A crate built for the browser registers its types once, e.g. from its start function:
+//!
+//! ```ignore
+//! use ssz_rs::wasm::{register, Descriptor};
+//! use wasm_bindgen::prelude::*;
+//!
+//! #[wasm_bindgen(start)]
+//! fn start() {
+//!     register(Descriptor::new::<BeaconBlockHeader>("BeaconBlockHeader"));
+//! }
+//! ```
+//!
+//! Each function is also available to Rust as [`serialize_json`], [`deserialize_json`] and
+//! [`hash_tree_root`], returning an [`Error`] rather than throwing.
+use crate::{
+    de::{Deserialize, DeserializeError},
+    lib::*,
+    merkleization::{MerkleizationError, Node},
+    ser::SerializeError,
+    utils::serialize,
+    SimpleSerialize,
+};
+use std::sync::Mutex;
+use wasm_bindgen::prelude::*;
+
+/// An error serializing, deserializing or merkleizing a registered type.
+#[derive(Debug)]
+pub enum Error {
+    /// No type was registered under the name.
+    UnknownType(String),
+    /// The JSON did not represent a value of the type, or the value could not be represented.
+    Json(serde_json::Error),
+    Serialize(SerializeError),
+    Deserialize(DeserializeError),
+    Merkleization(MerkleizationError),
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+impl From<SerializeError> for Error {
+    fn from(err: SerializeError) -> Self {
+        Self::Serialize(err)
+    }
+}
+
+impl From<DeserializeError> for Error {
+    fn from(err: DeserializeError) -> Self {
+        Self::Deserialize(err)
+    }
+}
+
+impl From<MerkleizationError> for Error {
+    fn from(err: MerkleizationError) -> Self {
+        Self::Merkleization(err)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownType(name) => write!(f, "no type is registered as `{name}`"),
+            Self::Json(err) => write!(f, "invalid JSON: {err}"),
+            Self::Serialize(err) => write!(f, "could not serialize: {err}"),
+            Self::Deserialize(err) => write!(f, "could not deserialize: {err}"),
+            Self::Merkleization(err) => write!(f, "could not merkleize: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The operations of one SSZ type, registered under a name for JavaScript to refer to it by.
+#[derive(Clone, Copy)]
+pub struct Descriptor {
+    name: &'static str,
+    serialize: fn(&str) -> Result<Vec<u8>, Error>,
+    deserialize: fn(&[u8]) -> Result<String, Error>,
+    hash_tree_root: fn(&[u8]) -> Result<Node, Error>,
+}
+
+impl Descriptor {
+    /// Describe `T` under `name`.
+    pub fn new<T>(name: &'static str) -> Self
+    where
+        T: SimpleSerialize + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        Self {
+            name,
+            serialize: |json| Ok(serialize(&serde_json::from_str::<T>(json)?)?),
+            deserialize: |encoding| {
+                Ok(serde_json::to_string(&<T as Deserialize>::deserialize(encoding)?)?)
+            },
+            hash_tree_root: |encoding| {
+                Ok(<T as Deserialize>::deserialize(encoding)?.hash_tree_root()?)
+            },
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl fmt::Debug for Descriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Descriptor").field("name", &self.name).finish()
+    }
+}
+
+static REGISTRY: Mutex<BTreeMap<&str, Descriptor>> = Mutex::new(BTreeMap::new());
+
+/// Make `descriptor` available under its name, replacing any type registered under it before.
+pub fn register(descriptor: Descriptor) {
+    registry().insert(descriptor.name, descriptor);
+}
+
+fn registry() -> std::sync::MutexGuard<'static, BTreeMap<&'static str, Descriptor>> {
+    // the registry holds no invariants a panic could break
+    REGISTRY.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+fn lookup(type_name: &str) -> Result<Descriptor, Error> {
+    registry().get(type_name).copied().ok_or_else(|| Error::UnknownType(type_name.to_string()))
+}
+
+/// Serialize the value of the type registered as `type_name` represented by `json`.
+pub fn serialize_json(type_name: &str, json: &str) -> Result<Vec<u8>, Error> {
+    (lookup(type_name)?.serialize)(json)
+}
+
+/// Deserialize a value of the type registered as `type_name` and represent it as JSON.
+pub fn deserialize_json(type_name: &str, encoding: &[u8]) -> Result<String, Error> {
+    (lookup(type_name)?.deserialize)(encoding)
+}
+
+/// Compute the root of the value of the type registered as `type_name` that `encoding` decodes
+/// to.
+pub fn hash_tree_root(type_name: &str, encoding: &[u8]) -> Result<Node, Error> {
+    (lookup(type_name)?.hash_tree_root)(encoding)
+}
+
+fn to_js(err: Error) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+#[wasm_bindgen(js_name = serialize)]
+pub fn js_serialize(type_name: &str, json: &str) -> Result<Vec<u8>, JsError> {
+    serialize_json(type_name, json).map_err(to_js)
+}
+
+#[wasm_bindgen(js_name = deserialize)]
+pub fn js_deserialize(type_name: &str, encoding: &[u8]) -> Result<String, JsError> {
+    deserialize_json(type_name, encoding).map_err(to_js)
+}
+
+#[wasm_bindgen(js_name = hashTreeRoot)]
+pub fn js_hash_tree_root(type_name: &str, encoding: &[u8]) -> Result<Vec<u8>, JsError> {
+    hash_tree_root(type_name, encoding).map(|root| root.as_ref().to_vec()).map_err(to_js)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(
+        Default, Debug, PartialEq, Eq, SimpleSerialize, serde::Serialize, serde::Deserialize,
+    )]
+    struct Checkpoint {
+        #[serde(with = "crate::serde::as_str")]
+        epoch: u64,
+        root: Node,
+    }
+
+    #[test]
+    fn test_registered_types() {
+        register(Descriptor::new::<Checkpoint>("Checkpoint"));
+        let checkpoint = Checkpoint { epoch: 7, root: Node::from([3u8; 32]) };
+        let json = serde_json::to_string(&checkpoint).unwrap();
+
+        let encoding = serialize_json("Checkpoint", &json).unwrap();
+        assert_eq!(encoding, serialize(&checkpoint).unwrap());
+        assert_eq!(deserialize_json("Checkpoint", &encoding).unwrap(), json);
+        assert_eq!(
+            hash_tree_root("Checkpoint", &encoding).unwrap(),
+            checkpoint.hash_tree_root().unwrap()
+        );
+
+        assert!(matches!(serialize_json("Checkpoint", "{}"), Err(Error::Json(_))));
+        assert!(matches!(hash_tree_root("Checkpoint", &encoding[1..]), Err(Error::Deserialize(_))));
+        assert!(matches!(deserialize_json("Slot", &encoding), Err(Error::UnknownType(_))));
+    }
+}